@@ -0,0 +1,21 @@
+//! Regenerate `src/generated.rs` from the bundled dictionary.
+
+use cif_core_items::codegen;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/src/generated.rs");
+    let source = match codegen::generate(codegen::DICTIONARY) {
+        Ok(source) => source,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Err(err) = std::fs::write(path, source) {
+        eprintln!("error: cannot write {path}: {err}");
+        return ExitCode::FAILURE;
+    }
+    println!("wrote {path}");
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,237 @@
+//! Generator for `src/generated.rs`.
+//!
+//! Reads the bundled dictionary (`dic/cif_core_items.dic`) and writes the
+//! [`CoreItem`](crate::CoreItem) enum with the name, aliases and value type
+//! of every definition, and a typed constant for each in
+//! [`items`](crate::items). Run it after editing the dictionary:
+//!
+//! ```text
+//! cargo run -p cif-core-items --bin generate-core-items
+//! ```
+//!
+//! `tests/generated_in_sync.rs` regenerates the file and fails if it differs
+//! from the one checked in.
+
+use crate::ValueType;
+use cif_parser::{CifDocument, CifError, CifFrame};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// The bundled dictionary the checked-in enum is generated from
+pub const DICTIONARY: &str = include_str!("../dic/cif_core_items.dic");
+
+/// One definition of the dictionary
+#[derive(Debug, Clone, PartialEq)]
+pub struct Definition {
+    /// Canonical data name
+    pub name: String,
+    /// Legacy data names
+    pub aliases: Vec<String>,
+    /// Type values are read as
+    pub value_type: ValueType,
+    /// Enum variant
+    pub variant: String,
+}
+
+/// Read every definition of a dictionary, sorted by name
+pub fn definitions(dictionary: &str) -> Result<Vec<Definition>, CifError> {
+    let doc = CifDocument::parse(dictionary)?;
+    let block = doc
+        .first_block()
+        .ok_or_else(|| invalid("dictionary has no data block"))?;
+
+    let mut definitions = block
+        .frames
+        .iter()
+        .map(definition)
+        .collect::<Result<Vec<_>, _>>()?;
+    definitions.sort_by_key(|d| d.name.to_lowercase());
+
+    let mut variants: HashMap<&str, &str> = HashMap::new();
+    for d in &definitions {
+        if let Some(other) = variants.insert(&d.variant, &d.name) {
+            return Err(invalid(&format!(
+                "'{}' and '{}' both become variant {}",
+                other, d.name, d.variant
+            )));
+        }
+    }
+    Ok(definitions)
+}
+
+/// Generate the source of `src/generated.rs` from a dictionary
+pub fn generate(dictionary: &str) -> Result<String, CifError> {
+    let definitions = definitions(dictionary)?;
+    let mut out = String::new();
+
+    out.push_str(
+        "// @generated by `cargo run -p cif-core-items --bin generate-core-items`\n\
+         // from dic/cif_core_items.dic. Do not edit by hand.\n\
+         \n\
+         use crate::ValueType;\n\
+         \n\
+         /// A well-known data item of the CIF core dictionary.\n\
+         ///\n\
+         /// Variants are named after the canonical (DDLm) data name in camel case:\n\
+         /// `_cell.length_a` is `CellLengthA`.\n\
+         #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]\n\
+         #[non_exhaustive]\n\
+         pub enum CoreItem {\n",
+    );
+    for d in &definitions {
+        let _ = writeln!(out, "    /// `{}`", d.name);
+        let _ = writeln!(out, "    {},", d.variant);
+    }
+    out.push_str("}\n\nimpl CoreItem {\n");
+
+    out.push_str("    /// Every item, sorted by data name\n");
+    out.push_str("    pub const ALL: &'static [CoreItem] = &[\n");
+    for d in &definitions {
+        let _ = writeln!(out, "        CoreItem::{},", d.variant);
+    }
+    out.push_str("    ];\n\n");
+
+    out.push_str("    /// Canonical (DDLm) data name\n");
+    out.push_str("    pub const fn name(self) -> &'static str {\n        match self {\n");
+    for d in &definitions {
+        let _ = writeln!(out, "            CoreItem::{} => {:?},", d.variant, d.name);
+    }
+    out.push_str("        }\n    }\n\n");
+
+    out.push_str("    /// Legacy data names meaning the same item\n");
+    out.push_str(
+        "    pub const fn aliases(self) -> &'static [&'static str] {\n        match self {\n",
+    );
+    for d in &definitions {
+        let _ = write!(out, "            CoreItem::{} => &[", d.variant);
+        for (i, alias) in d.aliases.iter().enumerate() {
+            let sep = if i == 0 { "" } else { ", " };
+            let _ = write!(out, "{sep}{alias:?}");
+        }
+        out.push_str("],\n");
+    }
+    out.push_str("        }\n    }\n\n");
+
+    out.push_str("    /// Type values of this item are read as\n");
+    out.push_str("    pub const fn value_type(self) -> ValueType {\n        match self {\n");
+    for d in &definitions {
+        let _ = writeln!(
+            out,
+            "            CoreItem::{} => ValueType::{:?},",
+            d.variant, d.value_type
+        );
+    }
+    out.push_str("        }\n    }\n}\n");
+
+    out.push_str(
+        "\n\
+         /// Every [`CoreItem`] with the Rust type of its values, for\n\
+         /// [`CoreItemsExt::get`](crate::CoreItemsExt::get).\n\
+         ///\n\
+         /// Constants are named after the canonical (DDLm) data name in upper\n\
+         /// snake case: `_cell.length_a` is `CELL_LENGTH_A`.\n\
+         pub mod items {\n\
+         \x20   use crate::{CoreItem, TypedItem};\n\
+         \x20   use cif_parser::Measurand;\n",
+    );
+    for d in &definitions {
+        let rust_type = match d.value_type {
+            ValueType::Measurand => "Measurand",
+            ValueType::Real => "f64",
+            ValueType::Integer => "i64",
+            ValueType::Text => "String",
+        };
+        let _ = writeln!(out, "\n    /// `{}`", d.name);
+        let _ = writeln!(
+            out,
+            "    pub const {}: TypedItem<{rust_type}> = TypedItem::new(CoreItem::{});",
+            const_name(&d.name),
+            d.variant
+        );
+    }
+    out.push_str("}\n");
+
+    Ok(out)
+}
+
+fn definition(frame: &CifFrame) -> Result<Definition, CifError> {
+    let text = |tag: &str| {
+        frame
+            .get_item(tag)
+            .and_then(|v| v.as_string())
+            .ok_or_else(|| invalid(&format!("save_{}: no {tag}", frame.name)))
+    };
+    let name = text("_definition.id")?.to_string();
+
+    let aliases = match frame.find_loop("_alias.definition_id") {
+        Some(loop_) => loop_
+            .get_column("_alias.definition_id")
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|v| v.as_string().map(str::to_string))
+            .collect(),
+        None => frame
+            .get_item("_alias.definition_id")
+            .and_then(|v| v.as_string())
+            .map(|alias| vec![alias.to_string()])
+            .unwrap_or_default(),
+    };
+
+    let purpose = text("_type.purpose")?;
+    let contents = text("_type.contents")?;
+    let value_type = value_type(purpose, contents)
+        .ok_or_else(|| invalid(&format!("{name}: no Rust type for contents '{contents}'")))?;
+
+    Ok(Definition {
+        variant: variant_name(&name),
+        name,
+        aliases,
+        value_type,
+    })
+}
+
+/// Rust type for a single value of DDLm `_type.purpose` and `_type.contents`
+pub fn value_type(purpose: &str, contents: &str) -> Option<ValueType> {
+    match contents {
+        "Real" if purpose == "Measurand" => Some(ValueType::Measurand),
+        "Real" => Some(ValueType::Real),
+        "Integer" => Some(ValueType::Integer),
+        "Text" | "Word" | "Code" | "Name" | "Tag" | "Uri" | "Date" | "DateTime" | "Symop" => {
+            Some(ValueType::Text)
+        }
+        _ => None,
+    }
+}
+
+/// `_space_group.name_H-M_alt` → `SpaceGroupNameHMAlt`
+pub fn variant_name(data_name: &str) -> String {
+    data_name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            let first = chars.next().map(|c| c.to_ascii_uppercase());
+            first
+                .into_iter()
+                .chain(chars.map(|c| c.to_ascii_lowercase()))
+                .collect::<String>()
+        })
+        .collect()
+}
+
+/// `_space_group.name_H-M_alt` → `SPACE_GROUP_NAME_H_M_ALT`
+pub fn const_name(data_name: &str) -> String {
+    data_name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| part.to_ascii_uppercase())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+fn invalid(message: &str) -> CifError {
+    CifError::InvalidStructure {
+        message: message.to_string(),
+        location: None,
+    }
+}
@@ -0,0 +1,4421 @@
+// @generated by `cargo run -p cif-core-items --bin generate-core-items`
+// from dic/cif_core_items.dic. Do not edit by hand.
+
+use crate::ValueType;
+
+/// A well-known data item of the CIF core dictionary.
+///
+/// Variants are named after the canonical (DDLm) data name in camel case:
+/// `_cell.length_a` is `CellLengthA`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum CoreItem {
+    /// `_atom_site.ADP_type`
+    AtomSiteAdpType,
+    /// `_atom_site.attached_hydrogens`
+    AtomSiteAttachedHydrogens,
+    /// `_atom_site.B_equiv_geom_mean`
+    AtomSiteBEquivGeomMean,
+    /// `_atom_site.B_equiv_geom_mean_su`
+    AtomSiteBEquivGeomMeanSu,
+    /// `_atom_site.B_iso_or_equiv`
+    AtomSiteBIsoOrEquiv,
+    /// `_atom_site.B_iso_or_equiv_su`
+    AtomSiteBIsoOrEquivSu,
+    /// `_atom_site.calc_attached_atom`
+    AtomSiteCalcAttachedAtom,
+    /// `_atom_site.calc_flag`
+    AtomSiteCalcFlag,
+    /// `_atom_site.Cartn_x`
+    AtomSiteCartnX,
+    /// `_atom_site.Cartn_x_su`
+    AtomSiteCartnXSu,
+    /// `_atom_site.Cartn_y`
+    AtomSiteCartnY,
+    /// `_atom_site.Cartn_y_su`
+    AtomSiteCartnYSu,
+    /// `_atom_site.Cartn_z`
+    AtomSiteCartnZ,
+    /// `_atom_site.Cartn_z_su`
+    AtomSiteCartnZSu,
+    /// `_atom_site.chemical_conn_number`
+    AtomSiteChemicalConnNumber,
+    /// `_atom_site.constraints`
+    AtomSiteConstraints,
+    /// `_atom_site.description`
+    AtomSiteDescription,
+    /// `_atom_site.disorder_assembly`
+    AtomSiteDisorderAssembly,
+    /// `_atom_site.disorder_group`
+    AtomSiteDisorderGroup,
+    /// `_atom_site.fract_symmform`
+    AtomSiteFractSymmform,
+    /// `_atom_site.fract_x`
+    AtomSiteFractX,
+    /// `_atom_site.fract_x_su`
+    AtomSiteFractXSu,
+    /// `_atom_site.fract_y`
+    AtomSiteFractY,
+    /// `_atom_site.fract_y_su`
+    AtomSiteFractYSu,
+    /// `_atom_site.fract_z`
+    AtomSiteFractZ,
+    /// `_atom_site.fract_z_su`
+    AtomSiteFractZSu,
+    /// `_atom_site.label`
+    AtomSiteLabel,
+    /// `_atom_site.label_component_1`
+    AtomSiteLabelComponent1,
+    /// `_atom_site.label_component_2`
+    AtomSiteLabelComponent2,
+    /// `_atom_site.label_component_3`
+    AtomSiteLabelComponent3,
+    /// `_atom_site.label_component_4`
+    AtomSiteLabelComponent4,
+    /// `_atom_site.label_component_5`
+    AtomSiteLabelComponent5,
+    /// `_atom_site.label_component_6`
+    AtomSiteLabelComponent6,
+    /// `_atom_site.occupancy`
+    AtomSiteOccupancy,
+    /// `_atom_site.occupancy_su`
+    AtomSiteOccupancySu,
+    /// `_atom_site.refinement_flags`
+    AtomSiteRefinementFlags,
+    /// `_atom_site.refinement_flags_ADP`
+    AtomSiteRefinementFlagsAdp,
+    /// `_atom_site.refinement_flags_occupancy`
+    AtomSiteRefinementFlagsOccupancy,
+    /// `_atom_site.refinement_flags_posn`
+    AtomSiteRefinementFlagsPosn,
+    /// `_atom_site.restraints`
+    AtomSiteRestraints,
+    /// `_atom_site.site_symmetry_multiplicity`
+    AtomSiteSiteSymmetryMultiplicity,
+    /// `_atom_site.site_symmetry_order`
+    AtomSiteSiteSymmetryOrder,
+    /// `_atom_site.type_symbol`
+    AtomSiteTypeSymbol,
+    /// `_atom_site.U_equiv_geom_mean`
+    AtomSiteUEquivGeomMean,
+    /// `_atom_site.U_equiv_geom_mean_su`
+    AtomSiteUEquivGeomMeanSu,
+    /// `_atom_site.U_iso_or_equiv`
+    AtomSiteUIsoOrEquiv,
+    /// `_atom_site.U_iso_or_equiv_su`
+    AtomSiteUIsoOrEquivSu,
+    /// `_atom_site.Wyckoff_symbol`
+    AtomSiteWyckoffSymbol,
+    /// `_atom_site_aniso.B_11`
+    AtomSiteAnisoB11,
+    /// `_atom_site_aniso.B_11_su`
+    AtomSiteAnisoB11Su,
+    /// `_atom_site_aniso.B_12`
+    AtomSiteAnisoB12,
+    /// `_atom_site_aniso.B_12_su`
+    AtomSiteAnisoB12Su,
+    /// `_atom_site_aniso.B_13`
+    AtomSiteAnisoB13,
+    /// `_atom_site_aniso.B_13_su`
+    AtomSiteAnisoB13Su,
+    /// `_atom_site_aniso.B_22`
+    AtomSiteAnisoB22,
+    /// `_atom_site_aniso.B_22_su`
+    AtomSiteAnisoB22Su,
+    /// `_atom_site_aniso.B_23`
+    AtomSiteAnisoB23,
+    /// `_atom_site_aniso.B_23_su`
+    AtomSiteAnisoB23Su,
+    /// `_atom_site_aniso.B_33`
+    AtomSiteAnisoB33,
+    /// `_atom_site_aniso.B_33_su`
+    AtomSiteAnisoB33Su,
+    /// `_atom_site_aniso.beta_11`
+    AtomSiteAnisoBeta11,
+    /// `_atom_site_aniso.beta_11_su`
+    AtomSiteAnisoBeta11Su,
+    /// `_atom_site_aniso.beta_12`
+    AtomSiteAnisoBeta12,
+    /// `_atom_site_aniso.beta_12_su`
+    AtomSiteAnisoBeta12Su,
+    /// `_atom_site_aniso.beta_13`
+    AtomSiteAnisoBeta13,
+    /// `_atom_site_aniso.beta_13_su`
+    AtomSiteAnisoBeta13Su,
+    /// `_atom_site_aniso.beta_22`
+    AtomSiteAnisoBeta22,
+    /// `_atom_site_aniso.beta_22_su`
+    AtomSiteAnisoBeta22Su,
+    /// `_atom_site_aniso.beta_23`
+    AtomSiteAnisoBeta23,
+    /// `_atom_site_aniso.beta_23_su`
+    AtomSiteAnisoBeta23Su,
+    /// `_atom_site_aniso.beta_33`
+    AtomSiteAnisoBeta33,
+    /// `_atom_site_aniso.beta_33_su`
+    AtomSiteAnisoBeta33Su,
+    /// `_atom_site_aniso.label`
+    AtomSiteAnisoLabel,
+    /// `_atom_site_aniso.ratio`
+    AtomSiteAnisoRatio,
+    /// `_atom_site_aniso.symmform`
+    AtomSiteAnisoSymmform,
+    /// `_atom_site_aniso.type_symbol`
+    AtomSiteAnisoTypeSymbol,
+    /// `_atom_site_aniso.U_11`
+    AtomSiteAnisoU11,
+    /// `_atom_site_aniso.U_11_su`
+    AtomSiteAnisoU11Su,
+    /// `_atom_site_aniso.U_12`
+    AtomSiteAnisoU12,
+    /// `_atom_site_aniso.U_12_su`
+    AtomSiteAnisoU12Su,
+    /// `_atom_site_aniso.U_13`
+    AtomSiteAnisoU13,
+    /// `_atom_site_aniso.U_13_su`
+    AtomSiteAnisoU13Su,
+    /// `_atom_site_aniso.U_22`
+    AtomSiteAnisoU22,
+    /// `_atom_site_aniso.U_22_su`
+    AtomSiteAnisoU22Su,
+    /// `_atom_site_aniso.U_23`
+    AtomSiteAnisoU23,
+    /// `_atom_site_aniso.U_23_su`
+    AtomSiteAnisoU23Su,
+    /// `_atom_site_aniso.U_33`
+    AtomSiteAnisoU33,
+    /// `_atom_site_aniso.U_33_su`
+    AtomSiteAnisoU33Su,
+    /// `_atom_sites.solution_hydrogens`
+    AtomSitesSolutionHydrogens,
+    /// `_atom_sites.solution_primary`
+    AtomSitesSolutionPrimary,
+    /// `_atom_sites.solution_secondary`
+    AtomSitesSolutionSecondary,
+    /// `_atom_sites.special_details`
+    AtomSitesSpecialDetails,
+    /// `_atom_type.analytical_mass_percent`
+    AtomTypeAnalyticalMassPercent,
+    /// `_atom_type.analytical_mass_percent_su`
+    AtomTypeAnalyticalMassPercentSu,
+    /// `_atom_type.atomic_mass`
+    AtomTypeAtomicMass,
+    /// `_atom_type.atomic_number`
+    AtomTypeAtomicNumber,
+    /// `_atom_type.description`
+    AtomTypeDescription,
+    /// `_atom_type.display_colour`
+    AtomTypeDisplayColour,
+    /// `_atom_type.electron_count`
+    AtomTypeElectronCount,
+    /// `_atom_type.element_symbol`
+    AtomTypeElementSymbol,
+    /// `_atom_type.mass_number`
+    AtomTypeMassNumber,
+    /// `_atom_type.number_in_cell`
+    AtomTypeNumberInCell,
+    /// `_atom_type.oxidation_number`
+    AtomTypeOxidationNumber,
+    /// `_atom_type.radius_bond`
+    AtomTypeRadiusBond,
+    /// `_atom_type.radius_contact`
+    AtomTypeRadiusContact,
+    /// `_atom_type.symbol`
+    AtomTypeSymbol,
+    /// `_audit.block_code`
+    AuditBlockCode,
+    /// `_audit.block_DOI`
+    AuditBlockDoi,
+    /// `_audit.creation_date`
+    AuditCreationDate,
+    /// `_audit.creation_method`
+    AuditCreationMethod,
+    /// `_audit.schema`
+    AuditSchema,
+    /// `_audit.update_record`
+    AuditUpdateRecord,
+    /// `_cell.angle_alpha`
+    CellAngleAlpha,
+    /// `_cell.angle_alpha_su`
+    CellAngleAlphaSu,
+    /// `_cell.angle_beta`
+    CellAngleBeta,
+    /// `_cell.angle_beta_su`
+    CellAngleBetaSu,
+    /// `_cell.angle_gamma`
+    CellAngleGamma,
+    /// `_cell.angle_gamma_su`
+    CellAngleGammaSu,
+    /// `_cell.atomic_mass`
+    CellAtomicMass,
+    /// `_cell.diffrn_id`
+    CellDiffrnId,
+    /// `_cell.formula_units_Z`
+    CellFormulaUnitsZ,
+    /// `_cell.formula_units_Z_details`
+    CellFormulaUnitsZDetails,
+    /// `_cell.length_a`
+    CellLengthA,
+    /// `_cell.length_a_su`
+    CellLengthASu,
+    /// `_cell.length_b`
+    CellLengthB,
+    /// `_cell.length_b_su`
+    CellLengthBSu,
+    /// `_cell.length_c`
+    CellLengthC,
+    /// `_cell.length_c_su`
+    CellLengthCSu,
+    /// `_cell.reciprocal_angle_alpha`
+    CellReciprocalAngleAlpha,
+    /// `_cell.reciprocal_angle_alpha_su`
+    CellReciprocalAngleAlphaSu,
+    /// `_cell.reciprocal_angle_beta`
+    CellReciprocalAngleBeta,
+    /// `_cell.reciprocal_angle_beta_su`
+    CellReciprocalAngleBetaSu,
+    /// `_cell.reciprocal_angle_gamma`
+    CellReciprocalAngleGamma,
+    /// `_cell.reciprocal_angle_gamma_su`
+    CellReciprocalAngleGammaSu,
+    /// `_cell.reciprocal_length_a`
+    CellReciprocalLengthA,
+    /// `_cell.reciprocal_length_a_su`
+    CellReciprocalLengthASu,
+    /// `_cell.reciprocal_length_b`
+    CellReciprocalLengthB,
+    /// `_cell.reciprocal_length_b_su`
+    CellReciprocalLengthBSu,
+    /// `_cell.reciprocal_length_c`
+    CellReciprocalLengthC,
+    /// `_cell.reciprocal_length_c_su`
+    CellReciprocalLengthCSu,
+    /// `_cell.special_details`
+    CellSpecialDetails,
+    /// `_cell.volume`
+    CellVolume,
+    /// `_cell.volume_su`
+    CellVolumeSu,
+    /// `_cell_measurement.condition_id`
+    CellMeasurementConditionId,
+    /// `_cell_measurement.diffrn_id`
+    CellMeasurementDiffrnId,
+    /// `_cell_measurement.pressure`
+    CellMeasurementPressure,
+    /// `_cell_measurement.pressure_su`
+    CellMeasurementPressureSu,
+    /// `_cell_measurement.radiation`
+    CellMeasurementRadiation,
+    /// `_cell_measurement.reflns_used`
+    CellMeasurementReflnsUsed,
+    /// `_cell_measurement.temperature`
+    CellMeasurementTemperature,
+    /// `_cell_measurement.temperature_su`
+    CellMeasurementTemperatureSu,
+    /// `_cell_measurement.theta_max`
+    CellMeasurementThetaMax,
+    /// `_cell_measurement.theta_min`
+    CellMeasurementThetaMin,
+    /// `_cell_measurement.wavelength`
+    CellMeasurementWavelength,
+    /// `_cell_measurement.wavelength_su`
+    CellMeasurementWavelengthSu,
+    /// `_chemical.absolute_configuration`
+    ChemicalAbsoluteConfiguration,
+    /// `_chemical.compound_source`
+    ChemicalCompoundSource,
+    /// `_chemical.enantioexcess_bulk`
+    ChemicalEnantioexcessBulk,
+    /// `_chemical.enantioexcess_bulk_su`
+    ChemicalEnantioexcessBulkSu,
+    /// `_chemical.enantioexcess_bulk_technique`
+    ChemicalEnantioexcessBulkTechnique,
+    /// `_chemical.enantioexcess_crystal`
+    ChemicalEnantioexcessCrystal,
+    /// `_chemical.enantioexcess_crystal_su`
+    ChemicalEnantioexcessCrystalSu,
+    /// `_chemical.enantioexcess_crystal_technique`
+    ChemicalEnantioexcessCrystalTechnique,
+    /// `_chemical.identifier_InChI`
+    ChemicalIdentifierInchi,
+    /// `_chemical.identifier_InChI_key`
+    ChemicalIdentifierInchiKey,
+    /// `_chemical.identifier_InChI_version`
+    ChemicalIdentifierInchiVersion,
+    /// `_chemical.melting_point`
+    ChemicalMeltingPoint,
+    /// `_chemical.melting_point_gt`
+    ChemicalMeltingPointGt,
+    /// `_chemical.melting_point_lt`
+    ChemicalMeltingPointLt,
+    /// `_chemical.melting_point_su`
+    ChemicalMeltingPointSu,
+    /// `_chemical.name_common`
+    ChemicalNameCommon,
+    /// `_chemical.name_mineral`
+    ChemicalNameMineral,
+    /// `_chemical.name_structure_type`
+    ChemicalNameStructureType,
+    /// `_chemical.name_systematic`
+    ChemicalNameSystematic,
+    /// `_chemical.optical_rotation`
+    ChemicalOpticalRotation,
+    /// `_chemical.properties_biological`
+    ChemicalPropertiesBiological,
+    /// `_chemical.properties_physical`
+    ChemicalPropertiesPhysical,
+    /// `_chemical.temperature_decomposition`
+    ChemicalTemperatureDecomposition,
+    /// `_chemical.temperature_decomposition_gt`
+    ChemicalTemperatureDecompositionGt,
+    /// `_chemical.temperature_decomposition_lt`
+    ChemicalTemperatureDecompositionLt,
+    /// `_chemical.temperature_decomposition_su`
+    ChemicalTemperatureDecompositionSu,
+    /// `_chemical.temperature_sublimation`
+    ChemicalTemperatureSublimation,
+    /// `_chemical.temperature_sublimation_gt`
+    ChemicalTemperatureSublimationGt,
+    /// `_chemical.temperature_sublimation_lt`
+    ChemicalTemperatureSublimationLt,
+    /// `_chemical.temperature_sublimation_su`
+    ChemicalTemperatureSublimationSu,
+    /// `_chemical_formula.analytical`
+    ChemicalFormulaAnalytical,
+    /// `_chemical_formula.IUPAC`
+    ChemicalFormulaIupac,
+    /// `_chemical_formula.moiety`
+    ChemicalFormulaMoiety,
+    /// `_chemical_formula.structural`
+    ChemicalFormulaStructural,
+    /// `_chemical_formula.sum`
+    ChemicalFormulaSum,
+    /// `_chemical_formula.weight`
+    ChemicalFormulaWeight,
+    /// `_chemical_formula.weight_meas`
+    ChemicalFormulaWeightMeas,
+    /// `_chemical_formula.weight_meas_su`
+    ChemicalFormulaWeightMeasSu,
+    /// `_computing.cell_refinement`
+    ComputingCellRefinement,
+    /// `_computing.diffrn_collection`
+    ComputingDiffrnCollection,
+    /// `_computing.diffrn_reduction`
+    ComputingDiffrnReduction,
+    /// `_computing.molecular_graphics`
+    ComputingMolecularGraphics,
+    /// `_computing.publication_material`
+    ComputingPublicationMaterial,
+    /// `_computing.sample_tracking`
+    ComputingSampleTracking,
+    /// `_computing.structure_refinement`
+    ComputingStructureRefinement,
+    /// `_computing.structure_solution`
+    ComputingStructureSolution,
+    /// `_diffrn.ambient_environment`
+    DiffrnAmbientEnvironment,
+    /// `_diffrn.ambient_pressure`
+    DiffrnAmbientPressure,
+    /// `_diffrn.ambient_pressure_gt`
+    DiffrnAmbientPressureGt,
+    /// `_diffrn.ambient_pressure_lt`
+    DiffrnAmbientPressureLt,
+    /// `_diffrn.ambient_pressure_su`
+    DiffrnAmbientPressureSu,
+    /// `_diffrn.ambient_temperature`
+    DiffrnAmbientTemperature,
+    /// `_diffrn.ambient_temperature_details`
+    DiffrnAmbientTemperatureDetails,
+    /// `_diffrn.ambient_temperature_gt`
+    DiffrnAmbientTemperatureGt,
+    /// `_diffrn.ambient_temperature_lt`
+    DiffrnAmbientTemperatureLt,
+    /// `_diffrn.ambient_temperature_su`
+    DiffrnAmbientTemperatureSu,
+    /// `_diffrn.crystal_id`
+    DiffrnCrystalId,
+    /// `_diffrn.crystal_support`
+    DiffrnCrystalSupport,
+    /// `_diffrn.crystal_treatment`
+    DiffrnCrystalTreatment,
+    /// `_diffrn.flux_density`
+    DiffrnFluxDensity,
+    /// `_diffrn.id`
+    DiffrnId,
+    /// `_diffrn.measured_fraction_theta_full`
+    DiffrnMeasuredFractionThetaFull,
+    /// `_diffrn.measured_fraction_theta_max`
+    DiffrnMeasuredFractionThetaMax,
+    /// `_diffrn.precession_semi_angle`
+    DiffrnPrecessionSemiAngle,
+    /// `_diffrn.precession_semi_angle_su`
+    DiffrnPrecessionSemiAngleSu,
+    /// `_diffrn.special_details`
+    DiffrnSpecialDetails,
+    /// `_diffrn.symmetry_description`
+    DiffrnSymmetryDescription,
+    /// `_diffrn.total_dose`
+    DiffrnTotalDose,
+    /// `_diffrn.total_dose_su`
+    DiffrnTotalDoseSu,
+    /// `_diffrn.total_exposure_time`
+    DiffrnTotalExposureTime,
+    /// `_diffrn_detector.area_resol_mean`
+    DiffrnDetectorAreaResolMean,
+    /// `_diffrn_detector.description`
+    DiffrnDetectorDescription,
+    /// `_diffrn_detector.details`
+    DiffrnDetectorDetails,
+    /// `_diffrn_detector.dtime`
+    DiffrnDetectorDtime,
+    /// `_diffrn_detector.make`
+    DiffrnDetectorMake,
+    /// `_diffrn_measurement.details`
+    DiffrnMeasurementDetails,
+    /// `_diffrn_measurement.device_class`
+    DiffrnMeasurementDeviceClass,
+    /// `_diffrn_measurement.device_details`
+    DiffrnMeasurementDeviceDetails,
+    /// `_diffrn_measurement.device_make`
+    DiffrnMeasurementDeviceMake,
+    /// `_diffrn_measurement.method`
+    DiffrnMeasurementMethod,
+    /// `_diffrn_measurement.method_precession`
+    DiffrnMeasurementMethodPrecession,
+    /// `_diffrn_measurement.rotation_mode`
+    DiffrnMeasurementRotationMode,
+    /// `_diffrn_measurement.sample_tracking`
+    DiffrnMeasurementSampleTracking,
+    /// `_diffrn_measurement.sample_tracking_method`
+    DiffrnMeasurementSampleTrackingMethod,
+    /// `_diffrn_measurement.specimen_attachment_type`
+    DiffrnMeasurementSpecimenAttachmentType,
+    /// `_diffrn_measurement.specimen_support`
+    DiffrnMeasurementSpecimenSupport,
+    /// `_diffrn_radiation.collimation`
+    DiffrnRadiationCollimation,
+    /// `_diffrn_radiation.filter_edge`
+    DiffrnRadiationFilterEdge,
+    /// `_diffrn_radiation.illumination_mode`
+    DiffrnRadiationIlluminationMode,
+    /// `_diffrn_radiation.inhomogeneity`
+    DiffrnRadiationInhomogeneity,
+    /// `_diffrn_radiation.monochromator`
+    DiffrnRadiationMonochromator,
+    /// `_diffrn_radiation.polarisn_norm`
+    DiffrnRadiationPolarisnNorm,
+    /// `_diffrn_radiation.polarisn_ratio`
+    DiffrnRadiationPolarisnRatio,
+    /// `_diffrn_radiation.probe`
+    DiffrnRadiationProbe,
+    /// `_diffrn_radiation.type`
+    DiffrnRadiationType,
+    /// `_diffrn_radiation.xray_symbol`
+    DiffrnRadiationXraySymbol,
+    /// `_diffrn_radiation_wavelength.details`
+    DiffrnRadiationWavelengthDetails,
+    /// `_diffrn_radiation_wavelength.determination`
+    DiffrnRadiationWavelengthDetermination,
+    /// `_diffrn_radiation_wavelength.id`
+    DiffrnRadiationWavelengthId,
+    /// `_diffrn_radiation_wavelength.type`
+    DiffrnRadiationWavelengthType,
+    /// `_diffrn_radiation_wavelength.value`
+    DiffrnRadiationWavelengthValue,
+    /// `_diffrn_radiation_wavelength.value_su`
+    DiffrnRadiationWavelengthValueSu,
+    /// `_diffrn_radiation_wavelength.wt`
+    DiffrnRadiationWavelengthWt,
+    /// `_diffrn_radiation_wavelength.xray_symbol`
+    DiffrnRadiationWavelengthXraySymbol,
+    /// `_diffrn_reflns.av_R_equivalents`
+    DiffrnReflnsAvREquivalents,
+    /// `_diffrn_reflns.av_sunetI_over_netI`
+    DiffrnReflnsAvSunetiOverNeti,
+    /// `_diffrn_reflns.Laue_measured_fraction_full`
+    DiffrnReflnsLaueMeasuredFractionFull,
+    /// `_diffrn_reflns.Laue_measured_fraction_max`
+    DiffrnReflnsLaueMeasuredFractionMax,
+    /// `_diffrn_reflns.limit_h_max`
+    DiffrnReflnsLimitHMax,
+    /// `_diffrn_reflns.limit_h_min`
+    DiffrnReflnsLimitHMin,
+    /// `_diffrn_reflns.limit_k_max`
+    DiffrnReflnsLimitKMax,
+    /// `_diffrn_reflns.limit_k_min`
+    DiffrnReflnsLimitKMin,
+    /// `_diffrn_reflns.limit_l_max`
+    DiffrnReflnsLimitLMax,
+    /// `_diffrn_reflns.limit_l_min`
+    DiffrnReflnsLimitLMin,
+    /// `_diffrn_reflns.number`
+    DiffrnReflnsNumber,
+    /// `_diffrn_reflns.point_measured_fraction_full`
+    DiffrnReflnsPointMeasuredFractionFull,
+    /// `_diffrn_reflns.point_measured_fraction_max`
+    DiffrnReflnsPointMeasuredFractionMax,
+    /// `_diffrn_reflns.reduction_process`
+    DiffrnReflnsReductionProcess,
+    /// `_diffrn_reflns.resolution_full`
+    DiffrnReflnsResolutionFull,
+    /// `_diffrn_reflns.resolution_max`
+    DiffrnReflnsResolutionMax,
+    /// `_diffrn_reflns.theta_full`
+    DiffrnReflnsThetaFull,
+    /// `_diffrn_reflns.theta_max`
+    DiffrnReflnsThetaMax,
+    /// `_diffrn_reflns.theta_min`
+    DiffrnReflnsThetaMin,
+    /// `_diffrn_source.beamline`
+    DiffrnSourceBeamline,
+    /// `_diffrn_source.convergence_angle`
+    DiffrnSourceConvergenceAngle,
+    /// `_diffrn_source.current`
+    DiffrnSourceCurrent,
+    /// `_diffrn_source.description`
+    DiffrnSourceDescription,
+    /// `_diffrn_source.details`
+    DiffrnSourceDetails,
+    /// `_diffrn_source.device`
+    DiffrnSourceDevice,
+    /// `_diffrn_source.ed_diffracting_area_selection`
+    DiffrnSourceEdDiffractingAreaSelection,
+    /// `_diffrn_source.facility`
+    DiffrnSourceFacility,
+    /// `_diffrn_source.make`
+    DiffrnSourceMake,
+    /// `_diffrn_source.power`
+    DiffrnSourcePower,
+    /// `_diffrn_source.size`
+    DiffrnSourceSize,
+    /// `_diffrn_source.take_off_angle`
+    DiffrnSourceTakeOffAngle,
+    /// `_diffrn_source.target`
+    DiffrnSourceTarget,
+    /// `_diffrn_source.voltage`
+    DiffrnSourceVoltage,
+    /// `_diffrn_standards.decay_percent`
+    DiffrnStandardsDecayPercent,
+    /// `_diffrn_standards.decay_percent_su`
+    DiffrnStandardsDecayPercentSu,
+    /// `_diffrn_standards.interval_count`
+    DiffrnStandardsIntervalCount,
+    /// `_diffrn_standards.interval_time`
+    DiffrnStandardsIntervalTime,
+    /// `_diffrn_standards.number`
+    DiffrnStandardsNumber,
+    /// `_diffrn_standards.scale_su_average`
+    DiffrnStandardsScaleSuAverage,
+    /// `_diffrn_standards.scale_su_average_su`
+    DiffrnStandardsScaleSuAverageSu,
+    /// `_exptl.crystals_number`
+    ExptlCrystalsNumber,
+    /// `_exptl.method`
+    ExptlMethod,
+    /// `_exptl.method_details`
+    ExptlMethodDetails,
+    /// `_exptl.special_details`
+    ExptlSpecialDetails,
+    /// `_exptl.transmission_factor_max`
+    ExptlTransmissionFactorMax,
+    /// `_exptl.transmission_factor_max_su`
+    ExptlTransmissionFactorMaxSu,
+    /// `_exptl.transmission_factor_min`
+    ExptlTransmissionFactorMin,
+    /// `_exptl.transmission_factor_min_su`
+    ExptlTransmissionFactorMinSu,
+    /// `_exptl_absorpt.coefficient_mu`
+    ExptlAbsorptCoefficientMu,
+    /// `_exptl_absorpt.coefficient_mu_su`
+    ExptlAbsorptCoefficientMuSu,
+    /// `_exptl_absorpt.correction_T_max`
+    ExptlAbsorptCorrectionTMax,
+    /// `_exptl_absorpt.correction_T_min`
+    ExptlAbsorptCorrectionTMin,
+    /// `_exptl_absorpt.correction_type`
+    ExptlAbsorptCorrectionType,
+    /// `_exptl_absorpt.process_details`
+    ExptlAbsorptProcessDetails,
+    /// `_exptl_absorpt.special_details`
+    ExptlAbsorptSpecialDetails,
+    /// `_exptl_crystal.colour`
+    ExptlCrystalColour,
+    /// `_exptl_crystal.density_diffrn`
+    ExptlCrystalDensityDiffrn,
+    /// `_exptl_crystal.density_diffrn_su`
+    ExptlCrystalDensityDiffrnSu,
+    /// `_exptl_crystal.density_meas`
+    ExptlCrystalDensityMeas,
+    /// `_exptl_crystal.density_meas_gt`
+    ExptlCrystalDensityMeasGt,
+    /// `_exptl_crystal.density_meas_lt`
+    ExptlCrystalDensityMeasLt,
+    /// `_exptl_crystal.density_meas_su`
+    ExptlCrystalDensityMeasSu,
+    /// `_exptl_crystal.density_meas_temp`
+    ExptlCrystalDensityMeasTemp,
+    /// `_exptl_crystal.density_meas_temp_gt`
+    ExptlCrystalDensityMeasTempGt,
+    /// `_exptl_crystal.density_meas_temp_lt`
+    ExptlCrystalDensityMeasTempLt,
+    /// `_exptl_crystal.density_meas_temp_su`
+    ExptlCrystalDensityMeasTempSu,
+    /// `_exptl_crystal.density_method`
+    ExptlCrystalDensityMethod,
+    /// `_exptl_crystal.description`
+    ExptlCrystalDescription,
+    /// `_exptl_crystal.F_000`
+    ExptlCrystalF000,
+    /// `_exptl_crystal.id`
+    ExptlCrystalId,
+    /// `_exptl_crystal.mosaic_block_size`
+    ExptlCrystalMosaicBlockSize,
+    /// `_exptl_crystal.mosaic_block_size_su`
+    ExptlCrystalMosaicBlockSizeSu,
+    /// `_exptl_crystal.mosaic_method`
+    ExptlCrystalMosaicMethod,
+    /// `_exptl_crystal.mosaicity`
+    ExptlCrystalMosaicity,
+    /// `_exptl_crystal.mosaicity_su`
+    ExptlCrystalMosaicitySu,
+    /// `_exptl_crystal.preparation`
+    ExptlCrystalPreparation,
+    /// `_exptl_crystal.pressure_history`
+    ExptlCrystalPressureHistory,
+    /// `_exptl_crystal.recrystallization_method`
+    ExptlCrystalRecrystallizationMethod,
+    /// `_exptl_crystal.size_length`
+    ExptlCrystalSizeLength,
+    /// `_exptl_crystal.size_length_su`
+    ExptlCrystalSizeLengthSu,
+    /// `_exptl_crystal.size_max`
+    ExptlCrystalSizeMax,
+    /// `_exptl_crystal.size_max_su`
+    ExptlCrystalSizeMaxSu,
+    /// `_exptl_crystal.size_mid`
+    ExptlCrystalSizeMid,
+    /// `_exptl_crystal.size_mid_su`
+    ExptlCrystalSizeMidSu,
+    /// `_exptl_crystal.size_min`
+    ExptlCrystalSizeMin,
+    /// `_exptl_crystal.size_min_su`
+    ExptlCrystalSizeMinSu,
+    /// `_exptl_crystal.size_rad`
+    ExptlCrystalSizeRad,
+    /// `_exptl_crystal.size_rad_su`
+    ExptlCrystalSizeRadSu,
+    /// `_exptl_crystal.thermal_history`
+    ExptlCrystalThermalHistory,
+    /// `_geom_angle.atom_site_label_1`
+    GeomAngleAtomSiteLabel1,
+    /// `_geom_angle.atom_site_label_2`
+    GeomAngleAtomSiteLabel2,
+    /// `_geom_angle.atom_site_label_3`
+    GeomAngleAtomSiteLabel3,
+    /// `_geom_angle.id`
+    GeomAngleId,
+    /// `_geom_angle.publ_flag`
+    GeomAnglePublFlag,
+    /// `_geom_angle.value`
+    GeomAngleValue,
+    /// `_geom_angle.value_su`
+    GeomAngleValueSu,
+    /// `_geom_bond.atom_site_label_1`
+    GeomBondAtomSiteLabel1,
+    /// `_geom_bond.atom_site_label_2`
+    GeomBondAtomSiteLabel2,
+    /// `_geom_bond.distance`
+    GeomBondDistance,
+    /// `_geom_bond.distance_su`
+    GeomBondDistanceSu,
+    /// `_geom_bond.id`
+    GeomBondId,
+    /// `_geom_bond.multiplicity`
+    GeomBondMultiplicity,
+    /// `_geom_bond.publ_flag`
+    GeomBondPublFlag,
+    /// `_geom_bond.valence`
+    GeomBondValence,
+    /// `_geom_bond.valence_su`
+    GeomBondValenceSu,
+    /// `_geom_hbond.angle_DHA`
+    GeomHbondAngleDha,
+    /// `_geom_hbond.angle_DHA_su`
+    GeomHbondAngleDhaSu,
+    /// `_geom_hbond.atom_site_label_A`
+    GeomHbondAtomSiteLabelA,
+    /// `_geom_hbond.atom_site_label_D`
+    GeomHbondAtomSiteLabelD,
+    /// `_geom_hbond.atom_site_label_H`
+    GeomHbondAtomSiteLabelH,
+    /// `_geom_hbond.distance_DA`
+    GeomHbondDistanceDa,
+    /// `_geom_hbond.distance_DA_su`
+    GeomHbondDistanceDaSu,
+    /// `_geom_hbond.distance_DH`
+    GeomHbondDistanceDh,
+    /// `_geom_hbond.distance_DH_su`
+    GeomHbondDistanceDhSu,
+    /// `_geom_hbond.distance_HA`
+    GeomHbondDistanceHa,
+    /// `_geom_hbond.distance_HA_su`
+    GeomHbondDistanceHaSu,
+    /// `_geom_hbond.id`
+    GeomHbondId,
+    /// `_geom_hbond.publ_flag`
+    GeomHbondPublFlag,
+    /// `_geom_torsion.angle`
+    GeomTorsionAngle,
+    /// `_geom_torsion.angle_su`
+    GeomTorsionAngleSu,
+    /// `_geom_torsion.atom_site_label_1`
+    GeomTorsionAtomSiteLabel1,
+    /// `_geom_torsion.atom_site_label_2`
+    GeomTorsionAtomSiteLabel2,
+    /// `_geom_torsion.atom_site_label_3`
+    GeomTorsionAtomSiteLabel3,
+    /// `_geom_torsion.atom_site_label_4`
+    GeomTorsionAtomSiteLabel4,
+    /// `_geom_torsion.id`
+    GeomTorsionId,
+    /// `_geom_torsion.publ_flag`
+    GeomTorsionPublFlag,
+    /// `_refine.diffraction_theory`
+    RefineDiffractionTheory,
+    /// `_refine.diffraction_theory_details`
+    RefineDiffractionTheoryDetails,
+    /// `_refine.special_details`
+    RefineSpecialDetails,
+    /// `_refine_diff.density_max`
+    RefineDiffDensityMax,
+    /// `_refine_diff.density_max_su`
+    RefineDiffDensityMaxSu,
+    /// `_refine_diff.density_min`
+    RefineDiffDensityMin,
+    /// `_refine_diff.density_min_su`
+    RefineDiffDensityMinSu,
+    /// `_refine_diff.density_RMS`
+    RefineDiffDensityRms,
+    /// `_refine_diff.density_RMS_su`
+    RefineDiffDensityRmsSu,
+    /// `_refine_diff.potential_max`
+    RefineDiffPotentialMax,
+    /// `_refine_diff.potential_max_su`
+    RefineDiffPotentialMaxSu,
+    /// `_refine_diff.potential_min`
+    RefineDiffPotentialMin,
+    /// `_refine_diff.potential_min_su`
+    RefineDiffPotentialMinSu,
+    /// `_refine_diff.potential_RMS`
+    RefineDiffPotentialRms,
+    /// `_refine_diff.potential_RMS_su`
+    RefineDiffPotentialRmsSu,
+    /// `_refine_ls.abs_structure_details`
+    RefineLsAbsStructureDetails,
+    /// `_refine_ls.abs_structure_Flack`
+    RefineLsAbsStructureFlack,
+    /// `_refine_ls.abs_structure_Flack_su`
+    RefineLsAbsStructureFlackSu,
+    /// `_refine_ls.abs_structure_Rogers`
+    RefineLsAbsStructureRogers,
+    /// `_refine_ls.abs_structure_Rogers_su`
+    RefineLsAbsStructureRogersSu,
+    /// `_refine_ls.abs_structure_z-score`
+    RefineLsAbsStructureZScore,
+    /// `_refine_ls.d_res_high`
+    RefineLsDResHigh,
+    /// `_refine_ls.d_res_low`
+    RefineLsDResLow,
+    /// `_refine_ls.extinction_coef`
+    RefineLsExtinctionCoef,
+    /// `_refine_ls.extinction_coef_su`
+    RefineLsExtinctionCoefSu,
+    /// `_refine_ls.extinction_expression`
+    RefineLsExtinctionExpression,
+    /// `_refine_ls.extinction_method`
+    RefineLsExtinctionMethod,
+    /// `_refine_ls.F_calc_details`
+    RefineLsFCalcDetails,
+    /// `_refine_ls.F_calc_formula`
+    RefineLsFCalcFormula,
+    /// `_refine_ls.F_calc_precision`
+    RefineLsFCalcPrecision,
+    /// `_refine_ls.F_calc_precision_su`
+    RefineLsFCalcPrecisionSu,
+    /// `_refine_ls.goodness_of_fit_all`
+    RefineLsGoodnessOfFitAll,
+    /// `_refine_ls.goodness_of_fit_all_su`
+    RefineLsGoodnessOfFitAllSu,
+    /// `_refine_ls.goodness_of_fit_gt`
+    RefineLsGoodnessOfFitGt,
+    /// `_refine_ls.goodness_of_fit_gt_su`
+    RefineLsGoodnessOfFitGtSu,
+    /// `_refine_ls.goodness_of_fit_ref`
+    RefineLsGoodnessOfFitRef,
+    /// `_refine_ls.goodness_of_fit_ref_su`
+    RefineLsGoodnessOfFitRefSu,
+    /// `_refine_ls.hydrogen_treatment`
+    RefineLsHydrogenTreatment,
+    /// `_refine_ls.matrix_type`
+    RefineLsMatrixType,
+    /// `_refine_ls.number_constraints`
+    RefineLsNumberConstraints,
+    /// `_refine_ls.number_parameters`
+    RefineLsNumberParameters,
+    /// `_refine_ls.number_reflns`
+    RefineLsNumberReflns,
+    /// `_refine_ls.number_reflns_gt`
+    RefineLsNumberReflnsGt,
+    /// `_refine_ls.number_restraints`
+    RefineLsNumberRestraints,
+    /// `_refine_ls.R_factor_all`
+    RefineLsRFactorAll,
+    /// `_refine_ls.R_factor_gt`
+    RefineLsRFactorGt,
+    /// `_refine_ls.R_Fsqd_factor`
+    RefineLsRFsqdFactor,
+    /// `_refine_ls.R_I_factor`
+    RefineLsRIFactor,
+    /// `_refine_ls.restrained_S_all`
+    RefineLsRestrainedSAll,
+    /// `_refine_ls.restrained_S_all_su`
+    RefineLsRestrainedSAllSu,
+    /// `_refine_ls.restrained_S_gt`
+    RefineLsRestrainedSGt,
+    /// `_refine_ls.restrained_S_gt_su`
+    RefineLsRestrainedSGtSu,
+    /// `_refine_ls.sample_shape_details`
+    RefineLsSampleShapeDetails,
+    /// `_refine_ls.sample_shape_expression`
+    RefineLsSampleShapeExpression,
+    /// `_refine_ls.sample_thickness`
+    RefineLsSampleThickness,
+    /// `_refine_ls.sample_thickness_su`
+    RefineLsSampleThicknessSu,
+    /// `_refine_ls.shift_over_su_max`
+    RefineLsShiftOverSuMax,
+    /// `_refine_ls.shift_over_su_max_lt`
+    RefineLsShiftOverSuMaxLt,
+    /// `_refine_ls.shift_over_su_mean`
+    RefineLsShiftOverSuMean,
+    /// `_refine_ls.shift_over_su_mean_lt`
+    RefineLsShiftOverSuMeanLt,
+    /// `_refine_ls.structure_factor_coef`
+    RefineLsStructureFactorCoef,
+    /// `_refine_ls.weighting_details`
+    RefineLsWeightingDetails,
+    /// `_refine_ls.weighting_scheme`
+    RefineLsWeightingScheme,
+    /// `_refine_ls.wR_factor_all`
+    RefineLsWrFactorAll,
+    /// `_refine_ls.wR_factor_gt`
+    RefineLsWrFactorGt,
+    /// `_refine_ls.wR_factor_ref`
+    RefineLsWrFactorRef,
+    /// `_reflns.apply_dispersion_to_Fcalc`
+    ReflnsApplyDispersionToFcalc,
+    /// `_reflns.d_resolution_high`
+    ReflnsDResolutionHigh,
+    /// `_reflns.d_resolution_low`
+    ReflnsDResolutionLow,
+    /// `_reflns.Friedel_coverage`
+    ReflnsFriedelCoverage,
+    /// `_reflns.Friedel_fraction_full`
+    ReflnsFriedelFractionFull,
+    /// `_reflns.Friedel_fraction_max`
+    ReflnsFriedelFractionMax,
+    /// `_reflns.limit_h_max`
+    ReflnsLimitHMax,
+    /// `_reflns.limit_h_min`
+    ReflnsLimitHMin,
+    /// `_reflns.limit_k_max`
+    ReflnsLimitKMax,
+    /// `_reflns.limit_k_min`
+    ReflnsLimitKMin,
+    /// `_reflns.limit_l_max`
+    ReflnsLimitLMax,
+    /// `_reflns.limit_l_min`
+    ReflnsLimitLMin,
+    /// `_reflns.number_gt`
+    ReflnsNumberGt,
+    /// `_reflns.number_total`
+    ReflnsNumberTotal,
+    /// `_reflns.special_details`
+    ReflnsSpecialDetails,
+    /// `_reflns.threshold_expression`
+    ReflnsThresholdExpression,
+    /// `_space_group.Bravais_type`
+    SpaceGroupBravaisType,
+    /// `_space_group.centring_type`
+    SpaceGroupCentringType,
+    /// `_space_group.crystal_system`
+    SpaceGroupCrystalSystem,
+    /// `_space_group.IT_coordinate_system_code`
+    SpaceGroupItCoordinateSystemCode,
+    /// `_space_group.IT_number`
+    SpaceGroupItNumber,
+    /// `_space_group.Laue_class`
+    SpaceGroupLaueClass,
+    /// `_space_group.multiplicity`
+    SpaceGroupMultiplicity,
+    /// `_space_group.name_H-M_alt`
+    SpaceGroupNameHMAlt,
+    /// `_space_group.name_H-M_alt_description`
+    SpaceGroupNameHMAltDescription,
+    /// `_space_group.name_H-M_full`
+    SpaceGroupNameHMFull,
+    /// `_space_group.name_H-M_ref`
+    SpaceGroupNameHMRef,
+    /// `_space_group.name_Hall`
+    SpaceGroupNameHall,
+    /// `_space_group.name_Schoenflies`
+    SpaceGroupNameSchoenflies,
+    /// `_space_group.Patterson_name_H-M`
+    SpaceGroupPattersonNameHM,
+    /// `_space_group.point_group_H-M`
+    SpaceGroupPointGroupHM,
+    /// `_space_group_symop.id`
+    SpaceGroupSymopId,
+    /// `_space_group_symop.operation_description`
+    SpaceGroupSymopOperationDescription,
+    /// `_space_group_symop.operation_xyz`
+    SpaceGroupSymopOperationXyz,
+    /// `_symmetry.cell_setting`
+    SymmetryCellSetting,
+}
+
+impl CoreItem {
+    /// Every item, sorted by data name
+    pub const ALL: &'static [CoreItem] = &[
+        CoreItem::AtomSiteAdpType,
+        CoreItem::AtomSiteAttachedHydrogens,
+        CoreItem::AtomSiteBEquivGeomMean,
+        CoreItem::AtomSiteBEquivGeomMeanSu,
+        CoreItem::AtomSiteBIsoOrEquiv,
+        CoreItem::AtomSiteBIsoOrEquivSu,
+        CoreItem::AtomSiteCalcAttachedAtom,
+        CoreItem::AtomSiteCalcFlag,
+        CoreItem::AtomSiteCartnX,
+        CoreItem::AtomSiteCartnXSu,
+        CoreItem::AtomSiteCartnY,
+        CoreItem::AtomSiteCartnYSu,
+        CoreItem::AtomSiteCartnZ,
+        CoreItem::AtomSiteCartnZSu,
+        CoreItem::AtomSiteChemicalConnNumber,
+        CoreItem::AtomSiteConstraints,
+        CoreItem::AtomSiteDescription,
+        CoreItem::AtomSiteDisorderAssembly,
+        CoreItem::AtomSiteDisorderGroup,
+        CoreItem::AtomSiteFractSymmform,
+        CoreItem::AtomSiteFractX,
+        CoreItem::AtomSiteFractXSu,
+        CoreItem::AtomSiteFractY,
+        CoreItem::AtomSiteFractYSu,
+        CoreItem::AtomSiteFractZ,
+        CoreItem::AtomSiteFractZSu,
+        CoreItem::AtomSiteLabel,
+        CoreItem::AtomSiteLabelComponent1,
+        CoreItem::AtomSiteLabelComponent2,
+        CoreItem::AtomSiteLabelComponent3,
+        CoreItem::AtomSiteLabelComponent4,
+        CoreItem::AtomSiteLabelComponent5,
+        CoreItem::AtomSiteLabelComponent6,
+        CoreItem::AtomSiteOccupancy,
+        CoreItem::AtomSiteOccupancySu,
+        CoreItem::AtomSiteRefinementFlags,
+        CoreItem::AtomSiteRefinementFlagsAdp,
+        CoreItem::AtomSiteRefinementFlagsOccupancy,
+        CoreItem::AtomSiteRefinementFlagsPosn,
+        CoreItem::AtomSiteRestraints,
+        CoreItem::AtomSiteSiteSymmetryMultiplicity,
+        CoreItem::AtomSiteSiteSymmetryOrder,
+        CoreItem::AtomSiteTypeSymbol,
+        CoreItem::AtomSiteUEquivGeomMean,
+        CoreItem::AtomSiteUEquivGeomMeanSu,
+        CoreItem::AtomSiteUIsoOrEquiv,
+        CoreItem::AtomSiteUIsoOrEquivSu,
+        CoreItem::AtomSiteWyckoffSymbol,
+        CoreItem::AtomSiteAnisoB11,
+        CoreItem::AtomSiteAnisoB11Su,
+        CoreItem::AtomSiteAnisoB12,
+        CoreItem::AtomSiteAnisoB12Su,
+        CoreItem::AtomSiteAnisoB13,
+        CoreItem::AtomSiteAnisoB13Su,
+        CoreItem::AtomSiteAnisoB22,
+        CoreItem::AtomSiteAnisoB22Su,
+        CoreItem::AtomSiteAnisoB23,
+        CoreItem::AtomSiteAnisoB23Su,
+        CoreItem::AtomSiteAnisoB33,
+        CoreItem::AtomSiteAnisoB33Su,
+        CoreItem::AtomSiteAnisoBeta11,
+        CoreItem::AtomSiteAnisoBeta11Su,
+        CoreItem::AtomSiteAnisoBeta12,
+        CoreItem::AtomSiteAnisoBeta12Su,
+        CoreItem::AtomSiteAnisoBeta13,
+        CoreItem::AtomSiteAnisoBeta13Su,
+        CoreItem::AtomSiteAnisoBeta22,
+        CoreItem::AtomSiteAnisoBeta22Su,
+        CoreItem::AtomSiteAnisoBeta23,
+        CoreItem::AtomSiteAnisoBeta23Su,
+        CoreItem::AtomSiteAnisoBeta33,
+        CoreItem::AtomSiteAnisoBeta33Su,
+        CoreItem::AtomSiteAnisoLabel,
+        CoreItem::AtomSiteAnisoRatio,
+        CoreItem::AtomSiteAnisoSymmform,
+        CoreItem::AtomSiteAnisoTypeSymbol,
+        CoreItem::AtomSiteAnisoU11,
+        CoreItem::AtomSiteAnisoU11Su,
+        CoreItem::AtomSiteAnisoU12,
+        CoreItem::AtomSiteAnisoU12Su,
+        CoreItem::AtomSiteAnisoU13,
+        CoreItem::AtomSiteAnisoU13Su,
+        CoreItem::AtomSiteAnisoU22,
+        CoreItem::AtomSiteAnisoU22Su,
+        CoreItem::AtomSiteAnisoU23,
+        CoreItem::AtomSiteAnisoU23Su,
+        CoreItem::AtomSiteAnisoU33,
+        CoreItem::AtomSiteAnisoU33Su,
+        CoreItem::AtomSitesSolutionHydrogens,
+        CoreItem::AtomSitesSolutionPrimary,
+        CoreItem::AtomSitesSolutionSecondary,
+        CoreItem::AtomSitesSpecialDetails,
+        CoreItem::AtomTypeAnalyticalMassPercent,
+        CoreItem::AtomTypeAnalyticalMassPercentSu,
+        CoreItem::AtomTypeAtomicMass,
+        CoreItem::AtomTypeAtomicNumber,
+        CoreItem::AtomTypeDescription,
+        CoreItem::AtomTypeDisplayColour,
+        CoreItem::AtomTypeElectronCount,
+        CoreItem::AtomTypeElementSymbol,
+        CoreItem::AtomTypeMassNumber,
+        CoreItem::AtomTypeNumberInCell,
+        CoreItem::AtomTypeOxidationNumber,
+        CoreItem::AtomTypeRadiusBond,
+        CoreItem::AtomTypeRadiusContact,
+        CoreItem::AtomTypeSymbol,
+        CoreItem::AuditBlockCode,
+        CoreItem::AuditBlockDoi,
+        CoreItem::AuditCreationDate,
+        CoreItem::AuditCreationMethod,
+        CoreItem::AuditSchema,
+        CoreItem::AuditUpdateRecord,
+        CoreItem::CellAngleAlpha,
+        CoreItem::CellAngleAlphaSu,
+        CoreItem::CellAngleBeta,
+        CoreItem::CellAngleBetaSu,
+        CoreItem::CellAngleGamma,
+        CoreItem::CellAngleGammaSu,
+        CoreItem::CellAtomicMass,
+        CoreItem::CellDiffrnId,
+        CoreItem::CellFormulaUnitsZ,
+        CoreItem::CellFormulaUnitsZDetails,
+        CoreItem::CellLengthA,
+        CoreItem::CellLengthASu,
+        CoreItem::CellLengthB,
+        CoreItem::CellLengthBSu,
+        CoreItem::CellLengthC,
+        CoreItem::CellLengthCSu,
+        CoreItem::CellReciprocalAngleAlpha,
+        CoreItem::CellReciprocalAngleAlphaSu,
+        CoreItem::CellReciprocalAngleBeta,
+        CoreItem::CellReciprocalAngleBetaSu,
+        CoreItem::CellReciprocalAngleGamma,
+        CoreItem::CellReciprocalAngleGammaSu,
+        CoreItem::CellReciprocalLengthA,
+        CoreItem::CellReciprocalLengthASu,
+        CoreItem::CellReciprocalLengthB,
+        CoreItem::CellReciprocalLengthBSu,
+        CoreItem::CellReciprocalLengthC,
+        CoreItem::CellReciprocalLengthCSu,
+        CoreItem::CellSpecialDetails,
+        CoreItem::CellVolume,
+        CoreItem::CellVolumeSu,
+        CoreItem::CellMeasurementConditionId,
+        CoreItem::CellMeasurementDiffrnId,
+        CoreItem::CellMeasurementPressure,
+        CoreItem::CellMeasurementPressureSu,
+        CoreItem::CellMeasurementRadiation,
+        CoreItem::CellMeasurementReflnsUsed,
+        CoreItem::CellMeasurementTemperature,
+        CoreItem::CellMeasurementTemperatureSu,
+        CoreItem::CellMeasurementThetaMax,
+        CoreItem::CellMeasurementThetaMin,
+        CoreItem::CellMeasurementWavelength,
+        CoreItem::CellMeasurementWavelengthSu,
+        CoreItem::ChemicalAbsoluteConfiguration,
+        CoreItem::ChemicalCompoundSource,
+        CoreItem::ChemicalEnantioexcessBulk,
+        CoreItem::ChemicalEnantioexcessBulkSu,
+        CoreItem::ChemicalEnantioexcessBulkTechnique,
+        CoreItem::ChemicalEnantioexcessCrystal,
+        CoreItem::ChemicalEnantioexcessCrystalSu,
+        CoreItem::ChemicalEnantioexcessCrystalTechnique,
+        CoreItem::ChemicalIdentifierInchi,
+        CoreItem::ChemicalIdentifierInchiKey,
+        CoreItem::ChemicalIdentifierInchiVersion,
+        CoreItem::ChemicalMeltingPoint,
+        CoreItem::ChemicalMeltingPointGt,
+        CoreItem::ChemicalMeltingPointLt,
+        CoreItem::ChemicalMeltingPointSu,
+        CoreItem::ChemicalNameCommon,
+        CoreItem::ChemicalNameMineral,
+        CoreItem::ChemicalNameStructureType,
+        CoreItem::ChemicalNameSystematic,
+        CoreItem::ChemicalOpticalRotation,
+        CoreItem::ChemicalPropertiesBiological,
+        CoreItem::ChemicalPropertiesPhysical,
+        CoreItem::ChemicalTemperatureDecomposition,
+        CoreItem::ChemicalTemperatureDecompositionGt,
+        CoreItem::ChemicalTemperatureDecompositionLt,
+        CoreItem::ChemicalTemperatureDecompositionSu,
+        CoreItem::ChemicalTemperatureSublimation,
+        CoreItem::ChemicalTemperatureSublimationGt,
+        CoreItem::ChemicalTemperatureSublimationLt,
+        CoreItem::ChemicalTemperatureSublimationSu,
+        CoreItem::ChemicalFormulaAnalytical,
+        CoreItem::ChemicalFormulaIupac,
+        CoreItem::ChemicalFormulaMoiety,
+        CoreItem::ChemicalFormulaStructural,
+        CoreItem::ChemicalFormulaSum,
+        CoreItem::ChemicalFormulaWeight,
+        CoreItem::ChemicalFormulaWeightMeas,
+        CoreItem::ChemicalFormulaWeightMeasSu,
+        CoreItem::ComputingCellRefinement,
+        CoreItem::ComputingDiffrnCollection,
+        CoreItem::ComputingDiffrnReduction,
+        CoreItem::ComputingMolecularGraphics,
+        CoreItem::ComputingPublicationMaterial,
+        CoreItem::ComputingSampleTracking,
+        CoreItem::ComputingStructureRefinement,
+        CoreItem::ComputingStructureSolution,
+        CoreItem::DiffrnAmbientEnvironment,
+        CoreItem::DiffrnAmbientPressure,
+        CoreItem::DiffrnAmbientPressureGt,
+        CoreItem::DiffrnAmbientPressureLt,
+        CoreItem::DiffrnAmbientPressureSu,
+        CoreItem::DiffrnAmbientTemperature,
+        CoreItem::DiffrnAmbientTemperatureDetails,
+        CoreItem::DiffrnAmbientTemperatureGt,
+        CoreItem::DiffrnAmbientTemperatureLt,
+        CoreItem::DiffrnAmbientTemperatureSu,
+        CoreItem::DiffrnCrystalId,
+        CoreItem::DiffrnCrystalSupport,
+        CoreItem::DiffrnCrystalTreatment,
+        CoreItem::DiffrnFluxDensity,
+        CoreItem::DiffrnId,
+        CoreItem::DiffrnMeasuredFractionThetaFull,
+        CoreItem::DiffrnMeasuredFractionThetaMax,
+        CoreItem::DiffrnPrecessionSemiAngle,
+        CoreItem::DiffrnPrecessionSemiAngleSu,
+        CoreItem::DiffrnSpecialDetails,
+        CoreItem::DiffrnSymmetryDescription,
+        CoreItem::DiffrnTotalDose,
+        CoreItem::DiffrnTotalDoseSu,
+        CoreItem::DiffrnTotalExposureTime,
+        CoreItem::DiffrnDetectorAreaResolMean,
+        CoreItem::DiffrnDetectorDescription,
+        CoreItem::DiffrnDetectorDetails,
+        CoreItem::DiffrnDetectorDtime,
+        CoreItem::DiffrnDetectorMake,
+        CoreItem::DiffrnMeasurementDetails,
+        CoreItem::DiffrnMeasurementDeviceClass,
+        CoreItem::DiffrnMeasurementDeviceDetails,
+        CoreItem::DiffrnMeasurementDeviceMake,
+        CoreItem::DiffrnMeasurementMethod,
+        CoreItem::DiffrnMeasurementMethodPrecession,
+        CoreItem::DiffrnMeasurementRotationMode,
+        CoreItem::DiffrnMeasurementSampleTracking,
+        CoreItem::DiffrnMeasurementSampleTrackingMethod,
+        CoreItem::DiffrnMeasurementSpecimenAttachmentType,
+        CoreItem::DiffrnMeasurementSpecimenSupport,
+        CoreItem::DiffrnRadiationCollimation,
+        CoreItem::DiffrnRadiationFilterEdge,
+        CoreItem::DiffrnRadiationIlluminationMode,
+        CoreItem::DiffrnRadiationInhomogeneity,
+        CoreItem::DiffrnRadiationMonochromator,
+        CoreItem::DiffrnRadiationPolarisnNorm,
+        CoreItem::DiffrnRadiationPolarisnRatio,
+        CoreItem::DiffrnRadiationProbe,
+        CoreItem::DiffrnRadiationType,
+        CoreItem::DiffrnRadiationXraySymbol,
+        CoreItem::DiffrnRadiationWavelengthDetails,
+        CoreItem::DiffrnRadiationWavelengthDetermination,
+        CoreItem::DiffrnRadiationWavelengthId,
+        CoreItem::DiffrnRadiationWavelengthType,
+        CoreItem::DiffrnRadiationWavelengthValue,
+        CoreItem::DiffrnRadiationWavelengthValueSu,
+        CoreItem::DiffrnRadiationWavelengthWt,
+        CoreItem::DiffrnRadiationWavelengthXraySymbol,
+        CoreItem::DiffrnReflnsAvREquivalents,
+        CoreItem::DiffrnReflnsAvSunetiOverNeti,
+        CoreItem::DiffrnReflnsLaueMeasuredFractionFull,
+        CoreItem::DiffrnReflnsLaueMeasuredFractionMax,
+        CoreItem::DiffrnReflnsLimitHMax,
+        CoreItem::DiffrnReflnsLimitHMin,
+        CoreItem::DiffrnReflnsLimitKMax,
+        CoreItem::DiffrnReflnsLimitKMin,
+        CoreItem::DiffrnReflnsLimitLMax,
+        CoreItem::DiffrnReflnsLimitLMin,
+        CoreItem::DiffrnReflnsNumber,
+        CoreItem::DiffrnReflnsPointMeasuredFractionFull,
+        CoreItem::DiffrnReflnsPointMeasuredFractionMax,
+        CoreItem::DiffrnReflnsReductionProcess,
+        CoreItem::DiffrnReflnsResolutionFull,
+        CoreItem::DiffrnReflnsResolutionMax,
+        CoreItem::DiffrnReflnsThetaFull,
+        CoreItem::DiffrnReflnsThetaMax,
+        CoreItem::DiffrnReflnsThetaMin,
+        CoreItem::DiffrnSourceBeamline,
+        CoreItem::DiffrnSourceConvergenceAngle,
+        CoreItem::DiffrnSourceCurrent,
+        CoreItem::DiffrnSourceDescription,
+        CoreItem::DiffrnSourceDetails,
+        CoreItem::DiffrnSourceDevice,
+        CoreItem::DiffrnSourceEdDiffractingAreaSelection,
+        CoreItem::DiffrnSourceFacility,
+        CoreItem::DiffrnSourceMake,
+        CoreItem::DiffrnSourcePower,
+        CoreItem::DiffrnSourceSize,
+        CoreItem::DiffrnSourceTakeOffAngle,
+        CoreItem::DiffrnSourceTarget,
+        CoreItem::DiffrnSourceVoltage,
+        CoreItem::DiffrnStandardsDecayPercent,
+        CoreItem::DiffrnStandardsDecayPercentSu,
+        CoreItem::DiffrnStandardsIntervalCount,
+        CoreItem::DiffrnStandardsIntervalTime,
+        CoreItem::DiffrnStandardsNumber,
+        CoreItem::DiffrnStandardsScaleSuAverage,
+        CoreItem::DiffrnStandardsScaleSuAverageSu,
+        CoreItem::ExptlCrystalsNumber,
+        CoreItem::ExptlMethod,
+        CoreItem::ExptlMethodDetails,
+        CoreItem::ExptlSpecialDetails,
+        CoreItem::ExptlTransmissionFactorMax,
+        CoreItem::ExptlTransmissionFactorMaxSu,
+        CoreItem::ExptlTransmissionFactorMin,
+        CoreItem::ExptlTransmissionFactorMinSu,
+        CoreItem::ExptlAbsorptCoefficientMu,
+        CoreItem::ExptlAbsorptCoefficientMuSu,
+        CoreItem::ExptlAbsorptCorrectionTMax,
+        CoreItem::ExptlAbsorptCorrectionTMin,
+        CoreItem::ExptlAbsorptCorrectionType,
+        CoreItem::ExptlAbsorptProcessDetails,
+        CoreItem::ExptlAbsorptSpecialDetails,
+        CoreItem::ExptlCrystalColour,
+        CoreItem::ExptlCrystalDensityDiffrn,
+        CoreItem::ExptlCrystalDensityDiffrnSu,
+        CoreItem::ExptlCrystalDensityMeas,
+        CoreItem::ExptlCrystalDensityMeasGt,
+        CoreItem::ExptlCrystalDensityMeasLt,
+        CoreItem::ExptlCrystalDensityMeasSu,
+        CoreItem::ExptlCrystalDensityMeasTemp,
+        CoreItem::ExptlCrystalDensityMeasTempGt,
+        CoreItem::ExptlCrystalDensityMeasTempLt,
+        CoreItem::ExptlCrystalDensityMeasTempSu,
+        CoreItem::ExptlCrystalDensityMethod,
+        CoreItem::ExptlCrystalDescription,
+        CoreItem::ExptlCrystalF000,
+        CoreItem::ExptlCrystalId,
+        CoreItem::ExptlCrystalMosaicBlockSize,
+        CoreItem::ExptlCrystalMosaicBlockSizeSu,
+        CoreItem::ExptlCrystalMosaicMethod,
+        CoreItem::ExptlCrystalMosaicity,
+        CoreItem::ExptlCrystalMosaicitySu,
+        CoreItem::ExptlCrystalPreparation,
+        CoreItem::ExptlCrystalPressureHistory,
+        CoreItem::ExptlCrystalRecrystallizationMethod,
+        CoreItem::ExptlCrystalSizeLength,
+        CoreItem::ExptlCrystalSizeLengthSu,
+        CoreItem::ExptlCrystalSizeMax,
+        CoreItem::ExptlCrystalSizeMaxSu,
+        CoreItem::ExptlCrystalSizeMid,
+        CoreItem::ExptlCrystalSizeMidSu,
+        CoreItem::ExptlCrystalSizeMin,
+        CoreItem::ExptlCrystalSizeMinSu,
+        CoreItem::ExptlCrystalSizeRad,
+        CoreItem::ExptlCrystalSizeRadSu,
+        CoreItem::ExptlCrystalThermalHistory,
+        CoreItem::GeomAngleAtomSiteLabel1,
+        CoreItem::GeomAngleAtomSiteLabel2,
+        CoreItem::GeomAngleAtomSiteLabel3,
+        CoreItem::GeomAngleId,
+        CoreItem::GeomAnglePublFlag,
+        CoreItem::GeomAngleValue,
+        CoreItem::GeomAngleValueSu,
+        CoreItem::GeomBondAtomSiteLabel1,
+        CoreItem::GeomBondAtomSiteLabel2,
+        CoreItem::GeomBondDistance,
+        CoreItem::GeomBondDistanceSu,
+        CoreItem::GeomBondId,
+        CoreItem::GeomBondMultiplicity,
+        CoreItem::GeomBondPublFlag,
+        CoreItem::GeomBondValence,
+        CoreItem::GeomBondValenceSu,
+        CoreItem::GeomHbondAngleDha,
+        CoreItem::GeomHbondAngleDhaSu,
+        CoreItem::GeomHbondAtomSiteLabelA,
+        CoreItem::GeomHbondAtomSiteLabelD,
+        CoreItem::GeomHbondAtomSiteLabelH,
+        CoreItem::GeomHbondDistanceDa,
+        CoreItem::GeomHbondDistanceDaSu,
+        CoreItem::GeomHbondDistanceDh,
+        CoreItem::GeomHbondDistanceDhSu,
+        CoreItem::GeomHbondDistanceHa,
+        CoreItem::GeomHbondDistanceHaSu,
+        CoreItem::GeomHbondId,
+        CoreItem::GeomHbondPublFlag,
+        CoreItem::GeomTorsionAngle,
+        CoreItem::GeomTorsionAngleSu,
+        CoreItem::GeomTorsionAtomSiteLabel1,
+        CoreItem::GeomTorsionAtomSiteLabel2,
+        CoreItem::GeomTorsionAtomSiteLabel3,
+        CoreItem::GeomTorsionAtomSiteLabel4,
+        CoreItem::GeomTorsionId,
+        CoreItem::GeomTorsionPublFlag,
+        CoreItem::RefineDiffractionTheory,
+        CoreItem::RefineDiffractionTheoryDetails,
+        CoreItem::RefineSpecialDetails,
+        CoreItem::RefineDiffDensityMax,
+        CoreItem::RefineDiffDensityMaxSu,
+        CoreItem::RefineDiffDensityMin,
+        CoreItem::RefineDiffDensityMinSu,
+        CoreItem::RefineDiffDensityRms,
+        CoreItem::RefineDiffDensityRmsSu,
+        CoreItem::RefineDiffPotentialMax,
+        CoreItem::RefineDiffPotentialMaxSu,
+        CoreItem::RefineDiffPotentialMin,
+        CoreItem::RefineDiffPotentialMinSu,
+        CoreItem::RefineDiffPotentialRms,
+        CoreItem::RefineDiffPotentialRmsSu,
+        CoreItem::RefineLsAbsStructureDetails,
+        CoreItem::RefineLsAbsStructureFlack,
+        CoreItem::RefineLsAbsStructureFlackSu,
+        CoreItem::RefineLsAbsStructureRogers,
+        CoreItem::RefineLsAbsStructureRogersSu,
+        CoreItem::RefineLsAbsStructureZScore,
+        CoreItem::RefineLsDResHigh,
+        CoreItem::RefineLsDResLow,
+        CoreItem::RefineLsExtinctionCoef,
+        CoreItem::RefineLsExtinctionCoefSu,
+        CoreItem::RefineLsExtinctionExpression,
+        CoreItem::RefineLsExtinctionMethod,
+        CoreItem::RefineLsFCalcDetails,
+        CoreItem::RefineLsFCalcFormula,
+        CoreItem::RefineLsFCalcPrecision,
+        CoreItem::RefineLsFCalcPrecisionSu,
+        CoreItem::RefineLsGoodnessOfFitAll,
+        CoreItem::RefineLsGoodnessOfFitAllSu,
+        CoreItem::RefineLsGoodnessOfFitGt,
+        CoreItem::RefineLsGoodnessOfFitGtSu,
+        CoreItem::RefineLsGoodnessOfFitRef,
+        CoreItem::RefineLsGoodnessOfFitRefSu,
+        CoreItem::RefineLsHydrogenTreatment,
+        CoreItem::RefineLsMatrixType,
+        CoreItem::RefineLsNumberConstraints,
+        CoreItem::RefineLsNumberParameters,
+        CoreItem::RefineLsNumberReflns,
+        CoreItem::RefineLsNumberReflnsGt,
+        CoreItem::RefineLsNumberRestraints,
+        CoreItem::RefineLsRFactorAll,
+        CoreItem::RefineLsRFactorGt,
+        CoreItem::RefineLsRFsqdFactor,
+        CoreItem::RefineLsRIFactor,
+        CoreItem::RefineLsRestrainedSAll,
+        CoreItem::RefineLsRestrainedSAllSu,
+        CoreItem::RefineLsRestrainedSGt,
+        CoreItem::RefineLsRestrainedSGtSu,
+        CoreItem::RefineLsSampleShapeDetails,
+        CoreItem::RefineLsSampleShapeExpression,
+        CoreItem::RefineLsSampleThickness,
+        CoreItem::RefineLsSampleThicknessSu,
+        CoreItem::RefineLsShiftOverSuMax,
+        CoreItem::RefineLsShiftOverSuMaxLt,
+        CoreItem::RefineLsShiftOverSuMean,
+        CoreItem::RefineLsShiftOverSuMeanLt,
+        CoreItem::RefineLsStructureFactorCoef,
+        CoreItem::RefineLsWeightingDetails,
+        CoreItem::RefineLsWeightingScheme,
+        CoreItem::RefineLsWrFactorAll,
+        CoreItem::RefineLsWrFactorGt,
+        CoreItem::RefineLsWrFactorRef,
+        CoreItem::ReflnsApplyDispersionToFcalc,
+        CoreItem::ReflnsDResolutionHigh,
+        CoreItem::ReflnsDResolutionLow,
+        CoreItem::ReflnsFriedelCoverage,
+        CoreItem::ReflnsFriedelFractionFull,
+        CoreItem::ReflnsFriedelFractionMax,
+        CoreItem::ReflnsLimitHMax,
+        CoreItem::ReflnsLimitHMin,
+        CoreItem::ReflnsLimitKMax,
+        CoreItem::ReflnsLimitKMin,
+        CoreItem::ReflnsLimitLMax,
+        CoreItem::ReflnsLimitLMin,
+        CoreItem::ReflnsNumberGt,
+        CoreItem::ReflnsNumberTotal,
+        CoreItem::ReflnsSpecialDetails,
+        CoreItem::ReflnsThresholdExpression,
+        CoreItem::SpaceGroupBravaisType,
+        CoreItem::SpaceGroupCentringType,
+        CoreItem::SpaceGroupCrystalSystem,
+        CoreItem::SpaceGroupItCoordinateSystemCode,
+        CoreItem::SpaceGroupItNumber,
+        CoreItem::SpaceGroupLaueClass,
+        CoreItem::SpaceGroupMultiplicity,
+        CoreItem::SpaceGroupNameHMAlt,
+        CoreItem::SpaceGroupNameHMAltDescription,
+        CoreItem::SpaceGroupNameHMFull,
+        CoreItem::SpaceGroupNameHMRef,
+        CoreItem::SpaceGroupNameHall,
+        CoreItem::SpaceGroupNameSchoenflies,
+        CoreItem::SpaceGroupPattersonNameHM,
+        CoreItem::SpaceGroupPointGroupHM,
+        CoreItem::SpaceGroupSymopId,
+        CoreItem::SpaceGroupSymopOperationDescription,
+        CoreItem::SpaceGroupSymopOperationXyz,
+        CoreItem::SymmetryCellSetting,
+    ];
+
+    /// Canonical (DDLm) data name
+    pub const fn name(self) -> &'static str {
+        match self {
+            CoreItem::AtomSiteAdpType => "_atom_site.ADP_type",
+            CoreItem::AtomSiteAttachedHydrogens => "_atom_site.attached_hydrogens",
+            CoreItem::AtomSiteBEquivGeomMean => "_atom_site.B_equiv_geom_mean",
+            CoreItem::AtomSiteBEquivGeomMeanSu => "_atom_site.B_equiv_geom_mean_su",
+            CoreItem::AtomSiteBIsoOrEquiv => "_atom_site.B_iso_or_equiv",
+            CoreItem::AtomSiteBIsoOrEquivSu => "_atom_site.B_iso_or_equiv_su",
+            CoreItem::AtomSiteCalcAttachedAtom => "_atom_site.calc_attached_atom",
+            CoreItem::AtomSiteCalcFlag => "_atom_site.calc_flag",
+            CoreItem::AtomSiteCartnX => "_atom_site.Cartn_x",
+            CoreItem::AtomSiteCartnXSu => "_atom_site.Cartn_x_su",
+            CoreItem::AtomSiteCartnY => "_atom_site.Cartn_y",
+            CoreItem::AtomSiteCartnYSu => "_atom_site.Cartn_y_su",
+            CoreItem::AtomSiteCartnZ => "_atom_site.Cartn_z",
+            CoreItem::AtomSiteCartnZSu => "_atom_site.Cartn_z_su",
+            CoreItem::AtomSiteChemicalConnNumber => "_atom_site.chemical_conn_number",
+            CoreItem::AtomSiteConstraints => "_atom_site.constraints",
+            CoreItem::AtomSiteDescription => "_atom_site.description",
+            CoreItem::AtomSiteDisorderAssembly => "_atom_site.disorder_assembly",
+            CoreItem::AtomSiteDisorderGroup => "_atom_site.disorder_group",
+            CoreItem::AtomSiteFractSymmform => "_atom_site.fract_symmform",
+            CoreItem::AtomSiteFractX => "_atom_site.fract_x",
+            CoreItem::AtomSiteFractXSu => "_atom_site.fract_x_su",
+            CoreItem::AtomSiteFractY => "_atom_site.fract_y",
+            CoreItem::AtomSiteFractYSu => "_atom_site.fract_y_su",
+            CoreItem::AtomSiteFractZ => "_atom_site.fract_z",
+            CoreItem::AtomSiteFractZSu => "_atom_site.fract_z_su",
+            CoreItem::AtomSiteLabel => "_atom_site.label",
+            CoreItem::AtomSiteLabelComponent1 => "_atom_site.label_component_1",
+            CoreItem::AtomSiteLabelComponent2 => "_atom_site.label_component_2",
+            CoreItem::AtomSiteLabelComponent3 => "_atom_site.label_component_3",
+            CoreItem::AtomSiteLabelComponent4 => "_atom_site.label_component_4",
+            CoreItem::AtomSiteLabelComponent5 => "_atom_site.label_component_5",
+            CoreItem::AtomSiteLabelComponent6 => "_atom_site.label_component_6",
+            CoreItem::AtomSiteOccupancy => "_atom_site.occupancy",
+            CoreItem::AtomSiteOccupancySu => "_atom_site.occupancy_su",
+            CoreItem::AtomSiteRefinementFlags => "_atom_site.refinement_flags",
+            CoreItem::AtomSiteRefinementFlagsAdp => "_atom_site.refinement_flags_ADP",
+            CoreItem::AtomSiteRefinementFlagsOccupancy => "_atom_site.refinement_flags_occupancy",
+            CoreItem::AtomSiteRefinementFlagsPosn => "_atom_site.refinement_flags_posn",
+            CoreItem::AtomSiteRestraints => "_atom_site.restraints",
+            CoreItem::AtomSiteSiteSymmetryMultiplicity => "_atom_site.site_symmetry_multiplicity",
+            CoreItem::AtomSiteSiteSymmetryOrder => "_atom_site.site_symmetry_order",
+            CoreItem::AtomSiteTypeSymbol => "_atom_site.type_symbol",
+            CoreItem::AtomSiteUEquivGeomMean => "_atom_site.U_equiv_geom_mean",
+            CoreItem::AtomSiteUEquivGeomMeanSu => "_atom_site.U_equiv_geom_mean_su",
+            CoreItem::AtomSiteUIsoOrEquiv => "_atom_site.U_iso_or_equiv",
+            CoreItem::AtomSiteUIsoOrEquivSu => "_atom_site.U_iso_or_equiv_su",
+            CoreItem::AtomSiteWyckoffSymbol => "_atom_site.Wyckoff_symbol",
+            CoreItem::AtomSiteAnisoB11 => "_atom_site_aniso.B_11",
+            CoreItem::AtomSiteAnisoB11Su => "_atom_site_aniso.B_11_su",
+            CoreItem::AtomSiteAnisoB12 => "_atom_site_aniso.B_12",
+            CoreItem::AtomSiteAnisoB12Su => "_atom_site_aniso.B_12_su",
+            CoreItem::AtomSiteAnisoB13 => "_atom_site_aniso.B_13",
+            CoreItem::AtomSiteAnisoB13Su => "_atom_site_aniso.B_13_su",
+            CoreItem::AtomSiteAnisoB22 => "_atom_site_aniso.B_22",
+            CoreItem::AtomSiteAnisoB22Su => "_atom_site_aniso.B_22_su",
+            CoreItem::AtomSiteAnisoB23 => "_atom_site_aniso.B_23",
+            CoreItem::AtomSiteAnisoB23Su => "_atom_site_aniso.B_23_su",
+            CoreItem::AtomSiteAnisoB33 => "_atom_site_aniso.B_33",
+            CoreItem::AtomSiteAnisoB33Su => "_atom_site_aniso.B_33_su",
+            CoreItem::AtomSiteAnisoBeta11 => "_atom_site_aniso.beta_11",
+            CoreItem::AtomSiteAnisoBeta11Su => "_atom_site_aniso.beta_11_su",
+            CoreItem::AtomSiteAnisoBeta12 => "_atom_site_aniso.beta_12",
+            CoreItem::AtomSiteAnisoBeta12Su => "_atom_site_aniso.beta_12_su",
+            CoreItem::AtomSiteAnisoBeta13 => "_atom_site_aniso.beta_13",
+            CoreItem::AtomSiteAnisoBeta13Su => "_atom_site_aniso.beta_13_su",
+            CoreItem::AtomSiteAnisoBeta22 => "_atom_site_aniso.beta_22",
+            CoreItem::AtomSiteAnisoBeta22Su => "_atom_site_aniso.beta_22_su",
+            CoreItem::AtomSiteAnisoBeta23 => "_atom_site_aniso.beta_23",
+            CoreItem::AtomSiteAnisoBeta23Su => "_atom_site_aniso.beta_23_su",
+            CoreItem::AtomSiteAnisoBeta33 => "_atom_site_aniso.beta_33",
+            CoreItem::AtomSiteAnisoBeta33Su => "_atom_site_aniso.beta_33_su",
+            CoreItem::AtomSiteAnisoLabel => "_atom_site_aniso.label",
+            CoreItem::AtomSiteAnisoRatio => "_atom_site_aniso.ratio",
+            CoreItem::AtomSiteAnisoSymmform => "_atom_site_aniso.symmform",
+            CoreItem::AtomSiteAnisoTypeSymbol => "_atom_site_aniso.type_symbol",
+            CoreItem::AtomSiteAnisoU11 => "_atom_site_aniso.U_11",
+            CoreItem::AtomSiteAnisoU11Su => "_atom_site_aniso.U_11_su",
+            CoreItem::AtomSiteAnisoU12 => "_atom_site_aniso.U_12",
+            CoreItem::AtomSiteAnisoU12Su => "_atom_site_aniso.U_12_su",
+            CoreItem::AtomSiteAnisoU13 => "_atom_site_aniso.U_13",
+            CoreItem::AtomSiteAnisoU13Su => "_atom_site_aniso.U_13_su",
+            CoreItem::AtomSiteAnisoU22 => "_atom_site_aniso.U_22",
+            CoreItem::AtomSiteAnisoU22Su => "_atom_site_aniso.U_22_su",
+            CoreItem::AtomSiteAnisoU23 => "_atom_site_aniso.U_23",
+            CoreItem::AtomSiteAnisoU23Su => "_atom_site_aniso.U_23_su",
+            CoreItem::AtomSiteAnisoU33 => "_atom_site_aniso.U_33",
+            CoreItem::AtomSiteAnisoU33Su => "_atom_site_aniso.U_33_su",
+            CoreItem::AtomSitesSolutionHydrogens => "_atom_sites.solution_hydrogens",
+            CoreItem::AtomSitesSolutionPrimary => "_atom_sites.solution_primary",
+            CoreItem::AtomSitesSolutionSecondary => "_atom_sites.solution_secondary",
+            CoreItem::AtomSitesSpecialDetails => "_atom_sites.special_details",
+            CoreItem::AtomTypeAnalyticalMassPercent => "_atom_type.analytical_mass_percent",
+            CoreItem::AtomTypeAnalyticalMassPercentSu => "_atom_type.analytical_mass_percent_su",
+            CoreItem::AtomTypeAtomicMass => "_atom_type.atomic_mass",
+            CoreItem::AtomTypeAtomicNumber => "_atom_type.atomic_number",
+            CoreItem::AtomTypeDescription => "_atom_type.description",
+            CoreItem::AtomTypeDisplayColour => "_atom_type.display_colour",
+            CoreItem::AtomTypeElectronCount => "_atom_type.electron_count",
+            CoreItem::AtomTypeElementSymbol => "_atom_type.element_symbol",
+            CoreItem::AtomTypeMassNumber => "_atom_type.mass_number",
+            CoreItem::AtomTypeNumberInCell => "_atom_type.number_in_cell",
+            CoreItem::AtomTypeOxidationNumber => "_atom_type.oxidation_number",
+            CoreItem::AtomTypeRadiusBond => "_atom_type.radius_bond",
+            CoreItem::AtomTypeRadiusContact => "_atom_type.radius_contact",
+            CoreItem::AtomTypeSymbol => "_atom_type.symbol",
+            CoreItem::AuditBlockCode => "_audit.block_code",
+            CoreItem::AuditBlockDoi => "_audit.block_DOI",
+            CoreItem::AuditCreationDate => "_audit.creation_date",
+            CoreItem::AuditCreationMethod => "_audit.creation_method",
+            CoreItem::AuditSchema => "_audit.schema",
+            CoreItem::AuditUpdateRecord => "_audit.update_record",
+            CoreItem::CellAngleAlpha => "_cell.angle_alpha",
+            CoreItem::CellAngleAlphaSu => "_cell.angle_alpha_su",
+            CoreItem::CellAngleBeta => "_cell.angle_beta",
+            CoreItem::CellAngleBetaSu => "_cell.angle_beta_su",
+            CoreItem::CellAngleGamma => "_cell.angle_gamma",
+            CoreItem::CellAngleGammaSu => "_cell.angle_gamma_su",
+            CoreItem::CellAtomicMass => "_cell.atomic_mass",
+            CoreItem::CellDiffrnId => "_cell.diffrn_id",
+            CoreItem::CellFormulaUnitsZ => "_cell.formula_units_Z",
+            CoreItem::CellFormulaUnitsZDetails => "_cell.formula_units_Z_details",
+            CoreItem::CellLengthA => "_cell.length_a",
+            CoreItem::CellLengthASu => "_cell.length_a_su",
+            CoreItem::CellLengthB => "_cell.length_b",
+            CoreItem::CellLengthBSu => "_cell.length_b_su",
+            CoreItem::CellLengthC => "_cell.length_c",
+            CoreItem::CellLengthCSu => "_cell.length_c_su",
+            CoreItem::CellReciprocalAngleAlpha => "_cell.reciprocal_angle_alpha",
+            CoreItem::CellReciprocalAngleAlphaSu => "_cell.reciprocal_angle_alpha_su",
+            CoreItem::CellReciprocalAngleBeta => "_cell.reciprocal_angle_beta",
+            CoreItem::CellReciprocalAngleBetaSu => "_cell.reciprocal_angle_beta_su",
+            CoreItem::CellReciprocalAngleGamma => "_cell.reciprocal_angle_gamma",
+            CoreItem::CellReciprocalAngleGammaSu => "_cell.reciprocal_angle_gamma_su",
+            CoreItem::CellReciprocalLengthA => "_cell.reciprocal_length_a",
+            CoreItem::CellReciprocalLengthASu => "_cell.reciprocal_length_a_su",
+            CoreItem::CellReciprocalLengthB => "_cell.reciprocal_length_b",
+            CoreItem::CellReciprocalLengthBSu => "_cell.reciprocal_length_b_su",
+            CoreItem::CellReciprocalLengthC => "_cell.reciprocal_length_c",
+            CoreItem::CellReciprocalLengthCSu => "_cell.reciprocal_length_c_su",
+            CoreItem::CellSpecialDetails => "_cell.special_details",
+            CoreItem::CellVolume => "_cell.volume",
+            CoreItem::CellVolumeSu => "_cell.volume_su",
+            CoreItem::CellMeasurementConditionId => "_cell_measurement.condition_id",
+            CoreItem::CellMeasurementDiffrnId => "_cell_measurement.diffrn_id",
+            CoreItem::CellMeasurementPressure => "_cell_measurement.pressure",
+            CoreItem::CellMeasurementPressureSu => "_cell_measurement.pressure_su",
+            CoreItem::CellMeasurementRadiation => "_cell_measurement.radiation",
+            CoreItem::CellMeasurementReflnsUsed => "_cell_measurement.reflns_used",
+            CoreItem::CellMeasurementTemperature => "_cell_measurement.temperature",
+            CoreItem::CellMeasurementTemperatureSu => "_cell_measurement.temperature_su",
+            CoreItem::CellMeasurementThetaMax => "_cell_measurement.theta_max",
+            CoreItem::CellMeasurementThetaMin => "_cell_measurement.theta_min",
+            CoreItem::CellMeasurementWavelength => "_cell_measurement.wavelength",
+            CoreItem::CellMeasurementWavelengthSu => "_cell_measurement.wavelength_su",
+            CoreItem::ChemicalAbsoluteConfiguration => "_chemical.absolute_configuration",
+            CoreItem::ChemicalCompoundSource => "_chemical.compound_source",
+            CoreItem::ChemicalEnantioexcessBulk => "_chemical.enantioexcess_bulk",
+            CoreItem::ChemicalEnantioexcessBulkSu => "_chemical.enantioexcess_bulk_su",
+            CoreItem::ChemicalEnantioexcessBulkTechnique => "_chemical.enantioexcess_bulk_technique",
+            CoreItem::ChemicalEnantioexcessCrystal => "_chemical.enantioexcess_crystal",
+            CoreItem::ChemicalEnantioexcessCrystalSu => "_chemical.enantioexcess_crystal_su",
+            CoreItem::ChemicalEnantioexcessCrystalTechnique => "_chemical.enantioexcess_crystal_technique",
+            CoreItem::ChemicalIdentifierInchi => "_chemical.identifier_InChI",
+            CoreItem::ChemicalIdentifierInchiKey => "_chemical.identifier_InChI_key",
+            CoreItem::ChemicalIdentifierInchiVersion => "_chemical.identifier_InChI_version",
+            CoreItem::ChemicalMeltingPoint => "_chemical.melting_point",
+            CoreItem::ChemicalMeltingPointGt => "_chemical.melting_point_gt",
+            CoreItem::ChemicalMeltingPointLt => "_chemical.melting_point_lt",
+            CoreItem::ChemicalMeltingPointSu => "_chemical.melting_point_su",
+            CoreItem::ChemicalNameCommon => "_chemical.name_common",
+            CoreItem::ChemicalNameMineral => "_chemical.name_mineral",
+            CoreItem::ChemicalNameStructureType => "_chemical.name_structure_type",
+            CoreItem::ChemicalNameSystematic => "_chemical.name_systematic",
+            CoreItem::ChemicalOpticalRotation => "_chemical.optical_rotation",
+            CoreItem::ChemicalPropertiesBiological => "_chemical.properties_biological",
+            CoreItem::ChemicalPropertiesPhysical => "_chemical.properties_physical",
+            CoreItem::ChemicalTemperatureDecomposition => "_chemical.temperature_decomposition",
+            CoreItem::ChemicalTemperatureDecompositionGt => "_chemical.temperature_decomposition_gt",
+            CoreItem::ChemicalTemperatureDecompositionLt => "_chemical.temperature_decomposition_lt",
+            CoreItem::ChemicalTemperatureDecompositionSu => "_chemical.temperature_decomposition_su",
+            CoreItem::ChemicalTemperatureSublimation => "_chemical.temperature_sublimation",
+            CoreItem::ChemicalTemperatureSublimationGt => "_chemical.temperature_sublimation_gt",
+            CoreItem::ChemicalTemperatureSublimationLt => "_chemical.temperature_sublimation_lt",
+            CoreItem::ChemicalTemperatureSublimationSu => "_chemical.temperature_sublimation_su",
+            CoreItem::ChemicalFormulaAnalytical => "_chemical_formula.analytical",
+            CoreItem::ChemicalFormulaIupac => "_chemical_formula.IUPAC",
+            CoreItem::ChemicalFormulaMoiety => "_chemical_formula.moiety",
+            CoreItem::ChemicalFormulaStructural => "_chemical_formula.structural",
+            CoreItem::ChemicalFormulaSum => "_chemical_formula.sum",
+            CoreItem::ChemicalFormulaWeight => "_chemical_formula.weight",
+            CoreItem::ChemicalFormulaWeightMeas => "_chemical_formula.weight_meas",
+            CoreItem::ChemicalFormulaWeightMeasSu => "_chemical_formula.weight_meas_su",
+            CoreItem::ComputingCellRefinement => "_computing.cell_refinement",
+            CoreItem::ComputingDiffrnCollection => "_computing.diffrn_collection",
+            CoreItem::ComputingDiffrnReduction => "_computing.diffrn_reduction",
+            CoreItem::ComputingMolecularGraphics => "_computing.molecular_graphics",
+            CoreItem::ComputingPublicationMaterial => "_computing.publication_material",
+            CoreItem::ComputingSampleTracking => "_computing.sample_tracking",
+            CoreItem::ComputingStructureRefinement => "_computing.structure_refinement",
+            CoreItem::ComputingStructureSolution => "_computing.structure_solution",
+            CoreItem::DiffrnAmbientEnvironment => "_diffrn.ambient_environment",
+            CoreItem::DiffrnAmbientPressure => "_diffrn.ambient_pressure",
+            CoreItem::DiffrnAmbientPressureGt => "_diffrn.ambient_pressure_gt",
+            CoreItem::DiffrnAmbientPressureLt => "_diffrn.ambient_pressure_lt",
+            CoreItem::DiffrnAmbientPressureSu => "_diffrn.ambient_pressure_su",
+            CoreItem::DiffrnAmbientTemperature => "_diffrn.ambient_temperature",
+            CoreItem::DiffrnAmbientTemperatureDetails => "_diffrn.ambient_temperature_details",
+            CoreItem::DiffrnAmbientTemperatureGt => "_diffrn.ambient_temperature_gt",
+            CoreItem::DiffrnAmbientTemperatureLt => "_diffrn.ambient_temperature_lt",
+            CoreItem::DiffrnAmbientTemperatureSu => "_diffrn.ambient_temperature_su",
+            CoreItem::DiffrnCrystalId => "_diffrn.crystal_id",
+            CoreItem::DiffrnCrystalSupport => "_diffrn.crystal_support",
+            CoreItem::DiffrnCrystalTreatment => "_diffrn.crystal_treatment",
+            CoreItem::DiffrnFluxDensity => "_diffrn.flux_density",
+            CoreItem::DiffrnId => "_diffrn.id",
+            CoreItem::DiffrnMeasuredFractionThetaFull => "_diffrn.measured_fraction_theta_full",
+            CoreItem::DiffrnMeasuredFractionThetaMax => "_diffrn.measured_fraction_theta_max",
+            CoreItem::DiffrnPrecessionSemiAngle => "_diffrn.precession_semi_angle",
+            CoreItem::DiffrnPrecessionSemiAngleSu => "_diffrn.precession_semi_angle_su",
+            CoreItem::DiffrnSpecialDetails => "_diffrn.special_details",
+            CoreItem::DiffrnSymmetryDescription => "_diffrn.symmetry_description",
+            CoreItem::DiffrnTotalDose => "_diffrn.total_dose",
+            CoreItem::DiffrnTotalDoseSu => "_diffrn.total_dose_su",
+            CoreItem::DiffrnTotalExposureTime => "_diffrn.total_exposure_time",
+            CoreItem::DiffrnDetectorAreaResolMean => "_diffrn_detector.area_resol_mean",
+            CoreItem::DiffrnDetectorDescription => "_diffrn_detector.description",
+            CoreItem::DiffrnDetectorDetails => "_diffrn_detector.details",
+            CoreItem::DiffrnDetectorDtime => "_diffrn_detector.dtime",
+            CoreItem::DiffrnDetectorMake => "_diffrn_detector.make",
+            CoreItem::DiffrnMeasurementDetails => "_diffrn_measurement.details",
+            CoreItem::DiffrnMeasurementDeviceClass => "_diffrn_measurement.device_class",
+            CoreItem::DiffrnMeasurementDeviceDetails => "_diffrn_measurement.device_details",
+            CoreItem::DiffrnMeasurementDeviceMake => "_diffrn_measurement.device_make",
+            CoreItem::DiffrnMeasurementMethod => "_diffrn_measurement.method",
+            CoreItem::DiffrnMeasurementMethodPrecession => "_diffrn_measurement.method_precession",
+            CoreItem::DiffrnMeasurementRotationMode => "_diffrn_measurement.rotation_mode",
+            CoreItem::DiffrnMeasurementSampleTracking => "_diffrn_measurement.sample_tracking",
+            CoreItem::DiffrnMeasurementSampleTrackingMethod => "_diffrn_measurement.sample_tracking_method",
+            CoreItem::DiffrnMeasurementSpecimenAttachmentType => "_diffrn_measurement.specimen_attachment_type",
+            CoreItem::DiffrnMeasurementSpecimenSupport => "_diffrn_measurement.specimen_support",
+            CoreItem::DiffrnRadiationCollimation => "_diffrn_radiation.collimation",
+            CoreItem::DiffrnRadiationFilterEdge => "_diffrn_radiation.filter_edge",
+            CoreItem::DiffrnRadiationIlluminationMode => "_diffrn_radiation.illumination_mode",
+            CoreItem::DiffrnRadiationInhomogeneity => "_diffrn_radiation.inhomogeneity",
+            CoreItem::DiffrnRadiationMonochromator => "_diffrn_radiation.monochromator",
+            CoreItem::DiffrnRadiationPolarisnNorm => "_diffrn_radiation.polarisn_norm",
+            CoreItem::DiffrnRadiationPolarisnRatio => "_diffrn_radiation.polarisn_ratio",
+            CoreItem::DiffrnRadiationProbe => "_diffrn_radiation.probe",
+            CoreItem::DiffrnRadiationType => "_diffrn_radiation.type",
+            CoreItem::DiffrnRadiationXraySymbol => "_diffrn_radiation.xray_symbol",
+            CoreItem::DiffrnRadiationWavelengthDetails => "_diffrn_radiation_wavelength.details",
+            CoreItem::DiffrnRadiationWavelengthDetermination => "_diffrn_radiation_wavelength.determination",
+            CoreItem::DiffrnRadiationWavelengthId => "_diffrn_radiation_wavelength.id",
+            CoreItem::DiffrnRadiationWavelengthType => "_diffrn_radiation_wavelength.type",
+            CoreItem::DiffrnRadiationWavelengthValue => "_diffrn_radiation_wavelength.value",
+            CoreItem::DiffrnRadiationWavelengthValueSu => "_diffrn_radiation_wavelength.value_su",
+            CoreItem::DiffrnRadiationWavelengthWt => "_diffrn_radiation_wavelength.wt",
+            CoreItem::DiffrnRadiationWavelengthXraySymbol => "_diffrn_radiation_wavelength.xray_symbol",
+            CoreItem::DiffrnReflnsAvREquivalents => "_diffrn_reflns.av_R_equivalents",
+            CoreItem::DiffrnReflnsAvSunetiOverNeti => "_diffrn_reflns.av_sunetI_over_netI",
+            CoreItem::DiffrnReflnsLaueMeasuredFractionFull => "_diffrn_reflns.Laue_measured_fraction_full",
+            CoreItem::DiffrnReflnsLaueMeasuredFractionMax => "_diffrn_reflns.Laue_measured_fraction_max",
+            CoreItem::DiffrnReflnsLimitHMax => "_diffrn_reflns.limit_h_max",
+            CoreItem::DiffrnReflnsLimitHMin => "_diffrn_reflns.limit_h_min",
+            CoreItem::DiffrnReflnsLimitKMax => "_diffrn_reflns.limit_k_max",
+            CoreItem::DiffrnReflnsLimitKMin => "_diffrn_reflns.limit_k_min",
+            CoreItem::DiffrnReflnsLimitLMax => "_diffrn_reflns.limit_l_max",
+            CoreItem::DiffrnReflnsLimitLMin => "_diffrn_reflns.limit_l_min",
+            CoreItem::DiffrnReflnsNumber => "_diffrn_reflns.number",
+            CoreItem::DiffrnReflnsPointMeasuredFractionFull => "_diffrn_reflns.point_measured_fraction_full",
+            CoreItem::DiffrnReflnsPointMeasuredFractionMax => "_diffrn_reflns.point_measured_fraction_max",
+            CoreItem::DiffrnReflnsReductionProcess => "_diffrn_reflns.reduction_process",
+            CoreItem::DiffrnReflnsResolutionFull => "_diffrn_reflns.resolution_full",
+            CoreItem::DiffrnReflnsResolutionMax => "_diffrn_reflns.resolution_max",
+            CoreItem::DiffrnReflnsThetaFull => "_diffrn_reflns.theta_full",
+            CoreItem::DiffrnReflnsThetaMax => "_diffrn_reflns.theta_max",
+            CoreItem::DiffrnReflnsThetaMin => "_diffrn_reflns.theta_min",
+            CoreItem::DiffrnSourceBeamline => "_diffrn_source.beamline",
+            CoreItem::DiffrnSourceConvergenceAngle => "_diffrn_source.convergence_angle",
+            CoreItem::DiffrnSourceCurrent => "_diffrn_source.current",
+            CoreItem::DiffrnSourceDescription => "_diffrn_source.description",
+            CoreItem::DiffrnSourceDetails => "_diffrn_source.details",
+            CoreItem::DiffrnSourceDevice => "_diffrn_source.device",
+            CoreItem::DiffrnSourceEdDiffractingAreaSelection => "_diffrn_source.ed_diffracting_area_selection",
+            CoreItem::DiffrnSourceFacility => "_diffrn_source.facility",
+            CoreItem::DiffrnSourceMake => "_diffrn_source.make",
+            CoreItem::DiffrnSourcePower => "_diffrn_source.power",
+            CoreItem::DiffrnSourceSize => "_diffrn_source.size",
+            CoreItem::DiffrnSourceTakeOffAngle => "_diffrn_source.take_off_angle",
+            CoreItem::DiffrnSourceTarget => "_diffrn_source.target",
+            CoreItem::DiffrnSourceVoltage => "_diffrn_source.voltage",
+            CoreItem::DiffrnStandardsDecayPercent => "_diffrn_standards.decay_percent",
+            CoreItem::DiffrnStandardsDecayPercentSu => "_diffrn_standards.decay_percent_su",
+            CoreItem::DiffrnStandardsIntervalCount => "_diffrn_standards.interval_count",
+            CoreItem::DiffrnStandardsIntervalTime => "_diffrn_standards.interval_time",
+            CoreItem::DiffrnStandardsNumber => "_diffrn_standards.number",
+            CoreItem::DiffrnStandardsScaleSuAverage => "_diffrn_standards.scale_su_average",
+            CoreItem::DiffrnStandardsScaleSuAverageSu => "_diffrn_standards.scale_su_average_su",
+            CoreItem::ExptlCrystalsNumber => "_exptl.crystals_number",
+            CoreItem::ExptlMethod => "_exptl.method",
+            CoreItem::ExptlMethodDetails => "_exptl.method_details",
+            CoreItem::ExptlSpecialDetails => "_exptl.special_details",
+            CoreItem::ExptlTransmissionFactorMax => "_exptl.transmission_factor_max",
+            CoreItem::ExptlTransmissionFactorMaxSu => "_exptl.transmission_factor_max_su",
+            CoreItem::ExptlTransmissionFactorMin => "_exptl.transmission_factor_min",
+            CoreItem::ExptlTransmissionFactorMinSu => "_exptl.transmission_factor_min_su",
+            CoreItem::ExptlAbsorptCoefficientMu => "_exptl_absorpt.coefficient_mu",
+            CoreItem::ExptlAbsorptCoefficientMuSu => "_exptl_absorpt.coefficient_mu_su",
+            CoreItem::ExptlAbsorptCorrectionTMax => "_exptl_absorpt.correction_T_max",
+            CoreItem::ExptlAbsorptCorrectionTMin => "_exptl_absorpt.correction_T_min",
+            CoreItem::ExptlAbsorptCorrectionType => "_exptl_absorpt.correction_type",
+            CoreItem::ExptlAbsorptProcessDetails => "_exptl_absorpt.process_details",
+            CoreItem::ExptlAbsorptSpecialDetails => "_exptl_absorpt.special_details",
+            CoreItem::ExptlCrystalColour => "_exptl_crystal.colour",
+            CoreItem::ExptlCrystalDensityDiffrn => "_exptl_crystal.density_diffrn",
+            CoreItem::ExptlCrystalDensityDiffrnSu => "_exptl_crystal.density_diffrn_su",
+            CoreItem::ExptlCrystalDensityMeas => "_exptl_crystal.density_meas",
+            CoreItem::ExptlCrystalDensityMeasGt => "_exptl_crystal.density_meas_gt",
+            CoreItem::ExptlCrystalDensityMeasLt => "_exptl_crystal.density_meas_lt",
+            CoreItem::ExptlCrystalDensityMeasSu => "_exptl_crystal.density_meas_su",
+            CoreItem::ExptlCrystalDensityMeasTemp => "_exptl_crystal.density_meas_temp",
+            CoreItem::ExptlCrystalDensityMeasTempGt => "_exptl_crystal.density_meas_temp_gt",
+            CoreItem::ExptlCrystalDensityMeasTempLt => "_exptl_crystal.density_meas_temp_lt",
+            CoreItem::ExptlCrystalDensityMeasTempSu => "_exptl_crystal.density_meas_temp_su",
+            CoreItem::ExptlCrystalDensityMethod => "_exptl_crystal.density_method",
+            CoreItem::ExptlCrystalDescription => "_exptl_crystal.description",
+            CoreItem::ExptlCrystalF000 => "_exptl_crystal.F_000",
+            CoreItem::ExptlCrystalId => "_exptl_crystal.id",
+            CoreItem::ExptlCrystalMosaicBlockSize => "_exptl_crystal.mosaic_block_size",
+            CoreItem::ExptlCrystalMosaicBlockSizeSu => "_exptl_crystal.mosaic_block_size_su",
+            CoreItem::ExptlCrystalMosaicMethod => "_exptl_crystal.mosaic_method",
+            CoreItem::ExptlCrystalMosaicity => "_exptl_crystal.mosaicity",
+            CoreItem::ExptlCrystalMosaicitySu => "_exptl_crystal.mosaicity_su",
+            CoreItem::ExptlCrystalPreparation => "_exptl_crystal.preparation",
+            CoreItem::ExptlCrystalPressureHistory => "_exptl_crystal.pressure_history",
+            CoreItem::ExptlCrystalRecrystallizationMethod => "_exptl_crystal.recrystallization_method",
+            CoreItem::ExptlCrystalSizeLength => "_exptl_crystal.size_length",
+            CoreItem::ExptlCrystalSizeLengthSu => "_exptl_crystal.size_length_su",
+            CoreItem::ExptlCrystalSizeMax => "_exptl_crystal.size_max",
+            CoreItem::ExptlCrystalSizeMaxSu => "_exptl_crystal.size_max_su",
+            CoreItem::ExptlCrystalSizeMid => "_exptl_crystal.size_mid",
+            CoreItem::ExptlCrystalSizeMidSu => "_exptl_crystal.size_mid_su",
+            CoreItem::ExptlCrystalSizeMin => "_exptl_crystal.size_min",
+            CoreItem::ExptlCrystalSizeMinSu => "_exptl_crystal.size_min_su",
+            CoreItem::ExptlCrystalSizeRad => "_exptl_crystal.size_rad",
+            CoreItem::ExptlCrystalSizeRadSu => "_exptl_crystal.size_rad_su",
+            CoreItem::ExptlCrystalThermalHistory => "_exptl_crystal.thermal_history",
+            CoreItem::GeomAngleAtomSiteLabel1 => "_geom_angle.atom_site_label_1",
+            CoreItem::GeomAngleAtomSiteLabel2 => "_geom_angle.atom_site_label_2",
+            CoreItem::GeomAngleAtomSiteLabel3 => "_geom_angle.atom_site_label_3",
+            CoreItem::GeomAngleId => "_geom_angle.id",
+            CoreItem::GeomAnglePublFlag => "_geom_angle.publ_flag",
+            CoreItem::GeomAngleValue => "_geom_angle.value",
+            CoreItem::GeomAngleValueSu => "_geom_angle.value_su",
+            CoreItem::GeomBondAtomSiteLabel1 => "_geom_bond.atom_site_label_1",
+            CoreItem::GeomBondAtomSiteLabel2 => "_geom_bond.atom_site_label_2",
+            CoreItem::GeomBondDistance => "_geom_bond.distance",
+            CoreItem::GeomBondDistanceSu => "_geom_bond.distance_su",
+            CoreItem::GeomBondId => "_geom_bond.id",
+            CoreItem::GeomBondMultiplicity => "_geom_bond.multiplicity",
+            CoreItem::GeomBondPublFlag => "_geom_bond.publ_flag",
+            CoreItem::GeomBondValence => "_geom_bond.valence",
+            CoreItem::GeomBondValenceSu => "_geom_bond.valence_su",
+            CoreItem::GeomHbondAngleDha => "_geom_hbond.angle_DHA",
+            CoreItem::GeomHbondAngleDhaSu => "_geom_hbond.angle_DHA_su",
+            CoreItem::GeomHbondAtomSiteLabelA => "_geom_hbond.atom_site_label_A",
+            CoreItem::GeomHbondAtomSiteLabelD => "_geom_hbond.atom_site_label_D",
+            CoreItem::GeomHbondAtomSiteLabelH => "_geom_hbond.atom_site_label_H",
+            CoreItem::GeomHbondDistanceDa => "_geom_hbond.distance_DA",
+            CoreItem::GeomHbondDistanceDaSu => "_geom_hbond.distance_DA_su",
+            CoreItem::GeomHbondDistanceDh => "_geom_hbond.distance_DH",
+            CoreItem::GeomHbondDistanceDhSu => "_geom_hbond.distance_DH_su",
+            CoreItem::GeomHbondDistanceHa => "_geom_hbond.distance_HA",
+            CoreItem::GeomHbondDistanceHaSu => "_geom_hbond.distance_HA_su",
+            CoreItem::GeomHbondId => "_geom_hbond.id",
+            CoreItem::GeomHbondPublFlag => "_geom_hbond.publ_flag",
+            CoreItem::GeomTorsionAngle => "_geom_torsion.angle",
+            CoreItem::GeomTorsionAngleSu => "_geom_torsion.angle_su",
+            CoreItem::GeomTorsionAtomSiteLabel1 => "_geom_torsion.atom_site_label_1",
+            CoreItem::GeomTorsionAtomSiteLabel2 => "_geom_torsion.atom_site_label_2",
+            CoreItem::GeomTorsionAtomSiteLabel3 => "_geom_torsion.atom_site_label_3",
+            CoreItem::GeomTorsionAtomSiteLabel4 => "_geom_torsion.atom_site_label_4",
+            CoreItem::GeomTorsionId => "_geom_torsion.id",
+            CoreItem::GeomTorsionPublFlag => "_geom_torsion.publ_flag",
+            CoreItem::RefineDiffractionTheory => "_refine.diffraction_theory",
+            CoreItem::RefineDiffractionTheoryDetails => "_refine.diffraction_theory_details",
+            CoreItem::RefineSpecialDetails => "_refine.special_details",
+            CoreItem::RefineDiffDensityMax => "_refine_diff.density_max",
+            CoreItem::RefineDiffDensityMaxSu => "_refine_diff.density_max_su",
+            CoreItem::RefineDiffDensityMin => "_refine_diff.density_min",
+            CoreItem::RefineDiffDensityMinSu => "_refine_diff.density_min_su",
+            CoreItem::RefineDiffDensityRms => "_refine_diff.density_RMS",
+            CoreItem::RefineDiffDensityRmsSu => "_refine_diff.density_RMS_su",
+            CoreItem::RefineDiffPotentialMax => "_refine_diff.potential_max",
+            CoreItem::RefineDiffPotentialMaxSu => "_refine_diff.potential_max_su",
+            CoreItem::RefineDiffPotentialMin => "_refine_diff.potential_min",
+            CoreItem::RefineDiffPotentialMinSu => "_refine_diff.potential_min_su",
+            CoreItem::RefineDiffPotentialRms => "_refine_diff.potential_RMS",
+            CoreItem::RefineDiffPotentialRmsSu => "_refine_diff.potential_RMS_su",
+            CoreItem::RefineLsAbsStructureDetails => "_refine_ls.abs_structure_details",
+            CoreItem::RefineLsAbsStructureFlack => "_refine_ls.abs_structure_Flack",
+            CoreItem::RefineLsAbsStructureFlackSu => "_refine_ls.abs_structure_Flack_su",
+            CoreItem::RefineLsAbsStructureRogers => "_refine_ls.abs_structure_Rogers",
+            CoreItem::RefineLsAbsStructureRogersSu => "_refine_ls.abs_structure_Rogers_su",
+            CoreItem::RefineLsAbsStructureZScore => "_refine_ls.abs_structure_z-score",
+            CoreItem::RefineLsDResHigh => "_refine_ls.d_res_high",
+            CoreItem::RefineLsDResLow => "_refine_ls.d_res_low",
+            CoreItem::RefineLsExtinctionCoef => "_refine_ls.extinction_coef",
+            CoreItem::RefineLsExtinctionCoefSu => "_refine_ls.extinction_coef_su",
+            CoreItem::RefineLsExtinctionExpression => "_refine_ls.extinction_expression",
+            CoreItem::RefineLsExtinctionMethod => "_refine_ls.extinction_method",
+            CoreItem::RefineLsFCalcDetails => "_refine_ls.F_calc_details",
+            CoreItem::RefineLsFCalcFormula => "_refine_ls.F_calc_formula",
+            CoreItem::RefineLsFCalcPrecision => "_refine_ls.F_calc_precision",
+            CoreItem::RefineLsFCalcPrecisionSu => "_refine_ls.F_calc_precision_su",
+            CoreItem::RefineLsGoodnessOfFitAll => "_refine_ls.goodness_of_fit_all",
+            CoreItem::RefineLsGoodnessOfFitAllSu => "_refine_ls.goodness_of_fit_all_su",
+            CoreItem::RefineLsGoodnessOfFitGt => "_refine_ls.goodness_of_fit_gt",
+            CoreItem::RefineLsGoodnessOfFitGtSu => "_refine_ls.goodness_of_fit_gt_su",
+            CoreItem::RefineLsGoodnessOfFitRef => "_refine_ls.goodness_of_fit_ref",
+            CoreItem::RefineLsGoodnessOfFitRefSu => "_refine_ls.goodness_of_fit_ref_su",
+            CoreItem::RefineLsHydrogenTreatment => "_refine_ls.hydrogen_treatment",
+            CoreItem::RefineLsMatrixType => "_refine_ls.matrix_type",
+            CoreItem::RefineLsNumberConstraints => "_refine_ls.number_constraints",
+            CoreItem::RefineLsNumberParameters => "_refine_ls.number_parameters",
+            CoreItem::RefineLsNumberReflns => "_refine_ls.number_reflns",
+            CoreItem::RefineLsNumberReflnsGt => "_refine_ls.number_reflns_gt",
+            CoreItem::RefineLsNumberRestraints => "_refine_ls.number_restraints",
+            CoreItem::RefineLsRFactorAll => "_refine_ls.R_factor_all",
+            CoreItem::RefineLsRFactorGt => "_refine_ls.R_factor_gt",
+            CoreItem::RefineLsRFsqdFactor => "_refine_ls.R_Fsqd_factor",
+            CoreItem::RefineLsRIFactor => "_refine_ls.R_I_factor",
+            CoreItem::RefineLsRestrainedSAll => "_refine_ls.restrained_S_all",
+            CoreItem::RefineLsRestrainedSAllSu => "_refine_ls.restrained_S_all_su",
+            CoreItem::RefineLsRestrainedSGt => "_refine_ls.restrained_S_gt",
+            CoreItem::RefineLsRestrainedSGtSu => "_refine_ls.restrained_S_gt_su",
+            CoreItem::RefineLsSampleShapeDetails => "_refine_ls.sample_shape_details",
+            CoreItem::RefineLsSampleShapeExpression => "_refine_ls.sample_shape_expression",
+            CoreItem::RefineLsSampleThickness => "_refine_ls.sample_thickness",
+            CoreItem::RefineLsSampleThicknessSu => "_refine_ls.sample_thickness_su",
+            CoreItem::RefineLsShiftOverSuMax => "_refine_ls.shift_over_su_max",
+            CoreItem::RefineLsShiftOverSuMaxLt => "_refine_ls.shift_over_su_max_lt",
+            CoreItem::RefineLsShiftOverSuMean => "_refine_ls.shift_over_su_mean",
+            CoreItem::RefineLsShiftOverSuMeanLt => "_refine_ls.shift_over_su_mean_lt",
+            CoreItem::RefineLsStructureFactorCoef => "_refine_ls.structure_factor_coef",
+            CoreItem::RefineLsWeightingDetails => "_refine_ls.weighting_details",
+            CoreItem::RefineLsWeightingScheme => "_refine_ls.weighting_scheme",
+            CoreItem::RefineLsWrFactorAll => "_refine_ls.wR_factor_all",
+            CoreItem::RefineLsWrFactorGt => "_refine_ls.wR_factor_gt",
+            CoreItem::RefineLsWrFactorRef => "_refine_ls.wR_factor_ref",
+            CoreItem::ReflnsApplyDispersionToFcalc => "_reflns.apply_dispersion_to_Fcalc",
+            CoreItem::ReflnsDResolutionHigh => "_reflns.d_resolution_high",
+            CoreItem::ReflnsDResolutionLow => "_reflns.d_resolution_low",
+            CoreItem::ReflnsFriedelCoverage => "_reflns.Friedel_coverage",
+            CoreItem::ReflnsFriedelFractionFull => "_reflns.Friedel_fraction_full",
+            CoreItem::ReflnsFriedelFractionMax => "_reflns.Friedel_fraction_max",
+            CoreItem::ReflnsLimitHMax => "_reflns.limit_h_max",
+            CoreItem::ReflnsLimitHMin => "_reflns.limit_h_min",
+            CoreItem::ReflnsLimitKMax => "_reflns.limit_k_max",
+            CoreItem::ReflnsLimitKMin => "_reflns.limit_k_min",
+            CoreItem::ReflnsLimitLMax => "_reflns.limit_l_max",
+            CoreItem::ReflnsLimitLMin => "_reflns.limit_l_min",
+            CoreItem::ReflnsNumberGt => "_reflns.number_gt",
+            CoreItem::ReflnsNumberTotal => "_reflns.number_total",
+            CoreItem::ReflnsSpecialDetails => "_reflns.special_details",
+            CoreItem::ReflnsThresholdExpression => "_reflns.threshold_expression",
+            CoreItem::SpaceGroupBravaisType => "_space_group.Bravais_type",
+            CoreItem::SpaceGroupCentringType => "_space_group.centring_type",
+            CoreItem::SpaceGroupCrystalSystem => "_space_group.crystal_system",
+            CoreItem::SpaceGroupItCoordinateSystemCode => "_space_group.IT_coordinate_system_code",
+            CoreItem::SpaceGroupItNumber => "_space_group.IT_number",
+            CoreItem::SpaceGroupLaueClass => "_space_group.Laue_class",
+            CoreItem::SpaceGroupMultiplicity => "_space_group.multiplicity",
+            CoreItem::SpaceGroupNameHMAlt => "_space_group.name_H-M_alt",
+            CoreItem::SpaceGroupNameHMAltDescription => "_space_group.name_H-M_alt_description",
+            CoreItem::SpaceGroupNameHMFull => "_space_group.name_H-M_full",
+            CoreItem::SpaceGroupNameHMRef => "_space_group.name_H-M_ref",
+            CoreItem::SpaceGroupNameHall => "_space_group.name_Hall",
+            CoreItem::SpaceGroupNameSchoenflies => "_space_group.name_Schoenflies",
+            CoreItem::SpaceGroupPattersonNameHM => "_space_group.Patterson_name_H-M",
+            CoreItem::SpaceGroupPointGroupHM => "_space_group.point_group_H-M",
+            CoreItem::SpaceGroupSymopId => "_space_group_symop.id",
+            CoreItem::SpaceGroupSymopOperationDescription => "_space_group_symop.operation_description",
+            CoreItem::SpaceGroupSymopOperationXyz => "_space_group_symop.operation_xyz",
+            CoreItem::SymmetryCellSetting => "_symmetry.cell_setting",
+        }
+    }
+
+    /// Legacy data names meaning the same item
+    pub const fn aliases(self) -> &'static [&'static str] {
+        match self {
+            CoreItem::AtomSiteAdpType => &["_atom_site_ADP_type", "_atom_site_thermal_displace_type", "_atom_site.thermal_displace_type"],
+            CoreItem::AtomSiteAttachedHydrogens => &["_atom_site_attached_hydrogens"],
+            CoreItem::AtomSiteBEquivGeomMean => &["_atom_site_B_equiv_geom_mean"],
+            CoreItem::AtomSiteBEquivGeomMeanSu => &["_atom_site_B_equiv_geom_mean_su", "_atom_site.B_equiv_geom_mean_esd"],
+            CoreItem::AtomSiteBIsoOrEquiv => &["_atom_site_B_iso_or_equiv"],
+            CoreItem::AtomSiteBIsoOrEquivSu => &["_atom_site_B_iso_or_equiv_su", "_atom_site.B_iso_or_equiv_esd"],
+            CoreItem::AtomSiteCalcAttachedAtom => &["_atom_site_calc_attached_atom"],
+            CoreItem::AtomSiteCalcFlag => &["_atom_site_calc_flag"],
+            CoreItem::AtomSiteCartnX => &["_atom_site_Cartn_x"],
+            CoreItem::AtomSiteCartnXSu => &["_atom_site_Cartn_x_su", "_atom_site.Cartn_x_esd"],
+            CoreItem::AtomSiteCartnY => &["_atom_site_Cartn_y"],
+            CoreItem::AtomSiteCartnYSu => &["_atom_site_Cartn_y_su", "_atom_site.Cartn_y_esd"],
+            CoreItem::AtomSiteCartnZ => &["_atom_site_Cartn_z"],
+            CoreItem::AtomSiteCartnZSu => &["_atom_site_Cartn_z_su", "_atom_site.Cartn_z_esd"],
+            CoreItem::AtomSiteChemicalConnNumber => &["_atom_site_chemical_conn_number"],
+            CoreItem::AtomSiteConstraints => &["_atom_site_constraints"],
+            CoreItem::AtomSiteDescription => &["_atom_site_description", "_atom_site.details"],
+            CoreItem::AtomSiteDisorderAssembly => &["_atom_site_disorder_assembly"],
+            CoreItem::AtomSiteDisorderGroup => &["_atom_site_disorder_group"],
+            CoreItem::AtomSiteFractSymmform => &["_atom_site_fract_symmform"],
+            CoreItem::AtomSiteFractX => &["_atom_site_fract_x"],
+            CoreItem::AtomSiteFractXSu => &["_atom_site_fract_x_su", "_atom_site.fract_x_esd"],
+            CoreItem::AtomSiteFractY => &["_atom_site_fract_y"],
+            CoreItem::AtomSiteFractYSu => &["_atom_site_fract_y_su", "_atom_site.fract_y_esd"],
+            CoreItem::AtomSiteFractZ => &["_atom_site_fract_z"],
+            CoreItem::AtomSiteFractZSu => &["_atom_site_fract_z_su", "_atom_site.fract_z_esd"],
+            CoreItem::AtomSiteLabel => &["_atom_site_label", "_atom_site.id"],
+            CoreItem::AtomSiteLabelComponent1 => &["_atom_site_label_component_1"],
+            CoreItem::AtomSiteLabelComponent2 => &["_atom_site_label_component_2"],
+            CoreItem::AtomSiteLabelComponent3 => &["_atom_site_label_component_3"],
+            CoreItem::AtomSiteLabelComponent4 => &["_atom_site_label_component_4"],
+            CoreItem::AtomSiteLabelComponent5 => &["_atom_site_label_component_5"],
+            CoreItem::AtomSiteLabelComponent6 => &["_atom_site_label_component_6"],
+            CoreItem::AtomSiteOccupancy => &["_atom_site_occupancy"],
+            CoreItem::AtomSiteOccupancySu => &["_atom_site_occupancy_su", "_atom_site.occupancy_esd"],
+            CoreItem::AtomSiteRefinementFlags => &["_atom_site_refinement_flags"],
+            CoreItem::AtomSiteRefinementFlagsAdp => &["_atom_site_refinement_flags_ADP"],
+            CoreItem::AtomSiteRefinementFlagsOccupancy => &["_atom_site_refinement_flags_occupancy"],
+            CoreItem::AtomSiteRefinementFlagsPosn => &["_atom_site_refinement_flags_posn"],
+            CoreItem::AtomSiteRestraints => &["_atom_site_restraints"],
+            CoreItem::AtomSiteSiteSymmetryMultiplicity => &["_atom_site_site_symmetry_multiplicity", "_atom_site_symmetry_multiplicity", "_atom_site.symmetry_multiplicity"],
+            CoreItem::AtomSiteSiteSymmetryOrder => &["_atom_site_site_symmetry_order"],
+            CoreItem::AtomSiteTypeSymbol => &["_atom_site_type_symbol"],
+            CoreItem::AtomSiteUEquivGeomMean => &["_atom_site_U_equiv_geom_mean"],
+            CoreItem::AtomSiteUEquivGeomMeanSu => &["_atom_site_U_equiv_geom_mean_su", "_atom_site.U_equiv_geom_mean_esd"],
+            CoreItem::AtomSiteUIsoOrEquiv => &["_atom_site_U_iso_or_equiv"],
+            CoreItem::AtomSiteUIsoOrEquivSu => &["_atom_site_U_iso_or_equiv_su", "_atom_site.U_iso_or_equiv_esd"],
+            CoreItem::AtomSiteWyckoffSymbol => &["_atom_site_Wyckoff_symbol"],
+            CoreItem::AtomSiteAnisoB11 => &["_atom_site_aniso_B_11", "_atom_site.aniso_B[1][1]", "_atom_site_anisotrop.B[1][1]"],
+            CoreItem::AtomSiteAnisoB11Su => &["_atom_site_aniso_B_11_su", "_atom_site.aniso_B[1][1]_esd", "_atom_site_anisotrop.B[1][1]_esd"],
+            CoreItem::AtomSiteAnisoB12 => &["_atom_site_aniso_B_12", "_atom_site.aniso_B[1][2]", "_atom_site_anisotrop.B[1][2]"],
+            CoreItem::AtomSiteAnisoB12Su => &["_atom_site_aniso_B_12_su", "_atom_site.aniso_B[1][2]_esd", "_atom_site_anisotrop.B[1][2]_esd"],
+            CoreItem::AtomSiteAnisoB13 => &["_atom_site_aniso_B_13", "_atom_site.aniso_B[1][3]", "_atom_site_anisotrop.B[1][3]"],
+            CoreItem::AtomSiteAnisoB13Su => &["_atom_site_aniso_B_13_su", "_atom_site.aniso_B[1][3]_esd", "_atom_site_anisotrop.B[1][3]_esd"],
+            CoreItem::AtomSiteAnisoB22 => &["_atom_site_aniso_B_22", "_atom_site.aniso_B[2][2]", "_atom_site_anisotrop.B[2][2]"],
+            CoreItem::AtomSiteAnisoB22Su => &["_atom_site_aniso_B_22_su", "_atom_site.aniso_B[2][2]_esd", "_atom_site_anisotrop.B[2][2]_esd"],
+            CoreItem::AtomSiteAnisoB23 => &["_atom_site_aniso_B_23", "_atom_site.aniso_B[2][3]", "_atom_site_anisotrop.B[2][3]"],
+            CoreItem::AtomSiteAnisoB23Su => &["_atom_site_aniso_B_23_su", "_atom_site.aniso_B[2][3]_esd", "_atom_site_anisotrop.B[2][3]_esd"],
+            CoreItem::AtomSiteAnisoB33 => &["_atom_site_aniso_B_33", "_atom_site.aniso_B[3][3]", "_atom_site_anisotrop.B[3][3]"],
+            CoreItem::AtomSiteAnisoB33Su => &["_atom_site_aniso_B_33_su", "_atom_site.aniso_B[3][3]_esd", "_atom_site_anisotrop.B[3][3]_esd"],
+            CoreItem::AtomSiteAnisoBeta11 => &["_atom_site_aniso_beta_11"],
+            CoreItem::AtomSiteAnisoBeta11Su => &["_atom_site_aniso_beta_11_su"],
+            CoreItem::AtomSiteAnisoBeta12 => &["_atom_site_aniso_beta_12"],
+            CoreItem::AtomSiteAnisoBeta12Su => &["_atom_site_aniso_beta_12_su"],
+            CoreItem::AtomSiteAnisoBeta13 => &["_atom_site_aniso_beta_13"],
+            CoreItem::AtomSiteAnisoBeta13Su => &["_atom_site_aniso_beta_13_su"],
+            CoreItem::AtomSiteAnisoBeta22 => &["_atom_site_aniso_beta_22"],
+            CoreItem::AtomSiteAnisoBeta22Su => &["_atom_site_aniso_beta_22_su"],
+            CoreItem::AtomSiteAnisoBeta23 => &["_atom_site_aniso_beta_23"],
+            CoreItem::AtomSiteAnisoBeta23Su => &["_atom_site_aniso_beta_23_su"],
+            CoreItem::AtomSiteAnisoBeta33 => &["_atom_site_aniso_beta_33"],
+            CoreItem::AtomSiteAnisoBeta33Su => &["_atom_site_aniso_beta_33_su"],
+            CoreItem::AtomSiteAnisoLabel => &["_atom_site_aniso_label", "_atom_site_anisotrop.id"],
+            CoreItem::AtomSiteAnisoRatio => &["_atom_site_aniso_ratio", "_atom_site_anisotrop.ratio", "_atom_site.aniso_ratio"],
+            CoreItem::AtomSiteAnisoSymmform => &["_atom_site_aniso_symmform"],
+            CoreItem::AtomSiteAnisoTypeSymbol => &["_atom_site_aniso_type_symbol", "_atom_site_anisotrop.type_symbol"],
+            CoreItem::AtomSiteAnisoU11 => &["_atom_site_aniso_U_11", "_atom_site.aniso_U[1][1]", "_atom_site_anisotrop.U[1][1]"],
+            CoreItem::AtomSiteAnisoU11Su => &["_atom_site_aniso_U_11_su", "_atom_site.aniso_U[1][1]_esd", "_atom_site_anisotrop.U[1][1]_esd"],
+            CoreItem::AtomSiteAnisoU12 => &["_atom_site_aniso_U_12", "_atom_site.aniso_U[1][2]", "_atom_site_anisotrop.U[1][2]"],
+            CoreItem::AtomSiteAnisoU12Su => &["_atom_site_aniso_U_12_su", "_atom_site.aniso_U[1][2]_esd", "_atom_site_anisotrop.U[1][2]_esd"],
+            CoreItem::AtomSiteAnisoU13 => &["_atom_site_aniso_U_13", "_atom_site.aniso_U[1][3]", "_atom_site_anisotrop.U[1][3]"],
+            CoreItem::AtomSiteAnisoU13Su => &["_atom_site_aniso_U_13_su", "_atom_site.aniso_U[1][3]_esd", "_atom_site_anisotrop.U[1][3]_esd"],
+            CoreItem::AtomSiteAnisoU22 => &["_atom_site_aniso_U_22", "_atom_site.aniso_U[2][2]", "_atom_site_anisotrop.U[2][2]"],
+            CoreItem::AtomSiteAnisoU22Su => &["_atom_site_aniso_U_22_su", "_atom_site.aniso_U[2][2]_esd", "_atom_site_anisotrop.U[2][2]_esd"],
+            CoreItem::AtomSiteAnisoU23 => &["_atom_site_aniso_U_23", "_atom_site.aniso_U[2][3]", "_atom_site_anisotrop.U[2][3]"],
+            CoreItem::AtomSiteAnisoU23Su => &["_atom_site_aniso_U_23_su", "_atom_site.aniso_U[2][3]_esd", "_atom_site_anisotrop.U[2][3]_esd"],
+            CoreItem::AtomSiteAnisoU33 => &["_atom_site_aniso_U_33", "_atom_site.aniso_U[3][3]", "_atom_site_anisotrop.U[3][3]"],
+            CoreItem::AtomSiteAnisoU33Su => &["_atom_site_aniso_U_33_su", "_atom_site.aniso_U[3][3]_esd", "_atom_site_anisotrop.U[3][3]_esd"],
+            CoreItem::AtomSitesSolutionHydrogens => &["_atom_sites_solution_hydrogens"],
+            CoreItem::AtomSitesSolutionPrimary => &["_atom_sites_solution_primary"],
+            CoreItem::AtomSitesSolutionSecondary => &["_atom_sites_solution_secondary"],
+            CoreItem::AtomSitesSpecialDetails => &["_atom_sites_special_details"],
+            CoreItem::AtomTypeAnalyticalMassPercent => &["_atom_type_analytical_mass_%", "_atom_type.analytical_mass_%"],
+            CoreItem::AtomTypeAnalyticalMassPercentSu => &[],
+            CoreItem::AtomTypeAtomicMass => &[],
+            CoreItem::AtomTypeAtomicNumber => &[],
+            CoreItem::AtomTypeDescription => &["_atom_type_description"],
+            CoreItem::AtomTypeDisplayColour => &[],
+            CoreItem::AtomTypeElectronCount => &[],
+            CoreItem::AtomTypeElementSymbol => &[],
+            CoreItem::AtomTypeMassNumber => &[],
+            CoreItem::AtomTypeNumberInCell => &["_atom_type_number_in_cell"],
+            CoreItem::AtomTypeOxidationNumber => &["_atom_type_oxidation_number"],
+            CoreItem::AtomTypeRadiusBond => &["_atom_type_radius_bond"],
+            CoreItem::AtomTypeRadiusContact => &["_atom_type_radius_contact"],
+            CoreItem::AtomTypeSymbol => &["_atom_type_symbol"],
+            CoreItem::AuditBlockCode => &["_audit_block_code", "_audit.revision_id"],
+            CoreItem::AuditBlockDoi => &["_audit_block_DOI"],
+            CoreItem::AuditCreationDate => &["_audit_creation_date"],
+            CoreItem::AuditCreationMethod => &["_audit_creation_method"],
+            CoreItem::AuditSchema => &[],
+            CoreItem::AuditUpdateRecord => &["_audit_update_record"],
+            CoreItem::CellAngleAlpha => &["_cell_angle_alpha"],
+            CoreItem::CellAngleAlphaSu => &["_cell_angle_alpha_su", "_cell.angle_alpha_esd"],
+            CoreItem::CellAngleBeta => &["_cell_angle_beta"],
+            CoreItem::CellAngleBetaSu => &["_cell_angle_beta_su", "_cell.angle_beta_esd"],
+            CoreItem::CellAngleGamma => &["_cell_angle_gamma"],
+            CoreItem::CellAngleGammaSu => &["_cell_angle_gamma_su", "_cell.angle_gamma_esd"],
+            CoreItem::CellAtomicMass => &[],
+            CoreItem::CellDiffrnId => &[],
+            CoreItem::CellFormulaUnitsZ => &["_cell_formula_units_Z"],
+            CoreItem::CellFormulaUnitsZDetails => &[],
+            CoreItem::CellLengthA => &["_cell_length_a"],
+            CoreItem::CellLengthASu => &["_cell_length_a_su", "_cell.length_a_esd"],
+            CoreItem::CellLengthB => &["_cell_length_b"],
+            CoreItem::CellLengthBSu => &["_cell_length_b_su", "_cell.length_b_esd"],
+            CoreItem::CellLengthC => &["_cell_length_c"],
+            CoreItem::CellLengthCSu => &["_cell_length_c_su", "_cell.length_c_esd"],
+            CoreItem::CellReciprocalAngleAlpha => &["_cell_reciprocal_angle_alpha"],
+            CoreItem::CellReciprocalAngleAlphaSu => &["_cell_reciprocal_angle_alpha_su", "_cell.reciprocal_angle_alpha_esd"],
+            CoreItem::CellReciprocalAngleBeta => &["_cell_reciprocal_angle_beta"],
+            CoreItem::CellReciprocalAngleBetaSu => &["_cell_reciprocal_angle_beta_su", "_cell.reciprocal_angle_beta_esd"],
+            CoreItem::CellReciprocalAngleGamma => &["_cell_reciprocal_angle_gamma"],
+            CoreItem::CellReciprocalAngleGammaSu => &["_cell_reciprocal_angle_gamma_su", "_cell.reciprocal_angle_gamma_esd"],
+            CoreItem::CellReciprocalLengthA => &["_cell_reciprocal_length_a"],
+            CoreItem::CellReciprocalLengthASu => &["_cell_reciprocal_length_a_su", "_cell.reciprocal_length_a_esd"],
+            CoreItem::CellReciprocalLengthB => &["_cell_reciprocal_length_b"],
+            CoreItem::CellReciprocalLengthBSu => &["_cell_reciprocal_length_b_su", "_cell.reciprocal_length_b_esd"],
+            CoreItem::CellReciprocalLengthC => &["_cell_reciprocal_length_c"],
+            CoreItem::CellReciprocalLengthCSu => &["_cell_reciprocal_length_c_su", "_cell.reciprocal_length_c_esd"],
+            CoreItem::CellSpecialDetails => &["_cell_special_details", "_cell.details"],
+            CoreItem::CellVolume => &["_cell_volume"],
+            CoreItem::CellVolumeSu => &["_cell_volume_su", "_cell.volume_esd"],
+            CoreItem::CellMeasurementConditionId => &[],
+            CoreItem::CellMeasurementDiffrnId => &[],
+            CoreItem::CellMeasurementPressure => &["_cell_measurement_pressure"],
+            CoreItem::CellMeasurementPressureSu => &["_cell_measurement_pressure_su", "_cell_measurement.pressure_esd"],
+            CoreItem::CellMeasurementRadiation => &["_cell_measurement_radiation"],
+            CoreItem::CellMeasurementReflnsUsed => &["_cell_measurement_reflns_used"],
+            CoreItem::CellMeasurementTemperature => &["_cell_measurement_temperature", "_cell_measurement_temp", "_cell_measurement.temp"],
+            CoreItem::CellMeasurementTemperatureSu => &["_cell_measurement_temp_su", "_cell_measurement.temp_esd"],
+            CoreItem::CellMeasurementThetaMax => &["_cell_measurement_theta_max"],
+            CoreItem::CellMeasurementThetaMin => &["_cell_measurement_theta_min"],
+            CoreItem::CellMeasurementWavelength => &["_cell_measurement_wavelength"],
+            CoreItem::CellMeasurementWavelengthSu => &[],
+            CoreItem::ChemicalAbsoluteConfiguration => &["_chemical_absolute_configuration"],
+            CoreItem::ChemicalCompoundSource => &["_chemical_compound_source"],
+            CoreItem::ChemicalEnantioexcessBulk => &["_chemical_enantioexcess_bulk"],
+            CoreItem::ChemicalEnantioexcessBulkSu => &[],
+            CoreItem::ChemicalEnantioexcessBulkTechnique => &["_chemical_enantioexcess_bulk_technique"],
+            CoreItem::ChemicalEnantioexcessCrystal => &["_chemical_enantioexcess_crystal"],
+            CoreItem::ChemicalEnantioexcessCrystalSu => &[],
+            CoreItem::ChemicalEnantioexcessCrystalTechnique => &["_chemical_enantioexcess_crystal_technique"],
+            CoreItem::ChemicalIdentifierInchi => &["_chemical_identifier_InChI"],
+            CoreItem::ChemicalIdentifierInchiKey => &["_chemical_identifier_InChI_key"],
+            CoreItem::ChemicalIdentifierInchiVersion => &["_chemical_identifier_InChI_version"],
+            CoreItem::ChemicalMeltingPoint => &["_chemical_melting_point"],
+            CoreItem::ChemicalMeltingPointGt => &["_chemical_melting_point_gt"],
+            CoreItem::ChemicalMeltingPointLt => &["_chemical_melting_point_lt"],
+            CoreItem::ChemicalMeltingPointSu => &[],
+            CoreItem::ChemicalNameCommon => &["_chemical_name_common"],
+            CoreItem::ChemicalNameMineral => &["_chemical_name_mineral"],
+            CoreItem::ChemicalNameStructureType => &["_chemical_name_structure_type"],
+            CoreItem::ChemicalNameSystematic => &["_chemical_name_systematic"],
+            CoreItem::ChemicalOpticalRotation => &["_chemical_optical_rotation"],
+            CoreItem::ChemicalPropertiesBiological => &["_chemical_properties_biological"],
+            CoreItem::ChemicalPropertiesPhysical => &["_chemical_properties_physical"],
+            CoreItem::ChemicalTemperatureDecomposition => &["_chemical_temperature_decomposition"],
+            CoreItem::ChemicalTemperatureDecompositionGt => &["_chemical_temperature_decomposition_gt"],
+            CoreItem::ChemicalTemperatureDecompositionLt => &["_chemical_temperature_decomposition_lt"],
+            CoreItem::ChemicalTemperatureDecompositionSu => &["_chemical_temperature_decomposition_su", "_chemical.temperature_decomposition_esd"],
+            CoreItem::ChemicalTemperatureSublimation => &["_chemical_temperature_sublimation"],
+            CoreItem::ChemicalTemperatureSublimationGt => &["_chemical_temperature_sublimation_gt"],
+            CoreItem::ChemicalTemperatureSublimationLt => &["_chemical_temperature_sublimation_lt"],
+            CoreItem::ChemicalTemperatureSublimationSu => &["_chemical_temperature_sublimation_su", "_chemical.temperature_sublimation_esd"],
+            CoreItem::ChemicalFormulaAnalytical => &["_chemical_formula_analytical"],
+            CoreItem::ChemicalFormulaIupac => &["_chemical_formula_IUPAC"],
+            CoreItem::ChemicalFormulaMoiety => &["_chemical_formula_moiety"],
+            CoreItem::ChemicalFormulaStructural => &["_chemical_formula_structural"],
+            CoreItem::ChemicalFormulaSum => &["_chemical_formula_sum"],
+            CoreItem::ChemicalFormulaWeight => &["_chemical_formula_weight"],
+            CoreItem::ChemicalFormulaWeightMeas => &["_chemical_formula_weight_meas"],
+            CoreItem::ChemicalFormulaWeightMeasSu => &[],
+            CoreItem::ComputingCellRefinement => &["_computing_cell_refinement"],
+            CoreItem::ComputingDiffrnCollection => &["_computing_data_collection", "_computing.data_collection"],
+            CoreItem::ComputingDiffrnReduction => &["_computing_data_reduction", "_computing.data_reduction"],
+            CoreItem::ComputingMolecularGraphics => &["_computing_molecular_graphics"],
+            CoreItem::ComputingPublicationMaterial => &["_computing_publication_material"],
+            CoreItem::ComputingSampleTracking => &[],
+            CoreItem::ComputingStructureRefinement => &["_computing_structure_refinement"],
+            CoreItem::ComputingStructureSolution => &["_computing_structure_solution"],
+            CoreItem::DiffrnAmbientEnvironment => &["_diffrn_ambient_environment"],
+            CoreItem::DiffrnAmbientPressure => &["_diffrn_ambient_pressure"],
+            CoreItem::DiffrnAmbientPressureGt => &["_diffrn_ambient_pressure_gt"],
+            CoreItem::DiffrnAmbientPressureLt => &["_diffrn_ambient_pressure_lt"],
+            CoreItem::DiffrnAmbientPressureSu => &["_diffrn_ambient_pressure_su", "_diffrn.ambient_pressure_esd"],
+            CoreItem::DiffrnAmbientTemperature => &["_diffrn_ambient_temperature", "_diffrn_ambient_temp", "_diffrn.ambient_temp"],
+            CoreItem::DiffrnAmbientTemperatureDetails => &["_diffrn_ambient_temp_details", "_diffrn.ambient_temp_details"],
+            CoreItem::DiffrnAmbientTemperatureGt => &["_diffrn_ambient_temp_gt", "_diffrn_ambient_temperature_gt", "_diffrn.ambient_temp_gt"],
+            CoreItem::DiffrnAmbientTemperatureLt => &["_diffrn_ambient_temp_lt", "_diffrn_ambient_temperature_lt", "_diffrn.ambient_temp_lt"],
+            CoreItem::DiffrnAmbientTemperatureSu => &["_diffrn_ambient_temperature_su", "_diffrn_ambient_temp_su", "_diffrn.ambient_temp_esd"],
+            CoreItem::DiffrnCrystalId => &[],
+            CoreItem::DiffrnCrystalSupport => &[],
+            CoreItem::DiffrnCrystalTreatment => &["_diffrn_crystal_treatment"],
+            CoreItem::DiffrnFluxDensity => &[],
+            CoreItem::DiffrnId => &[],
+            CoreItem::DiffrnMeasuredFractionThetaFull => &["_diffrn_measured_fraction_theta_full"],
+            CoreItem::DiffrnMeasuredFractionThetaMax => &["_diffrn_measured_fraction_theta_max"],
+            CoreItem::DiffrnPrecessionSemiAngle => &[],
+            CoreItem::DiffrnPrecessionSemiAngleSu => &[],
+            CoreItem::DiffrnSpecialDetails => &["_diffrn_special_details", "_diffrn.details"],
+            CoreItem::DiffrnSymmetryDescription => &["_diffrn_symmetry_description"],
+            CoreItem::DiffrnTotalDose => &[],
+            CoreItem::DiffrnTotalDoseSu => &[],
+            CoreItem::DiffrnTotalExposureTime => &[],
+            CoreItem::DiffrnDetectorAreaResolMean => &["_diffrn_detector_area_resol_mean"],
+            CoreItem::DiffrnDetectorDescription => &["_diffrn_radiation_detector", "_diffrn_detector", "_diffrn_detector.detector"],
+            CoreItem::DiffrnDetectorDetails => &["_diffrn_detector_details"],
+            CoreItem::DiffrnDetectorDtime => &["_diffrn_detector_dtime", "_diffrn_radiation.detector_dtime", "_diffrn_radiation_detector_dtime"],
+            CoreItem::DiffrnDetectorMake => &["_diffrn_detector_type", "_diffrn_detector.type"],
+            CoreItem::DiffrnMeasurementDetails => &["_diffrn_measurement_details", "_diffrn.measurement_details"],
+            CoreItem::DiffrnMeasurementDeviceClass => &["_diffrn_measurement_device", "_diffrn.measurement_device_class", "_diffrn_measurement.device"],
+            CoreItem::DiffrnMeasurementDeviceDetails => &["_diffrn_measurement_device_details", "_diffrn.measurement_device_details"],
+            CoreItem::DiffrnMeasurementDeviceMake => &["_diffrn_measurement_device_type", "_diffrn.measurement_device_make", "_diffrn_measurement.device_type"],
+            CoreItem::DiffrnMeasurementMethod => &["_diffrn_measurement_method", "_diffrn.measurement_method"],
+            CoreItem::DiffrnMeasurementMethodPrecession => &[],
+            CoreItem::DiffrnMeasurementRotationMode => &[],
+            CoreItem::DiffrnMeasurementSampleTracking => &[],
+            CoreItem::DiffrnMeasurementSampleTrackingMethod => &[],
+            CoreItem::DiffrnMeasurementSpecimenAttachmentType => &[],
+            CoreItem::DiffrnMeasurementSpecimenSupport => &["_diffrn_measurement_specimen_support", "_diffrn.measurement_specimen_support"],
+            CoreItem::DiffrnRadiationCollimation => &["_diffrn_radiation_collimation"],
+            CoreItem::DiffrnRadiationFilterEdge => &["_diffrn_radiation_filter_edge"],
+            CoreItem::DiffrnRadiationIlluminationMode => &[],
+            CoreItem::DiffrnRadiationInhomogeneity => &["_diffrn_radiation_inhomogeneity"],
+            CoreItem::DiffrnRadiationMonochromator => &["_diffrn_radiation_monochromator"],
+            CoreItem::DiffrnRadiationPolarisnNorm => &["_diffrn_radiation_polarisn_norm"],
+            CoreItem::DiffrnRadiationPolarisnRatio => &["_diffrn_radiation_polarisn_ratio"],
+            CoreItem::DiffrnRadiationProbe => &["_diffrn_radiation_probe"],
+            CoreItem::DiffrnRadiationType => &["_diffrn_radiation_type"],
+            CoreItem::DiffrnRadiationXraySymbol => &["_diffrn_radiation_xray_symbol"],
+            CoreItem::DiffrnRadiationWavelengthDetails => &["_diffrn_radiation_wavelength_details", "_diffrn_radiation.wavelength_details"],
+            CoreItem::DiffrnRadiationWavelengthDetermination => &["_diffrn_radiation_wavelength_determination", "_diffrn_radiation.wavelength_determination"],
+            CoreItem::DiffrnRadiationWavelengthId => &["_diffrn_radiation_wavelength_id"],
+            CoreItem::DiffrnRadiationWavelengthType => &[],
+            CoreItem::DiffrnRadiationWavelengthValue => &["_diffrn_radiation_wavelength", "_diffrn_radiation_wavelength.wavelength"],
+            CoreItem::DiffrnRadiationWavelengthValueSu => &["_diffrn_radiation_wavelength_su", "_diffrn_radiation_wavelength.wavelength_su"],
+            CoreItem::DiffrnRadiationWavelengthWt => &["_diffrn_radiation_wavelength_wt"],
+            CoreItem::DiffrnRadiationWavelengthXraySymbol => &[],
+            CoreItem::DiffrnReflnsAvREquivalents => &["_diffrn_reflns_av_R_equivalents"],
+            CoreItem::DiffrnReflnsAvSunetiOverNeti => &["_diffrn_reflns_av_sigmaI/netI", "_diffrn_reflns.av_unetI/netI", "_diffrn_reflns_av_unetI/netI", "_diffrn_reflns.av_sigmaI_over_netI"],
+            CoreItem::DiffrnReflnsLaueMeasuredFractionFull => &["_diffrn_reflns_Laue_measured_fraction_full"],
+            CoreItem::DiffrnReflnsLaueMeasuredFractionMax => &["_diffrn_reflns_Laue_measured_fraction_max"],
+            CoreItem::DiffrnReflnsLimitHMax => &["_diffrn_reflns_limit_h_max"],
+            CoreItem::DiffrnReflnsLimitHMin => &["_diffrn_reflns_limit_h_min"],
+            CoreItem::DiffrnReflnsLimitKMax => &["_diffrn_reflns_limit_k_max"],
+            CoreItem::DiffrnReflnsLimitKMin => &["_diffrn_reflns_limit_k_min"],
+            CoreItem::DiffrnReflnsLimitLMax => &["_diffrn_reflns_limit_l_max"],
+            CoreItem::DiffrnReflnsLimitLMin => &["_diffrn_reflns_limit_l_min"],
+            CoreItem::DiffrnReflnsNumber => &["_diffrn_reflns_number"],
+            CoreItem::DiffrnReflnsPointMeasuredFractionFull => &["_diffrn_reflns_point_group_measured_fraction_full"],
+            CoreItem::DiffrnReflnsPointMeasuredFractionMax => &["_diffrn_reflns_point_group_measured_fraction_max"],
+            CoreItem::DiffrnReflnsReductionProcess => &["_diffrn_reflns_reduction_process"],
+            CoreItem::DiffrnReflnsResolutionFull => &["_diffrn_reflns_resolution_full"],
+            CoreItem::DiffrnReflnsResolutionMax => &["_diffrn_reflns_resolution_max"],
+            CoreItem::DiffrnReflnsThetaFull => &["_diffrn_reflns_theta_full"],
+            CoreItem::DiffrnReflnsThetaMax => &["_diffrn_reflns_theta_max"],
+            CoreItem::DiffrnReflnsThetaMin => &["_diffrn_reflns_theta_min"],
+            CoreItem::DiffrnSourceBeamline => &[],
+            CoreItem::DiffrnSourceConvergenceAngle => &[],
+            CoreItem::DiffrnSourceCurrent => &["_diffrn_source_current"],
+            CoreItem::DiffrnSourceDescription => &["_diffrn_source", "_diffrn_radiation_source", "_diffrn_source.source"],
+            CoreItem::DiffrnSourceDetails => &["_diffrn_source_details"],
+            CoreItem::DiffrnSourceDevice => &[],
+            CoreItem::DiffrnSourceEdDiffractingAreaSelection => &[],
+            CoreItem::DiffrnSourceFacility => &[],
+            CoreItem::DiffrnSourceMake => &["_diffrn_source_make", "_diffrn_source.type", "_diffrn_source_type"],
+            CoreItem::DiffrnSourcePower => &["_diffrn_source_power"],
+            CoreItem::DiffrnSourceSize => &["_diffrn_source_size"],
+            CoreItem::DiffrnSourceTakeOffAngle => &["_diffrn_source_take-off_angle", "_diffrn_source.take-off_angle"],
+            CoreItem::DiffrnSourceTarget => &["_diffrn_source_target"],
+            CoreItem::DiffrnSourceVoltage => &["_diffrn_source_voltage"],
+            CoreItem::DiffrnStandardsDecayPercent => &["_diffrn_standards_decay_%", "_diffrn_standards.decay_%", "_diffrn_standards_decay_percent"],
+            CoreItem::DiffrnStandardsDecayPercentSu => &[],
+            CoreItem::DiffrnStandardsIntervalCount => &["_diffrn_standards_interval_count"],
+            CoreItem::DiffrnStandardsIntervalTime => &["_diffrn_standards_interval_time"],
+            CoreItem::DiffrnStandardsNumber => &["_diffrn_standards_number"],
+            CoreItem::DiffrnStandardsScaleSuAverage => &["_diffrn_standards_scale_sigma", "_diffrn_standards.scale_sigma", "_diffrn_standards.scale_u", "_diffrn_standards_scale_u"],
+            CoreItem::DiffrnStandardsScaleSuAverageSu => &[],
+            CoreItem::ExptlCrystalsNumber => &["_exptl_crystals_number"],
+            CoreItem::ExptlMethod => &[],
+            CoreItem::ExptlMethodDetails => &[],
+            CoreItem::ExptlSpecialDetails => &["_exptl_special_details", "_exptl.details"],
+            CoreItem::ExptlTransmissionFactorMax => &["_exptl_transmission_factor_max"],
+            CoreItem::ExptlTransmissionFactorMaxSu => &[],
+            CoreItem::ExptlTransmissionFactorMin => &["_exptl_transmission_factor_min"],
+            CoreItem::ExptlTransmissionFactorMinSu => &[],
+            CoreItem::ExptlAbsorptCoefficientMu => &["_exptl_absorpt_coefficient_mu", "_exptl.absorpt_coefficient_mu"],
+            CoreItem::ExptlAbsorptCoefficientMuSu => &[],
+            CoreItem::ExptlAbsorptCorrectionTMax => &["_exptl_absorpt_correction_T_max", "_exptl.absorpt_correction_T_max"],
+            CoreItem::ExptlAbsorptCorrectionTMin => &["_exptl_absorpt_correction_T_min", "_exptl.absorpt_correction_T_min"],
+            CoreItem::ExptlAbsorptCorrectionType => &["_exptl_absorpt_correction_type", "_exptl.absorpt_correction_type"],
+            CoreItem::ExptlAbsorptProcessDetails => &["_exptl_absorpt_process_details", "_exptl.absorpt_process_details"],
+            CoreItem::ExptlAbsorptSpecialDetails => &["_exptl_absorpt_special_details"],
+            CoreItem::ExptlCrystalColour => &["_exptl_crystal_colour"],
+            CoreItem::ExptlCrystalDensityDiffrn => &["_exptl_crystal_density_diffrn"],
+            CoreItem::ExptlCrystalDensityDiffrnSu => &[],
+            CoreItem::ExptlCrystalDensityMeas => &["_exptl_crystal_density_meas"],
+            CoreItem::ExptlCrystalDensityMeasGt => &["_exptl_crystal_density_meas_gt"],
+            CoreItem::ExptlCrystalDensityMeasLt => &["_exptl_crystal_density_meas_lt"],
+            CoreItem::ExptlCrystalDensityMeasSu => &["_exptl_crystal_density_meas_su", "_exptl_crystal.density_meas_esd"],
+            CoreItem::ExptlCrystalDensityMeasTemp => &["_exptl_crystal_density_meas_temp"],
+            CoreItem::ExptlCrystalDensityMeasTempGt => &["_exptl_crystal_density_meas_temp_gt"],
+            CoreItem::ExptlCrystalDensityMeasTempLt => &["_exptl_crystal_density_meas_temp_lt"],
+            CoreItem::ExptlCrystalDensityMeasTempSu => &["_exptl_crystal_density_meas_temp_su", "_exptl_crystal.density_meas_temp_esd"],
+            CoreItem::ExptlCrystalDensityMethod => &["_exptl_crystal_density_method"],
+            CoreItem::ExptlCrystalDescription => &["_exptl_crystal_description"],
+            CoreItem::ExptlCrystalF000 => &["_exptl_crystal_F_000"],
+            CoreItem::ExptlCrystalId => &["_exptl_crystal_id"],
+            CoreItem::ExptlCrystalMosaicBlockSize => &[],
+            CoreItem::ExptlCrystalMosaicBlockSizeSu => &[],
+            CoreItem::ExptlCrystalMosaicMethod => &[],
+            CoreItem::ExptlCrystalMosaicity => &[],
+            CoreItem::ExptlCrystalMosaicitySu => &[],
+            CoreItem::ExptlCrystalPreparation => &["_exptl_crystal_preparation"],
+            CoreItem::ExptlCrystalPressureHistory => &["_exptl_crystal_pressure_history"],
+            CoreItem::ExptlCrystalRecrystallizationMethod => &["_exptl_crystal_recrystallization_method"],
+            CoreItem::ExptlCrystalSizeLength => &["_exptl_crystal_size_length"],
+            CoreItem::ExptlCrystalSizeLengthSu => &[],
+            CoreItem::ExptlCrystalSizeMax => &["_exptl_crystal_size_max"],
+            CoreItem::ExptlCrystalSizeMaxSu => &[],
+            CoreItem::ExptlCrystalSizeMid => &["_exptl_crystal_size_mid"],
+            CoreItem::ExptlCrystalSizeMidSu => &[],
+            CoreItem::ExptlCrystalSizeMin => &["_exptl_crystal_size_min"],
+            CoreItem::ExptlCrystalSizeMinSu => &[],
+            CoreItem::ExptlCrystalSizeRad => &["_exptl_crystal_size_rad"],
+            CoreItem::ExptlCrystalSizeRadSu => &[],
+            CoreItem::ExptlCrystalThermalHistory => &["_exptl_crystal_thermal_history"],
+            CoreItem::GeomAngleAtomSiteLabel1 => &["_geom_angle_atom_site_label_1", "_geom_angle.atom_site_id_1"],
+            CoreItem::GeomAngleAtomSiteLabel2 => &["_geom_angle_atom_site_label_2", "_geom_angle.atom_site_id_2"],
+            CoreItem::GeomAngleAtomSiteLabel3 => &["_geom_angle_atom_site_label_3", "_geom_angle.atom_site_id_3"],
+            CoreItem::GeomAngleId => &[],
+            CoreItem::GeomAnglePublFlag => &["_geom_angle_publ_flag"],
+            CoreItem::GeomAngleValue => &["_geom_angle"],
+            CoreItem::GeomAngleValueSu => &["_geom_angle_su", "_geom_angle.value_esd"],
+            CoreItem::GeomBondAtomSiteLabel1 => &["_geom_bond_atom_site_label_1", "_geom_bond.atom_site_id_1"],
+            CoreItem::GeomBondAtomSiteLabel2 => &["_geom_bond_atom_site_label_2", "_geom_bond.atom_site_id_2"],
+            CoreItem::GeomBondDistance => &["_geom_bond_distance", "_geom_bond.dist"],
+            CoreItem::GeomBondDistanceSu => &["_geom_bond_distance_su", "_geom_bond.dist_esd"],
+            CoreItem::GeomBondId => &[],
+            CoreItem::GeomBondMultiplicity => &["_geom_bond_multiplicity"],
+            CoreItem::GeomBondPublFlag => &["_geom_bond_publ_flag"],
+            CoreItem::GeomBondValence => &["_geom_bond_valence"],
+            CoreItem::GeomBondValenceSu => &[],
+            CoreItem::GeomHbondAngleDha => &["_geom_hbond_angle_DHA"],
+            CoreItem::GeomHbondAngleDhaSu => &["_geom_hbond_angle_DHA_su", "_geom_hbond.angle_DHA_esd"],
+            CoreItem::GeomHbondAtomSiteLabelA => &["_geom_hbond_atom_site_label_A", "_geom_hbond.atom_site_id_A"],
+            CoreItem::GeomHbondAtomSiteLabelD => &["_geom_hbond_atom_site_label_D", "_geom_hbond.atom_site_id_D"],
+            CoreItem::GeomHbondAtomSiteLabelH => &["_geom_hbond_atom_site_label_H", "_geom_hbond.atom_site_id_H"],
+            CoreItem::GeomHbondDistanceDa => &["_geom_hbond_distance_DA", "_geom_hbond.dist_DA"],
+            CoreItem::GeomHbondDistanceDaSu => &["_geom_hbond_distance_DA_su", "_geom_hbond.dist_DA_esd"],
+            CoreItem::GeomHbondDistanceDh => &["_geom_hbond_distance_DH", "_geom_hbond.dist_DH"],
+            CoreItem::GeomHbondDistanceDhSu => &["_geom_hbond_distance_DH_su", "_geom_hbond.dist_DH_esd"],
+            CoreItem::GeomHbondDistanceHa => &["_geom_hbond_distance_HA", "_geom_hbond.dist_HA"],
+            CoreItem::GeomHbondDistanceHaSu => &["_geom_hbond_distance_HA_su", "_geom_hbond.dist_HA_esd"],
+            CoreItem::GeomHbondId => &[],
+            CoreItem::GeomHbondPublFlag => &["_geom_hbond_publ_flag"],
+            CoreItem::GeomTorsionAngle => &["_geom_torsion", "_geom_torsion.value"],
+            CoreItem::GeomTorsionAngleSu => &["_geom_torsion_su", "_geom_torsion.value_esd"],
+            CoreItem::GeomTorsionAtomSiteLabel1 => &["_geom_torsion_atom_site_label_1", "_geom_torsion.atom_site_id_1"],
+            CoreItem::GeomTorsionAtomSiteLabel2 => &["_geom_torsion_atom_site_label_2", "_geom_torsion.atom_site_id_2"],
+            CoreItem::GeomTorsionAtomSiteLabel3 => &["_geom_torsion_atom_site_label_3", "_geom_torsion.atom_site_id_3"],
+            CoreItem::GeomTorsionAtomSiteLabel4 => &["_geom_torsion_atom_site_label_4", "_geom_torsion.atom_site_id_4"],
+            CoreItem::GeomTorsionId => &[],
+            CoreItem::GeomTorsionPublFlag => &["_geom_torsion_publ_flag"],
+            CoreItem::RefineDiffractionTheory => &[],
+            CoreItem::RefineDiffractionTheoryDetails => &[],
+            CoreItem::RefineSpecialDetails => &["_refine_special_details", "_refine.details"],
+            CoreItem::RefineDiffDensityMax => &["_refine_diff_density_max", "_refine.diff_density_max"],
+            CoreItem::RefineDiffDensityMaxSu => &["_refine_diff_density_max_su", "_refine.diff_density_max_esd"],
+            CoreItem::RefineDiffDensityMin => &["_refine_diff_density_min", "_refine.diff_density_min"],
+            CoreItem::RefineDiffDensityMinSu => &["_refine_diff_density_min_su", "_refine.diff_density_min_esd"],
+            CoreItem::RefineDiffDensityRms => &["_refine_diff_density_RMS", "_refine.diff_density_RMS"],
+            CoreItem::RefineDiffDensityRmsSu => &["_refine_diff_density_RMS_su", "_refine.diff_density_RMS_esd"],
+            CoreItem::RefineDiffPotentialMax => &[],
+            CoreItem::RefineDiffPotentialMaxSu => &[],
+            CoreItem::RefineDiffPotentialMin => &[],
+            CoreItem::RefineDiffPotentialMinSu => &[],
+            CoreItem::RefineDiffPotentialRms => &[],
+            CoreItem::RefineDiffPotentialRmsSu => &[],
+            CoreItem::RefineLsAbsStructureDetails => &["_refine_ls_abs_structure_details", "_refine.ls_abs_structure_details"],
+            CoreItem::RefineLsAbsStructureFlack => &["_refine_ls_abs_structure_Flack", "_refine.ls_abs_structure_Flack"],
+            CoreItem::RefineLsAbsStructureFlackSu => &["_refine_ls_abs_structure_Flack_su", "_refine.ls_abs_structure_Flack_esd"],
+            CoreItem::RefineLsAbsStructureRogers => &["_refine_ls_abs_structure_Rogers", "_refine.ls_abs_structure_Rogers"],
+            CoreItem::RefineLsAbsStructureRogersSu => &["_refine_ls_abs_structure_Rogers_su", "_refine.ls_abs_structure_Rogers_esd"],
+            CoreItem::RefineLsAbsStructureZScore => &[],
+            CoreItem::RefineLsDResHigh => &["_refine_ls_d_res_high", "_refine.ls_d_res_high"],
+            CoreItem::RefineLsDResLow => &["_refine_ls_d_res_low", "_refine.ls_d_res_low"],
+            CoreItem::RefineLsExtinctionCoef => &["_refine_ls_extinction_coef", "_refine.ls_extinction_coef"],
+            CoreItem::RefineLsExtinctionCoefSu => &["_refine_ls_extinction_coef_su", "_refine.ls_extinction_coef_esd"],
+            CoreItem::RefineLsExtinctionExpression => &["_refine_ls_extinction_expression", "_refine.ls_extinction_expression"],
+            CoreItem::RefineLsExtinctionMethod => &["_refine_ls_extinction_method", "_refine.ls_extinction_method"],
+            CoreItem::RefineLsFCalcDetails => &["_refine_ls_F_calc_details"],
+            CoreItem::RefineLsFCalcFormula => &["_refine_ls_F_calc_formula"],
+            CoreItem::RefineLsFCalcPrecision => &["_refine_ls_F_calc_precision"],
+            CoreItem::RefineLsFCalcPrecisionSu => &[],
+            CoreItem::RefineLsGoodnessOfFitAll => &["_refine_ls_goodness_of_fit_all", "_refine.ls_goodness_of_fit_all"],
+            CoreItem::RefineLsGoodnessOfFitAllSu => &["_refine_ls_goodness_of_fit_all_su", "_refine.ls_goodness_of_fit_all_esd"],
+            CoreItem::RefineLsGoodnessOfFitGt => &["_refine_ls_goodness_of_fit_gt", "_refine_ls_goodness_of_fit_obs", "_refine.ls_goodness_of_fit_obs", "_refine.ls_goodness_of_fit_gt"],
+            CoreItem::RefineLsGoodnessOfFitGtSu => &["_refine_ls_goodness_of_fit_gt_su", "_refine.ls_goodness_of_fit_gt_esd", "_refine.ls_goodness_of_fit_obs_esd"],
+            CoreItem::RefineLsGoodnessOfFitRef => &["_refine_ls_goodness_of_fit_ref", "_refine.ls_goodness_of_fit_ref"],
+            CoreItem::RefineLsGoodnessOfFitRefSu => &[],
+            CoreItem::RefineLsHydrogenTreatment => &["_refine_ls_hydrogen_treatment", "_refine.ls_hydrogen_treatment"],
+            CoreItem::RefineLsMatrixType => &["_refine_ls_matrix_type", "_refine.ls_matrix_type"],
+            CoreItem::RefineLsNumberConstraints => &["_refine_ls_number_constraints", "_refine.ls_number_constraints"],
+            CoreItem::RefineLsNumberParameters => &["_refine_ls_number_parameters", "_refine.ls_number_parameters"],
+            CoreItem::RefineLsNumberReflns => &["_refine_ls_number_reflns", "_refine.ls_number_reflns_all"],
+            CoreItem::RefineLsNumberReflnsGt => &["_refine_ls_number_reflns_gt", "_refine.ls_number_reflns_obs"],
+            CoreItem::RefineLsNumberRestraints => &["_refine_ls_number_restraints", "_refine.ls_number_restraints"],
+            CoreItem::RefineLsRFactorAll => &["_refine_ls_R_factor_all", "_refine.ls_R_factor_all"],
+            CoreItem::RefineLsRFactorGt => &["_refine_ls_R_factor_obs", "_refine_ls_R_factor_gt", "_refine.ls_R_factor_obs", "_refine.ls_R_factor_gt"],
+            CoreItem::RefineLsRFsqdFactor => &["_refine_ls_R_Fsqd_factor", "_refine.ls_R_Fsqd_factor_obs"],
+            CoreItem::RefineLsRIFactor => &["_refine_ls_R_I_factor", "_refine.ls_R_I_factor_obs"],
+            CoreItem::RefineLsRestrainedSAll => &["_refine_ls_restrained_S_all", "_refine.ls_restrained_S_all"],
+            CoreItem::RefineLsRestrainedSAllSu => &[],
+            CoreItem::RefineLsRestrainedSGt => &["_refine_ls_restrained_S_obs", "_refine_ls_restrained_S_gt", "_refine.ls_restrained_S_obs"],
+            CoreItem::RefineLsRestrainedSGtSu => &[],
+            CoreItem::RefineLsSampleShapeDetails => &[],
+            CoreItem::RefineLsSampleShapeExpression => &[],
+            CoreItem::RefineLsSampleThickness => &[],
+            CoreItem::RefineLsSampleThicknessSu => &[],
+            CoreItem::RefineLsShiftOverSuMax => &["_refine_ls_shift_over_su_max", "_refine.ls_shift_over_esd_max", "_refine.ls_shift_over_su_max", "_refine_ls_shift/su_max", "_refine_ls_shift/esd_max"],
+            CoreItem::RefineLsShiftOverSuMaxLt => &["_refine_ls_shift/su_max_lt", "_refine.ls_shift_over_su_max_lt"],
+            CoreItem::RefineLsShiftOverSuMean => &["_refine_ls_shift_over_su_mean", "_refine.ls_shift_over_esd_mean", "_refine.ls_shift_over_su_mean", "_refine_ls_shift/su_mean", "_refine_ls_shift/esd_mean"],
+            CoreItem::RefineLsShiftOverSuMeanLt => &["_refine_ls_shift/su_mean_lt", "_refine.ls_shift_over_su_mean_lt"],
+            CoreItem::RefineLsStructureFactorCoef => &["_refine_ls_structure_factor_coef", "_refine.ls_structure_factor_coef"],
+            CoreItem::RefineLsWeightingDetails => &["_refine_ls_weighting_details", "_refine.ls_weighting_details"],
+            CoreItem::RefineLsWeightingScheme => &["_refine_ls_weighting_scheme", "_refine.ls_weighting_scheme"],
+            CoreItem::RefineLsWrFactorAll => &["_refine_ls_wR_factor_all", "_refine.ls_wR_factor_all"],
+            CoreItem::RefineLsWrFactorGt => &["_refine_ls_wR_factor_obs", "_refine.ls_wR_factor_obs", "_refine_ls_wR_factor_gt"],
+            CoreItem::RefineLsWrFactorRef => &["_refine_ls_wR_factor_ref"],
+            CoreItem::ReflnsApplyDispersionToFcalc => &[],
+            CoreItem::ReflnsDResolutionHigh => &["_reflns_d_resolution_high"],
+            CoreItem::ReflnsDResolutionLow => &["_reflns_d_resolution_low"],
+            CoreItem::ReflnsFriedelCoverage => &["_reflns_Friedel_coverage"],
+            CoreItem::ReflnsFriedelFractionFull => &["_reflns_Friedel_fraction_full"],
+            CoreItem::ReflnsFriedelFractionMax => &["_reflns_Friedel_fraction_max"],
+            CoreItem::ReflnsLimitHMax => &["_reflns_limit_h_max"],
+            CoreItem::ReflnsLimitHMin => &["_reflns_limit_h_min"],
+            CoreItem::ReflnsLimitKMax => &["_reflns_limit_k_max"],
+            CoreItem::ReflnsLimitKMin => &["_reflns_limit_k_min"],
+            CoreItem::ReflnsLimitLMax => &["_reflns_limit_l_max"],
+            CoreItem::ReflnsLimitLMin => &["_reflns_limit_l_min"],
+            CoreItem::ReflnsNumberGt => &["_reflns_number_gt", "_reflns_number_observed", "_reflns.number_obs"],
+            CoreItem::ReflnsNumberTotal => &["_reflns_number_total", "_reflns_number_all", "_reflns.number_all"],
+            CoreItem::ReflnsSpecialDetails => &["_reflns_special_details", "_reflns.details"],
+            CoreItem::ReflnsThresholdExpression => &["_reflns_threshold_expression", "_reflns_observed_criterion", "_reflns.observed_criterion"],
+            CoreItem::SpaceGroupBravaisType => &[],
+            CoreItem::SpaceGroupCentringType => &[],
+            CoreItem::SpaceGroupCrystalSystem => &["_space_group_crystal_system"],
+            CoreItem::SpaceGroupItCoordinateSystemCode => &[],
+            CoreItem::SpaceGroupItNumber => &["_space_group_IT_number", "_symmetry.Int_Tables_number", "_symmetry_Int_Tables_number"],
+            CoreItem::SpaceGroupLaueClass => &[],
+            CoreItem::SpaceGroupMultiplicity => &[],
+            CoreItem::SpaceGroupNameHMAlt => &["_space_group_name_H-M_alt"],
+            CoreItem::SpaceGroupNameHMAltDescription => &[],
+            CoreItem::SpaceGroupNameHMFull => &["_symmetry.space_group_name_H-M", "_symmetry_space_group_name_H-M"],
+            CoreItem::SpaceGroupNameHMRef => &[],
+            CoreItem::SpaceGroupNameHall => &["_space_group_name_Hall", "_symmetry_space_group_name_Hall", "_symmetry.space_group_name_Hall"],
+            CoreItem::SpaceGroupNameSchoenflies => &[],
+            CoreItem::SpaceGroupPattersonNameHM => &[],
+            CoreItem::SpaceGroupPointGroupHM => &[],
+            CoreItem::SpaceGroupSymopId => &["_space_group_symop_id", "_symmetry_equiv.pos_site_id", "_symmetry_equiv_pos_site_id"],
+            CoreItem::SpaceGroupSymopOperationDescription => &[],
+            CoreItem::SpaceGroupSymopOperationXyz => &["_space_group_symop_operation_xyz", "_symmetry_equiv.pos_as_xyz", "_symmetry_equiv_pos_as_xyz"],
+            CoreItem::SymmetryCellSetting => &["_symmetry_cell_setting"],
+        }
+    }
+
+    /// Type values of this item are read as
+    pub const fn value_type(self) -> ValueType {
+        match self {
+            CoreItem::AtomSiteAdpType => ValueType::Text,
+            CoreItem::AtomSiteAttachedHydrogens => ValueType::Integer,
+            CoreItem::AtomSiteBEquivGeomMean => ValueType::Measurand,
+            CoreItem::AtomSiteBEquivGeomMeanSu => ValueType::Real,
+            CoreItem::AtomSiteBIsoOrEquiv => ValueType::Measurand,
+            CoreItem::AtomSiteBIsoOrEquivSu => ValueType::Real,
+            CoreItem::AtomSiteCalcAttachedAtom => ValueType::Text,
+            CoreItem::AtomSiteCalcFlag => ValueType::Text,
+            CoreItem::AtomSiteCartnX => ValueType::Measurand,
+            CoreItem::AtomSiteCartnXSu => ValueType::Real,
+            CoreItem::AtomSiteCartnY => ValueType::Measurand,
+            CoreItem::AtomSiteCartnYSu => ValueType::Real,
+            CoreItem::AtomSiteCartnZ => ValueType::Measurand,
+            CoreItem::AtomSiteCartnZSu => ValueType::Real,
+            CoreItem::AtomSiteChemicalConnNumber => ValueType::Integer,
+            CoreItem::AtomSiteConstraints => ValueType::Text,
+            CoreItem::AtomSiteDescription => ValueType::Text,
+            CoreItem::AtomSiteDisorderAssembly => ValueType::Text,
+            CoreItem::AtomSiteDisorderGroup => ValueType::Text,
+            CoreItem::AtomSiteFractSymmform => ValueType::Text,
+            CoreItem::AtomSiteFractX => ValueType::Measurand,
+            CoreItem::AtomSiteFractXSu => ValueType::Real,
+            CoreItem::AtomSiteFractY => ValueType::Measurand,
+            CoreItem::AtomSiteFractYSu => ValueType::Real,
+            CoreItem::AtomSiteFractZ => ValueType::Measurand,
+            CoreItem::AtomSiteFractZSu => ValueType::Real,
+            CoreItem::AtomSiteLabel => ValueType::Text,
+            CoreItem::AtomSiteLabelComponent1 => ValueType::Text,
+            CoreItem::AtomSiteLabelComponent2 => ValueType::Text,
+            CoreItem::AtomSiteLabelComponent3 => ValueType::Text,
+            CoreItem::AtomSiteLabelComponent4 => ValueType::Text,
+            CoreItem::AtomSiteLabelComponent5 => ValueType::Text,
+            CoreItem::AtomSiteLabelComponent6 => ValueType::Text,
+            CoreItem::AtomSiteOccupancy => ValueType::Measurand,
+            CoreItem::AtomSiteOccupancySu => ValueType::Real,
+            CoreItem::AtomSiteRefinementFlags => ValueType::Text,
+            CoreItem::AtomSiteRefinementFlagsAdp => ValueType::Text,
+            CoreItem::AtomSiteRefinementFlagsOccupancy => ValueType::Text,
+            CoreItem::AtomSiteRefinementFlagsPosn => ValueType::Text,
+            CoreItem::AtomSiteRestraints => ValueType::Text,
+            CoreItem::AtomSiteSiteSymmetryMultiplicity => ValueType::Integer,
+            CoreItem::AtomSiteSiteSymmetryOrder => ValueType::Integer,
+            CoreItem::AtomSiteTypeSymbol => ValueType::Text,
+            CoreItem::AtomSiteUEquivGeomMean => ValueType::Measurand,
+            CoreItem::AtomSiteUEquivGeomMeanSu => ValueType::Real,
+            CoreItem::AtomSiteUIsoOrEquiv => ValueType::Measurand,
+            CoreItem::AtomSiteUIsoOrEquivSu => ValueType::Real,
+            CoreItem::AtomSiteWyckoffSymbol => ValueType::Text,
+            CoreItem::AtomSiteAnisoB11 => ValueType::Measurand,
+            CoreItem::AtomSiteAnisoB11Su => ValueType::Real,
+            CoreItem::AtomSiteAnisoB12 => ValueType::Measurand,
+            CoreItem::AtomSiteAnisoB12Su => ValueType::Real,
+            CoreItem::AtomSiteAnisoB13 => ValueType::Measurand,
+            CoreItem::AtomSiteAnisoB13Su => ValueType::Real,
+            CoreItem::AtomSiteAnisoB22 => ValueType::Measurand,
+            CoreItem::AtomSiteAnisoB22Su => ValueType::Real,
+            CoreItem::AtomSiteAnisoB23 => ValueType::Measurand,
+            CoreItem::AtomSiteAnisoB23Su => ValueType::Real,
+            CoreItem::AtomSiteAnisoB33 => ValueType::Measurand,
+            CoreItem::AtomSiteAnisoB33Su => ValueType::Real,
+            CoreItem::AtomSiteAnisoBeta11 => ValueType::Measurand,
+            CoreItem::AtomSiteAnisoBeta11Su => ValueType::Real,
+            CoreItem::AtomSiteAnisoBeta12 => ValueType::Measurand,
+            CoreItem::AtomSiteAnisoBeta12Su => ValueType::Real,
+            CoreItem::AtomSiteAnisoBeta13 => ValueType::Measurand,
+            CoreItem::AtomSiteAnisoBeta13Su => ValueType::Real,
+            CoreItem::AtomSiteAnisoBeta22 => ValueType::Measurand,
+            CoreItem::AtomSiteAnisoBeta22Su => ValueType::Real,
+            CoreItem::AtomSiteAnisoBeta23 => ValueType::Measurand,
+            CoreItem::AtomSiteAnisoBeta23Su => ValueType::Real,
+            CoreItem::AtomSiteAnisoBeta33 => ValueType::Measurand,
+            CoreItem::AtomSiteAnisoBeta33Su => ValueType::Real,
+            CoreItem::AtomSiteAnisoLabel => ValueType::Text,
+            CoreItem::AtomSiteAnisoRatio => ValueType::Real,
+            CoreItem::AtomSiteAnisoSymmform => ValueType::Text,
+            CoreItem::AtomSiteAnisoTypeSymbol => ValueType::Text,
+            CoreItem::AtomSiteAnisoU11 => ValueType::Measurand,
+            CoreItem::AtomSiteAnisoU11Su => ValueType::Real,
+            CoreItem::AtomSiteAnisoU12 => ValueType::Measurand,
+            CoreItem::AtomSiteAnisoU12Su => ValueType::Real,
+            CoreItem::AtomSiteAnisoU13 => ValueType::Measurand,
+            CoreItem::AtomSiteAnisoU13Su => ValueType::Real,
+            CoreItem::AtomSiteAnisoU22 => ValueType::Measurand,
+            CoreItem::AtomSiteAnisoU22Su => ValueType::Real,
+            CoreItem::AtomSiteAnisoU23 => ValueType::Measurand,
+            CoreItem::AtomSiteAnisoU23Su => ValueType::Real,
+            CoreItem::AtomSiteAnisoU33 => ValueType::Measurand,
+            CoreItem::AtomSiteAnisoU33Su => ValueType::Real,
+            CoreItem::AtomSitesSolutionHydrogens => ValueType::Text,
+            CoreItem::AtomSitesSolutionPrimary => ValueType::Text,
+            CoreItem::AtomSitesSolutionSecondary => ValueType::Text,
+            CoreItem::AtomSitesSpecialDetails => ValueType::Text,
+            CoreItem::AtomTypeAnalyticalMassPercent => ValueType::Measurand,
+            CoreItem::AtomTypeAnalyticalMassPercentSu => ValueType::Real,
+            CoreItem::AtomTypeAtomicMass => ValueType::Real,
+            CoreItem::AtomTypeAtomicNumber => ValueType::Integer,
+            CoreItem::AtomTypeDescription => ValueType::Text,
+            CoreItem::AtomTypeDisplayColour => ValueType::Text,
+            CoreItem::AtomTypeElectronCount => ValueType::Integer,
+            CoreItem::AtomTypeElementSymbol => ValueType::Text,
+            CoreItem::AtomTypeMassNumber => ValueType::Integer,
+            CoreItem::AtomTypeNumberInCell => ValueType::Real,
+            CoreItem::AtomTypeOxidationNumber => ValueType::Integer,
+            CoreItem::AtomTypeRadiusBond => ValueType::Real,
+            CoreItem::AtomTypeRadiusContact => ValueType::Real,
+            CoreItem::AtomTypeSymbol => ValueType::Text,
+            CoreItem::AuditBlockCode => ValueType::Text,
+            CoreItem::AuditBlockDoi => ValueType::Text,
+            CoreItem::AuditCreationDate => ValueType::Text,
+            CoreItem::AuditCreationMethod => ValueType::Text,
+            CoreItem::AuditSchema => ValueType::Text,
+            CoreItem::AuditUpdateRecord => ValueType::Text,
+            CoreItem::CellAngleAlpha => ValueType::Measurand,
+            CoreItem::CellAngleAlphaSu => ValueType::Real,
+            CoreItem::CellAngleBeta => ValueType::Measurand,
+            CoreItem::CellAngleBetaSu => ValueType::Real,
+            CoreItem::CellAngleGamma => ValueType::Measurand,
+            CoreItem::CellAngleGammaSu => ValueType::Real,
+            CoreItem::CellAtomicMass => ValueType::Real,
+            CoreItem::CellDiffrnId => ValueType::Text,
+            CoreItem::CellFormulaUnitsZ => ValueType::Real,
+            CoreItem::CellFormulaUnitsZDetails => ValueType::Text,
+            CoreItem::CellLengthA => ValueType::Measurand,
+            CoreItem::CellLengthASu => ValueType::Real,
+            CoreItem::CellLengthB => ValueType::Measurand,
+            CoreItem::CellLengthBSu => ValueType::Real,
+            CoreItem::CellLengthC => ValueType::Measurand,
+            CoreItem::CellLengthCSu => ValueType::Real,
+            CoreItem::CellReciprocalAngleAlpha => ValueType::Measurand,
+            CoreItem::CellReciprocalAngleAlphaSu => ValueType::Real,
+            CoreItem::CellReciprocalAngleBeta => ValueType::Measurand,
+            CoreItem::CellReciprocalAngleBetaSu => ValueType::Real,
+            CoreItem::CellReciprocalAngleGamma => ValueType::Measurand,
+            CoreItem::CellReciprocalAngleGammaSu => ValueType::Real,
+            CoreItem::CellReciprocalLengthA => ValueType::Measurand,
+            CoreItem::CellReciprocalLengthASu => ValueType::Real,
+            CoreItem::CellReciprocalLengthB => ValueType::Measurand,
+            CoreItem::CellReciprocalLengthBSu => ValueType::Real,
+            CoreItem::CellReciprocalLengthC => ValueType::Measurand,
+            CoreItem::CellReciprocalLengthCSu => ValueType::Real,
+            CoreItem::CellSpecialDetails => ValueType::Text,
+            CoreItem::CellVolume => ValueType::Measurand,
+            CoreItem::CellVolumeSu => ValueType::Real,
+            CoreItem::CellMeasurementConditionId => ValueType::Text,
+            CoreItem::CellMeasurementDiffrnId => ValueType::Text,
+            CoreItem::CellMeasurementPressure => ValueType::Measurand,
+            CoreItem::CellMeasurementPressureSu => ValueType::Real,
+            CoreItem::CellMeasurementRadiation => ValueType::Text,
+            CoreItem::CellMeasurementReflnsUsed => ValueType::Integer,
+            CoreItem::CellMeasurementTemperature => ValueType::Measurand,
+            CoreItem::CellMeasurementTemperatureSu => ValueType::Real,
+            CoreItem::CellMeasurementThetaMax => ValueType::Real,
+            CoreItem::CellMeasurementThetaMin => ValueType::Real,
+            CoreItem::CellMeasurementWavelength => ValueType::Measurand,
+            CoreItem::CellMeasurementWavelengthSu => ValueType::Real,
+            CoreItem::ChemicalAbsoluteConfiguration => ValueType::Text,
+            CoreItem::ChemicalCompoundSource => ValueType::Text,
+            CoreItem::ChemicalEnantioexcessBulk => ValueType::Measurand,
+            CoreItem::ChemicalEnantioexcessBulkSu => ValueType::Real,
+            CoreItem::ChemicalEnantioexcessBulkTechnique => ValueType::Text,
+            CoreItem::ChemicalEnantioexcessCrystal => ValueType::Measurand,
+            CoreItem::ChemicalEnantioexcessCrystalSu => ValueType::Real,
+            CoreItem::ChemicalEnantioexcessCrystalTechnique => ValueType::Text,
+            CoreItem::ChemicalIdentifierInchi => ValueType::Text,
+            CoreItem::ChemicalIdentifierInchiKey => ValueType::Text,
+            CoreItem::ChemicalIdentifierInchiVersion => ValueType::Text,
+            CoreItem::ChemicalMeltingPoint => ValueType::Measurand,
+            CoreItem::ChemicalMeltingPointGt => ValueType::Real,
+            CoreItem::ChemicalMeltingPointLt => ValueType::Real,
+            CoreItem::ChemicalMeltingPointSu => ValueType::Real,
+            CoreItem::ChemicalNameCommon => ValueType::Text,
+            CoreItem::ChemicalNameMineral => ValueType::Text,
+            CoreItem::ChemicalNameStructureType => ValueType::Text,
+            CoreItem::ChemicalNameSystematic => ValueType::Text,
+            CoreItem::ChemicalOpticalRotation => ValueType::Text,
+            CoreItem::ChemicalPropertiesBiological => ValueType::Text,
+            CoreItem::ChemicalPropertiesPhysical => ValueType::Text,
+            CoreItem::ChemicalTemperatureDecomposition => ValueType::Measurand,
+            CoreItem::ChemicalTemperatureDecompositionGt => ValueType::Real,
+            CoreItem::ChemicalTemperatureDecompositionLt => ValueType::Real,
+            CoreItem::ChemicalTemperatureDecompositionSu => ValueType::Real,
+            CoreItem::ChemicalTemperatureSublimation => ValueType::Measurand,
+            CoreItem::ChemicalTemperatureSublimationGt => ValueType::Real,
+            CoreItem::ChemicalTemperatureSublimationLt => ValueType::Real,
+            CoreItem::ChemicalTemperatureSublimationSu => ValueType::Real,
+            CoreItem::ChemicalFormulaAnalytical => ValueType::Text,
+            CoreItem::ChemicalFormulaIupac => ValueType::Text,
+            CoreItem::ChemicalFormulaMoiety => ValueType::Text,
+            CoreItem::ChemicalFormulaStructural => ValueType::Text,
+            CoreItem::ChemicalFormulaSum => ValueType::Text,
+            CoreItem::ChemicalFormulaWeight => ValueType::Real,
+            CoreItem::ChemicalFormulaWeightMeas => ValueType::Measurand,
+            CoreItem::ChemicalFormulaWeightMeasSu => ValueType::Real,
+            CoreItem::ComputingCellRefinement => ValueType::Text,
+            CoreItem::ComputingDiffrnCollection => ValueType::Text,
+            CoreItem::ComputingDiffrnReduction => ValueType::Text,
+            CoreItem::ComputingMolecularGraphics => ValueType::Text,
+            CoreItem::ComputingPublicationMaterial => ValueType::Text,
+            CoreItem::ComputingSampleTracking => ValueType::Text,
+            CoreItem::ComputingStructureRefinement => ValueType::Text,
+            CoreItem::ComputingStructureSolution => ValueType::Text,
+            CoreItem::DiffrnAmbientEnvironment => ValueType::Text,
+            CoreItem::DiffrnAmbientPressure => ValueType::Measurand,
+            CoreItem::DiffrnAmbientPressureGt => ValueType::Real,
+            CoreItem::DiffrnAmbientPressureLt => ValueType::Real,
+            CoreItem::DiffrnAmbientPressureSu => ValueType::Real,
+            CoreItem::DiffrnAmbientTemperature => ValueType::Measurand,
+            CoreItem::DiffrnAmbientTemperatureDetails => ValueType::Text,
+            CoreItem::DiffrnAmbientTemperatureGt => ValueType::Real,
+            CoreItem::DiffrnAmbientTemperatureLt => ValueType::Real,
+            CoreItem::DiffrnAmbientTemperatureSu => ValueType::Real,
+            CoreItem::DiffrnCrystalId => ValueType::Text,
+            CoreItem::DiffrnCrystalSupport => ValueType::Text,
+            CoreItem::DiffrnCrystalTreatment => ValueType::Text,
+            CoreItem::DiffrnFluxDensity => ValueType::Real,
+            CoreItem::DiffrnId => ValueType::Text,
+            CoreItem::DiffrnMeasuredFractionThetaFull => ValueType::Real,
+            CoreItem::DiffrnMeasuredFractionThetaMax => ValueType::Real,
+            CoreItem::DiffrnPrecessionSemiAngle => ValueType::Measurand,
+            CoreItem::DiffrnPrecessionSemiAngleSu => ValueType::Real,
+            CoreItem::DiffrnSpecialDetails => ValueType::Text,
+            CoreItem::DiffrnSymmetryDescription => ValueType::Text,
+            CoreItem::DiffrnTotalDose => ValueType::Measurand,
+            CoreItem::DiffrnTotalDoseSu => ValueType::Real,
+            CoreItem::DiffrnTotalExposureTime => ValueType::Real,
+            CoreItem::DiffrnDetectorAreaResolMean => ValueType::Real,
+            CoreItem::DiffrnDetectorDescription => ValueType::Text,
+            CoreItem::DiffrnDetectorDetails => ValueType::Text,
+            CoreItem::DiffrnDetectorDtime => ValueType::Real,
+            CoreItem::DiffrnDetectorMake => ValueType::Text,
+            CoreItem::DiffrnMeasurementDetails => ValueType::Text,
+            CoreItem::DiffrnMeasurementDeviceClass => ValueType::Text,
+            CoreItem::DiffrnMeasurementDeviceDetails => ValueType::Text,
+            CoreItem::DiffrnMeasurementDeviceMake => ValueType::Text,
+            CoreItem::DiffrnMeasurementMethod => ValueType::Text,
+            CoreItem::DiffrnMeasurementMethodPrecession => ValueType::Text,
+            CoreItem::DiffrnMeasurementRotationMode => ValueType::Text,
+            CoreItem::DiffrnMeasurementSampleTracking => ValueType::Text,
+            CoreItem::DiffrnMeasurementSampleTrackingMethod => ValueType::Text,
+            CoreItem::DiffrnMeasurementSpecimenAttachmentType => ValueType::Text,
+            CoreItem::DiffrnMeasurementSpecimenSupport => ValueType::Text,
+            CoreItem::DiffrnRadiationCollimation => ValueType::Text,
+            CoreItem::DiffrnRadiationFilterEdge => ValueType::Real,
+            CoreItem::DiffrnRadiationIlluminationMode => ValueType::Text,
+            CoreItem::DiffrnRadiationInhomogeneity => ValueType::Real,
+            CoreItem::DiffrnRadiationMonochromator => ValueType::Text,
+            CoreItem::DiffrnRadiationPolarisnNorm => ValueType::Real,
+            CoreItem::DiffrnRadiationPolarisnRatio => ValueType::Real,
+            CoreItem::DiffrnRadiationProbe => ValueType::Text,
+            CoreItem::DiffrnRadiationType => ValueType::Text,
+            CoreItem::DiffrnRadiationXraySymbol => ValueType::Text,
+            CoreItem::DiffrnRadiationWavelengthDetails => ValueType::Text,
+            CoreItem::DiffrnRadiationWavelengthDetermination => ValueType::Text,
+            CoreItem::DiffrnRadiationWavelengthId => ValueType::Text,
+            CoreItem::DiffrnRadiationWavelengthType => ValueType::Text,
+            CoreItem::DiffrnRadiationWavelengthValue => ValueType::Measurand,
+            CoreItem::DiffrnRadiationWavelengthValueSu => ValueType::Real,
+            CoreItem::DiffrnRadiationWavelengthWt => ValueType::Real,
+            CoreItem::DiffrnRadiationWavelengthXraySymbol => ValueType::Text,
+            CoreItem::DiffrnReflnsAvREquivalents => ValueType::Real,
+            CoreItem::DiffrnReflnsAvSunetiOverNeti => ValueType::Real,
+            CoreItem::DiffrnReflnsLaueMeasuredFractionFull => ValueType::Real,
+            CoreItem::DiffrnReflnsLaueMeasuredFractionMax => ValueType::Real,
+            CoreItem::DiffrnReflnsLimitHMax => ValueType::Integer,
+            CoreItem::DiffrnReflnsLimitHMin => ValueType::Integer,
+            CoreItem::DiffrnReflnsLimitKMax => ValueType::Integer,
+            CoreItem::DiffrnReflnsLimitKMin => ValueType::Integer,
+            CoreItem::DiffrnReflnsLimitLMax => ValueType::Integer,
+            CoreItem::DiffrnReflnsLimitLMin => ValueType::Integer,
+            CoreItem::DiffrnReflnsNumber => ValueType::Integer,
+            CoreItem::DiffrnReflnsPointMeasuredFractionFull => ValueType::Real,
+            CoreItem::DiffrnReflnsPointMeasuredFractionMax => ValueType::Real,
+            CoreItem::DiffrnReflnsReductionProcess => ValueType::Text,
+            CoreItem::DiffrnReflnsResolutionFull => ValueType::Real,
+            CoreItem::DiffrnReflnsResolutionMax => ValueType::Real,
+            CoreItem::DiffrnReflnsThetaFull => ValueType::Real,
+            CoreItem::DiffrnReflnsThetaMax => ValueType::Real,
+            CoreItem::DiffrnReflnsThetaMin => ValueType::Real,
+            CoreItem::DiffrnSourceBeamline => ValueType::Text,
+            CoreItem::DiffrnSourceConvergenceAngle => ValueType::Real,
+            CoreItem::DiffrnSourceCurrent => ValueType::Real,
+            CoreItem::DiffrnSourceDescription => ValueType::Text,
+            CoreItem::DiffrnSourceDetails => ValueType::Text,
+            CoreItem::DiffrnSourceDevice => ValueType::Text,
+            CoreItem::DiffrnSourceEdDiffractingAreaSelection => ValueType::Text,
+            CoreItem::DiffrnSourceFacility => ValueType::Text,
+            CoreItem::DiffrnSourceMake => ValueType::Text,
+            CoreItem::DiffrnSourcePower => ValueType::Real,
+            CoreItem::DiffrnSourceSize => ValueType::Text,
+            CoreItem::DiffrnSourceTakeOffAngle => ValueType::Real,
+            CoreItem::DiffrnSourceTarget => ValueType::Text,
+            CoreItem::DiffrnSourceVoltage => ValueType::Real,
+            CoreItem::DiffrnStandardsDecayPercent => ValueType::Measurand,
+            CoreItem::DiffrnStandardsDecayPercentSu => ValueType::Real,
+            CoreItem::DiffrnStandardsIntervalCount => ValueType::Integer,
+            CoreItem::DiffrnStandardsIntervalTime => ValueType::Real,
+            CoreItem::DiffrnStandardsNumber => ValueType::Integer,
+            CoreItem::DiffrnStandardsScaleSuAverage => ValueType::Measurand,
+            CoreItem::DiffrnStandardsScaleSuAverageSu => ValueType::Real,
+            CoreItem::ExptlCrystalsNumber => ValueType::Integer,
+            CoreItem::ExptlMethod => ValueType::Text,
+            CoreItem::ExptlMethodDetails => ValueType::Text,
+            CoreItem::ExptlSpecialDetails => ValueType::Text,
+            CoreItem::ExptlTransmissionFactorMax => ValueType::Measurand,
+            CoreItem::ExptlTransmissionFactorMaxSu => ValueType::Real,
+            CoreItem::ExptlTransmissionFactorMin => ValueType::Measurand,
+            CoreItem::ExptlTransmissionFactorMinSu => ValueType::Real,
+            CoreItem::ExptlAbsorptCoefficientMu => ValueType::Measurand,
+            CoreItem::ExptlAbsorptCoefficientMuSu => ValueType::Real,
+            CoreItem::ExptlAbsorptCorrectionTMax => ValueType::Real,
+            CoreItem::ExptlAbsorptCorrectionTMin => ValueType::Real,
+            CoreItem::ExptlAbsorptCorrectionType => ValueType::Text,
+            CoreItem::ExptlAbsorptProcessDetails => ValueType::Text,
+            CoreItem::ExptlAbsorptSpecialDetails => ValueType::Text,
+            CoreItem::ExptlCrystalColour => ValueType::Text,
+            CoreItem::ExptlCrystalDensityDiffrn => ValueType::Measurand,
+            CoreItem::ExptlCrystalDensityDiffrnSu => ValueType::Real,
+            CoreItem::ExptlCrystalDensityMeas => ValueType::Measurand,
+            CoreItem::ExptlCrystalDensityMeasGt => ValueType::Real,
+            CoreItem::ExptlCrystalDensityMeasLt => ValueType::Real,
+            CoreItem::ExptlCrystalDensityMeasSu => ValueType::Real,
+            CoreItem::ExptlCrystalDensityMeasTemp => ValueType::Measurand,
+            CoreItem::ExptlCrystalDensityMeasTempGt => ValueType::Real,
+            CoreItem::ExptlCrystalDensityMeasTempLt => ValueType::Real,
+            CoreItem::ExptlCrystalDensityMeasTempSu => ValueType::Real,
+            CoreItem::ExptlCrystalDensityMethod => ValueType::Text,
+            CoreItem::ExptlCrystalDescription => ValueType::Text,
+            CoreItem::ExptlCrystalF000 => ValueType::Real,
+            CoreItem::ExptlCrystalId => ValueType::Text,
+            CoreItem::ExptlCrystalMosaicBlockSize => ValueType::Measurand,
+            CoreItem::ExptlCrystalMosaicBlockSizeSu => ValueType::Real,
+            CoreItem::ExptlCrystalMosaicMethod => ValueType::Text,
+            CoreItem::ExptlCrystalMosaicity => ValueType::Measurand,
+            CoreItem::ExptlCrystalMosaicitySu => ValueType::Real,
+            CoreItem::ExptlCrystalPreparation => ValueType::Text,
+            CoreItem::ExptlCrystalPressureHistory => ValueType::Text,
+            CoreItem::ExptlCrystalRecrystallizationMethod => ValueType::Text,
+            CoreItem::ExptlCrystalSizeLength => ValueType::Measurand,
+            CoreItem::ExptlCrystalSizeLengthSu => ValueType::Real,
+            CoreItem::ExptlCrystalSizeMax => ValueType::Measurand,
+            CoreItem::ExptlCrystalSizeMaxSu => ValueType::Real,
+            CoreItem::ExptlCrystalSizeMid => ValueType::Measurand,
+            CoreItem::ExptlCrystalSizeMidSu => ValueType::Real,
+            CoreItem::ExptlCrystalSizeMin => ValueType::Measurand,
+            CoreItem::ExptlCrystalSizeMinSu => ValueType::Real,
+            CoreItem::ExptlCrystalSizeRad => ValueType::Measurand,
+            CoreItem::ExptlCrystalSizeRadSu => ValueType::Real,
+            CoreItem::ExptlCrystalThermalHistory => ValueType::Text,
+            CoreItem::GeomAngleAtomSiteLabel1 => ValueType::Text,
+            CoreItem::GeomAngleAtomSiteLabel2 => ValueType::Text,
+            CoreItem::GeomAngleAtomSiteLabel3 => ValueType::Text,
+            CoreItem::GeomAngleId => ValueType::Text,
+            CoreItem::GeomAnglePublFlag => ValueType::Text,
+            CoreItem::GeomAngleValue => ValueType::Measurand,
+            CoreItem::GeomAngleValueSu => ValueType::Real,
+            CoreItem::GeomBondAtomSiteLabel1 => ValueType::Text,
+            CoreItem::GeomBondAtomSiteLabel2 => ValueType::Text,
+            CoreItem::GeomBondDistance => ValueType::Measurand,
+            CoreItem::GeomBondDistanceSu => ValueType::Real,
+            CoreItem::GeomBondId => ValueType::Text,
+            CoreItem::GeomBondMultiplicity => ValueType::Integer,
+            CoreItem::GeomBondPublFlag => ValueType::Text,
+            CoreItem::GeomBondValence => ValueType::Measurand,
+            CoreItem::GeomBondValenceSu => ValueType::Real,
+            CoreItem::GeomHbondAngleDha => ValueType::Measurand,
+            CoreItem::GeomHbondAngleDhaSu => ValueType::Real,
+            CoreItem::GeomHbondAtomSiteLabelA => ValueType::Text,
+            CoreItem::GeomHbondAtomSiteLabelD => ValueType::Text,
+            CoreItem::GeomHbondAtomSiteLabelH => ValueType::Text,
+            CoreItem::GeomHbondDistanceDa => ValueType::Measurand,
+            CoreItem::GeomHbondDistanceDaSu => ValueType::Real,
+            CoreItem::GeomHbondDistanceDh => ValueType::Measurand,
+            CoreItem::GeomHbondDistanceDhSu => ValueType::Real,
+            CoreItem::GeomHbondDistanceHa => ValueType::Measurand,
+            CoreItem::GeomHbondDistanceHaSu => ValueType::Real,
+            CoreItem::GeomHbondId => ValueType::Text,
+            CoreItem::GeomHbondPublFlag => ValueType::Text,
+            CoreItem::GeomTorsionAngle => ValueType::Measurand,
+            CoreItem::GeomTorsionAngleSu => ValueType::Real,
+            CoreItem::GeomTorsionAtomSiteLabel1 => ValueType::Text,
+            CoreItem::GeomTorsionAtomSiteLabel2 => ValueType::Text,
+            CoreItem::GeomTorsionAtomSiteLabel3 => ValueType::Text,
+            CoreItem::GeomTorsionAtomSiteLabel4 => ValueType::Text,
+            CoreItem::GeomTorsionId => ValueType::Text,
+            CoreItem::GeomTorsionPublFlag => ValueType::Text,
+            CoreItem::RefineDiffractionTheory => ValueType::Text,
+            CoreItem::RefineDiffractionTheoryDetails => ValueType::Text,
+            CoreItem::RefineSpecialDetails => ValueType::Text,
+            CoreItem::RefineDiffDensityMax => ValueType::Measurand,
+            CoreItem::RefineDiffDensityMaxSu => ValueType::Real,
+            CoreItem::RefineDiffDensityMin => ValueType::Measurand,
+            CoreItem::RefineDiffDensityMinSu => ValueType::Real,
+            CoreItem::RefineDiffDensityRms => ValueType::Measurand,
+            CoreItem::RefineDiffDensityRmsSu => ValueType::Real,
+            CoreItem::RefineDiffPotentialMax => ValueType::Measurand,
+            CoreItem::RefineDiffPotentialMaxSu => ValueType::Real,
+            CoreItem::RefineDiffPotentialMin => ValueType::Measurand,
+            CoreItem::RefineDiffPotentialMinSu => ValueType::Real,
+            CoreItem::RefineDiffPotentialRms => ValueType::Measurand,
+            CoreItem::RefineDiffPotentialRmsSu => ValueType::Real,
+            CoreItem::RefineLsAbsStructureDetails => ValueType::Text,
+            CoreItem::RefineLsAbsStructureFlack => ValueType::Measurand,
+            CoreItem::RefineLsAbsStructureFlackSu => ValueType::Real,
+            CoreItem::RefineLsAbsStructureRogers => ValueType::Measurand,
+            CoreItem::RefineLsAbsStructureRogersSu => ValueType::Real,
+            CoreItem::RefineLsAbsStructureZScore => ValueType::Real,
+            CoreItem::RefineLsDResHigh => ValueType::Real,
+            CoreItem::RefineLsDResLow => ValueType::Real,
+            CoreItem::RefineLsExtinctionCoef => ValueType::Measurand,
+            CoreItem::RefineLsExtinctionCoefSu => ValueType::Real,
+            CoreItem::RefineLsExtinctionExpression => ValueType::Text,
+            CoreItem::RefineLsExtinctionMethod => ValueType::Text,
+            CoreItem::RefineLsFCalcDetails => ValueType::Text,
+            CoreItem::RefineLsFCalcFormula => ValueType::Text,
+            CoreItem::RefineLsFCalcPrecision => ValueType::Measurand,
+            CoreItem::RefineLsFCalcPrecisionSu => ValueType::Real,
+            CoreItem::RefineLsGoodnessOfFitAll => ValueType::Measurand,
+            CoreItem::RefineLsGoodnessOfFitAllSu => ValueType::Real,
+            CoreItem::RefineLsGoodnessOfFitGt => ValueType::Measurand,
+            CoreItem::RefineLsGoodnessOfFitGtSu => ValueType::Real,
+            CoreItem::RefineLsGoodnessOfFitRef => ValueType::Measurand,
+            CoreItem::RefineLsGoodnessOfFitRefSu => ValueType::Real,
+            CoreItem::RefineLsHydrogenTreatment => ValueType::Text,
+            CoreItem::RefineLsMatrixType => ValueType::Text,
+            CoreItem::RefineLsNumberConstraints => ValueType::Integer,
+            CoreItem::RefineLsNumberParameters => ValueType::Integer,
+            CoreItem::RefineLsNumberReflns => ValueType::Integer,
+            CoreItem::RefineLsNumberReflnsGt => ValueType::Integer,
+            CoreItem::RefineLsNumberRestraints => ValueType::Integer,
+            CoreItem::RefineLsRFactorAll => ValueType::Real,
+            CoreItem::RefineLsRFactorGt => ValueType::Real,
+            CoreItem::RefineLsRFsqdFactor => ValueType::Real,
+            CoreItem::RefineLsRIFactor => ValueType::Real,
+            CoreItem::RefineLsRestrainedSAll => ValueType::Measurand,
+            CoreItem::RefineLsRestrainedSAllSu => ValueType::Real,
+            CoreItem::RefineLsRestrainedSGt => ValueType::Measurand,
+            CoreItem::RefineLsRestrainedSGtSu => ValueType::Real,
+            CoreItem::RefineLsSampleShapeDetails => ValueType::Text,
+            CoreItem::RefineLsSampleShapeExpression => ValueType::Text,
+            CoreItem::RefineLsSampleThickness => ValueType::Measurand,
+            CoreItem::RefineLsSampleThicknessSu => ValueType::Real,
+            CoreItem::RefineLsShiftOverSuMax => ValueType::Real,
+            CoreItem::RefineLsShiftOverSuMaxLt => ValueType::Real,
+            CoreItem::RefineLsShiftOverSuMean => ValueType::Real,
+            CoreItem::RefineLsShiftOverSuMeanLt => ValueType::Real,
+            CoreItem::RefineLsStructureFactorCoef => ValueType::Text,
+            CoreItem::RefineLsWeightingDetails => ValueType::Text,
+            CoreItem::RefineLsWeightingScheme => ValueType::Text,
+            CoreItem::RefineLsWrFactorAll => ValueType::Real,
+            CoreItem::RefineLsWrFactorGt => ValueType::Real,
+            CoreItem::RefineLsWrFactorRef => ValueType::Real,
+            CoreItem::ReflnsApplyDispersionToFcalc => ValueType::Text,
+            CoreItem::ReflnsDResolutionHigh => ValueType::Real,
+            CoreItem::ReflnsDResolutionLow => ValueType::Real,
+            CoreItem::ReflnsFriedelCoverage => ValueType::Real,
+            CoreItem::ReflnsFriedelFractionFull => ValueType::Real,
+            CoreItem::ReflnsFriedelFractionMax => ValueType::Real,
+            CoreItem::ReflnsLimitHMax => ValueType::Integer,
+            CoreItem::ReflnsLimitHMin => ValueType::Integer,
+            CoreItem::ReflnsLimitKMax => ValueType::Integer,
+            CoreItem::ReflnsLimitKMin => ValueType::Integer,
+            CoreItem::ReflnsLimitLMax => ValueType::Integer,
+            CoreItem::ReflnsLimitLMin => ValueType::Integer,
+            CoreItem::ReflnsNumberGt => ValueType::Integer,
+            CoreItem::ReflnsNumberTotal => ValueType::Integer,
+            CoreItem::ReflnsSpecialDetails => ValueType::Text,
+            CoreItem::ReflnsThresholdExpression => ValueType::Text,
+            CoreItem::SpaceGroupBravaisType => ValueType::Text,
+            CoreItem::SpaceGroupCentringType => ValueType::Text,
+            CoreItem::SpaceGroupCrystalSystem => ValueType::Text,
+            CoreItem::SpaceGroupItCoordinateSystemCode => ValueType::Text,
+            CoreItem::SpaceGroupItNumber => ValueType::Integer,
+            CoreItem::SpaceGroupLaueClass => ValueType::Text,
+            CoreItem::SpaceGroupMultiplicity => ValueType::Integer,
+            CoreItem::SpaceGroupNameHMAlt => ValueType::Text,
+            CoreItem::SpaceGroupNameHMAltDescription => ValueType::Text,
+            CoreItem::SpaceGroupNameHMFull => ValueType::Text,
+            CoreItem::SpaceGroupNameHMRef => ValueType::Text,
+            CoreItem::SpaceGroupNameHall => ValueType::Text,
+            CoreItem::SpaceGroupNameSchoenflies => ValueType::Text,
+            CoreItem::SpaceGroupPattersonNameHM => ValueType::Text,
+            CoreItem::SpaceGroupPointGroupHM => ValueType::Text,
+            CoreItem::SpaceGroupSymopId => ValueType::Integer,
+            CoreItem::SpaceGroupSymopOperationDescription => ValueType::Text,
+            CoreItem::SpaceGroupSymopOperationXyz => ValueType::Text,
+            CoreItem::SymmetryCellSetting => ValueType::Text,
+        }
+    }
+}
+
+/// Every [`CoreItem`] with the Rust type of its values, for
+/// [`CoreItemsExt::get`](crate::CoreItemsExt::get).
+///
+/// Constants are named after the canonical (DDLm) data name in upper
+/// snake case: `_cell.length_a` is `CELL_LENGTH_A`.
+pub mod items {
+    use crate::{CoreItem, TypedItem};
+    use cif_parser::Measurand;
+
+    /// `_atom_site.ADP_type`
+    pub const ATOM_SITE_ADP_TYPE: TypedItem<String> = TypedItem::new(CoreItem::AtomSiteAdpType);
+
+    /// `_atom_site.attached_hydrogens`
+    pub const ATOM_SITE_ATTACHED_HYDROGENS: TypedItem<i64> = TypedItem::new(CoreItem::AtomSiteAttachedHydrogens);
+
+    /// `_atom_site.B_equiv_geom_mean`
+    pub const ATOM_SITE_B_EQUIV_GEOM_MEAN: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomSiteBEquivGeomMean);
+
+    /// `_atom_site.B_equiv_geom_mean_su`
+    pub const ATOM_SITE_B_EQUIV_GEOM_MEAN_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteBEquivGeomMeanSu);
+
+    /// `_atom_site.B_iso_or_equiv`
+    pub const ATOM_SITE_B_ISO_OR_EQUIV: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomSiteBIsoOrEquiv);
+
+    /// `_atom_site.B_iso_or_equiv_su`
+    pub const ATOM_SITE_B_ISO_OR_EQUIV_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteBIsoOrEquivSu);
+
+    /// `_atom_site.calc_attached_atom`
+    pub const ATOM_SITE_CALC_ATTACHED_ATOM: TypedItem<String> = TypedItem::new(CoreItem::AtomSiteCalcAttachedAtom);
+
+    /// `_atom_site.calc_flag`
+    pub const ATOM_SITE_CALC_FLAG: TypedItem<String> = TypedItem::new(CoreItem::AtomSiteCalcFlag);
+
+    /// `_atom_site.Cartn_x`
+    pub const ATOM_SITE_CARTN_X: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomSiteCartnX);
+
+    /// `_atom_site.Cartn_x_su`
+    pub const ATOM_SITE_CARTN_X_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteCartnXSu);
+
+    /// `_atom_site.Cartn_y`
+    pub const ATOM_SITE_CARTN_Y: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomSiteCartnY);
+
+    /// `_atom_site.Cartn_y_su`
+    pub const ATOM_SITE_CARTN_Y_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteCartnYSu);
+
+    /// `_atom_site.Cartn_z`
+    pub const ATOM_SITE_CARTN_Z: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomSiteCartnZ);
+
+    /// `_atom_site.Cartn_z_su`
+    pub const ATOM_SITE_CARTN_Z_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteCartnZSu);
+
+    /// `_atom_site.chemical_conn_number`
+    pub const ATOM_SITE_CHEMICAL_CONN_NUMBER: TypedItem<i64> = TypedItem::new(CoreItem::AtomSiteChemicalConnNumber);
+
+    /// `_atom_site.constraints`
+    pub const ATOM_SITE_CONSTRAINTS: TypedItem<String> = TypedItem::new(CoreItem::AtomSiteConstraints);
+
+    /// `_atom_site.description`
+    pub const ATOM_SITE_DESCRIPTION: TypedItem<String> = TypedItem::new(CoreItem::AtomSiteDescription);
+
+    /// `_atom_site.disorder_assembly`
+    pub const ATOM_SITE_DISORDER_ASSEMBLY: TypedItem<String> = TypedItem::new(CoreItem::AtomSiteDisorderAssembly);
+
+    /// `_atom_site.disorder_group`
+    pub const ATOM_SITE_DISORDER_GROUP: TypedItem<String> = TypedItem::new(CoreItem::AtomSiteDisorderGroup);
+
+    /// `_atom_site.fract_symmform`
+    pub const ATOM_SITE_FRACT_SYMMFORM: TypedItem<String> = TypedItem::new(CoreItem::AtomSiteFractSymmform);
+
+    /// `_atom_site.fract_x`
+    pub const ATOM_SITE_FRACT_X: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomSiteFractX);
+
+    /// `_atom_site.fract_x_su`
+    pub const ATOM_SITE_FRACT_X_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteFractXSu);
+
+    /// `_atom_site.fract_y`
+    pub const ATOM_SITE_FRACT_Y: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomSiteFractY);
+
+    /// `_atom_site.fract_y_su`
+    pub const ATOM_SITE_FRACT_Y_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteFractYSu);
+
+    /// `_atom_site.fract_z`
+    pub const ATOM_SITE_FRACT_Z: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomSiteFractZ);
+
+    /// `_atom_site.fract_z_su`
+    pub const ATOM_SITE_FRACT_Z_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteFractZSu);
+
+    /// `_atom_site.label`
+    pub const ATOM_SITE_LABEL: TypedItem<String> = TypedItem::new(CoreItem::AtomSiteLabel);
+
+    /// `_atom_site.label_component_1`
+    pub const ATOM_SITE_LABEL_COMPONENT_1: TypedItem<String> = TypedItem::new(CoreItem::AtomSiteLabelComponent1);
+
+    /// `_atom_site.label_component_2`
+    pub const ATOM_SITE_LABEL_COMPONENT_2: TypedItem<String> = TypedItem::new(CoreItem::AtomSiteLabelComponent2);
+
+    /// `_atom_site.label_component_3`
+    pub const ATOM_SITE_LABEL_COMPONENT_3: TypedItem<String> = TypedItem::new(CoreItem::AtomSiteLabelComponent3);
+
+    /// `_atom_site.label_component_4`
+    pub const ATOM_SITE_LABEL_COMPONENT_4: TypedItem<String> = TypedItem::new(CoreItem::AtomSiteLabelComponent4);
+
+    /// `_atom_site.label_component_5`
+    pub const ATOM_SITE_LABEL_COMPONENT_5: TypedItem<String> = TypedItem::new(CoreItem::AtomSiteLabelComponent5);
+
+    /// `_atom_site.label_component_6`
+    pub const ATOM_SITE_LABEL_COMPONENT_6: TypedItem<String> = TypedItem::new(CoreItem::AtomSiteLabelComponent6);
+
+    /// `_atom_site.occupancy`
+    pub const ATOM_SITE_OCCUPANCY: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomSiteOccupancy);
+
+    /// `_atom_site.occupancy_su`
+    pub const ATOM_SITE_OCCUPANCY_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteOccupancySu);
+
+    /// `_atom_site.refinement_flags`
+    pub const ATOM_SITE_REFINEMENT_FLAGS: TypedItem<String> = TypedItem::new(CoreItem::AtomSiteRefinementFlags);
+
+    /// `_atom_site.refinement_flags_ADP`
+    pub const ATOM_SITE_REFINEMENT_FLAGS_ADP: TypedItem<String> = TypedItem::new(CoreItem::AtomSiteRefinementFlagsAdp);
+
+    /// `_atom_site.refinement_flags_occupancy`
+    pub const ATOM_SITE_REFINEMENT_FLAGS_OCCUPANCY: TypedItem<String> = TypedItem::new(CoreItem::AtomSiteRefinementFlagsOccupancy);
+
+    /// `_atom_site.refinement_flags_posn`
+    pub const ATOM_SITE_REFINEMENT_FLAGS_POSN: TypedItem<String> = TypedItem::new(CoreItem::AtomSiteRefinementFlagsPosn);
+
+    /// `_atom_site.restraints`
+    pub const ATOM_SITE_RESTRAINTS: TypedItem<String> = TypedItem::new(CoreItem::AtomSiteRestraints);
+
+    /// `_atom_site.site_symmetry_multiplicity`
+    pub const ATOM_SITE_SITE_SYMMETRY_MULTIPLICITY: TypedItem<i64> = TypedItem::new(CoreItem::AtomSiteSiteSymmetryMultiplicity);
+
+    /// `_atom_site.site_symmetry_order`
+    pub const ATOM_SITE_SITE_SYMMETRY_ORDER: TypedItem<i64> = TypedItem::new(CoreItem::AtomSiteSiteSymmetryOrder);
+
+    /// `_atom_site.type_symbol`
+    pub const ATOM_SITE_TYPE_SYMBOL: TypedItem<String> = TypedItem::new(CoreItem::AtomSiteTypeSymbol);
+
+    /// `_atom_site.U_equiv_geom_mean`
+    pub const ATOM_SITE_U_EQUIV_GEOM_MEAN: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomSiteUEquivGeomMean);
+
+    /// `_atom_site.U_equiv_geom_mean_su`
+    pub const ATOM_SITE_U_EQUIV_GEOM_MEAN_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteUEquivGeomMeanSu);
+
+    /// `_atom_site.U_iso_or_equiv`
+    pub const ATOM_SITE_U_ISO_OR_EQUIV: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomSiteUIsoOrEquiv);
+
+    /// `_atom_site.U_iso_or_equiv_su`
+    pub const ATOM_SITE_U_ISO_OR_EQUIV_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteUIsoOrEquivSu);
+
+    /// `_atom_site.Wyckoff_symbol`
+    pub const ATOM_SITE_WYCKOFF_SYMBOL: TypedItem<String> = TypedItem::new(CoreItem::AtomSiteWyckoffSymbol);
+
+    /// `_atom_site_aniso.B_11`
+    pub const ATOM_SITE_ANISO_B_11: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomSiteAnisoB11);
+
+    /// `_atom_site_aniso.B_11_su`
+    pub const ATOM_SITE_ANISO_B_11_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteAnisoB11Su);
+
+    /// `_atom_site_aniso.B_12`
+    pub const ATOM_SITE_ANISO_B_12: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomSiteAnisoB12);
+
+    /// `_atom_site_aniso.B_12_su`
+    pub const ATOM_SITE_ANISO_B_12_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteAnisoB12Su);
+
+    /// `_atom_site_aniso.B_13`
+    pub const ATOM_SITE_ANISO_B_13: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomSiteAnisoB13);
+
+    /// `_atom_site_aniso.B_13_su`
+    pub const ATOM_SITE_ANISO_B_13_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteAnisoB13Su);
+
+    /// `_atom_site_aniso.B_22`
+    pub const ATOM_SITE_ANISO_B_22: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomSiteAnisoB22);
+
+    /// `_atom_site_aniso.B_22_su`
+    pub const ATOM_SITE_ANISO_B_22_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteAnisoB22Su);
+
+    /// `_atom_site_aniso.B_23`
+    pub const ATOM_SITE_ANISO_B_23: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomSiteAnisoB23);
+
+    /// `_atom_site_aniso.B_23_su`
+    pub const ATOM_SITE_ANISO_B_23_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteAnisoB23Su);
+
+    /// `_atom_site_aniso.B_33`
+    pub const ATOM_SITE_ANISO_B_33: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomSiteAnisoB33);
+
+    /// `_atom_site_aniso.B_33_su`
+    pub const ATOM_SITE_ANISO_B_33_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteAnisoB33Su);
+
+    /// `_atom_site_aniso.beta_11`
+    pub const ATOM_SITE_ANISO_BETA_11: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomSiteAnisoBeta11);
+
+    /// `_atom_site_aniso.beta_11_su`
+    pub const ATOM_SITE_ANISO_BETA_11_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteAnisoBeta11Su);
+
+    /// `_atom_site_aniso.beta_12`
+    pub const ATOM_SITE_ANISO_BETA_12: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomSiteAnisoBeta12);
+
+    /// `_atom_site_aniso.beta_12_su`
+    pub const ATOM_SITE_ANISO_BETA_12_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteAnisoBeta12Su);
+
+    /// `_atom_site_aniso.beta_13`
+    pub const ATOM_SITE_ANISO_BETA_13: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomSiteAnisoBeta13);
+
+    /// `_atom_site_aniso.beta_13_su`
+    pub const ATOM_SITE_ANISO_BETA_13_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteAnisoBeta13Su);
+
+    /// `_atom_site_aniso.beta_22`
+    pub const ATOM_SITE_ANISO_BETA_22: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomSiteAnisoBeta22);
+
+    /// `_atom_site_aniso.beta_22_su`
+    pub const ATOM_SITE_ANISO_BETA_22_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteAnisoBeta22Su);
+
+    /// `_atom_site_aniso.beta_23`
+    pub const ATOM_SITE_ANISO_BETA_23: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomSiteAnisoBeta23);
+
+    /// `_atom_site_aniso.beta_23_su`
+    pub const ATOM_SITE_ANISO_BETA_23_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteAnisoBeta23Su);
+
+    /// `_atom_site_aniso.beta_33`
+    pub const ATOM_SITE_ANISO_BETA_33: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomSiteAnisoBeta33);
+
+    /// `_atom_site_aniso.beta_33_su`
+    pub const ATOM_SITE_ANISO_BETA_33_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteAnisoBeta33Su);
+
+    /// `_atom_site_aniso.label`
+    pub const ATOM_SITE_ANISO_LABEL: TypedItem<String> = TypedItem::new(CoreItem::AtomSiteAnisoLabel);
+
+    /// `_atom_site_aniso.ratio`
+    pub const ATOM_SITE_ANISO_RATIO: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteAnisoRatio);
+
+    /// `_atom_site_aniso.symmform`
+    pub const ATOM_SITE_ANISO_SYMMFORM: TypedItem<String> = TypedItem::new(CoreItem::AtomSiteAnisoSymmform);
+
+    /// `_atom_site_aniso.type_symbol`
+    pub const ATOM_SITE_ANISO_TYPE_SYMBOL: TypedItem<String> = TypedItem::new(CoreItem::AtomSiteAnisoTypeSymbol);
+
+    /// `_atom_site_aniso.U_11`
+    pub const ATOM_SITE_ANISO_U_11: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomSiteAnisoU11);
+
+    /// `_atom_site_aniso.U_11_su`
+    pub const ATOM_SITE_ANISO_U_11_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteAnisoU11Su);
+
+    /// `_atom_site_aniso.U_12`
+    pub const ATOM_SITE_ANISO_U_12: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomSiteAnisoU12);
+
+    /// `_atom_site_aniso.U_12_su`
+    pub const ATOM_SITE_ANISO_U_12_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteAnisoU12Su);
+
+    /// `_atom_site_aniso.U_13`
+    pub const ATOM_SITE_ANISO_U_13: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomSiteAnisoU13);
+
+    /// `_atom_site_aniso.U_13_su`
+    pub const ATOM_SITE_ANISO_U_13_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteAnisoU13Su);
+
+    /// `_atom_site_aniso.U_22`
+    pub const ATOM_SITE_ANISO_U_22: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomSiteAnisoU22);
+
+    /// `_atom_site_aniso.U_22_su`
+    pub const ATOM_SITE_ANISO_U_22_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteAnisoU22Su);
+
+    /// `_atom_site_aniso.U_23`
+    pub const ATOM_SITE_ANISO_U_23: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomSiteAnisoU23);
+
+    /// `_atom_site_aniso.U_23_su`
+    pub const ATOM_SITE_ANISO_U_23_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteAnisoU23Su);
+
+    /// `_atom_site_aniso.U_33`
+    pub const ATOM_SITE_ANISO_U_33: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomSiteAnisoU33);
+
+    /// `_atom_site_aniso.U_33_su`
+    pub const ATOM_SITE_ANISO_U_33_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomSiteAnisoU33Su);
+
+    /// `_atom_sites.solution_hydrogens`
+    pub const ATOM_SITES_SOLUTION_HYDROGENS: TypedItem<String> = TypedItem::new(CoreItem::AtomSitesSolutionHydrogens);
+
+    /// `_atom_sites.solution_primary`
+    pub const ATOM_SITES_SOLUTION_PRIMARY: TypedItem<String> = TypedItem::new(CoreItem::AtomSitesSolutionPrimary);
+
+    /// `_atom_sites.solution_secondary`
+    pub const ATOM_SITES_SOLUTION_SECONDARY: TypedItem<String> = TypedItem::new(CoreItem::AtomSitesSolutionSecondary);
+
+    /// `_atom_sites.special_details`
+    pub const ATOM_SITES_SPECIAL_DETAILS: TypedItem<String> = TypedItem::new(CoreItem::AtomSitesSpecialDetails);
+
+    /// `_atom_type.analytical_mass_percent`
+    pub const ATOM_TYPE_ANALYTICAL_MASS_PERCENT: TypedItem<Measurand> = TypedItem::new(CoreItem::AtomTypeAnalyticalMassPercent);
+
+    /// `_atom_type.analytical_mass_percent_su`
+    pub const ATOM_TYPE_ANALYTICAL_MASS_PERCENT_SU: TypedItem<f64> = TypedItem::new(CoreItem::AtomTypeAnalyticalMassPercentSu);
+
+    /// `_atom_type.atomic_mass`
+    pub const ATOM_TYPE_ATOMIC_MASS: TypedItem<f64> = TypedItem::new(CoreItem::AtomTypeAtomicMass);
+
+    /// `_atom_type.atomic_number`
+    pub const ATOM_TYPE_ATOMIC_NUMBER: TypedItem<i64> = TypedItem::new(CoreItem::AtomTypeAtomicNumber);
+
+    /// `_atom_type.description`
+    pub const ATOM_TYPE_DESCRIPTION: TypedItem<String> = TypedItem::new(CoreItem::AtomTypeDescription);
+
+    /// `_atom_type.display_colour`
+    pub const ATOM_TYPE_DISPLAY_COLOUR: TypedItem<String> = TypedItem::new(CoreItem::AtomTypeDisplayColour);
+
+    /// `_atom_type.electron_count`
+    pub const ATOM_TYPE_ELECTRON_COUNT: TypedItem<i64> = TypedItem::new(CoreItem::AtomTypeElectronCount);
+
+    /// `_atom_type.element_symbol`
+    pub const ATOM_TYPE_ELEMENT_SYMBOL: TypedItem<String> = TypedItem::new(CoreItem::AtomTypeElementSymbol);
+
+    /// `_atom_type.mass_number`
+    pub const ATOM_TYPE_MASS_NUMBER: TypedItem<i64> = TypedItem::new(CoreItem::AtomTypeMassNumber);
+
+    /// `_atom_type.number_in_cell`
+    pub const ATOM_TYPE_NUMBER_IN_CELL: TypedItem<f64> = TypedItem::new(CoreItem::AtomTypeNumberInCell);
+
+    /// `_atom_type.oxidation_number`
+    pub const ATOM_TYPE_OXIDATION_NUMBER: TypedItem<i64> = TypedItem::new(CoreItem::AtomTypeOxidationNumber);
+
+    /// `_atom_type.radius_bond`
+    pub const ATOM_TYPE_RADIUS_BOND: TypedItem<f64> = TypedItem::new(CoreItem::AtomTypeRadiusBond);
+
+    /// `_atom_type.radius_contact`
+    pub const ATOM_TYPE_RADIUS_CONTACT: TypedItem<f64> = TypedItem::new(CoreItem::AtomTypeRadiusContact);
+
+    /// `_atom_type.symbol`
+    pub const ATOM_TYPE_SYMBOL: TypedItem<String> = TypedItem::new(CoreItem::AtomTypeSymbol);
+
+    /// `_audit.block_code`
+    pub const AUDIT_BLOCK_CODE: TypedItem<String> = TypedItem::new(CoreItem::AuditBlockCode);
+
+    /// `_audit.block_DOI`
+    pub const AUDIT_BLOCK_DOI: TypedItem<String> = TypedItem::new(CoreItem::AuditBlockDoi);
+
+    /// `_audit.creation_date`
+    pub const AUDIT_CREATION_DATE: TypedItem<String> = TypedItem::new(CoreItem::AuditCreationDate);
+
+    /// `_audit.creation_method`
+    pub const AUDIT_CREATION_METHOD: TypedItem<String> = TypedItem::new(CoreItem::AuditCreationMethod);
+
+    /// `_audit.schema`
+    pub const AUDIT_SCHEMA: TypedItem<String> = TypedItem::new(CoreItem::AuditSchema);
+
+    /// `_audit.update_record`
+    pub const AUDIT_UPDATE_RECORD: TypedItem<String> = TypedItem::new(CoreItem::AuditUpdateRecord);
+
+    /// `_cell.angle_alpha`
+    pub const CELL_ANGLE_ALPHA: TypedItem<Measurand> = TypedItem::new(CoreItem::CellAngleAlpha);
+
+    /// `_cell.angle_alpha_su`
+    pub const CELL_ANGLE_ALPHA_SU: TypedItem<f64> = TypedItem::new(CoreItem::CellAngleAlphaSu);
+
+    /// `_cell.angle_beta`
+    pub const CELL_ANGLE_BETA: TypedItem<Measurand> = TypedItem::new(CoreItem::CellAngleBeta);
+
+    /// `_cell.angle_beta_su`
+    pub const CELL_ANGLE_BETA_SU: TypedItem<f64> = TypedItem::new(CoreItem::CellAngleBetaSu);
+
+    /// `_cell.angle_gamma`
+    pub const CELL_ANGLE_GAMMA: TypedItem<Measurand> = TypedItem::new(CoreItem::CellAngleGamma);
+
+    /// `_cell.angle_gamma_su`
+    pub const CELL_ANGLE_GAMMA_SU: TypedItem<f64> = TypedItem::new(CoreItem::CellAngleGammaSu);
+
+    /// `_cell.atomic_mass`
+    pub const CELL_ATOMIC_MASS: TypedItem<f64> = TypedItem::new(CoreItem::CellAtomicMass);
+
+    /// `_cell.diffrn_id`
+    pub const CELL_DIFFRN_ID: TypedItem<String> = TypedItem::new(CoreItem::CellDiffrnId);
+
+    /// `_cell.formula_units_Z`
+    pub const CELL_FORMULA_UNITS_Z: TypedItem<f64> = TypedItem::new(CoreItem::CellFormulaUnitsZ);
+
+    /// `_cell.formula_units_Z_details`
+    pub const CELL_FORMULA_UNITS_Z_DETAILS: TypedItem<String> = TypedItem::new(CoreItem::CellFormulaUnitsZDetails);
+
+    /// `_cell.length_a`
+    pub const CELL_LENGTH_A: TypedItem<Measurand> = TypedItem::new(CoreItem::CellLengthA);
+
+    /// `_cell.length_a_su`
+    pub const CELL_LENGTH_A_SU: TypedItem<f64> = TypedItem::new(CoreItem::CellLengthASu);
+
+    /// `_cell.length_b`
+    pub const CELL_LENGTH_B: TypedItem<Measurand> = TypedItem::new(CoreItem::CellLengthB);
+
+    /// `_cell.length_b_su`
+    pub const CELL_LENGTH_B_SU: TypedItem<f64> = TypedItem::new(CoreItem::CellLengthBSu);
+
+    /// `_cell.length_c`
+    pub const CELL_LENGTH_C: TypedItem<Measurand> = TypedItem::new(CoreItem::CellLengthC);
+
+    /// `_cell.length_c_su`
+    pub const CELL_LENGTH_C_SU: TypedItem<f64> = TypedItem::new(CoreItem::CellLengthCSu);
+
+    /// `_cell.reciprocal_angle_alpha`
+    pub const CELL_RECIPROCAL_ANGLE_ALPHA: TypedItem<Measurand> = TypedItem::new(CoreItem::CellReciprocalAngleAlpha);
+
+    /// `_cell.reciprocal_angle_alpha_su`
+    pub const CELL_RECIPROCAL_ANGLE_ALPHA_SU: TypedItem<f64> = TypedItem::new(CoreItem::CellReciprocalAngleAlphaSu);
+
+    /// `_cell.reciprocal_angle_beta`
+    pub const CELL_RECIPROCAL_ANGLE_BETA: TypedItem<Measurand> = TypedItem::new(CoreItem::CellReciprocalAngleBeta);
+
+    /// `_cell.reciprocal_angle_beta_su`
+    pub const CELL_RECIPROCAL_ANGLE_BETA_SU: TypedItem<f64> = TypedItem::new(CoreItem::CellReciprocalAngleBetaSu);
+
+    /// `_cell.reciprocal_angle_gamma`
+    pub const CELL_RECIPROCAL_ANGLE_GAMMA: TypedItem<Measurand> = TypedItem::new(CoreItem::CellReciprocalAngleGamma);
+
+    /// `_cell.reciprocal_angle_gamma_su`
+    pub const CELL_RECIPROCAL_ANGLE_GAMMA_SU: TypedItem<f64> = TypedItem::new(CoreItem::CellReciprocalAngleGammaSu);
+
+    /// `_cell.reciprocal_length_a`
+    pub const CELL_RECIPROCAL_LENGTH_A: TypedItem<Measurand> = TypedItem::new(CoreItem::CellReciprocalLengthA);
+
+    /// `_cell.reciprocal_length_a_su`
+    pub const CELL_RECIPROCAL_LENGTH_A_SU: TypedItem<f64> = TypedItem::new(CoreItem::CellReciprocalLengthASu);
+
+    /// `_cell.reciprocal_length_b`
+    pub const CELL_RECIPROCAL_LENGTH_B: TypedItem<Measurand> = TypedItem::new(CoreItem::CellReciprocalLengthB);
+
+    /// `_cell.reciprocal_length_b_su`
+    pub const CELL_RECIPROCAL_LENGTH_B_SU: TypedItem<f64> = TypedItem::new(CoreItem::CellReciprocalLengthBSu);
+
+    /// `_cell.reciprocal_length_c`
+    pub const CELL_RECIPROCAL_LENGTH_C: TypedItem<Measurand> = TypedItem::new(CoreItem::CellReciprocalLengthC);
+
+    /// `_cell.reciprocal_length_c_su`
+    pub const CELL_RECIPROCAL_LENGTH_C_SU: TypedItem<f64> = TypedItem::new(CoreItem::CellReciprocalLengthCSu);
+
+    /// `_cell.special_details`
+    pub const CELL_SPECIAL_DETAILS: TypedItem<String> = TypedItem::new(CoreItem::CellSpecialDetails);
+
+    /// `_cell.volume`
+    pub const CELL_VOLUME: TypedItem<Measurand> = TypedItem::new(CoreItem::CellVolume);
+
+    /// `_cell.volume_su`
+    pub const CELL_VOLUME_SU: TypedItem<f64> = TypedItem::new(CoreItem::CellVolumeSu);
+
+    /// `_cell_measurement.condition_id`
+    pub const CELL_MEASUREMENT_CONDITION_ID: TypedItem<String> = TypedItem::new(CoreItem::CellMeasurementConditionId);
+
+    /// `_cell_measurement.diffrn_id`
+    pub const CELL_MEASUREMENT_DIFFRN_ID: TypedItem<String> = TypedItem::new(CoreItem::CellMeasurementDiffrnId);
+
+    /// `_cell_measurement.pressure`
+    pub const CELL_MEASUREMENT_PRESSURE: TypedItem<Measurand> = TypedItem::new(CoreItem::CellMeasurementPressure);
+
+    /// `_cell_measurement.pressure_su`
+    pub const CELL_MEASUREMENT_PRESSURE_SU: TypedItem<f64> = TypedItem::new(CoreItem::CellMeasurementPressureSu);
+
+    /// `_cell_measurement.radiation`
+    pub const CELL_MEASUREMENT_RADIATION: TypedItem<String> = TypedItem::new(CoreItem::CellMeasurementRadiation);
+
+    /// `_cell_measurement.reflns_used`
+    pub const CELL_MEASUREMENT_REFLNS_USED: TypedItem<i64> = TypedItem::new(CoreItem::CellMeasurementReflnsUsed);
+
+    /// `_cell_measurement.temperature`
+    pub const CELL_MEASUREMENT_TEMPERATURE: TypedItem<Measurand> = TypedItem::new(CoreItem::CellMeasurementTemperature);
+
+    /// `_cell_measurement.temperature_su`
+    pub const CELL_MEASUREMENT_TEMPERATURE_SU: TypedItem<f64> = TypedItem::new(CoreItem::CellMeasurementTemperatureSu);
+
+    /// `_cell_measurement.theta_max`
+    pub const CELL_MEASUREMENT_THETA_MAX: TypedItem<f64> = TypedItem::new(CoreItem::CellMeasurementThetaMax);
+
+    /// `_cell_measurement.theta_min`
+    pub const CELL_MEASUREMENT_THETA_MIN: TypedItem<f64> = TypedItem::new(CoreItem::CellMeasurementThetaMin);
+
+    /// `_cell_measurement.wavelength`
+    pub const CELL_MEASUREMENT_WAVELENGTH: TypedItem<Measurand> = TypedItem::new(CoreItem::CellMeasurementWavelength);
+
+    /// `_cell_measurement.wavelength_su`
+    pub const CELL_MEASUREMENT_WAVELENGTH_SU: TypedItem<f64> = TypedItem::new(CoreItem::CellMeasurementWavelengthSu);
+
+    /// `_chemical.absolute_configuration`
+    pub const CHEMICAL_ABSOLUTE_CONFIGURATION: TypedItem<String> = TypedItem::new(CoreItem::ChemicalAbsoluteConfiguration);
+
+    /// `_chemical.compound_source`
+    pub const CHEMICAL_COMPOUND_SOURCE: TypedItem<String> = TypedItem::new(CoreItem::ChemicalCompoundSource);
+
+    /// `_chemical.enantioexcess_bulk`
+    pub const CHEMICAL_ENANTIOEXCESS_BULK: TypedItem<Measurand> = TypedItem::new(CoreItem::ChemicalEnantioexcessBulk);
+
+    /// `_chemical.enantioexcess_bulk_su`
+    pub const CHEMICAL_ENANTIOEXCESS_BULK_SU: TypedItem<f64> = TypedItem::new(CoreItem::ChemicalEnantioexcessBulkSu);
+
+    /// `_chemical.enantioexcess_bulk_technique`
+    pub const CHEMICAL_ENANTIOEXCESS_BULK_TECHNIQUE: TypedItem<String> = TypedItem::new(CoreItem::ChemicalEnantioexcessBulkTechnique);
+
+    /// `_chemical.enantioexcess_crystal`
+    pub const CHEMICAL_ENANTIOEXCESS_CRYSTAL: TypedItem<Measurand> = TypedItem::new(CoreItem::ChemicalEnantioexcessCrystal);
+
+    /// `_chemical.enantioexcess_crystal_su`
+    pub const CHEMICAL_ENANTIOEXCESS_CRYSTAL_SU: TypedItem<f64> = TypedItem::new(CoreItem::ChemicalEnantioexcessCrystalSu);
+
+    /// `_chemical.enantioexcess_crystal_technique`
+    pub const CHEMICAL_ENANTIOEXCESS_CRYSTAL_TECHNIQUE: TypedItem<String> = TypedItem::new(CoreItem::ChemicalEnantioexcessCrystalTechnique);
+
+    /// `_chemical.identifier_InChI`
+    pub const CHEMICAL_IDENTIFIER_INCHI: TypedItem<String> = TypedItem::new(CoreItem::ChemicalIdentifierInchi);
+
+    /// `_chemical.identifier_InChI_key`
+    pub const CHEMICAL_IDENTIFIER_INCHI_KEY: TypedItem<String> = TypedItem::new(CoreItem::ChemicalIdentifierInchiKey);
+
+    /// `_chemical.identifier_InChI_version`
+    pub const CHEMICAL_IDENTIFIER_INCHI_VERSION: TypedItem<String> = TypedItem::new(CoreItem::ChemicalIdentifierInchiVersion);
+
+    /// `_chemical.melting_point`
+    pub const CHEMICAL_MELTING_POINT: TypedItem<Measurand> = TypedItem::new(CoreItem::ChemicalMeltingPoint);
+
+    /// `_chemical.melting_point_gt`
+    pub const CHEMICAL_MELTING_POINT_GT: TypedItem<f64> = TypedItem::new(CoreItem::ChemicalMeltingPointGt);
+
+    /// `_chemical.melting_point_lt`
+    pub const CHEMICAL_MELTING_POINT_LT: TypedItem<f64> = TypedItem::new(CoreItem::ChemicalMeltingPointLt);
+
+    /// `_chemical.melting_point_su`
+    pub const CHEMICAL_MELTING_POINT_SU: TypedItem<f64> = TypedItem::new(CoreItem::ChemicalMeltingPointSu);
+
+    /// `_chemical.name_common`
+    pub const CHEMICAL_NAME_COMMON: TypedItem<String> = TypedItem::new(CoreItem::ChemicalNameCommon);
+
+    /// `_chemical.name_mineral`
+    pub const CHEMICAL_NAME_MINERAL: TypedItem<String> = TypedItem::new(CoreItem::ChemicalNameMineral);
+
+    /// `_chemical.name_structure_type`
+    pub const CHEMICAL_NAME_STRUCTURE_TYPE: TypedItem<String> = TypedItem::new(CoreItem::ChemicalNameStructureType);
+
+    /// `_chemical.name_systematic`
+    pub const CHEMICAL_NAME_SYSTEMATIC: TypedItem<String> = TypedItem::new(CoreItem::ChemicalNameSystematic);
+
+    /// `_chemical.optical_rotation`
+    pub const CHEMICAL_OPTICAL_ROTATION: TypedItem<String> = TypedItem::new(CoreItem::ChemicalOpticalRotation);
+
+    /// `_chemical.properties_biological`
+    pub const CHEMICAL_PROPERTIES_BIOLOGICAL: TypedItem<String> = TypedItem::new(CoreItem::ChemicalPropertiesBiological);
+
+    /// `_chemical.properties_physical`
+    pub const CHEMICAL_PROPERTIES_PHYSICAL: TypedItem<String> = TypedItem::new(CoreItem::ChemicalPropertiesPhysical);
+
+    /// `_chemical.temperature_decomposition`
+    pub const CHEMICAL_TEMPERATURE_DECOMPOSITION: TypedItem<Measurand> = TypedItem::new(CoreItem::ChemicalTemperatureDecomposition);
+
+    /// `_chemical.temperature_decomposition_gt`
+    pub const CHEMICAL_TEMPERATURE_DECOMPOSITION_GT: TypedItem<f64> = TypedItem::new(CoreItem::ChemicalTemperatureDecompositionGt);
+
+    /// `_chemical.temperature_decomposition_lt`
+    pub const CHEMICAL_TEMPERATURE_DECOMPOSITION_LT: TypedItem<f64> = TypedItem::new(CoreItem::ChemicalTemperatureDecompositionLt);
+
+    /// `_chemical.temperature_decomposition_su`
+    pub const CHEMICAL_TEMPERATURE_DECOMPOSITION_SU: TypedItem<f64> = TypedItem::new(CoreItem::ChemicalTemperatureDecompositionSu);
+
+    /// `_chemical.temperature_sublimation`
+    pub const CHEMICAL_TEMPERATURE_SUBLIMATION: TypedItem<Measurand> = TypedItem::new(CoreItem::ChemicalTemperatureSublimation);
+
+    /// `_chemical.temperature_sublimation_gt`
+    pub const CHEMICAL_TEMPERATURE_SUBLIMATION_GT: TypedItem<f64> = TypedItem::new(CoreItem::ChemicalTemperatureSublimationGt);
+
+    /// `_chemical.temperature_sublimation_lt`
+    pub const CHEMICAL_TEMPERATURE_SUBLIMATION_LT: TypedItem<f64> = TypedItem::new(CoreItem::ChemicalTemperatureSublimationLt);
+
+    /// `_chemical.temperature_sublimation_su`
+    pub const CHEMICAL_TEMPERATURE_SUBLIMATION_SU: TypedItem<f64> = TypedItem::new(CoreItem::ChemicalTemperatureSublimationSu);
+
+    /// `_chemical_formula.analytical`
+    pub const CHEMICAL_FORMULA_ANALYTICAL: TypedItem<String> = TypedItem::new(CoreItem::ChemicalFormulaAnalytical);
+
+    /// `_chemical_formula.IUPAC`
+    pub const CHEMICAL_FORMULA_IUPAC: TypedItem<String> = TypedItem::new(CoreItem::ChemicalFormulaIupac);
+
+    /// `_chemical_formula.moiety`
+    pub const CHEMICAL_FORMULA_MOIETY: TypedItem<String> = TypedItem::new(CoreItem::ChemicalFormulaMoiety);
+
+    /// `_chemical_formula.structural`
+    pub const CHEMICAL_FORMULA_STRUCTURAL: TypedItem<String> = TypedItem::new(CoreItem::ChemicalFormulaStructural);
+
+    /// `_chemical_formula.sum`
+    pub const CHEMICAL_FORMULA_SUM: TypedItem<String> = TypedItem::new(CoreItem::ChemicalFormulaSum);
+
+    /// `_chemical_formula.weight`
+    pub const CHEMICAL_FORMULA_WEIGHT: TypedItem<f64> = TypedItem::new(CoreItem::ChemicalFormulaWeight);
+
+    /// `_chemical_formula.weight_meas`
+    pub const CHEMICAL_FORMULA_WEIGHT_MEAS: TypedItem<Measurand> = TypedItem::new(CoreItem::ChemicalFormulaWeightMeas);
+
+    /// `_chemical_formula.weight_meas_su`
+    pub const CHEMICAL_FORMULA_WEIGHT_MEAS_SU: TypedItem<f64> = TypedItem::new(CoreItem::ChemicalFormulaWeightMeasSu);
+
+    /// `_computing.cell_refinement`
+    pub const COMPUTING_CELL_REFINEMENT: TypedItem<String> = TypedItem::new(CoreItem::ComputingCellRefinement);
+
+    /// `_computing.diffrn_collection`
+    pub const COMPUTING_DIFFRN_COLLECTION: TypedItem<String> = TypedItem::new(CoreItem::ComputingDiffrnCollection);
+
+    /// `_computing.diffrn_reduction`
+    pub const COMPUTING_DIFFRN_REDUCTION: TypedItem<String> = TypedItem::new(CoreItem::ComputingDiffrnReduction);
+
+    /// `_computing.molecular_graphics`
+    pub const COMPUTING_MOLECULAR_GRAPHICS: TypedItem<String> = TypedItem::new(CoreItem::ComputingMolecularGraphics);
+
+    /// `_computing.publication_material`
+    pub const COMPUTING_PUBLICATION_MATERIAL: TypedItem<String> = TypedItem::new(CoreItem::ComputingPublicationMaterial);
+
+    /// `_computing.sample_tracking`
+    pub const COMPUTING_SAMPLE_TRACKING: TypedItem<String> = TypedItem::new(CoreItem::ComputingSampleTracking);
+
+    /// `_computing.structure_refinement`
+    pub const COMPUTING_STRUCTURE_REFINEMENT: TypedItem<String> = TypedItem::new(CoreItem::ComputingStructureRefinement);
+
+    /// `_computing.structure_solution`
+    pub const COMPUTING_STRUCTURE_SOLUTION: TypedItem<String> = TypedItem::new(CoreItem::ComputingStructureSolution);
+
+    /// `_diffrn.ambient_environment`
+    pub const DIFFRN_AMBIENT_ENVIRONMENT: TypedItem<String> = TypedItem::new(CoreItem::DiffrnAmbientEnvironment);
+
+    /// `_diffrn.ambient_pressure`
+    pub const DIFFRN_AMBIENT_PRESSURE: TypedItem<Measurand> = TypedItem::new(CoreItem::DiffrnAmbientPressure);
+
+    /// `_diffrn.ambient_pressure_gt`
+    pub const DIFFRN_AMBIENT_PRESSURE_GT: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnAmbientPressureGt);
+
+    /// `_diffrn.ambient_pressure_lt`
+    pub const DIFFRN_AMBIENT_PRESSURE_LT: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnAmbientPressureLt);
+
+    /// `_diffrn.ambient_pressure_su`
+    pub const DIFFRN_AMBIENT_PRESSURE_SU: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnAmbientPressureSu);
+
+    /// `_diffrn.ambient_temperature`
+    pub const DIFFRN_AMBIENT_TEMPERATURE: TypedItem<Measurand> = TypedItem::new(CoreItem::DiffrnAmbientTemperature);
+
+    /// `_diffrn.ambient_temperature_details`
+    pub const DIFFRN_AMBIENT_TEMPERATURE_DETAILS: TypedItem<String> = TypedItem::new(CoreItem::DiffrnAmbientTemperatureDetails);
+
+    /// `_diffrn.ambient_temperature_gt`
+    pub const DIFFRN_AMBIENT_TEMPERATURE_GT: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnAmbientTemperatureGt);
+
+    /// `_diffrn.ambient_temperature_lt`
+    pub const DIFFRN_AMBIENT_TEMPERATURE_LT: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnAmbientTemperatureLt);
+
+    /// `_diffrn.ambient_temperature_su`
+    pub const DIFFRN_AMBIENT_TEMPERATURE_SU: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnAmbientTemperatureSu);
+
+    /// `_diffrn.crystal_id`
+    pub const DIFFRN_CRYSTAL_ID: TypedItem<String> = TypedItem::new(CoreItem::DiffrnCrystalId);
+
+    /// `_diffrn.crystal_support`
+    pub const DIFFRN_CRYSTAL_SUPPORT: TypedItem<String> = TypedItem::new(CoreItem::DiffrnCrystalSupport);
+
+    /// `_diffrn.crystal_treatment`
+    pub const DIFFRN_CRYSTAL_TREATMENT: TypedItem<String> = TypedItem::new(CoreItem::DiffrnCrystalTreatment);
+
+    /// `_diffrn.flux_density`
+    pub const DIFFRN_FLUX_DENSITY: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnFluxDensity);
+
+    /// `_diffrn.id`
+    pub const DIFFRN_ID: TypedItem<String> = TypedItem::new(CoreItem::DiffrnId);
+
+    /// `_diffrn.measured_fraction_theta_full`
+    pub const DIFFRN_MEASURED_FRACTION_THETA_FULL: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnMeasuredFractionThetaFull);
+
+    /// `_diffrn.measured_fraction_theta_max`
+    pub const DIFFRN_MEASURED_FRACTION_THETA_MAX: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnMeasuredFractionThetaMax);
+
+    /// `_diffrn.precession_semi_angle`
+    pub const DIFFRN_PRECESSION_SEMI_ANGLE: TypedItem<Measurand> = TypedItem::new(CoreItem::DiffrnPrecessionSemiAngle);
+
+    /// `_diffrn.precession_semi_angle_su`
+    pub const DIFFRN_PRECESSION_SEMI_ANGLE_SU: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnPrecessionSemiAngleSu);
+
+    /// `_diffrn.special_details`
+    pub const DIFFRN_SPECIAL_DETAILS: TypedItem<String> = TypedItem::new(CoreItem::DiffrnSpecialDetails);
+
+    /// `_diffrn.symmetry_description`
+    pub const DIFFRN_SYMMETRY_DESCRIPTION: TypedItem<String> = TypedItem::new(CoreItem::DiffrnSymmetryDescription);
+
+    /// `_diffrn.total_dose`
+    pub const DIFFRN_TOTAL_DOSE: TypedItem<Measurand> = TypedItem::new(CoreItem::DiffrnTotalDose);
+
+    /// `_diffrn.total_dose_su`
+    pub const DIFFRN_TOTAL_DOSE_SU: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnTotalDoseSu);
+
+    /// `_diffrn.total_exposure_time`
+    pub const DIFFRN_TOTAL_EXPOSURE_TIME: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnTotalExposureTime);
+
+    /// `_diffrn_detector.area_resol_mean`
+    pub const DIFFRN_DETECTOR_AREA_RESOL_MEAN: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnDetectorAreaResolMean);
+
+    /// `_diffrn_detector.description`
+    pub const DIFFRN_DETECTOR_DESCRIPTION: TypedItem<String> = TypedItem::new(CoreItem::DiffrnDetectorDescription);
+
+    /// `_diffrn_detector.details`
+    pub const DIFFRN_DETECTOR_DETAILS: TypedItem<String> = TypedItem::new(CoreItem::DiffrnDetectorDetails);
+
+    /// `_diffrn_detector.dtime`
+    pub const DIFFRN_DETECTOR_DTIME: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnDetectorDtime);
+
+    /// `_diffrn_detector.make`
+    pub const DIFFRN_DETECTOR_MAKE: TypedItem<String> = TypedItem::new(CoreItem::DiffrnDetectorMake);
+
+    /// `_diffrn_measurement.details`
+    pub const DIFFRN_MEASUREMENT_DETAILS: TypedItem<String> = TypedItem::new(CoreItem::DiffrnMeasurementDetails);
+
+    /// `_diffrn_measurement.device_class`
+    pub const DIFFRN_MEASUREMENT_DEVICE_CLASS: TypedItem<String> = TypedItem::new(CoreItem::DiffrnMeasurementDeviceClass);
+
+    /// `_diffrn_measurement.device_details`
+    pub const DIFFRN_MEASUREMENT_DEVICE_DETAILS: TypedItem<String> = TypedItem::new(CoreItem::DiffrnMeasurementDeviceDetails);
+
+    /// `_diffrn_measurement.device_make`
+    pub const DIFFRN_MEASUREMENT_DEVICE_MAKE: TypedItem<String> = TypedItem::new(CoreItem::DiffrnMeasurementDeviceMake);
+
+    /// `_diffrn_measurement.method`
+    pub const DIFFRN_MEASUREMENT_METHOD: TypedItem<String> = TypedItem::new(CoreItem::DiffrnMeasurementMethod);
+
+    /// `_diffrn_measurement.method_precession`
+    pub const DIFFRN_MEASUREMENT_METHOD_PRECESSION: TypedItem<String> = TypedItem::new(CoreItem::DiffrnMeasurementMethodPrecession);
+
+    /// `_diffrn_measurement.rotation_mode`
+    pub const DIFFRN_MEASUREMENT_ROTATION_MODE: TypedItem<String> = TypedItem::new(CoreItem::DiffrnMeasurementRotationMode);
+
+    /// `_diffrn_measurement.sample_tracking`
+    pub const DIFFRN_MEASUREMENT_SAMPLE_TRACKING: TypedItem<String> = TypedItem::new(CoreItem::DiffrnMeasurementSampleTracking);
+
+    /// `_diffrn_measurement.sample_tracking_method`
+    pub const DIFFRN_MEASUREMENT_SAMPLE_TRACKING_METHOD: TypedItem<String> = TypedItem::new(CoreItem::DiffrnMeasurementSampleTrackingMethod);
+
+    /// `_diffrn_measurement.specimen_attachment_type`
+    pub const DIFFRN_MEASUREMENT_SPECIMEN_ATTACHMENT_TYPE: TypedItem<String> = TypedItem::new(CoreItem::DiffrnMeasurementSpecimenAttachmentType);
+
+    /// `_diffrn_measurement.specimen_support`
+    pub const DIFFRN_MEASUREMENT_SPECIMEN_SUPPORT: TypedItem<String> = TypedItem::new(CoreItem::DiffrnMeasurementSpecimenSupport);
+
+    /// `_diffrn_radiation.collimation`
+    pub const DIFFRN_RADIATION_COLLIMATION: TypedItem<String> = TypedItem::new(CoreItem::DiffrnRadiationCollimation);
+
+    /// `_diffrn_radiation.filter_edge`
+    pub const DIFFRN_RADIATION_FILTER_EDGE: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnRadiationFilterEdge);
+
+    /// `_diffrn_radiation.illumination_mode`
+    pub const DIFFRN_RADIATION_ILLUMINATION_MODE: TypedItem<String> = TypedItem::new(CoreItem::DiffrnRadiationIlluminationMode);
+
+    /// `_diffrn_radiation.inhomogeneity`
+    pub const DIFFRN_RADIATION_INHOMOGENEITY: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnRadiationInhomogeneity);
+
+    /// `_diffrn_radiation.monochromator`
+    pub const DIFFRN_RADIATION_MONOCHROMATOR: TypedItem<String> = TypedItem::new(CoreItem::DiffrnRadiationMonochromator);
+
+    /// `_diffrn_radiation.polarisn_norm`
+    pub const DIFFRN_RADIATION_POLARISN_NORM: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnRadiationPolarisnNorm);
+
+    /// `_diffrn_radiation.polarisn_ratio`
+    pub const DIFFRN_RADIATION_POLARISN_RATIO: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnRadiationPolarisnRatio);
+
+    /// `_diffrn_radiation.probe`
+    pub const DIFFRN_RADIATION_PROBE: TypedItem<String> = TypedItem::new(CoreItem::DiffrnRadiationProbe);
+
+    /// `_diffrn_radiation.type`
+    pub const DIFFRN_RADIATION_TYPE: TypedItem<String> = TypedItem::new(CoreItem::DiffrnRadiationType);
+
+    /// `_diffrn_radiation.xray_symbol`
+    pub const DIFFRN_RADIATION_XRAY_SYMBOL: TypedItem<String> = TypedItem::new(CoreItem::DiffrnRadiationXraySymbol);
+
+    /// `_diffrn_radiation_wavelength.details`
+    pub const DIFFRN_RADIATION_WAVELENGTH_DETAILS: TypedItem<String> = TypedItem::new(CoreItem::DiffrnRadiationWavelengthDetails);
+
+    /// `_diffrn_radiation_wavelength.determination`
+    pub const DIFFRN_RADIATION_WAVELENGTH_DETERMINATION: TypedItem<String> = TypedItem::new(CoreItem::DiffrnRadiationWavelengthDetermination);
+
+    /// `_diffrn_radiation_wavelength.id`
+    pub const DIFFRN_RADIATION_WAVELENGTH_ID: TypedItem<String> = TypedItem::new(CoreItem::DiffrnRadiationWavelengthId);
+
+    /// `_diffrn_radiation_wavelength.type`
+    pub const DIFFRN_RADIATION_WAVELENGTH_TYPE: TypedItem<String> = TypedItem::new(CoreItem::DiffrnRadiationWavelengthType);
+
+    /// `_diffrn_radiation_wavelength.value`
+    pub const DIFFRN_RADIATION_WAVELENGTH_VALUE: TypedItem<Measurand> = TypedItem::new(CoreItem::DiffrnRadiationWavelengthValue);
+
+    /// `_diffrn_radiation_wavelength.value_su`
+    pub const DIFFRN_RADIATION_WAVELENGTH_VALUE_SU: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnRadiationWavelengthValueSu);
+
+    /// `_diffrn_radiation_wavelength.wt`
+    pub const DIFFRN_RADIATION_WAVELENGTH_WT: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnRadiationWavelengthWt);
+
+    /// `_diffrn_radiation_wavelength.xray_symbol`
+    pub const DIFFRN_RADIATION_WAVELENGTH_XRAY_SYMBOL: TypedItem<String> = TypedItem::new(CoreItem::DiffrnRadiationWavelengthXraySymbol);
+
+    /// `_diffrn_reflns.av_R_equivalents`
+    pub const DIFFRN_REFLNS_AV_R_EQUIVALENTS: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnReflnsAvREquivalents);
+
+    /// `_diffrn_reflns.av_sunetI_over_netI`
+    pub const DIFFRN_REFLNS_AV_SUNETI_OVER_NETI: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnReflnsAvSunetiOverNeti);
+
+    /// `_diffrn_reflns.Laue_measured_fraction_full`
+    pub const DIFFRN_REFLNS_LAUE_MEASURED_FRACTION_FULL: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnReflnsLaueMeasuredFractionFull);
+
+    /// `_diffrn_reflns.Laue_measured_fraction_max`
+    pub const DIFFRN_REFLNS_LAUE_MEASURED_FRACTION_MAX: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnReflnsLaueMeasuredFractionMax);
+
+    /// `_diffrn_reflns.limit_h_max`
+    pub const DIFFRN_REFLNS_LIMIT_H_MAX: TypedItem<i64> = TypedItem::new(CoreItem::DiffrnReflnsLimitHMax);
+
+    /// `_diffrn_reflns.limit_h_min`
+    pub const DIFFRN_REFLNS_LIMIT_H_MIN: TypedItem<i64> = TypedItem::new(CoreItem::DiffrnReflnsLimitHMin);
+
+    /// `_diffrn_reflns.limit_k_max`
+    pub const DIFFRN_REFLNS_LIMIT_K_MAX: TypedItem<i64> = TypedItem::new(CoreItem::DiffrnReflnsLimitKMax);
+
+    /// `_diffrn_reflns.limit_k_min`
+    pub const DIFFRN_REFLNS_LIMIT_K_MIN: TypedItem<i64> = TypedItem::new(CoreItem::DiffrnReflnsLimitKMin);
+
+    /// `_diffrn_reflns.limit_l_max`
+    pub const DIFFRN_REFLNS_LIMIT_L_MAX: TypedItem<i64> = TypedItem::new(CoreItem::DiffrnReflnsLimitLMax);
+
+    /// `_diffrn_reflns.limit_l_min`
+    pub const DIFFRN_REFLNS_LIMIT_L_MIN: TypedItem<i64> = TypedItem::new(CoreItem::DiffrnReflnsLimitLMin);
+
+    /// `_diffrn_reflns.number`
+    pub const DIFFRN_REFLNS_NUMBER: TypedItem<i64> = TypedItem::new(CoreItem::DiffrnReflnsNumber);
+
+    /// `_diffrn_reflns.point_measured_fraction_full`
+    pub const DIFFRN_REFLNS_POINT_MEASURED_FRACTION_FULL: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnReflnsPointMeasuredFractionFull);
+
+    /// `_diffrn_reflns.point_measured_fraction_max`
+    pub const DIFFRN_REFLNS_POINT_MEASURED_FRACTION_MAX: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnReflnsPointMeasuredFractionMax);
+
+    /// `_diffrn_reflns.reduction_process`
+    pub const DIFFRN_REFLNS_REDUCTION_PROCESS: TypedItem<String> = TypedItem::new(CoreItem::DiffrnReflnsReductionProcess);
+
+    /// `_diffrn_reflns.resolution_full`
+    pub const DIFFRN_REFLNS_RESOLUTION_FULL: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnReflnsResolutionFull);
+
+    /// `_diffrn_reflns.resolution_max`
+    pub const DIFFRN_REFLNS_RESOLUTION_MAX: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnReflnsResolutionMax);
+
+    /// `_diffrn_reflns.theta_full`
+    pub const DIFFRN_REFLNS_THETA_FULL: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnReflnsThetaFull);
+
+    /// `_diffrn_reflns.theta_max`
+    pub const DIFFRN_REFLNS_THETA_MAX: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnReflnsThetaMax);
+
+    /// `_diffrn_reflns.theta_min`
+    pub const DIFFRN_REFLNS_THETA_MIN: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnReflnsThetaMin);
+
+    /// `_diffrn_source.beamline`
+    pub const DIFFRN_SOURCE_BEAMLINE: TypedItem<String> = TypedItem::new(CoreItem::DiffrnSourceBeamline);
+
+    /// `_diffrn_source.convergence_angle`
+    pub const DIFFRN_SOURCE_CONVERGENCE_ANGLE: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnSourceConvergenceAngle);
+
+    /// `_diffrn_source.current`
+    pub const DIFFRN_SOURCE_CURRENT: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnSourceCurrent);
+
+    /// `_diffrn_source.description`
+    pub const DIFFRN_SOURCE_DESCRIPTION: TypedItem<String> = TypedItem::new(CoreItem::DiffrnSourceDescription);
+
+    /// `_diffrn_source.details`
+    pub const DIFFRN_SOURCE_DETAILS: TypedItem<String> = TypedItem::new(CoreItem::DiffrnSourceDetails);
+
+    /// `_diffrn_source.device`
+    pub const DIFFRN_SOURCE_DEVICE: TypedItem<String> = TypedItem::new(CoreItem::DiffrnSourceDevice);
+
+    /// `_diffrn_source.ed_diffracting_area_selection`
+    pub const DIFFRN_SOURCE_ED_DIFFRACTING_AREA_SELECTION: TypedItem<String> = TypedItem::new(CoreItem::DiffrnSourceEdDiffractingAreaSelection);
+
+    /// `_diffrn_source.facility`
+    pub const DIFFRN_SOURCE_FACILITY: TypedItem<String> = TypedItem::new(CoreItem::DiffrnSourceFacility);
+
+    /// `_diffrn_source.make`
+    pub const DIFFRN_SOURCE_MAKE: TypedItem<String> = TypedItem::new(CoreItem::DiffrnSourceMake);
+
+    /// `_diffrn_source.power`
+    pub const DIFFRN_SOURCE_POWER: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnSourcePower);
+
+    /// `_diffrn_source.size`
+    pub const DIFFRN_SOURCE_SIZE: TypedItem<String> = TypedItem::new(CoreItem::DiffrnSourceSize);
+
+    /// `_diffrn_source.take_off_angle`
+    pub const DIFFRN_SOURCE_TAKE_OFF_ANGLE: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnSourceTakeOffAngle);
+
+    /// `_diffrn_source.target`
+    pub const DIFFRN_SOURCE_TARGET: TypedItem<String> = TypedItem::new(CoreItem::DiffrnSourceTarget);
+
+    /// `_diffrn_source.voltage`
+    pub const DIFFRN_SOURCE_VOLTAGE: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnSourceVoltage);
+
+    /// `_diffrn_standards.decay_percent`
+    pub const DIFFRN_STANDARDS_DECAY_PERCENT: TypedItem<Measurand> = TypedItem::new(CoreItem::DiffrnStandardsDecayPercent);
+
+    /// `_diffrn_standards.decay_percent_su`
+    pub const DIFFRN_STANDARDS_DECAY_PERCENT_SU: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnStandardsDecayPercentSu);
+
+    /// `_diffrn_standards.interval_count`
+    pub const DIFFRN_STANDARDS_INTERVAL_COUNT: TypedItem<i64> = TypedItem::new(CoreItem::DiffrnStandardsIntervalCount);
+
+    /// `_diffrn_standards.interval_time`
+    pub const DIFFRN_STANDARDS_INTERVAL_TIME: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnStandardsIntervalTime);
+
+    /// `_diffrn_standards.number`
+    pub const DIFFRN_STANDARDS_NUMBER: TypedItem<i64> = TypedItem::new(CoreItem::DiffrnStandardsNumber);
+
+    /// `_diffrn_standards.scale_su_average`
+    pub const DIFFRN_STANDARDS_SCALE_SU_AVERAGE: TypedItem<Measurand> = TypedItem::new(CoreItem::DiffrnStandardsScaleSuAverage);
+
+    /// `_diffrn_standards.scale_su_average_su`
+    pub const DIFFRN_STANDARDS_SCALE_SU_AVERAGE_SU: TypedItem<f64> = TypedItem::new(CoreItem::DiffrnStandardsScaleSuAverageSu);
+
+    /// `_exptl.crystals_number`
+    pub const EXPTL_CRYSTALS_NUMBER: TypedItem<i64> = TypedItem::new(CoreItem::ExptlCrystalsNumber);
+
+    /// `_exptl.method`
+    pub const EXPTL_METHOD: TypedItem<String> = TypedItem::new(CoreItem::ExptlMethod);
+
+    /// `_exptl.method_details`
+    pub const EXPTL_METHOD_DETAILS: TypedItem<String> = TypedItem::new(CoreItem::ExptlMethodDetails);
+
+    /// `_exptl.special_details`
+    pub const EXPTL_SPECIAL_DETAILS: TypedItem<String> = TypedItem::new(CoreItem::ExptlSpecialDetails);
+
+    /// `_exptl.transmission_factor_max`
+    pub const EXPTL_TRANSMISSION_FACTOR_MAX: TypedItem<Measurand> = TypedItem::new(CoreItem::ExptlTransmissionFactorMax);
+
+    /// `_exptl.transmission_factor_max_su`
+    pub const EXPTL_TRANSMISSION_FACTOR_MAX_SU: TypedItem<f64> = TypedItem::new(CoreItem::ExptlTransmissionFactorMaxSu);
+
+    /// `_exptl.transmission_factor_min`
+    pub const EXPTL_TRANSMISSION_FACTOR_MIN: TypedItem<Measurand> = TypedItem::new(CoreItem::ExptlTransmissionFactorMin);
+
+    /// `_exptl.transmission_factor_min_su`
+    pub const EXPTL_TRANSMISSION_FACTOR_MIN_SU: TypedItem<f64> = TypedItem::new(CoreItem::ExptlTransmissionFactorMinSu);
+
+    /// `_exptl_absorpt.coefficient_mu`
+    pub const EXPTL_ABSORPT_COEFFICIENT_MU: TypedItem<Measurand> = TypedItem::new(CoreItem::ExptlAbsorptCoefficientMu);
+
+    /// `_exptl_absorpt.coefficient_mu_su`
+    pub const EXPTL_ABSORPT_COEFFICIENT_MU_SU: TypedItem<f64> = TypedItem::new(CoreItem::ExptlAbsorptCoefficientMuSu);
+
+    /// `_exptl_absorpt.correction_T_max`
+    pub const EXPTL_ABSORPT_CORRECTION_T_MAX: TypedItem<f64> = TypedItem::new(CoreItem::ExptlAbsorptCorrectionTMax);
+
+    /// `_exptl_absorpt.correction_T_min`
+    pub const EXPTL_ABSORPT_CORRECTION_T_MIN: TypedItem<f64> = TypedItem::new(CoreItem::ExptlAbsorptCorrectionTMin);
+
+    /// `_exptl_absorpt.correction_type`
+    pub const EXPTL_ABSORPT_CORRECTION_TYPE: TypedItem<String> = TypedItem::new(CoreItem::ExptlAbsorptCorrectionType);
+
+    /// `_exptl_absorpt.process_details`
+    pub const EXPTL_ABSORPT_PROCESS_DETAILS: TypedItem<String> = TypedItem::new(CoreItem::ExptlAbsorptProcessDetails);
+
+    /// `_exptl_absorpt.special_details`
+    pub const EXPTL_ABSORPT_SPECIAL_DETAILS: TypedItem<String> = TypedItem::new(CoreItem::ExptlAbsorptSpecialDetails);
+
+    /// `_exptl_crystal.colour`
+    pub const EXPTL_CRYSTAL_COLOUR: TypedItem<String> = TypedItem::new(CoreItem::ExptlCrystalColour);
+
+    /// `_exptl_crystal.density_diffrn`
+    pub const EXPTL_CRYSTAL_DENSITY_DIFFRN: TypedItem<Measurand> = TypedItem::new(CoreItem::ExptlCrystalDensityDiffrn);
+
+    /// `_exptl_crystal.density_diffrn_su`
+    pub const EXPTL_CRYSTAL_DENSITY_DIFFRN_SU: TypedItem<f64> = TypedItem::new(CoreItem::ExptlCrystalDensityDiffrnSu);
+
+    /// `_exptl_crystal.density_meas`
+    pub const EXPTL_CRYSTAL_DENSITY_MEAS: TypedItem<Measurand> = TypedItem::new(CoreItem::ExptlCrystalDensityMeas);
+
+    /// `_exptl_crystal.density_meas_gt`
+    pub const EXPTL_CRYSTAL_DENSITY_MEAS_GT: TypedItem<f64> = TypedItem::new(CoreItem::ExptlCrystalDensityMeasGt);
+
+    /// `_exptl_crystal.density_meas_lt`
+    pub const EXPTL_CRYSTAL_DENSITY_MEAS_LT: TypedItem<f64> = TypedItem::new(CoreItem::ExptlCrystalDensityMeasLt);
+
+    /// `_exptl_crystal.density_meas_su`
+    pub const EXPTL_CRYSTAL_DENSITY_MEAS_SU: TypedItem<f64> = TypedItem::new(CoreItem::ExptlCrystalDensityMeasSu);
+
+    /// `_exptl_crystal.density_meas_temp`
+    pub const EXPTL_CRYSTAL_DENSITY_MEAS_TEMP: TypedItem<Measurand> = TypedItem::new(CoreItem::ExptlCrystalDensityMeasTemp);
+
+    /// `_exptl_crystal.density_meas_temp_gt`
+    pub const EXPTL_CRYSTAL_DENSITY_MEAS_TEMP_GT: TypedItem<f64> = TypedItem::new(CoreItem::ExptlCrystalDensityMeasTempGt);
+
+    /// `_exptl_crystal.density_meas_temp_lt`
+    pub const EXPTL_CRYSTAL_DENSITY_MEAS_TEMP_LT: TypedItem<f64> = TypedItem::new(CoreItem::ExptlCrystalDensityMeasTempLt);
+
+    /// `_exptl_crystal.density_meas_temp_su`
+    pub const EXPTL_CRYSTAL_DENSITY_MEAS_TEMP_SU: TypedItem<f64> = TypedItem::new(CoreItem::ExptlCrystalDensityMeasTempSu);
+
+    /// `_exptl_crystal.density_method`
+    pub const EXPTL_CRYSTAL_DENSITY_METHOD: TypedItem<String> = TypedItem::new(CoreItem::ExptlCrystalDensityMethod);
+
+    /// `_exptl_crystal.description`
+    pub const EXPTL_CRYSTAL_DESCRIPTION: TypedItem<String> = TypedItem::new(CoreItem::ExptlCrystalDescription);
+
+    /// `_exptl_crystal.F_000`
+    pub const EXPTL_CRYSTAL_F_000: TypedItem<f64> = TypedItem::new(CoreItem::ExptlCrystalF000);
+
+    /// `_exptl_crystal.id`
+    pub const EXPTL_CRYSTAL_ID: TypedItem<String> = TypedItem::new(CoreItem::ExptlCrystalId);
+
+    /// `_exptl_crystal.mosaic_block_size`
+    pub const EXPTL_CRYSTAL_MOSAIC_BLOCK_SIZE: TypedItem<Measurand> = TypedItem::new(CoreItem::ExptlCrystalMosaicBlockSize);
+
+    /// `_exptl_crystal.mosaic_block_size_su`
+    pub const EXPTL_CRYSTAL_MOSAIC_BLOCK_SIZE_SU: TypedItem<f64> = TypedItem::new(CoreItem::ExptlCrystalMosaicBlockSizeSu);
+
+    /// `_exptl_crystal.mosaic_method`
+    pub const EXPTL_CRYSTAL_MOSAIC_METHOD: TypedItem<String> = TypedItem::new(CoreItem::ExptlCrystalMosaicMethod);
+
+    /// `_exptl_crystal.mosaicity`
+    pub const EXPTL_CRYSTAL_MOSAICITY: TypedItem<Measurand> = TypedItem::new(CoreItem::ExptlCrystalMosaicity);
+
+    /// `_exptl_crystal.mosaicity_su`
+    pub const EXPTL_CRYSTAL_MOSAICITY_SU: TypedItem<f64> = TypedItem::new(CoreItem::ExptlCrystalMosaicitySu);
+
+    /// `_exptl_crystal.preparation`
+    pub const EXPTL_CRYSTAL_PREPARATION: TypedItem<String> = TypedItem::new(CoreItem::ExptlCrystalPreparation);
+
+    /// `_exptl_crystal.pressure_history`
+    pub const EXPTL_CRYSTAL_PRESSURE_HISTORY: TypedItem<String> = TypedItem::new(CoreItem::ExptlCrystalPressureHistory);
+
+    /// `_exptl_crystal.recrystallization_method`
+    pub const EXPTL_CRYSTAL_RECRYSTALLIZATION_METHOD: TypedItem<String> = TypedItem::new(CoreItem::ExptlCrystalRecrystallizationMethod);
+
+    /// `_exptl_crystal.size_length`
+    pub const EXPTL_CRYSTAL_SIZE_LENGTH: TypedItem<Measurand> = TypedItem::new(CoreItem::ExptlCrystalSizeLength);
+
+    /// `_exptl_crystal.size_length_su`
+    pub const EXPTL_CRYSTAL_SIZE_LENGTH_SU: TypedItem<f64> = TypedItem::new(CoreItem::ExptlCrystalSizeLengthSu);
+
+    /// `_exptl_crystal.size_max`
+    pub const EXPTL_CRYSTAL_SIZE_MAX: TypedItem<Measurand> = TypedItem::new(CoreItem::ExptlCrystalSizeMax);
+
+    /// `_exptl_crystal.size_max_su`
+    pub const EXPTL_CRYSTAL_SIZE_MAX_SU: TypedItem<f64> = TypedItem::new(CoreItem::ExptlCrystalSizeMaxSu);
+
+    /// `_exptl_crystal.size_mid`
+    pub const EXPTL_CRYSTAL_SIZE_MID: TypedItem<Measurand> = TypedItem::new(CoreItem::ExptlCrystalSizeMid);
+
+    /// `_exptl_crystal.size_mid_su`
+    pub const EXPTL_CRYSTAL_SIZE_MID_SU: TypedItem<f64> = TypedItem::new(CoreItem::ExptlCrystalSizeMidSu);
+
+    /// `_exptl_crystal.size_min`
+    pub const EXPTL_CRYSTAL_SIZE_MIN: TypedItem<Measurand> = TypedItem::new(CoreItem::ExptlCrystalSizeMin);
+
+    /// `_exptl_crystal.size_min_su`
+    pub const EXPTL_CRYSTAL_SIZE_MIN_SU: TypedItem<f64> = TypedItem::new(CoreItem::ExptlCrystalSizeMinSu);
+
+    /// `_exptl_crystal.size_rad`
+    pub const EXPTL_CRYSTAL_SIZE_RAD: TypedItem<Measurand> = TypedItem::new(CoreItem::ExptlCrystalSizeRad);
+
+    /// `_exptl_crystal.size_rad_su`
+    pub const EXPTL_CRYSTAL_SIZE_RAD_SU: TypedItem<f64> = TypedItem::new(CoreItem::ExptlCrystalSizeRadSu);
+
+    /// `_exptl_crystal.thermal_history`
+    pub const EXPTL_CRYSTAL_THERMAL_HISTORY: TypedItem<String> = TypedItem::new(CoreItem::ExptlCrystalThermalHistory);
+
+    /// `_geom_angle.atom_site_label_1`
+    pub const GEOM_ANGLE_ATOM_SITE_LABEL_1: TypedItem<String> = TypedItem::new(CoreItem::GeomAngleAtomSiteLabel1);
+
+    /// `_geom_angle.atom_site_label_2`
+    pub const GEOM_ANGLE_ATOM_SITE_LABEL_2: TypedItem<String> = TypedItem::new(CoreItem::GeomAngleAtomSiteLabel2);
+
+    /// `_geom_angle.atom_site_label_3`
+    pub const GEOM_ANGLE_ATOM_SITE_LABEL_3: TypedItem<String> = TypedItem::new(CoreItem::GeomAngleAtomSiteLabel3);
+
+    /// `_geom_angle.id`
+    pub const GEOM_ANGLE_ID: TypedItem<String> = TypedItem::new(CoreItem::GeomAngleId);
+
+    /// `_geom_angle.publ_flag`
+    pub const GEOM_ANGLE_PUBL_FLAG: TypedItem<String> = TypedItem::new(CoreItem::GeomAnglePublFlag);
+
+    /// `_geom_angle.value`
+    pub const GEOM_ANGLE_VALUE: TypedItem<Measurand> = TypedItem::new(CoreItem::GeomAngleValue);
+
+    /// `_geom_angle.value_su`
+    pub const GEOM_ANGLE_VALUE_SU: TypedItem<f64> = TypedItem::new(CoreItem::GeomAngleValueSu);
+
+    /// `_geom_bond.atom_site_label_1`
+    pub const GEOM_BOND_ATOM_SITE_LABEL_1: TypedItem<String> = TypedItem::new(CoreItem::GeomBondAtomSiteLabel1);
+
+    /// `_geom_bond.atom_site_label_2`
+    pub const GEOM_BOND_ATOM_SITE_LABEL_2: TypedItem<String> = TypedItem::new(CoreItem::GeomBondAtomSiteLabel2);
+
+    /// `_geom_bond.distance`
+    pub const GEOM_BOND_DISTANCE: TypedItem<Measurand> = TypedItem::new(CoreItem::GeomBondDistance);
+
+    /// `_geom_bond.distance_su`
+    pub const GEOM_BOND_DISTANCE_SU: TypedItem<f64> = TypedItem::new(CoreItem::GeomBondDistanceSu);
+
+    /// `_geom_bond.id`
+    pub const GEOM_BOND_ID: TypedItem<String> = TypedItem::new(CoreItem::GeomBondId);
+
+    /// `_geom_bond.multiplicity`
+    pub const GEOM_BOND_MULTIPLICITY: TypedItem<i64> = TypedItem::new(CoreItem::GeomBondMultiplicity);
+
+    /// `_geom_bond.publ_flag`
+    pub const GEOM_BOND_PUBL_FLAG: TypedItem<String> = TypedItem::new(CoreItem::GeomBondPublFlag);
+
+    /// `_geom_bond.valence`
+    pub const GEOM_BOND_VALENCE: TypedItem<Measurand> = TypedItem::new(CoreItem::GeomBondValence);
+
+    /// `_geom_bond.valence_su`
+    pub const GEOM_BOND_VALENCE_SU: TypedItem<f64> = TypedItem::new(CoreItem::GeomBondValenceSu);
+
+    /// `_geom_hbond.angle_DHA`
+    pub const GEOM_HBOND_ANGLE_DHA: TypedItem<Measurand> = TypedItem::new(CoreItem::GeomHbondAngleDha);
+
+    /// `_geom_hbond.angle_DHA_su`
+    pub const GEOM_HBOND_ANGLE_DHA_SU: TypedItem<f64> = TypedItem::new(CoreItem::GeomHbondAngleDhaSu);
+
+    /// `_geom_hbond.atom_site_label_A`
+    pub const GEOM_HBOND_ATOM_SITE_LABEL_A: TypedItem<String> = TypedItem::new(CoreItem::GeomHbondAtomSiteLabelA);
+
+    /// `_geom_hbond.atom_site_label_D`
+    pub const GEOM_HBOND_ATOM_SITE_LABEL_D: TypedItem<String> = TypedItem::new(CoreItem::GeomHbondAtomSiteLabelD);
+
+    /// `_geom_hbond.atom_site_label_H`
+    pub const GEOM_HBOND_ATOM_SITE_LABEL_H: TypedItem<String> = TypedItem::new(CoreItem::GeomHbondAtomSiteLabelH);
+
+    /// `_geom_hbond.distance_DA`
+    pub const GEOM_HBOND_DISTANCE_DA: TypedItem<Measurand> = TypedItem::new(CoreItem::GeomHbondDistanceDa);
+
+    /// `_geom_hbond.distance_DA_su`
+    pub const GEOM_HBOND_DISTANCE_DA_SU: TypedItem<f64> = TypedItem::new(CoreItem::GeomHbondDistanceDaSu);
+
+    /// `_geom_hbond.distance_DH`
+    pub const GEOM_HBOND_DISTANCE_DH: TypedItem<Measurand> = TypedItem::new(CoreItem::GeomHbondDistanceDh);
+
+    /// `_geom_hbond.distance_DH_su`
+    pub const GEOM_HBOND_DISTANCE_DH_SU: TypedItem<f64> = TypedItem::new(CoreItem::GeomHbondDistanceDhSu);
+
+    /// `_geom_hbond.distance_HA`
+    pub const GEOM_HBOND_DISTANCE_HA: TypedItem<Measurand> = TypedItem::new(CoreItem::GeomHbondDistanceHa);
+
+    /// `_geom_hbond.distance_HA_su`
+    pub const GEOM_HBOND_DISTANCE_HA_SU: TypedItem<f64> = TypedItem::new(CoreItem::GeomHbondDistanceHaSu);
+
+    /// `_geom_hbond.id`
+    pub const GEOM_HBOND_ID: TypedItem<String> = TypedItem::new(CoreItem::GeomHbondId);
+
+    /// `_geom_hbond.publ_flag`
+    pub const GEOM_HBOND_PUBL_FLAG: TypedItem<String> = TypedItem::new(CoreItem::GeomHbondPublFlag);
+
+    /// `_geom_torsion.angle`
+    pub const GEOM_TORSION_ANGLE: TypedItem<Measurand> = TypedItem::new(CoreItem::GeomTorsionAngle);
+
+    /// `_geom_torsion.angle_su`
+    pub const GEOM_TORSION_ANGLE_SU: TypedItem<f64> = TypedItem::new(CoreItem::GeomTorsionAngleSu);
+
+    /// `_geom_torsion.atom_site_label_1`
+    pub const GEOM_TORSION_ATOM_SITE_LABEL_1: TypedItem<String> = TypedItem::new(CoreItem::GeomTorsionAtomSiteLabel1);
+
+    /// `_geom_torsion.atom_site_label_2`
+    pub const GEOM_TORSION_ATOM_SITE_LABEL_2: TypedItem<String> = TypedItem::new(CoreItem::GeomTorsionAtomSiteLabel2);
+
+    /// `_geom_torsion.atom_site_label_3`
+    pub const GEOM_TORSION_ATOM_SITE_LABEL_3: TypedItem<String> = TypedItem::new(CoreItem::GeomTorsionAtomSiteLabel3);
+
+    /// `_geom_torsion.atom_site_label_4`
+    pub const GEOM_TORSION_ATOM_SITE_LABEL_4: TypedItem<String> = TypedItem::new(CoreItem::GeomTorsionAtomSiteLabel4);
+
+    /// `_geom_torsion.id`
+    pub const GEOM_TORSION_ID: TypedItem<String> = TypedItem::new(CoreItem::GeomTorsionId);
+
+    /// `_geom_torsion.publ_flag`
+    pub const GEOM_TORSION_PUBL_FLAG: TypedItem<String> = TypedItem::new(CoreItem::GeomTorsionPublFlag);
+
+    /// `_refine.diffraction_theory`
+    pub const REFINE_DIFFRACTION_THEORY: TypedItem<String> = TypedItem::new(CoreItem::RefineDiffractionTheory);
+
+    /// `_refine.diffraction_theory_details`
+    pub const REFINE_DIFFRACTION_THEORY_DETAILS: TypedItem<String> = TypedItem::new(CoreItem::RefineDiffractionTheoryDetails);
+
+    /// `_refine.special_details`
+    pub const REFINE_SPECIAL_DETAILS: TypedItem<String> = TypedItem::new(CoreItem::RefineSpecialDetails);
+
+    /// `_refine_diff.density_max`
+    pub const REFINE_DIFF_DENSITY_MAX: TypedItem<Measurand> = TypedItem::new(CoreItem::RefineDiffDensityMax);
+
+    /// `_refine_diff.density_max_su`
+    pub const REFINE_DIFF_DENSITY_MAX_SU: TypedItem<f64> = TypedItem::new(CoreItem::RefineDiffDensityMaxSu);
+
+    /// `_refine_diff.density_min`
+    pub const REFINE_DIFF_DENSITY_MIN: TypedItem<Measurand> = TypedItem::new(CoreItem::RefineDiffDensityMin);
+
+    /// `_refine_diff.density_min_su`
+    pub const REFINE_DIFF_DENSITY_MIN_SU: TypedItem<f64> = TypedItem::new(CoreItem::RefineDiffDensityMinSu);
+
+    /// `_refine_diff.density_RMS`
+    pub const REFINE_DIFF_DENSITY_RMS: TypedItem<Measurand> = TypedItem::new(CoreItem::RefineDiffDensityRms);
+
+    /// `_refine_diff.density_RMS_su`
+    pub const REFINE_DIFF_DENSITY_RMS_SU: TypedItem<f64> = TypedItem::new(CoreItem::RefineDiffDensityRmsSu);
+
+    /// `_refine_diff.potential_max`
+    pub const REFINE_DIFF_POTENTIAL_MAX: TypedItem<Measurand> = TypedItem::new(CoreItem::RefineDiffPotentialMax);
+
+    /// `_refine_diff.potential_max_su`
+    pub const REFINE_DIFF_POTENTIAL_MAX_SU: TypedItem<f64> = TypedItem::new(CoreItem::RefineDiffPotentialMaxSu);
+
+    /// `_refine_diff.potential_min`
+    pub const REFINE_DIFF_POTENTIAL_MIN: TypedItem<Measurand> = TypedItem::new(CoreItem::RefineDiffPotentialMin);
+
+    /// `_refine_diff.potential_min_su`
+    pub const REFINE_DIFF_POTENTIAL_MIN_SU: TypedItem<f64> = TypedItem::new(CoreItem::RefineDiffPotentialMinSu);
+
+    /// `_refine_diff.potential_RMS`
+    pub const REFINE_DIFF_POTENTIAL_RMS: TypedItem<Measurand> = TypedItem::new(CoreItem::RefineDiffPotentialRms);
+
+    /// `_refine_diff.potential_RMS_su`
+    pub const REFINE_DIFF_POTENTIAL_RMS_SU: TypedItem<f64> = TypedItem::new(CoreItem::RefineDiffPotentialRmsSu);
+
+    /// `_refine_ls.abs_structure_details`
+    pub const REFINE_LS_ABS_STRUCTURE_DETAILS: TypedItem<String> = TypedItem::new(CoreItem::RefineLsAbsStructureDetails);
+
+    /// `_refine_ls.abs_structure_Flack`
+    pub const REFINE_LS_ABS_STRUCTURE_FLACK: TypedItem<Measurand> = TypedItem::new(CoreItem::RefineLsAbsStructureFlack);
+
+    /// `_refine_ls.abs_structure_Flack_su`
+    pub const REFINE_LS_ABS_STRUCTURE_FLACK_SU: TypedItem<f64> = TypedItem::new(CoreItem::RefineLsAbsStructureFlackSu);
+
+    /// `_refine_ls.abs_structure_Rogers`
+    pub const REFINE_LS_ABS_STRUCTURE_ROGERS: TypedItem<Measurand> = TypedItem::new(CoreItem::RefineLsAbsStructureRogers);
+
+    /// `_refine_ls.abs_structure_Rogers_su`
+    pub const REFINE_LS_ABS_STRUCTURE_ROGERS_SU: TypedItem<f64> = TypedItem::new(CoreItem::RefineLsAbsStructureRogersSu);
+
+    /// `_refine_ls.abs_structure_z-score`
+    pub const REFINE_LS_ABS_STRUCTURE_Z_SCORE: TypedItem<f64> = TypedItem::new(CoreItem::RefineLsAbsStructureZScore);
+
+    /// `_refine_ls.d_res_high`
+    pub const REFINE_LS_D_RES_HIGH: TypedItem<f64> = TypedItem::new(CoreItem::RefineLsDResHigh);
+
+    /// `_refine_ls.d_res_low`
+    pub const REFINE_LS_D_RES_LOW: TypedItem<f64> = TypedItem::new(CoreItem::RefineLsDResLow);
+
+    /// `_refine_ls.extinction_coef`
+    pub const REFINE_LS_EXTINCTION_COEF: TypedItem<Measurand> = TypedItem::new(CoreItem::RefineLsExtinctionCoef);
+
+    /// `_refine_ls.extinction_coef_su`
+    pub const REFINE_LS_EXTINCTION_COEF_SU: TypedItem<f64> = TypedItem::new(CoreItem::RefineLsExtinctionCoefSu);
+
+    /// `_refine_ls.extinction_expression`
+    pub const REFINE_LS_EXTINCTION_EXPRESSION: TypedItem<String> = TypedItem::new(CoreItem::RefineLsExtinctionExpression);
+
+    /// `_refine_ls.extinction_method`
+    pub const REFINE_LS_EXTINCTION_METHOD: TypedItem<String> = TypedItem::new(CoreItem::RefineLsExtinctionMethod);
+
+    /// `_refine_ls.F_calc_details`
+    pub const REFINE_LS_F_CALC_DETAILS: TypedItem<String> = TypedItem::new(CoreItem::RefineLsFCalcDetails);
+
+    /// `_refine_ls.F_calc_formula`
+    pub const REFINE_LS_F_CALC_FORMULA: TypedItem<String> = TypedItem::new(CoreItem::RefineLsFCalcFormula);
+
+    /// `_refine_ls.F_calc_precision`
+    pub const REFINE_LS_F_CALC_PRECISION: TypedItem<Measurand> = TypedItem::new(CoreItem::RefineLsFCalcPrecision);
+
+    /// `_refine_ls.F_calc_precision_su`
+    pub const REFINE_LS_F_CALC_PRECISION_SU: TypedItem<f64> = TypedItem::new(CoreItem::RefineLsFCalcPrecisionSu);
+
+    /// `_refine_ls.goodness_of_fit_all`
+    pub const REFINE_LS_GOODNESS_OF_FIT_ALL: TypedItem<Measurand> = TypedItem::new(CoreItem::RefineLsGoodnessOfFitAll);
+
+    /// `_refine_ls.goodness_of_fit_all_su`
+    pub const REFINE_LS_GOODNESS_OF_FIT_ALL_SU: TypedItem<f64> = TypedItem::new(CoreItem::RefineLsGoodnessOfFitAllSu);
+
+    /// `_refine_ls.goodness_of_fit_gt`
+    pub const REFINE_LS_GOODNESS_OF_FIT_GT: TypedItem<Measurand> = TypedItem::new(CoreItem::RefineLsGoodnessOfFitGt);
+
+    /// `_refine_ls.goodness_of_fit_gt_su`
+    pub const REFINE_LS_GOODNESS_OF_FIT_GT_SU: TypedItem<f64> = TypedItem::new(CoreItem::RefineLsGoodnessOfFitGtSu);
+
+    /// `_refine_ls.goodness_of_fit_ref`
+    pub const REFINE_LS_GOODNESS_OF_FIT_REF: TypedItem<Measurand> = TypedItem::new(CoreItem::RefineLsGoodnessOfFitRef);
+
+    /// `_refine_ls.goodness_of_fit_ref_su`
+    pub const REFINE_LS_GOODNESS_OF_FIT_REF_SU: TypedItem<f64> = TypedItem::new(CoreItem::RefineLsGoodnessOfFitRefSu);
+
+    /// `_refine_ls.hydrogen_treatment`
+    pub const REFINE_LS_HYDROGEN_TREATMENT: TypedItem<String> = TypedItem::new(CoreItem::RefineLsHydrogenTreatment);
+
+    /// `_refine_ls.matrix_type`
+    pub const REFINE_LS_MATRIX_TYPE: TypedItem<String> = TypedItem::new(CoreItem::RefineLsMatrixType);
+
+    /// `_refine_ls.number_constraints`
+    pub const REFINE_LS_NUMBER_CONSTRAINTS: TypedItem<i64> = TypedItem::new(CoreItem::RefineLsNumberConstraints);
+
+    /// `_refine_ls.number_parameters`
+    pub const REFINE_LS_NUMBER_PARAMETERS: TypedItem<i64> = TypedItem::new(CoreItem::RefineLsNumberParameters);
+
+    /// `_refine_ls.number_reflns`
+    pub const REFINE_LS_NUMBER_REFLNS: TypedItem<i64> = TypedItem::new(CoreItem::RefineLsNumberReflns);
+
+    /// `_refine_ls.number_reflns_gt`
+    pub const REFINE_LS_NUMBER_REFLNS_GT: TypedItem<i64> = TypedItem::new(CoreItem::RefineLsNumberReflnsGt);
+
+    /// `_refine_ls.number_restraints`
+    pub const REFINE_LS_NUMBER_RESTRAINTS: TypedItem<i64> = TypedItem::new(CoreItem::RefineLsNumberRestraints);
+
+    /// `_refine_ls.R_factor_all`
+    pub const REFINE_LS_R_FACTOR_ALL: TypedItem<f64> = TypedItem::new(CoreItem::RefineLsRFactorAll);
+
+    /// `_refine_ls.R_factor_gt`
+    pub const REFINE_LS_R_FACTOR_GT: TypedItem<f64> = TypedItem::new(CoreItem::RefineLsRFactorGt);
+
+    /// `_refine_ls.R_Fsqd_factor`
+    pub const REFINE_LS_R_FSQD_FACTOR: TypedItem<f64> = TypedItem::new(CoreItem::RefineLsRFsqdFactor);
+
+    /// `_refine_ls.R_I_factor`
+    pub const REFINE_LS_R_I_FACTOR: TypedItem<f64> = TypedItem::new(CoreItem::RefineLsRIFactor);
+
+    /// `_refine_ls.restrained_S_all`
+    pub const REFINE_LS_RESTRAINED_S_ALL: TypedItem<Measurand> = TypedItem::new(CoreItem::RefineLsRestrainedSAll);
+
+    /// `_refine_ls.restrained_S_all_su`
+    pub const REFINE_LS_RESTRAINED_S_ALL_SU: TypedItem<f64> = TypedItem::new(CoreItem::RefineLsRestrainedSAllSu);
+
+    /// `_refine_ls.restrained_S_gt`
+    pub const REFINE_LS_RESTRAINED_S_GT: TypedItem<Measurand> = TypedItem::new(CoreItem::RefineLsRestrainedSGt);
+
+    /// `_refine_ls.restrained_S_gt_su`
+    pub const REFINE_LS_RESTRAINED_S_GT_SU: TypedItem<f64> = TypedItem::new(CoreItem::RefineLsRestrainedSGtSu);
+
+    /// `_refine_ls.sample_shape_details`
+    pub const REFINE_LS_SAMPLE_SHAPE_DETAILS: TypedItem<String> = TypedItem::new(CoreItem::RefineLsSampleShapeDetails);
+
+    /// `_refine_ls.sample_shape_expression`
+    pub const REFINE_LS_SAMPLE_SHAPE_EXPRESSION: TypedItem<String> = TypedItem::new(CoreItem::RefineLsSampleShapeExpression);
+
+    /// `_refine_ls.sample_thickness`
+    pub const REFINE_LS_SAMPLE_THICKNESS: TypedItem<Measurand> = TypedItem::new(CoreItem::RefineLsSampleThickness);
+
+    /// `_refine_ls.sample_thickness_su`
+    pub const REFINE_LS_SAMPLE_THICKNESS_SU: TypedItem<f64> = TypedItem::new(CoreItem::RefineLsSampleThicknessSu);
+
+    /// `_refine_ls.shift_over_su_max`
+    pub const REFINE_LS_SHIFT_OVER_SU_MAX: TypedItem<f64> = TypedItem::new(CoreItem::RefineLsShiftOverSuMax);
+
+    /// `_refine_ls.shift_over_su_max_lt`
+    pub const REFINE_LS_SHIFT_OVER_SU_MAX_LT: TypedItem<f64> = TypedItem::new(CoreItem::RefineLsShiftOverSuMaxLt);
+
+    /// `_refine_ls.shift_over_su_mean`
+    pub const REFINE_LS_SHIFT_OVER_SU_MEAN: TypedItem<f64> = TypedItem::new(CoreItem::RefineLsShiftOverSuMean);
+
+    /// `_refine_ls.shift_over_su_mean_lt`
+    pub const REFINE_LS_SHIFT_OVER_SU_MEAN_LT: TypedItem<f64> = TypedItem::new(CoreItem::RefineLsShiftOverSuMeanLt);
+
+    /// `_refine_ls.structure_factor_coef`
+    pub const REFINE_LS_STRUCTURE_FACTOR_COEF: TypedItem<String> = TypedItem::new(CoreItem::RefineLsStructureFactorCoef);
+
+    /// `_refine_ls.weighting_details`
+    pub const REFINE_LS_WEIGHTING_DETAILS: TypedItem<String> = TypedItem::new(CoreItem::RefineLsWeightingDetails);
+
+    /// `_refine_ls.weighting_scheme`
+    pub const REFINE_LS_WEIGHTING_SCHEME: TypedItem<String> = TypedItem::new(CoreItem::RefineLsWeightingScheme);
+
+    /// `_refine_ls.wR_factor_all`
+    pub const REFINE_LS_WR_FACTOR_ALL: TypedItem<f64> = TypedItem::new(CoreItem::RefineLsWrFactorAll);
+
+    /// `_refine_ls.wR_factor_gt`
+    pub const REFINE_LS_WR_FACTOR_GT: TypedItem<f64> = TypedItem::new(CoreItem::RefineLsWrFactorGt);
+
+    /// `_refine_ls.wR_factor_ref`
+    pub const REFINE_LS_WR_FACTOR_REF: TypedItem<f64> = TypedItem::new(CoreItem::RefineLsWrFactorRef);
+
+    /// `_reflns.apply_dispersion_to_Fcalc`
+    pub const REFLNS_APPLY_DISPERSION_TO_FCALC: TypedItem<String> = TypedItem::new(CoreItem::ReflnsApplyDispersionToFcalc);
+
+    /// `_reflns.d_resolution_high`
+    pub const REFLNS_D_RESOLUTION_HIGH: TypedItem<f64> = TypedItem::new(CoreItem::ReflnsDResolutionHigh);
+
+    /// `_reflns.d_resolution_low`
+    pub const REFLNS_D_RESOLUTION_LOW: TypedItem<f64> = TypedItem::new(CoreItem::ReflnsDResolutionLow);
+
+    /// `_reflns.Friedel_coverage`
+    pub const REFLNS_FRIEDEL_COVERAGE: TypedItem<f64> = TypedItem::new(CoreItem::ReflnsFriedelCoverage);
+
+    /// `_reflns.Friedel_fraction_full`
+    pub const REFLNS_FRIEDEL_FRACTION_FULL: TypedItem<f64> = TypedItem::new(CoreItem::ReflnsFriedelFractionFull);
+
+    /// `_reflns.Friedel_fraction_max`
+    pub const REFLNS_FRIEDEL_FRACTION_MAX: TypedItem<f64> = TypedItem::new(CoreItem::ReflnsFriedelFractionMax);
+
+    /// `_reflns.limit_h_max`
+    pub const REFLNS_LIMIT_H_MAX: TypedItem<i64> = TypedItem::new(CoreItem::ReflnsLimitHMax);
+
+    /// `_reflns.limit_h_min`
+    pub const REFLNS_LIMIT_H_MIN: TypedItem<i64> = TypedItem::new(CoreItem::ReflnsLimitHMin);
+
+    /// `_reflns.limit_k_max`
+    pub const REFLNS_LIMIT_K_MAX: TypedItem<i64> = TypedItem::new(CoreItem::ReflnsLimitKMax);
+
+    /// `_reflns.limit_k_min`
+    pub const REFLNS_LIMIT_K_MIN: TypedItem<i64> = TypedItem::new(CoreItem::ReflnsLimitKMin);
+
+    /// `_reflns.limit_l_max`
+    pub const REFLNS_LIMIT_L_MAX: TypedItem<i64> = TypedItem::new(CoreItem::ReflnsLimitLMax);
+
+    /// `_reflns.limit_l_min`
+    pub const REFLNS_LIMIT_L_MIN: TypedItem<i64> = TypedItem::new(CoreItem::ReflnsLimitLMin);
+
+    /// `_reflns.number_gt`
+    pub const REFLNS_NUMBER_GT: TypedItem<i64> = TypedItem::new(CoreItem::ReflnsNumberGt);
+
+    /// `_reflns.number_total`
+    pub const REFLNS_NUMBER_TOTAL: TypedItem<i64> = TypedItem::new(CoreItem::ReflnsNumberTotal);
+
+    /// `_reflns.special_details`
+    pub const REFLNS_SPECIAL_DETAILS: TypedItem<String> = TypedItem::new(CoreItem::ReflnsSpecialDetails);
+
+    /// `_reflns.threshold_expression`
+    pub const REFLNS_THRESHOLD_EXPRESSION: TypedItem<String> = TypedItem::new(CoreItem::ReflnsThresholdExpression);
+
+    /// `_space_group.Bravais_type`
+    pub const SPACE_GROUP_BRAVAIS_TYPE: TypedItem<String> = TypedItem::new(CoreItem::SpaceGroupBravaisType);
+
+    /// `_space_group.centring_type`
+    pub const SPACE_GROUP_CENTRING_TYPE: TypedItem<String> = TypedItem::new(CoreItem::SpaceGroupCentringType);
+
+    /// `_space_group.crystal_system`
+    pub const SPACE_GROUP_CRYSTAL_SYSTEM: TypedItem<String> = TypedItem::new(CoreItem::SpaceGroupCrystalSystem);
+
+    /// `_space_group.IT_coordinate_system_code`
+    pub const SPACE_GROUP_IT_COORDINATE_SYSTEM_CODE: TypedItem<String> = TypedItem::new(CoreItem::SpaceGroupItCoordinateSystemCode);
+
+    /// `_space_group.IT_number`
+    pub const SPACE_GROUP_IT_NUMBER: TypedItem<i64> = TypedItem::new(CoreItem::SpaceGroupItNumber);
+
+    /// `_space_group.Laue_class`
+    pub const SPACE_GROUP_LAUE_CLASS: TypedItem<String> = TypedItem::new(CoreItem::SpaceGroupLaueClass);
+
+    /// `_space_group.multiplicity`
+    pub const SPACE_GROUP_MULTIPLICITY: TypedItem<i64> = TypedItem::new(CoreItem::SpaceGroupMultiplicity);
+
+    /// `_space_group.name_H-M_alt`
+    pub const SPACE_GROUP_NAME_H_M_ALT: TypedItem<String> = TypedItem::new(CoreItem::SpaceGroupNameHMAlt);
+
+    /// `_space_group.name_H-M_alt_description`
+    pub const SPACE_GROUP_NAME_H_M_ALT_DESCRIPTION: TypedItem<String> = TypedItem::new(CoreItem::SpaceGroupNameHMAltDescription);
+
+    /// `_space_group.name_H-M_full`
+    pub const SPACE_GROUP_NAME_H_M_FULL: TypedItem<String> = TypedItem::new(CoreItem::SpaceGroupNameHMFull);
+
+    /// `_space_group.name_H-M_ref`
+    pub const SPACE_GROUP_NAME_H_M_REF: TypedItem<String> = TypedItem::new(CoreItem::SpaceGroupNameHMRef);
+
+    /// `_space_group.name_Hall`
+    pub const SPACE_GROUP_NAME_HALL: TypedItem<String> = TypedItem::new(CoreItem::SpaceGroupNameHall);
+
+    /// `_space_group.name_Schoenflies`
+    pub const SPACE_GROUP_NAME_SCHOENFLIES: TypedItem<String> = TypedItem::new(CoreItem::SpaceGroupNameSchoenflies);
+
+    /// `_space_group.Patterson_name_H-M`
+    pub const SPACE_GROUP_PATTERSON_NAME_H_M: TypedItem<String> = TypedItem::new(CoreItem::SpaceGroupPattersonNameHM);
+
+    /// `_space_group.point_group_H-M`
+    pub const SPACE_GROUP_POINT_GROUP_H_M: TypedItem<String> = TypedItem::new(CoreItem::SpaceGroupPointGroupHM);
+
+    /// `_space_group_symop.id`
+    pub const SPACE_GROUP_SYMOP_ID: TypedItem<i64> = TypedItem::new(CoreItem::SpaceGroupSymopId);
+
+    /// `_space_group_symop.operation_description`
+    pub const SPACE_GROUP_SYMOP_OPERATION_DESCRIPTION: TypedItem<String> = TypedItem::new(CoreItem::SpaceGroupSymopOperationDescription);
+
+    /// `_space_group_symop.operation_xyz`
+    pub const SPACE_GROUP_SYMOP_OPERATION_XYZ: TypedItem<String> = TypedItem::new(CoreItem::SpaceGroupSymopOperationXyz);
+
+    /// `_symmetry.cell_setting`
+    pub const SYMMETRY_CELL_SETTING: TypedItem<String> = TypedItem::new(CoreItem::SymmetryCellSetting);
+}
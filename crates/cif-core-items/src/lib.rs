@@ -0,0 +1,301 @@
+//! Typed access to well-known CIF core data items.
+//!
+//! Looking items up by string (`block.get_item("_cell.length_a")`) compiles
+//! whatever the string says, so a typo only shows up as a missing value at
+//! run time. [`CoreItem`] names several hundred data items of the CIF core
+//! dictionary instead, each with its canonical name, its legacy aliases and
+//! the Rust type its values are read as. The constants in [`items`] carry
+//! that type, so [`CoreItemsExt::get`] finds an item under any of its names
+//! and returns it as a [`Measurand`], `f64`, `i64` or `String`:
+//!
+//! ```
+//! use cif_core_items::{items, CoreItemsExt};
+//!
+//! let doc = cif_parser::parse_string(
+//!     "data_x\n_cell_length_a 10.5(2)\n_space_group.IT_number 14\n",
+//! )
+//! .unwrap();
+//! let block = doc.first_block().unwrap();
+//!
+//! let a = block.get(items::CELL_LENGTH_A).unwrap();
+//! assert_eq!(a.value, 10.5);
+//! assert_eq!(a.uncertainty, Some(0.2));
+//!
+//! assert_eq!(block.get(items::SPACE_GROUP_IT_NUMBER), Some(14));
+//! ```
+//!
+//! Code that picks items at run time reads them through [`CoreItem::read`]
+//! into a [`CoreValue`]. Tags outside the enum (other dictionaries, vendor
+//! extensions) keep using the string API of `cif-parser`.
+//!
+//! The enum and the constants are generated from `dic/cif_core_items.dic`, a
+//! trimmed copy of the core dictionary holding only names, aliases and types.
+//! After editing it, run `cargo run -p cif-core-items --bin generate-core-items`.
+
+use cif_parser::{CifBlock, CifValue, Measurand};
+use std::collections::HashMap;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::OnceLock;
+
+#[doc(hidden)]
+pub mod codegen;
+#[rustfmt::skip]
+mod generated;
+
+pub use generated::{items, CoreItem};
+
+/// Rust type the values of a [`CoreItem`] are read as
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValueType {
+    /// A measured real number with an optional standard uncertainty
+    Measurand,
+    /// A real number
+    Real,
+    /// A whole number
+    Integer,
+    /// Text, codes, names, dates and symmetry operators
+    Text,
+}
+
+/// A value read as the type of its [`CoreItem`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum CoreValue {
+    /// Value of a [`ValueType::Measurand`] item
+    Measurand(Measurand),
+    /// Value of a [`ValueType::Real`] item
+    Real(f64),
+    /// Value of a [`ValueType::Integer`] item
+    Integer(i64),
+    /// Value of a [`ValueType::Text`] item
+    Text(String),
+}
+
+impl CoreValue {
+    /// The measurand, if this is one
+    pub fn as_measurand(&self) -> Option<Measurand> {
+        match self {
+            CoreValue::Measurand(m) => Some(*m),
+            _ => None,
+        }
+    }
+
+    /// The real number, if this is one
+    pub fn as_real(&self) -> Option<f64> {
+        match self {
+            CoreValue::Real(r) => Some(*r),
+            _ => None,
+        }
+    }
+
+    /// The whole number, if this is one
+    pub fn as_integer(&self) -> Option<i64> {
+        match self {
+            CoreValue::Integer(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    /// The text, if this is text
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            CoreValue::Text(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+/// Rust type a [`TypedItem`] reads its values as
+pub trait ItemValue: Sized {
+    /// The [`ValueType`] this Rust type stands for
+    const VALUE_TYPE: ValueType;
+
+    /// Read `value`; `None` for `?`, `.` and values of the wrong kind
+    fn read(value: &CifValue) -> Option<Self>;
+}
+
+impl ItemValue for Measurand {
+    const VALUE_TYPE: ValueType = ValueType::Measurand;
+
+    fn read(value: &CifValue) -> Option<Self> {
+        Measurand::from_value(value)
+    }
+}
+
+impl ItemValue for f64 {
+    const VALUE_TYPE: ValueType = ValueType::Real;
+
+    fn read(value: &CifValue) -> Option<Self> {
+        value.as_numeric()
+    }
+}
+
+impl ItemValue for i64 {
+    const VALUE_TYPE: ValueType = ValueType::Integer;
+
+    /// A fraction is of the wrong kind
+    fn read(value: &CifValue) -> Option<Self> {
+        value
+            .as_numeric()
+            .filter(|n| n.fract() == 0.0)
+            .map(|n| n as i64)
+    }
+}
+
+impl ItemValue for String {
+    const VALUE_TYPE: ValueType = ValueType::Text;
+
+    fn read(value: &CifValue) -> Option<Self> {
+        value.as_string().map(str::to_string)
+    }
+}
+
+/// A [`CoreItem`] together with the Rust type of its values.
+///
+/// The constants in [`items`] are the typed items; `items::CELL_LENGTH_A` is
+/// a `TypedItem<Measurand>`, so [`CoreItemsExt::get`] returns a `Measurand`.
+pub struct TypedItem<T> {
+    item: CoreItem,
+    value: PhantomData<fn() -> T>,
+}
+
+impl<T: ItemValue> TypedItem<T> {
+    /// Pair an item with the type of its values; used by the generated
+    /// constants, which are checked against [`CoreItem::value_type`]
+    #[doc(hidden)]
+    pub const fn new(item: CoreItem) -> Self {
+        assert!(
+            item.value_type() as u8 == T::VALUE_TYPE as u8,
+            "value type does not match the item"
+        );
+        Self {
+            item,
+            value: PhantomData,
+        }
+    }
+}
+
+impl<T> TypedItem<T> {
+    /// The untyped item
+    pub const fn item(self) -> CoreItem {
+        self.item
+    }
+}
+
+impl<T> Clone for TypedItem<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for TypedItem<T> {}
+
+impl<T> PartialEq for TypedItem<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.item == other.item
+    }
+}
+
+impl<T> Eq for TypedItem<T> {}
+
+impl<T> fmt::Debug for TypedItem<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TypedItem").field(&self.item).finish()
+    }
+}
+
+impl<T> From<TypedItem<T>> for CoreItem {
+    fn from(item: TypedItem<T>) -> Self {
+        item.item
+    }
+}
+
+impl CoreItem {
+    /// Look up an item by its canonical name or any alias (case-insensitive)
+    ///
+    /// ```
+    /// use cif_core_items::CoreItem;
+    ///
+    /// assert_eq!(CoreItem::from_name("_CELL_LENGTH_A"), Some(CoreItem::CellLengthA));
+    /// assert_eq!(CoreItem::from_name("_vendor.setting"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<CoreItem> {
+        static BY_NAME: OnceLock<HashMap<String, CoreItem>> = OnceLock::new();
+        let by_name = BY_NAME.get_or_init(|| {
+            CoreItem::ALL
+                .iter()
+                .flat_map(|&item| item.names().map(move |name| (name.to_lowercase(), item)))
+                .collect()
+        });
+        by_name.get(&name.to_lowercase()).copied()
+    }
+
+    /// The canonical name followed by the aliases
+    pub fn names(self) -> impl Iterator<Item = &'static str> {
+        std::iter::once(self.name()).chain(self.aliases().iter().copied())
+    }
+
+    /// Whether `tag` is one of this item's names (case-insensitive)
+    pub fn matches(self, tag: &str) -> bool {
+        self.names().any(|name| name.eq_ignore_ascii_case(tag))
+    }
+
+    /// Read `value` as this item's [`ValueType`].
+    ///
+    /// Returns `None` for `?`, `.` and values of the wrong kind; a whole
+    /// number item holding a fraction is of the wrong kind.
+    pub fn read(self, value: &CifValue) -> Option<CoreValue> {
+        match self.value_type() {
+            ValueType::Measurand => ItemValue::read(value).map(CoreValue::Measurand),
+            ValueType::Real => ItemValue::read(value).map(CoreValue::Real),
+            ValueType::Integer => ItemValue::read(value).map(CoreValue::Integer),
+            ValueType::Text => ItemValue::read(value).map(CoreValue::Text),
+        }
+    }
+}
+
+/// [`CoreItem`] lookups on a data block
+pub trait CoreItemsExt {
+    /// The value of a single (unlooped) item, under whichever of its names
+    /// the block uses; the current name wins over a legacy alias
+    fn get_value(&self, item: CoreItem) -> Option<&CifValue>;
+
+    /// The values of a looped item, under whichever of its names the block
+    /// uses
+    fn column(&self, item: CoreItem) -> Option<Vec<&CifValue>>;
+
+    /// Read a single item as the Rust type of its values
+    fn get<T: ItemValue>(&self, item: TypedItem<T>) -> Option<T> {
+        self.get_value(item.item()).and_then(T::read)
+    }
+
+    /// Read every value of a looped item as the Rust type of its values;
+    /// `None` marks rows where it is missing or of the wrong kind
+    fn get_column<T: ItemValue>(&self, item: TypedItem<T>) -> Option<Vec<Option<T>>> {
+        let column = self.column(item.item())?;
+        Some(column.into_iter().map(T::read).collect())
+    }
+}
+
+impl CoreItemsExt for CifBlock {
+    fn get_value(&self, item: CoreItem) -> Option<&CifValue> {
+        // Look the names up directly; only a file spelling them in another
+        // case needs a pass over the items
+        item.names()
+            .find_map(|name| self.items.get(name))
+            .or_else(|| {
+                self.items
+                    .iter()
+                    .filter(|(tag, _)| item.matches(tag))
+                    .min_by_key(|(tag, value)| (value.span.start_line, value.span.start_col, *tag))
+                    .map(|(_, value)| value)
+            })
+    }
+
+    fn column(&self, item: CoreItem) -> Option<Vec<&CifValue>> {
+        self.loops.iter().find_map(|loop_| {
+            let col = loop_.tags.iter().position(|tag| item.matches(tag))?;
+            Some(loop_.values.iter().map(|row| &row[col]).collect())
+        })
+    }
+}
@@ -0,0 +1,107 @@
+//! Integration tests for typed access to core items
+
+use cif_core_items::{items, CoreItem, CoreItemsExt, CoreValue, ValueType};
+use cif_parser::{parse_string, CifDocument};
+
+fn fixture_path(name: &str) -> String {
+    format!("{}/../../fixtures/{}", env!("CARGO_MANIFEST_DIR"), name)
+}
+
+#[test]
+fn test_get_reads_items_under_any_name() {
+    let doc = parse_string(
+        "data_x\n\
+         _cell.length_a 5.4310(2)\n\
+         _CELL_LENGTH_B 5.4310\n\
+         _cell_measurement_temperature 293\n\
+         _space_group_IT_number 227\n\
+         _chemical_formula_sum 'Si'\n\
+         _refine_ls_R_factor_gt 0.021(3)\n",
+    )
+    .unwrap();
+    let block = doc.first_block().unwrap();
+
+    let a = block.get(items::CELL_LENGTH_A).unwrap();
+    assert_eq!((a.value, a.uncertainty), (5.431, Some(0.0002)));
+    let b = block.get(items::CELL_LENGTH_B).unwrap();
+    assert_eq!((b.value, b.uncertainty), (5.431, None));
+
+    assert_eq!(block.get(items::SPACE_GROUP_IT_NUMBER), Some(227));
+    assert_eq!(
+        block.get(items::CHEMICAL_FORMULA_SUM).as_deref(),
+        Some("Si")
+    );
+    assert_eq!(block.get(items::REFINE_LS_R_FACTOR_GT), Some(0.021));
+    assert!(block.get(items::CELL_LENGTH_C).is_none());
+
+    // Items picked at run time are read into a `CoreValue`
+    let item = CoreItem::from_name("_symmetry_Int_Tables_number").unwrap();
+    assert_eq!(
+        block.get_value(item).and_then(|value| item.read(value)),
+        Some(CoreValue::Integer(227))
+    );
+    assert_eq!(CoreItem::from(items::CELL_LENGTH_A), CoreItem::CellLengthA);
+}
+
+#[test]
+fn test_current_name_wins_over_alias() {
+    let doc = parse_string("data_x\n_cell_length_a 4.0\n_cell.length_a 5.0\n").unwrap();
+    let block = doc.first_block().unwrap();
+    assert_eq!(
+        block.get_value(CoreItem::CellLengthA).unwrap().as_numeric(),
+        Some(5.0)
+    );
+}
+
+#[test]
+fn test_values_of_the_wrong_kind_are_none() {
+    let doc =
+        parse_string("data_x\n_cell.length_a ?\n_cell.length_b abc\n_space_group.IT_number 14.5\n")
+            .unwrap();
+    let block = doc.first_block().unwrap();
+
+    for item in [items::CELL_LENGTH_A, items::CELL_LENGTH_B] {
+        assert!(block.get_value(item.item()).is_some());
+        assert!(block.get(item).is_none(), "{item:?}");
+    }
+    assert!(block.get_value(CoreItem::SpaceGroupItNumber).is_some());
+    assert!(block.get(items::SPACE_GROUP_IT_NUMBER).is_none());
+}
+
+#[test]
+fn test_looped_items_on_a_real_file() {
+    let doc = CifDocument::from_file(fixture_path("ccdc_paracetamol.cif")).unwrap();
+    let block = doc.first_block().unwrap();
+
+    let labels = block.get_column(items::ATOM_SITE_LABEL).unwrap();
+    let xs = block.get_column(items::ATOM_SITE_FRACT_X).unwrap();
+    assert_eq!(labels.len(), xs.len());
+    assert!(labels.iter().all(Option::is_some));
+    assert!(xs.iter().all(Option::is_some));
+
+    let volume = block.get(items::CELL_VOLUME).unwrap();
+    assert!(volume.value > 0.0);
+}
+
+#[test]
+fn test_from_name_and_value_types() {
+    assert_eq!(
+        CoreItem::from_name("_symmetry_Int_Tables_number"),
+        CoreItem::from_name("_space_group.IT_number"),
+    );
+    assert_eq!(
+        CoreItem::from_name("_cell.length_a"),
+        Some(CoreItem::CellLengthA)
+    );
+    assert_eq!(CoreItem::from_name("_cell.length_a_typo"), None);
+
+    assert_eq!(CoreItem::CellLengthA.value_type(), ValueType::Measurand);
+    assert_eq!(CoreItem::CellLengthASu.value_type(), ValueType::Real);
+    assert_eq!(CoreItem::CellFormulaUnitsZ.value_type(), ValueType::Real);
+    assert_eq!(
+        CoreItem::DiffrnReflnsNumber.value_type(),
+        ValueType::Integer
+    );
+    assert_eq!(CoreItem::AtomSiteLabel.value_type(), ValueType::Text);
+    assert!(CoreItem::CellLengthA.matches("_CELL_LENGTH_A"));
+}
@@ -0,0 +1,104 @@
+//! Tests that the generated enum matches the dictionaries it comes from
+
+use cif_core_items::codegen::{self, DICTIONARY};
+use cif_core_items::CoreItem;
+use cif_parser::CifDocument;
+use std::collections::HashMap;
+
+const GENERATED: &str = include_str!("../src/generated.rs");
+
+#[test]
+fn test_generated_source_is_up_to_date() {
+    let regenerated = codegen::generate(DICTIONARY).expect("bundled dictionary should generate");
+    if regenerated == GENERATED {
+        return;
+    }
+    let line = regenerated
+        .lines()
+        .zip(GENERATED.lines())
+        .position(|(new, old)| new != old)
+        .map_or(
+            regenerated.lines().count().min(GENERATED.lines().count()),
+            |i| i,
+        );
+    panic!(
+        "src/generated.rs is out of date from line {}; run \
+         `cargo run -p cif-core-items --bin generate-core-items`",
+        line + 1
+    );
+}
+
+#[test]
+fn test_enum_covers_every_bundled_definition() {
+    let definitions = codegen::definitions(DICTIONARY).unwrap();
+    assert_eq!(definitions.len(), CoreItem::ALL.len());
+    assert!(CoreItem::ALL.len() > 400, "expected several hundred items");
+
+    for (definition, &item) in definitions.iter().zip(CoreItem::ALL) {
+        assert_eq!(item.name(), definition.name);
+        assert_eq!(item.aliases(), definition.aliases.as_slice());
+        assert_eq!(item.value_type(), definition.value_type);
+    }
+}
+
+/// The trimmed dictionary must not drift from the full one it was cut from
+#[test]
+fn test_items_match_the_full_core_dictionary() {
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../cif-validator/dics/cif_core.dic"
+    );
+    let text = std::fs::read_to_string(path).unwrap();
+    let core = CifDocument::parse(&text).unwrap();
+
+    let mut aliases: HashMap<String, Vec<String>> = HashMap::new();
+    let mut types = HashMap::new();
+    for frame in &core.blocks[0].frames {
+        let Some(id) = frame.get_item("_definition.id").and_then(|v| v.as_string()) else {
+            continue;
+        };
+        let names = match frame.find_loop("_alias.definition_id") {
+            Some(loop_) => loop_
+                .get_column("_alias.definition_id")
+                .unwrap()
+                .iter()
+                .filter_map(|v| v.as_string().map(str::to_string))
+                .collect(),
+            None => frame
+                .get_item("_alias.definition_id")
+                .and_then(|v| v.as_string())
+                .map(|alias| vec![alias.to_string()])
+                .unwrap_or_default(),
+        };
+        aliases.insert(id.to_lowercase(), names);
+
+        // Types imported from templ_attr.cif are not in the file
+        let text = |tag| frame.get_item(tag).and_then(|v| v.as_string());
+        if let (Some(purpose), Some(contents)) = (text("_type.purpose"), text("_type.contents")) {
+            types.insert(id.to_lowercase(), codegen::value_type(purpose, contents));
+        }
+    }
+
+    for &item in CoreItem::ALL {
+        let Some(core_aliases) = aliases.get(&item.name().to_lowercase()) else {
+            panic!("{} is not defined in cif_core.dic", item.name());
+        };
+        assert_eq!(item.aliases(), core_aliases.as_slice(), "{}", item.name());
+        if let Some(&core_type) = types.get(&item.name().to_lowercase()) {
+            assert_eq!(Some(item.value_type()), core_type, "{}", item.name());
+        }
+    }
+}
+
+#[test]
+fn test_variant_names() {
+    assert_eq!(codegen::variant_name("_cell.length_a"), "CellLengthA");
+    assert_eq!(
+        codegen::variant_name("_space_group.name_H-M_alt"),
+        "SpaceGroupNameHMAlt"
+    );
+    assert_eq!(
+        codegen::variant_name("_atom_site_aniso.U_11"),
+        "AtomSiteAnisoU11"
+    );
+}
@@ -1,6 +1,7 @@
 //! Benchmarks for CIF parsing performance
 
-use cif_parser::{CIFParser, CifDocument, Rule};
+use cif_parser::internal::{CIFParser, Rule};
+use cif_parser::CifDocument;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use pest::Parser;
 use std::path::PathBuf;
@@ -78,10 +79,102 @@ fn bench_full_ast_parse(c: &mut Criterion) {
     });
 }
 
+/// A single atom_site-style loop with `rows` rows of eight values
+fn synthetic_loop(rows: usize) -> String {
+    let mut content = String::from(
+        "data_large_loop\nloop_\n_atom_site_label\n_atom_site_type_symbol\n\
+         _atom_site_fract_x\n_atom_site_fract_y\n_atom_site_fract_z\n\
+         _atom_site_U_iso_or_equiv\n_atom_site_adp_type\n_atom_site_occupancy\n",
+    );
+    for i in 0..rows {
+        content.push_str(&format!(
+            "C{i} C 0.{:04}(3) 0.{:04}(4) 0.{:04}(5) 0.0{:03} Uani 1\n",
+            i % 10000,
+            (i * 7) % 10000,
+            (i * 13) % 10000,
+            i % 1000
+        ));
+    }
+    content
+}
+
+fn bench_large_loop_parse(c: &mut Criterion) {
+    let content = synthetic_loop(100_000);
+
+    let mut group = c.benchmark_group("large_loop");
+    group.sample_size(10);
+    group.bench_function("full_ast_parse_100k_rows", |b| {
+        b.iter(|| {
+            let doc = CifDocument::parse(black_box(&content)).expect("Failed to parse");
+            black_box(doc)
+        })
+    });
+    group.finish();
+}
+
+/// Reading back a stored document versus parsing its source again
+fn bench_serialized_round_trip(c: &mut Criterion) {
+    let content = synthetic_loop(100_000);
+    let doc = CifDocument::parse(&content).expect("Failed to parse");
+    let mut cbor = Vec::new();
+    ciborium::into_writer(&doc, &mut cbor).expect("Failed to serialize");
+    let json = serde_json::to_string(&doc).expect("Failed to serialize");
+    let bincode = bincode::serialize(&doc).expect("Failed to serialize");
+    // bincode is not self-describing; check the document reads back before timing it
+    let decoded: CifDocument = bincode::deserialize(&bincode).expect("Failed to decode");
+    assert_eq!(decoded, doc);
+    println!(
+        "stored sizes: json {} B, cbor {} B, bincode {} B",
+        json.len(),
+        cbor.len(),
+        bincode.len()
+    );
+
+    let mut group = c.benchmark_group("serialized_100k_rows");
+    group.sample_size(10);
+    group.bench_function("parse_source", |b| {
+        b.iter(|| CifDocument::parse(black_box(&content)).expect("Failed to parse"))
+    });
+    group.bench_function("cbor_decode", |b| {
+        b.iter(|| {
+            let doc: CifDocument =
+                ciborium::from_reader(black_box(cbor.as_slice())).expect("Failed to decode");
+            doc
+        })
+    });
+    group.bench_function("json_decode", |b| {
+        b.iter(|| {
+            let doc: CifDocument =
+                serde_json::from_str(black_box(&json)).expect("Failed to decode");
+            doc
+        })
+    });
+    group.bench_function("bincode_decode", |b| {
+        b.iter(|| {
+            let doc: CifDocument =
+                bincode::deserialize(black_box(&bincode)).expect("Failed to decode");
+            doc
+        })
+    });
+    group.bench_function("cbor_encode", |b| {
+        b.iter(|| {
+            let mut bytes = Vec::with_capacity(cbor.len());
+            ciborium::into_writer(black_box(&doc), &mut bytes).expect("Failed to serialize");
+            bytes
+        })
+    });
+    group.bench_function("bincode_encode", |b| {
+        b.iter(|| bincode::serialize(black_box(&doc)).expect("Failed to serialize"))
+    });
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_pest_parse_lazy,
     bench_pest_full_traversal,
-    bench_full_ast_parse
+    bench_full_ast_parse,
+    bench_large_loop_parse,
+    bench_serialized_round_trip
 );
 criterion_main!(benches);
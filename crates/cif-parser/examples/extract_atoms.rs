@@ -0,0 +1,99 @@
+// Run with: cargo run --example extract_atoms [path/to/file.cif]
+//
+// Reads the atom_site loop into a Vec of plain structs, accepting both the
+// DDLm (`_atom_site.label`) and legacy (`_atom_site_label`) tag spellings.
+// Without an argument it reads the bundled fixtures/examples/structure.cif.
+
+use cif_parser::{CifBlock, CifDocument, CifLoop};
+use std::error::Error;
+
+const DEFAULT_INPUT: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../fixtures/examples/structure.cif"
+);
+
+/// One atom site, with coordinates as fractions of the cell
+#[derive(Debug, Clone, PartialEq)]
+struct Atom {
+    label: String,
+    element: String,
+    fract: [f64; 3],
+    /// Standard uncertainties of `fract`, zero where none was given
+    fract_su: [f64; 3],
+    occupancy: f64,
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| DEFAULT_INPUT.to_string());
+    let doc = CifDocument::from_file(&path)?;
+    let block = doc.first_block().ok_or("no data block")?;
+
+    let atoms = extract_atoms(block)?;
+    println!("{} atoms in data_{}", atoms.len(), block.name);
+    for atom in &atoms {
+        println!(
+            "{:<4} {:<2} {:>8.4} {:>8.4} {:>8.4}  occ {}",
+            atom.label, atom.element, atom.fract[0], atom.fract[1], atom.fract[2], atom.occupancy
+        );
+    }
+    Ok(())
+}
+
+/// The atom_site loop of `block` as structs
+fn extract_atoms(block: &CifBlock) -> Result<Vec<Atom>, String> {
+    let loop_ = ["_atom_site.label", "_atom_site_label"]
+        .iter()
+        .find_map(|tag| block.find_loop(tag))
+        .ok_or("no atom_site loop")?;
+
+    // Column of each field, in either spelling
+    let column = |object: &str| {
+        let names = [
+            format!("_atom_site.{object}"),
+            format!("_atom_site_{object}"),
+        ];
+        loop_
+            .tags
+            .iter()
+            .position(|tag| names.iter().any(|name| tag.eq_ignore_ascii_case(name)))
+    };
+    let label = column("label").ok_or("no label column")?;
+    let element = column("type_symbol");
+    let fract = ["fract_x", "fract_y", "fract_z"]
+        .map(|object| column(object).ok_or(format!("no {object} column")));
+    let [x, y, z] = fract;
+    let fract = [x?, y?, z?];
+    let occupancy = column("occupancy");
+
+    (0..loop_.len())
+        .map(|row| {
+            let text = |col: usize| cell(loop_, row, col).as_string().map(str::to_string);
+            let number = |col: usize| cell(loop_, row, col).as_numeric();
+            let su = |col: usize| cell(loop_, row, col).uncertainty().unwrap_or(0.0);
+
+            let label = text(label).ok_or(format!("row {row}: label is not text"))?;
+            let coordinate =
+                |col: usize| number(col).ok_or(format!("{label}: coordinate is not a number"));
+            Ok(Atom {
+                element: element.and_then(text).unwrap_or_default(),
+                fract: [
+                    coordinate(fract[0])?,
+                    coordinate(fract[1])?,
+                    coordinate(fract[2])?,
+                ],
+                fract_su: fract.map(su),
+                // Occupancy defaults to 1 when absent or unknown
+                occupancy: occupancy.and_then(number).unwrap_or(1.0),
+                label,
+            })
+        })
+        .collect()
+}
+
+fn cell(loop_: &CifLoop, row: usize, col: usize) -> &cif_parser::CifValue {
+    loop_
+        .get(row, col)
+        .expect("loop rows have one value per tag")
+}
@@ -1,6 +1,5 @@
 // Reading from files
 
-use cif_parser;
 use std::error::Error;
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -0,0 +1,97 @@
+// Run with: cargo run --example parse_and_summarize [path/to/file.cif]
+//
+// Parses a CIF file and prints each block's items, loops and the common
+// crystallographic metadata from `CifBlock::summary`. Without an argument it
+// reads the bundled fixtures/examples/structure.cif.
+
+use cif_parser::{CifBlock, CifDocument, Measurand};
+use std::error::Error;
+use std::fmt::Write;
+
+const DEFAULT_INPUT: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../fixtures/examples/structure.cif"
+);
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| DEFAULT_INPUT.to_string());
+    let doc = CifDocument::from_file(&path)?;
+    print!("{}", summarize(&doc));
+    Ok(())
+}
+
+/// Block and loop statistics, then the summary of each block
+fn summarize(doc: &CifDocument) -> String {
+    let mut out = String::new();
+    writeln!(
+        out,
+        "CIF version {}, {} block(s)",
+        doc.version,
+        doc.blocks.len()
+    )
+    .unwrap();
+    for block in &doc.blocks {
+        summarize_block(&mut out, block);
+    }
+    out
+}
+
+fn summarize_block(out: &mut String, block: &CifBlock) {
+    writeln!(out, "\ndata_{}", block.name).unwrap();
+    writeln!(
+        out,
+        "  {} items, {} loops, {} save frames",
+        block.items.len(),
+        block.loops.len(),
+        block.frames.len()
+    )
+    .unwrap();
+    for loop_ in &block.loops {
+        let first = loop_.tags.first().map_or("", String::as_str);
+        writeln!(
+            out,
+            "  loop of {} columns x {} rows, starting {}",
+            loop_.tags.len(),
+            loop_.len(),
+            first
+        )
+        .unwrap();
+    }
+
+    let summary = block.summary();
+    if summary.is_empty() {
+        return;
+    }
+    writeln!(out, "  summary:").unwrap();
+    if let Some(formula) = &summary.chemical_formula_sum {
+        writeln!(out, "    formula      {formula}").unwrap();
+    }
+    if let Some(name) = &summary.space_group_name_hm {
+        let number = summary
+            .space_group_number
+            .map_or(String::new(), |n| format!(" (No. {n})"));
+        writeln!(out, "    space group  {name}{number}").unwrap();
+    }
+    let cell: Vec<String> = summary.cell.iter().map(measurand).collect();
+    writeln!(out, "    cell         {}", cell.join(" ")).unwrap();
+    if let Some(volume) = &summary.volume {
+        writeln!(out, "    volume       {}", measurand(&Some(*volume))).unwrap();
+    }
+    if let Some(z) = summary.z {
+        writeln!(out, "    Z            {z}").unwrap();
+    }
+}
+
+/// A value with its uncertainty, or `?` when it is missing
+fn measurand(value: &Option<Measurand>) -> String {
+    match value {
+        Some(Measurand {
+            value,
+            uncertainty: Some(su),
+        }) => format!("{value}±{su}"),
+        Some(Measurand { value, .. }) => value.to_string(),
+        None => "?".to_string(),
+    }
+}
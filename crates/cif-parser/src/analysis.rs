@@ -0,0 +1,394 @@
+//! Inventory of version-sensitive constructs in a CIF file.
+//!
+//! [`feature_report`] runs only the version-agnostic first pass, so it works
+//! on files that one CIF version or the other would reject. The resulting
+//! [`FeatureReport`] lists each construct with a count and the location of
+//! its first occurrence, and judges which versions can hold the file as-is.
+//!
+//! ```
+//! use cif_parser::analysis::{feature_report, Feature};
+//! use cif_parser::CifVersion;
+//!
+//! let report = feature_report("#\\#CIF_2.0\ndata_t\n_a [1 2]\n_b 'Å'\n")?;
+//! assert_eq!(report.count(Feature::List), 1);
+//! assert_eq!(report.count(Feature::NonAscii), 1);
+//! assert_eq!(report.minimum_version(), CifVersion::V2_0);
+//! # Ok::<(), cif_parser::CifError>(())
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::ast::{CifVersion, MagicHeader, Span};
+use crate::error::CifError;
+use crate::raw::parser::{declared_version, parse_raw};
+use crate::raw::{
+    RawDataItem, RawDocument, RawLoop, RawTableKey, RawTextField, RawUnquoted, RawValue,
+};
+
+/// Longest line, in characters, that CIF 1.1 and CIF 2.0 allow
+pub const MAX_LINE_LENGTH: usize = 2048;
+
+/// Longest data name, in characters, that CIF 1.1 allows
+///
+/// CIF 2.0 drops the limit, but tools written for CIF 1.1 may truncate or
+/// reject longer names.
+pub const MAX_DATA_NAME_LENGTH: usize = 75;
+
+/// A construct whose meaning or validity depends on the CIF version
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum Feature {
+    /// `[...]` list syntax (a list in CIF 2.0, text in CIF 1.1)
+    List,
+    /// `{...}` table syntax (a table in CIF 2.0, text in CIF 1.1)
+    Table,
+    /// `'''...'''` or `"""..."""` (a string in CIF 2.0, text in CIF 1.1)
+    TripleQuotedString,
+    /// Characters outside ASCII (CIF 2.0 only)
+    NonAscii,
+    /// `''` or `""` escapes inside quoted strings (CIF 1.1 only)
+    DoubledQuoteEscape,
+    /// `data_` with no name (CIF 1.1 only)
+    EmptyBlockName,
+    /// `save_` frame with no name (CIF 1.1 only)
+    EmptyFrameName,
+    /// Lines longer than [`MAX_LINE_LENGTH`] characters (neither version)
+    LongLine,
+    /// Text fields using the line-folding protocol (`;\` opening line)
+    LineFolding,
+    /// Text fields using the text-prefix protocol (`;prefix\` opening line)
+    TextPrefix,
+    /// Data names longer than [`MAX_DATA_NAME_LENGTH`] characters (warned
+    /// about in both versions)
+    LongDataName,
+    /// Unquoted values longer than [`MAX_LINE_LENGTH`] characters (warned
+    /// about in both versions)
+    LongUnquotedValue,
+}
+
+impl Feature {
+    /// Short machine-readable name, e.g. `triple_quoted_string`
+    pub fn name(&self) -> &'static str {
+        match self {
+            Feature::List => "list",
+            Feature::Table => "table",
+            Feature::TripleQuotedString => "triple_quoted_string",
+            Feature::NonAscii => "non_ascii",
+            Feature::DoubledQuoteEscape => "doubled_quote_escape",
+            Feature::EmptyBlockName => "empty_block_name",
+            Feature::EmptyFrameName => "empty_frame_name",
+            Feature::LongLine => "long_line",
+            Feature::LineFolding => "line_folding",
+            Feature::TextPrefix => "text_prefix",
+            Feature::LongDataName => "long_data_name",
+            Feature::LongUnquotedValue => "long_unquoted_value",
+        }
+    }
+
+    /// Whether the construct is CIF 2.0 syntax that CIF 1.1 reads as text
+    fn is_cif2_syntax(&self) -> bool {
+        matches!(
+            self,
+            Feature::List | Feature::Table | Feature::TripleQuotedString
+        )
+    }
+
+    /// Whether CIF 2.0 rejects the construct
+    fn is_cif1_only(&self) -> bool {
+        matches!(
+            self,
+            Feature::DoubledQuoteEscape | Feature::EmptyBlockName | Feature::EmptyFrameName
+        )
+    }
+}
+
+impl fmt::Display for Feature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// How often a feature occurs and where it first appears
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct FeatureUsage {
+    /// The construct
+    pub feature: Feature,
+    /// Number of occurrences (characters for [`Feature::NonAscii`], lines
+    /// for [`Feature::LongLine`], syntactic constructs otherwise)
+    pub count: usize,
+    /// Location of the first occurrence
+    pub first: Span,
+}
+
+/// Version-sensitive constructs found in a CIF file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct FeatureReport {
+    /// The `#\#CIF_2.0` header, if the file has one
+    pub header: Option<MagicHeader>,
+    /// Every feature present, in [`Feature`] order
+    pub features: Vec<FeatureUsage>,
+}
+
+impl FeatureReport {
+    /// Version the file declares: CIF 2.0 with a well-placed header, else CIF 1.1
+    pub fn declared_version(&self) -> CifVersion {
+        declared_version(self.header.as_ref())
+    }
+
+    /// Usage of a single feature, if present
+    pub fn get(&self, feature: Feature) -> Option<&FeatureUsage> {
+        self.features.iter().find(|u| u.feature == feature)
+    }
+
+    /// Whether a feature is present
+    pub fn uses(&self, feature: Feature) -> bool {
+        self.get(feature).is_some()
+    }
+
+    /// Number of occurrences of a feature (0 when absent)
+    pub fn count(&self, feature: Feature) -> usize {
+        self.get(feature).map_or(0, |u| u.count)
+    }
+
+    /// Oldest version that can hold the file without losing content.
+    ///
+    /// CIF 2.0 when the file contains non-ASCII characters, or declares
+    /// CIF 2.0 and uses lists, tables, or triple-quoted strings.
+    pub fn minimum_version(&self) -> CifVersion {
+        let declared_cif2 = self.declared_version() == CifVersion::V2_0;
+        let needs_cif2 = self.features.iter().any(|u| {
+            u.feature == Feature::NonAscii || (declared_cif2 && u.feature.is_cif2_syntax())
+        });
+        if needs_cif2 {
+            CifVersion::V2_0
+        } else {
+            CifVersion::V1_1
+        }
+    }
+
+    /// Newest version the file can be labelled as without changing meaning.
+    ///
+    /// CIF 1.1 when the file uses constructs CIF 2.0 rejects (doubled-quote
+    /// escapes, empty block or frame names), or declares CIF 1.1 and contains
+    /// `[`, `{`, or triple-quote syntax that CIF 2.0 would read differently.
+    /// When this is older than [`minimum_version`](Self::minimum_version),
+    /// no version accepts the file unchanged.
+    pub fn maximum_compatible_version(&self) -> CifVersion {
+        let declared_cif1 = self.declared_version() == CifVersion::V1_1;
+        let blocks_cif2 = self
+            .features
+            .iter()
+            .any(|u| u.feature.is_cif1_only() || (declared_cif1 && u.feature.is_cif2_syntax()));
+        if blocks_cif2 {
+            CifVersion::V1_1
+        } else {
+            CifVersion::V2_0
+        }
+    }
+}
+
+/// Report the version-sensitive constructs in CIF source text.
+///
+/// Fails only if the text cannot be parsed by the permissive first pass;
+/// violations of either version's rules are reported as features instead.
+pub fn feature_report(input: &str) -> Result<FeatureReport, CifError> {
+    let raw = parse_raw(input)?;
+    let mut found = Found::default();
+    scan_text(input, &mut found);
+    scan_document(&raw, &mut found);
+
+    Ok(FeatureReport {
+        header: raw.magic_header.clone(),
+        features: found
+            .0
+            .into_iter()
+            .map(|(feature, (count, first))| FeatureUsage {
+                feature,
+                count,
+                first,
+            })
+            .collect(),
+    })
+}
+
+/// Occurrence counts and first spans, ordered by feature
+#[derive(Default)]
+struct Found(BTreeMap<Feature, (usize, Span)>);
+
+impl Found {
+    fn record(&mut self, feature: Feature, span: Span) {
+        let entry = self.0.entry(feature).or_insert((0, span));
+        entry.0 += 1;
+        if (span.start_line, span.start_col) < (entry.1.start_line, entry.1.start_col) {
+            entry.1 = span;
+        }
+    }
+}
+
+/// Character-level features: non-ASCII text and overlong lines
+fn scan_text(input: &str, found: &mut Found) {
+    let input = input.strip_prefix('\u{FEFF}').unwrap_or(input);
+    for (index, line) in input.lines().enumerate() {
+        let line_no = index + 1;
+        if line.chars().count() > MAX_LINE_LENGTH {
+            found.record(
+                Feature::LongLine,
+                Span::new(line_no, 1, line_no, line.len() + 1),
+            );
+        }
+        for (offset, c) in line.char_indices().filter(|(_, c)| !c.is_ascii()) {
+            let col = offset + 1;
+            found.record(
+                Feature::NonAscii,
+                Span::new(line_no, col, line_no, col + c.len_utf8()),
+            );
+        }
+    }
+}
+
+/// Syntax-level features from the raw AST
+fn scan_document(raw: &RawDocument, found: &mut Found) {
+    for block in &raw.blocks {
+        if block.name.is_empty() && !block.is_global {
+            found.record(Feature::EmptyBlockName, block.name_span);
+        }
+        scan_items(&block.items, found);
+        for loop_ in &block.loops {
+            scan_loop(loop_, found);
+        }
+        for frame in &block.frames {
+            if frame.name.is_empty() {
+                found.record(Feature::EmptyFrameName, frame.name_span);
+            }
+            scan_items(&frame.items, found);
+            for loop_ in &frame.loops {
+                scan_loop(loop_, found);
+            }
+        }
+    }
+}
+
+fn scan_items(items: &[RawDataItem], found: &mut Found) {
+    for item in items {
+        scan_data_name(&item.tag, item.tag_span, found);
+        scan_value(&item.value, found);
+    }
+}
+
+fn scan_loop(loop_: &RawLoop, found: &mut Found) {
+    for tag in &loop_.tags {
+        scan_data_name(&tag.name, tag.span, found);
+    }
+    for value in &loop_.values {
+        scan_value(value, found);
+    }
+}
+
+fn scan_data_name(name: &str, span: Span, found: &mut Found) {
+    if name.chars().count() > MAX_DATA_NAME_LENGTH {
+        found.record(Feature::LongDataName, span);
+    }
+}
+
+fn scan_unquoted(unquoted: &RawUnquoted, found: &mut Found) {
+    if unquoted.text.chars().count() > MAX_LINE_LENGTH {
+        found.record(Feature::LongUnquotedValue, unquoted.span);
+    }
+}
+
+fn scan_value(value: &RawValue, found: &mut Found) {
+    match value {
+        RawValue::QuotedString(q) => {
+            if q.has_doubled_quotes {
+                found.record(Feature::DoubledQuoteEscape, q.span);
+            }
+        }
+        RawValue::TripleQuotedString(t) => found.record(Feature::TripleQuotedString, t.span),
+        RawValue::TextField(text) => scan_text_field(text, found),
+        RawValue::Unquoted(unquoted) => scan_unquoted(unquoted, found),
+        RawValue::ListSyntax(list) => {
+            found.record(Feature::List, list.span);
+            for element in &list.elements {
+                scan_value(element, found);
+            }
+        }
+        RawValue::TableSyntax(table) => {
+            found.record(Feature::Table, table.span);
+            for entry in &table.entries {
+                match &entry.key {
+                    RawTableKey::TripleQuoted(t) => {
+                        found.record(Feature::TripleQuotedString, t.span)
+                    }
+                    RawTableKey::Quoted(q) if q.has_doubled_quotes => {
+                        found.record(Feature::DoubledQuoteEscape, q.span)
+                    }
+                    _ => {}
+                }
+                scan_value(&entry.value, found);
+            }
+        }
+    }
+}
+
+/// Detect the line-folding and text-prefix protocols from the opening line.
+///
+/// The opening line is `\` for folding, `prefix\` for a prefix, and
+/// `prefix\\` for both. A prefix only counts if every later line carries it.
+fn scan_text_field(text: &RawTextField, found: &mut Found) {
+    let mut lines = text.content.lines();
+    let Some(first) = lines.next().map(str::trim_end) else {
+        return;
+    };
+    let Some(head) = first.strip_suffix('\\') else {
+        return;
+    };
+
+    let (prefix, folded) = match head.strip_suffix('\\') {
+        Some(prefix) => (prefix, true),
+        None => (head, head.is_empty()),
+    };
+    if !prefix.is_empty() && lines.all(|line| line.starts_with(prefix)) {
+        found.record(Feature::TextPrefix, text.span);
+    }
+    if folded {
+        found.record(Feature::LineFolding, text.span);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_field_protocols() {
+        let report = |body: &str| feature_report(&format!("data_t\n_a\n{body}\n")).unwrap();
+
+        let folded = report(";\\\nlong line \\\ncontinued\n;");
+        assert!(folded.uses(Feature::LineFolding));
+        assert!(!folded.uses(Feature::TextPrefix));
+
+        let prefixed = report(";>\\\n>;not a delimiter\n>end\n;");
+        assert!(prefixed.uses(Feature::TextPrefix));
+        assert!(!prefixed.uses(Feature::LineFolding));
+
+        let both = report(";>\\\\\n>folded \\\n>end\n;");
+        assert!(both.uses(Feature::TextPrefix));
+        assert!(both.uses(Feature::LineFolding));
+
+        let plain = report(";\nC:\\data\\\nnext\n;");
+        assert!(plain.features.is_empty(), "{:?}", plain.features);
+    }
+
+    #[test]
+    fn test_long_line() {
+        let cif = format!("data_t\n_a {}\n", "x".repeat(MAX_LINE_LENGTH));
+        let report = feature_report(&cif).unwrap();
+        let usage = report.get(Feature::LongLine).unwrap();
+        assert_eq!(usage.count, 1);
+        assert_eq!(usage.first, Span::new(2, 1, 2, MAX_LINE_LENGTH + 4));
+    }
+}
@@ -1,6 +1,7 @@
 //! Data block structures in CIF files.
 
-use super::{CifFrame, CifLoop, CifValue, Span};
+use super::order::{in_document_order, serialize_in_document_order};
+use super::{CaseInsensitiveName, CifFrame, CifLoop, CifValue, Span};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -38,6 +39,9 @@ use std::collections::HashMap;
 /// - `DATA_STRUCTURE` → name is `"STRUCTURE"` (case-insensitive parsing)
 /// - `global_` → name is `""` (empty string for global blocks)
 ///
+/// The name keeps the file's spelling but, being a [`CaseInsensitiveName`],
+/// compares equal to any other casing of it.
+///
 /// # Access Methods
 ///
 /// ```
@@ -55,11 +59,12 @@ use std::collections::HashMap;
 /// // Get all loop tags
 /// let all_tags = block.get_loop_tags();
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CifBlock {
-    /// Block name (extracted from `data_name` header)
-    pub name: String,
-    /// Data items (key-value pairs) in this block
+    /// Block name (extracted from `data_name` header), compared ignoring case
+    pub name: CaseInsensitiveName,
+    /// Data items (key-value pairs) in this block; serialized in document order
+    #[serde(serialize_with = "serialize_in_document_order")]
     pub items: HashMap<String, CifValue>,
     /// Loop structures (tabular data) in this block
     pub loops: Vec<CifLoop>,
@@ -71,9 +76,9 @@ pub struct CifBlock {
 
 impl CifBlock {
     /// Create a new empty block with the given name (uses default span)
-    pub fn new(name: String) -> Self {
+    pub fn new(name: impl Into<CaseInsensitiveName>) -> Self {
         CifBlock {
-            name,
+            name: name.into(),
             items: HashMap::new(),
             loops: Vec::new(),
             frames: Vec::new(),
@@ -82,9 +87,9 @@ impl CifBlock {
     }
 
     /// Create a new empty block with the given name and span
-    pub fn with_span(name: String, span: Span) -> Self {
+    pub fn with_span(name: impl Into<CaseInsensitiveName>, span: Span) -> Self {
         CifBlock {
-            name,
+            name: name.into(),
             items: HashMap::new(),
             loops: Vec::new(),
             frames: Vec::new(),
@@ -123,7 +128,7 @@ impl CifBlock {
             .find(|loop_| loop_.tags.contains(&tag.to_string()))
     }
 
-    /// Get a frame by name
+    /// Get a frame by name, ignoring case
     ///
     /// # Examples
     /// ```
@@ -131,10 +136,11 @@ impl CifBlock {
     /// # let cif = "data_test\nsave_frame1\n_item val\nsave_\n";
     /// # let doc = Document::parse(cif).unwrap();
     /// # let block = doc.first_block().unwrap();
-    /// let frame = block.get_frame("frame1");
+    /// let frame = block.get_frame("Frame1").unwrap();
+    /// assert_eq!(frame.name.as_str(), "frame1");
     /// ```
     pub fn get_frame(&self, name: &str) -> Option<&CifFrame> {
-        self.frames.iter().find(|f| f.name == name)
+        self.frames.iter().find(|f| f.name.matches(name))
     }
 
     /// Get all loop tags in this block
@@ -143,10 +149,12 @@ impl CifBlock {
     }
 
     /// Iterate over all tags in this block (from items, loops, and frames)
+    ///
+    /// Items come first in document order, then loop tags, then each frame's tags.
     pub fn all_tags(&self) -> impl Iterator<Item = &str> {
-        self.items
-            .keys()
-            .map(|s| s.as_str())
+        in_document_order(&self.items)
+            .into_iter()
+            .map(|(name, _)| name.as_str())
             .chain(
                 self.loops
                     .iter()
@@ -165,8 +173,12 @@ impl CifBlock {
         self.frames.iter()
     }
 
-    /// Iterate over all items (key-value pairs)
+    /// Iterate over all items (key-value pairs) in document order
+    ///
+    /// Sorts the items on each call, in the same order as
+    /// [`CifValue::walk`] visits table entries; use [`get_item`](Self::get_item)
+    /// to look up a single tag.
     pub fn items_iter(&self) -> impl Iterator<Item = (&String, &CifValue)> {
-        self.items.iter()
+        in_document_order(&self.items).into_iter()
     }
 }
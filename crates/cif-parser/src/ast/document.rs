@@ -1,6 +1,6 @@
 //! CIF document (root container) structures.
 
-use super::{CifBlock, Span};
+use super::{BlockSummary, CifBlock, Span};
 use crate::error::CifError;
 use serde::{Deserialize, Serialize};
 use std::fs;
@@ -18,7 +18,9 @@ use std::path::Path;
 /// # Version Detection
 ///
 /// Version is automatically detected when parsing:
-/// - Files starting with `#\#CIF_2.0` are CIF 2.0
+/// - Files starting with `#\#CIF_2.0` are CIF 2.0 (text after the token on
+///   the same line is a comment)
+/// - A header below a comment line is rejected rather than ignored
 /// - Files without the magic comment default to CIF 1.1
 /// - Files can be forced to a specific version using `parse_with_version()`
 ///
@@ -66,6 +68,50 @@ impl std::fmt::Display for CifVersion {
     }
 }
 
+/// The `#\#CIF_2.0` magic header line as found in the source.
+///
+/// Recorded wherever the header was found so that tools can report a
+/// misplaced header and writers can re-emit the original line. The header
+/// must start the first line (only a byte-order mark may precede it); one
+/// found below blank or comment lines is misplaced. Anything after the token
+/// on the header line is a comment.
+///
+/// # Examples
+///
+/// ```
+/// use cif_parser::Document;
+///
+/// let doc = Document::parse("#\\#CIF_2.0 generated by XYZ\ndata_test\n").unwrap();
+/// let header = doc.header.as_ref().unwrap();
+/// assert!(!header.misplaced);
+/// assert_eq!(header.trailing, " generated by XYZ");
+/// assert_eq!(header.to_string(), "#\\#CIF_2.0 generated by XYZ");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MagicHeader {
+    /// 1-based line the header was found on
+    pub line: usize,
+    /// Whether the file starts with a byte-order mark
+    pub has_bom: bool,
+    /// Text after the magic token on the same line, as written
+    pub trailing: String,
+    /// Whether anything but a byte-order mark precedes the header, which CIF
+    /// 2.0 forbids
+    pub misplaced: bool,
+}
+
+impl MagicHeader {
+    /// The magic token that identifies a CIF 2.0 file
+    pub const TOKEN: &'static str = "#\\#CIF_2.0";
+}
+
+impl std::fmt::Display for MagicHeader {
+    /// Re-emit the header line (without the byte-order mark)
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", Self::TOKEN, self.trailing)
+    }
+}
+
 /// Represents a complete CIF document containing one or more data blocks.
 ///
 /// This is the root container for all parsed CIF data. A CIF file can contain
@@ -116,7 +162,10 @@ impl std::fmt::Display for CifVersion {
 /// ```
 ///
 /// Each structure gets its own [`CifBlock`] with independent data.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// The serde representation is versioned and stable; see
+/// [`serialized`](super::serialized).
+#[derive(Debug, Clone, PartialEq)]
 pub struct CifDocument {
     /// All data blocks in this document
     pub blocks: Vec<CifBlock>,
@@ -127,8 +176,17 @@ pub struct CifDocument {
     /// Set to CIF 2.0 if the file contains the magic comment `#\#CIF_2.0`.
     pub version: CifVersion,
 
+    /// The `#\#CIF_2.0` header line, if one was found
+    pub header: Option<MagicHeader>,
+
     /// Source location spanning the entire document
     pub span: Span,
+
+    /// Blank lines and comments after the last heading, tag, value or
+    /// closing `save_`/`stop_`, verbatim, so a writer can end the file the
+    /// way it was read. With no blocks this is everything after the
+    /// `#\#CIF_2.0` header line, or the whole input.
+    pub trailing: String,
 }
 
 impl Default for CifDocument {
@@ -143,7 +201,9 @@ impl CifDocument {
         CifDocument {
             blocks: Vec::new(),
             version: CifVersion::default(),
+            header: None,
             span: Span::default(),
+            trailing: String::new(),
         }
     }
 
@@ -152,7 +212,9 @@ impl CifDocument {
         CifDocument {
             blocks: Vec::new(),
             version,
+            header: None,
             span: Span::default(),
+            trailing: String::new(),
         }
     }
 
@@ -161,7 +223,9 @@ impl CifDocument {
         CifDocument {
             blocks: Vec::new(),
             version,
+            header: None,
             span,
+            trailing: String::new(),
         }
     }
 
@@ -200,18 +264,18 @@ impl CifDocument {
         Self::parse(&content)
     }
 
-    /// Get a block by name
+    /// Get a block by name, ignoring case
     ///
     /// # Examples
     /// ```
     /// # use cif_parser::Document;
     /// # let cif = "data_test\n_item value\n";
     /// # let doc = Document::parse(cif).unwrap();
-    /// let block = doc.get_block("test");
+    /// let block = doc.get_block("TEST");
     /// assert!(block.is_some());
     /// ```
     pub fn get_block(&self, name: &str) -> Option<&CifBlock> {
-        self.blocks.iter().find(|b| b.name == name)
+        self.blocks.iter().find(|b| b.name.matches(name))
     }
 
     /// Get the first block (common for single-block CIF files)
@@ -237,4 +301,11 @@ impl CifDocument {
     pub fn all_tags(&self) -> impl Iterator<Item = &str> {
         self.blocks.iter().flat_map(|b| b.all_tags())
     }
+
+    /// Summarize every data block, in document order
+    ///
+    /// See [`CifBlock::summary`] for which tags are read.
+    pub fn summaries(&self) -> Vec<BlockSummary> {
+        self.blocks.iter().map(CifBlock::summary).collect()
+    }
 }
@@ -0,0 +1,259 @@
+//! Applying text edits to a parsed document.
+//!
+//! An editor holding a large file reparses it on every keystroke, although
+//! almost every edit stays inside one data block. [`CifDocument::apply_edit`]
+//! reparses only the block an edit falls in, reuses the other blocks of the
+//! old document and moves the spans of the blocks after it.
+//!
+//! The result is always the document a full parse of the edited text would
+//! give. Whenever that cannot be guaranteed cheaply the whole text is
+//! reparsed instead: when the edit crosses the start of a data block, touches
+//! text before the first block, or leaves the edited block ending somewhere
+//! other than where the next block starts (e.g. after opening a text field
+//! or writing a new `data_` heading).
+//!
+//! ```
+//! use cif_parser::{CifDocument, TextEdit};
+//!
+//! let text = "data_a\n_x 1\ndata_b\n_y 2\n";
+//! let doc = CifDocument::parse(text).unwrap();
+//!
+//! let edit = TextEdit::new(10..11, "10\n_z 3");
+//! let (doc, changed) = CifDocument::apply_edit(text, edit, &doc).unwrap();
+//! assert_eq!(changed.reparsed, 0..1);
+//! assert!(!changed.full_reparse);
+//! assert_eq!(doc.blocks[0].get_item("_z").unwrap().as_numeric(), Some(3.0));
+//! assert_eq!(doc.blocks[1].span.start_line, 4);
+//! ```
+
+use super::{CifBlock, CifDocument, CifFrame, CifLoop, CifValue, CifValueKind, Span};
+use crate::error::CifError;
+use crate::raw::parser::helpers::LineIndex;
+use crate::raw::parser::{block_content_end, parse_block_at};
+use crate::rules::{Cif1Rules, Cif2Rules, VersionRules};
+use crate::CifVersion;
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+/// Replacement of a byte range of a document's text
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextEdit {
+    /// Bytes of the original text to replace (empty to insert)
+    pub range: Range<usize>,
+    /// Text to put in their place (empty to delete)
+    pub new_text: String,
+}
+
+impl TextEdit {
+    /// Create an edit replacing `range` with `new_text`
+    pub fn new(range: Range<usize>, new_text: impl Into<String>) -> Self {
+        Self {
+            range,
+            new_text: new_text.into(),
+        }
+    }
+
+    /// The text after the edit.
+    ///
+    /// Fails if the range is out of bounds or splits a character.
+    pub fn apply_to(&self, text: &str) -> Result<String, CifError> {
+        let Range { start, end } = self.range;
+        if start > end || !text.is_char_boundary(start) || !text.is_char_boundary(end) {
+            return Err(CifError::InvalidStructure {
+                message: format!(
+                    "Edit range {start}..{end} is not a valid range of the {}-byte text",
+                    text.len()
+                ),
+                location: None,
+            });
+        }
+        let mut result = String::with_capacity(text.len() - (end - start) + self.new_text.len());
+        result.push_str(&text[..start]);
+        result.push_str(&self.new_text);
+        result.push_str(&text[end..]);
+        Ok(result)
+    }
+}
+
+/// Which blocks [`CifDocument::apply_edit`] had to touch
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangedBlocks {
+    /// Indices of the blocks parsed again, in the new document
+    pub reparsed: Range<usize>,
+    /// Indices of the blocks taken from the old document with their spans
+    /// moved, in the new document
+    pub shifted: Range<usize>,
+    /// Whether the whole text was parsed again
+    pub full_reparse: bool,
+}
+
+impl CifDocument {
+    /// Apply `edit` to `original_text` and parse the result, reusing
+    /// `old_doc` where the edit cannot have changed it.
+    ///
+    /// `old_doc` must be the document parsed from `original_text` with
+    /// default options. Only the block containing the edit is parsed again;
+    /// see the [module documentation](self) for when the whole text is.
+    /// Returns the same document or error as parsing the edited text.
+    pub fn apply_edit(
+        original_text: &str,
+        edit: TextEdit,
+        old_doc: &CifDocument,
+    ) -> Result<(CifDocument, ChangedBlocks), CifError> {
+        let new_text = edit.apply_to(original_text)?;
+        if let Some(result) = reparse_block(original_text, &new_text, &edit, old_doc) {
+            return Ok(result);
+        }
+
+        let doc = CifDocument::parse(&new_text)?;
+        let changed = ChangedBlocks {
+            reparsed: 0..doc.blocks.len(),
+            shifted: doc.blocks.len()..doc.blocks.len(),
+            full_reparse: true,
+        };
+        Ok((doc, changed))
+    }
+}
+
+/// Reparse only the block containing the edit, or `None` if the whole text
+/// must be parsed again
+fn reparse_block(
+    original_text: &str,
+    new_text: &str,
+    edit: &TextEdit,
+    old_doc: &CifDocument,
+) -> Option<(CifDocument, ChangedBlocks)> {
+    let index = LineIndex::new(original_text);
+    let starts: Vec<usize> = old_doc
+        .blocks
+        .iter()
+        .map(|block| index.offset(block.span.start_line, block.span.start_col))
+        .collect();
+
+    // The block whose text (up to the next block) contains the edit
+    let block = starts
+        .iter()
+        .rposition(|&start| start <= edit.range.start)?;
+    let region_end = starts
+        .get(block + 1)
+        .copied()
+        .unwrap_or(original_text.len());
+    if edit.range.end > region_end {
+        return None;
+    }
+
+    // The reparsed block must end exactly where the next one starts
+    let growth = edit.new_text.len() as isize - edit.range.len() as isize;
+    let new_region_end = region_end.checked_add_signed(growth)?;
+    let (raw, end) = parse_block_at(new_text, starts[block]).ok()?;
+    if end != new_region_end {
+        return None;
+    }
+    let resolved = match old_doc.version {
+        CifVersion::V1_1 => Cif1Rules.resolve_block(&raw),
+        CifVersion::V2_0 => Cif2Rules.resolve_block(&raw),
+    };
+    let mut resolved = resolved.ok()?;
+
+    // Only the last block can change what follows the last construct
+    let trailing = if block + 1 == starts.len() {
+        new_text[block_content_end(new_text, starts[block], &raw)..].to_string()
+    } else {
+        old_doc.trailing.clone()
+    };
+
+    // Spans of the new block count from its start; the blocks after it move
+    // with the end of the edit
+    let block_start = index.line_col(starts[block]);
+    Shift::new((1, 1), block_start).block(&mut resolved);
+    let old_end = index.line_col(edit.range.end);
+    let new_end = end_of_insert(index.line_col(edit.range.start), &edit.new_text);
+    let shift = Shift::new(old_end, new_end);
+
+    let mut doc = CifDocument {
+        blocks: Vec::with_capacity(old_doc.blocks.len()),
+        version: old_doc.version,
+        header: old_doc.header.clone(),
+        span: old_doc.span,
+        trailing,
+    };
+    doc.blocks.extend_from_slice(&old_doc.blocks[..block]);
+    doc.blocks.push(resolved);
+    for old_block in &old_doc.blocks[block + 1..] {
+        let mut moved = old_block.clone();
+        shift.block(&mut moved);
+        doc.blocks.push(moved);
+    }
+    let (end_line, end_col) = shift.position(doc.span.end_line, doc.span.end_col);
+    doc.span.end_line = end_line;
+    doc.span.end_col = end_col;
+
+    let changed = ChangedBlocks {
+        reparsed: block..block + 1,
+        shifted: block + 1..doc.blocks.len(),
+        full_reparse: false,
+    };
+    Some((doc, changed))
+}
+
+/// Position just past `text` inserted at `start`
+fn end_of_insert(start: (usize, usize), text: &str) -> (usize, usize) {
+    match text.rfind('\n') {
+        Some(last) => (start.0 + text.matches('\n').count(), text.len() - last),
+        None => (start.0, start.1 + text.len()),
+    }
+}
+
+/// Moves every position at or after `from` so that `from` lands on `to`
+struct Shift {
+    from: (usize, usize),
+    to: (usize, usize),
+}
+
+impl Shift {
+    fn new(from: (usize, usize), to: (usize, usize)) -> Self {
+        Self { from, to }
+    }
+
+    fn position(&self, line: usize, col: usize) -> (usize, usize) {
+        if line == self.from.0 {
+            (self.to.0, col - self.from.1 + self.to.1)
+        } else {
+            (line - self.from.0 + self.to.0, col)
+        }
+    }
+
+    fn span(&self, span: &mut Span) {
+        (span.start_line, span.start_col) = self.position(span.start_line, span.start_col);
+        (span.end_line, span.end_col) = self.position(span.end_line, span.end_col);
+    }
+
+    fn value(&self, value: &mut CifValue) {
+        self.span(&mut value.span);
+        match &mut value.kind {
+            CifValueKind::List(values) => values.iter_mut().for_each(|v| self.value(v)),
+            CifValueKind::Table(entries) => entries.values_mut().for_each(|v| self.value(v)),
+            _ => {}
+        }
+    }
+
+    fn loop_(&self, loop_: &mut CifLoop) {
+        self.span(&mut loop_.span);
+        for value in loop_.values.iter_mut().flatten() {
+            self.value(value);
+        }
+    }
+
+    fn frame(&self, frame: &mut CifFrame) {
+        self.span(&mut frame.span);
+        frame.items.values_mut().for_each(|v| self.value(v));
+        frame.loops.iter_mut().for_each(|l| self.loop_(l));
+    }
+
+    fn block(&self, block: &mut CifBlock) {
+        self.span(&mut block.span);
+        block.items.values_mut().for_each(|v| self.value(v));
+        block.loops.iter_mut().for_each(|l| self.loop_(l));
+        block.frames.iter_mut().for_each(|f| self.frame(f));
+    }
+}
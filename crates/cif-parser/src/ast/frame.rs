@@ -1,6 +1,7 @@
 //! Save frame structures in CIF files.
 
-use super::{CifLoop, CifValue, Span};
+use super::order::{in_document_order, serialize_in_document_order};
+use super::{CaseInsensitiveName, CifLoop, CifValue, Span};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -36,11 +37,12 @@ use std::collections::HashMap;
 ///
 /// Save frames are contained within data blocks and can contain the same
 /// types of content (data items and loops) but cannot contain other save frames.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CifFrame {
-    /// Name of the save frame (from `save_name`)
-    pub name: String,
-    /// Data items (key-value pairs) within this frame
+    /// Name of the save frame (from `save_name`), compared ignoring case
+    pub name: CaseInsensitiveName,
+    /// Data items (key-value pairs) within this frame; serialized in document order
+    #[serde(serialize_with = "serialize_in_document_order")]
     pub items: HashMap<String, CifValue>,
     /// Loop structures within this frame
     pub loops: Vec<CifLoop>,
@@ -50,9 +52,9 @@ pub struct CifFrame {
 
 impl CifFrame {
     /// Create a new empty frame with the given name (uses default span)
-    pub fn new(name: String) -> Self {
+    pub fn new(name: impl Into<CaseInsensitiveName>) -> Self {
         CifFrame {
-            name,
+            name: name.into(),
             items: HashMap::new(),
             loops: Vec::new(),
             span: Span::default(),
@@ -60,9 +62,9 @@ impl CifFrame {
     }
 
     /// Create a new empty frame with the given name and span
-    pub fn with_span(name: String, span: Span) -> Self {
+    pub fn with_span(name: impl Into<CaseInsensitiveName>, span: Span) -> Self {
         CifFrame {
-            name,
+            name: name.into(),
             items: HashMap::new(),
             loops: Vec::new(),
             span,
@@ -82,11 +84,24 @@ impl CifFrame {
     }
 
     /// Iterate over all tags in this frame (from both items and loops)
+    ///
+    /// Items come first in document order, then loop tags.
     pub fn all_tags(&self) -> impl Iterator<Item = &str> {
-        self.items.keys().map(|s| s.as_str()).chain(
-            self.loops
-                .iter()
-                .flat_map(|l| l.tags.iter().map(|s| s.as_str())),
-        )
+        in_document_order(&self.items)
+            .into_iter()
+            .map(|(name, _)| name.as_str())
+            .chain(
+                self.loops
+                    .iter()
+                    .flat_map(|l| l.tags.iter().map(|s| s.as_str())),
+            )
+    }
+
+    /// Iterate over all items (key-value pairs) in document order
+    ///
+    /// Sorts the items on each call; use [`get_item`](Self::get_item) to look
+    /// up a single tag.
+    pub fn items_iter(&self) -> impl Iterator<Item = (&String, &CifValue)> {
+        in_document_order(&self.items).into_iter()
     }
 }
@@ -0,0 +1,242 @@
+//! Pointers from one data block to another.
+//!
+//! Powder CIFs split one experiment over several blocks: the phases, each
+//! diffractogram, and the calibration standards. A block names itself with
+//! `_pd_block.id` and points at the others through tags such as
+//! `_pd_block_diffractogram.id` and `_pd_phase.block_id`. `_audit_link`
+//! does the same by block code for any multi-block file.
+//! [`CifDocument::block_links`] collects these pointers and resolves each to
+//! the block it names.
+
+use super::{CifBlock, CifDocument, CifValue, Span};
+use serde::{Deserialize, Serialize};
+
+/// Tags whose values are the ids of other blocks, in both spellings
+pub const DEFAULT_POINTER_TAGS: &[&str] = &[
+    "_pd_block_diffractogram.id",
+    "_pd_block_diffractogram_id",
+    "_pd_phase.block_id",
+    "_pd_phase_block_id",
+    "_pd_calib_std.external_block_id",
+    "_pd_calib_std_external_block_id",
+    "_audit_link.block_code",
+    "_audit_link_block_code",
+];
+
+/// Tags whose value is a block's own id, in both spellings
+pub const DEFAULT_ID_TAGS: &[&str] = &["_pd_block.id", "_pd_block_id"];
+
+/// Which tags point at blocks, and which give a block its id.
+///
+/// Tags compare ignoring case.
+///
+/// # Examples
+///
+/// ```
+/// use cif_parser::{ast::BlockLinkTags, CifDocument};
+///
+/// let doc = CifDocument::parse(
+///     "data_a\n_my_block.id A1\n_my_parent.block_id B1\n\
+///      data_b\n_my_block.id B1\n",
+/// )
+/// .unwrap();
+/// let tags = BlockLinkTags::new()
+///     .with_id_tag("_my_block.id")
+///     .with_pointer_tag("_my_parent.block_id");
+/// let links = doc.block_links_with(&tags);
+/// assert_eq!(links[0].resolved, Some(1));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockLinkTags {
+    /// Tags whose values are the ids of other blocks
+    pub pointers: Vec<String>,
+    /// Tags whose value is the id of the block they are in
+    pub ids: Vec<String>,
+}
+
+impl Default for BlockLinkTags {
+    fn default() -> Self {
+        BlockLinkTags {
+            pointers: DEFAULT_POINTER_TAGS.iter().map(|t| t.to_string()).collect(),
+            ids: DEFAULT_ID_TAGS.iter().map(|t| t.to_string()).collect(),
+        }
+    }
+}
+
+impl BlockLinkTags {
+    /// The default powder and audit link tags
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also follow the values of `tag`
+    pub fn with_pointer_tag(mut self, tag: impl Into<String>) -> Self {
+        self.pointers.push(tag.into());
+        self
+    }
+
+    /// Also identify blocks by the value of `tag`
+    pub fn with_id_tag(mut self, tag: impl Into<String>) -> Self {
+        self.ids.push(tag.into());
+        self
+    }
+
+    fn is_pointer(&self, tag: &str) -> bool {
+        self.pointers.iter().any(|t| t.eq_ignore_ascii_case(tag))
+    }
+
+    fn is_id(&self, tag: &str) -> bool {
+        self.ids.iter().any(|t| t.eq_ignore_ascii_case(tag))
+    }
+}
+
+/// A value in one block that names another block.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockLink {
+    /// Index of the block the pointer is in
+    pub from_block: usize,
+    /// The pointer tag, as written
+    pub tag: String,
+    /// The id or block code pointed at
+    pub target_id: String,
+    /// Index of the block with that id, or failing that that name; `None`
+    /// if the pointer dangles
+    pub resolved: Option<usize>,
+    /// Location of the pointer value
+    pub span: Span,
+}
+
+impl BlockLink {
+    /// Whether no block in the document has the id pointed at
+    pub fn is_dangling(&self) -> bool {
+        self.resolved.is_none()
+    }
+}
+
+impl CifDocument {
+    /// Every pointer to another block, with the default tags
+    ///
+    /// See [`block_links_with`](Self::block_links_with).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cif_parser::CifDocument;
+    ///
+    /// let doc = CifDocument::parse(
+    ///     "data_phase\n_pd_block.id P1\n_pd_block_diffractogram.id D1\n\
+    ///      data_pattern\n_pd_block.id D1\n",
+    /// )
+    /// .unwrap();
+    /// let links = doc.block_links();
+    /// assert_eq!(links.len(), 1);
+    /// assert_eq!(links[0].target_id, "D1");
+    /// assert_eq!(links[0].resolved, Some(1));
+    /// ```
+    pub fn block_links(&self) -> Vec<BlockLink> {
+        self.block_links_with(&BlockLinkTags::default())
+    }
+
+    /// Every pointer to another block, in document order.
+    ///
+    /// Pointers are read from the items and loops of each block; `?` and
+    /// `.` point nowhere and are skipped. A target resolves to the block
+    /// whose id tag has exactly that value, or else to the block with that
+    /// name, ignoring case.
+    pub fn block_links_with(&self, tags: &BlockLinkTags) -> Vec<BlockLink> {
+        let ids: Vec<Vec<&str>> = self
+            .blocks
+            .iter()
+            .map(|block| {
+                tagged_values(block, |tag| tags.is_id(tag))
+                    .filter_map(|(_, value)| value.as_string())
+                    .collect()
+            })
+            .collect();
+        let resolve = |target: &str| {
+            ids.iter()
+                .position(|ids| ids.contains(&target))
+                .or_else(|| self.blocks.iter().position(|b| b.name.matches(target)))
+        };
+
+        let mut links = Vec::new();
+        for (index, block) in self.blocks.iter().enumerate() {
+            for (tag, value) in tagged_values(block, |tag| tags.is_pointer(tag)) {
+                let Some(target) = value.as_string() else {
+                    continue;
+                };
+                links.push(BlockLink {
+                    from_block: index,
+                    tag: tag.to_string(),
+                    target_id: target.to_string(),
+                    resolved: resolve(target),
+                    span: value.span,
+                });
+            }
+        }
+        links
+    }
+
+    /// Blocks linked to or from the named block, in document order
+    ///
+    /// Follows the resolved [`block_links`](Self::block_links) one step in
+    /// either direction. Empty if no block has that name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cif_parser::CifDocument;
+    ///
+    /// let doc = CifDocument::parse(
+    ///     "data_phase\n_pd_block.id P1\n_pd_block_diffractogram.id D1\n\
+    ///      data_pattern\n_pd_block.id D1\n_pd_phase.block_id P1\n\
+    ///      data_other\n_pd_block.id X1\n",
+    /// )
+    /// .unwrap();
+    /// let related = doc.related_blocks("PATTERN");
+    /// assert_eq!(related.len(), 1);
+    /// assert_eq!(related[0].name, "phase");
+    /// ```
+    pub fn related_blocks(&self, name: &str) -> Vec<&CifBlock> {
+        let Some(index) = self.blocks.iter().position(|b| b.name.matches(name)) else {
+            return Vec::new();
+        };
+        let mut related = vec![false; self.blocks.len()];
+        for link in self.block_links() {
+            match link.resolved {
+                Some(target) if link.from_block == index => related[target] = true,
+                Some(target) if target == index => related[link.from_block] = true,
+                _ => {}
+            }
+        }
+        related[index] = false;
+        self.blocks
+            .iter()
+            .zip(related)
+            .filter_map(|(block, related)| related.then_some(block))
+            .collect()
+    }
+}
+
+/// Values of the block's items and loop columns whose tag passes `wanted`
+fn tagged_values<'a>(
+    block: &'a CifBlock,
+    wanted: impl Fn(&str) -> bool + 'a,
+) -> impl Iterator<Item = (&'a str, &'a CifValue)> + 'a {
+    // Only the few wanted items are put in document order, not the whole block
+    let mut items: Vec<_> = block
+        .items
+        .iter()
+        .filter(|(tag, _)| wanted(tag))
+        .map(|(tag, value)| (tag.as_str(), value))
+        .collect();
+    items.sort_by_key(|(tag, value)| (value.span.start_line, value.span.start_col, *tag));
+    let columns = block.loops.iter().flat_map(|loop_| {
+        loop_.tags.iter().enumerate().flat_map(move |(col, tag)| {
+            (0..loop_.len()).filter_map(move |row| Some((tag.as_str(), loop_.get(row, col)?)))
+        })
+    });
+    items
+        .into_iter()
+        .chain(columns.filter(move |(tag, _)| wanted(tag)))
+}
@@ -50,7 +50,7 @@ use serde::{Deserialize, Serialize};
 /// - Number of values is divisible by number of tags
 /// - Each row has exactly the right number of values
 /// - Empty loops (tags but no values) are valid
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CifLoop {
     /// Column names/headers (CIF tags starting with `_`)
     pub tags: Vec<String>,
@@ -158,6 +158,41 @@ impl CifLoop {
         self.values.iter()
     }
 
+    /// Group the loop's values by the source line each value starts on
+    ///
+    /// Logical rows are formed purely by counting values, so they do not
+    /// always match how the loop was laid out in the file. This returns the
+    /// physical layout instead, in source order, which is useful for
+    /// diagnosing rows that were accidentally split or merged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cif_parser::Document;
+    /// # let cif = "data_test\nloop_\n_col1\n_col2\nval1 val2 val3\nval4\n";
+    /// # let doc = Document::parse(cif).unwrap();
+    /// # let loop_ = &doc.blocks[0].loops[0];
+    /// let lines = loop_.source_lines();
+    /// assert_eq!(lines.len(), 2);
+    /// assert_eq!(lines[0].len(), 3);
+    /// assert_eq!(lines[1].len(), 1);
+    /// ```
+    pub fn source_lines(&self) -> Vec<Vec<&CifValue>> {
+        let mut lines: Vec<Vec<&CifValue>> = Vec::new();
+        let mut current_line = None;
+
+        for value in self.values.iter().flatten() {
+            if current_line != Some(value.span.start_line) {
+                current_line = Some(value.span.start_line);
+                lines.push(Vec::new());
+            }
+            if let Some(line) = lines.last_mut() {
+                line.push(value);
+            }
+        }
+
+        lines
+    }
+
     /// Iterate over all tags (column names)
     ///
     /// # Examples
@@ -9,7 +9,7 @@
 //!
 //! ```text
 //! CifDocument
-//!  └─ CifBlock (data blocks)
+//!  └─ CifBlock (data blocks, named by a CaseInsensitiveName)
 //!      ├─ items: HashMap<String, CifValue>  (key-value pairs)
 //!      ├─ loops: Vec<CifLoop>               (tabular data)
 //!      └─ frames: Vec<CifFrame>             (named sub-containers)
@@ -26,14 +26,31 @@
 
 pub mod block;
 pub mod document;
+pub mod edit;
 pub mod frame;
+pub mod links;
 pub mod loop_struct;
+pub mod name;
+pub(crate) mod order;
+pub mod path;
+pub mod rename;
+pub mod serialized;
 pub mod span;
+pub mod stats;
+pub mod summary;
 pub mod value;
 
 pub use block::CifBlock;
-pub use document::{CifDocument, CifVersion};
+pub use document::{CifDocument, CifVersion, MagicHeader};
+pub use edit::{ChangedBlocks, TextEdit};
 pub use frame::CifFrame;
+pub use links::{BlockLink, BlockLinkTags};
 pub use loop_struct::CifLoop;
+pub use name::CaseInsensitiveName;
+pub use path::{PathSegment, ValuePath, ValuePathError, Walk};
+pub use rename::{RefusedRename, RenameConflict, RenameOutcome, TagLocation, TagRename};
+pub use serialized::DOCUMENT_FORMAT_VERSION;
 pub use span::Span;
-pub use value::{CifValue, CifValueKind};
+pub use stats::{Anomaly, AnomalyReason, ColumnStats, PlausibleRange, ScreeningOptions};
+pub use summary::{BlockSummary, Measurand};
+pub use value::{CifValue, CifValueKind, QuoteStyle};
@@ -0,0 +1,183 @@
+//! Block and frame codes, compared without regard to case.
+
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+
+/// The name of a data block or save frame.
+///
+/// Block and frame codes are case-insensitive in both CIF 1.1 and CIF 2.0:
+/// `data_Quartz` and `DATA_QUARTZ` head the same block. The name keeps the
+/// spelling the file used, for display and round-tripping, but compares,
+/// orders and hashes by its case-folded form. Comparing with a `str` is
+/// case-insensitive as well; use [`as_str`](Self::as_str) to compare the
+/// spelling itself.
+///
+/// # Examples
+///
+/// ```
+/// use cif_parser::CaseInsensitiveName;
+///
+/// let name = CaseInsensitiveName::from("Quartz");
+/// assert_eq!(name, "QUARTZ");
+/// assert_eq!(name.as_str(), "Quartz");
+/// assert_eq!(name.to_string(), "Quartz");
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CaseInsensitiveName(String);
+
+impl CaseInsensitiveName {
+    /// Create a name from its spelling in the file
+    pub fn new(name: impl Into<String>) -> Self {
+        Self(name.into())
+    }
+
+    /// The name as written
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Take the name as written
+    pub fn into_string(self) -> String {
+        self.0
+    }
+
+    /// Check if the name is a spelling of `other`, ignoring case
+    pub fn matches(&self, other: &str) -> bool {
+        fold(&self.0).eq(fold(other))
+    }
+}
+
+/// Characters of a name with case folded away
+fn fold(name: &str) -> impl Iterator<Item = char> + '_ {
+    name.chars().flat_map(char::to_lowercase)
+}
+
+impl PartialEq for CaseInsensitiveName {
+    fn eq(&self, other: &Self) -> bool {
+        self.matches(&other.0)
+    }
+}
+
+impl Eq for CaseInsensitiveName {}
+
+impl PartialEq<str> for CaseInsensitiveName {
+    fn eq(&self, other: &str) -> bool {
+        self.matches(other)
+    }
+}
+
+impl PartialEq<&str> for CaseInsensitiveName {
+    fn eq(&self, other: &&str) -> bool {
+        self.matches(other)
+    }
+}
+
+impl PartialEq<String> for CaseInsensitiveName {
+    fn eq(&self, other: &String) -> bool {
+        self.matches(other)
+    }
+}
+
+impl PartialOrd for CaseInsensitiveName {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CaseInsensitiveName {
+    fn cmp(&self, other: &Self) -> Ordering {
+        fold(&self.0).cmp(fold(&other.0))
+    }
+}
+
+impl Hash for CaseInsensitiveName {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for c in fold(&self.0) {
+            c.hash(state);
+        }
+        // Terminate like `str` does, so consecutive names hash apart
+        state.write_u8(0xff);
+    }
+}
+
+impl Deref for CaseInsensitiveName {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for CaseInsensitiveName {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for CaseInsensitiveName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl fmt::Debug for CaseInsensitiveName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl From<String> for CaseInsensitiveName {
+    fn from(name: String) -> Self {
+        Self(name)
+    }
+}
+
+impl From<&str> for CaseInsensitiveName {
+    fn from(name: &str) -> Self {
+        Self(name.to_string())
+    }
+}
+
+impl From<CaseInsensitiveName> for String {
+    fn from(name: CaseInsensitiveName) -> Self {
+        name.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_compares_and_hashes_folded() {
+        let set: HashSet<_> = ["Quartz", "QUARTZ", "quartz", "Calcite"]
+            .into_iter()
+            .map(CaseInsensitiveName::from)
+            .collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&CaseInsensitiveName::from("qUaRtZ")));
+    }
+
+    #[test]
+    fn test_keeps_spelling() {
+        let name = CaseInsensitiveName::from("Ångström_A");
+        assert_eq!(name, "ångström_a");
+        assert_ne!(name.as_str(), "ångström_a");
+        assert_eq!(format!("{name:?}"), "\"Ångström_A\"");
+        assert_eq!(serde_json::to_string(&name).unwrap(), "\"Ångström_A\"");
+    }
+
+    #[test]
+    fn test_orders_folded() {
+        let mut names: Vec<CaseInsensitiveName> =
+            vec!["b".into(), "A".into(), "c".into(), "B2".into()];
+        names.sort();
+        let spelled: Vec<&str> = names.iter().map(|n| n.as_str()).collect();
+        assert_eq!(spelled, ["A", "b", "B2", "c"]);
+    }
+}
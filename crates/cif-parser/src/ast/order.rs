@@ -0,0 +1,35 @@
+//! Stable ordering for the hash maps in the AST.
+//!
+//! Items and table entries live in `HashMap`s, whose iteration order changes
+//! from run to run. Everything that walks or serializes them goes through
+//! here instead: entries come out in document order, with the name breaking
+//! ties (values built in code often share a default span).
+
+use super::CifValue;
+use serde::ser::{SerializeMap, Serializer};
+use std::collections::HashMap;
+
+/// Entries of `map` in document order, then by name
+pub(crate) fn in_document_order(map: &HashMap<String, CifValue>) -> Vec<(&String, &CifValue)> {
+    let mut entries: Vec<_> = map.iter().collect();
+    entries.sort_by(|(a_name, a), (b_name, b)| {
+        (a.span.start_line, a.span.start_col, a_name).cmp(&(
+            b.span.start_line,
+            b.span.start_col,
+            b_name,
+        ))
+    });
+    entries
+}
+
+/// Serialize `map` with its entries in document order
+pub(crate) fn serialize_in_document_order<S: Serializer>(
+    map: &HashMap<String, CifValue>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut out = serializer.serialize_map(Some(map.len()))?;
+    for (name, value) in in_document_order(map) {
+        out.serialize_entry(name, value)?;
+    }
+    out.end()
+}
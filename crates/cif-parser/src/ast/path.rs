@@ -0,0 +1,410 @@
+//! Paths into nested CIF 2.0 list and table values.
+//!
+//! A [`ValuePath`] addresses a value inside a list or table by the sequence of
+//! list indices and table keys leading to it. [`CifValue::walk`] visits every
+//! nested value together with its path, and [`CifValue::get_path`] follows a
+//! path back to the value.
+//!
+//! # String Form
+//!
+//! Paths print and parse as a compact string: list indices as `[2]`, table
+//! keys as `.file`. The leading dot is omitted for a key at the start of a
+//! path, and keys that are not plain words are written quoted, `["a key"]`.
+//! The empty string is the path to the value itself.
+//!
+//! ```
+//! use cif_parser::{PathSegment, ValuePath};
+//!
+//! let path = ValuePath::parse("[2].file").unwrap();
+//! assert_eq!(
+//!     path.segments(),
+//!     [PathSegment::Index(2), PathSegment::Key("file".to_string())]
+//! );
+//! assert_eq!(path.to_string(), "[2].file");
+//! ```
+
+use super::order::in_document_order;
+use super::value::{CifValue, CifValueKind};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt;
+
+/// One step into a list or table.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PathSegment {
+    /// Position in a list (0-based)
+    Index(usize),
+    /// Key in a table
+    Key(String),
+}
+
+/// Location of a value nested inside list and table values.
+///
+/// The empty path refers to the outermost value. See the [module
+/// documentation](self) for the string form.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct ValuePath {
+    segments: Vec<PathSegment>,
+}
+
+/// Error returned by [`ValuePath::parse`] for malformed path strings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValuePathError {
+    /// Byte offset in the input where parsing failed
+    pub position: usize,
+    /// What was expected at that position
+    pub message: String,
+}
+
+impl fmt::Display for ValuePathError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Invalid value path at offset {}: {}",
+            self.position, self.message
+        )
+    }
+}
+
+impl Error for ValuePathError {}
+
+impl ValuePath {
+    /// The empty path, referring to the outermost value
+    pub fn root() -> Self {
+        Self::default()
+    }
+
+    /// Parse the string form of a path.
+    ///
+    /// # Examples
+    /// ```
+    /// use cif_parser::{PathSegment, ValuePath};
+    ///
+    /// let path = ValuePath::parse(r#"files[0]["last modified"]"#).unwrap();
+    /// assert_eq!(path.len(), 3);
+    /// assert_eq!(path.segments()[2], PathSegment::Key("last modified".to_string()));
+    ///
+    /// assert!(ValuePath::parse("").unwrap().is_root());
+    /// assert!(ValuePath::parse("[x]").is_err());
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, ValuePathError> {
+        PathReader { input: s, pos: 0 }.read()
+    }
+
+    /// The path extended by one segment
+    pub fn child(&self, segment: PathSegment) -> Self {
+        let mut path = self.clone();
+        path.segments.push(segment);
+        path
+    }
+
+    /// The path extended by a list index
+    pub fn index(&self, index: usize) -> Self {
+        self.child(PathSegment::Index(index))
+    }
+
+    /// The path extended by a table key
+    pub fn key(&self, key: impl Into<String>) -> Self {
+        self.child(PathSegment::Key(key.into()))
+    }
+
+    /// The segments of the path, outermost first
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.segments
+    }
+
+    /// Number of segments (the nesting depth of the addressed value)
+    pub fn len(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Whether this is the empty path
+    pub fn is_root(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Alias for [`is_root`](Self::is_root)
+    pub fn is_empty(&self) -> bool {
+        self.is_root()
+    }
+
+    /// The path without its last segment, or `None` for the root
+    pub fn parent(&self) -> Option<Self> {
+        let (_, rest) = self.segments.split_last()?;
+        Some(Self {
+            segments: rest.to_vec(),
+        })
+    }
+
+    /// The last segment, or `None` for the root
+    pub fn last(&self) -> Option<&PathSegment> {
+        self.segments.last()
+    }
+}
+
+impl From<Vec<PathSegment>> for ValuePath {
+    fn from(segments: Vec<PathSegment>) -> Self {
+        Self { segments }
+    }
+}
+
+impl std::str::FromStr for ValuePath {
+    type Err = ValuePathError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for ValuePath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, segment) in self.segments.iter().enumerate() {
+            match segment {
+                PathSegment::Index(index) => write!(f, "[{index}]")?,
+                PathSegment::Key(key) if is_plain_key(key) => {
+                    if i > 0 {
+                        f.write_str(".")?;
+                    }
+                    f.write_str(key)?;
+                }
+                PathSegment::Key(key) => {
+                    f.write_str("[\"")?;
+                    for c in key.chars() {
+                        if matches!(c, '"' | '\\') {
+                            f.write_str("\\")?;
+                        }
+                        write!(f, "{c}")?;
+                    }
+                    f.write_str("\"]")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Whether a key can be written without quotes
+fn is_plain_key(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Cursor over a path string
+struct PathReader<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl PathReader<'_> {
+    fn read(mut self) -> Result<ValuePath, ValuePathError> {
+        let mut segments = Vec::new();
+        while let Some(c) = self.peek() {
+            let segment = match c {
+                '[' => {
+                    self.pos += 1;
+                    let segment = if self.peek() == Some('"') {
+                        PathSegment::Key(self.quoted()?)
+                    } else {
+                        PathSegment::Index(self.index()?)
+                    };
+                    self.expect(']')?;
+                    segment
+                }
+                '.' => {
+                    self.pos += 1;
+                    PathSegment::Key(self.plain_key()?)
+                }
+                _ if segments.is_empty() => PathSegment::Key(self.plain_key()?),
+                _ => return Err(self.error("expected '[' or '.'")),
+            };
+            segments.push(segment);
+        }
+        Ok(ValuePath { segments })
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), ValuePathError> {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{c}'")))
+        }
+    }
+
+    fn index(&mut self) -> Result<usize, ValuePathError> {
+        let start = self.pos;
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        digits.parse().map_err(|_| ValuePathError {
+            position: start,
+            message: "expected a list index".to_string(),
+        })
+    }
+
+    fn plain_key(&mut self) -> Result<String, ValuePathError> {
+        let key = self.take_while(|c| c.is_alphanumeric() || c == '_' || c == '-');
+        if key.is_empty() {
+            return Err(self.error("expected a table key"));
+        }
+        Ok(key.to_string())
+    }
+
+    fn quoted(&mut self) -> Result<String, ValuePathError> {
+        self.expect('"')?;
+        let mut key = String::new();
+        let mut escaped = false;
+        while let Some(c) = self.peek() {
+            self.pos += c.len_utf8();
+            match c {
+                _ if escaped => {
+                    key.push(c);
+                    escaped = false;
+                }
+                '\\' => escaped = true,
+                '"' => return Ok(key),
+                _ => key.push(c),
+            }
+        }
+        Err(self.error("unterminated quoted key"))
+    }
+
+    fn take_while(&mut self, pred: impl Fn(char) -> bool) -> &str {
+        let start = self.pos;
+        while let Some(c) = self.peek().filter(|&c| pred(c)) {
+            self.pos += c.len_utf8();
+        }
+        &self.input[start..self.pos]
+    }
+
+    fn error(&self, message: &str) -> ValuePathError {
+        ValuePathError {
+            position: self.pos,
+            message: message.to_string(),
+        }
+    }
+}
+
+/// Depth-first iterator over a value and everything nested in it.
+///
+/// Created by [`CifValue::walk`].
+pub struct Walk<'a> {
+    stack: Vec<(ValuePath, &'a CifValue)>,
+}
+
+impl<'a> Iterator for Walk<'a> {
+    type Item = (ValuePath, &'a CifValue);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (path, value) = self.stack.pop()?;
+        // Push children in reverse so the first child is visited next
+        match &value.kind {
+            CifValueKind::List(items) => {
+                for (i, item) in items.iter().enumerate().rev() {
+                    self.stack.push((path.index(i), item));
+                }
+            }
+            CifValueKind::Table(entries) => {
+                for (key, entry) in in_document_order(entries).into_iter().rev() {
+                    self.stack.push((path.key(key.as_str()), entry));
+                }
+            }
+            _ => {}
+        }
+        Some((path, value))
+    }
+}
+
+impl CifValue {
+    /// Visit this value and every value nested inside it.
+    ///
+    /// Yields `(path, value)` pairs depth-first, each value before its
+    /// children, starting with this value at the root path. List elements
+    /// and table entries are visited in document order; entries built in code,
+    /// which share a default span, come in key order.
+    ///
+    /// # Examples
+    /// ```
+    /// use cif_parser::parse_string;
+    ///
+    /// let doc = parse_string("#\\#CIF_2.0\ndata_x\n_v [a {'k':[b c]} d]\n").unwrap();
+    /// let value = doc.first_block().unwrap().get_item("_v").unwrap();
+    /// let paths: Vec<String> = value.walk().map(|(path, _)| path.to_string()).collect();
+    /// assert_eq!(paths, ["", "[0]", "[1]", "[1].k", "[1].k[0]", "[1].k[1]", "[2]"]);
+    /// ```
+    pub fn walk(&self) -> Walk<'_> {
+        Walk {
+            stack: vec![(ValuePath::root(), self)],
+        }
+    }
+
+    /// Follow a path into nested lists and tables.
+    ///
+    /// Returns `None` if a segment does not match: an index into a non-list
+    /// or past its end, or a key into a non-table or missing from it.
+    ///
+    /// # Examples
+    /// ```
+    /// use cif_parser::{parse_string, ValuePath};
+    ///
+    /// let doc = parse_string("#\\#CIF_2.0\ndata_x\n_v [a {'k':[b c]}]\n").unwrap();
+    /// let value = doc.first_block().unwrap().get_item("_v").unwrap();
+    /// let path = ValuePath::parse("[1].k[0]").unwrap();
+    /// assert_eq!(value.get_path(&path).unwrap().as_string(), Some("b"));
+    /// assert!(value.get_path(&ValuePath::parse("[5]").unwrap()).is_none());
+    /// ```
+    pub fn get_path(&self, path: &ValuePath) -> Option<&CifValue> {
+        path.segments
+            .iter()
+            .try_fold(self, |value, segment| match (segment, &value.kind) {
+                (PathSegment::Index(i), CifValueKind::List(items)) => items.get(*i),
+                (PathSegment::Key(key), CifValueKind::Table(entries)) => entries.get(key),
+                _ => None,
+            })
+    }
+
+    /// Mutable counterpart of [`get_path`](Self::get_path).
+    pub fn get_path_mut(&mut self, path: &ValuePath) -> Option<&mut CifValue> {
+        path.segments
+            .iter()
+            .try_fold(self, |value, segment| match (segment, &mut value.kind) {
+                (PathSegment::Index(i), CifValueKind::List(items)) => items.get_mut(*i),
+                (PathSegment::Key(key), CifValueKind::Table(entries)) => entries.get_mut(key),
+                _ => None,
+            })
+    }
+
+    /// Find the tables, at any depth, that contain a key.
+    ///
+    /// Yields `(path, table)` pairs in [`walk`](Self::walk) order. Useful for
+    /// picking out records by a marker key, such as the `"save"` key of
+    /// DDLm import specifications.
+    ///
+    /// # Examples
+    /// ```
+    /// use cif_parser::parse_string;
+    ///
+    /// let doc = parse_string(
+    ///     "#\\#CIF_2.0\ndata_x\n_v [{'save':a 'file':f.dic} {'mode':Full}]\n",
+    /// )
+    /// .unwrap();
+    /// let value = doc.first_block().unwrap().get_item("_v").unwrap();
+    /// let found: Vec<String> = value
+    ///     .find_tables_with_key("save")
+    ///     .map(|(path, _)| path.to_string())
+    ///     .collect();
+    /// assert_eq!(found, ["[0]"]);
+    /// ```
+    pub fn find_tables_with_key<'a>(
+        &'a self,
+        key: &'a str,
+    ) -> impl Iterator<Item = (ValuePath, &'a CifValue)> + 'a {
+        self.walk()
+            .filter(move |(_, value)| value.as_table_get(key).is_some())
+    }
+}
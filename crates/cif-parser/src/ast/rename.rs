@@ -0,0 +1,265 @@
+//! Renaming tags across a document.
+//!
+//! Migrating files from legacy to DDLm names means renaming many tags at
+//! once. [`CifDocument::rename_tags`] applies a rename map to data items,
+//! loop headers and save frames. Values, their spans and the column order of
+//! loops are untouched; only tag names change.
+//!
+//! Tags are matched case-insensitively, as CIF requires. Each data block and
+//! each save frame is its own namespace: a rename is refused there, and only
+//! there, when the new name is already used in that container or when two
+//! tags in it would get the same new name.
+//!
+//! ```
+//! use cif_parser::parse_string;
+//! use std::collections::HashMap;
+//!
+//! let mut doc = parse_string("data_x\n_cell_length_a 5.0\n_cell.length_b 6.0\n").unwrap();
+//! let renames = HashMap::from([
+//!     ("_cell_length_a".to_string(), "_cell.length_a".to_string()),
+//!     ("_cell_length_b".to_string(), "_cell.length_b".to_string()),
+//! ]);
+//! let outcome = doc.rename_tags(&renames);
+//! assert_eq!(outcome.renamed.len(), 1);
+//! assert!(outcome.is_complete());
+//! assert!(doc.first_block().unwrap().get_item("_cell.length_a").is_some());
+//! ```
+
+use super::{CifBlock, CifDocument, CifFrame, CifLoop, CifValue};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Where a tag lives: a data block, optionally inside one of its save frames
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TagLocation {
+    /// Name of the data block
+    pub block: String,
+    /// Name of the save frame, if the tag is inside one
+    pub frame: Option<String>,
+}
+
+/// A tag that was renamed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TagRename {
+    /// Container of the tag
+    pub location: TagLocation,
+    /// Tag as written before the rename
+    pub from: String,
+    /// New tag
+    pub to: String,
+}
+
+/// Why a rename was refused
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum RenameConflict {
+    /// The new name is already used by another tag in the same container
+    TargetExists {
+        /// The tag already using the name, as written
+        existing: String,
+    },
+    /// Another tag in the same container would get the same new name
+    SameTarget {
+        /// The other tag, as written
+        other: String,
+    },
+}
+
+/// A rename that was not applied
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RefusedRename {
+    /// Container of the tag
+    pub location: TagLocation,
+    /// Tag as written, left unchanged
+    pub from: String,
+    /// Requested new tag
+    pub to: String,
+    /// What the rename would have collided with
+    pub conflict: RenameConflict,
+}
+
+/// Result of [`CifDocument::rename_tags`] or [`CifDocument::check_renames`]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RenameOutcome {
+    /// Renames applied (or, for a check, that would be applied)
+    pub renamed: Vec<TagRename>,
+    /// Renames refused because of a collision
+    pub refused: Vec<RefusedRename>,
+}
+
+impl RenameOutcome {
+    /// Whether every requested rename of a tag present in the document
+    /// was applied
+    pub fn is_complete(&self) -> bool {
+        self.refused.is_empty()
+    }
+}
+
+impl CifDocument {
+    /// Rename tags throughout the document.
+    ///
+    /// `renames` maps old tags to new ones; keys are matched
+    /// case-insensitively. Renames that would collide within a block or save
+    /// frame are refused there and reported; the rest are applied. See the
+    /// [module documentation](self) for the rules.
+    pub fn rename_tags(&mut self, renames: &HashMap<String, String>) -> RenameOutcome {
+        let renames = lowercase_keys(renames);
+        let mut outcome = RenameOutcome::default();
+        for block in &mut self.blocks {
+            block.rename_tags_into(&renames, &mut outcome);
+        }
+        outcome
+    }
+
+    /// Report what [`rename_tags`](Self::rename_tags) would do without
+    /// changing the document.
+    pub fn check_renames(&self, renames: &HashMap<String, String>) -> RenameOutcome {
+        let renames = lowercase_keys(renames);
+        let mut outcome = RenameOutcome::default();
+        for block in &self.blocks {
+            for (items, loops, location) in block.containers() {
+                plan_renames(items, loops, location, &renames, &mut outcome);
+            }
+        }
+        outcome
+    }
+}
+
+impl CifBlock {
+    /// Rename tags in this block and its save frames.
+    ///
+    /// Same rules as [`CifDocument::rename_tags`].
+    pub fn rename_tags(&mut self, renames: &HashMap<String, String>) -> RenameOutcome {
+        let mut outcome = RenameOutcome::default();
+        self.rename_tags_into(&lowercase_keys(renames), &mut outcome);
+        outcome
+    }
+
+    fn rename_tags_into(&mut self, renames: &HashMap<String, &str>, outcome: &mut RenameOutcome) {
+        let plans: Vec<HashMap<String, String>> = self
+            .containers()
+            .map(|(items, loops, location)| plan_renames(items, loops, location, renames, outcome))
+            .collect();
+        let mut plans = plans.into_iter();
+        if let Some(plan) = plans.next() {
+            apply_renames(&mut self.items, &mut self.loops, &plan);
+        }
+        for (frame, plan) in self.frames.iter_mut().zip(plans) {
+            apply_renames(&mut frame.items, &mut frame.loops, &plan);
+        }
+    }
+
+    /// The block's own namespace followed by each save frame's
+    fn containers(
+        &self,
+    ) -> impl Iterator<Item = (&HashMap<String, CifValue>, &[CifLoop], TagLocation)> {
+        let own = (
+            &self.items,
+            self.loops.as_slice(),
+            TagLocation {
+                block: self.name.to_string(),
+                frame: None,
+            },
+        );
+        let frames = self.frames.iter().map(|frame: &CifFrame| {
+            (
+                &frame.items,
+                frame.loops.as_slice(),
+                TagLocation {
+                    block: self.name.to_string(),
+                    frame: Some(frame.name.to_string()),
+                },
+            )
+        });
+        std::iter::once(own).chain(frames)
+    }
+}
+
+/// Rename map keyed by lowercased old tag
+fn lowercase_keys(renames: &HashMap<String, String>) -> HashMap<String, &str> {
+    renames
+        .iter()
+        .map(|(from, to)| (from.to_lowercase(), to.as_str()))
+        .collect()
+}
+
+/// Decide which renames apply within one block or save frame
+///
+/// Records each decision in `outcome` and returns the accepted renames,
+/// keyed by the tag as written.
+fn plan_renames(
+    items: &HashMap<String, CifValue>,
+    loops: &[CifLoop],
+    location: TagLocation,
+    renames: &HashMap<String, &str>,
+    outcome: &mut RenameOutcome,
+) -> HashMap<String, String> {
+    // Every tag in the container: items sorted by name, then loop columns
+    let mut tags: Vec<&String> = items.keys().collect();
+    tags.sort();
+    tags.extend(loops.iter().flat_map(|l| &l.tags));
+    let existing: HashMap<String, &String> =
+        tags.iter().map(|tag| (tag.to_lowercase(), *tag)).collect();
+
+    let requested: Vec<(&String, &str)> = tags
+        .iter()
+        .filter_map(|tag| {
+            let to = *renames.get(&tag.to_lowercase())?;
+            (to != tag.as_str()).then_some((*tag, to))
+        })
+        .collect();
+
+    let mut accepted = HashMap::new();
+    for &(from, to) in &requested {
+        let target = to.to_lowercase();
+        let existing_tag = existing.get(&target).filter(|tag| **tag != from);
+        let other = requested
+            .iter()
+            .find(|(other, other_to)| other != &from && other_to.to_lowercase() == target);
+        let conflict = match (existing_tag, other) {
+            (Some(tag), _) => Some(RenameConflict::TargetExists {
+                existing: tag.to_string(),
+            }),
+            (None, Some((other, _))) => Some(RenameConflict::SameTarget {
+                other: other.to_string(),
+            }),
+            (None, None) => None,
+        };
+        match conflict {
+            Some(conflict) => outcome.refused.push(RefusedRename {
+                location: location.clone(),
+                from: from.clone(),
+                to: to.to_string(),
+                conflict,
+            }),
+            None => {
+                outcome.renamed.push(TagRename {
+                    location: location.clone(),
+                    from: from.clone(),
+                    to: to.to_string(),
+                });
+                accepted.insert(from.clone(), to.to_string());
+            }
+        }
+    }
+    accepted
+}
+
+/// Rename items and loop columns in place
+fn apply_renames(
+    items: &mut HashMap<String, CifValue>,
+    loops: &mut [CifLoop],
+    accepted: &HashMap<String, String>,
+) {
+    for (from, to) in accepted {
+        if let Some(value) = items.remove(from) {
+            items.insert(to.clone(), value);
+        }
+    }
+    for tag in loops.iter_mut().flat_map(|l| l.tags.iter_mut()) {
+        if let Some(to) = accepted.get(tag.as_str()) {
+            *tag = to.clone();
+        }
+    }
+}
@@ -0,0 +1,186 @@
+//! Stable serialized form of documents.
+//!
+//! Parsing a large file again costs far more than reading back a stored
+//! [`CifDocument`], so tools cache parse results in key-value stores and on
+//! disk. The serde representation of a document is therefore a contract, not
+//! whatever the derives happen to produce, and is versioned by
+//! [`DOCUMENT_FORMAT_VERSION`]:
+//!
+//! ```text
+//! {
+//!   "format_version": 2,
+//!   "blocks": [{
+//!     "name": "x",
+//!     "items": { "_a": { "kind": { "NumericWithUncertainty": { "value": 10.5, "uncertainty": 0.2 } },
+//!                        "span": { "start_line": 3, "start_col": 4, "end_line": 3, "end_col": 11 },
+//!                        "quote": "bare" } },
+//!     "loops": [{ "tags": ["_b"], "values": [[{ "kind": { "Text": "c" }, ... }]], "span": ... }],
+//!     "frames": [{ "name": "f", "items": {}, "loops": [], "span": ... }],
+//!     "span": ...
+//!   }],
+//!   "version": "V2_0",
+//!   "header": { "line": 1, "has_bom": false, "trailing": "", "misplaced": false },
+//!   "span": ...,
+//!   "trailing": "\n# end\n"
+//! }
+//! ```
+//!
+//! - `format_version` comes first and is checked before anything else is
+//!   read; a document of another version fails to deserialize, and the
+//!   caller should parse the source again.
+//! - Fields appear in the order above. Items and table entries are written
+//!   in document order (see [`CifValueKind::Table`](super::CifValueKind::Table)),
+//!   so the same document always serializes to the same bytes.
+//! - Every value keeps its span and [`QuoteStyle`](super::QuoteStyle); lists
+//!   and tables nest values of the same shape.
+//! - Value kinds are externally tagged with their variant name: `"Unknown"`,
+//!   `{"Numeric": 1.5}`, `{"List": [...]}`.
+//!
+//! Any change to this shape increments [`DOCUMENT_FORMAT_VERSION`]. The
+//! representation works with any serde format; JSON cannot hold the infinite
+//! value of an overflowing number such as `1e999`, binary formats can.
+//!
+//! ```
+//! use cif_parser::CifDocument;
+//!
+//! let doc = CifDocument::parse("#\\#CIF_2.0\ndata_x\n_a [1 {'b':2}]\n").unwrap();
+//! let json = serde_json::to_string(&doc).unwrap();
+//! assert!(json.starts_with(r#"{"format_version":2,"#));
+//!
+//! let back: CifDocument = serde_json::from_str(&json).unwrap();
+//! assert_eq!(back, doc);
+//!
+//! let stale = json.replacen(r#""format_version":2"#, r#""format_version":1"#, 1);
+//! assert!(serde_json::from_str::<CifDocument>(&stale).is_err());
+//! ```
+
+use super::CifDocument;
+use serde::de::{self, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Version of the serialized document format
+pub const DOCUMENT_FORMAT_VERSION: u32 = 2;
+
+const FIELDS: &[&str] = &[
+    "format_version",
+    "blocks",
+    "version",
+    "header",
+    "span",
+    "trailing",
+];
+
+impl Serialize for CifDocument {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut out = serializer.serialize_struct("CifDocument", FIELDS.len())?;
+        out.serialize_field("format_version", &DOCUMENT_FORMAT_VERSION)?;
+        out.serialize_field("blocks", &self.blocks)?;
+        out.serialize_field("version", &self.version)?;
+        out.serialize_field("header", &self.header)?;
+        out.serialize_field("span", &self.span)?;
+        out.serialize_field("trailing", &self.trailing)?;
+        out.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for CifDocument {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct("CifDocument", FIELDS, DocumentVisitor)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum Field {
+    FormatVersion,
+    Blocks,
+    Version,
+    Header,
+    Span,
+    Trailing,
+    #[serde(other)]
+    Unknown,
+}
+
+struct DocumentVisitor;
+
+impl<'de> Visitor<'de> for DocumentVisitor {
+    type Value = CifDocument;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "a CIF document of format version {DOCUMENT_FORMAT_VERSION}"
+        )
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<CifDocument, A::Error> {
+        let format_version = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        check_format_version(format_version)?;
+        Ok(CifDocument {
+            blocks: seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(1, &self))?,
+            version: seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(2, &self))?,
+            header: seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(3, &self))?,
+            span: seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(4, &self))?,
+            trailing: seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(5, &self))?,
+        })
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<CifDocument, A::Error> {
+        let mut format_version = None;
+        let mut blocks = None;
+        let mut version = None;
+        let mut header = None;
+        let mut span = None;
+        let mut trailing = None;
+        while let Some(field) = map.next_key()? {
+            match field {
+                Field::FormatVersion => {
+                    let found = map.next_value()?;
+                    check_format_version(found)?;
+                    format_version = Some(found);
+                }
+                Field::Blocks => blocks = Some(map.next_value()?),
+                Field::Version => version = Some(map.next_value()?),
+                Field::Header => header = Some(map.next_value()?),
+                Field::Span => span = Some(map.next_value()?),
+                Field::Trailing => trailing = Some(map.next_value()?),
+                Field::Unknown => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        format_version.ok_or_else(|| de::Error::missing_field("format_version"))?;
+        Ok(CifDocument {
+            blocks: blocks.ok_or_else(|| de::Error::missing_field("blocks"))?,
+            version: version.ok_or_else(|| de::Error::missing_field("version"))?,
+            header: header.ok_or_else(|| de::Error::missing_field("header"))?,
+            span: span.ok_or_else(|| de::Error::missing_field("span"))?,
+            trailing: trailing.ok_or_else(|| de::Error::missing_field("trailing"))?,
+        })
+    }
+}
+
+fn check_format_version<E: de::Error>(found: u32) -> Result<(), E> {
+    if found == DOCUMENT_FORMAT_VERSION {
+        Ok(())
+    } else {
+        Err(E::custom(format_args!(
+            "document format version {found} is not supported (expected {DOCUMENT_FORMAT_VERSION})"
+        )))
+    }
+}
@@ -0,0 +1,374 @@
+//! Statistics and anomaly screening for numeric loop columns.
+//!
+//! Reviewers triaging a file want to spot a fractional coordinate of 73.2 or
+//! an occupancy of 11 without loading a dictionary. [`CifLoop::column_stats`]
+//! summarizes one column, and [`CifBlock::screen_numeric_anomalies`] flags
+//! the cells of every numeric column that are far from the rest of their
+//! column, or outside the plausible range of a few well-known tags.
+
+use super::{CifBlock, CifLoop, CifValue, CifValueKind, Span};
+use serde::{Deserialize, Serialize};
+
+/// Summary statistics of one loop column.
+///
+/// Only numbers, with or without an uncertainty, enter the statistics; a
+/// quoted number is text. The value statistics are `None` when the column
+/// has no numbers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ColumnStats {
+    /// The column tag, as written
+    pub tag: String,
+    /// Number of cells
+    pub count: usize,
+    /// Number of numeric cells
+    pub numeric: usize,
+    /// Number of unknown (`?`) cells
+    pub unknown: usize,
+    /// Number of not applicable (`.`) cells
+    pub not_applicable: usize,
+    /// Number of cells that are neither numbers nor `?` or `.`
+    pub non_numeric: usize,
+    /// Smallest number
+    pub min: Option<f64>,
+    /// Largest number
+    pub max: Option<f64>,
+    /// Mean of the numbers
+    pub mean: Option<f64>,
+    /// Sample standard deviation of the numbers; `None` below two numbers
+    pub std_dev: Option<f64>,
+    /// First quartile of the numbers
+    pub q1: Option<f64>,
+    /// Median of the numbers
+    pub median: Option<f64>,
+    /// Third quartile of the numbers
+    pub q3: Option<f64>,
+}
+
+impl ColumnStats {
+    /// Statistics of a column's cells
+    fn from_cells<'a>(tag: &str, cells: impl Iterator<Item = &'a CifValue>) -> Self {
+        let mut stats = ColumnStats {
+            tag: tag.to_string(),
+            count: 0,
+            numeric: 0,
+            unknown: 0,
+            not_applicable: 0,
+            non_numeric: 0,
+            min: None,
+            max: None,
+            mean: None,
+            std_dev: None,
+            q1: None,
+            median: None,
+            q3: None,
+        };
+        let mut numbers = Vec::new();
+        for cell in cells {
+            stats.count += 1;
+            match &cell.kind {
+                CifValueKind::Numeric(n)
+                | CifValueKind::NumericWithUncertainty { value: n, .. } => numbers.push(*n),
+                CifValueKind::Unknown => stats.unknown += 1,
+                CifValueKind::NotApplicable => stats.not_applicable += 1,
+                _ => stats.non_numeric += 1,
+            }
+        }
+        stats.numeric = numbers.len();
+        if numbers.is_empty() {
+            return stats;
+        }
+
+        numbers.sort_by(f64::total_cmp);
+        let n = numbers.len() as f64;
+        let mean = numbers.iter().sum::<f64>() / n;
+        stats.min = numbers.first().copied();
+        stats.max = numbers.last().copied();
+        stats.mean = Some(mean);
+        if numbers.len() > 1 {
+            let squares: f64 = numbers.iter().map(|x| (x - mean).powi(2)).sum();
+            stats.std_dev = Some((squares / (n - 1.0)).sqrt());
+        }
+        stats.q1 = Some(quantile(&numbers, 0.25));
+        stats.median = Some(quantile(&numbers, 0.5));
+        stats.q3 = Some(quantile(&numbers, 0.75));
+        stats
+    }
+
+    /// Check if most of the column's informative cells are numbers
+    pub fn is_numeric(&self) -> bool {
+        self.numeric > 0 && self.numeric >= self.non_numeric
+    }
+
+    /// The interquartile range, `q3 - q1`
+    pub fn iqr(&self) -> Option<f64> {
+        Some(self.q3? - self.q1?)
+    }
+}
+
+/// Quantile of sorted values, interpolating linearly between ranks
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let rank = q * (sorted.len() - 1) as f64;
+    let below = rank.floor() as usize;
+    let above = rank.ceil() as usize;
+    sorted[below] + (sorted[above] - sorted[below]) * (rank - below as f64)
+}
+
+/// The values a tag can plausibly take, inclusive
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlausibleRange {
+    /// The tag; compared ignoring case
+    pub tag: String,
+    /// Smallest plausible value
+    pub min: f64,
+    /// Largest plausible value
+    pub max: f64,
+}
+
+impl PlausibleRange {
+    /// Create a range for `tag`
+    pub fn new(tag: impl Into<String>, min: f64, max: f64) -> Self {
+        PlausibleRange {
+            tag: tag.into(),
+            min,
+            max,
+        }
+    }
+
+    fn contains(&self, value: f64) -> bool {
+        (self.min..=self.max).contains(&value)
+    }
+}
+
+/// Built-in plausible ranges, in both tag spellings, as (tags, min, max).
+///
+/// A fractional coordinate may lie a cell outside the unit cell, as when a
+/// molecule is kept whole, but not much further. Torsion angles are written
+/// both from -180 to 180 and from 0 to 360.
+const KNOWN_RANGES: &[(&[&str], f64, f64)] = &[
+    (
+        &[
+            "_atom_site.fract_x",
+            "_atom_site_fract_x",
+            "_atom_site.fract_y",
+            "_atom_site_fract_y",
+            "_atom_site.fract_z",
+            "_atom_site_fract_z",
+        ],
+        -1.0,
+        2.0,
+    ),
+    (&["_atom_site.occupancy", "_atom_site_occupancy"], 0.0, 1.0),
+    (
+        &[
+            "_geom_angle.value",
+            "_geom_angle",
+            "_geom_hbond.angle_DHA",
+            "_geom_hbond_angle_DHA",
+        ],
+        0.0,
+        180.0,
+    ),
+    (&["_geom_torsion.value", "_geom_torsion"], -180.0, 360.0),
+];
+
+/// Thresholds for [`CifBlock::screen_numeric_anomalies`].
+///
+/// # Examples
+///
+/// ```
+/// use cif_parser::{CifDocument, ScreeningOptions};
+///
+/// let doc = CifDocument::parse("data_x\nloop_\n_my.fraction\n0.2\n0.4\n1.7\n").unwrap();
+/// let options = ScreeningOptions::new().with_range("_my.fraction", 0.0, 1.0);
+/// let anomalies = doc.blocks[0].screen_numeric_anomalies(&options);
+/// assert_eq!(anomalies.len(), 1);
+/// assert_eq!(anomalies[0].row, 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScreeningOptions {
+    /// A number is an outlier when it is more than this many interquartile
+    /// ranges below the first quartile or above the third
+    pub iqr_factor: f64,
+    /// Fewest numbers a column needs before outliers are looked for
+    pub min_values: usize,
+    /// Plausible ranges of tags, checked whenever a column's tag matches
+    pub ranges: Vec<PlausibleRange>,
+}
+
+impl Default for ScreeningOptions {
+    fn default() -> Self {
+        let ranges = KNOWN_RANGES
+            .iter()
+            .flat_map(|(tags, min, max)| {
+                tags.iter()
+                    .map(move |tag| PlausibleRange::new(*tag, *min, *max))
+            })
+            .collect();
+        ScreeningOptions {
+            iqr_factor: 3.0,
+            min_values: 5,
+            ranges,
+        }
+    }
+}
+
+impl ScreeningOptions {
+    /// Outliers beyond 3 interquartile ranges in columns of at least 5
+    /// numbers, and the built-in ranges of fractional coordinates,
+    /// occupancies and geometric angles
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Flag numbers more than `factor` interquartile ranges outside the quartiles
+    pub fn with_iqr_factor(mut self, factor: f64) -> Self {
+        self.iqr_factor = factor;
+        self
+    }
+
+    /// Look for outliers only in columns with at least `count` numbers
+    pub fn with_min_values(mut self, count: usize) -> Self {
+        self.min_values = count;
+        self
+    }
+
+    /// Also flag values of `tag` outside `min..=max`
+    pub fn with_range(mut self, tag: impl Into<String>, min: f64, max: f64) -> Self {
+        self.ranges.push(PlausibleRange::new(tag, min, max));
+        self
+    }
+
+    /// Drop the built-in ranges, keeping only those added afterwards
+    pub fn without_known_ranges(mut self) -> Self {
+        self.ranges.clear();
+        self
+    }
+
+    fn range_of(&self, tag: &str) -> Option<&PlausibleRange> {
+        self.ranges.iter().find(|r| r.tag.eq_ignore_ascii_case(tag))
+    }
+}
+
+/// Why a number was flagged
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AnomalyReason {
+    /// Outside the plausible range of its tag
+    OutOfRange {
+        /// Smallest plausible value
+        min: f64,
+        /// Largest plausible value
+        max: f64,
+    },
+    /// Far from the rest of its column: outside `lower..=upper`, the
+    /// quartiles widened by the configured multiple of the interquartile range
+    Outlier {
+        /// Lowest value that is not an outlier
+        lower: f64,
+        /// Highest value that is not an outlier
+        upper: f64,
+    },
+}
+
+/// A number flagged by [`CifBlock::screen_numeric_anomalies`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Anomaly {
+    /// Index of the loop in the block
+    pub loop_index: usize,
+    /// The column tag, as written
+    pub tag: String,
+    /// Row of the number in the loop (0-based)
+    pub row: usize,
+    /// The number
+    pub value: f64,
+    /// Why it was flagged
+    pub reason: AnomalyReason,
+    /// Location of the cell
+    pub span: Span,
+}
+
+impl CifLoop {
+    /// Statistics of the column with `tag`, or `None` if the loop has no
+    /// such column
+    ///
+    /// Tags compare ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cif_parser::CifDocument;
+    ///
+    /// let doc = CifDocument::parse("data_x\nloop_\n_a\n1\n2\n?\n6\n").unwrap();
+    /// let stats = doc.blocks[0].loops[0].column_stats("_a").unwrap();
+    /// assert_eq!((stats.count, stats.numeric, stats.unknown), (4, 3, 1));
+    /// assert_eq!(stats.mean, Some(3.0));
+    /// assert_eq!(stats.median, Some(2.0));
+    /// ```
+    pub fn column_stats(&self, tag: &str) -> Option<ColumnStats> {
+        let col = self.tags.iter().position(|t| t.eq_ignore_ascii_case(tag))?;
+        let cells = self.values.iter().filter_map(|row| row.get(col));
+        Some(ColumnStats::from_cells(&self.tags[col], cells))
+    }
+}
+
+impl CifBlock {
+    /// Numbers in the block's loops that look wrong, in loop, column and
+    /// row order
+    ///
+    /// A column whose tag has a plausible range in `options` has every number
+    /// outside it flagged. Every mostly numeric column with at least
+    /// `options.min_values` numbers also has its outliers flagged, unless its
+    /// interquartile range is zero. A number is flagged once, as out of range
+    /// if it is both. Save frames are not screened.
+    pub fn screen_numeric_anomalies(&self, options: &ScreeningOptions) -> Vec<Anomaly> {
+        let mut anomalies = Vec::new();
+        for (loop_index, loop_) in self.loops.iter().enumerate() {
+            for (col, tag) in loop_.tags.iter().enumerate() {
+                let cells = loop_.values.iter().filter_map(|row| row.get(col));
+                let stats = ColumnStats::from_cells(tag, cells);
+                if !stats.is_numeric() {
+                    continue;
+                }
+                let range = options.range_of(tag);
+                let fences = outlier_fences(&stats, options);
+                for (row, values) in loop_.values.iter().enumerate() {
+                    let Some(cell) = values.get(col) else {
+                        continue;
+                    };
+                    let Some(value) = cell.as_numeric() else {
+                        continue;
+                    };
+                    let reason = match (range, fences) {
+                        (Some(range), _) if !range.contains(value) => AnomalyReason::OutOfRange {
+                            min: range.min,
+                            max: range.max,
+                        },
+                        (_, Some((lower, upper))) if value < lower || value > upper => {
+                            AnomalyReason::Outlier { lower, upper }
+                        }
+                        _ => continue,
+                    };
+                    anomalies.push(Anomaly {
+                        loop_index,
+                        tag: tag.clone(),
+                        row,
+                        value,
+                        reason,
+                        span: cell.span,
+                    });
+                }
+            }
+        }
+        anomalies
+    }
+}
+
+/// The bounds outside which a column's numbers are outliers, if it has
+/// enough numbers and they are spread out
+fn outlier_fences(stats: &ColumnStats, options: &ScreeningOptions) -> Option<(f64, f64)> {
+    if stats.numeric < options.min_values {
+        return None;
+    }
+    let iqr = stats.iqr().filter(|&iqr| iqr > 0.0)?;
+    let spread = options.iqr_factor * iqr;
+    Some((stats.q1? - spread, stats.q3? + spread))
+}
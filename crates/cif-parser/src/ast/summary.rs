@@ -0,0 +1,243 @@
+//! Common crystallographic metadata extracted from a data block.
+//!
+//! Most consumers of a structure CIF want the same handful of values: the
+//! formula, space group, unit cell, and refinement statistics. Each has a
+//! modern DDLm spelling (`_cell.length_a`) and one or more legacy CIF 1.1
+//! spellings (`_cell_length_a`), so [`CifBlock::summary`] checks all of them
+//! without needing a dictionary.
+
+use super::{CifBlock, CifValue, CifValueKind};
+use crate::cif_num::{format_numeric, FormatPolicy};
+use serde::{Deserialize, Serialize};
+
+/// A measured number with its optional standard uncertainty.
+///
+/// # Examples
+///
+/// ```
+/// use cif_parser::{ast::Measurand, CifValue};
+///
+/// let m = Measurand::from_value(&CifValue::parse_value("10.01(11)")).unwrap();
+/// assert_eq!(m.value, 10.01);
+/// assert!((m.uncertainty.unwrap() - 0.11).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Measurand {
+    /// The measured value
+    pub value: f64,
+    /// The standard uncertainty, if one was written
+    pub uncertainty: Option<f64>,
+}
+
+impl Measurand {
+    /// Create a measurand without an uncertainty
+    pub fn new(value: f64) -> Self {
+        Measurand {
+            value,
+            uncertainty: None,
+        }
+    }
+
+    /// Create a measurand with a standard uncertainty
+    pub fn with_uncertainty(value: f64, uncertainty: f64) -> Self {
+        Measurand {
+            value,
+            uncertainty: Some(uncertainty),
+        }
+    }
+
+    /// Read a measurand from a numeric value.
+    ///
+    /// Returns `None` for text, special values, lists, and tables.
+    pub fn from_value(value: &CifValue) -> Option<Self> {
+        match value.kind {
+            CifValueKind::Numeric(n) => Some(Measurand::new(n)),
+            CifValueKind::NumericWithUncertainty { value, uncertainty } => {
+                Some(Measurand::with_uncertainty(value, uncertainty))
+            }
+            _ => None,
+        }
+    }
+
+    /// Write the measurand as a CIF number, such as `10.01(11)`
+    ///
+    /// ```
+    /// use cif_parser::ast::Measurand;
+    /// use cif_parser::cif_num::FormatPolicy;
+    ///
+    /// let m = Measurand::with_uncertainty(1.23456, 0.0234);
+    /// assert_eq!(m.format_cif(&FormatPolicy::new()), "1.23(2)");
+    /// assert_eq!(Measurand::new(90.0).format_cif(&FormatPolicy::new()), "90");
+    /// ```
+    pub fn format_cif(&self, policy: &FormatPolicy) -> String {
+        format_numeric(self.value, self.uncertainty, policy)
+    }
+}
+
+/// Common crystallographic values found in a data block.
+///
+/// Every field is optional; a field is `None` when none of its tag spellings
+/// are present or the value is `?`, `.`, or of the wrong type.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BlockSummary {
+    /// Name of the block the summary was taken from
+    pub block_name: String,
+    /// Empirical formula (`_chemical_formula.sum`)
+    pub chemical_formula_sum: Option<String>,
+    /// Hermann-Mauguin space group symbol (`_space_group.name_H-M_alt`)
+    pub space_group_name_hm: Option<String>,
+    /// International Tables space group number (`_space_group.IT_number`)
+    pub space_group_number: Option<u32>,
+    /// Cell lengths and angles in the order a, b, c, α, β, γ
+    pub cell: [Option<Measurand>; 6],
+    /// Cell volume (`_cell.volume`)
+    pub volume: Option<Measurand>,
+    /// Formula units per cell (`_cell.formula_units_Z`)
+    pub z: Option<u32>,
+    /// Measurement temperature in kelvin (`_diffrn.ambient_temperature`)
+    pub temperature: Option<Measurand>,
+    /// Radiation wavelength in ångströms (`_diffrn_radiation_wavelength.value`)
+    pub wavelength: Option<Measurand>,
+    /// R factor for significantly intense reflections (`_refine_ls.R_factor_gt`)
+    pub r_factor_gt: Option<f64>,
+    /// Weighted R factor for all reflections (`_refine_ls.wR_factor_ref`)
+    pub wr_factor: Option<f64>,
+}
+
+impl BlockSummary {
+    /// Check if no summary field was found
+    pub fn is_empty(&self) -> bool {
+        let empty = BlockSummary {
+            block_name: self.block_name.clone(),
+            ..BlockSummary::default()
+        };
+        *self == empty
+    }
+}
+
+const FORMULA_SUM: &[&str] = &["_chemical_formula.sum", "_chemical_formula_sum"];
+
+const SPACE_GROUP_HM: &[&str] = &[
+    "_space_group.name_H-M_alt",
+    "_space_group_name_H-M_alt",
+    "_space_group.name_H-M_full",
+    "_space_group_name_H-M_full",
+    "_symmetry.space_group_name_H-M",
+    "_symmetry_space_group_name_H-M",
+];
+
+const SPACE_GROUP_NUMBER: &[&str] = &[
+    "_space_group.IT_number",
+    "_space_group_IT_number",
+    "_symmetry.Int_Tables_number",
+    "_symmetry_Int_Tables_number",
+];
+
+const CELL: [&[&str]; 6] = [
+    &["_cell.length_a", "_cell_length_a"],
+    &["_cell.length_b", "_cell_length_b"],
+    &["_cell.length_c", "_cell_length_c"],
+    &["_cell.angle_alpha", "_cell_angle_alpha"],
+    &["_cell.angle_beta", "_cell_angle_beta"],
+    &["_cell.angle_gamma", "_cell_angle_gamma"],
+];
+
+const VOLUME: &[&str] = &["_cell.volume", "_cell_volume"];
+
+const Z: &[&str] = &["_cell.formula_units_Z", "_cell_formula_units_Z"];
+
+const TEMPERATURE: &[&str] = &[
+    "_diffrn.ambient_temperature",
+    "_diffrn_ambient_temperature",
+    "_cell_measurement.temperature",
+    "_cell_measurement_temperature",
+];
+
+const WAVELENGTH: &[&str] = &[
+    "_diffrn_radiation_wavelength.value",
+    "_diffrn_radiation_wavelength.wavelength",
+    "_diffrn_radiation_wavelength",
+];
+
+const R_FACTOR_GT: &[&str] = &[
+    "_refine_ls.R_factor_gt",
+    "_refine_ls_R_factor_gt",
+    "_refine_ls_R_factor_obs",
+];
+
+const WR_FACTOR: &[&str] = &["_refine_ls.wR_factor_ref", "_refine_ls_wR_factor_ref"];
+
+impl CifBlock {
+    /// Extract common crystallographic metadata from this block.
+    ///
+    /// Modern and legacy spellings of each tag are checked case-insensitively,
+    /// in both single items and the first row of loops.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cif_parser::Document;
+    /// let cif = "data_test\n_cell_length_a 10.01(11)\n_symmetry_Int_Tables_number 14\n";
+    /// let doc = Document::parse(cif).unwrap();
+    /// let summary = doc.first_block().unwrap().summary();
+    ///
+    /// assert_eq!(summary.space_group_number, Some(14));
+    /// assert_eq!(summary.cell[0].unwrap().value, 10.01);
+    /// assert!(summary.volume.is_none());
+    /// ```
+    pub fn summary(&self) -> BlockSummary {
+        BlockSummary {
+            block_name: self.name.to_string(),
+            chemical_formula_sum: self.first_text(FORMULA_SUM),
+            space_group_name_hm: self.first_text(SPACE_GROUP_HM),
+            space_group_number: self.first_count(SPACE_GROUP_NUMBER),
+            cell: CELL.map(|tags| self.first_measurand(tags)),
+            volume: self.first_measurand(VOLUME),
+            z: self.first_count(Z),
+            temperature: self.first_measurand(TEMPERATURE),
+            wavelength: self.first_measurand(WAVELENGTH),
+            r_factor_gt: self.first_measurand(R_FACTOR_GT).map(|m| m.value),
+            wr_factor: self.first_measurand(WR_FACTOR).map(|m| m.value),
+        }
+    }
+
+    /// Look up a tag case-insensitively in items, then in the first loop row
+    fn lookup(&self, tag: &str) -> Option<&CifValue> {
+        let item = self
+            .items
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(tag))
+            .map(|(_, value)| value);
+
+        item.or_else(|| {
+            self.loops.iter().find_map(|loop_| {
+                let column = loop_
+                    .tags
+                    .iter()
+                    .position(|name| name.eq_ignore_ascii_case(tag))?;
+                loop_.get(0, column)
+            })
+        })
+    }
+
+    fn first_value<T>(&self, tags: &[&str], read: impl Fn(&CifValue) -> Option<T>) -> Option<T> {
+        tags.iter().find_map(|tag| self.lookup(tag).and_then(&read))
+    }
+
+    fn first_text(&self, tags: &[&str]) -> Option<String> {
+        self.first_value(tags, |value| match &value.kind {
+            CifValueKind::Text(text) if !text.trim().is_empty() => Some(text.trim().to_string()),
+            _ => None,
+        })
+    }
+
+    fn first_measurand(&self, tags: &[&str]) -> Option<Measurand> {
+        self.first_value(tags, Measurand::from_value)
+    }
+
+    fn first_count(&self, tags: &[&str]) -> Option<u32> {
+        self.first_value(tags, |value| {
+            let n = value.as_numeric()?;
+            (n >= 0.0 && n.fract() == 0.0 && n <= u32::MAX as f64).then_some(n as u32)
+        })
+    }
+}
@@ -1,8 +1,11 @@
 //! CIF value types with automatic type detection and source location tracking.
 
+use super::order::{in_document_order, serialize_in_document_order};
 use super::span::Span;
+use crate::cif_num::{format_numeric, FormatPolicy};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt;
 
 /// Represents a single value in a CIF file with source location tracking.
 ///
@@ -20,12 +23,81 @@ use std::collections::HashMap;
 /// assert_eq!(val.as_string(), Some("hello"));
 /// assert_eq!(val.span.start_line, 1);
 /// ```
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+///
+/// Equality compares `kind` and `span` only; two values that differ only in
+/// how they were quoted are equal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CifValue {
     /// The kind/variant of the value
     pub kind: CifValueKind,
     /// Source location of this value
     pub span: Span,
+    /// How the value was delimited in the source
+    #[serde(default)]
+    pub quote: QuoteStyle,
+}
+
+impl PartialEq for CifValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind && self.span == other.span
+    }
+}
+
+/// The delimiters a value was written with.
+///
+/// Recorded by the parser so that tools can rewrite a file faithfully and
+/// lint quoting choices. Values built in code default to [`QuoteStyle::Auto`],
+/// leaving the choice of delimiters to whoever serializes them.
+///
+/// In CIF 1.1, triple-quoted strings, lists and tables are not recognized and
+/// degrade to literal text; those values are recorded as [`QuoteStyle::Bare`].
+///
+/// # Examples
+///
+/// ```
+/// use cif_parser::{parse_string, QuoteStyle};
+///
+/// let doc = parse_string("data_x\n_a 'quoted'\n_b bare\n").unwrap();
+/// let block = doc.first_block().unwrap();
+/// assert_eq!(block.get_item("_a").unwrap().quote, QuoteStyle::SingleQuote);
+/// assert_eq!(block.get_item("_b").unwrap().quote, QuoteStyle::Bare);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum QuoteStyle {
+    /// Not read from source; any valid delimiters may be used
+    #[default]
+    Auto,
+    /// Whitespace-delimited, no quotes (also numbers, `?`, `.`, lists and tables)
+    Bare,
+    /// `'...'`
+    SingleQuote,
+    /// `"..."`
+    DoubleQuote,
+    /// `'''...'''` (CIF 2.0)
+    TripleSingle,
+    /// `"""..."""` (CIF 2.0)
+    TripleDouble,
+    /// Semicolon-delimited text field
+    TextField,
+}
+
+impl QuoteStyle {
+    /// Style for a single- or triple-quoted string opened with `quote_char`
+    pub(crate) fn quoted(quote_char: char, triple: bool) -> Self {
+        match (quote_char, triple) {
+            ('"', false) => QuoteStyle::DoubleQuote,
+            ('"', true) => QuoteStyle::TripleDouble,
+            (_, false) => QuoteStyle::SingleQuote,
+            (_, true) => QuoteStyle::TripleSingle,
+        }
+    }
+
+    /// Returns true for the triple-quoted forms
+    pub fn is_triple(&self) -> bool {
+        matches!(self, QuoteStyle::TripleSingle | QuoteStyle::TripleDouble)
+    }
 }
 
 /// The variant/kind of a CIF value.
@@ -62,7 +134,11 @@ pub struct CifValue {
 /// assert!(matches!(CifValue::parse_value("?").kind, cif_parser::CifValueKind::Unknown));
 /// assert!(matches!(CifValue::parse_value(".").kind, cif_parser::CifValueKind::NotApplicable));
 /// ```
+///
+/// This enum is `#[non_exhaustive]`: matches outside this crate need a
+/// wildcard arm so new value kinds can be added without a breaking change.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum CifValueKind {
     // ===== CIF 1.1 Value Types =====
     /// String value (from quoted strings, unquoted strings, or text fields)
@@ -91,8 +167,9 @@ pub enum CifValueKind {
 
     /// Table/dictionary of key-value pairs (CIF 2.0 only)
     /// Example: `{key1:value1 key2:value2}`
-    /// Keys must be quoted strings, values can be any CIF value type
-    Table(HashMap<String, CifValue>),
+    /// Keys must be quoted strings, values can be any CIF value type.
+    /// Entries are serialized in document order.
+    Table(#[serde(serialize_with = "serialize_in_document_order")] HashMap<String, CifValue>),
 }
 
 impl CifValue {
@@ -100,7 +177,17 @@ impl CifValue {
 
     /// Create a new CifValue with the given kind and span.
     pub fn new(kind: CifValueKind, span: Span) -> Self {
-        Self { kind, span }
+        Self {
+            kind,
+            span,
+            quote: QuoteStyle::Auto,
+        }
+    }
+
+    /// Record the delimiters the value was written with.
+    pub fn with_quote(mut self, quote: QuoteStyle) -> Self {
+        self.quote = quote;
+        self
     }
 
     /// Create a text value.
@@ -237,18 +324,75 @@ impl CifValue {
 
     /// Attempt to parse as a number, falling back to text. Returns the kind only.
     fn parse_numeric_or_text_kind(s: &str) -> CifValueKind {
-        // Try standard f64 parsing first
-        if let Ok(num) = s.parse::<f64>() {
-            return CifValueKind::Numeric(num);
+        match Self::parse_number(s) {
+            Some((value, None)) => CifValueKind::Numeric(value),
+            Some((value, Some(uncertainty))) => {
+                CifValueKind::NumericWithUncertainty { value, uncertainty }
+            }
+            None => CifValueKind::Text(s.to_string()),
+        }
+    }
+
+    /// Parse a CIF number and its standard uncertainty, if it has one.
+    ///
+    /// Accepts the numeric forms of the CIF grammar: an optional sign, digits
+    /// with an optional decimal point on either side (`5`, `5.`, `.5`,
+    /// `5.25`), an optional exponent (`e3`, `E-3`, `e+3`) and an optional
+    /// uncertainty of unsigned digits in parentheses. Anything else is text,
+    /// including the `inf` and `nan` that `f64::from_str` would accept.
+    ///
+    /// # Examples
+    /// ```
+    /// use cif_parser::CifValue;
+    ///
+    /// assert_eq!(CifValue::parse_number("+5."), Some((5.0, None)));
+    /// assert_eq!(CifValue::parse_number(".5(2)"), Some((0.5, Some(0.2))));
+    /// assert_eq!(CifValue::parse_number("inf"), None);
+    /// ```
+    pub fn parse_number(s: &str) -> Option<(f64, Option<f64>)> {
+        let (number, su_digits) = match s.strip_suffix(')') {
+            Some(rest) => {
+                let (number, digits) = rest.rsplit_once('(')?;
+                (number, Some(digits))
+            }
+            None => (s, None),
+        };
+
+        let (mantissa, exponent) = match number.split_once(['e', 'E']) {
+            Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+            None => (number, None),
+        };
+        let unsigned = mantissa.strip_prefix(['+', '-']).unwrap_or(mantissa);
+        let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        if (whole.is_empty() && fraction.is_empty()) || !is_digits(whole) || !is_digits(fraction) {
+            return None;
+        }
+        if let Some(exponent) = exponent {
+            let digits = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+            if digits.is_empty() || !is_digits(digits) {
+                return None;
+            }
         }
 
-        // Try uncertainty notation (e.g., "7.470(6)")
-        if let Some((value, uncertainty)) = Self::parse_with_uncertainty(s) {
-            return CifValueKind::NumericWithUncertainty { value, uncertainty };
+        let value: f64 = number.parse().ok()?;
+        let Some(su_digits) = su_digits else {
+            return Some((value, None));
+        };
+        if su_digits.is_empty() || !is_digits(su_digits) {
+            return None;
         }
+        let unc_value: u64 = su_digits.parse().ok()?;
+        let exponent: i32 = exponent.map_or(Some(0), |e| e.parse().ok())?;
+
+        // The uncertainty is in the last digits of the mantissa, i.e.
+        // digits * 10^(exponent - decimal_places). Parsing that as a decimal
+        // literal rounds once, where multiplying by `10_f64.powi(..)` would
+        // round the scale and the product separately.
+        let uncertainty: f64 = format!("{}e{}", unc_value, exponent - fraction.len() as i32)
+            .parse()
+            .ok()?;
 
-        // Fall back to text
-        CifValueKind::Text(s.to_string())
+        Some((value, Some(uncertainty)))
     }
 
     /// Parse a number with standard uncertainty notation.
@@ -261,47 +405,16 @@ impl CifValue {
     /// - `11.910400(4)` → value=11.910400, uncertainty=0.000004
     /// - `3.45e1(12)` → value=34.5, uncertainty=0.12
     /// - `-1.2345e-4(2)` → value=-0.00012345, uncertainty=0.000000002
+    /// - `.5(2)` → value=0.5, uncertainty=0.2
+    /// - `+5.(3)` → value=5.0, uncertainty=3.0
+    ///
+    /// Returns `None` for numbers without an uncertainty; see
+    /// [`parse_number`](Self::parse_number) for the accepted forms.
     pub fn parse_with_uncertainty(s: &str) -> Option<(f64, f64)> {
-        // Find the opening parenthesis for uncertainty
-        let paren_start = s.rfind('(')?;
-        let paren_end = s.rfind(')')?;
-
-        // Validate parentheses are at the end
-        if paren_end != s.len() - 1 || paren_start >= paren_end {
-            return None;
+        match Self::parse_number(s)? {
+            (value, Some(uncertainty)) => Some((value, uncertainty)),
+            (_, None) => None,
         }
-
-        // Extract the numeric part and uncertainty digits
-        let num_part = &s[..paren_start];
-        let unc_digits = &s[paren_start + 1..paren_end];
-
-        // Parse the uncertainty digits as an integer
-        let unc_value: u64 = unc_digits.parse().ok()?;
-
-        // Handle scientific notation: split into mantissa and exponent
-        let (mantissa_str, exponent) = if let Some(e_pos) = num_part.to_lowercase().find('e') {
-            let exp: i32 = num_part[e_pos + 1..].parse().ok()?;
-            (&num_part[..e_pos], exp)
-        } else {
-            (num_part, 0)
-        };
-
-        // Parse the mantissa as f64
-        let value: f64 = num_part.parse().ok()?;
-
-        // Calculate the scale factor based on decimal places in the mantissa
-        let decimal_places = if let Some(dot_pos) = mantissa_str.find('.') {
-            (mantissa_str.len() - dot_pos - 1) as i32
-        } else {
-            0
-        };
-
-        // The uncertainty is in the last digits of the mantissa
-        // Scale = 10^(-decimal_places + exponent)
-        let scale = 10_f64.powi(-decimal_places + exponent);
-        let uncertainty = (unc_value as f64) * scale;
-
-        Some((value, uncertainty))
     }
 
     // ===== Accessor methods =====
@@ -501,7 +614,7 @@ impl CifValue {
         }
     }
 
-    /// Get an iterator over table keys.
+    /// Get an iterator over table keys, in document order.
     ///
     /// Returns `Some(iterator)` if this is a Table, `None` otherwise.
     ///
@@ -520,7 +633,11 @@ impl CifValue {
     /// ```
     pub fn as_table_keys(&self) -> Option<impl Iterator<Item = &str>> {
         match &self.kind {
-            CifValueKind::Table(table) => Some(table.keys().map(|s| s.as_str())),
+            CifValueKind::Table(table) => Some(
+                in_document_order(table)
+                    .into_iter()
+                    .map(|(key, _)| key.as_str()),
+            ),
             _ => None,
         }
     }
@@ -552,6 +669,11 @@ impl CifValue {
     }
 }
 
+/// Whether every byte of `s` is an ASCII digit (true when empty)
+fn is_digits(s: &str) -> bool {
+    s.bytes().all(|b| b.is_ascii_digit())
+}
+
 // Implement standard FromStr trait
 impl std::str::FromStr for CifValue {
     type Err = std::convert::Infallible; // This method never fails
@@ -560,3 +682,108 @@ impl std::str::FromStr for CifValue {
         Ok(Self::parse_value(s))
     }
 }
+
+/// Writes the value in CIF syntax, so that parsing the output gives it back.
+///
+/// Numbers are written by [`format_numeric`] with [`FormatPolicy::exact`]:
+/// the fewest digits that round-trip, and an uncertainty in parentheses
+/// scaled to the last digits of the value with none of its digits dropped.
+/// Text keeps the delimiters it was read with when they can still hold it;
+/// otherwise it is written bare when that is unambiguous, then in single or
+/// double quotes, then as a text field. Table entries come out in document
+/// order.
+///
+/// # Examples
+///
+/// ```
+/// use cif_parser::{CifValue, ast::Span};
+///
+/// let su = CifValue::numeric_with_uncertainty(7.47, 0.006, Span::default());
+/// assert_eq!(su.to_string(), "7.470(6)");
+/// assert_eq!(CifValue::text("C1", Span::default()).to_string(), "C1");
+/// assert_eq!(CifValue::text("two words", Span::default()).to_string(), "'two words'");
+/// assert_eq!(CifValue::text("?", Span::default()).to_string(), "'?'");
+/// assert_eq!(CifValue::unknown(Span::default()).to_string(), "?");
+/// ```
+impl fmt::Display for CifValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            CifValueKind::Text(text) => write_text(f, text, self.quote),
+            CifValueKind::Numeric(value) => {
+                f.write_str(&format_numeric(*value, None, &FormatPolicy::exact()))
+            }
+            CifValueKind::NumericWithUncertainty { value, uncertainty } => f.write_str(
+                &format_numeric(*value, Some(*uncertainty), &FormatPolicy::exact()),
+            ),
+            CifValueKind::Unknown => f.write_str("?"),
+            CifValueKind::NotApplicable => f.write_str("."),
+            CifValueKind::List(items) => {
+                f.write_str("[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" ")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                f.write_str("]")
+            }
+            CifValueKind::Table(table) => {
+                f.write_str("{")?;
+                for (i, (key, value)) in in_document_order(table).into_iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" ")?;
+                    }
+                    write_quoted_key(f, key)?;
+                    write!(f, ":{value}")?;
+                }
+                f.write_str("}")
+            }
+        }
+    }
+}
+
+/// Write text with delimiters that parse back to the same string
+fn write_text(f: &mut fmt::Formatter, text: &str, quote: QuoteStyle) -> fmt::Result {
+    let fits_single = !text.contains(['\'', '\n', '\r']);
+    let fits_double = !text.contains(['"', '\n', '\r']);
+
+    match quote {
+        QuoteStyle::SingleQuote if fits_single => return write!(f, "'{text}'"),
+        QuoteStyle::DoubleQuote if fits_double => return write!(f, "\"{text}\""),
+        QuoteStyle::TextField => return write!(f, "\n;{text}\n;"),
+        _ => {}
+    }
+
+    if is_safe_bare(text) {
+        f.write_str(text)
+    } else if fits_single {
+        write!(f, "'{text}'")
+    } else if fits_double {
+        write!(f, "\"{text}\"")
+    } else {
+        write!(f, "\n;{text}\n;")
+    }
+}
+
+/// Table keys are always quoted
+fn write_quoted_key(f: &mut fmt::Formatter, key: &str) -> fmt::Result {
+    if key.contains('\'') {
+        write!(f, "\"{key}\"")
+    } else {
+        write!(f, "'{key}'")
+    }
+}
+
+/// Whether `text` reads back as the same text when written without quotes
+fn is_safe_bare(text: &str) -> bool {
+    const RESERVED: [&str; 5] = ["data_", "save_", "loop_", "global_", "stop_"];
+
+    let lower = text.to_ascii_lowercase();
+    !text.is_empty()
+        && text != "?"
+        && text != "."
+        && !text.starts_with(['_', '#', '$', '\'', '"', ';'])
+        && !text.contains(|c: char| c.is_whitespace() || matches!(c, '[' | ']' | '{' | '}'))
+        && !RESERVED.iter().any(|word| lower.starts_with(word))
+        && CifValue::parse_number(text).is_none()
+}
@@ -0,0 +1,341 @@
+//! Formatting of numbers and standard uncertainties as CIF writes them.
+//!
+//! Everything that turns a number back into CIF text goes through
+//! [`format_numeric`], so a value reads the same whether it comes from
+//! [`CifValue`]'s `Display`, [`Measurand::format_cif`](crate::ast::Measurand::format_cif)
+//! or a CSV export. A [`FormatPolicy`] chooses how the uncertainty is
+//! rounded and when to switch to exponent notation.
+//!
+//! With an uncertainty, the value is written to the decimal place of the
+//! uncertainty's last digit and the uncertainty follows in parentheses as a
+//! whole number of units in that place: 0.5 with an uncertainty of 0.0003 is
+//! `0.5000(3)`. Without one, the value has the fewest digits that read back
+//! as the same number, so there are no trailing zeros.
+//!
+//! ```
+//! use cif_parser::cif_num::{format_numeric, FormatPolicy, Notation};
+//!
+//! let iucr = FormatPolicy::new();
+//! assert_eq!(format_numeric(1.23456, Some(0.0123), &iucr), "1.235(12)");
+//! assert_eq!(format_numeric(1.23456, Some(0.0234), &iucr), "1.23(2)");
+//! assert_eq!(format_numeric(0.5, Some(0.0003), &iucr), "0.5000(3)");
+//!
+//! // Every written digit of the uncertainty is kept
+//! assert_eq!(format_numeric(1.234, Some(0.056), &FormatPolicy::exact()), "1.234(56)");
+//!
+//! let scientific = FormatPolicy::new().with_notation(Notation::Scientific);
+//! assert_eq!(format_numeric(12345.0, Some(23.0), &scientific), "1.234e4(2)");
+//! ```
+//!
+//! A number CIF cannot write (infinite or NaN) comes out as `?`.
+
+use crate::ast::CifValue;
+
+/// How [`format_numeric`] rounds a standard uncertainty
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SuRounding {
+    /// The IUCr convention: the uncertainty is rounded to one significant
+    /// digit, or to two when they come to 10 to 19, so it is written as 1
+    /// to 19 units in the last place of the value
+    #[default]
+    Iucr,
+    /// Keep the uncertainty as given, in the fewest decimal places (up to
+    /// 15) that hold it exactly, so `1.234(56)` is written back unchanged
+    Exact,
+}
+
+/// When [`format_numeric`] writes an exponent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Notation {
+    /// Plain digits for magnitudes within the policy's plain range, an
+    /// exponent outside it
+    #[default]
+    Auto,
+    /// Always plain digits, unless the uncertainty is too large to read
+    /// back written out in full (from about 10¹⁹)
+    Plain,
+    /// Always a mantissa from 1 to 10 and an exponent
+    Scientific,
+}
+
+/// How numbers are written; see [`format_numeric`].
+///
+/// The default follows the IUCr convention for uncertainties and writes
+/// magnitudes from 10⁻⁵ up to, but not including, 10¹⁵ in plain digits.
+///
+/// ```
+/// use cif_parser::cif_num::{format_numeric, FormatPolicy};
+///
+/// let policy = FormatPolicy::new();
+/// assert_eq!(format_numeric(0.00001, None, &policy), "0.00001");
+/// assert_eq!(format_numeric(0.0000099, None, &policy), "9.9e-6");
+///
+/// let wide = policy.with_plain_exponents(-8, 20);
+/// assert_eq!(format_numeric(0.0000099, None, &wide), "0.0000099");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatPolicy {
+    /// How the uncertainty is rounded
+    pub su_rounding: SuRounding,
+    /// When an exponent is written
+    pub notation: Notation,
+    /// Smallest power of ten written in plain digits under [`Notation::Auto`]
+    pub min_plain_exponent: i32,
+    /// Largest power of ten written in plain digits under [`Notation::Auto`]
+    pub max_plain_exponent: i32,
+}
+
+impl Default for FormatPolicy {
+    fn default() -> Self {
+        FormatPolicy {
+            su_rounding: SuRounding::Iucr,
+            notation: Notation::Auto,
+            min_plain_exponent: -5,
+            max_plain_exponent: 14,
+        }
+    }
+}
+
+impl FormatPolicy {
+    /// IUCr rounding of uncertainties, plain digits from 10⁻⁵ to below 10¹⁵
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The default policy, but keeping uncertainties exactly as given
+    ///
+    /// Used for values read from a file, so that writing them back does not
+    /// lose precision.
+    pub fn exact() -> Self {
+        Self::default().with_su_rounding(SuRounding::Exact)
+    }
+
+    /// Round uncertainties with `rounding`
+    pub fn with_su_rounding(mut self, rounding: SuRounding) -> Self {
+        self.su_rounding = rounding;
+        self
+    }
+
+    /// Choose when to write an exponent
+    pub fn with_notation(mut self, notation: Notation) -> Self {
+        self.notation = notation;
+        self
+    }
+
+    /// Under [`Notation::Auto`], write magnitudes from 10^`min` to below
+    /// 10^(`max` + 1) in plain digits
+    pub fn with_plain_exponents(mut self, min: i32, max: i32) -> Self {
+        self.min_plain_exponent = min;
+        self.max_plain_exponent = max;
+        self
+    }
+
+    /// Whether a number of this magnitude gets an exponent
+    fn scientific(&self, magnitude: f64) -> bool {
+        match self.notation {
+            Notation::Plain => false,
+            Notation::Scientific => true,
+            Notation::Auto => {
+                magnitude != 0.0 && {
+                    let exponent = decimal_exponent(magnitude);
+                    exponent < self.min_plain_exponent || exponent > self.max_plain_exponent
+                }
+            }
+        }
+    }
+}
+
+/// Write a number, and its standard uncertainty if it has one, as CIF text.
+///
+/// The uncertainty is rounded as the policy says and the value is rounded
+/// to the same decimal place; a value smaller than its uncertainty becomes
+/// zero or a few digits (`0.0(12)`). A negative, infinite or NaN
+/// uncertainty is ignored, and a zero one is written as `(0)` after the
+/// value's own digits.
+///
+/// # Examples
+///
+/// ```
+/// use cif_parser::cif_num::{format_numeric, FormatPolicy};
+///
+/// let policy = FormatPolicy::new();
+/// assert_eq!(format_numeric(7.47, Some(0.006), &policy), "7.470(6)");
+/// assert_eq!(format_numeric(-2.5, None, &policy), "-2.5");
+/// assert_eq!(format_numeric(12345.0, Some(230.0), &policy), "12300(200)");
+/// assert_eq!(format_numeric(1.5e20, None, &policy), "1.5e20");
+/// ```
+pub fn format_numeric(value: f64, su: Option<f64>, policy: &FormatPolicy) -> String {
+    if !value.is_finite() {
+        return "?".to_string();
+    }
+    let su = su.filter(|su| su.is_finite() && *su >= 0.0);
+    let magnitude = match su {
+        Some(su) if value == 0.0 => su,
+        _ => value.abs(),
+    };
+    let scientific = policy.scientific(magnitude);
+
+    let Some(su) = su else {
+        return shortest(value, scientific);
+    };
+    if su == 0.0 {
+        return format!("{}(0)", shortest(value, scientific));
+    }
+
+    let (place, digits) = match policy.su_rounding {
+        SuRounding::Iucr => iucr_digits(su),
+        SuRounding::Exact => exact_digits(value, su),
+    };
+    if !scientific {
+        if let Some(written) = plain_with_su(value, place, digits) {
+            return written;
+        }
+    }
+    scientific_with_su(value, place, digits)
+}
+
+/// Write a number as [`format_numeric`] does, unless `lexical` already
+/// writes it.
+///
+/// `lexical` is the number as it was read, such as `1.50(3)`. While it
+/// still reads back as exactly `value` and `su` it is kept as it is, with
+/// its own trailing zeros, exponent and uncertainty digits; once either has
+/// changed the number is formatted afresh.
+///
+/// # Examples
+///
+/// ```
+/// use cif_parser::cif_num::{format_numeric_preserving, FormatPolicy};
+///
+/// let policy = FormatPolicy::new();
+/// assert_eq!(format_numeric_preserving("1.50(3)", 1.5, Some(0.03), &policy), "1.50(3)");
+/// assert_eq!(format_numeric_preserving("1.50E0", 1.5, None, &policy), "1.50E0");
+/// assert_eq!(format_numeric_preserving("1.50(3)", 1.5, Some(0.04), &policy), "1.50(4)");
+/// ```
+pub fn format_numeric_preserving(
+    lexical: &str,
+    value: f64,
+    su: Option<f64>,
+    policy: &FormatPolicy,
+) -> String {
+    if CifValue::parse_number(lexical) == Some((value, su)) {
+        lexical.to_string()
+    } else {
+        format_numeric(value, su, policy)
+    }
+}
+
+/// The power of ten of a positive number's leading digit
+fn decimal_exponent(magnitude: f64) -> i32 {
+    let mut exponent = magnitude.log10().floor() as i32;
+    // log10 can land on the wrong side of an exact power of ten
+    if magnitude < 10f64.powi(exponent) {
+        exponent -= 1;
+    } else if magnitude >= 10f64.powi(exponent + 1) {
+        exponent += 1;
+    }
+    exponent
+}
+
+/// The value in the fewest digits that read back as it
+fn shortest(value: f64, scientific: bool) -> String {
+    if scientific {
+        format!("{value:e}")
+    } else {
+        format!("{value}")
+    }
+}
+
+/// The power of ten of the uncertainty's last digit, and its digits there,
+/// under the IUCr convention
+fn iucr_digits(su: f64) -> (i32, u64) {
+    let exponent = decimal_exponent(su);
+    let two = (su / 10f64.powi(exponent - 1)).round();
+    if two <= 19.0 {
+        return (exponent - 1, two as u64);
+    }
+    match (su / 10f64.powi(exponent)).round() {
+        // 0.097 rounds up to 0.10: two digits, 10, one place higher
+        one if one >= 10.0 => (exponent, 10),
+        one => (exponent, one as u64),
+    }
+}
+
+/// The power of ten of the uncertainty's last digit, and its digits there,
+/// keeping every digit of both the uncertainty and the value
+fn exact_digits(value: f64, su: f64) -> (i32, u64) {
+    const MAX_PLACES: i32 = 15;
+    // Most digits the uncertainty can be written with
+    const MAX_DIGITS: f64 = u64::MAX as f64;
+
+    let scaled = |places: i32| su * 10f64.powi(places);
+    let places = (0..=MAX_PLACES)
+        .find(|&places| is_whole(scaled(places)) && scaled(places) >= 0.5)
+        .or_else(|| (0..=MAX_PLACES).find(|&places| scaled(places) >= 1.0))
+        .unwrap_or(MAX_PLACES);
+    let (mut place, mut digits) = (-places, scaled(places).round());
+    // Trailing zeros of a large uncertainty move into the place while the
+    // value has no digits there either, so 1e18 is not written out digit
+    // by digit; all of them do when there are too many digits to write
+    let too_long = digits >= MAX_DIGITS;
+    while digits >= 10.0
+        && is_whole(digits / 10.0)
+        && (too_long || is_whole(value / 10f64.powi(place + 1)))
+    {
+        digits = (digits / 10.0).round();
+        place += 1;
+    }
+    (place, digits as u64)
+}
+
+/// Whether a scaled number is a whole number, allowing for rounding error
+fn is_whole(scaled: f64) -> bool {
+    (scaled - scaled.round()).abs() <= scaled.abs() * 1e-9
+}
+
+/// `value(su)` in plain digits, with the value rounded to `10^place`, or
+/// `None` if the uncertainty has too many digits to read back
+fn plain_with_su(value: f64, place: i32, digits: u64) -> Option<String> {
+    if place <= 0 {
+        let decimals = (-place) as usize;
+        let written = format!("{value:.decimals$}");
+        return Some(format!("{}({digits})", unsigned_if_zero(written)));
+    }
+    // The uncertainty reaches left of the decimal point: 12300(200)
+    let su = 10u64
+        .checked_pow(place as u32)
+        .and_then(|scale| digits.checked_mul(scale))?;
+    let scale = 10f64.powi(place);
+    let rounded = (value / scale).round() * scale;
+    let written = format!("{rounded:.0}");
+    Some(format!("{}({su})", unsigned_if_zero(written)))
+}
+
+/// `mantissa`e`exponent`(`su`), with the uncertainty in the mantissa's
+/// last digits
+fn scientific_with_su(value: f64, place: i32, digits: u64) -> String {
+    let mut exponent = if value == 0.0 {
+        place
+    } else {
+        decimal_exponent(value.abs()).max(place)
+    };
+    loop {
+        let decimals = (exponent - place) as usize;
+        let mantissa = value / 10f64.powi(exponent);
+        let written = format!("{mantissa:.decimals$}");
+        // Rounding 9.99 can carry into a tenth digit
+        if written.trim_start_matches('-').starts_with("10") {
+            exponent += 1;
+            continue;
+        }
+        return format!("{}e{exponent}({digits})", unsigned_if_zero(written));
+    }
+}
+
+/// Drop the sign of a number that rounded to zero, as in `-0.00`
+fn unsigned_if_zero(written: String) -> String {
+    match written.strip_prefix('-') {
+        Some(rest) if rest.bytes().all(|b| b == b'0' || b == b'.') => rest.to_string(),
+        _ => written,
+    }
+}
@@ -0,0 +1,329 @@
+//! Export of loops as CSV or TSV tables, and import back into loops.
+//!
+//! A loop becomes one header row of tags followed by one row per packet.
+//! Numbers, uncertainties, `?` and `.` are written as they would be in CIF
+//! (see the [`Display`](std::fmt::Display) impl of [`CifValue`] and
+//! [`cif_num`](crate::cif_num)); text is
+//! written as-is, because the table has its own quoting. Fields are escaped
+//! per RFC 4180: a field holding the delimiter, a double quote or a line
+//! break is wrapped in double quotes, with inner quotes doubled.
+//!
+//! ```
+//! use cif_parser::export::{category_to_csv, csv_to_loop, CsvOptions};
+//! use cif_parser::Document;
+//!
+//! let doc = Document::parse("data_x\nloop_\n_atom_site_label\n_atom_site_occupancy\nC1 0.5(1)\nN1 ?\n")?;
+//! let block = doc.first_block().unwrap();
+//!
+//! let csv = category_to_csv(block, "atom_site", &CsvOptions::new()).unwrap();
+//! assert_eq!(csv, "_atom_site_label,_atom_site_occupancy\r\nC1,0.5(1)\r\nN1,\r\n");
+//!
+//! let loop_ = csv_to_loop(&block.loops[0].tags, &csv)?;
+//! assert_eq!(loop_.get(0, 1).unwrap().as_numeric_with_uncertainty(), Some((0.5, 0.1)));
+//! assert!(loop_.get(1, 1).unwrap().is_unknown());
+//! # Ok::<(), cif_parser::CifError>(())
+//! ```
+
+use crate::ast::{CifBlock, CifLoop, CifValue, CifValueKind, Span};
+use crate::error::CifError;
+
+/// Options for writing loops as CSV or TSV.
+///
+/// Use the builder pattern to configure the output:
+///
+/// ```
+/// use cif_parser::export::CsvOptions;
+///
+/// let options = CsvOptions::tsv()
+///     .strip_category_prefix(true)
+///     .unknown_as_empty(false);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CsvOptions {
+    /// Field delimiter, `,` for CSV and a tab for TSV
+    pub delimiter: char,
+    /// Write `label` rather than `_atom_site_label` in the header row
+    pub strip_category_prefix: bool,
+    /// Write `?` as an empty cell rather than a literal `?`
+    pub unknown_as_empty: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            strip_category_prefix: false,
+            unknown_as_empty: true,
+        }
+    }
+}
+
+impl CsvOptions {
+    /// Comma-separated output, full tags, `?` as empty cells
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tab-separated output, otherwise as [`CsvOptions::new`]
+    pub fn tsv() -> Self {
+        Self::default().delimiter('\t')
+    }
+
+    /// Set the field delimiter
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Strip the category prefix shared by all tags from the header row
+    pub fn strip_category_prefix(mut self, enabled: bool) -> Self {
+        self.strip_category_prefix = enabled;
+        self
+    }
+
+    /// Write unknown values as empty cells (the default) or as `?`
+    pub fn unknown_as_empty(mut self, enabled: bool) -> Self {
+        self.unknown_as_empty = enabled;
+        self
+    }
+}
+
+/// Write the loop holding a category, or a given tag, as CSV.
+///
+/// `category_or_tag` is either a tag of the loop (`_atom_site_label`) or a
+/// category name (`atom_site`). A category matches the first loop whose tags
+/// all start with `_atom_site.` or, failing that, `_atom_site_`. The second
+/// form cannot tell `atom_site` from `atom_site_aniso`; resolve categories
+/// with a dictionary when that matters. Returns `None` when no loop matches.
+pub fn category_to_csv(
+    block: &CifBlock,
+    category_or_tag: &str,
+    options: &CsvOptions,
+) -> Option<String> {
+    find_category_loop(block, category_or_tag).map(|loop_| loop_to_csv(loop_, options))
+}
+
+/// Write the loop holding a category, or a given tag, as TSV.
+///
+/// As [`category_to_csv`], with a tab as the delimiter whatever `options` say.
+pub fn category_to_tsv(
+    block: &CifBlock,
+    category_or_tag: &str,
+    options: &CsvOptions,
+) -> Option<String> {
+    let options = options.clone().delimiter('\t');
+    category_to_csv(block, category_or_tag, &options)
+}
+
+/// Write a loop as CSV: a header row of tags, then one row per packet.
+///
+/// Rows end in CR LF, as RFC 4180 asks.
+pub fn loop_to_csv(loop_: &CifLoop, options: &CsvOptions) -> String {
+    let prefix_len = if options.strip_category_prefix {
+        category_prefix(&loop_.tags).len()
+    } else {
+        0
+    };
+
+    let mut out = String::new();
+    let header = loop_
+        .tags
+        .iter()
+        .map(|tag| tag.get(prefix_len..).unwrap_or(tag).to_string());
+    write_row(&mut out, header, options.delimiter);
+    for row in loop_.rows() {
+        let cells = row.iter().map(|value| cell_text(value, options));
+        write_row(&mut out, cells, options.delimiter);
+    }
+    out
+}
+
+/// Read CSV written by [`loop_to_csv`] back into a loop.
+///
+/// The header row is skipped, since it may have lost the category prefix;
+/// `tags` name the columns instead and must match it in number. Each cell
+/// is read as a CIF value would be: `?` and empty cells are unknown, `.` is
+/// not applicable, numbers with or without an uncertainty are numeric, and
+/// anything else is text.
+///
+/// # Errors
+///
+/// Returns [`CifError::InvalidStructure`] when a row has the wrong number
+/// of cells or a quoted field is not closed.
+pub fn csv_to_loop<S: AsRef<str>>(tags: &[S], csv: &str) -> Result<CifLoop, CifError> {
+    table_to_loop(tags, csv, ',')
+}
+
+/// Read TSV written by [`category_to_tsv`] back into a loop.
+///
+/// As [`csv_to_loop`], with a tab as the delimiter.
+pub fn tsv_to_loop<S: AsRef<str>>(tags: &[S], tsv: &str) -> Result<CifLoop, CifError> {
+    table_to_loop(tags, tsv, '\t')
+}
+
+/// The loop holding `category_or_tag`; see [`category_to_csv`]
+fn find_category_loop<'a>(block: &'a CifBlock, category_or_tag: &str) -> Option<&'a CifLoop> {
+    if let Some(loop_) = block.find_loop(category_or_tag) {
+        return Some(loop_);
+    }
+
+    let category = category_or_tag.trim_start_matches('_').to_lowercase();
+    let has_prefix = |loop_: &CifLoop, separator: char| {
+        let prefix = format!("_{category}{separator}");
+        !loop_.tags.is_empty()
+            && loop_
+                .tags
+                .iter()
+                .all(|tag| tag.to_lowercase().starts_with(&prefix))
+    };
+    block
+        .loops
+        .iter()
+        .find(|loop_| has_prefix(loop_, '.'))
+        .or_else(|| block.loops.iter().find(|loop_| has_prefix(loop_, '_')))
+}
+
+/// The longest prefix shared by all tags that ends in `.` or `_` and leaves
+/// every tag a non-empty name
+fn category_prefix(tags: &[String]) -> &str {
+    let Some(first) = tags.first() else {
+        return "";
+    };
+    let mut len = tags.iter().fold(first.len(), |len, tag| {
+        first
+            .bytes()
+            .zip(tag.bytes())
+            .take(len)
+            .take_while(|(a, b)| a.eq_ignore_ascii_case(b))
+            .count()
+    });
+    if tags.iter().any(|tag| tag.len() == len) {
+        len = len.saturating_sub(1);
+    }
+    match first.get(..len).and_then(|shared| shared.rfind(['.', '_'])) {
+        Some(0) | None => "",
+        Some(end) => &first[..=end],
+    }
+}
+
+/// The cell for one value: text as-is, everything else in CIF syntax
+fn cell_text(value: &CifValue, options: &CsvOptions) -> String {
+    match &value.kind {
+        CifValueKind::Text(text) => text.clone(),
+        CifValueKind::Unknown if options.unknown_as_empty => String::new(),
+        _ => value.to_string(),
+    }
+}
+
+/// Append one row, escaping fields per RFC 4180
+fn write_row(out: &mut String, cells: impl Iterator<Item = String>, delimiter: char) {
+    for (i, cell) in cells.enumerate() {
+        if i > 0 {
+            out.push(delimiter);
+        }
+        if cell.contains([delimiter, '"', '\n', '\r']) {
+            out.push('"');
+            out.push_str(&cell.replace('"', "\"\""));
+            out.push('"');
+        } else {
+            out.push_str(&cell);
+        }
+    }
+    out.push_str("\r\n");
+}
+
+fn table_to_loop<S: AsRef<str>>(
+    tags: &[S],
+    input: &str,
+    delimiter: char,
+) -> Result<CifLoop, CifError> {
+    let mut loop_ = CifLoop::new();
+    loop_.tags = tags.iter().map(|tag| tag.as_ref().to_string()).collect();
+
+    for (line, row) in read_rows(input, delimiter)?.into_iter().skip(1) {
+        if row.len() != tags.len() {
+            return Err(CifError::InvalidStructure {
+                message: format!(
+                    "row has {} cells but {} tags were given",
+                    row.len(),
+                    tags.len()
+                ),
+                location: Some((line, 1)),
+            });
+        }
+        loop_
+            .values
+            .push(row.iter().map(|cell| cell_value(cell)).collect());
+    }
+    Ok(loop_)
+}
+
+/// The value a cell holds
+fn cell_value(cell: &str) -> CifValue {
+    let span = Span::default();
+    match cell {
+        "" | "?" => CifValue::unknown(span),
+        "." => CifValue::not_applicable(span),
+        _ => match CifValue::parse_number(cell) {
+            Some((value, None)) => CifValue::numeric(value, span),
+            Some((value, Some(uncertainty))) => {
+                CifValue::numeric_with_uncertainty(value, uncertainty, span)
+            }
+            None => CifValue::text(cell, span),
+        },
+    }
+}
+
+/// Split RFC 4180 input into rows of fields, each with its 1-based line
+fn read_rows(input: &str, delimiter: char) -> Result<Vec<(usize, Vec<String>)>, CifError> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut line = 1;
+    let mut row_line = 1;
+    let mut in_quotes = false;
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                _ => {
+                    if c == '\n' {
+                        line += 1;
+                    }
+                    field.push(c);
+                }
+            }
+            continue;
+        }
+        match c {
+            '"' if field.is_empty() => in_quotes = true,
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' | '\r' => {
+                row.push(std::mem::take(&mut field));
+                rows.push((row_line, std::mem::take(&mut row)));
+                line += 1;
+                row_line = line;
+            }
+            _ if c == delimiter => row.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err(CifError::InvalidStructure {
+            message: "quoted field is not closed".to_string(),
+            location: Some((row_line, 1)),
+        });
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push((row_line, row));
+    }
+    Ok(rows)
+}
@@ -72,6 +72,9 @@
 //! - [`ast`] - Abstract Syntax Tree types (final, typed representation)
 //! - [`raw`] - Raw AST types and Pass 1 parsing (lossless, version-agnostic)
 //! - [`rules`] - Pass 2 resolution (version-specific: CIF 1.1 vs 2.0)
+//! - [`analysis`] - Inventory of version-sensitive constructs in a file
+//! - [`split`] - Splitting concatenated archives into entries
+//! - [`export`] - Loops as CSV or TSV tables, and back
 //! - [`error`] - Error types
 //!
 //! ## Examples
@@ -122,32 +125,56 @@
 //! assert_eq!(atom_type.as_string().unwrap(), "C");
 //! ```
 
-use pest_derive::Parser;
 use std::path::Path;
 
 // ===== Core Modules =====
 
+pub mod analysis;
 pub mod ast;
+pub mod cif_num;
 pub mod error;
+pub mod export;
+pub mod prelude;
 pub mod raw;
 pub mod rules;
+pub mod split;
 
 // ===== PEST Parser =====
 
-#[derive(Parser)]
-#[grammar = "grammar/cif.pest"]
-pub struct CIFParser;
+/// Grammar-level items for grammar tests and low-level tooling.
+///
+/// Nothing in this module is covered by semver: rule names follow the grammar
+/// file and change whenever it does. Use [`prelude`] or the crate root instead.
+#[doc(hidden)]
+pub mod internal {
+    use pest_derive::Parser;
+
+    /// PEST parser generated from `grammar/cif.pest`
+    #[derive(Parser)]
+    #[grammar = "grammar/cif.pest"]
+    pub struct CIFParser;
+
+    pub use pest::iterators::Pair;
+}
+
+pub(crate) use internal::{CIFParser, Rule};
 
 // ===== Re-exports =====
 
 // AST types
-pub use ast::{CifBlock, CifDocument, CifFrame, CifLoop, CifValue, CifValueKind, CifVersion, Span};
+pub use ast::{
+    Anomaly, AnomalyReason, BlockLink, BlockLinkTags, BlockSummary, CaseInsensitiveName,
+    ChangedBlocks, CifBlock, CifDocument, CifFrame, CifLoop, CifValue, CifValueKind, CifVersion,
+    ColumnStats, MagicHeader, Measurand, PathSegment, PlausibleRange, QuoteStyle, RefusedRename,
+    RenameConflict, RenameOutcome, ScreeningOptions, Span, TagLocation, TagRename, TextEdit,
+    ValuePath, ValuePathError, DOCUMENT_FORMAT_VERSION,
+};
 
 // Error types
 pub use error::CifError;
 
 // Rules and violations
-pub use rules::{Cif1Rules, Cif2Rules, VersionRules, VersionViolation};
+pub use rules::{Cif1Rules, Cif2Rules, ParseNote, VersionRules, VersionViolation};
 
 // Convenient type aliases (matching old API)
 pub use CifBlock as Block;
@@ -168,12 +195,15 @@ pub use CifVersion as Version;
 /// use cif_parser::ParseOptions;
 ///
 /// let options = ParseOptions::new()
-///     .upgrade_guidance(true);
+///     .upgrade_guidance(true)
+///     .skip_leading_garbage(true);
 /// ```
 #[derive(Debug, Clone, Default)]
 pub struct ParseOptions {
     /// Collect upgrade guidance (what would make CIF 1.1 valid CIF 2.0)
     pub upgrade_guidance: bool,
+    /// Skip prose, mail headers or markup before the first data block
+    pub skip_leading_garbage: bool,
 }
 
 impl ParseOptions {
@@ -198,12 +228,38 @@ impl ParseOptions {
         self.upgrade_guidance = enabled;
         self
     }
+
+    /// Enable or disable skipping of non-CIF text before the first data block.
+    ///
+    /// CIFs pulled from supplementary material or old email submissions can
+    /// start with prose or mail headers. When enabled, everything above the
+    /// first line starting with `data_` (or the `#\#CIF_2.0` header) is
+    /// ignored if it contains anything besides blank lines and comments, and
+    /// a [`rules::note_ids::LEADING_CONTENT_SKIPPED`] note records what was skipped.
+    /// Spans in the document still refer to lines of the original input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cif_parser::{parse_string_with_options, ParseOptions};
+    ///
+    /// let input = "Dear editor,\nplease find our structure below.\ndata_x\n_a 1\n";
+    /// let result = parse_string_with_options(input, ParseOptions::new().skip_leading_garbage(true))?;
+    /// assert_eq!(result.document.blocks[0].name, "x");
+    /// assert_eq!(result.notes[0].span.end_line, 2);
+    /// # Ok::<(), cif_parser::CifError>(())
+    /// ```
+    pub fn skip_leading_garbage(mut self, enabled: bool) -> Self {
+        self.skip_leading_garbage = enabled;
+        self
+    }
 }
 
 /// Result of parsing with options.
 ///
-/// Contains both the parsed document and any upgrade issues found
-/// (if `upgrade_guidance` was enabled).
+/// Contains the parsed document, any upgrade issues found (if
+/// `upgrade_guidance` was enabled), and notes about values that resolved
+/// with caveats.
 #[derive(Debug)]
 pub struct ParseResult {
     /// The parsed CIF document
@@ -213,6 +269,9 @@ pub struct ParseResult {
     ///
     /// Each issue describes what would need to change to make the file valid CIF 2.0.
     pub upgrade_issues: Vec<VersionViolation>,
+
+    /// Informational notes, such as numbers that lost precision as `f64`
+    pub notes: Vec<ParseNote>,
 }
 
 impl ParseResult {
@@ -221,13 +280,25 @@ impl ParseResult {
         Self {
             document,
             upgrade_issues,
+            notes: Vec::new(),
         }
     }
 
+    /// Attach informational notes to this result.
+    pub fn with_notes(mut self, notes: Vec<ParseNote>) -> Self {
+        self.notes = notes;
+        self
+    }
+
     /// Check if the document has any upgrade issues.
     pub fn has_upgrade_issues(&self) -> bool {
         !self.upgrade_issues.is_empty()
     }
+
+    /// Check if any informational notes were recorded.
+    pub fn has_notes(&self) -> bool {
+        !self.notes.is_empty()
+    }
 }
 
 // ===== Public Convenience Functions =====
@@ -278,21 +349,59 @@ pub fn parse_string_with_options(
     input: &str,
     options: ParseOptions,
 ) -> Result<ParseResult, CifError> {
+    parse_with_default_version(input, options, CifVersion::V1_1)
+}
+
+/// Parse as [`parse_string_with_options`] does, reading input without a
+/// `#\#CIF_2.0` header as `default` rather than CIF 1.1.
+///
+/// For text cut from a larger file whose header applies to it, such as an
+/// archive entry after the first.
+pub(crate) fn parse_with_default_version(
+    input: &str,
+    options: ParseOptions,
+    default: CifVersion,
+) -> Result<ParseResult, CifError> {
+    // Blank out non-CIF text above the first block, keeping line numbers
+    let garbage = if options.skip_leading_garbage {
+        raw::parser::find_leading_garbage(input)
+    } else {
+        None
+    };
+    let blanked;
+    let input = match &garbage {
+        Some(garbage) => {
+            blanked = raw::parser::blank_out(input, garbage.bytes.clone());
+            blanked.as_str()
+        }
+        None => input,
+    };
+
     // Pass 1: Parse to raw AST (version-agnostic)
-    let raw_doc = raw::parser::parse_raw(input)?;
+    let mut raw_doc = raw::parser::parse_raw(input)?;
+
+    // A header directly below the skipped text starts what is left of the file
+    if let (Some(garbage), Some(header)) = (&garbage, &mut raw_doc.magic_header) {
+        if header.line == garbage.lines + 1 {
+            header.misplaced = false;
+        }
+    }
 
-    // Detect version from magic comment (stored in raw_doc)
-    let version = if raw_doc.has_cif2_magic {
+    // Detect version from magic comment (stored in raw_doc). A header the
+    // author misplaced still selects CIF 2.0 so the rules can report it.
+    let version = if raw_doc.magic_header.is_some() {
         CifVersion::V2_0
     } else {
-        CifVersion::V1_1
+        default
     };
 
     // Pass 2: Resolve with version rules
     let document = match version {
-        CifVersion::V1_1 => Cif1Rules.resolve(&raw_doc).map_err(violation_to_error)?,
-        CifVersion::V2_0 => Cif2Rules.resolve(&raw_doc).map_err(violation_to_error)?,
-    };
+        CifVersion::V1_1 => Cif1Rules.resolve(&raw_doc),
+        CifVersion::V2_0 if raw_doc.magic_header.is_none() => Cif2Rules.resolve_document(&raw_doc),
+        CifVersion::V2_0 => Cif2Rules.resolve(&raw_doc),
+    }
+    .map_err(violation_to_error)?;
 
     // Collect upgrade issues if requested AND file is CIF 1.1
     let upgrade_issues = if options.upgrade_guidance && version == CifVersion::V1_1 {
@@ -301,7 +410,23 @@ pub fn parse_string_with_options(
         vec![]
     };
 
-    Ok(ParseResult::new(document, upgrade_issues))
+    // Note skipped text, then values whose typed form lost information
+    let mut notes: Vec<ParseNote> = garbage
+        .map(|garbage| {
+            ParseNote::new(
+                garbage.span,
+                format!(
+                    "Skipped {} line(s) (bytes {}..{}) before the first data block",
+                    garbage.lines, garbage.bytes.start, garbage.bytes.end
+                ),
+                rules::note_ids::LEADING_CONTENT_SKIPPED,
+            )
+        })
+        .into_iter()
+        .collect();
+    notes.extend(rules::collect_notes(&raw_doc, version == CifVersion::V2_0));
+
+    Ok(ParseResult::new(document, upgrade_issues).with_notes(notes))
 }
 
 /// Convert a VersionViolation to CifError.
@@ -320,10 +445,6 @@ fn violation_to_error(violation: VersionViolation) -> CifError {
     }
 }
 
-// ===== Re-export for internal use =====
-pub use pest::iterators::Pair;
-// Rule enum is automatically public via the #[derive(Parser)] macro
-
 // ===== Conditional Compilation Modules =====
 
 // WASM bindings module (conditionally compiled)
@@ -0,0 +1,32 @@
+//! The stable, intended-for-users surface of the parser.
+//!
+//! ```
+//! use cif_parser::prelude::*;
+//!
+//! let doc = parse_string("data_test\n_cell_length_a 10.5(2)\n")?;
+//! let block: &CifBlock = doc.first_block().unwrap();
+//! assert!(matches!(block.get_item("_cell_length_a").unwrap().kind, CifValueKind::NumericWithUncertainty { .. }));
+//! # Ok::<(), CifError>(())
+//! ```
+//!
+//! Everything here follows semver. The short aliases at the crate root
+//! (`Block`, `Document`, ...) are kept for compatibility but are not part of
+//! the prelude, so a glob import never brings in two names for one type.
+//!
+//! # Stability guarantees
+//!
+//! [`CifValueKind`] is `#[non_exhaustive]`, so matches need a wildcard arm.
+//! [`VersionRules`](crate::VersionRules) is sealed and cannot be implemented
+//! downstream, and the PEST grammar types are no longer exported from the
+//! crate root. The compile-fail cases in `tests/ui` hold the crate to each of
+//! these.
+
+pub use crate::ast::{
+    Anomaly, AnomalyReason, BlockLink, BlockLinkTags, BlockSummary, CaseInsensitiveName, CifBlock,
+    CifDocument, CifFrame, CifLoop, CifValue, CifValueKind, CifVersion, ColumnStats, MagicHeader,
+    Measurand, PathSegment, PlausibleRange, QuoteStyle, ScreeningOptions, Span, ValuePath,
+    ValuePathError,
+};
+pub use crate::error::CifError;
+pub use crate::rules::{ParseNote, VersionViolation};
+pub use crate::{parse_file, parse_string, parse_string_with_options, ParseOptions, ParseResult};
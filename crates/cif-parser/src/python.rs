@@ -2,15 +2,25 @@
 //!
 //! This module provides Python-native wrappers around the core CIF parsing
 //! functionality, following Python naming conventions and idioms.
-
+//!
+//! A parsed document is held once behind an `Arc`. `Block`, `Frame` and `Loop`
+//! objects are lightweight views that store the shared document and indices
+//! into it, so handing them out never copies the document. Mutation, once
+//! supported, will copy the document on write so existing views keep seeing
+//! the document they were taken from.
+
+use crate::analysis::{FeatureReport, FeatureUsage};
+use crate::ast::order::in_document_order;
+use crate::export::{self, CsvOptions};
 use crate::{
-    ast::Span, CifBlock, CifDocument, CifError, CifFrame, CifLoop, CifValue, CifValueKind,
-    CifVersion,
+    ast::Span, parse_string_with_options, Anomaly, AnomalyReason, BlockSummary, CifBlock,
+    CifDocument, CifError, CifFrame, CifLoop, CifValue, CifValueKind, CifVersion, ColumnStats,
+    Measurand, ParseNote, ParseOptions, ParseResult, ScreeningOptions, ValuePath,
 };
 use pyo3::exceptions::{PyIOError, PyIndexError, PyKeyError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::PyString;
-use std::collections::HashMap;
+use pyo3::types::{PyDict, PyString};
+use std::sync::Arc;
 
 /// Convert a Rust CifError to a Python exception
 fn cif_error_to_py_err(err: CifError) -> PyErr {
@@ -30,7 +40,7 @@ fn cif_error_to_py_err(err: CifError) -> PyErr {
     }
 }
 
-/// Python wrapper for CifVersion enum
+/// CIF specification version of a document
 #[pyclass(name = "Version", eq, eq_int)]
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum PyVersion {
@@ -42,6 +52,7 @@ pub enum PyVersion {
 
 #[pymethods]
 impl PyVersion {
+    /// Variant name, "V1_1" or "V2_0"
     #[getter]
     fn name(&self) -> &'static str {
         match self {
@@ -91,10 +102,10 @@ impl From<CifVersion> for PyVersion {
     }
 }
 
-/// Python wrapper for source location (Span)
+/// Source location of a value in the CIF file
 ///
-/// Tracks where a value appears in the source CIF file.
-/// Useful for LSP/IDE features, error reporting, and highlighting.
+/// Line and column numbers are 1-indexed. Useful for LSP/IDE features,
+/// error reporting, and highlighting.
 #[pyclass(name = "Span")]
 #[derive(Clone, Copy)]
 pub struct PySpan {
@@ -128,6 +139,11 @@ impl PySpan {
     }
 
     /// Check if a line and column position is within this span
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line number (1-indexed)
+    /// * `col` - Column number (1-indexed)
     fn contains(&self, line: usize, col: usize) -> bool {
         self.inner.contains(line, col)
     }
@@ -165,7 +181,11 @@ impl From<Span> for PySpan {
     }
 }
 
-/// Python wrapper for CifValue with Pythonic interface
+/// A single CIF value with its type and source location
+///
+/// Values can be text, numbers (with or without an uncertainty), the
+/// special values unknown (`?`) and not applicable (`.`), or CIF 2.0 lists
+/// and tables. Use the `is_*` properties to find out which.
 #[pyclass(name = "Value")]
 #[derive(Clone)]
 pub struct PyValue {
@@ -232,7 +252,8 @@ impl PyValue {
     }
 
     /// Get the value as a number (returns None if not numeric)
-    /// For values with uncertainty, returns just the value (use uncertainty property for the uncertainty)
+    ///
+    /// For values with uncertainty, returns just the value; see `uncertainty`.
     #[getter]
     fn numeric(&self) -> Option<f64> {
         self.inner.as_numeric()
@@ -245,6 +266,9 @@ impl PyValue {
     }
 
     /// Get the value type as a string
+    ///
+    /// One of "text", "numeric", "numeric_with_uncertainty", "unknown",
+    /// "not_applicable", "list" or "table".
     #[getter]
     fn value_type(&self) -> String {
         match &self.inner.kind {
@@ -258,8 +282,37 @@ impl PyValue {
         }
     }
 
+    /// Visit this value and every value nested in its lists and tables
+    ///
+    /// Returns (path, value) pairs depth-first, starting with this value at
+    /// the empty path. Paths use the form `[2].file`; table entries are
+    /// visited in document order.
+    fn walk(&self) -> Vec<(String, PyValue)> {
+        self.inner
+            .walk()
+            .map(|(path, value)| (path.to_string(), PyValue::from(value.clone())))
+            .collect()
+    }
+
+    /// Get a nested value by path string such as `[2].file`
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Indices and keys from this value down, such as `[2].file`
+    ///
+    /// # Returns
+    ///
+    /// The nested value, or None if the path does not lead to one. Raises
+    /// ValueError for a malformed path.
+    fn get_path(&self, path: &str) -> PyResult<Option<PyValue>> {
+        let path = ValuePath::parse(path).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok(self.inner.get_path(&path).cloned().map(PyValue::from))
+    }
+
     /// Convert to Python native type
-    /// For NumericWithUncertainty, returns just the numeric value (use uncertainty property for the uncertainty)
+    ///
+    /// Text becomes str, numbers float (dropping any uncertainty), unknown
+    /// and not applicable None, lists list and tables dict.
     fn to_python(&self, py: Python) -> PyResult<Py<PyAny>> {
         match &self.inner.kind {
             CifValueKind::Text(s) => Ok(PyString::new(py, s).into_any().unbind()),
@@ -278,12 +331,12 @@ impl PyValue {
                 Ok(py_list.into_pyobject(py)?.into_any().unbind())
             }
             CifValueKind::Table(map) => {
-                // Convert HashMap to Python dict
-                let py_dict: HashMap<String, Py<PyAny>> = map
-                    .iter()
-                    .map(|(k, v)| Ok((k.clone(), PyValue::from(v.clone()).to_python(py)?)))
-                    .collect::<PyResult<HashMap<_, _>>>()?;
-                Ok(py_dict.into_pyobject(py)?.into_any().unbind())
+                // Convert to a Python dict with entries in document order
+                let py_dict = PyDict::new(py);
+                for (key, value) in in_document_order(map) {
+                    py_dict.set_item(key, PyValue::from(value.clone()).to_python(py)?)?;
+                }
+                Ok(py_dict.into_any().unbind())
             }
         }
     }
@@ -332,11 +385,42 @@ impl From<CifValue> for PyValue {
     }
 }
 
-/// Python wrapper for CifLoop with Pythonic interface
+/// Index of an element, found by reference, within its slice
+fn position_of<T>(items: &[T], item: &T) -> Option<usize> {
+    items
+        .iter()
+        .position(|candidate| std::ptr::eq(candidate, item))
+}
+
+/// Where a loop sits inside its document
+#[derive(Clone, Copy)]
+enum LoopOwner {
+    /// Loop directly in the block at this index
+    Block(usize),
+    /// Loop in a save frame: block index, frame index
+    Frame(usize, usize),
+}
+
+/// A loop of tagged columns (tabular data) in a block or save frame
+///
+/// Iterating over a loop yields each row as a dictionary from tag to value.
 #[pyclass(name = "Loop")]
 #[derive(Clone)]
 pub struct PyLoop {
-    inner: CifLoop,
+    doc: Arc<CifDocument>,
+    owner: LoopOwner,
+    index: usize,
+}
+
+impl PyLoop {
+    /// The loop this view refers to
+    fn inner(&self) -> &CifLoop {
+        let loops = match self.owner {
+            LoopOwner::Block(block) => &self.doc.blocks[block].loops,
+            LoopOwner::Frame(block, frame) => &self.doc.blocks[block].frames[frame].loops,
+        };
+        &loops[self.index]
+    }
 }
 
 #[pymethods]
@@ -344,64 +428,138 @@ impl PyLoop {
     /// Get the column tags (headers)
     #[getter]
     fn tags(&self) -> Vec<String> {
-        self.inner.tags.clone()
+        self.inner().tags.clone()
     }
 
     /// Get the number of rows
     fn __len__(&self) -> usize {
-        self.inner.len()
+        self.inner().len()
     }
 
     /// Get the number of columns
     #[getter]
     fn num_columns(&self) -> usize {
-        self.inner.tags.len()
+        self.inner().tags.len()
     }
 
     /// Check if the loop is empty
     fn is_empty(&self) -> bool {
-        self.inner.is_empty()
+        self.inner().is_empty()
     }
 
     /// Get a value by row and column index
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - Row index (0-based)
+    /// * `col` - Column index (0-based)
+    ///
+    /// # Returns
+    ///
+    /// The value, or None if either index is out of range.
     fn get(&self, row: usize, col: usize) -> Option<PyValue> {
-        self.inner.get(row, col).map(|v| v.clone().into())
+        self.inner().get(row, col).map(|v| v.clone().into())
     }
 
     /// Get a value by row index and tag name
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - Row index (0-based)
+    /// * `tag` - Column tag, such as `_atom_site_label`
+    ///
+    /// # Returns
+    ///
+    /// The value, or None if the row or tag does not exist.
     fn get_by_tag(&self, row: usize, tag: &str) -> Option<PyValue> {
-        self.inner.get_by_tag(row, tag).map(|v| v.clone().into())
+        self.inner().get_by_tag(row, tag).map(|v| v.clone().into())
     }
 
     /// Get all values for a specific tag as a list
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - Column tag
+    ///
+    /// # Returns
+    ///
+    /// The column's values in row order, or None if the loop has no such tag.
     fn get_column(&self, tag: &str) -> Option<Vec<PyValue>> {
-        self.inner
+        self.inner()
             .get_column(tag)
             .map(|values| values.iter().map(|v| (*v).clone().into()).collect())
     }
 
-    /// Iterate over rows
+    /// Get statistics of a numeric column
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - Column tag, compared ignoring case
+    ///
+    /// # Returns
+    ///
+    /// The column's statistics, or None if the loop has no such tag.
+    fn column_stats(&self, tag: &str) -> Option<PyColumnStats> {
+        self.inner().column_stats(tag).map(Into::into)
+    }
+
+    /// Get all rows as lists of values
     fn rows(&self) -> Vec<Vec<PyValue>> {
-        self.inner
+        self.inner()
             .values
             .iter()
             .map(|row| row.iter().map(|v| v.clone().into()).collect())
             .collect()
     }
 
-    /// Get a row as a dictionary mapping tags to values
-    fn get_row_dict(&self, row: usize) -> Option<HashMap<String, PyValue>> {
-        if row >= self.inner.len() {
-            return None;
+    /// Get a row as a dictionary mapping tags to values, in column order
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - Row index (0-based)
+    ///
+    /// # Returns
+    ///
+    /// The row, or None if the index is out of range.
+    fn get_row_dict<'py>(
+        &self,
+        py: Python<'py>,
+        row: usize,
+    ) -> PyResult<Option<Bound<'py, PyDict>>> {
+        if row >= self.inner().len() {
+            return Ok(None);
         }
 
-        let mut result = HashMap::new();
-        for (col, tag) in self.inner.tags.iter().enumerate() {
-            if let Some(value) = self.inner.get(row, col) {
-                result.insert(tag.clone(), value.clone().into());
+        let result = PyDict::new(py);
+        for (col, tag) in self.inner().tags.iter().enumerate() {
+            if let Some(value) = self.inner().get(row, col) {
+                result.set_item(tag, PyValue::from(value.clone()))?;
             }
         }
-        Some(result)
+        Ok(Some(result))
+    }
+
+    /// Write the loop as CSV (or TSV with `delimiter="\t"`)
+    ///
+    /// The table has a header row of tags, then one row per packet, with
+    /// rows ending in CR LF. Numbers keep their uncertainties, e.g.
+    /// "0.1234(5)".
+    ///
+    /// # Arguments
+    ///
+    /// * `delimiter` - Field delimiter
+    /// * `strip_category_prefix` - Write "label" rather than "_atom_site_label"
+    ///   in the header
+    /// * `unknown_as_empty` - Write unknown values (`?`) as empty cells
+    #[pyo3(signature = (*, delimiter = ',', strip_category_prefix = false, unknown_as_empty = true))]
+    fn to_csv(
+        &self,
+        delimiter: char,
+        strip_category_prefix: bool,
+        unknown_as_empty: bool,
+    ) -> String {
+        let options = csv_options(delimiter, strip_category_prefix, unknown_as_empty);
+        export::loop_to_csv(self.inner(), &options)
     }
 
     /// Python iterator protocol
@@ -421,8 +579,8 @@ impl PyLoop {
     fn __str__(&self) -> String {
         format!(
             "Loop({} columns, {} rows)",
-            self.inner.tags.len(),
-            self.inner.len()
+            self.inner().tags.len(),
+            self.inner().len()
         )
     }
 
@@ -430,19 +588,13 @@ impl PyLoop {
     fn __repr__(&self) -> String {
         format!(
             "Loop(tags={:?}, rows={})",
-            self.inner.tags,
-            self.inner.len()
+            self.inner().tags,
+            self.inner().len()
         )
     }
 }
 
-impl From<CifLoop> for PyLoop {
-    fn from(loop_: CifLoop) -> Self {
-        PyLoop { inner: loop_ }
-    }
-}
-
-/// Iterator for PyLoop that yields row dictionaries
+/// Iterator over the rows of a Loop, as dictionaries from tag to value
 #[pyclass]
 struct PyLoopIterator {
     loop_: Py<PyLoop>,
@@ -455,15 +607,15 @@ impl PyLoopIterator {
         slf
     }
 
-    fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<HashMap<String, PyValue>> {
+    fn __next__<'py>(mut slf: PyRefMut<'py, Self>) -> PyResult<Option<Bound<'py, PyDict>>> {
         let py = slf.py();
         let current_index = slf.index;
 
         // Get the row dict in a scope so the borrow is dropped before we mutate slf.index
         let result = {
             let loop_ = slf.loop_.borrow(py);
-            if current_index < loop_.inner.len() {
-                loop_.get_row_dict(current_index)
+            if current_index < loop_.inner().len() {
+                loop_.get_row_dict(py, current_index)?
             } else {
                 None
             }
@@ -474,15 +626,32 @@ impl PyLoopIterator {
             slf.index += 1;
         }
 
-        result
+        Ok(result)
     }
 }
 
-/// Python wrapper for CifFrame
+/// A save frame within a data block
 #[pyclass(name = "Frame")]
 #[derive(Clone)]
 pub struct PyFrame {
-    inner: CifFrame,
+    doc: Arc<CifDocument>,
+    block: usize,
+    index: usize,
+}
+
+impl PyFrame {
+    /// The frame this view refers to
+    fn inner(&self) -> &CifFrame {
+        &self.doc.blocks[self.block].frames[self.index]
+    }
+
+    fn loop_view(&self, index: usize) -> PyLoop {
+        PyLoop {
+            doc: Arc::clone(&self.doc),
+            owner: LoopOwner::Frame(self.block, self.index),
+            index,
+        }
+    }
 }
 
 #[pymethods]
@@ -490,53 +659,74 @@ impl PyFrame {
     /// Get the frame name
     #[getter]
     fn name(&self) -> String {
-        self.inner.name.clone()
+        self.inner().name.to_string()
     }
 
-    /// Get all item keys
+    /// Get all item keys, in document order
     #[getter]
     fn item_keys(&self) -> Vec<String> {
-        self.inner.items.keys().cloned().collect()
+        self.inner()
+            .items_iter()
+            .map(|(key, _)| key.clone())
+            .collect()
     }
 
     /// Get an item by key
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Tag name, such as `_cell_length_a`
+    ///
+    /// # Returns
+    ///
+    /// The item's value, or None if there is no such item.
     fn get_item(&self, key: &str) -> Option<PyValue> {
-        self.inner.items.get(key).map(|v| v.clone().into())
+        self.inner().items.get(key).map(|v| v.clone().into())
     }
 
-    /// Get all items as a dictionary
-    fn items(&self) -> HashMap<String, PyValue> {
-        self.inner
-            .items
-            .iter()
-            .map(|(k, v)| (k.clone(), v.clone().into()))
-            .collect()
+    /// Get all items as a dictionary, in document order
+    fn items<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        for (key, value) in self.inner().items_iter() {
+            dict.set_item(key, PyValue::from(value.clone()))?;
+        }
+        Ok(dict)
     }
 
     /// Get the number of loops
     #[getter]
     fn num_loops(&self) -> usize {
-        self.inner.loops.len()
+        self.inner().loops.len()
     }
 
     /// Get a loop by index
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Loop index (0-based)
+    ///
+    /// # Returns
+    ///
+    /// The loop, or None if the index is out of range.
     fn get_loop(&self, index: usize) -> Option<PyLoop> {
-        self.inner.loops.get(index).map(|l| l.clone().into())
+        (index < self.inner().loops.len()).then(|| self.loop_view(index))
     }
 
     /// Get all loops
     #[getter]
     fn loops(&self) -> Vec<PyLoop> {
-        self.inner.loops.iter().map(|l| l.clone().into()).collect()
+        (0..self.inner().loops.len())
+            .map(|index| self.loop_view(index))
+            .collect()
     }
 
     /// String representation
     fn __str__(&self) -> String {
         format!(
             "Frame('{}', {} items, {} loops)",
-            self.inner.name,
-            self.inner.items.len(),
-            self.inner.loops.len()
+            self.inner().name,
+            self.inner().items.len(),
+            self.inner().loops.len()
         )
     }
 
@@ -544,24 +734,49 @@ impl PyFrame {
     fn __repr__(&self) -> String {
         format!(
             "Frame(name='{}', items={}, loops={})",
-            self.inner.name,
-            self.inner.items.len(),
-            self.inner.loops.len()
+            self.inner().name,
+            self.inner().items.len(),
+            self.inner().loops.len()
         )
     }
 }
 
-impl From<CifFrame> for PyFrame {
-    fn from(frame: CifFrame) -> Self {
-        PyFrame { inner: frame }
-    }
-}
-
-/// Python wrapper for CifBlock with Pythonic interface
+/// A data block holding items, loops and save frames
 #[pyclass(name = "Block")]
 #[derive(Clone)]
 pub struct PyBlock {
-    inner: CifBlock,
+    doc: Arc<CifDocument>,
+    index: usize,
+}
+
+impl PyBlock {
+    fn new(doc: &Arc<CifDocument>, index: usize) -> Self {
+        PyBlock {
+            doc: Arc::clone(doc),
+            index,
+        }
+    }
+
+    /// The block this view refers to
+    fn inner(&self) -> &CifBlock {
+        &self.doc.blocks[self.index]
+    }
+
+    fn loop_view(&self, index: usize) -> PyLoop {
+        PyLoop {
+            doc: Arc::clone(&self.doc),
+            owner: LoopOwner::Block(self.index),
+            index,
+        }
+    }
+
+    fn frame_view(&self, index: usize) -> PyFrame {
+        PyFrame {
+            doc: Arc::clone(&self.doc),
+            block: self.index,
+            index,
+        }
+    }
 }
 
 #[pymethods]
@@ -569,81 +784,196 @@ impl PyBlock {
     /// Get the block name
     #[getter]
     fn name(&self) -> String {
-        self.inner.name.clone()
+        self.inner().name.to_string()
     }
 
-    /// Get all item keys
+    /// Get all item keys, in document order
     #[getter]
     fn item_keys(&self) -> Vec<String> {
-        self.inner.items.keys().cloned().collect()
+        self.inner()
+            .items_iter()
+            .map(|(key, _)| key.clone())
+            .collect()
     }
 
     /// Get an item by key
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Tag name, such as `_cell_length_a`
+    ///
+    /// # Returns
+    ///
+    /// The item's value, or None if there is no such item.
     fn get_item(&self, key: &str) -> Option<PyValue> {
-        self.inner.items.get(key).map(|v| v.clone().into())
+        self.inner().items.get(key).map(|v| v.clone().into())
     }
 
-    /// Get all items as a dictionary
-    fn items(&self) -> HashMap<String, PyValue> {
-        self.inner
-            .items
-            .iter()
-            .map(|(k, v)| (k.clone(), v.clone().into()))
-            .collect()
+    /// Get all items as a dictionary, in document order
+    fn items<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        for (key, value) in self.inner().items_iter() {
+            dict.set_item(key, PyValue::from(value.clone()))?;
+        }
+        Ok(dict)
     }
 
     /// Get the number of loops
     #[getter]
     fn num_loops(&self) -> usize {
-        self.inner.loops.len()
+        self.inner().loops.len()
     }
 
     /// Get a loop by index
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Loop index (0-based)
+    ///
+    /// # Returns
+    ///
+    /// The loop, or None if the index is out of range.
     fn get_loop(&self, index: usize) -> Option<PyLoop> {
-        self.inner.loops.get(index).map(|l| l.clone().into())
+        (index < self.inner().loops.len()).then(|| self.loop_view(index))
     }
 
     /// Find a loop containing a specific tag
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - Tag to look for
+    ///
+    /// # Returns
+    ///
+    /// The first loop with that tag, or None if no loop has it.
     fn find_loop(&self, tag: &str) -> Option<PyLoop> {
-        self.inner.find_loop(tag).map(|l| l.clone().into())
+        let block = self.inner();
+        block
+            .find_loop(tag)
+            .and_then(|found| position_of(&block.loops, found))
+            .map(|index| self.loop_view(index))
     }
 
     /// Get all loops
     #[getter]
     fn loops(&self) -> Vec<PyLoop> {
-        self.inner.loops.iter().map(|l| l.clone().into()).collect()
+        (0..self.inner().loops.len())
+            .map(|index| self.loop_view(index))
+            .collect()
     }
 
-    /// Get all loop tags
+    /// Get all loop tags, from every loop in the block
     fn get_loop_tags(&self) -> Vec<String> {
-        self.inner.get_loop_tags().into_iter().cloned().collect()
+        self.inner().get_loop_tags().into_iter().cloned().collect()
+    }
+
+    /// Write the loop holding a category (or a given tag) as CSV
+    ///
+    /// See `Loop.to_csv` for the table format.
+    ///
+    /// # Arguments
+    ///
+    /// * `category_or_tag` - A category name (`atom_site`) or a tag of the loop
+    /// * `delimiter` - Field delimiter
+    /// * `strip_category_prefix` - Write "label" rather than "_atom_site_label"
+    ///   in the header
+    /// * `unknown_as_empty` - Write unknown values (`?`) as empty cells
+    ///
+    /// # Returns
+    ///
+    /// The table, or None if no loop matches.
+    #[pyo3(signature = (category_or_tag, *, delimiter = ',', strip_category_prefix = false, unknown_as_empty = true))]
+    fn category_to_csv(
+        &self,
+        category_or_tag: &str,
+        delimiter: char,
+        strip_category_prefix: bool,
+        unknown_as_empty: bool,
+    ) -> Option<String> {
+        let options = csv_options(delimiter, strip_category_prefix, unknown_as_empty);
+        export::category_to_csv(self.inner(), category_or_tag, &options)
     }
 
     /// Get the number of frames
     #[getter]
     fn num_frames(&self) -> usize {
-        self.inner.frames.len()
+        self.inner().frames.len()
     }
 
     /// Get a frame by index
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Frame index (0-based)
+    ///
+    /// # Returns
+    ///
+    /// The frame, or None if the index is out of range.
     fn get_frame(&self, index: usize) -> Option<PyFrame> {
-        self.inner.frames.get(index).map(|f| f.clone().into())
+        (index < self.inner().frames.len()).then(|| self.frame_view(index))
     }
 
     /// Get all frames
     #[getter]
     fn frames(&self) -> Vec<PyFrame> {
-        self.inner.frames.iter().map(|f| f.clone().into()).collect()
+        (0..self.inner().frames.len())
+            .map(|index| self.frame_view(index))
+            .collect()
+    }
+
+    /// Get common crystallographic metadata (formula, space group, cell, ...)
+    ///
+    /// Modern (`_cell.length_a`) and legacy (`_cell_length_a`) tag spellings
+    /// are both checked; no dictionary is required.
+    fn summary(&self) -> PyBlockSummary {
+        self.inner().summary().into()
+    }
+
+    /// Find numbers in loop columns that look like mistakes
+    ///
+    /// A number is flagged when it lies outside the plausible range of its
+    /// tag (fractional coordinates, occupancies and geometric angles are
+    /// built in) or more than `iqr_factor` interquartile ranges outside the
+    /// quartiles of its column. No dictionary is required.
+    ///
+    /// # Arguments
+    ///
+    /// * `iqr_factor` - How many interquartile ranges make an outlier
+    /// * `min_values` - Fewest numbers a column needs to be checked for outliers
+    /// * `ranges` - Extra plausible ranges as a dict from tag to `(min, max)`
+    /// * `known_ranges` - Whether to apply the built-in ranges
+    #[pyo3(signature = (*, iqr_factor = 3.0, min_values = 5, ranges = None, known_ranges = true))]
+    fn screen_numeric_anomalies(
+        &self,
+        iqr_factor: f64,
+        min_values: usize,
+        ranges: Option<std::collections::BTreeMap<String, (f64, f64)>>,
+        known_ranges: bool,
+    ) -> Vec<PyAnomaly> {
+        let mut options = ScreeningOptions::new()
+            .with_iqr_factor(iqr_factor)
+            .with_min_values(min_values);
+        if !known_ranges {
+            options = options.without_known_ranges();
+        }
+        for (tag, (min, max)) in ranges.unwrap_or_default() {
+            options = options.with_range(tag, min, max);
+        }
+        self.inner()
+            .screen_numeric_anomalies(&options)
+            .into_iter()
+            .map(Into::into)
+            .collect()
     }
 
     /// String representation
     fn __str__(&self) -> String {
         format!(
             "Block('{}', {} items, {} loops, {} frames)",
-            self.inner.name,
-            self.inner.items.len(),
-            self.inner.loops.len(),
-            self.inner.frames.len()
+            self.inner().name,
+            self.inner().items.len(),
+            self.inner().loops.len(),
+            self.inner().frames.len()
         )
     }
 
@@ -651,42 +981,649 @@ impl PyBlock {
     fn __repr__(&self) -> String {
         format!(
             "Block(name='{}', items={}, loops={}, frames={})",
-            self.inner.name,
-            self.inner.items.len(),
-            self.inner.loops.len(),
-            self.inner.frames.len()
+            self.inner().name,
+            self.inner().items.len(),
+            self.inner().loops.len(),
+            self.inner().frames.len()
+        )
+    }
+}
+
+/// A measured value with its optional standard uncertainty
+#[pyclass(name = "Measurand", frozen, eq)]
+#[derive(Clone, PartialEq)]
+pub struct PyMeasurand {
+    inner: Measurand,
+}
+
+#[pymethods]
+impl PyMeasurand {
+    /// The measured value
+    #[getter]
+    fn value(&self) -> f64 {
+        self.inner.value
+    }
+
+    /// The standard uncertainty, if one was written
+    #[getter]
+    fn uncertainty(&self) -> Option<f64> {
+        self.inner.uncertainty
+    }
+
+    /// String representation
+    fn __str__(&self) -> String {
+        match self.inner.uncertainty {
+            Some(su) => format!("{} ± {}", self.inner.value, su),
+            None => self.inner.value.to_string(),
+        }
+    }
+
+    /// Debug representation
+    fn __repr__(&self) -> String {
+        match self.inner.uncertainty {
+            Some(su) => format!("Measurand(value={}, uncertainty={})", self.inner.value, su),
+            None => format!("Measurand(value={}, uncertainty=None)", self.inner.value),
+        }
+    }
+}
+
+impl From<Measurand> for PyMeasurand {
+    fn from(measurand: Measurand) -> Self {
+        PyMeasurand { inner: measurand }
+    }
+}
+
+/// Common crystallographic values found in a data block
+///
+/// Every field is None when none of its tag spellings are present or the
+/// value is unknown (`?`), not applicable (`.`), or of the wrong type.
+#[pyclass(name = "BlockSummary", frozen, eq)]
+#[derive(Clone, PartialEq)]
+pub struct PyBlockSummary {
+    inner: BlockSummary,
+}
+
+#[pymethods]
+impl PyBlockSummary {
+    /// Name of the block the summary was taken from
+    #[getter]
+    fn block_name(&self) -> String {
+        self.inner.block_name.clone()
+    }
+
+    /// Empirical formula (`_chemical_formula.sum`)
+    #[getter]
+    fn chemical_formula_sum(&self) -> Option<String> {
+        self.inner.chemical_formula_sum.clone()
+    }
+
+    /// Hermann-Mauguin space group symbol (`_space_group.name_H-M_alt`)
+    #[getter]
+    fn space_group_name_hm(&self) -> Option<String> {
+        self.inner.space_group_name_hm.clone()
+    }
+
+    /// International Tables space group number (`_space_group.IT_number`)
+    #[getter]
+    fn space_group_number(&self) -> Option<u32> {
+        self.inner.space_group_number
+    }
+
+    /// Cell lengths and angles in the order a, b, c, alpha, beta, gamma
+    #[getter]
+    fn cell(&self) -> Vec<Option<PyMeasurand>> {
+        self.inner.cell.iter().map(|m| m.map(Into::into)).collect()
+    }
+
+    /// Cell volume (`_cell.volume`)
+    #[getter]
+    fn volume(&self) -> Option<PyMeasurand> {
+        self.inner.volume.map(Into::into)
+    }
+
+    /// Formula units per cell (`_cell.formula_units_Z`)
+    #[getter]
+    fn z(&self) -> Option<u32> {
+        self.inner.z
+    }
+
+    /// Measurement temperature in kelvin (`_diffrn.ambient_temperature`)
+    #[getter]
+    fn temperature(&self) -> Option<PyMeasurand> {
+        self.inner.temperature.map(Into::into)
+    }
+
+    /// Radiation wavelength in angstroms (`_diffrn_radiation_wavelength.value`)
+    #[getter]
+    fn wavelength(&self) -> Option<PyMeasurand> {
+        self.inner.wavelength.map(Into::into)
+    }
+
+    /// R factor for significantly intense reflections (`_refine_ls.R_factor_gt`)
+    #[getter]
+    fn r_factor_gt(&self) -> Option<f64> {
+        self.inner.r_factor_gt
+    }
+
+    /// Weighted R factor for all reflections (`_refine_ls.wR_factor_ref`)
+    #[getter]
+    fn wr_factor(&self) -> Option<f64> {
+        self.inner.wr_factor
+    }
+
+    /// Check if no summary field was found
+    fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Debug representation
+    fn __repr__(&self) -> String {
+        fn opt<T: std::fmt::Display>(value: Option<T>, quoted: bool) -> String {
+            match value {
+                Some(v) if quoted => format!("'{v}'"),
+                Some(v) => v.to_string(),
+                None => "None".to_string(),
+            }
+        }
+
+        let s = &self.inner;
+        format!(
+            "BlockSummary(block_name='{}', chemical_formula_sum={}, space_group_name_hm={}, \
+             space_group_number={}, z={}, r_factor_gt={}, wr_factor={})",
+            s.block_name,
+            opt(s.chemical_formula_sum.as_deref(), true),
+            opt(s.space_group_name_hm.as_deref(), true),
+            opt(s.space_group_number, false),
+            opt(s.z, false),
+            opt(s.r_factor_gt, false),
+            opt(s.wr_factor, false)
         )
     }
 }
 
-impl From<CifBlock> for PyBlock {
-    fn from(block: CifBlock) -> Self {
-        PyBlock { inner: block }
+impl From<BlockSummary> for PyBlockSummary {
+    fn from(summary: BlockSummary) -> Self {
+        PyBlockSummary { inner: summary }
     }
 }
 
-/// Python wrapper for CifDocument with Pythonic interface
+/// Summary statistics of one loop column
+///
+/// Only numbers, with or without an uncertainty, enter the statistics; the
+/// value statistics are None when the column has no numbers.
+#[pyclass(name = "ColumnStats", frozen, eq)]
+#[derive(Clone, PartialEq)]
+pub struct PyColumnStats {
+    inner: ColumnStats,
+}
+
+#[pymethods]
+impl PyColumnStats {
+    /// The column tag, as written
+    #[getter]
+    fn tag(&self) -> String {
+        self.inner.tag.clone()
+    }
+
+    /// Number of cells
+    #[getter]
+    fn count(&self) -> usize {
+        self.inner.count
+    }
+
+    /// Number of numeric cells
+    #[getter]
+    fn numeric(&self) -> usize {
+        self.inner.numeric
+    }
+
+    /// Number of unknown (`?`) cells
+    #[getter]
+    fn unknown(&self) -> usize {
+        self.inner.unknown
+    }
+
+    /// Number of not applicable (`.`) cells
+    #[getter]
+    fn not_applicable(&self) -> usize {
+        self.inner.not_applicable
+    }
+
+    /// Number of cells that are neither numbers nor `?` or `.`
+    #[getter]
+    fn non_numeric(&self) -> usize {
+        self.inner.non_numeric
+    }
+
+    /// Smallest number
+    #[getter]
+    fn min(&self) -> Option<f64> {
+        self.inner.min
+    }
+
+    /// Largest number
+    #[getter]
+    fn max(&self) -> Option<f64> {
+        self.inner.max
+    }
+
+    /// Mean of the numbers
+    #[getter]
+    fn mean(&self) -> Option<f64> {
+        self.inner.mean
+    }
+
+    /// Sample standard deviation of the numbers; None below two numbers
+    #[getter]
+    fn std_dev(&self) -> Option<f64> {
+        self.inner.std_dev
+    }
+
+    /// First quartile of the numbers
+    #[getter]
+    fn q1(&self) -> Option<f64> {
+        self.inner.q1
+    }
+
+    /// Median of the numbers
+    #[getter]
+    fn median(&self) -> Option<f64> {
+        self.inner.median
+    }
+
+    /// Third quartile of the numbers
+    #[getter]
+    fn q3(&self) -> Option<f64> {
+        self.inner.q3
+    }
+
+    /// Interquartile range of the numbers
+    #[getter]
+    fn iqr(&self) -> Option<f64> {
+        self.inner.iqr()
+    }
+
+    /// Check if the column is mostly numbers
+    fn is_numeric(&self) -> bool {
+        self.inner.is_numeric()
+    }
+
+    /// Debug representation
+    fn __repr__(&self) -> String {
+        format!(
+            "ColumnStats(tag='{}', count={}, numeric={})",
+            self.inner.tag, self.inner.count, self.inner.numeric
+        )
+    }
+}
+
+impl From<ColumnStats> for PyColumnStats {
+    fn from(stats: ColumnStats) -> Self {
+        PyColumnStats { inner: stats }
+    }
+}
+
+/// A number in a loop column that looks like a mistake
+#[pyclass(name = "Anomaly", frozen, eq)]
+#[derive(Clone, PartialEq)]
+pub struct PyAnomaly {
+    inner: Anomaly,
+}
+
+#[pymethods]
+impl PyAnomaly {
+    /// Index of the loop in the block
+    #[getter]
+    fn loop_index(&self) -> usize {
+        self.inner.loop_index
+    }
+
+    /// The column tag, as written
+    #[getter]
+    fn tag(&self) -> String {
+        self.inner.tag.clone()
+    }
+
+    /// Row of the number in the loop (0-based)
+    #[getter]
+    fn row(&self) -> usize {
+        self.inner.row
+    }
+
+    /// The number
+    #[getter]
+    fn value(&self) -> f64 {
+        self.inner.value
+    }
+
+    /// Why the number was flagged: "out_of_range" or "outlier"
+    #[getter]
+    fn reason(&self) -> &'static str {
+        match self.inner.reason {
+            AnomalyReason::OutOfRange { .. } => "out_of_range",
+            AnomalyReason::Outlier { .. } => "outlier",
+        }
+    }
+
+    /// The plausible range, or the outlier fences, the number is outside of
+    #[getter]
+    fn bounds(&self) -> (f64, f64) {
+        match self.inner.reason {
+            AnomalyReason::OutOfRange { min, max } => (min, max),
+            AnomalyReason::Outlier { lower, upper } => (lower, upper),
+        }
+    }
+
+    /// Location of the cell
+    #[getter]
+    fn span(&self) -> PySpan {
+        self.inner.span.into()
+    }
+
+    /// Debug representation
+    fn __repr__(&self) -> String {
+        format!(
+            "Anomaly(loop_index={}, tag='{}', row={}, value={}, reason='{}')",
+            self.inner.loop_index,
+            self.inner.tag,
+            self.inner.row,
+            self.inner.value,
+            self.reason()
+        )
+    }
+}
+
+impl From<Anomaly> for PyAnomaly {
+    fn from(anomaly: Anomaly) -> Self {
+        PyAnomaly { inner: anomaly }
+    }
+}
+
+/// A non-fatal observation recorded while parsing
+#[pyclass(name = "ParseNote", frozen)]
+#[derive(Clone)]
+pub struct PyParseNote {
+    inner: ParseNote,
+}
+
+#[pymethods]
+impl PyParseNote {
+    /// Machine-readable note identifier, e.g. "leading-content-skipped"
+    #[getter]
+    fn note_id(&self) -> &'static str {
+        self.inner.note_id
+    }
+
+    /// Human-readable description
+    #[getter]
+    fn message(&self) -> String {
+        self.inner.message.clone()
+    }
+
+    /// Source location the note refers to
+    #[getter]
+    fn span(&self) -> PySpan {
+        self.inner.span.into()
+    }
+
+    /// Another location the note refers to, such as the first of two duplicate tags
+    #[getter]
+    fn related_span(&self) -> Option<PySpan> {
+        self.inner.related_span.map(Into::into)
+    }
+
+    /// String representation
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    /// Debug representation
+    fn __repr__(&self) -> String {
+        format!(
+            "ParseNote(note_id='{}', line={})",
+            self.inner.note_id, self.inner.span.start_line
+        )
+    }
+}
+
+/// A parsed document together with upgrade issues and notes
+#[pyclass(name = "ParseResult", frozen)]
+pub struct PyParseResult {
+    document: PyDocument,
+    upgrade_issues: Vec<String>,
+    notes: Vec<PyParseNote>,
+}
+
+#[pymethods]
+impl PyParseResult {
+    /// The parsed document
+    #[getter]
+    fn document(&self) -> PyDocument {
+        self.document.clone()
+    }
+
+    /// Changes needed for CIF 2.0, if upgrade guidance was requested
+    #[getter]
+    fn upgrade_issues(&self) -> Vec<String> {
+        self.upgrade_issues.clone()
+    }
+
+    /// Informational notes, such as skipped leading text
+    #[getter]
+    fn notes(&self) -> Vec<PyParseNote> {
+        self.notes.clone()
+    }
+
+    /// Debug representation
+    fn __repr__(&self) -> String {
+        format!(
+            "ParseResult(blocks={}, upgrade_issues={}, notes={})",
+            self.document.inner.blocks.len(),
+            self.upgrade_issues.len(),
+            self.notes.len()
+        )
+    }
+}
+
+impl From<ParseResult> for PyParseResult {
+    fn from(result: ParseResult) -> Self {
+        PyParseResult {
+            document: PyDocument::new(result.document),
+            upgrade_issues: result
+                .upgrade_issues
+                .iter()
+                .map(|issue| issue.to_string())
+                .collect(),
+            notes: result
+                .notes
+                .into_iter()
+                .map(|note| PyParseNote { inner: note })
+                .collect(),
+        }
+    }
+}
+
+/// Count and first location of one version-sensitive construct
+#[pyclass(name = "FeatureUsage", frozen)]
+#[derive(Clone)]
+pub struct PyFeatureUsage {
+    inner: FeatureUsage,
+}
+
+#[pymethods]
+impl PyFeatureUsage {
+    /// Snake-case feature name, e.g. "triple_quoted_string"
+    #[getter]
+    fn feature(&self) -> &'static str {
+        self.inner.feature.name()
+    }
+
+    /// Number of occurrences
+    #[getter]
+    fn count(&self) -> usize {
+        self.inner.count
+    }
+
+    /// Location of the first occurrence
+    #[getter]
+    fn first(&self) -> PySpan {
+        self.inner.first.into()
+    }
+
+    /// Debug representation
+    fn __repr__(&self) -> String {
+        format!(
+            "FeatureUsage(feature='{}', count={}, first={}:{})",
+            self.inner.feature.name(),
+            self.inner.count,
+            self.inner.first.start_line,
+            self.inner.first.start_col
+        )
+    }
+}
+
+/// Version-sensitive constructs used in a document
+///
+/// Features are looked up by their snake-case names, as in
+/// `FeatureUsage.feature`.
+#[pyclass(name = "FeatureReport", frozen)]
+#[derive(Clone)]
+pub struct PyFeatureReport {
+    inner: FeatureReport,
+}
+
+#[pymethods]
+impl PyFeatureReport {
+    /// Features found, in a fixed order
+    #[getter]
+    fn features(&self) -> Vec<PyFeatureUsage> {
+        self.inner
+            .features
+            .iter()
+            .map(|usage| PyFeatureUsage { inner: *usage })
+            .collect()
+    }
+
+    /// Line of the `#\#CIF_2.0` header, or None if there is none
+    #[getter]
+    fn header_line(&self) -> Option<usize> {
+        self.inner.header.as_ref().map(|h| h.line)
+    }
+
+    /// Version the header declares
+    #[getter]
+    fn declared_version(&self) -> PyVersion {
+        self.inner.declared_version().into()
+    }
+
+    /// Oldest CIF version that can represent the document
+    fn minimum_version(&self) -> PyVersion {
+        self.inner.minimum_version().into()
+    }
+
+    /// Newest CIF version the document can be read as unchanged
+    fn maximum_compatible_version(&self) -> PyVersion {
+        self.inner.maximum_compatible_version().into()
+    }
+
+    /// Get the usage of a feature by name, or None if it is absent
+    ///
+    /// # Arguments
+    ///
+    /// * `feature` - Snake-case feature name, such as "table"
+    fn get(&self, feature: &str) -> Option<PyFeatureUsage> {
+        self.inner
+            .features
+            .iter()
+            .find(|usage| usage.feature.name() == feature)
+            .map(|usage| PyFeatureUsage { inner: *usage })
+    }
+
+    /// Number of occurrences of a feature by name
+    ///
+    /// # Arguments
+    ///
+    /// * `feature` - Snake-case feature name, such as "table"
+    fn count(&self, feature: &str) -> usize {
+        self.get(feature).map_or(0, |usage| usage.inner.count)
+    }
+
+    /// Number of distinct features found
+    fn __len__(&self) -> usize {
+        self.inner.features.len()
+    }
+
+    /// Check if a feature is used, by name
+    fn __contains__(&self, feature: &str) -> bool {
+        self.get(feature).is_some()
+    }
+
+    /// Debug representation
+    fn __repr__(&self) -> String {
+        let names: Vec<_> = self
+            .inner
+            .features
+            .iter()
+            .map(|usage| format!("'{}'", usage.feature.name()))
+            .collect();
+        format!("FeatureReport(features=[{}])", names.join(", "))
+    }
+}
+
+/// A CIF document containing one or more data blocks
+///
+/// Blocks can be looked up by index or by name (`doc[0]`, `doc["name"]`),
+/// and iterating over a document yields its blocks.
 #[pyclass(name = "Document")]
 #[derive(Clone)]
 pub struct PyDocument {
-    inner: CifDocument,
+    inner: Arc<CifDocument>,
+}
+
+impl PyDocument {
+    fn new(doc: CifDocument) -> Self {
+        PyDocument {
+            inner: Arc::new(doc),
+        }
+    }
+
+    fn block_view(&self, index: usize) -> PyBlock {
+        PyBlock::new(&self.inner, index)
+    }
 }
 
 #[pymethods]
 impl PyDocument {
     /// Parse a CIF string
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - CIF file content
+    ///
+    /// # Returns
+    ///
+    /// The parsed document. Raises ValueError if the content is not valid CIF.
     #[staticmethod]
     fn parse(content: &str) -> PyResult<PyDocument> {
         CifDocument::parse(content)
-            .map(|doc| PyDocument { inner: doc })
+            .map(PyDocument::new)
             .map_err(cif_error_to_py_err)
     }
 
     /// Parse a CIF file
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the CIF file
+    ///
+    /// # Returns
+    ///
+    /// The parsed document. Raises IOError if the file cannot be read and
+    /// ValueError if it is not valid CIF.
     #[staticmethod]
     fn from_file(path: &str) -> PyResult<PyDocument> {
         CifDocument::from_file(path)
-            .map(|doc| PyDocument { inner: doc })
+            .map(PyDocument::new)
             .map_err(cif_error_to_py_err)
     }
 
@@ -718,30 +1655,55 @@ impl PyDocument {
     }
 
     /// Get a block by index
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Block index (0-based)
+    ///
+    /// # Returns
+    ///
+    /// The block, or None if the index is out of range.
     fn get_block(&self, index: usize) -> Option<PyBlock> {
-        self.inner.blocks.get(index).map(|b| b.clone().into())
+        (index < self.inner.blocks.len()).then(|| self.block_view(index))
     }
 
-    /// Get a block by name
+    /// Get a block by name, ignoring case
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Block name, without the `data_` prefix
+    ///
+    /// # Returns
+    ///
+    /// The block, or None if no block has that name.
     fn get_block_by_name(&self, name: &str) -> Option<PyBlock> {
-        self.inner.get_block(name).map(|b| b.clone().into())
+        self.inner
+            .get_block(name)
+            .and_then(|found| position_of(&self.inner.blocks, found))
+            .map(|index| self.block_view(index))
     }
 
     /// Get the first block
     fn first_block(&self) -> Option<PyBlock> {
-        self.inner.first_block().map(|b| b.clone().into())
+        self.get_block(0)
     }
 
     /// Get all blocks
     #[getter]
     fn blocks(&self) -> Vec<PyBlock> {
-        self.inner.blocks.iter().map(|b| b.clone().into()).collect()
+        (0..self.inner.blocks.len())
+            .map(|index| self.block_view(index))
+            .collect()
     }
 
     /// Get all block names
     #[getter]
     fn block_names(&self) -> Vec<String> {
-        self.inner.blocks.iter().map(|b| b.name.clone()).collect()
+        self.inner
+            .blocks
+            .iter()
+            .map(|b| b.name.to_string())
+            .collect()
     }
 
     /// Python iterator protocol
@@ -768,15 +1730,10 @@ impl PyDocument {
                 index as usize
             };
 
-            self.inner
-                .blocks
-                .get(actual_index)
-                .map(|b| b.clone().into())
+            self.get_block(actual_index)
                 .ok_or_else(|| PyIndexError::new_err("Block index out of range"))
         } else if let Ok(name) = key.extract::<String>() {
-            self.inner
-                .get_block(&name)
-                .map(|b| b.clone().into())
+            self.get_block_by_name(&name)
                 .ok_or_else(|| PyKeyError::new_err(format!("Block '{name}' not found")))
         } else {
             Err(PyTypeError::new_err("Block key must be int or str"))
@@ -795,7 +1752,7 @@ impl PyDocument {
     }
 }
 
-/// Iterator for PyDocument
+/// Iterator over the blocks of a Document
 #[pyclass]
 pub struct PyDocumentIterator {
     doc: PyDocument,
@@ -810,7 +1767,7 @@ impl PyDocumentIterator {
 
     fn __next__(&mut self) -> Option<PyBlock> {
         if self.index < self.doc.inner.blocks.len() {
-            let block = self.doc.inner.blocks[self.index].clone().into();
+            let block = self.doc.block_view(self.index);
             self.index += 1;
             Some(block)
         } else {
@@ -831,10 +1788,22 @@ fn _cif_parser(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyLoopIterator>()?;
     m.add_class::<PyFrame>()?;
     m.add_class::<PyValue>()?;
+    m.add_class::<PyMeasurand>()?;
+    m.add_class::<PyBlockSummary>()?;
+    m.add_class::<PyColumnStats>()?;
+    m.add_class::<PyAnomaly>()?;
+    m.add_class::<PyParseNote>()?;
+    m.add_class::<PyParseResult>()?;
+    m.add_class::<PyFeatureUsage>()?;
+    m.add_class::<PyFeatureReport>()?;
 
     // Convenience functions
     m.add_function(wrap_pyfunction!(parse, m)?)?;
     m.add_function(wrap_pyfunction!(parse_file, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_with_options, m)?)?;
+    m.add_function(wrap_pyfunction!(feature_report, m)?)?;
+    m.add_function(wrap_pyfunction!(csv_to_loop, m)?)?;
+    m.add_function(wrap_pyfunction!(tsv_to_loop, m)?)?;
 
     // Module metadata
     m.add("__version__", env!("CARGO_PKG_VERSION"))?;
@@ -844,14 +1813,138 @@ fn _cif_parser(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     Ok(())
 }
 
-/// Convenience function for parsing CIF content
+/// Parse a CIF string
+///
+/// Equivalent to `Document.parse`.
+///
+/// # Arguments
+///
+/// * `content` - CIF file content
+///
+/// # Returns
+///
+/// The parsed document. Raises ValueError if the content is not valid CIF.
 #[pyfunction]
 fn parse(content: &str) -> PyResult<PyDocument> {
     PyDocument::parse(content)
 }
 
-/// Convenience function for parsing CIF files
+/// Parse a CIF file
+///
+/// Equivalent to `Document.from_file`.
+///
+/// # Arguments
+///
+/// * `path` - Path to the CIF file
+///
+/// # Returns
+///
+/// The parsed document. Raises IOError if the file cannot be read and
+/// ValueError if it is not valid CIF.
 #[pyfunction]
 fn parse_file(path: &str) -> PyResult<PyDocument> {
     PyDocument::from_file(path)
 }
+
+/// Parse CIF content with options, returning notes alongside the document
+///
+/// # Arguments
+///
+/// * `content` - CIF file content
+/// * `upgrade_guidance` - For CIF 1.1 input, also list what would need to
+///   change for the file to be valid CIF 2.0
+/// * `skip_leading_garbage` - Ignore prose, mail headers or markup above the
+///   first `data_` line; skipped lines are reported as a note
+///
+/// # Returns
+///
+/// The document with any upgrade issues and notes. Raises ValueError if the
+/// content is not valid CIF.
+#[pyfunction]
+#[pyo3(signature = (content, *, upgrade_guidance = false, skip_leading_garbage = false))]
+fn parse_with_options(
+    content: &str,
+    upgrade_guidance: bool,
+    skip_leading_garbage: bool,
+) -> PyResult<PyParseResult> {
+    let options = ParseOptions::new()
+        .upgrade_guidance(upgrade_guidance)
+        .skip_leading_garbage(skip_leading_garbage);
+    parse_string_with_options(content, options)
+        .map(Into::into)
+        .map_err(cif_error_to_py_err)
+}
+
+/// Read CSV written by `Loop.to_csv` back into a loop with the given tags
+///
+/// The header row is skipped. Empty cells and `?` become unknown values,
+/// `.` is not applicable, and numbers (with or without an uncertainty) are
+/// numeric.
+///
+/// # Arguments
+///
+/// * `tags` - Full tag names for the columns, in order
+/// * `csv` - The table
+///
+/// # Returns
+///
+/// A loop with one row per table row. Raises ValueError if a row has the
+/// wrong number of cells or a quoted field is not closed.
+#[pyfunction]
+fn csv_to_loop(tags: Vec<String>, csv: &str) -> PyResult<PyLoop> {
+    export::csv_to_loop(&tags, csv)
+        .map(standalone_loop)
+        .map_err(cif_error_to_py_err)
+}
+
+/// Read TSV back into a loop with the given tags
+///
+/// See `csv_to_loop`.
+///
+/// # Arguments
+///
+/// * `tags` - Full tag names for the columns, in order
+/// * `tsv` - The table
+#[pyfunction]
+fn tsv_to_loop(tags: Vec<String>, tsv: &str) -> PyResult<PyLoop> {
+    export::tsv_to_loop(&tags, tsv)
+        .map(standalone_loop)
+        .map_err(cif_error_to_py_err)
+}
+
+/// A loop view over a document holding only `loop_`
+fn standalone_loop(loop_: CifLoop) -> PyLoop {
+    let mut block = CifBlock::new("csv".to_string());
+    block.loops.push(loop_);
+    let mut doc = CifDocument::new();
+    doc.blocks.push(block);
+    PyLoop {
+        doc: Arc::new(doc),
+        owner: LoopOwner::Block(0),
+        index: 0,
+    }
+}
+
+fn csv_options(delimiter: char, strip_category_prefix: bool, unknown_as_empty: bool) -> CsvOptions {
+    CsvOptions::new()
+        .delimiter(delimiter)
+        .strip_category_prefix(strip_category_prefix)
+        .unknown_as_empty(unknown_as_empty)
+}
+
+/// Inventory the version-sensitive constructs used in CIF content
+///
+/// # Arguments
+///
+/// * `content` - CIF file content
+///
+/// # Returns
+///
+/// The constructs found, with counts and first locations. Raises ValueError
+/// if the content is not valid CIF.
+#[pyfunction]
+fn feature_report(content: &str) -> PyResult<PyFeatureReport> {
+    crate::analysis::feature_report(content)
+        .map(|inner| PyFeatureReport { inner })
+        .map_err(cif_error_to_py_err)
+}
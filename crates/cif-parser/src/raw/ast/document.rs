@@ -1,6 +1,7 @@
 //! Raw document type for lossless CIF parsing.
 
-use crate::ast::Span;
+use crate::ast::{CifVersion, MagicHeader, Span};
+use crate::raw::parser::declared_version;
 use crate::raw::RawBlock;
 
 /// A raw CIF document before version-specific resolution.
@@ -11,10 +12,14 @@ use crate::raw::RawBlock;
 pub struct RawDocument {
     /// All data blocks in the document
     pub blocks: Vec<RawBlock>,
-    /// Whether the `#\#CIF_2.0` magic comment was present
-    pub has_cif2_magic: bool,
+    /// The `#\#CIF_2.0` header wherever it was found in the leading comments
+    pub magic_header: Option<MagicHeader>,
     /// Span of the entire document
     pub span: Span,
+    /// Blank lines and comments after the last construct, verbatim, so the
+    /// end of the file can be written back unchanged. With no blocks this is
+    /// everything after the `#\#CIF_2.0` header line, or the whole input.
+    pub trailing: String,
 }
 
 impl RawDocument {
@@ -22,19 +27,26 @@ impl RawDocument {
     pub fn new() -> Self {
         Self {
             blocks: Vec::new(),
-            has_cif2_magic: false,
+            magic_header: None,
             span: Span::default(),
+            trailing: String::new(),
         }
     }
 
     /// Create a raw document with the given blocks
-    pub fn with_blocks(blocks: Vec<RawBlock>, has_cif2_magic: bool, span: Span) -> Self {
+    pub fn with_blocks(blocks: Vec<RawBlock>, span: Span) -> Self {
         Self {
             blocks,
-            has_cif2_magic,
+            magic_header: None,
             span,
+            trailing: String::new(),
         }
     }
+
+    /// Whether the `#\#CIF_2.0` magic comment was present on line 1
+    pub fn has_cif2_magic(&self) -> bool {
+        declared_version(self.magic_header.as_ref()) == CifVersion::V2_0
+    }
 }
 
 impl Default for RawDocument {
@@ -34,6 +34,20 @@ pub enum RawValue {
     TableSyntax(RawTableSyntax),
 }
 
+impl RawValue {
+    /// Get the span of this value
+    pub fn span(&self) -> Span {
+        match self {
+            RawValue::QuotedString(q) => q.span,
+            RawValue::TripleQuotedString(t) => t.span,
+            RawValue::TextField(t) => t.span,
+            RawValue::Unquoted(u) => u.span,
+            RawValue::ListSyntax(l) => l.span,
+            RawValue::TableSyntax(t) => t.span,
+        }
+    }
+}
+
 /// A quoted string with metadata for version-specific processing.
 ///
 /// Preserves:
@@ -129,15 +143,22 @@ pub struct RawTableEntry {
     pub key: RawTableKey,
     /// The value
     pub value: RawValue,
+    /// Whitespace between the key and the colon, if any (invalid in CIF 2.0)
+    pub space_before_colon: Option<Span>,
 }
 
 /// Table key can be a quoted or triple-quoted string.
+///
+/// Unquoted keys are accepted by the grammar only so they can be reported
+/// precisely; CIF 2.0 resolution rejects them.
 #[derive(Debug, Clone)]
 pub enum RawTableKey {
     /// Regular quoted string key
     Quoted(RawQuotedString),
     /// Triple-quoted string key (CIF 2.0)
     TripleQuoted(RawTripleQuoted),
+    /// Unquoted key such as `{a:1}` (invalid in CIF 2.0)
+    Unquoted(RawUnquoted),
 }
 
 impl RawTableKey {
@@ -146,6 +167,7 @@ impl RawTableKey {
         match self {
             RawTableKey::Quoted(q) => q.span,
             RawTableKey::TripleQuoted(t) => t.span,
+            RawTableKey::Unquoted(u) => u.span,
         }
     }
 
@@ -154,6 +176,7 @@ impl RawTableKey {
         match self {
             RawTableKey::Quoted(q) => &q.raw_content,
             RawTableKey::TripleQuoted(t) => &t.raw_content,
+            RawTableKey::Unquoted(u) => &u.text,
         }
     }
 }
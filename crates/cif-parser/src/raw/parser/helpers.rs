@@ -7,12 +7,15 @@
 use crate::ast::Span;
 use crate::Rule;
 use pest::iterators::Pair;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 
 // Thread-local line index for fast byte-offset to line/column conversion
 // Built once per parse, then used for O(log n) lookups
 thread_local! {
     static LINE_INDEX: RefCell<Option<LineIndex>> = const { RefCell::new(None) };
+    // Offset of the text being parsed within the indexed input; non-zero only
+    // while re-parsing a slice (see `with_offset_base`)
+    static OFFSET_BASE: Cell<usize> = const { Cell::new(0) };
 }
 
 /// Index of newline positions for fast line/column lookup
@@ -52,6 +55,16 @@ impl LineIndex {
         let col = offset - line_start + 1; // 1-indexed
         (line, col)
     }
+
+    /// Convert a 1-indexed (line, column) back to a byte offset
+    pub fn offset(&self, line: usize, col: usize) -> usize {
+        let line_start = if line == 1 {
+            0
+        } else {
+            self.newlines[line - 2] + 1
+        };
+        line_start + col - 1
+    }
 }
 
 /// Initialize the thread-local line index for the current parse
@@ -68,8 +81,21 @@ pub(crate) fn clear_line_index() {
     });
 }
 
+/// Run `f` with offsets shifted by `base`.
+///
+/// Used when a slice of the input is parsed on its own: PEST then reports
+/// offsets from the start of the slice, and `base` (the slice's offset in the
+/// full input) turns them back into positions in the indexed input.
+pub(crate) fn with_offset_base<T>(base: usize, f: impl FnOnce() -> T) -> T {
+    let previous = OFFSET_BASE.with(|b| b.replace(base));
+    let result = f();
+    OFFSET_BASE.with(|b| b.set(previous));
+    result
+}
+
 /// Convert byte offset to line/column using the cached line index
 fn offset_to_line_col(offset: usize) -> (usize, usize) {
+    let offset = offset + OFFSET_BASE.with(Cell::get);
     LINE_INDEX.with(|idx| {
         if let Some(index) = idx.borrow().as_ref() {
             index.line_col(offset)
@@ -80,6 +106,15 @@ fn offset_to_line_col(offset: usize) -> (usize, usize) {
     })
 }
 
+/// Convert a 1-indexed (line, column) to a byte offset using the cached line index
+pub(crate) fn line_col_to_offset(line: usize, col: usize) -> usize {
+    LINE_INDEX.with(|idx| {
+        idx.borrow()
+            .as_ref()
+            .map_or(0, |index| index.offset(line, col))
+    })
+}
+
 /// Extract the string content from a parse tree node.
 #[inline]
 pub(crate) fn extract_text(pair: &Pair<Rule>) -> String {
@@ -92,7 +127,12 @@ pub(crate) fn extract_text(pair: &Pair<Rule>) -> String {
 /// PERFORMANCE: O(log n) per lookup using pre-built line index.
 pub(crate) fn extract_span(pair: &Pair<Rule>) -> Span {
     let pest_span = pair.as_span();
-    let (start_line, start_col) = offset_to_line_col(pest_span.start());
-    let (end_line, end_col) = offset_to_line_col(pest_span.end());
+    offsets_to_span(pest_span.start(), pest_span.end())
+}
+
+/// Build a [`Span`] from byte offsets into the input being parsed.
+pub(crate) fn offsets_to_span(start: usize, end: usize) -> Span {
+    let (start_line, start_col) = offset_to_line_col(start);
+    let (end_line, end_col) = offset_to_line_col(end);
     Span::new(start_line, start_col, end_line, end_col)
 }
@@ -1,11 +1,15 @@
 //! Loop structure parsing logic - produces RawLoop.
 
 use crate::error::CifError;
-use crate::raw::parser::helpers::{extract_span, extract_text};
-use crate::raw::parser::value::parse_value_raw;
+use crate::raw::parser::helpers::{extract_span, extract_text, offsets_to_span, with_offset_base};
+use crate::raw::parser::value::{
+    parse_value_raw, quoted_string_raw, text_field_raw, triple_quoted_raw, unquoted_raw,
+};
 use crate::raw::{RawLoop, RawLoopTag, RawValue};
-use crate::Rule;
+use crate::{CIFParser, Rule};
 use pest::iterators::Pair;
+use pest::Parser;
+use std::ops::Range;
 
 /// Parse a loop structure from the parse tree to RawLoop.
 ///
@@ -53,22 +57,352 @@ pub(crate) fn parse_loop_raw(pair: Pair<Rule>) -> Result<RawLoop, CifError> {
 }
 
 /// Helper to collect values from loop_values rule.
+///
+/// `loop_values` is atomic in the grammar: PEST checks the loop body but
+/// builds no nodes inside it, since a node per value token dominated the time
+/// and memory spent on large loops. The body is split into values here
+/// instead, following the grammar's choices exactly so values and spans are
+/// the same as parsing each `data_value` would give.
 fn collect_loop_values_raw(pair: Pair<Rule>, values: &mut Vec<RawValue>) -> Result<(), CifError> {
-    for value_pair in pair.into_inner() {
-        match value_pair.as_rule() {
-            Rule::loop_value | Rule::value => {
-                let value = parse_value_raw(value_pair)?;
+    let base = pair.as_span().start();
+    let body = pair.as_str();
+    let bytes = body.as_bytes();
+    let mut pos = 0;
+
+    loop {
+        pos = skip_whitespace(bytes, pos);
+        if pos >= bytes.len() {
+            return Ok(());
+        }
+        let start = pos;
+        let after_line_term = start > 0 && is_line_term(bytes[start - 1]);
+        let end = match bytes[start] {
+            // Line-start ';' always opens a text field: whitespace never
+            // consumes the line break in front of one
+            b';' if after_line_term => text_field_end(bytes, start),
+            b'\'' | b'"' => match triple_quoted_end(body, start) {
+                Some(end) => {
+                    values.push(triple_quoted_raw(
+                        &body[start..end],
+                        offsets_to_span(base + start, base + end),
+                    ));
+                    pos = end;
+                    continue;
+                }
+                None => quoted_end(bytes, start),
+            },
+            b'[' | b'{' => {
+                let (value, end) = parse_nested_value(body, base, start)?;
                 values.push(value);
+                pos = end;
+                continue;
             }
-            _rule => {
-                // Unknown rule - safely ignored
+            _ => unquoted_end(bytes, start),
+        };
+        let text = &body[start..end];
+        let span = offsets_to_span(base + start, base + end);
+        values.push(match bytes[start] {
+            b';' if after_line_term => text_field_raw(text, span),
+            b'\'' | b'"' => quoted_string_raw(text, span),
+            _ => unquoted_raw(text, span),
+        });
+        pos = end;
+    }
+}
+
+fn is_line_term(byte: u8) -> bool {
+    matches!(byte, b'\n' | b'\r')
+}
+
+/// Skip spaces, tabs, line terminators and comments.
+fn skip_whitespace(bytes: &[u8], mut pos: usize) -> usize {
+    while let Some(&byte) = bytes.get(pos) {
+        match byte {
+            b' ' | b'\t' | b'\n' | b'\r' => pos += 1,
+            b'#' => {
+                while pos < bytes.len() && !is_line_term(bytes[pos]) {
+                    pos += 1;
+                }
+            }
+            _ => break,
+        }
+    }
+    pos
+}
+
+/// End of a text field opened at `start`: just past the first ';' that
+/// follows a line terminator (`text_delim`).
+fn text_field_end(bytes: &[u8], start: usize) -> usize {
+    let mut pos = start + 1;
+    while pos < bytes.len() {
+        let term_len = match bytes[pos] {
+            b'\r' if bytes.get(pos + 1) == Some(&b'\n') => 2,
+            b'\r' | b'\n' => 1,
+            _ => {
+                pos += 1;
+                continue;
             }
+        };
+        if bytes.get(pos + term_len) == Some(&b';') {
+            return pos + term_len + 1;
+        }
+        pos += term_len;
+    }
+    bytes.len()
+}
+
+/// End of a triple-quoted string opened at `start`, if it is one.
+///
+/// Content runs to the first three quotes in a row; without them the grammar
+/// falls back to a single-quoted string.
+fn triple_quoted_end(body: &str, start: usize) -> Option<usize> {
+    let delim = match &body[start..] {
+        rest if rest.starts_with("'''") => "'''",
+        rest if rest.starts_with("\"\"\"") => "\"\"\"",
+        _ => return None,
+    };
+    let content = start + delim.len();
+    body[content..]
+        .find(delim)
+        .map(|offset| content + offset + delim.len())
+}
+
+/// End of a quoted string opened at `start`.
+///
+/// CIF 1.1 rules: only a quote followed by whitespace, a comment, the end of
+/// input, or (for CIF 2.0 lists and tables) `]` or `}` closes the string, so
+/// `'O''Brien'` is one value.
+fn quoted_end(bytes: &[u8], start: usize) -> usize {
+    let quote = bytes[start];
+    let mut pos = start + 1;
+    while pos < bytes.len() {
+        if bytes[pos] == quote
+            && bytes.get(pos + 1).is_none_or(|next| {
+                matches!(next, b' ' | b'\t' | b'\n' | b'\r' | b'#' | b']' | b'}')
+            })
+        {
+            return pos + 1;
+        }
+        pos += 1;
+    }
+    bytes.len()
+}
+
+/// End of an unquoted value starting at `start`.
+fn unquoted_end(bytes: &[u8], start: usize) -> usize {
+    let mut pos = start + 1;
+    while pos < bytes.len()
+        && !matches!(
+            bytes[pos],
+            b' ' | b'\t' | b'\n' | b'\r' | b'[' | b']' | b'{' | b'}'
+        )
+    {
+        pos += 1;
+    }
+    pos
+}
+
+/// Parse a list or table starting at `start` with the full grammar.
+///
+/// These are rare in loops and nest arbitrarily, so they go back through
+/// PEST. Returns the value and the offset just past it.
+fn parse_nested_value(
+    body: &str,
+    base: usize,
+    start: usize,
+) -> Result<(RawValue, usize), CifError> {
+    let pair = CIFParser::parse(Rule::data_value, &body[start..])?
+        .next()
+        .expect("data_value match produces a pair");
+    let end = start + pair.as_span().end();
+    let value = with_offset_base(base + start, || parse_value_raw(pair))?;
+    Ok((value, end))
+}
+
+/// Suggestion for a value that ended a loop header after `tags` tags
+pub(crate) fn cut_short_header_hint(value: &str, tags: usize) -> String {
+    format!(
+        "'{value}' ended the loop header after {tags} tag(s); it may be a value \
+         misplaced among the tags, or a tag missing its leading '_'"
+    )
+}
+
+/// A loop header ended early by a value, as in `loop_ _a _b value1 _c`
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct CutShortHeader {
+    /// Tags before the value
+    pub tags: usize,
+    /// Byte range of the value
+    pub value: Range<usize>,
+}
+
+/// Find a loop header ended early by a value that explains a grammar failure
+/// at byte `error_at`.
+///
+/// The value starts the loop body, so the tags after it start data items
+/// and the rows meant for the loop follow them as stray values. The shape is
+/// `loop_`, tags, a single value or fewer values than tags, then more tags.
+/// Valid CIF can look like that too (a one-row loop followed by items), so
+/// it only counts if the failure lies between the `loop_` and the end of the
+/// values after those tags.
+pub(crate) fn find_cut_short_header(input: &str, error_at: usize) -> Option<CutShortHeader> {
+    let tokens: Vec<Range<usize>> = tokens(input).collect();
+    let is_tag = |token: &Range<usize>| input.as_bytes()[token.start] == b'_';
+    let is_value = |token: &Range<usize>| {
+        let text = input[token.clone()].to_ascii_lowercase();
+        !is_tag(token)
+            && !matches!(text.as_str(), "loop_" | "global_" | "stop_")
+            && !text.starts_with("data_")
+            && !text.starts_with("save_")
+    };
+    let run = |from: usize, matches: &dyn Fn(&Range<usize>) -> bool| {
+        tokens[from.min(tokens.len())..]
+            .iter()
+            .take_while(|token| matches(token))
+            .count()
+    };
+
+    let mut i = 0;
+    while i < tokens.len() {
+        if !input[tokens[i].clone()].eq_ignore_ascii_case("loop_") {
+            i += 1;
+            continue;
+        }
+        if tokens[i].start > error_at {
+            return None;
+        }
+        let tags = run(i + 1, &is_tag);
+        let body = i + 1 + tags;
+        let values = run(body, &is_value);
+        let more_tags = run(body + values, &is_tag);
+        let after = body + values + more_tags;
+        let stray = run(after, &is_value);
+        let region_end = tokens.get(after + stray).map_or(input.len(), |t| t.start);
+
+        if tags > 0
+            && more_tags > 0
+            && (values == 1 || (values > 0 && values < tags))
+            && error_at <= region_end
+        {
+            return Some(CutShortHeader {
+                tags,
+                value: tokens[body].clone(),
+            });
         }
+        i = body.max(i + 1);
     }
-    Ok(())
+    None
+}
+
+/// Byte ranges of the tokens of `input`, for diagnosing grammar failures.
+///
+/// Follows the loop body scanner for quotes and text fields; brackets of
+/// lists and tables are tokens of their own.
+fn tokens(input: &str) -> impl Iterator<Item = Range<usize>> + '_ {
+    let bytes = input.as_bytes();
+    let mut pos = 0;
+    std::iter::from_fn(move || {
+        pos = skip_whitespace(bytes, pos);
+        if pos >= bytes.len() {
+            return None;
+        }
+        let start = pos;
+        let after_line_term = start == 0 || is_line_term(bytes[start - 1]);
+        pos = match bytes[start] {
+            b';' if after_line_term => text_field_end(bytes, start),
+            b'\'' | b'"' => {
+                triple_quoted_end(input, start).unwrap_or_else(|| quoted_end(bytes, start))
+            }
+            b'[' | b']' | b'{' | b'}' => start + 1,
+            _ => unquoted_end(bytes, start),
+        };
+        Some(start..pos)
+    })
 }
 
 #[cfg(test)]
 mod tests {
-    // Tests will be added as we implement the parser
+    use super::*;
+    use crate::raw::parser::helpers::{clear_line_index, init_line_index};
+
+    /// Values from the scanner, as debug strings
+    fn scanned(input: &str) -> Vec<String> {
+        init_line_index(input);
+        let pair = CIFParser::parse(Rule::loop_values, input)
+            .unwrap()
+            .next()
+            .unwrap();
+        assert_eq!(pair.as_str(), input, "loop_values should match all input");
+        let mut values = Vec::new();
+        collect_loop_values_raw(pair, &mut values).unwrap();
+        clear_line_index();
+        values.iter().map(|v| format!("{v:?}")).collect()
+    }
+
+    /// Values from the full parse tree of the traced rule, as debug strings
+    fn traced(input: &str) -> Vec<String> {
+        init_line_index(input);
+        let pair = CIFParser::parse(Rule::loop_values_traced, input)
+            .unwrap()
+            .next()
+            .unwrap();
+        let values = pair
+            .into_inner()
+            .filter(|p| p.as_rule() == Rule::loop_value)
+            .map(|p| format!("{:?}", parse_value_raw(p).unwrap()))
+            .collect();
+        clear_line_index();
+        values
+    }
+
+    #[test]
+    fn test_scanner_matches_parse_tree() {
+        let inputs = [
+            "1 ;semi a#b\n\"q x\" 'O''Brien' \"a\"b\"\n",
+            "x\n;\ntext\n;\n'''tri\nple''' \"\"\"x\"\"y\"\"\"\n",
+            "x # comment\n\n\t? . -1.5(3)\n;x\n;  # after\n'q' #c\n",
+            "x\r\n;text\r\nmore\r\n;\r\n2 3\r;cr\r;\r\"a b\"",
+            "''' x ''' '' \"\" 'a'b' \"\"\"y\"\"\" \"\"\"a\"\"b\"\"\" '''''",
+            "[1 2 [3 4]] {\"k\":v \"m\":[1 {\"n\":2}]}\n[ ] {} ['a' \"b\"]",
+            "x {\"k\":\n;\nfield\n;\n} [\n;\nt\n;\n]",
+            "\u{3b1}\u{3b2} \"\u{3b3} \u{3b4}\" 'x]' trailing # end",
+        ];
+        for input in inputs {
+            let values = scanned(input);
+            assert!(!values.is_empty(), "{input:?}");
+            assert_eq!(values, traced(input), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_scan_helpers() {
+        assert_eq!(text_field_end(b"a\n;one\r\n;two\n;", 2), 9);
+        assert_eq!(skip_whitespace(b"  # c\r\n\tx", 0), 8);
+        assert_eq!(quoted_end(b"'it''s' x", 0), 7);
+        assert_eq!(quoted_end(b"'a'b'", 0), 5);
+        assert_eq!(triple_quoted_end("'''a''''", 0), Some(7));
+        assert_eq!(triple_quoted_end("'''a", 0), None);
+        assert_eq!(unquoted_end(b"a#b]c", 0), 3);
+    }
+
+    #[test]
+    fn test_find_cut_short_header() {
+        let input = "data_t\nloop_ _a _b value1 _c\n1 2 3\n";
+        let value = input.find("value1").unwrap();
+        let header = find_cut_short_header(input, input.find('2').unwrap()).unwrap();
+        assert_eq!(header.tags, 2);
+        assert_eq!(header.value, value..value + "value1".len());
+
+        // A one-row loop followed by items, with a failure further on
+        let input = "data_t\nloop_ _a 1 _c 2\n_d 3\n_e 4 5\n";
+        assert_eq!(find_cut_short_header(input, input.find('5').unwrap()), None);
+
+        // Quoted tokens and text fields are values, never tags
+        let input = "data_t\nloop_ _a _b\n;\n_c\n;\n_d 1 2\n";
+        let header = find_cut_short_header(input, input.find('2').unwrap()).unwrap();
+        assert_eq!(&input[header.value], ";\n_c\n;");
+        let input = "data_t\nloop_ _a _b '_c x' _d 1 2\n";
+        let header = find_cut_short_header(input, input.find('2').unwrap()).unwrap();
+        assert_eq!(&input[header.value], "'_c x'");
+    }
 }
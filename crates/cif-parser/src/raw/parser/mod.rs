@@ -28,27 +28,205 @@ pub(crate) mod helpers;
 pub(crate) mod loop_parser;
 pub(crate) mod value;
 
-use crate::ast::CifVersion;
+use crate::ast::{CifVersion, MagicHeader, Span};
 use crate::error::CifError;
-use crate::raw::RawDocument;
+use crate::raw::{RawBlock, RawDocument, RawValue};
+use crate::split::{find_triple, open_triple_quote};
 use crate::{CIFParser, Rule};
 use block::parse_datablock_raw;
-use helpers::{clear_line_index, extract_span, init_line_index};
+use helpers::{clear_line_index, extract_span, init_line_index, line_col_to_offset, LineIndex};
+use loop_parser::{cut_short_header_hint, find_cut_short_header};
+use pest::error::{InputLocation, LineColLocation};
 use pest::Parser;
+use std::ops::Range;
 
-/// Detect CIF version from input by scanning for magic comment.
+/// Version declared by a header found with [`find_magic_header`].
 ///
-/// CIF 2.0 files MUST start with `#\#CIF_2.0` magic comment (after optional BOM).
-/// Files without this comment are treated as CIF 1.1.
-pub(crate) fn detect_version(input: &str) -> CifVersion {
+/// CIF 2.0 files MUST start with the `#\#CIF_2.0` magic comment (after an
+/// optional BOM). Files without it, or with it on a later line, are treated
+/// as CIF 1.1.
+pub(crate) fn declared_version(header: Option<&MagicHeader>) -> CifVersion {
+    match header {
+        Some(header) if !header.misplaced => CifVersion::V2_0,
+        _ => CifVersion::V1_1,
+    }
+}
+
+/// Find the `#\#CIF_2.0` header among the leading blank and comment lines.
+///
+/// The search stops at the first line of CIF content, so the token quoted
+/// later in the file (e.g. in a text field) is never mistaken for a header.
+/// The token must be followed by whitespace or the end of the line. A header
+/// anywhere but the start of the first line is misplaced.
+pub(crate) fn find_magic_header(input: &str) -> Option<MagicHeader> {
+    let has_bom = input.starts_with('\u{FEFF}');
     let trimmed = input.trim_start_matches('\u{FEFF}'); // Remove BOM if present
-    let first_line = trimmed.lines().next().unwrap_or("");
 
-    if first_line.trim_start().starts_with("#\\#CIF_2.0") {
-        CifVersion::V2_0
-    } else {
-        CifVersion::V1_1
+    for (index, line) in trimmed.lines().enumerate() {
+        let content = line.trim_start();
+        if let Some(trailing) = content.strip_prefix(MagicHeader::TOKEN) {
+            if trailing.is_empty() || trailing.starts_with(char::is_whitespace) {
+                return Some(MagicHeader {
+                    line: index + 1,
+                    has_bom,
+                    trailing: trailing.to_string(),
+                    misplaced: index > 0 || content.len() < line.len(),
+                });
+            }
+        }
+        if !content.is_empty() && !content.starts_with('#') {
+            return None;
+        }
+    }
+    None
+}
+
+/// Find a text field that is opened but never closed.
+///
+/// Returns the 1-based line of the opening `;`. Text field delimiters are the
+/// only `;` characters allowed at the start of a line, so they alternate
+/// between opening and closing; an odd count means the last one is unclosed.
+/// In CIF 2.0 a triple-quoted string may span lines, and a `;` starting a
+/// line inside one is part of the string.
+pub(crate) fn find_unterminated_text_field(input: &str, version: CifVersion) -> Option<usize> {
+    let mut open = None;
+    let mut triple: Option<u8> = None;
+    for (index, line) in input.lines().enumerate() {
+        let from = match triple {
+            Some(quote) => match find_triple(line.as_bytes(), 0, quote) {
+                Some(end) => end,
+                None => continue,
+            },
+            None if line.starts_with(';') => {
+                open = match open {
+                    Some(_) => None,
+                    None => Some(index + 1),
+                };
+                continue;
+            }
+            None => 0,
+        };
+        triple = None;
+        if open.is_none() && version == CifVersion::V2_0 {
+            triple = open_triple_quote(line, from);
+        }
+    }
+    open
+}
+
+/// Text before the first data block that is neither blank nor a comment.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct LeadingGarbage {
+    /// Byte range of the skipped lines, from the start of the input
+    pub bytes: Range<usize>,
+    /// Number of skipped lines
+    pub lines: usize,
+    /// Span from the start of the input to the end of the last skipped line
+    pub span: Span,
+}
+
+/// Find prose, mail headers or markup before the first data block.
+///
+/// Looks for the first line starting with `data_` or the CIF 2.0 header.
+/// Everything above it is reported when any of those lines is something
+/// other than a blank line or a comment; a valid preamble is left alone.
+pub(crate) fn find_leading_garbage(input: &str) -> Option<LeadingGarbage> {
+    let mut offset = 0;
+    let mut garbage = false;
+    let mut last_line = (0, 0);
+
+    for (index, line) in input.split_inclusive('\n').enumerate() {
+        let content = line.trim_end_matches(['\r', '\n']);
+        let trimmed = content.trim_start_matches('\u{FEFF}').trim_start();
+        let starts_block = trimmed
+            .get(.."data_".len())
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case("data_"));
+        if starts_block || trimmed.starts_with(MagicHeader::TOKEN) {
+            if !garbage {
+                return None;
+            }
+            let (lines, width) = last_line;
+            return Some(LeadingGarbage {
+                bytes: 0..offset,
+                lines,
+                span: Span::new(1, 1, lines, width + 1),
+            });
+        }
+        if !trimmed.is_empty() && !trimmed.starts_with('#') {
+            garbage = true;
+        }
+        offset += line.len();
+        last_line = (index + 1, content.len());
+    }
+    None
+}
+
+/// Replace a byte range with spaces, keeping line breaks.
+///
+/// Byte offsets, line numbers and columns after the range are unchanged, so
+/// spans from parsing the result point into the original input.
+pub(crate) fn blank_out(input: &str, bytes: Range<usize>) -> String {
+    let mut result = String::with_capacity(input.len());
+    for c in input[bytes.clone()].chars() {
+        match c {
+            '\n' | '\r' => result.push(c),
+            _ => result.extend(std::iter::repeat_n(' ', c.len_utf8())),
+        }
+    }
+    result.push_str(&input[bytes.end..]);
+    result
+}
+
+/// Turn a grammar failure into the most helpful error available.
+///
+/// An unclosed text field swallows the rest of the file, so PEST reports the
+/// failure at end of input. Point at the opening `;` instead. A value among
+/// the tags of a loop header ends the header, and PEST fails later on the
+/// rows that no longer fit; point at that value.
+fn diagnose_parse_error(
+    input: &str,
+    version: CifVersion,
+    err: pest::error::Error<Rule>,
+) -> CifError {
+    let error_line = match err.line_col {
+        LineColLocation::Pos((line, _)) | LineColLocation::Span((line, _), _) => line,
+    };
+    let error_at = match err.location {
+        InputLocation::Pos(pos) | InputLocation::Span((pos, _)) => pos,
+    };
+    if let Some(line) =
+        find_unterminated_text_field(input, version).filter(|&line| line <= error_line)
+    {
+        return CifError::InvalidStructure {
+            message: "Unterminated text field: no line starting with ';' closes it, \
+                      so it runs to the end of the file"
+                .to_string(),
+            location: Some((line, 1)),
+        };
+    }
+    if let Some(header) = find_cut_short_header(input, error_at) {
+        return CifError::InvalidStructure {
+            message: format!(
+                "Loop header interrupted: {}",
+                cut_short_header_hint(&input[header.value.clone()], header.tags)
+            ),
+            location: Some(LineIndex::new(input).line_col(header.value.start)),
+        };
     }
+    err.into()
+}
+
+/// Re-parse failed input with the `file_traced` rule for a precise error.
+///
+/// `file` keeps loop bodies atomic, so PEST cannot say where inside one it
+/// failed. `file_traced` accepts the same input and builds the full tree,
+/// which gives the position and expected tokens of the actual failure.
+fn traced_parse_error(input: &str) -> Option<pest::error::Error<Rule>> {
+    let err = CIFParser::parse(Rule::file_traced, input).err()?;
+    Some(err.renamed_rules(|rule| {
+        let name = format!("{rule:?}");
+        name.strip_suffix("_traced").unwrap_or(&name).to_string()
+    }))
 }
 
 /// Parse input to raw AST (Pass 1 - version-agnostic).
@@ -57,22 +235,26 @@ pub(crate) fn detect_version(input: &str) -> CifVersion {
 /// that preserves all syntactic information from the input.
 pub(crate) fn parse_raw(input: &str) -> Result<RawDocument, CifError> {
     // Detect version for metadata (but don't use it for parsing decisions)
-    let has_cif2_magic = detect_version(input) == CifVersion::V2_0;
+    let magic_header = find_magic_header(input);
 
     // Build line index for fast line/column lookups
     init_line_index(input);
 
     // Parse with PEST
-    let pairs = CIFParser::parse(Rule::file, input)?;
+    let pairs = CIFParser::parse(Rule::file, input).map_err(|err| {
+        clear_line_index();
+        let version = declared_version(magic_header.as_ref());
+        diagnose_parse_error(input, version, traced_parse_error(input).unwrap_or(err))
+    })?;
 
     // Build raw AST
     let mut raw_doc = RawDocument::new();
-    raw_doc.has_cif2_magic = has_cif2_magic;
+    raw_doc.magic_header = magic_header;
 
     for pair in pairs {
         if pair.as_rule() == Rule::file {
             raw_doc.span = extract_span(&pair);
-            parse_file_content_raw(pair, &mut raw_doc)?;
+            parse_file_content_raw(input, pair, &mut raw_doc)?;
         }
     }
 
@@ -82,13 +264,38 @@ pub(crate) fn parse_raw(input: &str) -> Result<RawDocument, CifError> {
     Ok(raw_doc)
 }
 
-/// Parse the content of a file rule to raw blocks.
+/// Parse the data block starting at byte `start` of `input` on its own.
+///
+/// Used to reparse one block after an edit. The block is matched against
+/// the rest of the input, not just its own text, so lookaheads at its end
+/// see what a full parse would see. Spans count from the start of the
+/// block: its first character is line 1, column 1. Returns the block and
+/// the offset just past it.
+pub(crate) fn parse_block_at(input: &str, start: usize) -> Result<(RawBlock, usize), CifError> {
+    let text = &input[start..];
+    let pair = CIFParser::parse(Rule::datablock, text)?
+        .next()
+        .expect("datablock match produces a pair");
+    let end = start + pair.as_span().end();
+
+    init_line_index(&input[start..end]);
+    let block = parse_datablock_raw(pair);
+    clear_line_index();
+    Ok((block?, end))
+}
+
+/// Parse the content of a file rule to raw blocks and the text after them.
 fn parse_file_content_raw(
+    input: &str,
     pair: pest::iterators::Pair<Rule>,
     raw_doc: &mut RawDocument,
 ) -> Result<(), CifError> {
+    let mut content_end = pair.as_span().start();
     for inner_pair in pair.into_inner() {
         match inner_pair.as_rule() {
+            Rule::file_heading => {
+                content_end = inner_pair.as_span().end();
+            }
             Rule::datablock => {
                 let block = parse_datablock_raw(inner_pair)?;
                 raw_doc.blocks.push(block);
@@ -103,20 +310,107 @@ fn parse_file_content_raw(
                 }
             }
             _ => {
-                // Skip other rules (file_heading, wspace, etc.)
+                // Skip other rules (wspace, comments, etc.)
             }
         }
     }
+
+    if let Some(block) = raw_doc.blocks.last() {
+        let (line, col) = last_construct_end(block);
+        content_end = skip_closing_keywords(input, line_col_to_offset(line, col));
+    }
+    raw_doc.trailing = input[content_end..].to_string();
     Ok(())
 }
 
+/// Offset just past the last construct of a block that
+/// [`parse_block_at`] parsed from byte `start` of `input`.
+///
+/// The text from there on is the document's `trailing` when the block is
+/// the last one.
+pub(crate) fn block_content_end(input: &str, start: usize, block: &RawBlock) -> usize {
+    let text = &input[start..];
+    init_line_index(text);
+    let (line, col) = last_construct_end(block);
+    let end = skip_closing_keywords(text, line_col_to_offset(line, col));
+    clear_line_index();
+    start + end
+}
+
+/// Line and column just past the last heading, tag or value of a block
+fn last_construct_end(block: &RawBlock) -> (usize, usize) {
+    let frames = || block.frames.iter();
+    let headings = std::iter::once(block.name_span).chain(frames().map(|frame| frame.name_span));
+    let items = block
+        .items
+        .iter()
+        .chain(frames().flat_map(|frame| &frame.items))
+        .map(|item| item.value.span());
+    // Values follow the tags, so a loop ends at its last value if it has any
+    let loops = block
+        .loops
+        .iter()
+        .chain(frames().flat_map(|frame| &frame.loops))
+        .filter_map(|loop_| {
+            loop_
+                .values
+                .last()
+                .map(RawValue::span)
+                .or_else(|| loop_.tags.last().map(|tag| tag.span))
+        });
+    headings
+        .chain(items)
+        .chain(loops)
+        .map(|span| (span.end_line, span.end_col))
+        .max()
+        .unwrap_or((1, 1))
+}
+
+/// Move past the `save_` and `stop_` keywords that may close the last
+/// frame or loop, skipping the whitespace and comments between them.
+///
+/// Returns the offset just past the last keyword, or `start` if there is none.
+fn skip_closing_keywords(input: &str, start: usize) -> usize {
+    let mut end = start;
+    let mut pos = start;
+    loop {
+        let rest = &input[pos..];
+        let trimmed = rest.trim_start_matches([' ', '\t', '\r', '\n']);
+        pos += rest.len() - trimmed.len();
+        if trimmed.starts_with('#') {
+            pos += trimmed.find(['\r', '\n']).unwrap_or(trimmed.len());
+            continue;
+        }
+        let keyword = trimmed.get(..5).filter(|word| {
+            word.eq_ignore_ascii_case("save_") || word.eq_ignore_ascii_case("stop_")
+        });
+        let ends_word = trimmed[5.min(trimmed.len())..]
+            .chars()
+            .next()
+            .is_none_or(char::is_whitespace);
+        if keyword.is_none() || !ends_word {
+            return end;
+        }
+        pos += 5;
+        end = pos;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn detect_version(input: &str) -> CifVersion {
+        declared_version(find_magic_header(input).as_ref())
+    }
+
     #[test]
     fn test_detect_version_cif2() {
         assert_eq!(detect_version("#\\#CIF_2.0\ndata_test\n"), CifVersion::V2_0);
+        assert_eq!(
+            detect_version("\u{FEFF}#\\#CIF_2.0\ndata_test\n"),
+            CifVersion::V2_0
+        );
     }
 
     #[test]
@@ -124,6 +418,72 @@ mod tests {
         assert_eq!(detect_version("data_test\n"), CifVersion::V1_1);
     }
 
+    #[test]
+    fn test_detect_version_header_after_comment() {
+        assert_eq!(
+            detect_version("# written by XYZ\n#\\#CIF_2.0\ndata_test\n"),
+            CifVersion::V1_1
+        );
+        let header = find_magic_header("# written by XYZ\n\n#\\#CIF_2.0\ndata_test\n").unwrap();
+        assert_eq!(header.line, 3);
+        assert!(header.misplaced);
+    }
+
+    #[test]
+    fn test_header_after_blank_lines_misplaced() {
+        let header = find_magic_header("\n  \n#\\#CIF_2.0\ndata_test\n").unwrap();
+        assert_eq!(header.line, 3);
+        assert!(header.misplaced);
+
+        let header = find_magic_header("  #\\#CIF_2.0\ndata_test\n").unwrap();
+        assert_eq!(header.line, 1);
+        assert!(header.misplaced);
+    }
+
+    #[test]
+    fn test_find_magic_header_stops_at_content() {
+        assert!(find_magic_header("data_test\n#\\#CIF_2.0\n").is_none());
+        assert!(find_magic_header("#\\#CIF_2.01\ndata_test\n").is_none());
+    }
+
+    #[test]
+    fn test_find_unterminated_text_field_skips_triple_quotes() {
+        let input = "data_t\n_a \"\"\"x\n;\n\"\"\" _b '''y'''\n_c\n;\n";
+        assert_eq!(
+            find_unterminated_text_field(input, CifVersion::V2_0),
+            Some(6)
+        );
+        // CIF 1.1 has no triple-quoted strings
+        assert_eq!(find_unterminated_text_field(input, CifVersion::V1_1), None);
+        assert_eq!(
+            find_unterminated_text_field("data_t\n_a\n;\n'''\n;\n", CifVersion::V2_0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_find_leading_garbage() {
+        let input = "From: author@example.org\r\n<p>See below</p>\r\n\r\nDATA_x\r\n_a 1\r\n";
+        let garbage = find_leading_garbage(input).unwrap();
+        assert_eq!(garbage.bytes, 0..46);
+        assert_eq!(garbage.lines, 3);
+        assert_eq!(garbage.span, Span::new(1, 1, 3, 1));
+
+        let blanked = blank_out(input, garbage.bytes);
+        assert_eq!(blanked.len(), input.len());
+        assert_eq!(blanked.lines().count(), input.lines().count());
+        assert!(blanked.lines().take(3).all(|line| line.trim().is_empty()));
+        assert!(blanked.ends_with("DATA_x\r\n_a 1\r\n"));
+    }
+
+    #[test]
+    fn test_find_leading_garbage_ignores_valid_preamble() {
+        assert!(find_leading_garbage("# comment\n\ndata_x\n").is_none());
+        assert!(find_leading_garbage("#\\#CIF_2.0\ndata_x\n").is_none());
+        assert!(find_leading_garbage("no blocks at all\n").is_none());
+        assert!(find_leading_garbage("prose\n#\\#CIF_2.0\ndata_x\n").is_some());
+    }
+
     #[test]
     fn test_parse_raw_empty_block() {
         let cif = "data_test\n";
@@ -140,4 +500,36 @@ mod tests {
         assert_eq!(raw.blocks[0].name, "first");
         assert_eq!(raw.blocks[1].name, "second");
     }
+
+    #[test]
+    fn test_parse_raw_comments_only() {
+        let raw = parse_raw("# no blocks\n\n# at all").unwrap();
+        assert!(raw.blocks.is_empty());
+        assert_eq!(raw.trailing, "# no blocks\n\n# at all");
+
+        let raw = parse_raw("#\\#CIF_2.0\n# note\n").unwrap();
+        assert_eq!(raw.trailing, "\n# note\n");
+
+        assert_eq!(parse_raw("").unwrap().trailing, "");
+    }
+
+    #[test]
+    fn test_parse_raw_trailing_after_last_construct() {
+        let cases = [
+            ("data_test", ""),
+            ("data_test\n\n# end\n", "\n\n# end\n"),
+            ("data_test\n_a 1 # one\n\n", " # one\n\n"),
+            ("data_test\n_a\n;\ntext\n;\n  \n", "\n  \n"),
+            ("data_test\nloop_\n_b\n1\n2\n# done\n", "\n# done\n"),
+            ("data_test\nloop_\n_b\n_c\n", "\n"),
+            ("data_test\nloop_\n_b\n1\nstop_ # stop\n", " # stop\n"),
+            ("data_test\nsave_f\n_a 1\nsave_\n\n", "\n\n"),
+            ("data_test\nsave_f\n# empty\nSAVE_\t#x", "\t#x"),
+        ];
+        for (cif, trailing) in cases {
+            let raw = parse_raw(cif).unwrap();
+            assert_eq!(raw.trailing, trailing, "{cif:?}");
+            assert!(cif.ends_with(trailing));
+        }
+    }
 }
@@ -3,8 +3,9 @@
 //! This module handles parsing of CIF values from the parse tree to lossless
 //! RawValue types. Version-specific interpretation happens in the rules module.
 
+use crate::ast::Span;
 use crate::error::CifError;
-use crate::raw::parser::helpers::extract_span;
+use crate::raw::parser::helpers::{extract_span, offsets_to_span};
 use crate::raw::{
     RawListSyntax, RawQuotedString, RawTableEntry, RawTableKey, RawTableSyntax, RawTextField,
     RawTripleQuoted, RawUnquoted, RawValue,
@@ -111,6 +112,7 @@ fn parse_table_syntax_raw(pair: Pair<Rule>) -> Result<RawValue, CifError> {
 fn parse_table_entry_raw(pair: Pair<Rule>) -> Result<RawTableEntry, CifError> {
     let mut key: Option<RawTableKey> = None;
     let mut value: Option<RawValue> = None;
+    let mut space_before_colon = None;
 
     for inner_pair in pair.into_inner() {
         match inner_pair.as_rule() {
@@ -131,6 +133,15 @@ fn parse_table_entry_raw(pair: Pair<Rule>) -> Result<RawTableEntry, CifError> {
                     key = Some(RawTableKey::Quoted(q));
                 }
             }
+            // Unquoted key (kept so CIF 2.0 rules can reject it precisely)
+            Rule::table_key_unquoted => {
+                key = Some(RawTableKey::Unquoted(RawUnquoted {
+                    text: inner_pair.as_str().to_string(),
+                    span: extract_span(&inner_pair),
+                }));
+            }
+            // Whitespace before the colon
+            Rule::table_space_before_colon => space_before_colon = Some(extract_span(&inner_pair)),
             // Value
             Rule::data_value
             | Rule::value
@@ -165,14 +176,45 @@ fn parse_table_entry_raw(pair: Pair<Rule>) -> Result<RawTableEntry, CifError> {
         })
     });
 
-    Ok(RawTableEntry { key, value })
+    Ok(RawTableEntry {
+        key,
+        value,
+        space_before_colon,
+    })
 }
 
 /// Parse a triple-quoted string to RawTripleQuoted.
 fn parse_triple_quoted_raw(pair: Pair<Rule>) -> Result<RawValue, CifError> {
-    let span = extract_span(&pair);
-    let raw_content = pair.as_str().to_string();
+    Ok(triple_quoted_raw(pair.as_str(), extract_span(&pair)))
+}
+
+/// Parse a quoted string to RawQuotedString.
+fn parse_quoted_string_raw(pair: Pair<Rule>) -> Result<RawValue, CifError> {
+    Ok(quoted_string_raw(pair.as_str(), extract_span(&pair)))
+}
+
+/// Parse a text field to RawTextField.
+///
+/// The matched text starts with the line terminator before the opening `;`
+/// (absent at the start of input); the span starts at the `;` itself so it
+/// covers exactly the two delimiters and what lies between them.
+fn parse_text_field_raw(pair: Pair<Rule>) -> Result<RawValue, CifError> {
+    let text = pair.as_str();
+    let open = text.find(';').unwrap_or(0);
+    let span = offsets_to_span(pair.as_span().start() + open, pair.as_span().end());
+    Ok(text_field_raw(&text[open..], span))
+}
+
+/// Parse an unquoted string to RawUnquoted.
+fn parse_unquoted_raw(pair: Pair<Rule>) -> Result<RawValue, CifError> {
+    Ok(unquoted_raw(pair.as_str(), extract_span(&pair)))
+}
+
+// The constructors below take the matched source text, so values recognised
+// without a parse tree node (see `loop_parser`) come out exactly the same.
 
+/// Build a RawTripleQuoted from its source text, delimiters included.
+pub(crate) fn triple_quoted_raw(raw_content: &str, span: Span) -> RawValue {
     // Determine quote character
     let quote_char = if raw_content.starts_with("'''") {
         '\''
@@ -180,18 +222,15 @@ fn parse_triple_quoted_raw(pair: Pair<Rule>) -> Result<RawValue, CifError> {
         '"'
     };
 
-    Ok(RawValue::TripleQuotedString(RawTripleQuoted {
-        raw_content,
+    RawValue::TripleQuotedString(RawTripleQuoted {
+        raw_content: raw_content.to_string(),
         quote_char,
         span,
-    }))
+    })
 }
 
-/// Parse a quoted string to RawQuotedString.
-fn parse_quoted_string_raw(pair: Pair<Rule>) -> Result<RawValue, CifError> {
-    let span = extract_span(&pair);
-    let raw_content = pair.as_str().to_string();
-
+/// Build a RawQuotedString from its source text, quotes included.
+pub(crate) fn quoted_string_raw(raw_content: &str, span: Span) -> RawValue {
     // Determine quote character
     let quote_char = raw_content.chars().next().unwrap_or('\'');
 
@@ -208,34 +247,32 @@ fn parse_quoted_string_raw(pair: Pair<Rule>) -> Result<RawValue, CifError> {
         content_without_quotes.contains("\"\"")
     };
 
-    Ok(RawValue::QuotedString(RawQuotedString {
-        raw_content,
+    RawValue::QuotedString(RawQuotedString {
+        raw_content: raw_content.to_string(),
         quote_char,
         has_doubled_quotes,
         span,
-    }))
+    })
 }
 
-/// Parse a text field to RawTextField.
-fn parse_text_field_raw(pair: Pair<Rule>) -> Result<RawValue, CifError> {
-    let span = extract_span(&pair);
-    let text = pair.as_str();
-
+/// Build a RawTextField from its source text, from the opening `;` through
+/// the closing one.
+pub(crate) fn text_field_raw(text: &str, span: Span) -> RawValue {
     // Remove semicolon delimiters and trim
     let content = text.trim_start_matches(';').trim_end_matches(';').trim();
 
-    Ok(RawValue::TextField(RawTextField {
+    RawValue::TextField(RawTextField {
         content: content.to_string(),
         span,
-    }))
+    })
 }
 
-/// Parse an unquoted string to RawUnquoted.
-fn parse_unquoted_raw(pair: Pair<Rule>) -> Result<RawValue, CifError> {
-    let span = extract_span(&pair);
-    let text = pair.as_str().trim().to_string();
-
-    Ok(RawValue::Unquoted(RawUnquoted { text, span }))
+/// Build a RawUnquoted from its source text.
+pub(crate) fn unquoted_raw(text: &str, span: Span) -> RawValue {
+    RawValue::Unquoted(RawUnquoted {
+        text: text.trim().to_string(),
+        span,
+    })
 }
 
 #[cfg(test)]
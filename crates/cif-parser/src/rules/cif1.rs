@@ -4,12 +4,14 @@
 
 use std::collections::HashMap;
 
-use crate::ast::{CifBlock, CifDocument, CifFrame, CifLoop, CifValue, CifVersion, Span};
+use crate::ast::{
+    CifBlock, CifDocument, CifFrame, CifLoop, CifValue, CifVersion, QuoteStyle, Span,
+};
 use crate::raw::{
     RawBlock, RawDocument, RawFrame, RawListSyntax, RawLoop, RawQuotedString, RawTableSyntax,
     RawTextField, RawTripleQuoted, RawUnquoted, RawValue,
 };
-use crate::rules::helpers::{extract_quoted_content, parse_unquoted_value};
+use crate::rules::helpers::{extract_quoted_content, misaligned_loop, parse_unquoted_value};
 use crate::rules::{VersionRules, VersionViolation};
 
 /// CIF 1.1 version rules.
@@ -24,6 +26,8 @@ impl VersionRules for Cif1Rules {
     fn resolve(&self, raw: &RawDocument) -> Result<CifDocument, VersionViolation> {
         let mut doc = CifDocument::new_with_version(CifVersion::V1_1);
         doc.span = raw.span;
+        doc.header = raw.magic_header.clone();
+        doc.trailing = raw.trailing.clone();
 
         for raw_block in &raw.blocks {
             let block = self.resolve_block(raw_block)?;
@@ -48,33 +52,33 @@ impl VersionRules for Cif1Rules {
         // CIF 1.1: Extract content but PRESERVE doubled quotes as-is
         // (Grammar captures doubled quotes in content, and we preserve them)
         let content = extract_quoted_content(&raw.raw_content);
-        Ok(CifValue::text(content, raw.span))
+        Ok(CifValue::text(content, raw.span).with_quote(QuoteStyle::quoted(raw.quote_char, false)))
     }
 
     fn resolve_triple_quoted(&self, raw: &RawTripleQuoted) -> Result<CifValue, VersionViolation> {
         // CIF 1.1: TRANSFORMATION - treat as literal text (silent degradation)
         // Triple-quoted strings aren't a CIF 1.1 feature, so return the raw content
-        Ok(CifValue::text(raw.raw_content.clone(), raw.span))
+        Ok(CifValue::text(raw.raw_content.clone(), raw.span).with_quote(QuoteStyle::Bare))
     }
 
     fn resolve_text_field(&self, raw: &RawTextField) -> Result<CifValue, VersionViolation> {
         // Text fields are the same in both versions
-        Ok(CifValue::text(raw.content.clone(), raw.span))
+        Ok(CifValue::text(raw.content.clone(), raw.span).with_quote(QuoteStyle::TextField))
     }
 
     fn resolve_unquoted(&self, raw: &RawUnquoted) -> Result<CifValue, VersionViolation> {
         // Unquoted values are the same in both versions
-        Ok(parse_unquoted_value(&raw.text, raw.span))
+        Ok(parse_unquoted_value(&raw.text, raw.span).with_quote(QuoteStyle::Bare))
     }
 
     fn resolve_list(&self, raw: &RawListSyntax) -> Result<CifValue, VersionViolation> {
         // CIF 1.1: TRANSFORMATION - treat as literal text (silent degradation)
-        Ok(CifValue::text(raw.raw_text.clone(), raw.span))
+        Ok(CifValue::text(raw.raw_text.clone(), raw.span).with_quote(QuoteStyle::Bare))
     }
 
     fn resolve_table(&self, raw: &RawTableSyntax) -> Result<CifValue, VersionViolation> {
         // CIF 1.1: TRANSFORMATION - treat as literal text (silent degradation)
-        Ok(CifValue::text(raw.raw_text.clone(), raw.span))
+        Ok(CifValue::text(raw.raw_text.clone(), raw.span).with_quote(QuoteStyle::Bare))
     }
 
     fn validate_block_name(&self, _name: &str, _span: Span) -> Result<(), VersionViolation> {
@@ -109,7 +113,7 @@ impl VersionRules for Cif1Rules {
         }
 
         Ok(CifBlock {
-            name: raw.name.clone(),
+            name: raw.name.clone().into(),
             items,
             loops,
             frames,
@@ -133,7 +137,7 @@ impl VersionRules for Cif1Rules {
         }
 
         Ok(CifFrame {
-            name: raw.name.clone(),
+            name: raw.name.clone().into(),
             items,
             loops,
             span: raw.span,
@@ -161,15 +165,7 @@ impl VersionRules for Cif1Rules {
 
         // Validate: values must align with tags
         if !resolved_values.is_empty() && resolved_values.len() % num_tags != 0 {
-            return Err(VersionViolation::new(
-                raw.span,
-                format!(
-                    "Loop has {} tags but {} values (not divisible)",
-                    num_tags,
-                    resolved_values.len()
-                ),
-                "loop-values-misaligned",
-            ));
+            return Err(misaligned_loop(raw));
         }
 
         // Organize into rows
@@ -5,13 +5,15 @@
 
 use std::collections::HashMap;
 
-use crate::ast::{CifBlock, CifDocument, CifFrame, CifLoop, CifValue, CifVersion, Span};
+use crate::ast::{
+    CifBlock, CifDocument, CifFrame, CifLoop, CifValue, CifVersion, QuoteStyle, Span,
+};
 use crate::raw::{
     RawBlock, RawDataItem, RawDocument, RawFrame, RawListSyntax, RawLoop, RawQuotedString,
     RawTableKey, RawTableSyntax, RawTextField, RawTripleQuoted, RawUnquoted, RawValue,
 };
 use crate::rules::helpers::{
-    extract_quoted_content, extract_triple_quoted_content, parse_unquoted_value,
+    extract_quoted_content, extract_triple_quoted_content, misaligned_loop, parse_unquoted_value,
 };
 use crate::rules::{rule_ids, VersionRules, VersionViolation};
 
@@ -20,23 +22,23 @@ use crate::rules::{rule_ids, VersionRules, VersionViolation};
 /// CIF 2.0 is strict:
 /// - Empty block/frame names are NOT allowed
 /// - Doubled-quote escaping (`''` and `""`) is NOT allowed
+/// - List and table values are whitespace-separated; table keys are quoted
+///   and sit directly against the colon (`{'a':1}`), though the value may
+///   follow after whitespace (`{'a': 1}`)
 /// - Lists, tables, and triple-quoted strings are fully supported
 pub struct Cif2Rules;
 
-impl VersionRules for Cif2Rules {
-    fn resolve(&self, raw: &RawDocument) -> Result<CifDocument, VersionViolation> {
-        // CIF 2.0: VALIDATION - magic header is required
-        if !raw.has_cif2_magic {
-            return Err(VersionViolation::new(
-                raw.span,
-                "CIF 2.0 files must start with the #\\#CIF_2.0 magic header",
-                rule_ids::CIF2_MISSING_MAGIC_HEADER,
-            )
-            .with_suggestion("Add '#\\#CIF_2.0' as the first line of the file"));
-        }
-
+impl Cif2Rules {
+    /// Resolve a document without checking its magic header, for text whose
+    /// header is elsewhere, such as an archive entry after the first
+    pub(crate) fn resolve_document(
+        &self,
+        raw: &RawDocument,
+    ) -> Result<CifDocument, VersionViolation> {
         let mut doc = CifDocument::new_with_version(CifVersion::V2_0);
         doc.span = raw.span;
+        doc.header = raw.magic_header.clone();
+        doc.trailing = raw.trailing.clone();
 
         for raw_block in &raw.blocks {
             let block = self.resolve_block(raw_block)?;
@@ -45,6 +47,16 @@ impl VersionRules for Cif2Rules {
 
         Ok(doc)
     }
+}
+
+impl VersionRules for Cif2Rules {
+    fn resolve(&self, raw: &RawDocument) -> Result<CifDocument, VersionViolation> {
+        // CIF 2.0: VALIDATION - magic header is required on line 1
+        if let Some(violation) = magic_header_violation(raw) {
+            return Err(violation);
+        }
+        self.resolve_document(raw)
+    }
 
     fn resolve_value(&self, raw: &RawValue) -> Result<CifValue, VersionViolation> {
         match raw {
@@ -70,35 +82,45 @@ impl VersionRules for Cif2Rules {
 
         // CIF 2.0: TRANSFORMATION - extract content
         let content = extract_quoted_content(&raw.raw_content);
-        Ok(CifValue::text(content, raw.span))
+        Ok(CifValue::text(content, raw.span).with_quote(QuoteStyle::quoted(raw.quote_char, false)))
     }
 
     fn resolve_triple_quoted(&self, raw: &RawTripleQuoted) -> Result<CifValue, VersionViolation> {
         // CIF 2.0: TRANSFORMATION - extract content from triple quotes
         let content = extract_triple_quoted_content(&raw.raw_content);
-        Ok(CifValue::text(content, raw.span))
+        Ok(CifValue::text(content, raw.span).with_quote(QuoteStyle::quoted(raw.quote_char, true)))
     }
 
     fn resolve_text_field(&self, raw: &RawTextField) -> Result<CifValue, VersionViolation> {
         // Text fields are the same in both versions
-        Ok(CifValue::text(raw.content.clone(), raw.span))
+        Ok(CifValue::text(raw.content.clone(), raw.span).with_quote(QuoteStyle::TextField))
     }
 
     fn resolve_unquoted(&self, raw: &RawUnquoted) -> Result<CifValue, VersionViolation> {
         // Unquoted values are the same in both versions
-        Ok(parse_unquoted_value(&raw.text, raw.span))
+        Ok(parse_unquoted_value(&raw.text, raw.span).with_quote(QuoteStyle::Bare))
     }
 
     fn resolve_list(&self, raw: &RawListSyntax) -> Result<CifValue, VersionViolation> {
+        // CIF 2.0: VALIDATION - values are whitespace-separated
+        if let Some(violation) = list_syntax_violations(raw).into_iter().next() {
+            return Err(violation);
+        }
+
         // CIF 2.0: TRANSFORMATION - parse as actual list
         let mut values = Vec::new();
         for element in &raw.elements {
             values.push(self.resolve_value(element)?);
         }
-        Ok(CifValue::list(values, raw.span))
+        Ok(CifValue::list(values, raw.span).with_quote(QuoteStyle::Bare))
     }
 
     fn resolve_table(&self, raw: &RawTableSyntax) -> Result<CifValue, VersionViolation> {
+        // CIF 2.0: VALIDATION - quoted keys, no space around colons, no commas
+        if let Some(violation) = table_syntax_violations(raw).into_iter().next() {
+            return Err(violation);
+        }
+
         // CIF 2.0: TRANSFORMATION - parse as actual table
         let mut table = HashMap::new();
         for entry in &raw.entries {
@@ -116,11 +138,13 @@ impl VersionRules for Cif2Rules {
                     extract_quoted_content(&q.raw_content)
                 }
                 RawTableKey::TripleQuoted(t) => extract_triple_quoted_content(&t.raw_content),
+                // Already rejected by table_syntax_violations
+                RawTableKey::Unquoted(u) => u.text.clone(),
             };
             let value = self.resolve_value(&entry.value)?;
             table.insert(key, value);
         }
-        Ok(CifValue::table(table, raw.span))
+        Ok(CifValue::table(table, raw.span).with_quote(QuoteStyle::Bare))
     }
 
     fn validate_block_name(&self, name: &str, span: Span) -> Result<(), VersionViolation> {
@@ -174,7 +198,7 @@ impl VersionRules for Cif2Rules {
         }
 
         Ok(CifBlock {
-            name: raw.name.clone(),
+            name: raw.name.clone().into(),
             items,
             loops,
             frames,
@@ -198,7 +222,7 @@ impl VersionRules for Cif2Rules {
         }
 
         Ok(CifFrame {
-            name: raw.name.clone(),
+            name: raw.name.clone().into(),
             items,
             loops,
             span: raw.span,
@@ -226,15 +250,7 @@ impl VersionRules for Cif2Rules {
 
         // Validate: values must align with tags
         if !resolved_values.is_empty() && resolved_values.len() % num_tags != 0 {
-            return Err(VersionViolation::new(
-                raw.span,
-                format!(
-                    "Loop has {} tags but {} values (not divisible)",
-                    num_tags,
-                    resolved_values.len()
-                ),
-                "loop-values-misaligned",
-            ));
+            return Err(misaligned_loop(raw));
         }
 
         // Organize into rows
@@ -255,17 +271,8 @@ impl VersionRules for Cif2Rules {
     fn collect_violations(&self, raw: &RawDocument) -> Vec<VersionViolation> {
         let mut violations = Vec::new();
 
-        // Check for missing magic header
-        if !raw.has_cif2_magic {
-            violations.push(
-                VersionViolation::new(
-                    raw.span,
-                    "CIF 2.0 files must start with the #\\#CIF_2.0 magic header",
-                    rule_ids::CIF2_MISSING_MAGIC_HEADER,
-                )
-                .with_suggestion("Add '#\\#CIF_2.0' as the first line of the file"),
-            );
-        }
+        // Check for missing or misplaced magic header
+        violations.extend(magic_header_violation(raw));
 
         for block in &raw.blocks {
             // Check block name (skip for global_ blocks)
@@ -299,6 +306,33 @@ impl VersionRules for Cif2Rules {
     }
 }
 
+/// Check that the magic header is present and on line 1.
+fn magic_header_violation(raw: &RawDocument) -> Option<VersionViolation> {
+    if raw.has_cif2_magic() {
+        return None;
+    }
+    let violation = match &raw.magic_header {
+        Some(header) => VersionViolation::new(
+            Span::new(header.line, 1, header.line, header.to_string().len() + 1),
+            format!(
+                "CIF 2.0 header must be on line 1, but was found on line {}",
+                header.line
+            ),
+            rule_ids::CIF2_MAGIC_HEADER_NOT_FIRST_LINE,
+        )
+        .with_suggestion(
+            "Move '#\\#CIF_2.0' to the first line; only a byte-order mark may precede it",
+        ),
+        None => VersionViolation::new(
+            raw.span,
+            "CIF 2.0 files must start with the #\\#CIF_2.0 magic header",
+            rule_ids::CIF2_MISSING_MAGIC_HEADER,
+        )
+        .with_suggestion("Add '#\\#CIF_2.0' as the first line of the file"),
+    };
+    Some(violation)
+}
+
 /// Collect violations from data items.
 fn collect_item_violations(items: &[RawDataItem], violations: &mut Vec<VersionViolation>) {
     for item in items {
@@ -316,24 +350,24 @@ fn collect_loop_violations(loop_: &RawLoop, violations: &mut Vec<VersionViolatio
 /// Recursively collect violations from a value.
 fn collect_value_violations(value: &RawValue, violations: &mut Vec<VersionViolation>) {
     match value {
-        RawValue::QuotedString(qs) => {
-            if qs.has_doubled_quotes {
-                violations.push(
-                    VersionViolation::new(
-                        qs.span,
-                        "Doubled-quote escaping not allowed in CIF 2.0",
-                        rule_ids::CIF2_NO_DOUBLED_QUOTES,
-                    )
-                    .with_suggestion("Use triple-quoted strings: '''...'''"),
-                );
-            }
+        RawValue::QuotedString(qs) if qs.has_doubled_quotes => {
+            violations.push(
+                VersionViolation::new(
+                    qs.span,
+                    "Doubled-quote escaping not allowed in CIF 2.0",
+                    rule_ids::CIF2_NO_DOUBLED_QUOTES,
+                )
+                .with_suggestion("Use triple-quoted strings: '''...'''"),
+            );
         }
         RawValue::ListSyntax(list) => {
+            violations.extend(list_syntax_violations(list));
             for element in &list.elements {
                 collect_value_violations(element, violations);
             }
         }
         RawValue::TableSyntax(table) => {
+            violations.extend(table_syntax_violations(table));
             for entry in &table.entries {
                 // Check key
                 if let RawTableKey::Quoted(q) = &entry.key {
@@ -356,3 +390,92 @@ fn collect_value_violations(value: &RawValue, violations: &mut Vec<VersionViolat
         _ => {}
     }
 }
+
+/// Check the separators of a list (not its nested values).
+fn list_syntax_violations(list: &RawListSyntax) -> Vec<VersionViolation> {
+    let first_comma = list.elements.iter().find_map(|element| match element {
+        RawValue::Unquoted(u) => comma_span(u),
+        _ => None,
+    });
+
+    first_comma
+        .map(|span| {
+            let violation = VersionViolation::new(
+                span,
+                "CIF 2.0 lists are whitespace-separated; remove the commas",
+                rule_ids::CIF2_COMMA_SEPARATOR,
+            );
+            vec![match without_commas(&list.raw_text) {
+                Some(fixed) => violation.with_suggestion(format!("Write the list as {}", fixed)),
+                None => violation.with_suggestion("Separate list values with spaces"),
+            }]
+        })
+        .unwrap_or_default()
+}
+
+/// Check the keys, colons, and separators of a table (not its nested values).
+fn table_syntax_violations(table: &RawTableSyntax) -> Vec<VersionViolation> {
+    let mut violations = Vec::new();
+
+    for entry in &table.entries {
+        if let RawTableKey::Unquoted(key) = &entry.key {
+            violations.push(
+                VersionViolation::new(
+                    key.span,
+                    format!("CIF 2.0 table keys must be quoted, found '{}'", key.text),
+                    rule_ids::CIF2_TABLE_UNQUOTED_KEY,
+                )
+                .with_suggestion(format!("Write the key as '{}'", key.text)),
+            );
+        }
+        if let Some(span) = entry.space_before_colon {
+            violations.push(
+                VersionViolation::new(
+                    span,
+                    "CIF 2.0 does not allow whitespace between a table key and its colon",
+                    rule_ids::CIF2_TABLE_SPACE_BEFORE_COLON,
+                )
+                .with_suggestion("Remove the space before ':'"),
+            );
+        }
+        if let RawValue::Unquoted(value) = &entry.value {
+            if let Some(span) = comma_span(value) {
+                violations.push(
+                    VersionViolation::new(
+                        span,
+                        "CIF 2.0 table entries are whitespace-separated; remove the commas",
+                        rule_ids::CIF2_COMMA_SEPARATOR,
+                    )
+                    .with_suggestion("Separate table entries with spaces"),
+                );
+            }
+        }
+    }
+
+    violations
+}
+
+/// Span of the first comma in a single-line unquoted value.
+fn comma_span(value: &RawUnquoted) -> Option<Span> {
+    let offset = value.text.find(',')?;
+    let col = value.span.start_col + offset;
+    Some(Span::new(
+        value.span.start_line,
+        col,
+        value.span.start_line,
+        col + 1,
+    ))
+}
+
+/// Rewrite `[1, 2,3]` as `[1 2 3]`, unless quoted values make that unsafe.
+fn without_commas(raw_text: &str) -> Option<String> {
+    if raw_text.contains(['\'', '"']) {
+        return None;
+    }
+    let fixed: Vec<&str> = raw_text
+        .split(',')
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .collect();
+    Some(fixed.join(" "))
+}
@@ -1,6 +1,9 @@
 //! Helper functions for version rule implementations.
 
+use super::VersionViolation;
 use crate::ast::{CifValue, Span};
+use crate::raw::parser::loop_parser::cut_short_header_hint;
+use crate::raw::{RawLoop, RawValue};
 
 /// Extract content from a quoted string (remove surrounding quotes).
 ///
@@ -54,16 +57,40 @@ pub fn parse_unquoted_value(text: &str, span: Span) -> CifValue {
         return CifValue::not_applicable(span);
     }
 
-    // Try parsing as number
-    if let Ok(num) = trimmed.parse::<f64>() {
-        return CifValue::numeric(num, span);
-    }
-
-    // Try uncertainty notation like "1.234(5)"
-    if let Some((value, uncertainty)) = CifValue::parse_with_uncertainty(trimmed) {
-        return CifValue::numeric_with_uncertainty(value, uncertainty, span);
+    // Numbers, with optional uncertainty notation like "1.234(5)"
+    match CifValue::parse_number(trimmed) {
+        Some((value, None)) => return CifValue::numeric(value, span),
+        Some((value, Some(uncertainty))) => {
+            return CifValue::numeric_with_uncertainty(value, uncertainty, span)
+        }
+        None => {}
     }
 
     // Fall back to text
     CifValue::text(trimmed.to_string(), span)
 }
+
+/// Violation for a loop whose values do not fill whole rows.
+///
+/// A body shorter than one row usually means a value ended the header
+/// early: `loop_ _a _b value1 _c 1` reads `value1` as the whole body and
+/// `_c` as a data item. The violation then points at that value.
+pub fn misaligned_loop(raw: &RawLoop) -> VersionViolation {
+    let message = format!(
+        "Loop has {} tags but {} values (not divisible)",
+        raw.tags.len(),
+        raw.values.len()
+    );
+    match raw.values.first() {
+        Some(first) if raw.values.len() < raw.tags.len() => {
+            let text = match first {
+                RawValue::Unquoted(u) => u.text.as_str(),
+                RawValue::QuotedString(q) => q.raw_content.as_str(),
+                _ => "the first value",
+            };
+            VersionViolation::new(first.span(), message, "loop-values-misaligned")
+                .with_suggestion(cut_short_header_hint(text, raw.tags.len()))
+        }
+        _ => VersionViolation::new(raw.span, message, "loop-values-misaligned"),
+    }
+}
@@ -22,12 +22,22 @@
 mod cif1;
 mod cif2;
 mod helpers;
+mod notes;
 
 pub use cif1::Cif1Rules;
 pub use cif2::Cif2Rules;
+pub use notes::{collect_notes, note_ids, numeric_notes, ParseNote};
 
 use std::fmt;
 
+mod sealed {
+    /// Prevents [`VersionRules`](super::VersionRules) from being implemented downstream.
+    pub trait Sealed {}
+
+    impl Sealed for super::Cif1Rules {}
+    impl Sealed for super::Cif2Rules {}
+}
+
 use crate::ast::{CifBlock, CifDocument, CifFrame, CifLoop, CifValue, Span};
 use crate::raw::{
     RawBlock, RawDocument, RawFrame, RawListSyntax, RawLoop, RawQuotedString, RawTableSyntax,
@@ -44,13 +54,22 @@ use crate::raw::{
 ///
 /// | Construct | CIF 1.1 | CIF 2.0 |
 /// |-----------|---------|---------|
+/// | `#\#CIF_2.0` below a comment | N/A | ❌ Error |
 /// | Empty block name | ✅ Allowed | ❌ Error |
 /// | Empty frame name | ✅ Allowed | ❌ Error |
 /// | Lists `[...]` | → Text | → List |
 /// | Tables `{...}` | → Text | → Table |
 /// | Triple quotes | → Text | → Text (parsed) |
 /// | Doubled quotes `''` | ✅ Unescape | ❌ Error |
-pub trait VersionRules {
+/// | Commas in `[...]`/`{...}` | → Text | ❌ Error |
+/// | Unquoted key / space around `:` | → Text | ❌ Error |
+///
+/// # Sealed
+///
+/// This trait is sealed: the set of CIF versions is fixed by the
+/// specifications, so [`Cif1Rules`] and [`Cif2Rules`] are its only
+/// implementations. It can be called but not implemented outside this crate.
+pub trait VersionRules: sealed::Sealed {
     /// Resolve a raw document to a typed CifDocument.
     fn resolve(&self, raw: &RawDocument) -> Result<CifDocument, VersionViolation>;
 
@@ -166,6 +185,9 @@ pub mod rule_ids {
     /// CIF 2.0 files MUST start with the `#\#CIF_2.0` magic header.
     pub const CIF2_MISSING_MAGIC_HEADER: &str = "cif2-missing-magic-header";
 
+    /// The `#\#CIF_2.0` magic header must be on line 1 (only a BOM may precede it).
+    pub const CIF2_MAGIC_HEADER_NOT_FIRST_LINE: &str = "cif2-magic-header-not-first-line";
+
     /// CIF 2.0 does not allow doubled-quote escaping (`''` or `""`).
     pub const CIF2_NO_DOUBLED_QUOTES: &str = "cif2-no-doubled-quotes";
 
@@ -174,4 +196,13 @@ pub mod rule_ids {
 
     /// CIF 2.0 requires non-empty save frame names.
     pub const CIF2_NO_EMPTY_FRAME_NAME: &str = "cif2-no-empty-frame-name";
+
+    /// CIF 2.0 list and table values are separated by whitespace, not commas.
+    pub const CIF2_COMMA_SEPARATOR: &str = "cif2-comma-separator";
+
+    /// CIF 2.0 table keys must be quoted strings.
+    pub const CIF2_TABLE_UNQUOTED_KEY: &str = "cif2-table-unquoted-key";
+
+    /// CIF 2.0 does not allow whitespace between a table key and its colon.
+    pub const CIF2_TABLE_SPACE_BEFORE_COLON: &str = "cif2-table-space-before-colon";
 }
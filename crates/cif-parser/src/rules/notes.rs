@@ -0,0 +1,342 @@
+//! Informational notes about values that resolved with caveats.
+//!
+//! Notes never make a document invalid. They flag places where the typed
+//! value is not a faithful copy of what was written, such as numbers with
+//! more significant digits than an `f64` can hold, and names and values
+//! longer than other tools may accept.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::analysis::{MAX_DATA_NAME_LENGTH, MAX_LINE_LENGTH};
+use crate::ast::{CaseInsensitiveName, CifValue, Span};
+use crate::raw::{RawDataItem, RawDocument, RawLoop, RawValue};
+
+/// A non-fatal observation about how a value was resolved.
+#[derive(Debug, Clone)]
+pub struct ParseNote {
+    /// Source location of the value
+    pub span: Span,
+    /// Human-readable description
+    pub message: String,
+    /// Machine-readable note identifier
+    pub note_id: &'static str,
+    /// Another location the note refers to, such as the first of two
+    /// duplicate tags
+    pub related_span: Option<Span>,
+}
+
+impl ParseNote {
+    /// Create a new note with the given span, message, and note ID.
+    pub fn new(span: Span, message: impl Into<String>, note_id: &'static str) -> Self {
+        Self {
+            span,
+            message: message.into(),
+            note_id,
+            related_span: None,
+        }
+    }
+
+    /// Add another location the note refers to.
+    pub fn with_related_span(mut self, span: Span) -> Self {
+        self.related_span = Some(span);
+        self
+    }
+}
+
+impl fmt::Display for ParseNote {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[{}] {} at line {}, column {}",
+            self.note_id, self.message, self.span.start_line, self.span.start_col
+        )
+    }
+}
+
+/// Machine-readable identifiers for parse notes.
+pub mod note_ids {
+    /// A number has more significant digits than `f64` can represent.
+    pub const NUMERIC_PRECISION_EXCEEDED: &str = "numeric-precision-exceeded";
+
+    /// A nonzero standard uncertainty resolved to exactly zero.
+    pub const UNCERTAINTY_UNDERFLOW: &str = "uncertainty-underflow";
+
+    /// Text before the first data block was skipped.
+    pub const LEADING_CONTENT_SKIPPED: &str = "leading-content-skipped";
+
+    /// A tag appears more than once in one loop header.
+    pub const LOOP_DUPLICATE_TAG: &str = "loop-duplicate-tag";
+
+    /// Two data blocks have the same name, ignoring case.
+    pub const DUPLICATE_BLOCK_NAME: &str = "duplicate-block-name";
+
+    /// Two save frames in one block have the same name, ignoring case.
+    pub const DUPLICATE_FRAME_NAME: &str = "duplicate-frame-name";
+
+    /// A data name is longer than
+    /// [`MAX_DATA_NAME_LENGTH`](crate::analysis::MAX_DATA_NAME_LENGTH)
+    /// characters, the CIF 1.1 limit.
+    pub const LONG_DATA_NAME: &str = "long-data-name";
+
+    /// An unquoted value is longer than a line may be
+    /// ([`MAX_LINE_LENGTH`](crate::analysis::MAX_LINE_LENGTH) characters).
+    pub const LONG_UNQUOTED_VALUE: &str = "long-unquoted-value";
+}
+
+/// Significant decimal digits an `f64` can round-trip
+const F64_SIGNIFICANT_DIGITS: usize = 17;
+
+/// Collect notes for every value in a raw document.
+///
+/// List and table elements are only inspected when `containers` is true,
+/// since CIF 1.1 resolves container syntax to plain text.
+pub fn collect_notes(raw: &RawDocument, containers: bool) -> Vec<ParseNote> {
+    let mut notes = Vec::new();
+
+    let blocks = raw
+        .blocks
+        .iter()
+        .filter(|block| !block.is_global)
+        .map(|block| (block.name.as_str(), block.name_span));
+    collect_duplicate_names(
+        blocks,
+        "Data block",
+        note_ids::DUPLICATE_BLOCK_NAME,
+        &mut notes,
+    );
+
+    for block in &raw.blocks {
+        let frames = block
+            .frames
+            .iter()
+            .map(|frame| (frame.name.as_str(), frame.name_span));
+        collect_duplicate_names(
+            frames,
+            "Save frame",
+            note_ids::DUPLICATE_FRAME_NAME,
+            &mut notes,
+        );
+
+        collect_item_notes(&block.items, containers, &mut notes);
+        for loop_ in &block.loops {
+            collect_loop_notes(loop_, containers, &mut notes);
+        }
+        for frame in &block.frames {
+            collect_item_notes(&frame.items, containers, &mut notes);
+            for loop_ in &frame.loops {
+                collect_loop_notes(loop_, containers, &mut notes);
+            }
+        }
+    }
+
+    notes
+}
+
+/// Note every name that repeats an earlier one, ignoring case
+fn collect_duplicate_names<'a>(
+    names: impl Iterator<Item = (&'a str, Span)>,
+    kind: &str,
+    note_id: &'static str,
+    notes: &mut Vec<ParseNote>,
+) {
+    let mut first_seen: HashMap<CaseInsensitiveName, Span> = HashMap::new();
+    for (name, span) in names {
+        match first_seen.get(&CaseInsensitiveName::from(name)) {
+            Some(first) => notes.push(
+                ParseNote::new(
+                    span,
+                    format!(
+                        "{} name '{}' is already used (first at line {}, column {})",
+                        kind, name, first.start_line, first.start_col
+                    ),
+                    note_id,
+                )
+                .with_related_span(*first),
+            ),
+            None => {
+                first_seen.insert(name.into(), span);
+            }
+        }
+    }
+}
+
+fn collect_item_notes(items: &[RawDataItem], containers: bool, notes: &mut Vec<ParseNote>) {
+    for item in items {
+        notes.extend(data_name_note(&item.tag, item.tag_span));
+        collect_value_notes(&item.value, containers, notes);
+    }
+}
+
+fn collect_loop_notes(loop_: &RawLoop, containers: bool, notes: &mut Vec<ParseNote>) {
+    for (i, tag) in loop_.tags.iter().enumerate() {
+        notes.extend(data_name_note(&tag.name, tag.span));
+        let first = loop_.tags[..i]
+            .iter()
+            .find(|earlier| earlier.name.eq_ignore_ascii_case(&tag.name));
+        if let Some(first) = first {
+            notes.push(
+                ParseNote::new(
+                    tag.span,
+                    format!(
+                        "Tag '{}' appears more than once in the loop header \
+                         (first at line {}, column {})",
+                        tag.name, first.span.start_line, first.span.start_col
+                    ),
+                    note_ids::LOOP_DUPLICATE_TAG,
+                )
+                .with_related_span(first.span),
+            );
+        }
+    }
+    for value in &loop_.values {
+        collect_value_notes(value, containers, notes);
+    }
+}
+
+fn collect_value_notes(value: &RawValue, containers: bool, notes: &mut Vec<ParseNote>) {
+    match value {
+        RawValue::Unquoted(u) => {
+            notes.extend(unquoted_length_note(&u.text, u.span));
+            notes.extend(numeric_notes(u.text.trim(), u.span));
+        }
+        RawValue::ListSyntax(list) if containers => {
+            for element in &list.elements {
+                collect_value_notes(element, containers, notes);
+            }
+        }
+        RawValue::TableSyntax(table) if containers => {
+            for entry in &table.entries {
+                collect_value_notes(&entry.value, containers, notes);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Note a data name longer than CIF 1.1 allows.
+///
+/// CIF 2.0 drops the limit, but tools written for CIF 1.1 may truncate or
+/// reject longer names, so both versions note it.
+fn data_name_note(name: &str, span: Span) -> Option<ParseNote> {
+    let length = name.chars().count();
+    (length > MAX_DATA_NAME_LENGTH).then(|| {
+        ParseNote::new(
+            span,
+            format!(
+                "Data name is {length} characters long; CIF 1.1 allows at most \
+                 {MAX_DATA_NAME_LENGTH}"
+            ),
+            note_ids::LONG_DATA_NAME,
+        )
+    })
+}
+
+/// Note an unquoted value too long to fit on a line.
+fn unquoted_length_note(text: &str, span: Span) -> Option<ParseNote> {
+    let length = text.chars().count();
+    (length > MAX_LINE_LENGTH).then(|| {
+        ParseNote::new(
+            span,
+            format!(
+                "Unquoted value is {length} characters long, more than the \
+                 {MAX_LINE_LENGTH} a line may hold; write it as a text field"
+            ),
+            note_ids::LONG_UNQUOTED_VALUE,
+        )
+    })
+}
+
+/// Check a numeric token for precision loss.
+///
+/// Returns no notes for tokens that do not resolve to a number.
+pub fn numeric_notes(text: &str, span: Span) -> Vec<ParseNote> {
+    let mut notes = Vec::new();
+
+    let (number, su_digits) = match text.strip_suffix(')').and_then(|t| t.rsplit_once('(')) {
+        Some((number, digits)) => (number, Some(digits)),
+        None => (text, None),
+    };
+
+    let Some((_, uncertainty)) = CifValue::parse_number(text) else {
+        return notes;
+    };
+
+    let digits = significant_digits(number);
+    if digits > F64_SIGNIFICANT_DIGITS {
+        notes.push(ParseNote::new(
+            span,
+            format!(
+                "Numeric value '{}' has {} significant digits; only about {} are kept",
+                number, digits, F64_SIGNIFICANT_DIGITS
+            ),
+            note_ids::NUMERIC_PRECISION_EXCEEDED,
+        ));
+    }
+
+    let su_is_nonzero = su_digits.is_some_and(|d| d.bytes().any(|b| matches!(b, b'1'..=b'9')));
+    if su_is_nonzero && uncertainty == Some(0.0) {
+        notes.push(ParseNote::new(
+            span,
+            format!(
+                "Standard uncertainty in '{}' is too small to represent and became 0",
+                text
+            ),
+            note_ids::UNCERTAINTY_UNDERFLOW,
+        ));
+    }
+
+    notes
+}
+
+/// Count significant digits in a numeric token's mantissa.
+///
+/// Leading zeros are not significant; trailing zeros are, since CIF uses
+/// them to state precision (`1.2300`).
+fn significant_digits(number: &str) -> usize {
+    let mantissa = number.split(['e', 'E']).next().unwrap_or_default();
+
+    mantissa
+        .bytes()
+        .filter(u8::is_ascii_digit)
+        .skip_while(|&b| b == b'0')
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_significant_digits() {
+        assert_eq!(significant_digits("1.2300"), 5);
+        assert_eq!(significant_digits("-0.00012"), 2);
+        assert_eq!(significant_digits("6.022e23"), 4);
+        assert_eq!(significant_digits("0"), 0);
+    }
+
+    #[test]
+    fn test_precision_note() {
+        let notes = numeric_notes("1.5405981234567890123", Span::default());
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes[0].note_id, note_ids::NUMERIC_PRECISION_EXCEEDED);
+
+        assert!(numeric_notes("1.54059812345678", Span::default()).is_empty());
+    }
+
+    #[test]
+    fn test_uncertainty_underflow_note() {
+        let notes = numeric_notes("1.0e-323(1)", Span::default());
+        assert!(notes
+            .iter()
+            .any(|n| n.note_id == note_ids::UNCERTAINTY_UNDERFLOW));
+
+        assert!(numeric_notes("1.0(0)", Span::default()).is_empty());
+    }
+
+    #[test]
+    fn test_text_has_no_notes() {
+        assert!(numeric_notes("C12345678901234567890", Span::default()).is_empty());
+        assert!(numeric_notes("Fe(3)", Span::default()).is_empty());
+    }
+}
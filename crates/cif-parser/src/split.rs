@@ -0,0 +1,381 @@
+//! Splitting concatenated CIF archives into their entries.
+//!
+//! Database dumps such as the COD archives join thousands of single-block
+//! CIFs into one file. [`split_entries`] finds the `data_` heading of each
+//! entry with a line scan that skips text fields, quoted strings and
+//! comments, without parsing anything. Each [`EntrySlice`] can then be
+//! written back out on its own or parsed with [`parse_entry`].
+//!
+//! ```
+//! use cif_parser::split::{parse_entry, split_entries};
+//!
+//! let archive = "data_a\n_note\n;\ndata_trap\n;\ndata_b\n_x 2\n";
+//! let entries = split_entries(archive);
+//! assert_eq!(entries.len(), 2);
+//! assert_eq!(entries[1].name, "b");
+//! assert_eq!(entries[1].line_range, 6..8);
+//!
+//! let doc = parse_entry(archive, &entries[1])?;
+//! assert_eq!(doc.blocks[0].get_item("_x").unwrap().as_numeric(), Some(2.0));
+//! # Ok::<(), cif_parser::CifError>(())
+//! ```
+//!
+//! An entry runs from its heading to the start of the next entry. It also
+//! owns the comment lines directly above its heading, so the banner each
+//! file starts with travels with it; when those comments include a
+//! `#\#CIF_2.0` header the entry starts at the header instead, keeping the
+//! entry a valid CIF 2.0 file. The first entry starts at the beginning of
+//! the input.
+//!
+//! An archive that starts with a `#\#CIF_2.0` header is CIF 2.0 throughout:
+//! triple-quoted strings are skipped while scanning, and every entry is
+//! parsed as CIF 2.0 whether or not it repeats the header. In a CIF 1.1
+//! archive `'''` is an ordinary quote, and only entries with their own
+//! header are CIF 2.0.
+
+use std::ops::Range;
+
+use crate::ast::{CifDocument, CifVersion, MagicHeader};
+use crate::error::CifError;
+use crate::ParseOptions;
+
+/// One entry of a concatenated archive
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntrySlice {
+    /// Block name from the entry's `data_` heading
+    pub name: String,
+    /// Byte range of the entry in the archive
+    pub byte_range: Range<usize>,
+    /// 1-based lines of the entry, end exclusive
+    pub line_range: Range<usize>,
+    /// Version the entry is read as: CIF 2.0 if it starts at its own
+    /// `#\#CIF_2.0` header or the archive starts with one
+    pub version: CifVersion,
+}
+
+impl EntrySlice {
+    /// The entry's text within `input`, the archive it was found in
+    pub fn text<'a>(&self, input: &'a str) -> &'a str {
+        &input[self.byte_range.clone()]
+    }
+}
+
+/// Find the entries of a concatenated archive without parsing it.
+///
+/// Returns one slice per top-level `data_` heading, in order. A `data_`
+/// inside a text field, quoted string or comment is not a heading. Input
+/// without any heading has no entries.
+pub fn split_entries(input: &str) -> Vec<EntrySlice> {
+    let archive_version = match input.lines().next() {
+        Some(first) if is_magic_header(first.trim_start_matches('\u{FEFF}')) => CifVersion::V2_0,
+        _ => CifVersion::V1_1,
+    };
+    let mut headings: Vec<Heading> = Vec::new();
+    let mut state = ScanState::Code;
+    // Start of the comment lines above the next heading, as (byte, line),
+    // and whether they include a `#\#CIF_2.0` header
+    let mut comments: Option<(usize, usize, bool)> = None;
+    // Version of the entry being scanned
+    let mut version = archive_version;
+    let mut offset = 0;
+    let mut lines = 0;
+
+    for (index, raw_line) in input.split_inclusive('\n').enumerate() {
+        let line_no = index + 1;
+        let line = raw_line.trim_end_matches(['\r', '\n']);
+        let start = offset;
+        offset += raw_line.len();
+        lines = line_no;
+
+        let (line, bom) = match index {
+            0 => match line.strip_prefix('\u{FEFF}') {
+                Some(rest) => (rest, '\u{FEFF}'.len_utf8()),
+                None => (line, 0),
+            },
+            _ => (line, 0),
+        };
+
+        let from = match state {
+            ScanState::TextField => {
+                if line.starts_with(';') {
+                    state = ScanState::Code;
+                }
+                comments = None;
+                continue;
+            }
+            ScanState::TripleQuoted(quote) => {
+                comments = None;
+                match find_triple(line.as_bytes(), 0, quote) {
+                    Some(end) => {
+                        state = ScanState::Code;
+                        end
+                    }
+                    None => continue,
+                }
+            }
+            ScanState::Code => {
+                if line.starts_with(';') {
+                    state = ScanState::TextField;
+                    comments = None;
+                    continue;
+                }
+                let trimmed = line.trim_start();
+                if trimmed.is_empty() {
+                    continue;
+                }
+                if trimmed.starts_with('#') {
+                    if is_magic_header(trimmed) {
+                        comments = Some((start, line_no, true));
+                    } else if comments.is_none() {
+                        comments = Some((start, line_no, false));
+                    }
+                    continue;
+                }
+                0
+            }
+        };
+
+        let scan = scan_line(line, from, version == CifVersion::V2_0);
+        if let Some(new_state) = scan.state {
+            state = new_state;
+        }
+        let owner = comments.take();
+        push_headings(
+            &mut headings,
+            scan.headings,
+            Line {
+                text: line,
+                start: start + bom,
+                number: line_no,
+            },
+            owner,
+            archive_version,
+        );
+        if let Some(last) = headings.last() {
+            version = last.version;
+        }
+    }
+
+    let total_lines = lines + 1;
+    let mut entries: Vec<EntrySlice> = Vec::with_capacity(headings.len());
+    for (i, heading) in headings.into_iter().enumerate() {
+        let (byte_start, line_start) = if i == 0 {
+            (0, 1)
+        } else {
+            (heading.byte_start, heading.line_start)
+        };
+        if let Some(previous) = entries.last_mut() {
+            previous.byte_range.end = byte_start;
+            previous.line_range.end = line_start;
+        }
+        entries.push(EntrySlice {
+            name: heading.name,
+            byte_range: byte_start..input.len(),
+            line_range: line_start..total_lines,
+            version: heading.version,
+        });
+    }
+    entries
+}
+
+/// Parse one entry that [`split_entries`] found in `input`.
+///
+/// Parses the entry's text as its [`version`](EntrySlice::version), so the
+/// entries of a CIF 2.0 archive stay CIF 2.0 without repeating the header.
+/// Spans in the result count from the start of the entry; add
+/// `entry.line_range.start - 1` to a line to find it in the archive.
+pub fn parse_entry(input: &str, entry: &EntrySlice) -> Result<CifDocument, CifError> {
+    crate::parse_with_default_version(entry.text(input), ParseOptions::default(), entry.version)
+        .map(|result| result.document)
+}
+
+/// Parse every entry of an archive, in parallel.
+///
+/// Returns one result per entry of [`split_entries`], in the same order, so
+/// a bad entry does not stop the others from parsing.
+#[cfg(feature = "rayon")]
+pub fn parse_entries_parallel(input: &str) -> Vec<Result<CifDocument, CifError>> {
+    use rayon::prelude::*;
+
+    split_entries(input)
+        .par_iter()
+        .map(|entry| parse_entry(input, entry))
+        .collect()
+}
+
+/// What the scanner is inside of at the end of a line
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ScanState {
+    Code,
+    TextField,
+    TripleQuoted(u8),
+}
+
+/// A `data_` heading found by the scan
+struct Heading {
+    name: String,
+    /// Where the entry starts: the heading, or the comments above it
+    byte_start: usize,
+    line_start: usize,
+    version: CifVersion,
+}
+
+/// A line of the archive
+struct Line<'a> {
+    text: &'a str,
+    /// Byte offset of `text` in the archive
+    start: usize,
+    /// 1-based line number
+    number: usize,
+}
+
+/// Result of scanning one line outside text fields
+#[derive(Default)]
+struct LineScan {
+    /// Block name and byte offset within the line of each `data_` heading
+    headings: Vec<(String, usize)>,
+    /// State the line leaves the scanner in, if it opens a multi-line string
+    state: Option<ScanState>,
+}
+
+/// Record the headings found on a line.
+///
+/// A heading alone at the start of its line takes the comment lines above
+/// it (`owner`), and is CIF 2.0 if they include a header; one after other
+/// content on the line starts at the heading. The first entry always has
+/// the archive's version, since it starts at the top of the archive.
+fn push_headings(
+    headings: &mut Vec<Heading>,
+    found: Vec<(String, usize)>,
+    line: Line,
+    owner: Option<(usize, usize, bool)>,
+    archive_version: CifVersion,
+) {
+    for (name, column) in found {
+        let (byte_start, line_start, own_header) = if !line.text[..column].trim().is_empty() {
+            (line.start + column, line.number, false)
+        } else {
+            owner.unwrap_or((line.start, line.number, false))
+        };
+        let version = if own_header && !headings.is_empty() {
+            CifVersion::V2_0
+        } else {
+            archive_version
+        };
+        headings.push(Heading {
+            name,
+            byte_start,
+            line_start,
+            version,
+        });
+    }
+}
+
+/// Scan a line from byte `from` for `data_` headings.
+///
+/// Skips quoted strings and stops at a comment. Three quotes open a
+/// triple-quoted string only in CIF 2.0 (`cif2`).
+fn scan_line(line: &str, from: usize, cif2: bool) -> LineScan {
+    let bytes = line.as_bytes();
+    let mut scan = LineScan::default();
+    let mut i = from;
+    // Whether the previous byte ends a token, so a new one may start here
+    let mut boundary = true;
+    // Whether a quote here would open a string
+    let mut opener = true;
+
+    while i < bytes.len() {
+        let c = bytes[i];
+        if c == b' ' || c == b'\t' {
+            boundary = true;
+            opener = true;
+            i += 1;
+            continue;
+        }
+        if boundary && c == b'#' {
+            break;
+        }
+        if opener && (c == b'\'' || c == b'"') {
+            if cif2 && bytes[i..].starts_with(&[c, c, c]) {
+                match find_triple(bytes, i + 3, c) {
+                    Some(end) => i = end,
+                    None => {
+                        scan.state = Some(ScanState::TripleQuoted(c));
+                        break;
+                    }
+                }
+            } else {
+                i = find_closing_quote(bytes, i + 1, c);
+            }
+            boundary = false;
+            opener = false;
+            continue;
+        }
+        if boundary && is_heading(&bytes[i..]) {
+            let name_start = i + "data_".len();
+            let name_end = bytes[name_start..]
+                .iter()
+                .position(|b| *b == b' ' || *b == b'\t')
+                .map_or(bytes.len(), |end| name_start + end);
+            scan.headings
+                .push((line[name_start..name_end].to_string(), i));
+            i = name_end;
+            continue;
+        }
+        boundary = false;
+        opener = matches!(c, b'[' | b'{' | b':');
+        i += 1;
+    }
+    scan
+}
+
+/// Quote character of a triple-quoted string left open at the end of a
+/// line, scanning from byte `from`
+pub(crate) fn open_triple_quote(line: &str, from: usize) -> Option<u8> {
+    match scan_line(line, from, true).state {
+        Some(ScanState::TripleQuoted(quote)) => Some(quote),
+        _ => None,
+    }
+}
+
+/// Whether a line (without leading whitespace) is a `#\#CIF_2.0` header
+fn is_magic_header(line: &str) -> bool {
+    line.strip_prefix(MagicHeader::TOKEN)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with(char::is_whitespace))
+}
+
+/// Whether `bytes` starts with `data_`, in any case
+fn is_heading(bytes: &[u8]) -> bool {
+    bytes
+        .get(.."data_".len())
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case(b"data_"))
+}
+
+/// Offset just past the triple quote closing a string, searching from `from`
+pub(crate) fn find_triple(bytes: &[u8], from: usize, quote: u8) -> Option<usize> {
+    bytes
+        .get(from..)?
+        .windows(3)
+        .position(|w| w == [quote, quote, quote])
+        .map(|pos| from + pos + 3)
+}
+
+/// Offset just past the quote closing a string, or the end of the line.
+///
+/// A closing quote is followed by whitespace, the end of the line or a
+/// CIF 2.0 delimiter, which covers both CIF 1.1 quotes with embedded quote
+/// characters and CIF 2.0 strings inside lists and tables.
+fn find_closing_quote(bytes: &[u8], from: usize, quote: u8) -> usize {
+    let mut i = from;
+    while i < bytes.len() {
+        if bytes[i] == quote
+            && bytes
+                .get(i + 1)
+                .is_none_or(|next| matches!(next, b' ' | b'\t' | b']' | b'}' | b':'))
+        {
+            return i + 1;
+        }
+        i += 1;
+    }
+    bytes.len()
+}
@@ -2,10 +2,17 @@
 //!
 //! This module provides JavaScript-compatible wrappers around the core CIF parsing
 //! functionality, using wasm-bindgen for seamless interop with JavaScript.
+//!
+//! A parsed document is held once behind an `Arc`; block, frame and loop
+//! objects are views holding the shared document and indices into it.
 
-use crate::{CifBlock, CifDocument, CifFrame, CifLoop, CifValue, CifValueKind, CifVersion};
+use crate::{
+    parse_string_with_options, CifBlock, CifDocument, CifError, CifFrame, CifLoop, CifValue,
+    CifValueKind, CifVersion, ParseOptions, ParseResult,
+};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
+use std::sync::Arc;
 use wasm_bindgen::prelude::*;
 
 // Console logging for debugging
@@ -133,7 +140,7 @@ pub struct JsCifValue {
     numeric_value: Option<f64>,
     uncertainty_value: Option<f64>,
     list_value: Option<Vec<JsCifValue>>,
-    table_value: Option<HashMap<String, JsCifValue>>,
+    table_value: Option<BTreeMap<String, JsCifValue>>,
     span: JsSpan,
 }
 
@@ -231,7 +238,7 @@ impl JsCifValue {
     }
 
     /// Get the table value as a JavaScript object (if this is a table value)
-    /// Returns the serialized table or undefined if not a table
+    /// Returns the serialized table, keys sorted, or undefined if not a table
     #[wasm_bindgen(getter)]
     pub fn table_value(&self) -> JsValue {
         match &self.table_value {
@@ -318,10 +325,39 @@ impl From<&CifValue> for JsCifValue {
     }
 }
 
+/// Index of an element, found by reference, within its slice
+fn position_of<T>(items: &[T], item: &T) -> Option<usize> {
+    items
+        .iter()
+        .position(|candidate| std::ptr::eq(candidate, item))
+}
+
+/// Where a loop sits inside its document
+#[derive(Clone, Copy)]
+enum LoopOwner {
+    /// Loop directly in the block at this index
+    Block(usize),
+    /// Loop in a save frame: block index, frame index
+    Frame(usize, usize),
+}
+
 /// JavaScript-compatible representation of a CIF loop
 #[wasm_bindgen]
 pub struct JsCifLoop {
-    inner: CifLoop,
+    doc: Arc<CifDocument>,
+    owner: LoopOwner,
+    index: usize,
+}
+
+impl JsCifLoop {
+    /// The loop this view refers to
+    fn inner(&self) -> &CifLoop {
+        let loops = match self.owner {
+            LoopOwner::Block(block) => &self.doc.blocks[block].loops,
+            LoopOwner::Frame(block, frame) => &self.doc.blocks[block].frames[frame].loops,
+        };
+        &loops[self.index]
+    }
 }
 
 #[wasm_bindgen]
@@ -329,19 +365,19 @@ impl JsCifLoop {
     /// Get the tag names (column headers)
     #[wasm_bindgen(getter)]
     pub fn tags(&self) -> Vec<String> {
-        self.inner.tags.clone()
+        self.inner().tags.clone()
     }
 
     /// Get the number of rows
     #[wasm_bindgen(getter = numRows)]
     pub fn num_rows(&self) -> usize {
-        self.inner.len()
+        self.inner().len()
     }
 
     /// Get the number of columns
     #[wasm_bindgen(getter = numColumns)]
     pub fn num_columns(&self) -> usize {
-        self.inner.tags.len()
+        self.inner().tags.len()
     }
 
     /// Get the tag names (column headers) - method alias for compatibility
@@ -365,19 +401,19 @@ impl JsCifLoop {
     /// Get a value by row and column index
     #[wasm_bindgen]
     pub fn get_value(&self, row: usize, col: usize) -> Option<JsCifValue> {
-        self.inner.get(row, col).map(|v| v.into())
+        self.inner().get(row, col).map(|v| v.into())
     }
 
     /// Get a value by row index and tag name
     #[wasm_bindgen]
     pub fn get_value_by_tag(&self, row: usize, tag: &str) -> Option<JsCifValue> {
-        self.inner.get_by_tag(row, tag).map(|v| v.into())
+        self.inner().get_by_tag(row, tag).map(|v| v.into())
     }
 
     /// Get all values for a specific tag as an array
     #[wasm_bindgen]
     pub fn get_column(&self, tag: &str) -> Option<Vec<JsCifValue>> {
-        self.inner
+        self.inner()
             .get_column(tag)
             .map(|values| values.iter().map(|v| (*v).into()).collect())
     }
@@ -388,13 +424,13 @@ impl JsCifLoop {
         use js_sys::Object;
         use wasm_bindgen::JsValue;
 
-        if row >= self.inner.len() {
+        if row >= self.inner().len() {
             return Err(JsValue::from_str("Row index out of bounds"));
         }
 
         let obj = Object::new();
-        for (col, tag) in self.inner.tags.iter().enumerate() {
-            if let Some(value) = self.inner.get(row, col) {
+        for (col, tag) in self.inner().tags.iter().enumerate() {
+            if let Some(value) = self.inner().get(row, col) {
                 let js_value: JsCifValue = value.into();
                 let _ = js_sys::Reflect::set(
                     &obj,
@@ -409,20 +445,31 @@ impl JsCifLoop {
     /// Check if the loop is empty
     #[wasm_bindgen]
     pub fn is_empty(&self) -> bool {
-        self.inner.is_empty()
-    }
-}
-
-impl From<CifLoop> for JsCifLoop {
-    fn from(loop_: CifLoop) -> Self {
-        JsCifLoop { inner: loop_ }
+        self.inner().is_empty()
     }
 }
 
 /// JavaScript-compatible representation of a CIF frame
 #[wasm_bindgen]
 pub struct JsCifFrame {
-    inner: CifFrame,
+    doc: Arc<CifDocument>,
+    block: usize,
+    index: usize,
+}
+
+impl JsCifFrame {
+    /// The frame this view refers to
+    fn inner(&self) -> &CifFrame {
+        &self.doc.blocks[self.block].frames[self.index]
+    }
+
+    fn loop_view(&self, index: usize) -> JsCifLoop {
+        JsCifLoop {
+            doc: Arc::clone(&self.doc),
+            owner: LoopOwner::Frame(self.block, self.index),
+            index,
+        }
+    }
 }
 
 #[wasm_bindgen]
@@ -430,19 +477,22 @@ impl JsCifFrame {
     /// Get the frame name
     #[wasm_bindgen(getter)]
     pub fn name(&self) -> String {
-        self.inner.name.clone()
+        self.inner().name.to_string()
     }
 
-    /// Get all item keys
+    /// Get all item keys, in document order
     #[wasm_bindgen(getter = itemKeys)]
     pub fn item_keys(&self) -> Vec<String> {
-        self.inner.items.keys().cloned().collect()
+        self.inner()
+            .items_iter()
+            .map(|(key, _)| key.clone())
+            .collect()
     }
 
     /// Get the number of loops in this frame
     #[wasm_bindgen(getter = numLoops)]
     pub fn num_loops(&self) -> usize {
-        self.inner.loops.len()
+        self.inner().loops.len()
     }
 
     /// Get all item keys - method alias for compatibility
@@ -454,7 +504,7 @@ impl JsCifFrame {
     /// Get an item value by key
     #[wasm_bindgen]
     pub fn get_item(&self, key: &str) -> Option<JsCifValue> {
-        self.inner.items.get(key).map(|v| v.into())
+        self.inner().items.get(key).map(|v| v.into())
     }
 
     /// Get the number of loops in this frame - method alias for compatibility
@@ -466,20 +516,45 @@ impl JsCifFrame {
     /// Get a loop by index
     #[wasm_bindgen]
     pub fn get_loop(&self, index: usize) -> Option<JsCifLoop> {
-        self.inner.loops.get(index).cloned().map(|l| l.into())
-    }
-}
-
-impl From<CifFrame> for JsCifFrame {
-    fn from(frame: CifFrame) -> Self {
-        JsCifFrame { inner: frame }
+        (index < self.inner().loops.len()).then(|| self.loop_view(index))
     }
 }
 
 /// JavaScript-compatible representation of a CIF block
 #[wasm_bindgen]
 pub struct JsCifBlock {
-    inner: CifBlock,
+    doc: Arc<CifDocument>,
+    index: usize,
+}
+
+impl JsCifBlock {
+    fn new(doc: &Arc<CifDocument>, index: usize) -> Self {
+        JsCifBlock {
+            doc: Arc::clone(doc),
+            index,
+        }
+    }
+
+    /// The block this view refers to
+    fn inner(&self) -> &CifBlock {
+        &self.doc.blocks[self.index]
+    }
+
+    fn loop_view(&self, index: usize) -> JsCifLoop {
+        JsCifLoop {
+            doc: Arc::clone(&self.doc),
+            owner: LoopOwner::Block(self.index),
+            index,
+        }
+    }
+
+    fn frame_view(&self, index: usize) -> JsCifFrame {
+        JsCifFrame {
+            doc: Arc::clone(&self.doc),
+            block: self.index,
+            index,
+        }
+    }
 }
 
 #[wasm_bindgen]
@@ -487,25 +562,28 @@ impl JsCifBlock {
     /// Get the block name
     #[wasm_bindgen(getter)]
     pub fn name(&self) -> String {
-        self.inner.name.clone()
+        self.inner().name.to_string()
     }
 
-    /// Get all item keys
+    /// Get all item keys, in document order
     #[wasm_bindgen(getter = itemKeys)]
     pub fn item_keys(&self) -> Vec<String> {
-        self.inner.items.keys().cloned().collect()
+        self.inner()
+            .items_iter()
+            .map(|(key, _)| key.clone())
+            .collect()
     }
 
     /// Get the number of loops in this block
     #[wasm_bindgen(getter = numLoops)]
     pub fn num_loops(&self) -> usize {
-        self.inner.loops.len()
+        self.inner().loops.len()
     }
 
     /// Get the number of frames in this block
     #[wasm_bindgen(getter = numFrames)]
     pub fn num_frames(&self) -> usize {
-        self.inner.frames.len()
+        self.inner().frames.len()
     }
 
     /// Get all item keys - method alias for compatibility
@@ -517,7 +595,7 @@ impl JsCifBlock {
     /// Get an item value by key
     #[wasm_bindgen]
     pub fn get_item(&self, key: &str) -> Option<JsCifValue> {
-        self.inner.items.get(key).map(|v| v.into())
+        self.inner().items.get(key).map(|v| v.into())
     }
 
     /// Get the number of loops in this block - method alias for compatibility
@@ -529,19 +607,23 @@ impl JsCifBlock {
     /// Get a loop by index
     #[wasm_bindgen]
     pub fn get_loop(&self, index: usize) -> Option<JsCifLoop> {
-        self.inner.loops.get(index).cloned().map(|l| l.into())
+        (index < self.inner().loops.len()).then(|| self.loop_view(index))
     }
 
     /// Find a loop containing a specific tag
     #[wasm_bindgen]
     pub fn find_loop(&self, tag: &str) -> Option<JsCifLoop> {
-        self.inner.find_loop(tag).cloned().map(|l| l.into())
+        let block = self.inner();
+        block
+            .find_loop(tag)
+            .and_then(|found| position_of(&block.loops, found))
+            .map(|index| self.loop_view(index))
     }
 
     /// Get all loop tags in this block
     #[wasm_bindgen]
     pub fn get_loop_tags(&self) -> Vec<String> {
-        self.inner.get_loop_tags().into_iter().cloned().collect()
+        self.inner().get_loop_tags().into_iter().cloned().collect()
     }
 
     /// Get the number of frames in this block - method alias for compatibility
@@ -553,20 +635,58 @@ impl JsCifBlock {
     /// Get a frame by index
     #[wasm_bindgen]
     pub fn get_frame(&self, index: usize) -> Option<JsCifFrame> {
-        self.inner.frames.get(index).cloned().map(|f| f.into())
+        (index < self.inner().frames.len()).then(|| self.frame_view(index))
     }
-}
 
-impl From<CifBlock> for JsCifBlock {
-    fn from(block: CifBlock) -> Self {
-        JsCifBlock { inner: block }
+    /// Get common crystallographic metadata (formula, space group, cell, ...)
+    ///
+    /// Returns a plain object whose measured fields are `{ value, uncertainty }`
+    /// and whose missing fields are `null`.
+    #[wasm_bindgen]
+    pub fn summary(&self) -> Result<JsValue, JsValue> {
+        self.inner()
+            .summary()
+            .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .map_err(|e| JsValue::from_str(&e.to_string()))
     }
 }
 
+/// Format a CifError with its location and log it to the console
+fn cif_error_to_js(err: CifError) -> JsValue {
+    let error_msg = match err {
+        CifError::ParseError(msg) => {
+            format!("Parse error: {}", msg)
+        }
+        CifError::IoError(err) => {
+            format!("IO error: {}", err)
+        }
+        CifError::InvalidStructure { message, location } => {
+            if let Some((line, col)) = location {
+                format!(
+                    "Invalid structure at line {}, col {}: {}",
+                    line, col, message
+                )
+            } else {
+                format!("Invalid structure: {}", message)
+            }
+        }
+    };
+    console_log!("{}", error_msg);
+    js_sys::Error::new(&error_msg).into()
+}
+
 /// JavaScript-compatible representation of a CIF document
 #[wasm_bindgen]
 pub struct JsCifDocument {
-    inner: CifDocument,
+    inner: Arc<CifDocument>,
+}
+
+impl JsCifDocument {
+    fn new(doc: CifDocument) -> Self {
+        JsCifDocument {
+            inner: Arc::new(doc),
+        }
+    }
 }
 
 #[wasm_bindgen]
@@ -579,31 +699,9 @@ impl JsCifDocument {
         match CifDocument::parse(input) {
             Ok(doc) => {
                 console_log!("Successfully parsed {} blocks", doc.blocks.len());
-                Ok(JsCifDocument { inner: doc })
-            }
-            Err(e) => {
-                // Format error message with location info if available
-                let error_msg = match e {
-                    crate::CifError::ParseError(msg) => {
-                        format!("Parse error: {}", msg)
-                    }
-                    crate::CifError::IoError(err) => {
-                        format!("IO error: {}", err)
-                    }
-                    crate::CifError::InvalidStructure { message, location } => {
-                        if let Some((line, col)) = location {
-                            format!(
-                                "Invalid structure at line {}, col {}: {}",
-                                line, col, message
-                            )
-                        } else {
-                            format!("Invalid structure: {}", message)
-                        }
-                    }
-                };
-                console_log!("{}", error_msg);
-                Err(js_sys::Error::new(&error_msg).into())
+                Ok(JsCifDocument::new(doc))
             }
+            Err(e) => Err(cif_error_to_js(e)),
         }
     }
 
@@ -640,7 +738,11 @@ impl JsCifDocument {
     /// Get all block names
     #[wasm_bindgen(getter = blockNames)]
     pub fn block_names(&self) -> Vec<String> {
-        self.inner.blocks.iter().map(|b| b.name.clone()).collect()
+        self.inner
+            .blocks
+            .iter()
+            .map(|b| b.name.to_string())
+            .collect()
     }
 
     /// Get the number of blocks - method alias for compatibility
@@ -652,19 +754,22 @@ impl JsCifDocument {
     /// Get a block by index
     #[wasm_bindgen]
     pub fn get_block(&self, index: usize) -> Option<JsCifBlock> {
-        self.inner.blocks.get(index).cloned().map(|b| b.into())
+        (index < self.inner.blocks.len()).then(|| JsCifBlock::new(&self.inner, index))
     }
 
-    /// Get a block by name
+    /// Get a block by name, ignoring case
     #[wasm_bindgen]
     pub fn get_block_by_name(&self, name: &str) -> Option<JsCifBlock> {
-        self.inner.get_block(name).cloned().map(|b| b.into())
+        self.inner
+            .get_block(name)
+            .and_then(|found| position_of(&self.inner.blocks, found))
+            .map(|index| JsCifBlock::new(&self.inner, index))
     }
 
     /// Get the first block (common for single-block CIF files)
     #[wasm_bindgen]
     pub fn first_block(&self) -> Option<JsCifBlock> {
-        self.inner.first_block().cloned().map(|b| b.into())
+        self.get_block(0)
     }
 
     /// Get the first block - method alias for compatibility
@@ -680,6 +785,96 @@ impl JsCifDocument {
     }
 }
 
+/// Options accepted by `parseWithOptions`; missing fields default to false
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct JsParseOptions {
+    upgrade_guidance: bool,
+    skip_leading_garbage: bool,
+}
+
+/// JavaScript-compatible representation of a parse note
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsParseNote {
+    note_id: &'static str,
+    message: String,
+    span: JsSpan,
+    related_span: Option<JsSpan>,
+}
+
+/// Result of `parseWithOptions`: the document plus upgrade issues and notes
+#[wasm_bindgen]
+pub struct JsParseResult {
+    document: Arc<CifDocument>,
+    upgrade_issues: Vec<String>,
+    notes: Vec<JsParseNote>,
+}
+
+#[wasm_bindgen]
+impl JsParseResult {
+    /// The parsed document
+    #[wasm_bindgen(getter)]
+    pub fn document(&self) -> JsCifDocument {
+        JsCifDocument {
+            inner: Arc::clone(&self.document),
+        }
+    }
+
+    /// Changes needed for CIF 2.0, if upgrade guidance was requested
+    #[wasm_bindgen(getter = upgradeIssues)]
+    pub fn upgrade_issues(&self) -> Vec<String> {
+        self.upgrade_issues.clone()
+    }
+
+    /// Informational notes as `{ noteId, message, span, relatedSpan }` objects
+    #[wasm_bindgen(getter)]
+    pub fn notes(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.notes).unwrap_or(JsValue::NULL)
+    }
+}
+
+impl From<ParseResult> for JsParseResult {
+    fn from(result: ParseResult) -> Self {
+        JsParseResult {
+            document: Arc::new(result.document),
+            upgrade_issues: result
+                .upgrade_issues
+                .iter()
+                .map(|issue| issue.to_string())
+                .collect(),
+            notes: result
+                .notes
+                .into_iter()
+                .map(|note| JsParseNote {
+                    note_id: note.note_id,
+                    message: note.message,
+                    span: note.span.into(),
+                    related_span: note.related_span.map(Into::into),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Parse a CIF string with options such as `{ skipLeadingGarbage: true }`
+#[wasm_bindgen(js_name = parseWithOptions)]
+pub fn parse_with_options(content: &str, options: JsValue) -> Result<JsParseResult, JsValue> {
+    let options: JsParseOptions = if options.is_undefined() || options.is_null() {
+        JsParseOptions::default()
+    } else {
+        serde_wasm_bindgen::from_value(options)
+            .map_err(|e| JsValue::from(js_sys::Error::new(&format!("Invalid options: {}", e))))?
+    };
+    let options = ParseOptions::new()
+        .upgrade_guidance(options.upgrade_guidance)
+        .skip_leading_garbage(options.skip_leading_garbage);
+
+    parse_string_with_options(content, options)
+        .map(Into::into)
+        .map_err(cif_error_to_js)
+}
+
 /// Initialize the WASM module (optional, for any setup needed)
 #[wasm_bindgen(start)]
 pub fn main() {
@@ -0,0 +1,35 @@
+// tests/api_visibility.rs
+// The prelude covers parsing, inspecting values, and upgrade guidance.
+// Compile-fail checks for sealed/non-exhaustive items live in `tests/ui` (see compile_fail.rs).
+
+use cif_parser::prelude::*;
+
+fn describe(value: &CifValue) -> &'static str {
+    match value.kind {
+        CifValueKind::Numeric(_) | CifValueKind::NumericWithUncertainty { .. } => "number",
+        CifValueKind::Text(_) => "text",
+        _ => "other",
+    }
+}
+
+#[test]
+fn test_prelude_covers_common_workflow() -> Result<(), CifError> {
+    let doc: CifDocument = parse_string("data_t\n_cell_length_a 10.5(2)\n")?;
+    let block: &CifBlock = doc.first_block().unwrap();
+    assert_eq!(
+        describe(block.get_item("_cell_length_a").unwrap()),
+        "number"
+    );
+
+    let summary: BlockSummary = block.summary();
+    let a: Option<Measurand> = summary.cell[0];
+    assert_eq!(a, Some(Measurand::with_uncertainty(10.5, 0.2)));
+
+    let result: ParseResult =
+        parse_string_with_options("data_t\n_x 1\n", ParseOptions::new().upgrade_guidance(true))?;
+    let _: &[VersionViolation] = &result.upgrade_issues;
+    let _: &[ParseNote] = &result.notes;
+    let _: Span = block.span;
+    let _: CifVersion = doc.version;
+    Ok(())
+}
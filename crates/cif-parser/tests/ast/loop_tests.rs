@@ -224,3 +224,23 @@ a b c
     assert_eq!(block.loops[0].tags.len(), 2);
     assert_eq!(block.loops[1].tags.len(), 3);
 }
+
+#[test]
+fn test_loop_source_lines() {
+    // One row per line: source lines match logical rows
+    let cif = "data_test\nloop_\n_a\n_b\n1 2\n3 4\n";
+    let doc = CifDocument::parse(cif).unwrap();
+    let loop_ = &doc.first_block().unwrap().loops[0];
+    let lines = loop_.source_lines();
+    assert_eq!(lines.len(), 2);
+    assert!(lines.iter().all(|line| line.len() == 2));
+
+    // A row split over two lines is reported as laid out in the file
+    let cif = "data_test\nloop_\n_a\n_b\n1 2 3\n4\n";
+    let doc = CifDocument::parse(cif).unwrap();
+    let loop_ = &doc.first_block().unwrap().loops[0];
+    let lines = loop_.source_lines();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0].len(), 3);
+    assert_eq!(lines[1][0].as_numeric(), Some(4.0));
+}
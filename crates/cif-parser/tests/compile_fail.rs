@@ -0,0 +1,12 @@
+// tests/compile_fail.rs
+// Code that must not compile against the public API: matches on
+// non-exhaustive enums without a wildcard, impls of sealed traits, and
+// imports of types that are no longer exported. Each case in `tests/ui`
+// has the compiler output it is expected to give; regenerate it with
+// `TRYBUILD=overwrite cargo test -p cif-parser --test compile_fail`.
+
+#[test]
+fn test_api_visibility_compile_failures() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
@@ -2,6 +2,28 @@
 //!
 //! - Real-world CIF files: ccdc_paracetamol, cod_urea, crystalmaker_luag, pycifrw_xanthine
 //! - Synthetic tests: inline CIF content testing parser features
+//! - High precision: numbers at the limits of f64 and their parse notes
+//! - Numeric forms: signs, bare decimal points, exponents and uncertainties
+//! - Loop headers: values ending a header early, duplicate tags
+//! - Block summary: common crystallographic metadata from real-world files
+//! - Feature report: version-sensitive constructs in CIF 1.1 and 2.0 fixtures
+//! - Leading garbage: prose before the first data block, skipped on request
+//! - Quote style: the delimiters each value was written with
+//! - Value paths: walking nested lists and tables and addressing values by path
+//! - Text fields: ';' delimiters after LF, CR LF and comments, and mid-line ';'
+//! - Tag renaming: bulk renames across items, loops and save frames
+//! - Ordering: items and table entries in document order, identical JSON per parse
+//! - Partial reparse: text edits applied block by block match a full reparse
+//! - Serialized: stable serde form of documents, golden files per fixture
+//! - Archive split: entries of concatenated archives, found without parsing
+//! - CSV export: loops as CSV and TSV tables, and back
+//! - Block names: case-insensitive block and frame lookups and duplicates
+//! - Long values: over-long data names and unquoted values, and parse time
+//! - Block links: pointers between blocks by powder block id or block code
+//! - Empty documents: no blocks, blocks without items, trailing comments
+//! - Column stats: numeric loop column statistics and anomaly screening
+//! - Number format: numbers and uncertainties written back as CIF text
+//! - Examples: the runnable examples on their bundled inputs
 
 use std::path::PathBuf;
 
@@ -25,6 +47,70 @@ mod integration {
     // Synthetic inline CIF tests
     pub mod synthetic_tests;
 
+    // Numeric precision limits
+    pub mod high_precision;
+    pub mod numeric_forms;
+
+    // Loop header diagnostics
+    pub mod loop_headers;
+
+    // Common metadata extraction
+    pub mod block_summary;
+
+    // Version-sensitive construct inventory
+    pub mod feature_report;
+
+    // Non-CIF text before the first block
+    pub mod leading_garbage;
+
+    // Delimiters recorded per value
+    pub mod quote_style;
+
+    // Paths into nested lists and tables
+    pub mod value_paths;
+
+    // Text field delimiters at line starts
+    pub mod text_fields;
+
+    // Bulk tag renames
+    pub mod tag_renaming;
+
+    // Stable iteration and serialization order
+    pub mod ordering;
+
+    // Edits reparsed one block at a time
+    pub mod partial_reparse;
+
+    // Stable serialized form
+    pub mod serialized;
+
+    // Entries of concatenated archives
+    pub mod archive_split;
+
+    // Loops as CSV tables
+    pub mod csv_export;
+
+    // Case-insensitive block and frame names
+    pub mod block_names;
+
+    // Length limits on data names and unquoted values
+    pub mod long_values;
+
+    // Pointers between data blocks
+    pub mod block_links;
+
+    // Documents and blocks with nothing in them
+    pub mod empty_documents;
+
+    // Statistics and anomalies of numeric columns
+    pub mod column_stats;
+
+    // Writing numbers and uncertainties back as CIF text
+    pub mod number_format;
+
+    // Runnable examples
+    pub mod examples;
+
     // Shared fixtures tests (designed for parity with Python/JS)
     pub mod shared_fixtures;
 }
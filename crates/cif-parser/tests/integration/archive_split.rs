@@ -0,0 +1,181 @@
+// tests/integration/archive_split.rs
+// Integration tests for splitting concatenated CIF archives into entries
+
+use cif_parser::split::{parse_entry, split_entries};
+use cif_parser::CifVersion;
+
+const ARCHIVE: &str = "\
+#------------------------------------------------------------------------------
+# first entry
+data_first
+_cell_length_a 5.43
+_publ_section_comment
+;
+Results are listed below.
+data_trap
+_cell_length_a 99
+;
+_title 'quoted data_trap'
+loop_
+_atom_site_label
+_atom_site_fract_x
+C1 0.1 # data_trap in a comment
+#------------------------------------------------------------------------------
+# second entry
+
+# end of banner
+data_second
+_cell_length_a 6.00
+#\\#CIF_2.0
+# third entry, a CIF 2.0 file
+DATA_third
+_list ['data_trap' \"data_trap\"]
+_text '''
+data_trap
+'''
+_cell_length_a 7.00
+";
+
+#[test]
+fn test_text_fields_and_quotes_do_not_split() {
+    let entries = split_entries(ARCHIVE);
+    let names: Vec<&str> = entries.iter().map(|e| e.name.as_str()).collect();
+    assert_eq!(names, ["first", "second", "third"]);
+}
+
+#[test]
+fn test_entries_cover_the_archive() {
+    let entries = split_entries(ARCHIVE);
+
+    assert_eq!(entries[0].byte_range.start, 0);
+    assert_eq!(entries[2].byte_range.end, ARCHIVE.len());
+    for pair in entries.windows(2) {
+        assert_eq!(pair[0].byte_range.end, pair[1].byte_range.start);
+        assert_eq!(pair[0].line_range.end, pair[1].line_range.start);
+    }
+    let joined: String = entries.iter().map(|e| e.text(ARCHIVE)).collect();
+    assert_eq!(joined, ARCHIVE);
+
+    // Banners and headers above a heading belong to its entry
+    assert_eq!(entries[0].line_range, 1..16);
+    assert_eq!(entries[1].line_range, 16..22);
+    assert!(entries[1].text(ARCHIVE).starts_with("#----"));
+    assert_eq!(entries[2].line_range, 22..30);
+    assert!(entries[2].text(ARCHIVE).starts_with("#\\#CIF_2.0\n"));
+}
+
+#[test]
+fn test_each_entry_parses_on_its_own() {
+    let entries = split_entries(ARCHIVE);
+    let docs: Vec<_> = entries
+        .iter()
+        .map(|e| parse_entry(ARCHIVE, e).unwrap())
+        .collect();
+
+    let first = docs[0].first_block().unwrap();
+    assert_eq!(
+        first.get_item("_cell_length_a").unwrap().as_numeric(),
+        Some(5.43)
+    );
+    assert!(first
+        .get_item("_publ_section_comment")
+        .unwrap()
+        .as_string()
+        .unwrap()
+        .contains("data_trap"));
+
+    assert_eq!(docs[1].blocks.len(), 1);
+    assert_eq!(docs[1].version, CifVersion::V1_1);
+    assert_eq!(entries[1].version, CifVersion::V1_1);
+    assert_eq!(entries[2].version, CifVersion::V2_0);
+
+    // Spans count from the start of the entry
+    let third = docs[2].first_block().unwrap();
+    assert_eq!(docs[2].version, CifVersion::V2_0);
+    assert_eq!(third.span.start_line, 3);
+    assert_eq!(third.span.start_line + entries[2].line_range.start - 1, 24);
+}
+
+#[test]
+fn test_cif2_archive_entries_stay_cif2() {
+    let archive = "#\\#CIF_2.0\ndata_a\n_x [1 2]\ndata_b\n_y {'k':1}\n_z '''\ndata_trap\n'''\n";
+    let entries = split_entries(archive);
+    let names: Vec<&str> = entries.iter().map(|e| e.name.as_str()).collect();
+    assert_eq!(names, ["a", "b"]);
+
+    // The second entry has no header of its own but is still CIF 2.0
+    assert!(!entries[1].text(archive).contains("CIF_2.0"));
+    assert_eq!(entries[1].version, CifVersion::V2_0);
+    let doc = parse_entry(archive, &entries[1]).unwrap();
+    assert_eq!(doc.version, CifVersion::V2_0);
+    let block = doc.first_block().unwrap();
+    assert!(block.get_item("_y").unwrap().as_table().is_some());
+    assert_eq!(
+        block.get_item("_z").unwrap().as_string(),
+        Some("\ndata_trap\n")
+    );
+}
+
+#[test]
+fn test_cif1_triple_quote_is_an_ordinary_quote() {
+    let archive = "data_a\n_x '''\ndata_b\n_y 1\n";
+    let entries = split_entries(archive);
+    let names: Vec<&str> = entries.iter().map(|e| e.name.as_str()).collect();
+    assert_eq!(names, ["a", "b"]);
+    assert!(entries.iter().all(|e| e.version == CifVersion::V1_1));
+
+    let doc = parse_entry(archive, &entries[0]).unwrap();
+    assert_eq!(
+        doc.first_block()
+            .unwrap()
+            .get_item("_x")
+            .unwrap()
+            .as_string(),
+        Some("'")
+    );
+}
+
+#[test]
+fn test_heading_after_content_on_a_line() {
+    let input = "data_a _x 1 data_b\n_y 2\n";
+    let entries = split_entries(input);
+
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[1].name, "b");
+    assert_eq!(entries[0].text(input), "data_a _x 1 ");
+    assert_eq!(entries[1].text(input), "data_b\n_y 2\n");
+}
+
+#[test]
+fn test_input_without_entries() {
+    assert!(split_entries("").is_empty());
+    assert!(split_entries("# only a comment\n").is_empty());
+    assert!(split_entries(";\ndata_trap\n").is_empty());
+}
+
+#[test]
+fn test_crlf_archive() {
+    let input = "data_a\r\n_x\r\n;\r\ndata_trap\r\n;\r\ndata_b\r\n_y 2\r\n";
+    let entries = split_entries(input);
+
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[1].text(input), "data_b\r\n_y 2\r\n");
+    assert_eq!(entries[1].line_range, 6..8);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_parse_entries_parallel() {
+    use cif_parser::split::parse_entries_parallel;
+
+    let broken = format!("{ARCHIVE}data_broken\nloop_\n");
+    let results = parse_entries_parallel(&broken);
+
+    assert_eq!(results.len(), 4);
+    let names: Vec<&str> = results[..3]
+        .iter()
+        .map(|r| r.as_ref().unwrap().blocks[0].name.as_str())
+        .collect();
+    assert_eq!(names, ["first", "second", "third"]);
+    assert!(results[3].is_err());
+}
@@ -0,0 +1,94 @@
+// tests/integration/block_links.rs
+// Pointers between data blocks: powder block ids and audit link block codes
+
+use crate::fixture_path;
+use cif_parser::{BlockLinkTags, CifDocument};
+
+const PHASE_ID: &str = "2023-05-11T10:15|CORUNDUM|A.Smith|D8";
+const XRD_ID: &str = "2023-05-11T10:15|CORUNDUM_XRD|A.Smith|D8";
+const NPD_ID: &str = "2023-05-12T09:40|CORUNDUM_NPD|A.Smith|POLARIS";
+
+fn powder() -> CifDocument {
+    CifDocument::from_file(fixture_path("powder_links.cif")).unwrap()
+}
+
+#[test]
+fn test_powder_links_resolve_by_block_id() {
+    let links = powder().block_links();
+
+    let found: Vec<(usize, &str, &str, Option<usize>)> = links
+        .iter()
+        .map(|l| {
+            (
+                l.from_block,
+                l.tag.as_str(),
+                l.target_id.as_str(),
+                l.resolved,
+            )
+        })
+        .collect();
+    assert_eq!(
+        found,
+        [
+            (0, "_pd_block_diffractogram_id", XRD_ID, Some(1)),
+            (0, "_pd_block_diffractogram_id", NPD_ID, None),
+            (1, "_pd_phase_block_id", PHASE_ID, Some(0)),
+        ]
+    );
+    assert!(links[1].is_dangling());
+    assert_eq!((links[1].span.start_line, links[1].span.start_col), (20, 1));
+}
+
+#[test]
+fn test_related_blocks_follow_links_both_ways() {
+    let doc = powder();
+
+    let names = |name: &str| -> Vec<String> {
+        doc.related_blocks(name)
+            .iter()
+            .map(|b| b.name.to_string())
+            .collect()
+    };
+    assert_eq!(names("corundum_phase"), ["corundum_xrd"]);
+    assert_eq!(names("CORUNDUM_XRD"), ["corundum_phase"]);
+    assert!(names("corundum_npd").is_empty());
+}
+
+#[test]
+fn test_audit_links_resolve_by_block_name() {
+    let cif = "data_global_info
+loop_
+_audit_link.block_code
+_audit_link.block_description
+. 'This block'
+Form_I 'Polymorph I'
+form_iii 'Polymorph III'
+?  'Not yet assigned'
+data_form_I
+_cell.length_a 7.1
+";
+    let doc = CifDocument::parse(cif).unwrap();
+    let links = doc.block_links();
+
+    let found: Vec<(&str, Option<usize>)> = links
+        .iter()
+        .map(|l| (l.target_id.as_str(), l.resolved))
+        .collect();
+    assert_eq!(found, [("Form_I", Some(1)), ("form_iii", None)]);
+    assert_eq!(doc.related_blocks("form_i")[0].name, "global_info");
+}
+
+#[test]
+fn test_custom_pointer_tags() {
+    let cif = "data_child\n_my_link.parent 'P-1'\ndata_parent\n_my_link.id 'P-1'\n";
+    let doc = CifDocument::parse(cif).unwrap();
+
+    assert!(doc.block_links().is_empty());
+    let tags = BlockLinkTags::new()
+        .with_pointer_tag("_MY_LINK.PARENT")
+        .with_id_tag("_my_link.id");
+    let links = doc.block_links_with(&tags);
+    assert_eq!(links.len(), 1);
+    assert_eq!(links[0].tag, "_my_link.parent");
+    assert_eq!(links[0].resolved, Some(1));
+}
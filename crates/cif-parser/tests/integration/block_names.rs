@@ -0,0 +1,104 @@
+// tests/integration/block_names.rs
+// Block and frame names: kept as written, looked up and compared ignoring case
+
+use cif_parser::{
+    parse_string_with_options, rules::note_ids, CaseInsensitiveName, CifBlock, Document,
+    ParseOptions, Span,
+};
+
+const MIXED_CASE: &str = "data_Quartz\n_cell.length_a 4.9\n\
+                          save_SiO4_Tetrahedron\n_x 1\nsave_\n\
+                          DATA_calcite\n_cell.length_a 4.99\n";
+
+#[test]
+fn test_names_keep_their_spelling() {
+    let doc = Document::parse(MIXED_CASE).unwrap();
+
+    assert_eq!(doc.blocks[0].name.as_str(), "Quartz");
+    assert_eq!(doc.blocks[0].name.to_string(), "Quartz");
+    assert_eq!(doc.blocks[0].frames[0].name.as_str(), "SiO4_Tetrahedron");
+    assert_eq!(doc.blocks[1].name.as_str(), "calcite");
+}
+
+#[test]
+fn test_block_lookup_ignores_case() {
+    let doc = Document::parse(MIXED_CASE).unwrap();
+
+    for name in ["Quartz", "quartz", "QUARTZ"] {
+        let block = doc.get_block(name).unwrap();
+        assert_eq!(block.name.as_str(), "Quartz");
+    }
+    assert_eq!(doc.get_block("Calcite").unwrap().name.as_str(), "calcite");
+    assert!(doc.get_block("quartz2").is_none());
+}
+
+#[test]
+fn test_frame_lookup_ignores_case() {
+    let doc = Document::parse(MIXED_CASE).unwrap();
+    let block = doc.get_block("quartz").unwrap();
+
+    let frame = block.get_frame("sio4_tetrahedron").unwrap();
+    assert_eq!(frame.name.as_str(), "SiO4_Tetrahedron");
+    assert!(block.get_frame("SIO4_TETRAHEDRON").is_some());
+    assert!(block.get_frame("SiO4").is_none());
+}
+
+#[test]
+fn test_names_compare_folded() {
+    let doc = Document::parse(MIXED_CASE).unwrap();
+    let block = &doc.blocks[0];
+
+    assert_eq!(block.name, "QUARTZ");
+    assert_eq!(block.name, CaseInsensitiveName::from("quartz"));
+    assert_eq!(
+        CifBlock::new("quartz").name,
+        CifBlock::new("QUARTZ".to_string()).name
+    );
+}
+
+#[test]
+fn test_duplicate_block_names_noted() {
+    let input = "data_a\n_x 1\ndata_b\n_x 2\nDATA_A\n_x 3\n";
+    let result = parse_string_with_options(input, ParseOptions::new()).unwrap();
+
+    // Both blocks are kept; lookup finds the first
+    assert_eq!(result.document.blocks.len(), 3);
+    let first = result.document.get_block("a").unwrap();
+    assert_eq!(first.get_item("_x").unwrap().as_numeric(), Some(1.0));
+
+    let notes: Vec<_> = result
+        .notes
+        .iter()
+        .filter(|n| n.note_id == note_ids::DUPLICATE_BLOCK_NAME)
+        .collect();
+    assert_eq!(notes.len(), 1);
+    assert_eq!(notes[0].span.start_line, 5);
+    assert_eq!(notes[0].related_span.unwrap().start_line, 1);
+    assert_eq!(
+        notes[0].message,
+        "Data block name 'A' is already used (first at line 1, column 1)"
+    );
+}
+
+#[test]
+fn test_duplicate_frame_names_noted_per_block() {
+    let input = "data_a\nsave_f\n_x 1\nsave_\nsave_F\n_x 2\nsave_\n\
+                 data_b\nsave_f\n_x 3\nsave_\n";
+    let result = parse_string_with_options(input, ParseOptions::new()).unwrap();
+
+    // The same frame name in another block is not a duplicate
+    let notes: Vec<_> = result
+        .notes
+        .iter()
+        .filter(|n| n.note_id == note_ids::DUPLICATE_FRAME_NAME)
+        .collect();
+    assert_eq!(notes.len(), 1);
+    assert_eq!(notes[0].span, Span::new(5, 6, 5, 7));
+    assert_eq!(notes[0].related_span, Some(Span::new(2, 6, 2, 7)));
+}
+
+#[test]
+fn test_distinct_names_have_no_notes() {
+    let result = parse_string_with_options(MIXED_CASE, ParseOptions::new()).unwrap();
+    assert!(result.notes.is_empty(), "{:?}", result.notes);
+}
@@ -0,0 +1,107 @@
+// tests/integration/block_summary.rs
+// Integration tests for CifBlock::summary() against real-world fixtures
+
+use crate::fixture_path;
+use cif_parser::{Document, Measurand};
+
+fn assert_measurand(actual: Option<Measurand>, value: f64, uncertainty: Option<f64>) {
+    let actual = actual.expect("measurand should be present");
+    assert!(
+        (actual.value - value).abs() < 1e-9,
+        "expected {value}, got {}",
+        actual.value
+    );
+    match (actual.uncertainty, uncertainty) {
+        (Some(a), Some(e)) => assert!((a - e).abs() < 1e-9, "expected SU {e}, got {a}"),
+        (a, e) => assert_eq!(a, e),
+    }
+}
+
+#[test]
+fn test_xanthine_summary() {
+    let doc = Document::from_file(fixture_path("pycifrw_xanthine.cif")).unwrap();
+    let summary = doc.first_block().unwrap().summary();
+
+    assert_eq!(summary.block_name, "xanthine_form_1");
+    assert_eq!(summary.space_group_name_hm.as_deref(), Some("P 21/c"));
+    assert_eq!(summary.space_group_number, Some(14));
+
+    // 10.01(11), 18.23(8), 6.93(13), 90, 107.5(9), 90
+    assert_measurand(summary.cell[0], 10.01, Some(0.11));
+    assert_measurand(summary.cell[1], 18.23, Some(0.08));
+    assert_measurand(summary.cell[2], 6.93, Some(0.13));
+    assert_measurand(summary.cell[3], 90.0, None);
+    assert_measurand(summary.cell[4], 107.5, Some(0.9));
+    assert_measurand(summary.cell[5], 90.0, None);
+    assert_measurand(summary.volume, 1204.62, None);
+
+    // Not recorded in this file
+    assert!(summary.chemical_formula_sum.is_none());
+    assert!(summary.z.is_none());
+    assert!(summary.r_factor_gt.is_none());
+}
+
+#[test]
+fn test_luag_summary() {
+    let doc = Document::from_file(fixture_path("crystalmaker_LuAG.cif")).unwrap();
+    let summary = doc.first_block().unwrap().summary();
+
+    assert_eq!(summary.space_group_name_hm.as_deref(), Some("I a -3 d"));
+    assert_eq!(summary.space_group_number, Some(230));
+
+    // 11.910400(4) for each length, 90.000000(0) for each angle
+    for length in &summary.cell[..3] {
+        assert_measurand(*length, 11.9104, Some(0.000004));
+    }
+    for angle in &summary.cell[3..] {
+        assert_measurand(*angle, 90.0, Some(0.0));
+    }
+    assert!(summary.volume.is_none());
+}
+
+#[test]
+fn test_summary_prefers_modern_spellings_and_reads_loops() {
+    let cif = r#"
+data_modern
+_chemical_formula.sum      'C5 H4 N4 O2'
+_space_group.name_H-M_alt  'P 1 21/c 1'
+_cell_length_a             1.0
+_CELL.LENGTH_A             10.5(2)
+_cell.formula_units_Z      4
+_diffrn.ambient_temperature 293(2)
+_refine_ls.R_factor_gt     0.0412
+_refine_ls_wR_factor_ref   0.1123
+loop_
+_diffrn_radiation_wavelength.id
+_diffrn_radiation_wavelength.value
+1 0.71073
+2 1.54184
+"#;
+    let doc = Document::parse(cif).unwrap();
+    let summary = doc.first_block().unwrap().summary();
+
+    assert_eq!(summary.chemical_formula_sum.as_deref(), Some("C5 H4 N4 O2"));
+    assert_eq!(summary.space_group_name_hm.as_deref(), Some("P 1 21/c 1"));
+    assert!(summary.space_group_number.is_none());
+    assert_measurand(summary.cell[0], 10.5, Some(0.2));
+    assert_eq!(summary.z, Some(4));
+    assert_measurand(summary.temperature, 293.0, Some(2.0));
+    assert_measurand(summary.wavelength, 0.71073, None);
+    assert_eq!(summary.r_factor_gt, Some(0.0412));
+    assert_eq!(summary.wr_factor, Some(0.1123));
+}
+
+#[test]
+fn test_summary_serializes_and_handles_empty_blocks() {
+    let doc = Document::parse("data_a\n_cell_volume ?\ndata_b\n_cell_volume 100.0\n").unwrap();
+    let summaries = doc.summaries();
+
+    assert_eq!(summaries.len(), 2);
+    assert!(summaries[0].is_empty());
+    assert!(!summaries[1].is_empty());
+
+    let json = serde_json::to_value(&summaries[1]).unwrap();
+    assert_eq!(json["block_name"], "b");
+    assert_eq!(json["volume"]["value"], 100.0);
+    assert!(json["volume"]["uncertainty"].is_null());
+}
@@ -0,0 +1,132 @@
+// tests/integration/column_stats.rs
+// Statistics of numeric loop columns and the anomalies screened from them
+
+use crate::fixture_path;
+use cif_parser::{AnomalyReason, CifBlock, CifDocument, ScreeningOptions};
+
+fn anomalies_block() -> CifBlock {
+    let doc = CifDocument::from_file(fixture_path("numeric_anomalies.cif")).unwrap();
+    doc.blocks.into_iter().next().unwrap()
+}
+
+#[test]
+fn test_column_stats_counts() {
+    let block = anomalies_block();
+    let atoms = &block.loops[0];
+
+    let occupancy = atoms.column_stats("_atom_site_occupancy").unwrap();
+    assert_eq!(occupancy.count, 8);
+    assert_eq!(occupancy.numeric, 7);
+    assert_eq!(occupancy.unknown, 1);
+    assert_eq!(occupancy.not_applicable, 0);
+    assert_eq!(occupancy.non_numeric, 0);
+    assert_eq!(occupancy.min, Some(1.0));
+    assert_eq!(occupancy.max, Some(11.0));
+    assert_eq!(occupancy.iqr(), Some(0.0));
+
+    let labels = atoms.column_stats("_atom_site_label").unwrap();
+    assert_eq!(labels.non_numeric, 8);
+    assert!(!labels.is_numeric());
+    assert_eq!(labels.mean, None);
+
+    assert!(atoms.column_stats("_atom_site_missing").is_none());
+}
+
+#[test]
+fn test_column_stats_values() {
+    let block = anomalies_block();
+    // Tags compare ignoring case
+    let bonds = block.loops[2].column_stats("_GEOM_BOND_DISTANCE").unwrap();
+    assert_eq!(bonds.tag, "_geom_bond_distance");
+
+    let numbers = [1.523, 1.432, 1.401, 1.298, 14.71, 0.97];
+    let mean = numbers.iter().sum::<f64>() / 6.0;
+    let variance = numbers.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / 5.0;
+    assert!((bonds.mean.unwrap() - mean).abs() < 1e-12);
+    assert!((bonds.std_dev.unwrap() - variance.sqrt()).abs() < 1e-12);
+    assert!((bonds.median.unwrap() - 1.4165).abs() < 1e-12);
+    assert!((bonds.q1.unwrap() - 1.32375).abs() < 1e-12);
+    assert!((bonds.q3.unwrap() - 1.50025).abs() < 1e-12);
+}
+
+#[test]
+fn test_screen_flags_planted_anomalies() {
+    let anomalies = anomalies_block().screen_numeric_anomalies(&ScreeningOptions::new());
+
+    let found: Vec<(usize, &str, usize, f64, bool)> = anomalies
+        .iter()
+        .map(|a| {
+            let out_of_range = matches!(a.reason, AnomalyReason::OutOfRange { .. });
+            (a.loop_index, a.tag.as_str(), a.row, a.value, out_of_range)
+        })
+        .collect();
+    assert_eq!(
+        found,
+        [
+            (0, "_atom_site_fract_y", 2, 73.2, true),
+            (0, "_atom_site_U_iso_or_equiv", 6, 4.8, false),
+            (0, "_atom_site_occupancy", 4, 11.0, true),
+            (1, "_geom_angle", 2, 250.0, true),
+            (2, "_geom_bond_distance", 4, 14.71, false),
+        ]
+    );
+    assert_eq!(
+        anomalies[2].reason,
+        AnomalyReason::OutOfRange { min: 0.0, max: 1.0 }
+    );
+    assert_eq!(
+        (anomalies[0].span.start_line, anomalies[0].span.start_col),
+        (19, 16)
+    );
+}
+
+#[test]
+fn test_screen_options() {
+    let block = anomalies_block();
+
+    // Without the built-in ranges only outliers are flagged: the stray
+    // coordinate still is, the occupancy and the angle are not
+    let options = ScreeningOptions::new().without_known_ranges();
+    let tags: Vec<String> = block
+        .screen_numeric_anomalies(&options)
+        .into_iter()
+        .map(|a| a.tag)
+        .collect();
+    assert_eq!(
+        tags,
+        [
+            "_atom_site_fract_y",
+            "_atom_site_U_iso_or_equiv",
+            "_geom_bond_distance"
+        ]
+    );
+
+    // Too few bonds to look for outliers
+    let options = ScreeningOptions::new().with_min_values(7);
+    assert!(block
+        .screen_numeric_anomalies(&options)
+        .iter()
+        .all(|a| a.tag != "_geom_bond_distance"));
+
+    // A custom range on a column with no built-in one
+    let options = ScreeningOptions::new()
+        .without_known_ranges()
+        .with_iqr_factor(1000.0)
+        .with_range("_geom_bond_distance", 1.0, 2.0);
+    let rows: Vec<usize> = block
+        .screen_numeric_anomalies(&options)
+        .iter()
+        .map(|a| a.row)
+        .collect();
+    assert_eq!(rows, [4, 5]);
+}
+
+#[test]
+fn test_anomalies_serialize() {
+    let anomalies = anomalies_block().screen_numeric_anomalies(&ScreeningOptions::new());
+    let json = serde_json::to_string(&anomalies).unwrap();
+    assert!(json.contains(r#""reason":{"OutOfRange":{"min":-1.0,"max":2.0}}"#));
+
+    let back: Vec<cif_parser::Anomaly> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, anomalies);
+}
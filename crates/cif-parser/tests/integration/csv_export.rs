@@ -0,0 +1,117 @@
+// tests/integration/csv_export.rs
+// Integration tests for writing loops as CSV/TSV and reading them back
+
+use cif_parser::export::{
+    category_to_csv, category_to_tsv, csv_to_loop, loop_to_csv, tsv_to_loop, CsvOptions,
+};
+use cif_parser::{CifLoop, CifValue, Document};
+
+fn loops_fixture() -> Document {
+    Document::from_file(crate::fixture_path("loops.cif")).expect("Failed to parse loops.cif")
+}
+
+/// Same tags and same values, ignoring spans and quoting
+fn assert_same_loop(actual: &CifLoop, expected: &CifLoop) {
+    assert_eq!(actual.tags, expected.tags);
+    assert_eq!(actual.len(), expected.len());
+    for (actual_row, expected_row) in actual.rows().zip(expected.rows()) {
+        let kinds = |row: &Vec<CifValue>| row.iter().map(|v| v.kind.clone()).collect::<Vec<_>>();
+        assert_eq!(kinds(actual_row), kinds(expected_row));
+    }
+}
+
+#[test]
+fn test_atom_site_round_trip() {
+    let doc = loops_fixture();
+    let block = doc.first_block().unwrap();
+    let atoms = block.find_loop("_atom_site_label").unwrap();
+
+    let csv = category_to_csv(block, "atom_site", &CsvOptions::new()).unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 6);
+    assert_eq!(
+        lines[0],
+        "_atom_site_label,_atom_site_type_symbol,_atom_site_fract_x,\
+         _atom_site_fract_y,_atom_site_fract_z,_atom_site_occupancy"
+    );
+    assert_eq!(lines[3], "N1,N,0.3456,0.4567,0.5678,0.95");
+
+    let back = csv_to_loop(&atoms.tags, &csv).unwrap();
+    assert_same_loop(&back, atoms);
+}
+
+#[test]
+fn test_locate_by_tag_and_strip_prefix() {
+    let doc = loops_fixture();
+    let block = doc.first_block().unwrap();
+    let options = CsvOptions::new().strip_category_prefix(true);
+
+    let csv = category_to_csv(block, "_bond_length", &options).unwrap();
+    assert_eq!(
+        csv,
+        "type,length\r\nsingle,1.54\r\ndouble,1.34\r\ntriple,1.2\r\n"
+    );
+
+    let csv = category_to_csv(block, "atom_site", &options).unwrap();
+    assert!(csv.starts_with("label,type_symbol,fract_x,"));
+
+    assert!(category_to_csv(block, "refln", &options).is_none());
+}
+
+#[test]
+fn test_tsv_round_trip() {
+    let doc = loops_fixture();
+    let block = doc.first_block().unwrap();
+    let bonds = block.find_loop("_bond_type").unwrap();
+
+    let tsv = category_to_tsv(block, "bond", &CsvOptions::new()).unwrap();
+    assert!(tsv.starts_with("_bond_type\t_bond_length\r\n"));
+    assert_same_loop(&tsv_to_loop(&bonds.tags, &tsv).unwrap(), bonds);
+}
+
+#[test]
+fn test_special_values_and_uncertainties() {
+    let doc = Document::parse(
+        "data_x\nloop_\n_refine.id\n_refine.value\n_refine.note\n\
+         a 1.2345(12) ?\nb 100(3) .\nc -0.5e-3(2) 'with, comma'\n",
+    )
+    .unwrap();
+    let block = doc.first_block().unwrap();
+    let refine = &block.loops[0];
+
+    let csv = category_to_csv(block, "refine", &CsvOptions::new()).unwrap();
+    assert_eq!(
+        csv,
+        "_refine.id,_refine.value,_refine.note\r\n\
+         a,1.2345(12),\r\n\
+         b,100(3),.\r\n\
+         c,-0.0005(2),\"with, comma\"\r\n"
+    );
+    assert_same_loop(&csv_to_loop(&refine.tags, &csv).unwrap(), refine);
+
+    let literal = category_to_csv(block, "refine", &CsvOptions::new().unknown_as_empty(false));
+    assert!(literal.unwrap().contains("a,1.2345(12),?\r\n"));
+}
+
+#[test]
+fn test_rfc4180_escaping() {
+    let doc =
+        Document::parse("data_x\nloop_\n_note.id\n_note.text\n1\n;\nsaid \"hi\"\nthen left\n;\n")
+            .unwrap();
+    let notes = &doc.first_block().unwrap().loops[0];
+
+    let csv = loop_to_csv(notes, &CsvOptions::new());
+    assert_eq!(
+        csv,
+        "_note.id,_note.text\r\n1,\"said \"\"hi\"\"\nthen left\"\r\n"
+    );
+    assert_same_loop(&csv_to_loop(&notes.tags, &csv).unwrap(), notes);
+}
+
+#[test]
+fn test_csv_with_wrong_cell_count() {
+    let err = csv_to_loop(&["_a", "_b"], "a,b\n1,2\n3\n").unwrap_err();
+    assert!(err.to_string().contains("line 3"), "{err}");
+
+    assert!(csv_to_loop(&["_a"], "a\n\"open\n").is_err());
+}
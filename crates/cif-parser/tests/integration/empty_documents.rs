@@ -0,0 +1,122 @@
+// tests/integration/empty_documents.rs
+// Documents with no blocks, blocks with no items, and comments after the
+// last value
+
+use crate::fixture_path;
+use cif_parser::split::{parse_entry, split_entries};
+use cif_parser::{CifDocument, CifVersion, TextEdit};
+
+fn read(name: &str) -> String {
+    std::fs::read_to_string(fixture_path(name)).unwrap()
+}
+
+#[test]
+fn test_empty_input_is_an_empty_document() {
+    for input in [
+        "",
+        "\n\n",
+        "  \t\n",
+        "# just a comment",
+        "# one\r\n# two\r\n",
+    ] {
+        let doc = CifDocument::parse(input).unwrap();
+        assert!(doc.blocks.is_empty(), "{input:?}");
+        assert_eq!(doc.version, CifVersion::V1_1, "{input:?}");
+    }
+}
+
+#[test]
+fn test_comments_only_fixture() {
+    let doc = CifDocument::from_file(fixture_path("comments_only.cif")).unwrap();
+
+    assert!(doc.blocks.is_empty());
+    assert!(doc.first_block().is_none());
+    assert_eq!(doc.version, CifVersion::V2_0);
+    assert!(doc.summaries().is_empty());
+    assert!(doc.block_links().is_empty());
+    assert!(split_entries(&read("comments_only.cif")).is_empty());
+}
+
+#[test]
+fn test_empty_block_fixture() {
+    let text = read("empty_block.cif");
+    let doc = CifDocument::parse(&text).unwrap();
+
+    assert_eq!(doc.blocks.len(), 1);
+    let block = &doc.blocks[0];
+    assert_eq!(block.name, "empty");
+    assert!(block.items.is_empty());
+    assert!(block.loops.is_empty());
+    assert!(block.frames.is_empty());
+    assert_eq!(block.all_tags().count(), 0);
+    assert!(block.summary().is_empty());
+
+    let entries = split_entries(&text);
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].name, "empty");
+    assert_eq!(parse_entry(&text, &entries[0]).unwrap(), doc);
+}
+
+#[test]
+fn test_edit_fills_empty_block() {
+    let text = read("empty_block.cif");
+    let doc = CifDocument::parse(&text).unwrap();
+
+    let edit = TextEdit::new(text.len()..text.len(), "_a 1\n");
+    let (edited, _) = CifDocument::apply_edit(&text, edit, &doc).unwrap();
+    assert_eq!(edited, CifDocument::parse("data_empty\n_a 1\n").unwrap());
+}
+
+#[test]
+fn test_trailing_comments_fixture() {
+    let text = read("trailing_comments.cif");
+    let doc = CifDocument::parse(&text).unwrap();
+
+    assert_eq!(doc.blocks.len(), 1);
+    let block = &doc.blocks[0];
+    assert_eq!(block.items.len(), 2);
+    let loop_ = &block.loops[0];
+    assert_eq!(loop_.len(), 2);
+    assert_eq!(loop_.get(1, 0).unwrap().span.start_line, 8);
+    assert_eq!(block.summary().cell[0].unwrap().value, 10.5);
+
+    // The trailing comments stay with the one entry
+    let entries = split_entries(&text);
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].text(&text), text);
+}
+
+#[test]
+fn test_trailing_text_round_trips() {
+    let text = read("trailing_comments.cif");
+    let doc = CifDocument::parse(&text).unwrap();
+    assert_eq!(doc.trailing, "\n\n# ---- end of data ----\n   \n#\n\n");
+    assert!(text.ends_with(&doc.trailing));
+
+    // The stored form keeps it
+    let json = serde_json::to_string(&doc).unwrap();
+    let back: CifDocument = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.trailing, doc.trailing);
+
+    // With no blocks it is everything after the header
+    let doc = CifDocument::parse("#\\#CIF_2.0\n# only a note\n").unwrap();
+    assert_eq!(doc.trailing, "\n# only a note\n");
+    assert_eq!(CifDocument::parse("# one\n").unwrap().trailing, "# one\n");
+
+    // An edit to the last block updates it, an edit before leaves it
+    let two = "data_a\n_x 1\ndata_b\n_y 2 # two\n";
+    let doc = CifDocument::parse(two).unwrap();
+    assert_eq!(doc.trailing, " # two\n");
+    let edit = TextEdit::new(two.len() - 7..two.len(), "\n# three\n");
+    let (edited, changed) = CifDocument::apply_edit(two, edit.clone(), &doc).unwrap();
+    assert!(!changed.full_reparse);
+    assert_eq!(edited.trailing, "\n# three\n");
+    assert_eq!(
+        edited,
+        CifDocument::parse(&edit.apply_to(two).unwrap()).unwrap()
+    );
+
+    let edit = TextEdit::new(10..11, "10");
+    let (edited, _) = CifDocument::apply_edit(two, edit, &doc).unwrap();
+    assert_eq!(edited.trailing, " # two\n");
+}
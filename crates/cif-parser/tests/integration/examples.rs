@@ -0,0 +1,56 @@
+// tests/integration/examples.rs
+// Smoke tests running the logic of the runnable examples on their bundled inputs
+
+use cif_parser::CifDocument;
+
+fn structure() -> CifDocument {
+    CifDocument::from_file(crate::fixture_path("examples/structure.cif"))
+        .expect("Failed to parse examples/structure.cif")
+}
+
+// Each example is compiled into a module here, so a change to the API that
+// breaks it fails the tests, and `main` goes unused
+#[allow(dead_code)]
+mod parse_and_summarize {
+    include!("../../examples/parse_and_summarize.rs");
+
+    #[test]
+    fn test_parse_and_summarize() {
+        let report = summarize(&super::structure());
+
+        assert!(report.starts_with("CIF version CIF 2.0, 1 block(s)\n"));
+        assert!(report.contains("data_urea\n  11 items, 1 loops, 0 save frames\n"));
+        assert!(report.contains("loop of 6 columns x 5 rows, starting _atom_site.label"));
+        assert!(report.contains("space group  P -4 21 m (No. 113)"));
+        assert!(report.contains("cell         5.565±0.001 5.565±0.001 4.684±0.001 90 90 90"));
+        assert!(report.contains("Z            2"));
+    }
+}
+
+#[allow(dead_code)]
+mod extract_atoms {
+    include!("../../examples/extract_atoms.rs");
+
+    #[test]
+    fn test_extract_atoms() {
+        let doc = super::structure();
+        let atoms = extract_atoms(doc.first_block().unwrap()).unwrap();
+
+        assert_eq!(atoms.len(), 5);
+        let n1 = &atoms[2];
+        assert_eq!((n1.label.as_str(), n1.element.as_str()), ("N1", "N"));
+        assert_eq!(n1.fract, [0.1459, 0.6459, 0.1766]);
+        assert_eq!(n1.fract_su[0], 0.0002);
+        assert_eq!(atoms[4].fract[2], -0.038);
+        assert!(atoms.iter().all(|atom| atom.occupancy == 1.0));
+    }
+
+    #[test]
+    fn test_extract_atoms_with_legacy_tags() {
+        let doc = CifDocument::from_file(crate::fixture_path("loops.cif")).unwrap();
+        let atoms = extract_atoms(doc.first_block().unwrap()).unwrap();
+
+        assert_eq!(atoms[3].label, "O1");
+        assert_eq!(atoms[4].occupancy, 0.9);
+    }
+}
@@ -0,0 +1,113 @@
+// tests/integration/feature_report.rs
+// Integration tests for analysis::feature_report() on CIF 1.1 and CIF 2.0 fixtures
+
+use crate::fixture_path;
+use cif_parser::analysis::{feature_report, Feature, FeatureReport, FeatureUsage};
+use cif_parser::{CifVersion, Span};
+
+fn report_for(name: &str) -> FeatureReport {
+    let content = std::fs::read_to_string(fixture_path(name)).unwrap();
+    feature_report(&content).unwrap()
+}
+
+fn usage(feature: Feature, count: usize, first: Span) -> FeatureUsage {
+    FeatureUsage {
+        feature,
+        count,
+        first,
+    }
+}
+
+#[test]
+fn test_cif2_lists_report() {
+    let report = report_for("cif2_lists.cif");
+
+    // [] [42] [1 2 3 4 5] [[1 2] [3 4]] (three lists) [1 2 ? 4]
+    assert_eq!(
+        report.features,
+        vec![usage(Feature::List, 7, Span::new(5, 20, 5, 22))]
+    );
+    assert_eq!(report.declared_version(), CifVersion::V2_0);
+    assert_eq!(report.minimum_version(), CifVersion::V2_0);
+    assert_eq!(report.maximum_compatible_version(), CifVersion::V2_0);
+}
+
+#[test]
+fn test_cif2_tables_report() {
+    let report = report_for("cif2_tables.cif");
+
+    assert_eq!(
+        report.features,
+        vec![usage(Feature::Table, 4, Span::new(5, 20, 5, 22))]
+    );
+    assert_eq!(report.minimum_version(), CifVersion::V2_0);
+    assert_eq!(report.maximum_compatible_version(), CifVersion::V2_0);
+}
+
+#[test]
+fn test_cif2_comprehensive_report() {
+    let report = report_for("cif2_comprehensive.cif");
+
+    assert_eq!(
+        report.features,
+        vec![
+            usage(Feature::List, 36, Span::new(27, 28, 27, 30)),
+            usage(Feature::Table, 21, Span::new(60, 28, 60, 30)),
+            // Five triple-quoted values plus two inside a list
+            usage(Feature::TripleQuotedString, 7, Span::new(96, 28, 96, 64)),
+            // One per character: Greek, maths, Å°, accents, list and table keys
+            usage(Feature::NonAscii, 24, Span::new(121, 29, 121, 31)),
+        ]
+    );
+    assert!(!report.header.as_ref().unwrap().misplaced);
+    assert_eq!(report.minimum_version(), CifVersion::V2_0);
+    assert_eq!(report.maximum_compatible_version(), CifVersion::V2_0);
+}
+
+#[test]
+fn test_plain_cif1_report() {
+    let report = report_for("simple.cif");
+
+    assert!(report.header.is_none());
+    assert!(report.features.is_empty());
+    assert_eq!(report.declared_version(), CifVersion::V1_1);
+    assert_eq!(report.minimum_version(), CifVersion::V1_1);
+    assert_eq!(report.maximum_compatible_version(), CifVersion::V2_0);
+}
+
+#[test]
+fn test_cif1_only_constructs_report() {
+    let cif = "data_\n_name 'O''Brien'\n_range [1 2]\n";
+    let report = feature_report(cif).unwrap();
+
+    assert_eq!(
+        report.features,
+        vec![
+            usage(Feature::List, 1, Span::new(3, 8, 3, 13)),
+            usage(Feature::DoubledQuoteEscape, 1, Span::new(2, 7, 2, 17)),
+            usage(Feature::EmptyBlockName, 1, Span::new(1, 1, 1, 6)),
+        ]
+    );
+    assert_eq!(report.minimum_version(), CifVersion::V1_1);
+    // The list is text in CIF 1.1 and the doubled quote is invalid CIF 2.0
+    assert_eq!(report.maximum_compatible_version(), CifVersion::V1_1);
+}
+
+#[test]
+fn test_conflicting_report_has_no_compatible_version() {
+    // Non-ASCII needs CIF 2.0; the doubled quote needs CIF 1.1
+    let report = feature_report("data_t\n_a 'Å'\n_b 'it''s'\n").unwrap();
+
+    assert_eq!(report.minimum_version(), CifVersion::V2_0);
+    assert_eq!(report.maximum_compatible_version(), CifVersion::V1_1);
+}
+
+#[test]
+fn test_report_serializes() {
+    let report = feature_report("#\\#CIF_2.0 written by hand\ndata_t\n_a {'k':1}\n").unwrap();
+    let json = serde_json::to_value(&report).unwrap();
+
+    assert_eq!(json["header"]["trailing"], " written by hand");
+    assert_eq!(json["features"][0]["feature"], "table");
+    assert_eq!(json["features"][0]["count"], 1);
+}
@@ -0,0 +1,74 @@
+// tests/integration/high_precision.rs
+// Integration tests for numbers at the limits of f64 precision
+
+use crate::fixture_path;
+use cif_parser::{parse_string_with_options, rules::note_ids, ParseOptions};
+
+fn parse_fixture() -> cif_parser::ParseResult {
+    let content = std::fs::read_to_string(fixture_path("high_precision.cif")).unwrap();
+    parse_string_with_options(&content, ParseOptions::new()).unwrap()
+}
+
+#[test]
+fn test_high_precision_notes() {
+    let result = parse_fixture();
+
+    let precision: Vec<_> = result
+        .notes
+        .iter()
+        .filter(|n| n.note_id == note_ids::NUMERIC_PRECISION_EXCEEDED)
+        .collect();
+    assert_eq!(precision.len(), 2);
+    // Wavelength item and the looped fractional coordinate
+    assert_eq!(precision[0].span.start_line, 7);
+    assert_eq!(precision[1].span.start_line, 16);
+
+    let underflow: Vec<_> = result
+        .notes
+        .iter()
+        .filter(|n| n.note_id == note_ids::UNCERTAINTY_UNDERFLOW)
+        .collect();
+    assert_eq!(underflow.len(), 1);
+    assert_eq!(underflow[0].span.start_line, 11);
+
+    assert_eq!(result.notes.len(), 3);
+}
+
+#[test]
+fn test_high_precision_uncertainty_arithmetic() {
+    let result = parse_fixture();
+    let block = &result.document.blocks[0];
+
+    // 3 in the fourth decimal place is exactly the literal 3e-4, not
+    // 3 * 10^-4 with the scale rounded separately
+    let (_, su) = block
+        .get_item("_cell_volume")
+        .unwrap()
+        .as_numeric_with_uncertainty()
+        .unwrap();
+    assert_eq!(su, 3e-4);
+
+    let (_, su) = block
+        .get_item("_cell_length_a")
+        .unwrap()
+        .as_numeric_with_uncertainty()
+        .unwrap();
+    assert_eq!(su, 5e-17);
+
+    let (_, su) = block
+        .get_item("_cell_length_b")
+        .unwrap()
+        .as_numeric_with_uncertainty()
+        .unwrap();
+    assert_eq!(su, 3e-4);
+}
+
+#[test]
+fn test_plain_numbers_have_no_notes() {
+    let result = parse_string_with_options(
+        "data_t\n_cell_length_a 10.5(2)\n_cell_volume 1234.567\n",
+        ParseOptions::new(),
+    )
+    .unwrap();
+    assert!(!result.has_notes());
+}
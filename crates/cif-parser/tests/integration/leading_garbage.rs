@@ -0,0 +1,90 @@
+// tests/integration/leading_garbage.rs
+// Integration tests for skipping prose before the first data block
+
+use crate::fixture_path;
+use cif_parser::{
+    parse_string_with_options, rules::note_ids, CifDocument, CifError, CifVersion, ParseOptions,
+    Span,
+};
+
+fn read_fixture() -> String {
+    std::fs::read_to_string(fixture_path("leading_prose.cif")).unwrap()
+}
+
+#[test]
+fn test_leading_prose_rejected_by_default() {
+    let content = read_fixture();
+
+    assert!(CifDocument::parse(&content).is_err());
+    assert!(parse_string_with_options(&content, ParseOptions::new()).is_err());
+}
+
+#[test]
+fn test_leading_prose_skipped() {
+    let content = read_fixture();
+    let result =
+        parse_string_with_options(&content, ParseOptions::new().skip_leading_garbage(true))
+            .unwrap();
+
+    let block = result.document.first_block().unwrap();
+    assert_eq!(block.name, "x");
+    assert_eq!(
+        block.get_item("_cell_length_a").unwrap().as_numeric(),
+        Some(5.4309)
+    );
+    assert_eq!(block.find_loop("_atom_site_label").unwrap().len(), 1);
+
+    // Spans still count the skipped lines
+    assert_eq!(block.span.start_line, 4);
+    assert_eq!(block.get_item("_cell_length_a").unwrap().span.start_line, 5);
+
+    assert_eq!(result.notes.len(), 1);
+    let note = &result.notes[0];
+    assert_eq!(note.note_id, note_ids::LEADING_CONTENT_SKIPPED);
+    assert_eq!(note.span, Span::new(1, 1, 3, 49));
+    assert_eq!(
+        note.message,
+        "Skipped 3 line(s) (bytes 0..178) before the first data block"
+    );
+}
+
+#[test]
+fn test_valid_preamble_not_reported() {
+    let content = "# Generated by hand\n\n# checked 2004-03-12\ndata_x\n_a 1\n";
+    let result =
+        parse_string_with_options(content, ParseOptions::new().skip_leading_garbage(true)).unwrap();
+
+    assert!(!result.has_notes());
+    assert_eq!(result.document.blocks[0].name, "x");
+}
+
+#[test]
+fn test_skipped_text_before_cif2_header() {
+    let content =
+        "Received: from mail.example.org\nSubject: structure\n#\\#CIF_2.0\ndata_x\n_a [1 2]\n";
+    let result =
+        parse_string_with_options(content, ParseOptions::new().skip_leading_garbage(true)).unwrap();
+
+    assert_eq!(result.document.version, CifVersion::V2_0);
+    assert!(result
+        .document
+        .first_block()
+        .unwrap()
+        .get_item("_a")
+        .unwrap()
+        .is_list());
+    assert_eq!(result.notes[0].span, Span::new(1, 1, 2, 19));
+}
+
+#[test]
+fn test_no_data_block_still_fails() {
+    let err = parse_string_with_options(
+        "just some prose\nwith no block\n",
+        ParseOptions::new().skip_leading_garbage(true),
+    )
+    .unwrap_err();
+    assert!(matches!(
+        err,
+        CifError::ParseError(_) | CifError::InvalidStructure { .. }
+    ));
+}
@@ -0,0 +1,143 @@
+// tests/integration/long_values.rs
+// Integration tests for over-long data names and unquoted values
+
+use std::time::{Duration, Instant};
+
+use cif_parser::analysis::{feature_report, Feature, MAX_DATA_NAME_LENGTH, MAX_LINE_LENGTH};
+use cif_parser::rules::{note_ids, rule_ids, ParseNote};
+use cif_parser::{parse_string_with_options, CifDocument, ParseOptions, Span};
+
+/// A data name of exactly `length` characters
+fn data_name(length: usize) -> String {
+    format!("_{}", "n".repeat(length - 1))
+}
+
+/// Notes with the given id from parsing `cif` with default options
+fn notes_with_id(cif: &str, note_id: &str) -> Vec<ParseNote> {
+    parse_string_with_options(cif, ParseOptions::new())
+        .unwrap()
+        .notes
+        .into_iter()
+        .filter(|note| note.note_id == note_id)
+        .collect()
+}
+
+#[test]
+fn test_long_data_names_are_noted_in_both_versions() {
+    let long = data_name(MAX_DATA_NAME_LENGTH + 1);
+    let longest = data_name(MAX_DATA_NAME_LENGTH);
+    let looped = format!("{}_2", data_name(MAX_DATA_NAME_LENGTH));
+    let body = format!("data_t\n{long} 1\n{longest} 2\nloop_\n{looped}\n3\n");
+    let cif2 = format!("#\\#CIF_2.0\n{body}");
+
+    let result = parse_string_with_options(&body, ParseOptions::new()).unwrap();
+    assert!(result.document.blocks[0].get_item(&long).is_some());
+
+    let notes = notes_with_id(&body, note_ids::LONG_DATA_NAME);
+    assert_eq!(notes.len(), 2, "{notes:?}");
+    assert_eq!(notes[0].span, Span::new(2, 1, 2, 77));
+    assert_eq!(
+        notes[0].message,
+        "Data name is 76 characters long; CIF 1.1 allows at most 75"
+    );
+    assert_eq!(notes[1].span.start_line, 5);
+
+    // CIF 2.0 drops the limit, but the names are still noted
+    let notes = notes_with_id(&cif2, note_ids::LONG_DATA_NAME);
+    assert_eq!(notes.len(), 2, "{notes:?}");
+    assert_eq!(notes[0].span, Span::new(3, 1, 3, 77));
+
+    let report = feature_report(&body).unwrap();
+    let usage = report.get(Feature::LongDataName).unwrap();
+    assert_eq!(usage.count, 2);
+    assert_eq!(usage.first, Span::new(2, 1, 2, 77));
+}
+
+#[test]
+fn test_long_unquoted_values_are_noted_in_both_versions() {
+    let value = "x".repeat(MAX_LINE_LENGTH + 1);
+    let cif1 = format!("data_t\n_short ok\n_long {value}\n");
+    let cif2 = format!("#\\#CIF_2.0\ndata_t\n_list [1 {value}]\n");
+
+    // Neither version refuses the value
+    let result = parse_string_with_options(&cif1, ParseOptions::new()).unwrap();
+    assert_eq!(
+        result.document.blocks[0].items["_long"].as_string(),
+        Some(value.as_str())
+    );
+    assert!(CifDocument::parse(&cif2).is_ok());
+
+    let notes = notes_with_id(&cif1, note_ids::LONG_UNQUOTED_VALUE);
+    assert_eq!(notes.len(), 1, "{notes:?}");
+    assert_eq!(notes[0].span, Span::new(3, 7, 3, 7 + value.len()));
+    assert!(notes[0].message.contains("text field"));
+
+    let notes = notes_with_id(&cif2, note_ids::LONG_UNQUOTED_VALUE);
+    assert_eq!(notes.len(), 1, "{notes:?}");
+    assert_eq!(notes[0].span.start_col, 10);
+
+    // Upgrade guidance is only about what CIF 2.0 would reject
+    let result =
+        parse_string_with_options(&cif1, ParseOptions::new().upgrade_guidance(true)).unwrap();
+    assert_eq!(
+        result.upgrade_issues.len(),
+        1,
+        "{:?}",
+        result.upgrade_issues
+    );
+    assert_eq!(
+        result.upgrade_issues[0].rule_id,
+        rule_ids::CIF2_MISSING_MAGIC_HEADER
+    );
+
+    assert_eq!(
+        feature_report(&cif1)
+            .unwrap()
+            .count(Feature::LongUnquotedValue),
+        1
+    );
+    let report = feature_report(&cif2).unwrap();
+    assert_eq!(
+        report
+            .get(Feature::LongUnquotedValue)
+            .unwrap()
+            .first
+            .start_col,
+        10
+    );
+    assert!(report.uses(Feature::LongLine));
+}
+
+/// Fastest of three parses of `input`
+fn parse_time(input: &str) -> Duration {
+    (0..3)
+        .map(|_| {
+            let start = Instant::now();
+            CifDocument::parse(input).unwrap();
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+#[test]
+fn test_pathological_value_parses_in_linear_time() {
+    let input = |length: usize| format!("data_t\n_a {}\n_b 1\n", "x".repeat(length));
+    let long = input(100_000);
+
+    let doc = CifDocument::parse(&long).unwrap();
+    let block = &doc.blocks[0];
+    let value = &block.items["_a"];
+    assert_eq!(value.as_string().map(str::len), Some(100_000));
+    assert_eq!(value.span, Span::new(2, 4, 2, 100_004));
+    assert_eq!(block.items["_b"].span, Span::new(3, 4, 3, 5));
+
+    // Four times the text should take about four times as long; rescanning
+    // the line for each character would take sixteen
+    let short = parse_time(&input(25_000));
+    let long = parse_time(&long);
+    assert!(
+        long < short * 10,
+        "25k characters took {short:?}, 100k took {long:?}"
+    );
+}
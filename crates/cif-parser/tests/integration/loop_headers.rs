@@ -0,0 +1,117 @@
+// tests/integration/loop_headers.rs
+// Loop headers ended early by a value, and duplicate tags in one header
+
+use cif_parser::{
+    parse_string_with_options, rules::note_ids, CifError, Document, ParseOptions, Span,
+};
+
+fn structure_error(input: &str) -> (String, (usize, usize)) {
+    match Document::parse(input) {
+        Err(CifError::InvalidStructure {
+            message,
+            location: Some(location),
+        }) => (message, location),
+        other => panic!("Expected a located structure error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_value_after_tags_starts_the_body() {
+    // The first value ends the header, as CIF specifies; the tag after the
+    // rows is an ordinary data item
+    let input = "data_t\nloop_\n_a\n_b\n1 2\n3 4\n_c 5\n";
+    let result = parse_string_with_options(input, ParseOptions::new()).unwrap();
+    let block = &result.document.blocks[0];
+
+    assert_eq!(block.loops[0].tags, ["_a", "_b"]);
+    assert_eq!(block.loops[0].len(), 2);
+    assert_eq!(block.get_item("_c").unwrap().as_numeric(), Some(5.0));
+    assert!(result.notes.is_empty());
+
+    let doc = Document::parse("data_t\nloop_ _a _b 1 2 _c 5\n").unwrap();
+    assert_eq!(doc.blocks[0].loops[0].len(), 1);
+    assert_eq!(
+        doc.blocks[0].get_item("_c").unwrap().as_numeric(),
+        Some(5.0)
+    );
+}
+
+#[test]
+fn test_misplaced_value_among_tags() {
+    // The rows after `_c` no longer fit anywhere and the grammar fails on
+    // them; the error points at the value that ended the header instead
+    let (message, location) = structure_error("data_t\nloop_\n_a\n_b\nvalue1\n_c\n1 2 3\n4 5 6\n");
+    assert_eq!(location, (5, 1));
+    assert!(message.contains("Loop header interrupted"), "{message}");
+    assert!(
+        message.contains("'value1' ended the loop header after 2 tag(s)"),
+        "{message}"
+    );
+    assert!(message.contains("misplaced among the tags"), "{message}");
+
+    // The same on one line
+    let (message, location) = structure_error("data_t\nloop_ _a _b value1 _c\n1 2 3\n");
+    assert_eq!(location, (2, 13));
+    assert!(message.contains("'value1'"), "{message}");
+
+    // When everything after the value still parses, the loop is short of
+    // one row and that error points at the value too
+    let (message, location) = structure_error("data_t\nloop_\n_a\n_b\nvalue1\n_c 1\n");
+    assert_eq!(location, (5, 1));
+    assert!(
+        message.contains("[loop-values-misaligned] Loop has 2 tags but 1 values"),
+        "{message}"
+    );
+    assert!(
+        message.contains("'value1' ended the loop header after 2 tag(s)"),
+        "{message}"
+    );
+}
+
+#[test]
+fn test_tag_missing_its_underscore() {
+    let (message, location) = structure_error("data_t\nloop_\n_a\nb\n_c\n1 2 3\n4 5 6\n");
+    assert_eq!(location, (4, 1));
+    assert!(
+        message.contains("'b' ended the loop header after 1 tag(s)"),
+        "{message}"
+    );
+    assert!(message.contains("missing its leading '_'"), "{message}");
+}
+
+#[test]
+fn test_unrelated_errors_not_blamed_on_one_row_loops() {
+    // A one-row loop followed by items is valid; a later error stays as is
+    let err = Document::parse("data_t\nloop_ _a 1\n_c 2\n_d 3\n_e 4 5\n").unwrap_err();
+    assert!(matches!(err, CifError::ParseError(_)), "{err:?}");
+}
+
+#[test]
+fn test_duplicate_tags_noted() {
+    let input = "data_t\nloop_\n_a\n_b\n_A\n1 2 3\nsave_f\nloop_ _x _x\n1 2\nsave_\n";
+    let result = parse_string_with_options(input, ParseOptions::new()).unwrap();
+
+    // The loops still parse, one column per tag
+    let block = &result.document.blocks[0];
+    assert_eq!(block.loops[0].tags, ["_a", "_b", "_A"]);
+    assert_eq!(block.loops[0].len(), 1);
+    assert_eq!(block.frames[0].loops[0].len(), 1);
+
+    let notes: Vec<_> = result
+        .notes
+        .iter()
+        .filter(|n| n.note_id == note_ids::LOOP_DUPLICATE_TAG)
+        .collect();
+    assert_eq!(notes.len(), 2);
+
+    assert_eq!(notes[0].span, Span::new(5, 1, 5, 3));
+    assert_eq!(notes[0].related_span, Some(Span::new(3, 1, 3, 3)));
+    assert_eq!(
+        notes[0].message,
+        "Tag '_A' appears more than once in the loop header (first at line 3, column 1)"
+    );
+
+    assert_eq!(notes[1].span.start_line, 8);
+    assert_eq!(notes[1].span.start_col, 10);
+    assert_eq!(notes[1].related_span.unwrap().start_col, 7);
+}
@@ -0,0 +1,241 @@
+// tests/integration/number_format.rs
+// Numbers and standard uncertainties written back as CIF text
+
+use cif_parser::ast::Measurand;
+use cif_parser::cif_num::{
+    format_numeric, format_numeric_preserving, FormatPolicy, Notation, SuRounding,
+};
+use cif_parser::{CifDocument, CifValue};
+
+/// Value, standard uncertainty and the text under the IUCr convention
+const IUCR: &[(f64, Option<f64>, &str)] = &[
+    (1.23456, Some(0.0019), "1.2346(19)"),
+    (1.23456, Some(0.0020), "1.235(2)"),
+    (1.23456, Some(0.0194), "1.235(19)"),
+    (1.23456, Some(0.0196), "1.23(2)"),
+    (1.23456, Some(0.0123), "1.235(12)"),
+    (1.23456, Some(0.097), "1.23(10)"),
+    (1.23456, Some(0.0999), "1.23(10)"),
+    (0.5, Some(0.0003), "0.5000(3)"),
+    (7.47, Some(0.006), "7.470(6)"),
+    (-7.47, Some(0.006), "-7.470(6)"),
+    (12345.0, Some(15.0), "12345(15)"),
+    (12345.0, Some(230.0), "12300(200)"),
+    (0.3, Some(1.2), "0.3(12)"),
+    (0.04, Some(1.2), "0.0(12)"),
+    (-0.04, Some(1.2), "0.0(12)"),
+    (2.0, Some(7.0), "2(7)"),
+    (1.0, Some(1e25), "0e24(10)"),
+    (5.0, Some(3e20), "0e20(3)"),
+    (1.5, Some(0.0), "1.5(0)"),
+    (1.5, Some(-0.1), "1.5"),
+    (1.5, Some(f64::NAN), "1.5"),
+    (-2.5, None, "-2.5"),
+    (90.0, None, "90"),
+    (0.1, None, "0.1"),
+    (f64::NAN, None, "?"),
+    (f64::INFINITY, Some(0.1), "?"),
+];
+
+/// Value, standard uncertainty and the text with every digit kept
+const EXACT: &[(f64, Option<f64>, &str)] = &[
+    (1.234, Some(0.056), "1.234(56)"),
+    (1.23456, Some(0.0234), "1.2346(234)"),
+    (7.47, Some(0.016), "7.470(16)"),
+    (0.5, Some(0.2), "0.5(2)"),
+    (5.0, Some(3.0), "5(3)"),
+    (1.2, Some(0.123), "1.200(123)"),
+    (12345.0, Some(230.0), "12345(230)"),
+    (12300.0, Some(200.0), "12300(200)"),
+    (0.0, Some(0.5), "0.0(5)"),
+    (1.0, Some(1e25), "0e25(1)"),
+    (5.0, Some(3e20), "0e20(3)"),
+];
+
+/// Value and the text with the default plain range, 10⁻⁵ to below 10¹⁵
+const THRESHOLDS: &[(f64, &str)] = &[
+    (0.00001, "0.00001"),
+    (-0.00001, "-0.00001"),
+    (0.0000099, "9.9e-6"),
+    (999999999999999.0, "999999999999999"),
+    (1e15, "1e15"),
+    (-1.5e20, "-1.5e20"),
+    (0.0, "0"),
+];
+
+#[test]
+fn test_iucr_rounding() {
+    let policy = FormatPolicy::new();
+    for &(value, su, expected) in IUCR {
+        assert_eq!(
+            format_numeric(value, su, &policy),
+            expected,
+            "{value} ({su:?})"
+        );
+    }
+}
+
+#[test]
+fn test_exact_rounding() {
+    let policy = FormatPolicy::exact();
+    assert_eq!(policy.su_rounding, SuRounding::Exact);
+    for &(value, su, expected) in EXACT {
+        assert_eq!(
+            format_numeric(value, su, &policy),
+            expected,
+            "{value} ({su:?})"
+        );
+    }
+}
+
+#[test]
+fn test_plain_range() {
+    let policy = FormatPolicy::new();
+    for &(value, expected) in THRESHOLDS {
+        assert_eq!(format_numeric(value, None, &policy), expected, "{value}");
+    }
+
+    let plain = FormatPolicy::new().with_notation(Notation::Plain);
+    assert_eq!(format_numeric(0.0000099, None, &plain), "0.0000099");
+    assert_eq!(format_numeric(1e15, None, &plain), "1000000000000000");
+
+    let narrow = FormatPolicy::new().with_plain_exponents(-2, 3);
+    assert_eq!(format_numeric(0.01, None, &narrow), "0.01");
+    assert_eq!(format_numeric(0.009, None, &narrow), "9e-3");
+    assert_eq!(format_numeric(9999.0, None, &narrow), "9999");
+    assert_eq!(format_numeric(10000.0, None, &narrow), "1e4");
+}
+
+#[test]
+fn test_exponent_with_uncertainty() {
+    let scientific = FormatPolicy::new().with_notation(Notation::Scientific);
+    assert_eq!(
+        format_numeric(12345.0, Some(23.0), &scientific),
+        "1.234e4(2)"
+    );
+    assert_eq!(format_numeric(7.47, Some(0.006), &scientific), "7.470e0(6)");
+    assert_eq!(
+        format_numeric(-0.0123, Some(0.0004), &scientific),
+        "-1.23e-2(4)"
+    );
+
+    // The uncertainty goes after the exponent, as CIF reads it
+    let policy = FormatPolicy::new();
+    assert_eq!(format_numeric(1.5e-7, Some(2e-9), &policy), "1.50e-7(2)");
+    assert_eq!(format_numeric(0.0, Some(2e-9), &policy), "0e-9(2)");
+
+    // Rounding the mantissa up to 10 moves the exponent
+    assert_eq!(format_numeric(9.996e20, Some(3e18), &policy), "1.000e21(3)");
+    assert_eq!(
+        format_numeric(9.996e20, Some(1e18), &policy),
+        "9.996e20(10)"
+    );
+
+    // Exact uncertainties keep the value's digits too
+    let exact = FormatPolicy::exact();
+    assert_eq!(format_numeric(9.996e20, Some(1e18), &exact), "9.996e20(10)");
+    assert_eq!(format_numeric(1.5e20, Some(2e19), &exact), "1.5e20(2)");
+}
+
+#[test]
+fn test_written_numbers_read_back() {
+    let policies = [
+        FormatPolicy::new(),
+        FormatPolicy::exact(),
+        FormatPolicy::new().with_notation(Notation::Scientific),
+    ];
+    let values = [
+        0.0,
+        1.0,
+        -2.5,
+        0.1,
+        1.0 / 3.0,
+        6.02214076e23,
+        1.6e-19,
+        123456.789,
+    ];
+    for policy in &policies {
+        for value in values {
+            let written = format_numeric(value, None, policy);
+            assert_eq!(
+                CifValue::parse_number(&written),
+                Some((value, None)),
+                "{written}"
+            );
+        }
+        for &(value, su, _) in IUCR.iter().chain(EXACT) {
+            let (Some(su), true) = (su, value.is_finite()) else {
+                continue;
+            };
+            if !su.is_finite() || su < 0.0 {
+                continue;
+            }
+            let written = format_numeric(value, Some(su), policy);
+            let (read_value, read_su) = CifValue::parse_number(&written).unwrap();
+            let read_su = read_su.unwrap();
+            assert!(
+                (read_value - value).abs() <= read_su.max(su),
+                "{written} for {value}"
+            );
+            assert!(
+                (read_su - su).abs() <= su * 0.5 + read_su * 0.5,
+                "{written} for {su}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_display_writes_parsed_numbers_back() {
+    let tokens = [
+        "1.234(56)",
+        "7.470(6)",
+        "0.5(2)",
+        "-5(3)",
+        "12345(230)",
+        "2.5",
+    ];
+    for token in tokens {
+        let doc = CifDocument::parse(&format!("data_t\n_x {token}\n")).unwrap();
+        let value = doc.blocks[0].get_item("_x").unwrap();
+        assert_eq!(value.to_string(), token);
+    }
+}
+
+#[test]
+fn test_preserving_keeps_lexical_form() {
+    let policy = FormatPolicy::new();
+    let cases: &[(&str, f64, Option<f64>, &str)] = &[
+        ("1.50(3)", 1.5, Some(0.03), "1.50(3)"),
+        ("1.50E0", 1.5, None, "1.50E0"),
+        ("+.5", 0.5, None, "+.5"),
+        ("1.234(56)", 1.234, Some(0.056), "1.234(56)"),
+        // Changed value or uncertainty: formatted afresh
+        ("1.50(3)", 1.5, Some(0.04), "1.50(4)"),
+        ("1.50(3)", 1.6, Some(0.03), "1.60(3)"),
+        ("1.50(3)", 1.5, None, "1.5"),
+        ("1.234(56)", 1.234, Some(0.0561), "1.23(6)"),
+        ("?", 2.0, None, "2"),
+        ("1(2)", 1.0, Some(1e25), "0e24(10)"),
+    ];
+    for &(lexical, value, su, expected) in cases {
+        assert_eq!(
+            format_numeric_preserving(lexical, value, su, &policy),
+            expected,
+            "{lexical}"
+        );
+    }
+}
+
+#[test]
+fn test_measurand_format_cif() {
+    let policy = FormatPolicy::new();
+    let doc =
+        CifDocument::parse("data_t\n_cell_length_a 10.0123(112)\n_cell_angle_beta 90\n").unwrap();
+    let summary = doc.blocks[0].summary();
+    let a = summary.cell[0].unwrap();
+    assert_eq!(a.format_cif(&policy), "10.012(11)");
+    assert_eq!(a.format_cif(&FormatPolicy::exact()), "10.0123(112)");
+    assert_eq!(summary.cell[4].unwrap().format_cif(&policy), "90");
+    assert_eq!(Measurand::new(1.5e20).format_cif(&policy), "1.5e20");
+}
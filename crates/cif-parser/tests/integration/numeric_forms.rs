@@ -0,0 +1,108 @@
+// tests/integration/numeric_forms.rs
+// Every numeric shape of the CIF grammar, with and without uncertainties
+
+use cif_parser::{CifDocument, CifValueKind};
+
+/// Token, value and standard uncertainty
+const NUMBERS: &[(&str, f64, Option<f64>)] = &[
+    ("5", 5.0, None),
+    ("+5", 5.0, None),
+    ("-5", -5.0, None),
+    (".5", 0.5, None),
+    ("+.5", 0.5, None),
+    ("-.5", -0.5, None),
+    ("5.", 5.0, None),
+    ("+5.", 5.0, None),
+    ("-5.", -5.0, None),
+    ("5.25", 5.25, None),
+    ("5e3", 5e3, None),
+    ("+5.E+3", 5e3, None),
+    ("-.5e-3", -0.5e-3, None),
+    ("5(3)", 5.0, Some(3.0)),
+    ("+5(3)", 5.0, Some(3.0)),
+    ("5.(3)", 5.0, Some(3.0)),
+    ("+5.(3)", 5.0, Some(3.0)),
+    ("-5.(3)", -5.0, Some(3.0)),
+    (".5(2)", 0.5, Some(0.2)),
+    ("+.5(2)", 0.5, Some(0.2)),
+    ("-.5(2)", -0.5, Some(0.2)),
+    ("7.470(6)", 7.47, Some(0.006)),
+    ("+7.470(16)", 7.47, Some(0.016)),
+    ("5.e3(2)", 5e3, Some(2e3)),
+    ("+.5E2(3)", 50.0, Some(30.0)),
+    ("-.25e-1(12)", -0.025, Some(0.012)),
+    ("1.5e+2(02)", 150.0, Some(20.0)),
+];
+
+/// Tokens `f64::from_str` would take, or that only look numeric
+const TEXT: &[&str] = &[
+    "inf",
+    "+inf",
+    "-infinity",
+    "NaN",
+    "+",
+    "-.",
+    "+.e3",
+    "e5",
+    "5e",
+    "5e+",
+    "5.5.5",
+    "1_0",
+    "0x10",
+    "5()",
+    "5(a)",
+    "5(-2)",
+    "5(+2)",
+    "5(2",
+    "5(2)(3)",
+    "5e(2)",
+    ".(2)",
+];
+
+fn single_value(token: &str) -> CifValueKind {
+    let cif = format!("data_t\n_x {token}\n");
+    let doc = CifDocument::parse(&cif).unwrap();
+    doc.blocks[0].get_item("_x").unwrap().kind.clone()
+}
+
+#[test]
+fn test_numeric_forms() {
+    for &(token, value, uncertainty) in NUMBERS {
+        let expected = match uncertainty {
+            None => CifValueKind::Numeric(value),
+            Some(uncertainty) => CifValueKind::NumericWithUncertainty { value, uncertainty },
+        };
+        assert_eq!(single_value(token), expected, "{token}");
+    }
+}
+
+#[test]
+fn test_non_numeric_forms_are_text() {
+    for &token in TEXT {
+        assert_eq!(
+            single_value(token),
+            CifValueKind::Text(token.to_string()),
+            "{token}"
+        );
+    }
+}
+
+#[test]
+fn test_numeric_forms_in_loops() {
+    let mut cif = String::from("#\\#CIF_2.0\ndata_t\nloop_\n_x\n");
+    for &(token, _, _) in NUMBERS {
+        cif.push_str(token);
+        cif.push('\n');
+    }
+    let doc = CifDocument::parse(&cif).unwrap();
+    let column = doc.blocks[0].loops[0].get_column("_x").unwrap();
+
+    for (value, &(token, expected, uncertainty)) in column.iter().zip(NUMBERS) {
+        assert_eq!(
+            value.as_numeric_with_uncertainty(),
+            uncertainty.map(|u| (expected, u)),
+            "{token}"
+        );
+        assert_eq!(value.as_numeric(), Some(expected), "{token}");
+    }
+}
@@ -0,0 +1,57 @@
+// tests/integration/ordering.rs
+// Integration tests for the order items and table entries come out in
+
+use cif_parser::parse_string;
+
+const INPUT: &str = r#"#\#CIF_2.0
+data_x
+_zeta 1
+_alpha 2
+_mid {"z":1 "a":2 "m":[3 4]}
+loop_
+_col_b
+_col_a
+5 6
+save_frame
+_second 7
+_first 8
+save_
+"#;
+
+#[test]
+fn test_items_iterate_in_document_order() {
+    let doc = parse_string(INPUT).unwrap();
+    let block = doc.first_block().unwrap();
+
+    let names: Vec<&str> = block.items_iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, ["_zeta", "_alpha", "_mid"]);
+
+    let tags: Vec<&str> = block.all_tags().collect();
+    assert_eq!(
+        tags,
+        ["_zeta", "_alpha", "_mid", "_col_b", "_col_a", "_second", "_first"]
+    );
+
+    let frame = &block.frames[0];
+    let names: Vec<&str> = frame.items_iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, ["_second", "_first"]);
+
+    let table = block.get_item("_mid").unwrap();
+    let keys: Vec<&str> = table.as_table_keys().unwrap().collect();
+    assert_eq!(keys, ["z", "a", "m"]);
+}
+
+#[test]
+fn test_json_is_identical_across_parses() {
+    let json = serde_json::to_string(&parse_string(INPUT).unwrap()).unwrap();
+    for _ in 0..5 {
+        // Each parse builds new maps with new hash seeds
+        let again = serde_json::to_string(&parse_string(INPUT).unwrap()).unwrap();
+        assert_eq!(json, again);
+    }
+
+    let position = |needle: &str| json.find(needle).unwrap();
+    assert!(position("\"_zeta\"") < position("\"_alpha\""));
+    assert!(position("\"z\"") < position("\"a\"") && position("\"a\"") < position("\"m\""));
+    assert!(position("\"_second\"") < position("\"_first\""));
+}
@@ -0,0 +1,257 @@
+// tests/integration/partial_reparse.rs
+// Integration tests for applying text edits with CifDocument::apply_edit:
+// whatever path it takes, the result must match a full parse of the edited text
+
+use crate::fixture_path;
+use cif_parser::{CifDocument, CifError, TextEdit};
+use std::fs;
+
+/// Small deterministic generator (xorshift64), so failures reproduce
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+/// Text inserted by random edits: values, tags, whitespace and the
+/// delimiters most likely to change how the rest of a block parses
+const SNIPPETS: &[&str] = &[
+    "",
+    " ",
+    "\n",
+    "\r\n",
+    "1",
+    "-2.5(3)",
+    "x",
+    "?",
+    ".",
+    "'",
+    "\"",
+    "#",
+    "# comment\n",
+    ";",
+    "\n;\n",
+    "\n;text\n;\n",
+    "_new_tag value\n",
+    "\n_new_tag 'quoted value'\n",
+    "loop_\n_l_a _l_b\n1 2 3 4\n",
+    "save_f\n_in_frame 1\nsave_\n",
+    "data_inserted\n",
+    "\ndata_inserted\n_i 1\n",
+    "[1 2 [3]]",
+    "{'k':1 'm':[2]}",
+    "\"\"\"triple\nquoted\"\"\"",
+    "é",
+];
+
+/// Move `offset` back to the nearest character boundary of `text`
+fn floor_boundary(text: &str, mut offset: usize) -> usize {
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
+
+fn random_edit(rng: &mut Rng, text: &str) -> TextEdit {
+    let start = floor_boundary(text, rng.below(text.len() + 1));
+    let end = floor_boundary(text, (start + rng.below(12)).min(text.len()));
+    TextEdit::new(start..end, SNIPPETS[rng.below(SNIPPETS.len())])
+}
+
+fn to_json(result: &Result<CifDocument, CifError>) -> Result<String, String> {
+    match result {
+        Ok(doc) => Ok(serde_json::to_string(doc).unwrap()),
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+/// Apply `count` random edits in sequence, checking each against a full
+/// parse. Returns how many took the partial path.
+fn check_random_edits(name: &str, text: &str, seed: u64, count: usize) -> usize {
+    let mut rng = Rng(seed);
+    let mut text = text.to_string();
+    let mut doc = CifDocument::parse(&text).unwrap_or_else(|e| panic!("{name}: {e}"));
+    let mut partial = 0;
+
+    for step in 0..count {
+        let edit = random_edit(&mut rng, &text);
+        let new_text = edit.apply_to(&text).unwrap();
+        let full = CifDocument::parse(&new_text);
+        let applied = CifDocument::apply_edit(&text, edit.clone(), &doc);
+
+        let (applied_doc, changed) = match applied {
+            Ok((applied_doc, changed)) => (Ok(applied_doc), Some(changed)),
+            Err(err) => (Err(err), None),
+        };
+        assert_eq!(
+            to_json(&applied_doc),
+            to_json(&full),
+            "{name}, step {step}: {edit:?} differs from a full reparse"
+        );
+
+        // Continue from the edited document, so edits build on spans that
+        // were moved rather than parsed
+        if let (Ok(applied_doc), Some(changed)) = (applied_doc, changed) {
+            if !changed.full_reparse {
+                partial += 1;
+                assert_eq!(changed.reparsed.len(), 1);
+                assert_eq!(changed.shifted.end, applied_doc.blocks.len());
+            }
+            text = new_text;
+            doc = applied_doc;
+        }
+    }
+    partial
+}
+
+/// Several fixtures of the same version, concatenated into one document
+fn concatenated(names: &[&str]) -> String {
+    names
+        .iter()
+        .map(|name| fs::read_to_string(fixture_path(name)).unwrap())
+        .map(|text| text.replace("#\\#CIF_2.0", "# header"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+const MULTI_BLOCK_CIF2: &str = r#"#\#CIF_2.0
+# Several blocks with every kind of value
+data_first
+_list [1 2 [3 'x']]
+_table {'a':1 'b':{'c':[4 5]}}
+loop_
+_l_a
+_l_b
+1 [2 3]
+{'k':4} '''five
+lines'''
+save_frame
+_in_frame 1
+loop_
+_f_a
+?
+save_
+
+data_second
+_text
+;
+line one
+line two
+;
+_after_text 3.5(2)
+
+data_third _inline 1 _list [a b
+c] # comment
+data_fourth
+"#;
+
+#[test]
+fn test_random_edits_match_full_reparse_on_fixtures() {
+    let fixtures = [
+        "simple.cif",
+        "simple_with_loop.cif",
+        "loops.cif",
+        "complex.cif",
+        "high_precision.cif",
+        "cif2_lists.cif",
+        "cif2_tables.cif",
+        "cif2_nested.cif",
+        "cif2_comprehensive.cif",
+        "ccdc_paracetamol.cif",
+        "cod_urea.cif",
+        "pycifrw_xanthine.cif",
+        "validation/text_field_quotes_cif.cif",
+    ];
+    for (seed, name) in fixtures.into_iter().enumerate() {
+        let text = fs::read_to_string(fixture_path(name)).unwrap();
+        check_random_edits(name, &text, seed as u64 + 1, 60);
+    }
+}
+
+#[test]
+fn test_random_edits_match_full_reparse_across_blocks() {
+    let cif1 = concatenated(&[
+        "simple.cif",
+        "ccdc_paracetamol.cif",
+        "complex.cif",
+        "simple_with_loop.cif",
+    ]);
+    let cif2 = format!(
+        "#\\#CIF_2.0\n{}",
+        concatenated(&["cif2_lists.cif", "cif2_tables.cif", "cif2_nested.cif"])
+    );
+
+    let mut partial = 0;
+    for seed in 1..=3 {
+        partial += check_random_edits("CIF 1.1 blocks", &cif1, seed, 100);
+        partial += check_random_edits("CIF 2.0 blocks", &cif2, seed + 10, 100);
+        partial += check_random_edits("inline CIF 2.0", MULTI_BLOCK_CIF2, seed + 20, 100);
+    }
+    // Make sure the partial path is exercised, not just the fallback
+    assert!(partial > 200, "only {partial} edits were partial reparses");
+}
+
+#[test]
+fn test_edit_reparses_one_block_and_moves_the_rest() {
+    let text = "#\\#CIF_2.0\ndata_a\n_x 1\n\ndata_b\n_y [1 2]\nloop_\n_z\n3\n";
+    let doc = CifDocument::parse(text).unwrap();
+
+    // Replace "1" with two lines
+    let edit = TextEdit::new(21..22, "2\n_w 'new'");
+    let (doc, changed) = CifDocument::apply_edit(text, edit, &doc).unwrap();
+    assert!(!changed.full_reparse);
+    assert_eq!(changed.reparsed, 0..1);
+    assert_eq!(changed.shifted, 1..2);
+
+    let block = &doc.blocks[1];
+    assert_eq!(block.span.start_line, 6);
+    let list = block.get_item("_y").unwrap();
+    assert_eq!(list.span.start_line, 7);
+    assert_eq!(list.as_list().unwrap()[1].span.start_col, 7);
+    assert_eq!(block.loops[0].values[0][0].span.start_line, 10);
+}
+
+#[test]
+fn test_edit_across_blocks_reparses_everything() {
+    let text = "data_a\n_x 1\ndata_b\n_y 2\n";
+    let doc = CifDocument::parse(text).unwrap();
+
+    // Deleting "1\nd" joins the blocks: `_x ata_b`
+    let edit = TextEdit::new(10..13, "");
+    let (doc, changed) = CifDocument::apply_edit(text, edit, &doc).unwrap();
+    assert!(changed.full_reparse);
+    assert_eq!(changed.reparsed, 0..1);
+    assert_eq!(
+        doc.blocks[0].get_item("_x").unwrap().as_string(),
+        Some("ata_b")
+    );
+}
+
+#[test]
+fn test_edit_adding_a_block_reparses_everything() {
+    let text = "data_a\n_x 1\ndata_b\n_y 2\n";
+    let doc = CifDocument::parse(text).unwrap();
+
+    let edit = TextEdit::new(12..12, "data_new\n");
+    let (doc, changed) = CifDocument::apply_edit(text, edit, &doc).unwrap();
+    assert!(changed.full_reparse);
+    assert_eq!(doc.blocks.len(), 3);
+    assert_eq!(doc.blocks[1].name, "new");
+}
+
+#[test]
+fn test_invalid_edit_range() {
+    let text = "data_é\n";
+    let doc = CifDocument::parse(text).unwrap();
+    assert!(CifDocument::apply_edit(text, TextEdit::new(6..7, ""), &doc).is_err());
+    assert!(CifDocument::apply_edit(text, TextEdit::new(3..20, ""), &doc).is_err());
+}
@@ -0,0 +1,112 @@
+// tests/integration/quote_style.rs
+// Integration tests for recording how each value was delimited
+
+use cif_parser::{CifDocument, CifValue, QuoteStyle, Span};
+
+const CIF2: &str = "#\\#CIF_2.0
+data_styles
+_bare        word
+_number      1.25(3)
+_unknown     ?
+_single      'single quoted'
+_double      \"double quoted\"
+_triple_s    '''triple single'''
+_triple_d    \"\"\"triple double\"\"\"
+_list        ['a' b]
+_table       {'k':\"v\"}
+_text
+;
+text field
+;
+";
+
+const CIF1: &str = "data_styles
+_single      'single quoted'
+_triple      '''not special'''
+_list        [a b]
+_text
+;
+text field
+;
+loop_
+_col
+'looped' bare
+";
+
+fn quote_of(doc: &CifDocument, tag: &str) -> QuoteStyle {
+    doc.first_block().unwrap().get_item(tag).unwrap().quote
+}
+
+#[test]
+fn test_cif2_styles() {
+    let doc = CifDocument::parse(CIF2).unwrap();
+
+    let expected = [
+        ("_bare", QuoteStyle::Bare),
+        ("_number", QuoteStyle::Bare),
+        ("_unknown", QuoteStyle::Bare),
+        ("_single", QuoteStyle::SingleQuote),
+        ("_double", QuoteStyle::DoubleQuote),
+        ("_triple_s", QuoteStyle::TripleSingle),
+        ("_triple_d", QuoteStyle::TripleDouble),
+        ("_list", QuoteStyle::Bare),
+        ("_table", QuoteStyle::Bare),
+        ("_text", QuoteStyle::TextField),
+    ];
+    for (tag, style) in expected {
+        assert_eq!(quote_of(&doc, tag), style, "{tag}");
+    }
+
+    let block = doc.first_block().unwrap();
+    let list = block.get_item("_list").unwrap().as_list().unwrap();
+    assert_eq!(list[0].quote, QuoteStyle::SingleQuote);
+    assert_eq!(list[1].quote, QuoteStyle::Bare);
+    let table = block.get_item("_table").unwrap();
+    assert_eq!(
+        table.as_table_get("k").unwrap().quote,
+        QuoteStyle::DoubleQuote
+    );
+}
+
+#[test]
+fn test_cif1_styles() {
+    let doc = CifDocument::parse(CIF1).unwrap();
+
+    assert_eq!(quote_of(&doc, "_single"), QuoteStyle::SingleQuote);
+    assert_eq!(quote_of(&doc, "_text"), QuoteStyle::TextField);
+    // Not CIF 1.1 syntax: kept as literal text, written without delimiters
+    assert_eq!(quote_of(&doc, "_triple"), QuoteStyle::Bare);
+    assert_eq!(quote_of(&doc, "_list"), QuoteStyle::Bare);
+
+    let column = doc.first_block().unwrap().loops[0]
+        .get_column("_col")
+        .unwrap();
+    let styles: Vec<QuoteStyle> = column.iter().map(|value| value.quote).collect();
+    assert_eq!(styles, [QuoteStyle::SingleQuote, QuoteStyle::Bare]);
+}
+
+#[test]
+fn test_constructed_values_are_auto() {
+    let value = CifValue::text("made in code", Span::default());
+    assert_eq!(value.quote, QuoteStyle::Auto);
+
+    let quoted = value.clone().with_quote(QuoteStyle::DoubleQuote);
+    assert_eq!(quoted.quote, QuoteStyle::DoubleQuote);
+    // Equality ignores how the value was delimited
+    assert_eq!(quoted, value);
+}
+
+#[test]
+fn test_style_survives_serde() {
+    let doc = CifDocument::parse(CIF2).unwrap();
+    let value = doc.first_block().unwrap().get_item("_triple_d").unwrap();
+
+    let json = serde_json::to_string(value).unwrap();
+    assert!(json.contains("\"quote\":\"triple_double\""));
+    let back: CifValue = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.quote, QuoteStyle::TripleDouble);
+
+    let legacy = json.replace(",\"quote\":\"triple_double\"", "");
+    let back: CifValue = serde_json::from_str(&legacy).unwrap();
+    assert_eq!(back.quote, QuoteStyle::Auto);
+}
@@ -0,0 +1,152 @@
+// tests/integration/serialized.rs
+// The stable serialized form of documents: round trips and golden files
+//
+// Each fixture has its serialized form checked in under
+// fixtures/serialized/. A change to the representation fails here; if it is
+// intended, increment DOCUMENT_FORMAT_VERSION and rewrite the golden files
+// with CIF_UPDATE_GOLDEN=1 cargo test -p cif-parser --test integration serialized
+
+use crate::fixture_path;
+use cif_parser::{parse_string_with_options, CifDocument, ParseOptions, DOCUMENT_FORMAT_VERSION};
+
+const FIXTURES: &[&str] = &[
+    "ccdc_paracetamol.cif",
+    "cif2_comprehensive.cif",
+    "cif2_lists.cif",
+    "cif2_nested.cif",
+    "cif2_tables.cif",
+    "cod_urea.cif",
+    "comments_only.cif",
+    "complex.cif",
+    "crystalmaker_LuAG.cif",
+    "empty_block.cif",
+    "high_precision.cif",
+    "leading_prose.cif",
+    "loops.cif",
+    "numeric_anomalies.cif",
+    "powder_links.cif",
+    "pycifrw_xanthine.cif",
+    "simple.cif",
+    "simple_with_loop.cif",
+    "trailing_comments.cif",
+];
+
+fn parse_fixture(name: &str) -> CifDocument {
+    let content = std::fs::read_to_string(fixture_path(name)).unwrap();
+    let options = ParseOptions::new().skip_leading_garbage(true);
+    parse_string_with_options(&content, options)
+        .unwrap()
+        .document
+}
+
+fn to_json(doc: &CifDocument) -> String {
+    serde_json::to_string_pretty(doc).unwrap() + "\n"
+}
+
+#[test]
+fn test_golden_files() {
+    let update = std::env::var_os("CIF_UPDATE_GOLDEN").is_some();
+
+    for name in FIXTURES {
+        let doc = parse_fixture(name);
+        let json = to_json(&doc);
+        let golden_path = fixture_path("serialized").join(name.replace(".cif", ".json"));
+
+        if update {
+            std::fs::write(&golden_path, &json).unwrap();
+            continue;
+        }
+        let golden = std::fs::read_to_string(&golden_path)
+            .unwrap_or_else(|e| panic!("{}: {e}", golden_path.display()));
+        assert!(
+            json == golden,
+            "{name}: serialized form differs from {}",
+            golden_path.display()
+        );
+
+        // Reading the golden file gives the parsed document back
+        let stored: CifDocument = serde_json::from_str(&golden).unwrap();
+        assert_eq!(stored, doc, "{name}");
+    }
+}
+
+#[test]
+fn test_json_round_trip() {
+    for name in FIXTURES {
+        let doc = parse_fixture(name);
+        let json = to_json(&doc);
+        let back: CifDocument = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(back, doc, "{name}");
+        // Equality ignores quote styles; the serialized form does not
+        assert_eq!(to_json(&back), json, "{name}");
+    }
+}
+
+#[test]
+fn test_binary_round_trip() {
+    for name in FIXTURES {
+        let doc = parse_fixture(name);
+        let mut bytes = Vec::new();
+        ciborium::into_writer(&doc, &mut bytes).unwrap();
+        let back: CifDocument = ciborium::from_reader(bytes.as_slice()).unwrap();
+
+        assert_eq!(back, doc, "{name}");
+        assert_eq!(to_json(&back), to_json(&doc), "{name}");
+
+        // A positional format reads back too
+        let bytes = bincode::serialize(&doc).unwrap();
+        let back: CifDocument = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(back, doc, "{name}");
+    }
+}
+
+#[test]
+fn test_serialization_is_deterministic() {
+    for name in FIXTURES {
+        let first = to_json(&parse_fixture(name));
+        for _ in 0..3 {
+            assert_eq!(to_json(&parse_fixture(name)), first, "{name}");
+        }
+    }
+}
+
+#[test]
+fn test_other_format_versions_rejected() {
+    let doc = parse_fixture("simple.cif");
+    let json = serde_json::to_string(&doc).unwrap();
+    let current = format!(r#"{{"format_version":{DOCUMENT_FORMAT_VERSION},"#);
+    assert!(json.starts_with(&current));
+
+    let newer = json.replacen(&current, r#"{"format_version":99,"#, 1);
+    let err = serde_json::from_str::<CifDocument>(&newer).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("document format version 99 is not supported"),
+        "{err}"
+    );
+
+    let unversioned = json.replacen(&current, "{", 1);
+    let err = serde_json::from_str::<CifDocument>(&unversioned).unwrap_err();
+    assert!(err.to_string().contains("missing field `format_version`"));
+
+    // The version is checked before the rest of the document is read, so a
+    // changed shape is reported as a version mismatch
+    #[derive(serde::Serialize)]
+    struct Reshaped {
+        format_version: u32,
+        blocks: u32,
+    }
+    let reshaped = Reshaped {
+        format_version: 99,
+        blocks: 1,
+    };
+    let mut bytes = Vec::new();
+    ciborium::into_writer(&reshaped, &mut bytes).unwrap();
+    let err = ciborium::from_reader::<CifDocument, _>(bytes.as_slice()).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("document format version 99 is not supported"),
+        "{err}"
+    );
+}
@@ -155,6 +155,10 @@ fn test_complex_multiple_blocks() {
         block2.get_item("_title").unwrap().as_string().unwrap(),
         "Second Data Block"
     );
+
+    // Names are looked up ignoring case
+    let block2 = doc.get_block("BLOCK2").unwrap();
+    assert_eq!(block2.name.as_str(), "block2");
 }
 
 #[test]
@@ -316,6 +316,7 @@ _empty_text
 }
 
 #[test]
+#[allow(clippy::approx_constant)]
 fn test_parse_numeric_formats() {
     let cif_content = r#"
 data_numbers
@@ -430,7 +431,7 @@ value1
             assert!(message.contains("Loop has 2 tags but 1 values"));
             assert!(location.is_some());
             let (line, col) = location.unwrap();
-            assert_eq!(line, 3); // loop_ starts on line 3
+            assert_eq!(line, 6); // value1, which ended the header, is on line 6
             assert!(col > 0);
         } else {
             panic!("Expected InvalidStructure error");
@@ -438,13 +439,82 @@ value1
 
         // Test that error message formatting includes location
         let error_message = format!("{}", err);
-        assert!(error_message.contains("Error at line 3"));
+        assert!(error_message.contains("Error at line 6"));
         assert!(error_message.contains("column"));
     } else {
         panic!("Expected error");
     }
 }
 
+#[test]
+fn test_unterminated_text_field_points_at_opening() {
+    use cif_parser::CifError;
+
+    let cif_content = "data_test\n_cell_length_a 10.5\n_publ_section_comment\n;\nThe closing semicolon is missing\n_cell_length_b 11.2\nloop_\n_atom_site_label\nC1\n";
+
+    match Document::parse(cif_content) {
+        Err(CifError::InvalidStructure { message, location }) => {
+            assert!(message.contains("Unterminated text field"), "{message}");
+            assert_eq!(location, Some((4, 1)));
+        }
+        other => panic!("Expected unterminated text field error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_unterminated_text_field_after_triple_quoted_semicolon() {
+    use cif_parser::CifError;
+
+    // The ';' line inside the triple-quoted string is not a delimiter
+    let cif_content =
+        "#\\#CIF_2.0\ndata_test\n_a '''first\n;not a field\n'''\n_b\n;\nno closing line\n";
+
+    match Document::parse(cif_content) {
+        Err(CifError::InvalidStructure { message, location }) => {
+            assert!(message.contains("Unterminated text field"), "{message}");
+            assert_eq!(location, Some((7, 1)));
+        }
+        other => panic!("Expected unterminated text field error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_unrelated_parse_error_not_blamed_on_text_field() {
+    use cif_parser::CifError;
+
+    // The bad quote comes before the unclosed field, so it stays the reported error
+    let cif_content = "data_test\n_a 'unclosed\n_b\n;\ntext\n";
+
+    let err = Document::parse(cif_content).unwrap_err();
+    assert!(matches!(err, CifError::ParseError(_)), "{err:?}");
+}
+
+#[test]
+fn test_loop_body_errors_point_inside_the_body() {
+    use cif_parser::CifError;
+
+    // Loop bodies are scanned without a parse tree; errors still land on
+    // the offending character rather than the start of the body
+    let err = Document::parse("data_test\nloop_\n_a\n1 \"unterminated\n2\n").unwrap_err();
+    match err {
+        CifError::ParseError(message) => {
+            assert!(message.contains("--> 4:16"), "{message}");
+            assert!(message.contains("expected endq_double"), "{message}");
+            assert!(!message.contains("_traced"), "{message}");
+        }
+        other => panic!("Expected parse error, got {other:?}"),
+    }
+
+    let err = Document::parse("data_test\nloop_\n_a\n_b\n;\nno closing line\n").unwrap_err();
+    match err {
+        CifError::InvalidStructure { message, location } => {
+            assert!(message.contains("Unterminated text field"), "{message}");
+            assert_eq!(location, Some((5, 1)));
+        }
+        other => panic!("Expected unterminated text field error, got {other:?}"),
+    }
+}
+
 #[test]
 fn test_parse_empty_block() {
     let cif = "data_test\n";
@@ -0,0 +1,134 @@
+// tests/integration/tag_renaming.rs
+// Integration tests for renaming tags across a document
+
+use cif_parser::{parse_string, CifDocument, RenameConflict, TagLocation};
+use std::collections::HashMap;
+
+const INPUT: &str = "\
+data_x
+_Old_A 1
+_new_b 2
+loop_
+_old_c
+_keep
+3 4
+save_frame
+_old_a 5
+_old_b 6
+save_
+";
+
+fn renames() -> HashMap<String, String> {
+    [
+        ("_old_a", "_new_a"),
+        ("_old_b", "_new_b"),
+        ("_old_c", "_new_c"),
+    ]
+    .into_iter()
+    .map(|(from, to)| (from.to_string(), to.to_string()))
+    .collect()
+}
+
+fn doc() -> CifDocument {
+    parse_string(INPUT).unwrap()
+}
+
+#[test]
+fn test_rename_items_loops_and_frames() {
+    let original = doc();
+    let mut doc = original.clone();
+    let outcome = doc.rename_tags(&renames());
+
+    assert!(outcome.is_complete());
+    assert_eq!(outcome.renamed.len(), 4);
+    let block = doc.first_block().unwrap();
+    let old_block = original.first_block().unwrap();
+
+    // Matched case-insensitively; value and span carried over
+    assert!(block.get_item("_Old_A").is_none());
+    assert_eq!(block.get_item("_new_a"), old_block.get_item("_Old_A"));
+    assert_eq!(block.loops[0].tags, ["_new_c", "_keep"]);
+    assert_eq!(block.loops[0].values, old_block.loops[0].values);
+
+    let frame = &block.frames[0];
+    assert_eq!(frame.get_item("_new_a").unwrap().as_numeric(), Some(5.0));
+    assert_eq!(frame.get_item("_new_b").unwrap().as_numeric(), Some(6.0));
+    let in_frame = outcome
+        .renamed
+        .iter()
+        .filter(|r| r.location.frame.as_deref() == Some("frame"))
+        .count();
+    assert_eq!(in_frame, 2);
+}
+
+#[test]
+fn test_collision_refused_only_in_its_container() {
+    let mut doc = doc();
+    let mut renames = renames();
+    renames.insert("_keep".to_string(), "_new_c".to_string());
+    let outcome = doc.rename_tags(&renames);
+
+    let block = TagLocation {
+        block: "x".to_string(),
+        frame: None,
+    };
+    let refused: Vec<(&TagLocation, &str, &RenameConflict)> = outcome
+        .refused
+        .iter()
+        .map(|r| (&r.location, r.from.as_str(), &r.conflict))
+        .collect();
+    assert_eq!(
+        refused,
+        [
+            (
+                &block,
+                "_old_c",
+                &RenameConflict::SameTarget {
+                    other: "_keep".to_string()
+                }
+            ),
+            (
+                &block,
+                "_keep",
+                &RenameConflict::SameTarget {
+                    other: "_old_c".to_string()
+                }
+            ),
+        ]
+    );
+
+    // _new_b already exists in the block but not in the frame
+    let data = doc.first_block().unwrap();
+    assert_eq!(data.loops[0].tags, ["_old_c", "_keep"]);
+    assert!(data.frames[0].get_item("_new_b").is_some());
+}
+
+#[test]
+fn test_existing_target_is_refused() {
+    let mut doc = parse_string("data_x\n_old_b 1\n_NEW_B 2\n").unwrap();
+    let outcome = doc.rename_tags(&renames());
+    assert!(outcome.renamed.is_empty());
+    assert_eq!(
+        outcome.refused[0].conflict,
+        RenameConflict::TargetExists {
+            existing: "_NEW_B".to_string()
+        }
+    );
+    let block = doc.first_block().unwrap();
+    assert!(block.get_item("_old_b").is_some());
+    assert!(block.get_item("_new_b").is_none());
+}
+
+#[test]
+fn test_check_renames_leaves_document_alone() {
+    let original = doc();
+    let mut renamed = original.clone();
+    let checked = original.check_renames(&renames());
+    let applied = renamed.rename_tags(&renames());
+
+    assert_eq!(checked, applied);
+    let block = original.first_block().unwrap();
+    assert!(block.get_item("_Old_A").is_some());
+    assert_eq!(block.loops[0].tags, ["_old_c", "_keep"]);
+    assert!(block.frames[0].get_item("_old_a").is_some());
+}
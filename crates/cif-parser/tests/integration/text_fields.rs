@@ -0,0 +1,90 @@
+// tests/integration/text_fields.rs
+// Integration tests for text field delimiters at line starts
+
+use cif_parser::{parse_string, parse_string_with_options, CifValue, ParseOptions, QuoteStyle};
+
+/// Assert a value is a text field whose span runs from the opening ';' in
+/// column 1 to just past the closing ';' in column 1
+fn assert_text_field(value: &CifValue, text: &str, open_line: usize, close_line: usize) {
+    assert_eq!(value.as_string(), Some(text));
+    assert_eq!(value.quote, QuoteStyle::TextField);
+    assert_eq!(
+        (
+            value.span.start_line,
+            value.span.start_col,
+            value.span.end_line,
+            value.span.end_col
+        ),
+        (open_line, 1, close_line, 2),
+        "span of {text:?}"
+    );
+}
+
+#[test]
+fn test_lf_and_crlf_spans_match() {
+    let lf = "data_x\n_a\n;first\n;\n_b\n;\nsecond\n;\n_c x\n";
+    let crlf = lf.replace('\n', "\r\n");
+
+    for input in [lf.to_string(), crlf] {
+        let doc = parse_string(&input).unwrap();
+        let block = doc.first_block().unwrap();
+        assert_text_field(block.get_item("_a").unwrap(), "first", 3, 4);
+        assert_text_field(block.get_item("_b").unwrap(), "second", 6, 8);
+        assert_eq!(block.get_item("_c").unwrap().as_string(), Some("x"));
+    }
+}
+
+#[test]
+fn test_crlf_text_field_after_loop_value() {
+    let input = "data_x\r\nloop_\r\n_a\r\n_b\r\n1\r\n;text\r\n;\r\n2 3\r\n";
+    let doc = parse_string(input).unwrap();
+    let loop_ = &doc.first_block().unwrap().loops[0];
+
+    assert_eq!(loop_.len(), 2);
+    assert_eq!(loop_.get(0, 0).unwrap().as_numeric(), Some(1.0));
+    assert_text_field(loop_.get(0, 1).unwrap(), "text", 6, 7);
+    assert_eq!(loop_.get(1, 1).unwrap().as_numeric(), Some(3.0));
+}
+
+#[test]
+fn test_back_to_back_text_fields() {
+    let input = "data_x\r\nloop_\r\n_a\r\n;one\r\n;\r\n;two\r\n;\r\n";
+    let doc = parse_string(input).unwrap();
+    let loop_ = &doc.first_block().unwrap().loops[0];
+
+    assert_eq!(loop_.len(), 2);
+    assert_text_field(loop_.get(0, 0).unwrap(), "one", 4, 5);
+    assert_text_field(loop_.get(1, 0).unwrap(), "two", 6, 7);
+}
+
+#[test]
+fn test_comment_before_text_field() {
+    let input = "data_x\n_a # note\n;text\n;\n_b\n# note\n;more\n;\n";
+    let doc = parse_string(input).unwrap();
+    let block = doc.first_block().unwrap();
+
+    assert_text_field(block.get_item("_a").unwrap(), "text", 3, 4);
+    assert_text_field(block.get_item("_b").unwrap(), "more", 7, 8);
+}
+
+#[test]
+fn test_semicolon_mid_line_is_not_a_delimiter() {
+    let doc = parse_string("data_x\n_a ;text\n_b 1\n").unwrap();
+    let block = doc.first_block().unwrap();
+    let value = block.get_item("_a").unwrap();
+    assert_eq!(value.as_string(), Some(";text"));
+    assert_eq!(value.quote, QuoteStyle::Bare);
+
+    // Previously read as a text field running to the next line-start ';'
+    assert!(parse_string("data_x\n_a ;text\n_b 1\n;\n").is_err());
+}
+
+#[test]
+fn test_text_field_after_skipped_garbage() {
+    // A text field at the very start of the input is skipped prose here
+    let input = ";not CIF\n;\ndata_x\n_a\n;text\n;\n";
+    let result =
+        parse_string_with_options(input, ParseOptions::new().skip_leading_garbage(true)).unwrap();
+    let block = result.document.first_block().unwrap();
+    assert_text_field(block.get_item("_a").unwrap(), "text", 5, 6);
+}
@@ -0,0 +1,170 @@
+// tests/integration/value_paths.rs
+// Integration tests for walking nested values and addressing them by path
+
+use crate::fixture_path;
+use cif_parser::{CifDocument, CifValue, PathSegment, Span, ValuePath};
+
+fn nested_doc() -> CifDocument {
+    CifDocument::from_file(fixture_path("cif2_nested.cif"))
+        .expect("Failed to parse cif2_nested.cif")
+}
+
+fn paths(value: &CifValue) -> Vec<String> {
+    value.walk().map(|(path, _)| path.to_string()).collect()
+}
+
+#[test]
+fn test_walk_order_is_depth_first() {
+    let doc = nested_doc();
+    let block = doc.first_block().unwrap();
+
+    let matrix = block.get_item("_matrix").unwrap();
+    assert_eq!(
+        paths(matrix),
+        [
+            "",
+            "[0]",
+            "[0][0]",
+            "[0][1]",
+            "[1]",
+            "[1][0]",
+            "[1][1]",
+            "[1][1][0]",
+            "[1][1][1]",
+            "[2]",
+        ]
+    );
+    let leaves: Vec<f64> = matrix
+        .walk()
+        .filter_map(|(_, value)| value.as_numeric())
+        .collect();
+    assert_eq!(leaves, [1.0, 2.0, 3.0, 4.0, 5.0]);
+}
+
+#[test]
+fn test_walk_visits_table_entries_in_document_order() {
+    let doc = nested_doc();
+    let record = doc.first_block().unwrap().get_item("_record").unwrap();
+
+    assert_eq!(
+        paths(record),
+        [
+            "",
+            "name",
+            "tags",
+            "tags[0]",
+            "tags[1]",
+            "tags[2]",
+            "tags[2].deep",
+            "tags[2].deep.leaf",
+            "empty",
+        ]
+    );
+
+    // Entries built in code share a span and come in key order
+    let span = Span::default();
+    let built = CifValue::table(
+        [
+            ("b", CifValue::text("2", span)),
+            ("a", CifValue::text("1", span)),
+        ]
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .collect(),
+        span,
+    );
+    assert_eq!(paths(&built), ["", "a", "b"]);
+}
+
+#[test]
+fn test_get_path_round_trips_walk() {
+    let doc = nested_doc();
+    let block = doc.first_block().unwrap();
+
+    for tag in ["_import.get", "_matrix", "_record"] {
+        let value = block.get_item(tag).unwrap();
+        for (path, nested) in value.walk() {
+            let reparsed = ValuePath::parse(&path.to_string()).unwrap();
+            assert_eq!(reparsed, path, "{tag} path {path}");
+            assert!(
+                std::ptr::eq(value.get_path(&reparsed).unwrap(), nested),
+                "{tag} path {path}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_get_path_lookups() {
+    let doc = nested_doc();
+    let block = doc.first_block().unwrap();
+    let record = block.get_item("_record").unwrap();
+    let imports = block.get_item("_import.get").unwrap();
+
+    let leaf = ValuePath::parse("tags[2].deep.leaf").unwrap();
+    assert_eq!(record.get_path(&leaf).unwrap().as_numeric(), Some(42.0));
+
+    let file = ValuePath::parse("[1].file").unwrap();
+    assert_eq!(
+        imports.get_path(&file).unwrap().as_string(),
+        Some("templ_enum.cif")
+    );
+
+    assert!(std::ptr::eq(
+        record.get_path(&ValuePath::root()).unwrap(),
+        record
+    ));
+    for missing in ["[0]", "tags[3]", "tags.deep", "name.x", "[9].file"] {
+        let path = ValuePath::parse(missing).unwrap();
+        assert!(record.get_path(&path).is_none(), "{missing}");
+    }
+}
+
+#[test]
+fn test_find_tables_with_key() {
+    let doc = nested_doc();
+    let imports = doc.first_block().unwrap().get_item("_import.get").unwrap();
+
+    let saves: Vec<(String, Option<&str>)> = imports
+        .find_tables_with_key("save")
+        .map(|(path, table)| {
+            let save = table.as_table_get("save").unwrap();
+            (path.to_string(), save.as_string())
+        })
+        .collect();
+    assert_eq!(
+        saves,
+        [
+            ("[0]".to_string(), Some("core_def")),
+            ("[1]".to_string(), Some("units_code")),
+            ("[2]".to_string(), None),
+        ]
+    );
+    assert_eq!(imports.find_tables_with_key("absent").count(), 0);
+}
+
+#[test]
+fn test_path_string_form() {
+    let path = ValuePath::root()
+        .index(2)
+        .key("file")
+        .key("a key")
+        .key("say \"hi\"")
+        .index(0);
+    assert_eq!(path.to_string(), r#"[2].file["a key"]["say \"hi\""][0]"#);
+    assert_eq!(ValuePath::parse(&path.to_string()).unwrap(), path);
+
+    let leading = ValuePath::parse("file[0]").unwrap();
+    assert_eq!(
+        leading.segments(),
+        [PathSegment::Key("file".to_string()), PathSegment::Index(0)]
+    );
+    assert_eq!(ValuePath::parse(".file[0]").unwrap(), leading);
+    assert_eq!(leading.parent(), Some(ValuePath::root().key("file")));
+
+    for bad in ["[", "[x]", "[1", "a..b", "[0]x", r#"["open"#, "[-1]"] {
+        assert!(ValuePath::parse(bad).is_err(), "{bad}");
+    }
+    let err = ValuePath::parse("[0]x").unwrap_err();
+    assert_eq!(err.position, 3);
+}
@@ -2,7 +2,7 @@
 // Tests that individual grammar rules parse correctly (version-agnostic)
 // Reference: https://www.iucr.org/resources/cif/spec/version1.1/cifsyntax
 
-use cif_parser::{CIFParser, Rule};
+use cif_parser::internal::{CIFParser, Rule};
 use pest::Parser;
 
 // ===== HELPER MACRO =====
@@ -221,6 +221,26 @@ fn test_text_field() {
     assert_parse!(Rule::textfield, text3);
 }
 
+#[test]
+fn test_text_field_line_starts() {
+    // The start of input and CR LF both count as the start of a line
+    assert_parse!(Rule::text_field, ";text\n;");
+    assert_parse!(Rule::text_field, "\r\n;text\r\n;");
+    assert_parse!(Rule::text_field, "\r;text\r;");
+
+    // A ';' after other characters on the line does not open a text field
+    assert_parse_fails!(Rule::text_field, " ;text\n;");
+}
+
+#[test]
+fn test_wspace_leaves_text_field_line_break() {
+    // The line break before a text field belongs to the text field
+    let pairs = CIFParser::parse(Rule::wspace, "  \r\n;").unwrap();
+    assert_eq!(pairs.as_str(), "  ");
+    let pairs = CIFParser::parse(Rule::wspace, "\n# note\n;").unwrap();
+    assert_eq!(pairs.as_str(), "\n# note");
+}
+
 #[test]
 fn test_unquoted_string() {
     // Paragraph 20: Unquoted strings
@@ -8,8 +8,12 @@
 //! - Empty container name validation
 //! - CIF 2.0 feature gating (lists, tables, triple-quoted strings)
 //! - Reserved character handling
+//! - CIF 2.0 container whitespace rules (commas, table keys and colons)
 
-use cif_parser::{CifDocument, CifVersion};
+use cif_parser::rules::rule_ids;
+use cif_parser::{
+    parse_string_with_options, CifDocument, CifError, CifVersion, MagicHeader, ParseOptions,
+};
 
 // ========================================================================
 // Version Detection Tests
@@ -34,6 +38,90 @@ fn test_cif1_with_comment_not_magic_header() {
     let cif = "# This is a comment\ndata_test\n_item value\n";
     let doc = CifDocument::parse(cif).unwrap();
     assert_eq!(doc.version, CifVersion::V1_1);
+    assert!(doc.header.is_none());
+}
+
+/// Check the header as written against its source line, and read it back
+fn assert_header_round_trips(cif: &str, header: &MagicHeader) {
+    let source = cif
+        .trim_start_matches('\u{FEFF}')
+        .lines()
+        .nth(header.line - 1);
+    let written = header.to_string();
+    assert_eq!(Some(written.as_str()), source);
+
+    let reparsed = CifDocument::parse(&format!("{written}\ndata_copy\n")).unwrap();
+    assert_eq!(reparsed.version, CifVersion::V2_0);
+    let reread = reparsed.header.unwrap();
+    assert_eq!(reread.trailing, header.trailing);
+    assert!(!reread.misplaced);
+}
+
+#[test]
+fn test_cif2_header_with_trailing_text() {
+    let cif = "#\\#CIF_2.0 generated by XYZ 1.2\ndata_test\n_item [1 2 3]\n";
+    let doc = CifDocument::parse(cif).unwrap();
+    assert_eq!(doc.version, CifVersion::V2_0);
+    assert!(doc.blocks[0].get_item("_item").unwrap().is_list());
+
+    let header = doc.header.as_ref().unwrap();
+    assert_eq!(header.line, 1);
+    assert_eq!(header.trailing, " generated by XYZ 1.2");
+    assert_header_round_trips(cif, header);
+}
+
+#[test]
+fn test_cif2_header_after_bom() {
+    let cif = "\u{FEFF}#\\#CIF_2.0\ndata_test\n_item [1 2 3]\n";
+    let doc = CifDocument::parse(cif).unwrap();
+    assert_eq!(doc.version, CifVersion::V2_0);
+
+    let header = doc.header.as_ref().unwrap();
+    assert!(header.has_bom);
+    assert!(header.trailing.is_empty());
+    assert_header_round_trips(cif, header);
+}
+
+#[test]
+fn test_cif2_header_not_on_first_line_rejected() {
+    // A leading comment used to hide the header, so the list parsed as text
+    let cif = "# Written by XYZ\n\n#\\#CIF_2.0\ndata_test\n_item [1 2 3]\n";
+    match CifDocument::parse(cif) {
+        Err(CifError::InvalidStructure { message, location }) => {
+            assert!(message.contains(rule_ids::CIF2_MAGIC_HEADER_NOT_FIRST_LINE));
+            assert!(message.contains("CIF 2.0 header must be on line 1"));
+            assert_eq!(location, Some((3, 1)));
+        }
+        other => panic!("expected a misplaced-header violation, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_cif2_header_after_blank_lines_rejected() {
+    let cif = "\n\n#\\#CIF_2.0\ndata_test\n_item [1 2 3]\n";
+    match CifDocument::parse(cif) {
+        Err(CifError::InvalidStructure { message, location }) => {
+            assert!(message.contains(rule_ids::CIF2_MAGIC_HEADER_NOT_FIRST_LINE));
+            assert_eq!(location, Some((3, 1)));
+        }
+        other => panic!("expected a misplaced-header violation, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_cif2_token_later_in_file_is_not_a_header() {
+    // Only the leading comments are searched for the header
+    let cif = "data_test\n_note\n;\n#\\#CIF_2.0\n;\n";
+    let doc = CifDocument::parse(cif).unwrap();
+    assert_eq!(doc.version, CifVersion::V1_1);
+    assert!(doc.header.is_none());
+
+    let result =
+        parse_string_with_options(cif, ParseOptions::new().upgrade_guidance(true)).unwrap();
+    assert_eq!(
+        result.upgrade_issues[0].rule_id,
+        rule_ids::CIF2_MISSING_MAGIC_HEADER
+    );
 }
 
 // ========================================================================
@@ -310,3 +398,136 @@ fn test_reserved_characters_must_be_quoted() {
         Some("{text}")
     );
 }
+
+// ========================================================================
+// CIF 2.0 Container Whitespace Rules
+// ========================================================================
+
+/// Parse CIF 2.0 content that must fail; return (message, line, column)
+fn cif2_error(body: &str) -> (String, usize, usize) {
+    let input = format!("#\\#CIF_2.0\ndata_test\n{}\n", body);
+    match CifDocument::parse(&input) {
+        Err(CifError::InvalidStructure {
+            message,
+            location: Some((line, col)),
+        }) => (message, line, col),
+        other => panic!(
+            "expected a located violation for {:?}, got {:?}",
+            body, other
+        ),
+    }
+}
+
+#[test]
+fn test_cif2_list_commas_rejected() {
+    // Commas used to be folded into the values: "1," "2," "3"
+    let (message, line, col) = cif2_error("_list [1, 2, 3]");
+    assert!(message.contains(rule_ids::CIF2_COMMA_SEPARATOR));
+    assert!(message.contains("CIF 2.0 lists are whitespace-separated; remove the commas"));
+    assert!(message.contains("Write the list as [1 2 3]"));
+    // Points at the comma after "1", not the opening bracket
+    assert_eq!((line, col), (3, 9));
+
+    let (message, _, col) = cif2_error("_list [1,2,3]");
+    assert!(message.contains(rule_ids::CIF2_COMMA_SEPARATOR));
+    assert_eq!(col, 9);
+}
+
+#[test]
+fn test_cif2_nested_list_commas_rejected() {
+    let (message, line, col) = cif2_error("_list [[1 2] [3,4]]");
+    assert!(message.contains(rule_ids::CIF2_COMMA_SEPARATOR));
+    assert_eq!((line, col), (3, 16));
+}
+
+#[test]
+fn test_cif2_table_commas_rejected() {
+    let (message, _, col) = cif2_error("_point {'x':1.0, 'y':2.0}");
+    assert!(message.contains(rule_ids::CIF2_COMMA_SEPARATOR));
+    assert!(message.contains("CIF 2.0 table entries are whitespace-separated"));
+    assert_eq!(col, 16);
+}
+
+#[test]
+fn test_cif2_table_unquoted_key_rejected() {
+    let (message, line, col) = cif2_error("_point {x:1.0}");
+    assert!(message.contains(rule_ids::CIF2_TABLE_UNQUOTED_KEY));
+    assert!(message.contains("CIF 2.0 table keys must be quoted, found 'x'"));
+    assert!(message.contains("Write the key as 'x'"));
+    assert_eq!((line, col), (3, 9));
+}
+
+#[test]
+fn test_cif2_table_space_after_colon_accepted() {
+    let cif2 =
+        "#\\#CIF_2.0\ndata_test\n_point {'x': 1.0 'y':\t2.0 'z':\n  3.0 'w': # comment\n[4 5]}\n";
+    let doc = CifDocument::parse(cif2).unwrap();
+    let point = doc.first_block().unwrap().items["_point"]
+        .as_table()
+        .unwrap();
+    assert_eq!(point.len(), 4);
+    assert_eq!(point["x"].as_numeric(), Some(1.0));
+    assert_eq!(point["y"].as_numeric(), Some(2.0));
+    assert_eq!(point["z"].as_numeric(), Some(3.0));
+    assert_eq!(point["w"].as_list_len(), Some(2));
+}
+
+#[test]
+fn test_cif2_table_text_field_after_colon() {
+    let cif2 = "#\\#CIF_2.0\ndata_test\n_t {'note':\n;\nline one\n;\n}\n";
+    let doc = CifDocument::parse(cif2).unwrap();
+    let table = doc.first_block().unwrap().items["_t"].as_table().unwrap();
+    assert_eq!(table["note"].as_string(), Some("line one"));
+}
+
+#[test]
+fn test_cif2_table_space_before_colon_rejected() {
+    let (message, _, col) = cif2_error("_point {'x' :1.0}");
+    assert!(message.contains(rule_ids::CIF2_TABLE_SPACE_BEFORE_COLON));
+    assert!(message.contains("whitespace between a table key and its colon"));
+    assert_eq!(col, 12);
+}
+
+#[test]
+fn test_cif2_unquoted_key_reported_before_space() {
+    // `{a :1}` has two problems; the key comes first in the source
+    let (message, _, col) = cif2_error("_point {a :1}");
+    assert!(message.contains(rule_ids::CIF2_TABLE_UNQUOTED_KEY));
+    assert_eq!(col, 9);
+}
+
+#[test]
+fn test_cif2_well_formed_containers_still_parse() {
+    let cif2 = "#\\#CIF_2.0\ndata_test\n_list [1 2 'a,b']\n_point {'x':1.0 'y':[1 2]}\n";
+    let doc = CifDocument::parse(cif2).unwrap();
+    let block = doc.first_block().unwrap();
+    assert_eq!(block.items["_list"].as_list_len(), Some(3));
+    assert_eq!(block.items["_point"].as_table().unwrap().len(), 2);
+}
+
+#[test]
+fn test_cif1_comma_brackets_upgrade_guidance() {
+    // CIF 1.1 keeps the bracket text as-is but suggests the CIF 2.0 form
+    let cif1 = "data_test\n_list [1,2,3]\n_point {x: 1}\n";
+    let result =
+        parse_string_with_options(cif1, ParseOptions::new().upgrade_guidance(true)).unwrap();
+
+    let block = result.document.first_block().unwrap();
+    assert_eq!(block.items["_list"].as_string(), Some("[1,2,3]"));
+    assert_eq!(block.items["_point"].as_string(), Some("{x: 1}"));
+
+    let comma = result
+        .upgrade_issues
+        .iter()
+        .find(|v| v.rule_id == rule_ids::CIF2_COMMA_SEPARATOR)
+        .expect("comma violation");
+    assert_eq!(
+        comma.suggestion.as_deref(),
+        Some("Write the list as [1 2 3]")
+    );
+    assert_eq!((comma.span.start_line, comma.span.start_col), (2, 9));
+
+    let ids: Vec<&str> = result.upgrade_issues.iter().map(|v| v.rule_id).collect();
+    assert!(ids.contains(&rule_ids::CIF2_TABLE_UNQUOTED_KEY));
+    assert!(!ids.contains(&rule_ids::CIF2_TABLE_SPACE_BEFORE_COLON));
+}
@@ -0,0 +1,4 @@
+// The PEST grammar types are not exported from the crate root
+use cif_parser::{CIFParser, Pair, Rule};
+
+fn main() {}
@@ -0,0 +1,18 @@
+// CifValueKind is non-exhaustive: naming every variant is not enough
+use cif_parser::prelude::*;
+
+fn describe(kind: &CifValueKind) -> &'static str {
+    match kind {
+        CifValueKind::Text(_) => "text",
+        CifValueKind::Numeric(_) => "number",
+        CifValueKind::NumericWithUncertainty { .. } => "number",
+        CifValueKind::Unknown => "unknown",
+        CifValueKind::NotApplicable => "n/a",
+        CifValueKind::List(_) => "list",
+        CifValueKind::Table(_) => "table",
+    }
+}
+
+fn main() {
+    println!("{}", describe(&CifValueKind::Unknown));
+}
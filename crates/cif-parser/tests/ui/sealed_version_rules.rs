@@ -0,0 +1,25 @@
+// VersionRules is sealed: a complete impl outside the crate is still refused
+use cif_parser::prelude::*;
+use cif_parser::raw::*;
+use cif_parser::VersionRules;
+
+struct Cif3Rules;
+
+impl VersionRules for Cif3Rules {
+    fn resolve(&self, _: &RawDocument) -> Result<CifDocument, VersionViolation> { todo!() }
+    fn resolve_value(&self, _: &RawValue) -> Result<CifValue, VersionViolation> { todo!() }
+    fn resolve_quoted(&self, _: &RawQuotedString) -> Result<CifValue, VersionViolation> { todo!() }
+    fn resolve_triple_quoted(&self, _: &RawTripleQuoted) -> Result<CifValue, VersionViolation> { todo!() }
+    fn resolve_text_field(&self, _: &RawTextField) -> Result<CifValue, VersionViolation> { todo!() }
+    fn resolve_unquoted(&self, _: &RawUnquoted) -> Result<CifValue, VersionViolation> { todo!() }
+    fn resolve_list(&self, _: &RawListSyntax) -> Result<CifValue, VersionViolation> { todo!() }
+    fn resolve_table(&self, _: &RawTableSyntax) -> Result<CifValue, VersionViolation> { todo!() }
+    fn validate_block_name(&self, _: &str, _: Span) -> Result<(), VersionViolation> { todo!() }
+    fn validate_frame_name(&self, _: &str, _: Span) -> Result<(), VersionViolation> { todo!() }
+    fn resolve_block(&self, _: &RawBlock) -> Result<CifBlock, VersionViolation> { todo!() }
+    fn resolve_frame(&self, _: &RawFrame) -> Result<CifFrame, VersionViolation> { todo!() }
+    fn resolve_loop(&self, _: &RawLoop) -> Result<CifLoop, VersionViolation> { todo!() }
+    fn collect_violations(&self, _: &RawDocument) -> Vec<VersionViolation> { todo!() }
+}
+
+fn main() {}
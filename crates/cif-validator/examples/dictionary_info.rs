@@ -0,0 +1,80 @@
+// Run with: cargo run --example dictionary_info [path/to/dictionary.dic]
+//
+// Loads a DDLm dictionary and prints its metadata, each category with its
+// items, and the items that a dREL method computes. Without an argument it
+// reads the bundled trimmed core dictionary fixtures/examples/core_trimmed.dic.
+
+use cif_validator::{load_dictionary_file, Dictionary, MethodSource};
+use std::error::Error;
+use std::fmt::Write;
+
+const DEFAULT_DICTIONARY: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../fixtures/examples/core_trimmed.dic"
+);
+
+fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| DEFAULT_DICTIONARY.to_string());
+    let dict = load_dictionary_file(&path)?;
+    print!("{}", describe(&dict));
+    Ok(())
+}
+
+/// Metadata, categories with their items, then computed items
+fn describe(dict: &Dictionary) -> String {
+    let mut out = String::new();
+    let meta = &dict.metadata;
+    writeln!(
+        out,
+        "{} {} ({} categories, {} items, {} aliases)",
+        meta.title.as_deref().unwrap_or("untitled"),
+        meta.version.as_deref().unwrap_or("?"),
+        dict.categories.len(),
+        dict.items.len(),
+        dict.aliases.len()
+    )
+    .unwrap();
+
+    for name in dict.category_names() {
+        let category = &dict.categories[name];
+        write!(out, "\n{} [{:?}]", category.name, category.class).unwrap();
+        if !category.key_items.is_empty() {
+            write!(out, " key {}", category.key_items.join(", ")).unwrap();
+        }
+        writeln!(out).unwrap();
+        for item_name in &category.item_names {
+            let item = &dict.items[item_name];
+            let units = item
+                .type_info
+                .units
+                .as_deref()
+                .map_or(String::new(), |units| format!(" in {units}"));
+            writeln!(
+                out,
+                "  {:<32} {:?}{units}",
+                item.name, item.type_info.contents
+            )
+            .unwrap();
+        }
+    }
+
+    writeln!(out, "\nComputed by dREL methods:").unwrap();
+    for name in dict.item_names() {
+        let Some(method) = dict.effective_method(name) else {
+            continue;
+        };
+        let source = match &method.source {
+            MethodSource::Local => "own method".to_string(),
+            MethodSource::Category(category) => format!("method of {category}"),
+            _ => "method".to_string(),
+        };
+        let parses = match dict.get_item(name).and_then(|item| item.method_ast()) {
+            Some(Err(_)) => ", does not parse",
+            _ => "",
+        };
+        writeln!(out, "  {} ({source}{parses})", dict.items[name].name).unwrap();
+    }
+    out
+}
@@ -0,0 +1,109 @@
+// Run with: cargo run --example validate_with_core [--format text|sarif] [path/to/file.cif] [path/to/dictionary.dic]
+//
+// Validates a CIF file against a DDLm dictionary and prints a report of the
+// errors and warnings, grouped by data block. Without arguments it checks
+// the bundled fixtures/examples/structure.cif against the trimmed core
+// dictionary fixtures/examples/core_trimmed.dic. With `--format sarif` the
+// report is a SARIF 2.1.0 log for code-scanning tools instead.
+
+use cif_parser::CifDocument;
+use cif_validator::{report, ValidationMode, ValidationResult, Validator};
+use std::error::Error;
+use std::fmt::Write;
+
+const DEFAULT_INPUT: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../fixtures/examples/structure.cif"
+);
+const DEFAULT_DICTIONARY: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../fixtures/examples/core_trimmed.dic"
+);
+
+/// How the report is written
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Sarif,
+}
+
+/// Split the command line into the report format and the positional paths
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<(Format, Vec<String>), String> {
+    let mut format = Format::Text;
+    let mut paths = Vec::new();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let value = match arg.strip_prefix("--format") {
+            Some("") => args.next().ok_or("--format needs a value")?,
+            Some(rest) if rest.starts_with('=') => rest[1..].to_string(),
+            _ => {
+                paths.push(arg);
+                continue;
+            }
+        };
+        format = match value.as_str() {
+            "text" => Format::Text,
+            "sarif" => Format::Sarif,
+            other => return Err(format!("Unknown format '{other}'; use text or sarif")),
+        };
+    }
+    Ok((format, paths))
+}
+
+fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
+    let (format, paths) = parse_args(std::env::args().skip(1))?;
+    let mut args = paths.into_iter();
+    let input = args.next().unwrap_or_else(|| DEFAULT_INPUT.to_string());
+    let dictionary = args
+        .next()
+        .unwrap_or_else(|| DEFAULT_DICTIONARY.to_string());
+
+    let validator = Validator::new()
+        .with_dictionary_file(&dictionary)?
+        .with_mode(ValidationMode::Strict);
+    let doc = CifDocument::from_file(&input)?;
+    let result = validator.validate(&doc)?;
+
+    match format {
+        Format::Text => print!("{}", render_report(&input, &result)),
+        Format::Sarif => print!("{}", report::to_sarif(&result, &input)),
+    }
+    if !result.is_valid {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Findings grouped by block, in document order, then a one-line verdict
+fn render_report(input: &str, result: &ValidationResult) -> String {
+    let mut out = String::new();
+    writeln!(out, "Validating {input}").unwrap();
+
+    let mut blocks: Vec<&str> = Vec::new();
+    let named = result.errors.iter().map(|e| &e.block);
+    for block in named.chain(result.warnings.iter().map(|w| &w.block)) {
+        if let Some(block) = block.as_deref().filter(|b| !blocks.contains(b)) {
+            blocks.push(block);
+        }
+    }
+    for block in blocks {
+        writeln!(out, "\ndata_{block}").unwrap();
+        let in_block = |name: &Option<String>| name.as_deref() == Some(block);
+        for error in result.errors.iter().filter(|e| in_block(&e.block)) {
+            writeln!(out, "  error   [{}] {}", error.category, error).unwrap();
+        }
+        for warning in result.warnings.iter().filter(|w| in_block(&w.block)) {
+            writeln!(out, "  warning [{:?}] {}", warning.category, warning).unwrap();
+        }
+    }
+
+    let verdict = if result.is_valid { "valid" } else { "INVALID" };
+    writeln!(
+        out,
+        "\n{verdict}: {} error(s), {} warning(s)",
+        result.error_count(),
+        result.warning_count()
+    )
+    .unwrap();
+    out
+}
@@ -0,0 +1,358 @@
+//! Baselines of known findings for regression checks.
+//!
+//! Running the validator over an existing archive usually turns up many
+//! findings nobody will fix soon. A [`Baseline`] records them so that later
+//! runs report only what is new:
+//!
+//! ```rust,ignore
+//! use cif_validator::{Baseline, Validator};
+//!
+//! // Once: record the current findings
+//! let result = Validator::new().with_dictionary_file("cif_core.dic")?.validate(&doc)?;
+//! result.to_baseline().save("validation-baseline.json")?;
+//!
+//! // In CI: findings in the baseline move to `result.suppressed`
+//! let baseline = Baseline::load("validation-baseline.json")?;
+//! let result = Validator::new()
+//!     .with_dictionary_file("cif_core.dic")?
+//!     .with_baseline(baseline)
+//!     .validate(&doc)?;
+//! assert!(result.errors.is_empty());
+//! ```
+//!
+//! Findings are matched by [`Fingerprint`], which leaves out source
+//! positions so that edits elsewhere in a file do not invalidate the
+//! baseline.
+
+use crate::error::{BaselineError, Finding, ValidationError, ValidationResult, ValidationWarning};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+/// Version of the baseline file format written by [`Baseline::save`]
+pub const BASELINE_VERSION: u32 = 1;
+
+/// Position-independent identity of a finding
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Fingerprint {
+    /// Severity and category, e.g. `error.RangeError`
+    pub code: String,
+    /// Data name involved, lowercased
+    pub item: Option<String>,
+    /// Data block the finding is in, lowercased
+    pub block: Option<String>,
+    /// Hash of the finding's message
+    ///
+    /// Messages name the item and the offending value, so the hash tells
+    /// apart findings of the same kind on the same item. A number after
+    /// `line` or `column` is left out of the hash, so a message that mentions
+    /// where something is still matches after the file is edited above it.
+    pub context: String,
+}
+
+impl Fingerprint {
+    /// Fingerprint an error
+    pub fn of_error(error: &ValidationError) -> Self {
+        Self::new(
+            format!("error.{:?}", error.category),
+            error.data_name.as_deref(),
+            error.block.as_deref(),
+            &error.message,
+        )
+    }
+
+    /// Fingerprint a warning
+    pub fn of_warning(warning: &ValidationWarning) -> Self {
+        Self::new(
+            format!("warning.{:?}", warning.category),
+            None,
+            warning.block.as_deref(),
+            &warning.message,
+        )
+    }
+
+    /// Fingerprint an error or warning
+    pub fn of(finding: &Finding) -> Self {
+        match finding {
+            Finding::Error(error) => Self::of_error(error),
+            Finding::Warning(warning) => Self::of_warning(warning),
+        }
+    }
+
+    fn new(code: String, item: Option<&str>, block: Option<&str>, message: &str) -> Self {
+        Self {
+            code,
+            item: item.map(str::to_lowercase),
+            block: block.map(str::to_lowercase),
+            context: context_hash(message),
+        }
+    }
+}
+
+/// FNV-1a hash of a message with whitespace runs collapsed and line and
+/// column numbers replaced by `#`
+///
+/// Written out rather than using `std::hash` so the value is the same across
+/// Rust versions and platforms.
+fn context_hash(message: &str) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let mut normalized = String::with_capacity(message.len());
+    let mut after_position = false;
+    for word in message.split_whitespace() {
+        if !normalized.is_empty() {
+            normalized.push(' ');
+        }
+        let rest = word.trim_start_matches(|c: char| c.is_ascii_digit());
+        if after_position && rest.len() < word.len() {
+            normalized.push('#');
+            normalized.push_str(rest);
+        } else {
+            normalized.push_str(word);
+        }
+        after_position = matches!(
+            word.to_ascii_lowercase().as_str(),
+            "line" | "lines" | "column" | "columns"
+        );
+    }
+    for byte in normalized.bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    format!("{hash:016x}")
+}
+
+/// One distinct finding in a baseline
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    /// Identity used for matching
+    #[serde(flatten)]
+    pub fingerprint: Fingerprint,
+    /// How many findings share this fingerprint
+    pub count: usize,
+    /// Message of the first such finding, for people reading the file
+    pub message: String,
+}
+
+/// A set of known findings to suppress in later validation runs
+///
+/// Created by [`ValidationResult::to_baseline`] and applied with
+/// [`Validator::with_baseline`](crate::Validator::with_baseline) or
+/// [`Baseline::apply`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Baseline {
+    /// File format version
+    pub version: u32,
+    /// Known findings, sorted by fingerprint
+    pub entries: Vec<BaselineEntry>,
+}
+
+impl Default for Baseline {
+    fn default() -> Self {
+        Self {
+            version: BASELINE_VERSION,
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl Baseline {
+    /// Create an empty baseline
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a baseline from findings
+    pub fn from_findings<'a>(findings: impl IntoIterator<Item = &'a Finding>) -> Self {
+        let mut entries: BTreeMap<Fingerprint, BaselineEntry> = BTreeMap::new();
+        for finding in findings {
+            let fingerprint = Fingerprint::of(finding);
+            entries
+                .entry(fingerprint.clone())
+                .or_insert_with(|| BaselineEntry {
+                    fingerprint,
+                    count: 0,
+                    message: finding.message().to_string(),
+                })
+                .count += 1;
+        }
+        Self {
+            version: BASELINE_VERSION,
+            entries: entries.into_values().collect(),
+        }
+    }
+
+    /// Total number of findings recorded
+    pub fn len(&self) -> usize {
+        self.entries.iter().map(|entry| entry.count).sum()
+    }
+
+    /// Whether the baseline records no findings
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether a finding with this fingerprint is recorded
+    pub fn contains(&self, fingerprint: &Fingerprint) -> bool {
+        self.entries
+            .iter()
+            .any(|entry| &entry.fingerprint == fingerprint)
+    }
+
+    /// Move findings recorded in the baseline to `result.suppressed`.
+    ///
+    /// Each recorded finding suppresses at most one finding in the result, so
+    /// a second occurrence of a known problem is still reported. Recorded
+    /// findings left over are counted in `result.baseline_unmatched`, and
+    /// `result.is_valid` is recomputed from the errors that remain.
+    pub fn apply(&self, result: &mut ValidationResult) {
+        let mut remaining: HashMap<&Fingerprint, usize> = self
+            .entries
+            .iter()
+            .map(|entry| (&entry.fingerprint, entry.count))
+            .collect();
+        let mut take = |fingerprint: Fingerprint| match remaining.get_mut(&fingerprint) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                true
+            }
+            _ => false,
+        };
+
+        let (suppressed, errors): (Vec<_>, Vec<_>) = std::mem::take(&mut result.errors)
+            .into_iter()
+            .partition(|error| take(Fingerprint::of_error(error)));
+        result.errors = errors;
+        result
+            .suppressed
+            .extend(suppressed.into_iter().map(Finding::Error));
+
+        let (suppressed, warnings): (Vec<_>, Vec<_>) = std::mem::take(&mut result.warnings)
+            .into_iter()
+            .partition(|warning| take(Fingerprint::of_warning(warning)));
+        result.warnings = warnings;
+        result
+            .suppressed
+            .extend(suppressed.into_iter().map(Finding::Warning));
+
+        result.baseline_unmatched = remaining.values().sum();
+        result.is_valid = result.errors.is_empty();
+    }
+
+    /// Serialize to pretty-printed JSON
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("baseline serializes to JSON")
+    }
+
+    /// Parse a baseline from JSON
+    pub fn from_json(json: &str) -> Result<Self, BaselineError> {
+        let baseline: Self =
+            serde_json::from_str(json).map_err(|e| BaselineError::InvalidFormat(e.to_string()))?;
+        if baseline.version > BASELINE_VERSION {
+            return Err(BaselineError::UnsupportedVersion {
+                found: baseline.version,
+                expected: BASELINE_VERSION,
+            });
+        }
+        Ok(baseline)
+    }
+
+    /// Write the baseline to a JSON file
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), BaselineError> {
+        std::fs::write(path, self.to_json() + "\n")
+            .map_err(|e| BaselineError::IoError(e.to_string()))
+    }
+
+    /// Read a baseline from a JSON file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, BaselineError> {
+        let json =
+            std::fs::read_to_string(path).map_err(|e| BaselineError::IoError(e.to_string()))?;
+        Self::from_json(&json)
+    }
+}
+
+impl Finding {
+    /// Human-readable message of the finding
+    pub fn message(&self) -> &str {
+        match self {
+            Finding::Error(error) => &error.message,
+            Finding::Warning(warning) => &warning.message,
+        }
+    }
+}
+
+impl ValidationResult {
+    /// Record every finding in this result, including suppressed ones.
+    ///
+    /// Saving this after a run with an old baseline gives the updated
+    /// baseline: fixed findings drop out and new ones are added.
+    pub fn to_baseline(&self) -> Baseline {
+        let findings: Vec<Finding> = self
+            .errors
+            .iter()
+            .cloned()
+            .map(Finding::Error)
+            .chain(self.warnings.iter().cloned().map(Finding::Warning))
+            .chain(self.suppressed.iter().cloned())
+            .collect();
+        Baseline::from_findings(&findings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorCategory;
+    use cif_parser::Span;
+
+    fn range_error(value: f64, line: usize) -> ValidationError {
+        let mut error =
+            ValidationError::range_error("_cell.length_a", value, &[], Span::point(line, 1));
+        error.block = Some("Test".to_string());
+        error
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_position() {
+        let a = Fingerprint::of_error(&range_error(-5.0, 3));
+        let b = Fingerprint::of_error(&range_error(-5.0, 40));
+        let c = Fingerprint::of_error(&range_error(-6.0, 3));
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(a.code, "error.RangeError");
+        assert_eq!(a.block.as_deref(), Some("test"));
+    }
+
+    #[test]
+    fn test_context_hash_is_stable() {
+        assert_eq!(context_hash(""), "cbf29ce484222325");
+        assert_eq!(context_hash("a  b"), context_hash("a b"));
+        assert_eq!(
+            context_hash("shifted; check near line 12"),
+            context_hash("shifted; check near line 40")
+        );
+        assert_eq!(context_hash("at column 3."), context_hash("at column 17."));
+        assert_ne!(context_hash("value 12"), context_hash("value 40"));
+    }
+
+    #[test]
+    fn test_apply_counts_duplicates() {
+        let mut result = ValidationResult::new();
+        result.add_error(range_error(-5.0, 3));
+        let baseline = result.to_baseline();
+
+        let mut result = ValidationResult::new();
+        result.add_error(range_error(-5.0, 7));
+        result.add_error(range_error(-5.0, 9));
+        result.add_error(ValidationError::new(
+            ErrorCategory::LoopStructure,
+            "bad loop",
+            Span::point(1, 1),
+        ));
+        baseline.apply(&mut result);
+
+        assert_eq!(result.suppressed.len(), 1);
+        assert_eq!(result.errors.len(), 2);
+        assert_eq!(result.baseline_unmatched, 0);
+        assert!(!result.is_valid);
+    }
+}
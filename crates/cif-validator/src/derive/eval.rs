@@ -0,0 +1,708 @@
+//! Evaluation of dREL methods against one data block.
+//!
+//! An item missing from the block is computed by its Evaluation method when
+//! a method reads it, so items are evaluated in dependency order, each after
+//! the items it needs. Each item is computed at most once per block; a
+//! failure is remembered too and reported to every item that needed it.
+
+use std::collections::HashMap;
+
+use cif_parser::{CifBlock, CifLoop, CifValue};
+use drel_parser::{
+    AssignOp, BinaryOperator, Expr, ExprKind, Program, Span, Stmt, StmtKind, Subscript,
+};
+
+use super::value::{self, CallError, Value};
+use super::{DerivationInput, DerivedValue, FailureReason};
+use crate::dictionary::{CategoryClass, Dictionary, MethodSource};
+use crate::resolve::{find_column, find_looped, find_single_value};
+
+/// Most iterations a `Do` or `Repeat` loop may take
+const MAX_ITERATIONS: usize = 1_000_000;
+
+/// Evaluates methods for the items missing from one block
+pub(super) struct Evaluator<'a> {
+    block: &'a CifBlock,
+    dictionary: &'a Dictionary,
+    /// Items computed so far, in the order they were completed
+    computed: Vec<DerivedValue>,
+    /// Position in `computed` by canonical name
+    index: HashMap<String, usize>,
+    /// Items that could not be computed, by canonical name
+    failed: HashMap<String, FailureReason>,
+    /// Items whose methods are running, outermost first
+    active: Vec<String>,
+}
+
+/// What a method variable stands for
+#[derive(Debug, Clone)]
+enum Binding<'a> {
+    /// A local value
+    Value(Value),
+    /// A Set category, from `With c as cell`
+    Set(String),
+    /// One packet of a category, from `Loop t as atom_type`
+    Packet(Packet<'a>),
+}
+
+/// A packet of a looped category: a loop row, or the standalone items
+#[derive(Debug, Clone)]
+struct Packet<'a> {
+    category: String,
+    row: Option<(&'a CifLoop, usize)>,
+}
+
+/// State of one method while it runs
+struct Run<'a> {
+    /// Definition the method belongs to, for failures
+    method: String,
+    vars: HashMap<String, Binding<'a>>,
+    /// Items the method has read, in the order it read them
+    inputs: Vec<DerivationInput>,
+    /// Items the method has assigned, by canonical name
+    assigned: HashMap<String, Value>,
+}
+
+/// How a statement ended
+enum Flow {
+    Normal,
+    Break,
+    Next,
+}
+
+impl<'a> Evaluator<'a> {
+    pub(super) fn new(block: &'a CifBlock, dictionary: &'a Dictionary) -> Self {
+        Self {
+            block,
+            dictionary,
+            computed: Vec::new(),
+            index: HashMap::new(),
+            failed: HashMap::new(),
+            active: Vec::new(),
+        }
+    }
+
+    /// The block's value of an item, unless it is absent or `?`
+    pub(super) fn present(&self, canonical: &str) -> Option<&'a CifValue> {
+        find_single_value(self.block, self.dictionary, canonical, &[])
+            .map(|(_, value)| value)
+            .filter(|value| !value.is_unknown())
+    }
+
+    /// Mark a computed item as one the caller asked for
+    pub(super) fn request(&mut self, index: usize) {
+        self.computed[index].requested = true;
+    }
+
+    /// Every item computed, inputs first
+    pub(super) fn into_values(self) -> Vec<DerivedValue> {
+        self.computed
+    }
+
+    /// The name the dictionary spells an item with
+    pub(super) fn spelling(&self, canonical: &str) -> String {
+        self.dictionary
+            .get_item(canonical)
+            .map_or_else(|| canonical.to_string(), |def| def.name.clone())
+    }
+
+    /// Compute an item with its method, returning its position in the
+    /// computed values
+    pub(super) fn derive(&mut self, canonical: &str) -> Result<usize, FailureReason> {
+        if let Some(&index) = self.index.get(canonical) {
+            return Ok(index);
+        }
+        if let Some(reason) = self.failed.get(canonical) {
+            return Err(reason.clone());
+        }
+        if let Some(start) = self.active.iter().position(|name| name == canonical) {
+            let mut items: Vec<String> = self.active[start..]
+                .iter()
+                .map(|name| self.spelling(name))
+                .collect();
+            items.push(self.spelling(canonical));
+            return Err(FailureReason::Cycle { items });
+        }
+
+        self.active.push(canonical.to_string());
+        let result = self.run_method(canonical);
+        self.active.pop();
+
+        match result {
+            Ok(derived) => {
+                self.index
+                    .insert(canonical.to_string(), self.computed.len());
+                self.computed.push(derived);
+                Ok(self.computed.len() - 1)
+            }
+            Err(reason) => {
+                self.failed.insert(canonical.to_string(), reason.clone());
+                Err(reason)
+            }
+        }
+    }
+
+    fn run_method(&mut self, canonical: &str) -> Result<DerivedValue, FailureReason> {
+        let dictionary = self.dictionary;
+        let def = dictionary
+            .get_item(canonical)
+            .ok_or(FailureReason::UnknownItem)?;
+        let method = dictionary
+            .effective_method(canonical)
+            .ok_or(FailureReason::NoMethod)?;
+        let (owner, program) = match &method.source {
+            MethodSource::Local => (def.name.clone(), def.method_ast()),
+            MethodSource::Category(name) => (
+                name.clone(),
+                dictionary
+                    .get_category(name)
+                    .and_then(|category| category.method_ast()),
+            ),
+        };
+        let program: &'a Program = program.ok_or(FailureReason::NoMethod)?.map_err(|error| {
+            FailureReason::InvalidMethod {
+                method: owner.clone(),
+                message: error.to_string(),
+            }
+        })?;
+
+        let mut run = Run {
+            method: owner,
+            vars: HashMap::new(),
+            inputs: Vec::new(),
+            assigned: HashMap::new(),
+        };
+        self.exec_block(&mut run, &program.statements)?;
+
+        let Some(value) = run.assigned.remove(canonical) else {
+            let span = match (program.statements.first(), program.statements.last()) {
+                (Some(first), Some(last)) => first.span.merge(last.span),
+                _ => Span::default(),
+            };
+            return Err(run.failure(span, format!("the method does not assign {}", def.name)));
+        };
+        let tag = find_single_value(self.block, dictionary, canonical, &[])
+            .map_or_else(|| def.name.clone(), |(tag, _)| tag.to_string());
+        Ok(DerivedValue {
+            block: self.block.name.to_string(),
+            name: def.name.clone(),
+            tag,
+            value,
+            requested: false,
+            method: run.method,
+            method_span: method.span,
+            inputs: run.inputs,
+        })
+    }
+
+    fn exec_block(&mut self, run: &mut Run<'a>, stmts: &'a [Stmt]) -> Result<Flow, FailureReason> {
+        for stmt in stmts {
+            match self.exec(run, stmt)? {
+                Flow::Normal => {}
+                flow => return Ok(flow),
+            }
+        }
+        Ok(Flow::Normal)
+    }
+
+    fn exec(&mut self, run: &mut Run<'a>, stmt: &'a Stmt) -> Result<Flow, FailureReason> {
+        match &stmt.kind {
+            StmtKind::Assignment { target, op, value } => {
+                let value = self.eval(run, value)?;
+                self.assign(run, target, *op, value)?;
+            }
+            StmtKind::Expr(expr) => {
+                self.eval(run, expr)?;
+            }
+            StmtKind::If {
+                condition,
+                then_block,
+                elseif_blocks,
+                else_block,
+            } => {
+                if self.condition(run, condition)? {
+                    return self.exec_block(run, then_block);
+                }
+                for (condition, block) in elseif_blocks {
+                    if self.condition(run, condition)? {
+                        return self.exec_block(run, block);
+                    }
+                }
+                if let Some(block) = else_block {
+                    return self.exec_block(run, block);
+                }
+            }
+            StmtKind::For {
+                var,
+                iterable,
+                body,
+            } => {
+                let Value::List(items) = self.eval(run, iterable)? else {
+                    return Err(run.failure(iterable.span, "For needs a list to iterate over"));
+                };
+                for item in items {
+                    run.vars.insert(var.clone(), Binding::Value(item));
+                    if let Flow::Break = self.exec_block(run, body)? {
+                        break;
+                    }
+                }
+            }
+            StmtKind::Loop {
+                var,
+                category,
+                index_var,
+                condition,
+                body,
+            } => {
+                for (index, packet) in self.packets(category)?.into_iter().enumerate() {
+                    run.vars.insert(var.clone(), Binding::Packet(packet));
+                    if let Some(index_var) = index_var {
+                        let index = Value::Number(index as f64);
+                        run.vars.insert(index_var.clone(), Binding::Value(index));
+                    }
+                    if let Some(condition) = condition {
+                        if !self.condition(run, condition)? {
+                            continue;
+                        }
+                    }
+                    if let Flow::Break = self.exec_block(run, body)? {
+                        break;
+                    }
+                }
+                run.vars.remove(var);
+            }
+            StmtKind::Do {
+                var,
+                start,
+                end,
+                step,
+                body,
+            } => {
+                let mut current = self.number(run, start)?;
+                let end = self.number(run, end)?;
+                let step = match step {
+                    Some(step) => self.number(run, step)?,
+                    None => 1.0,
+                };
+                if step == 0.0 {
+                    return Err(run.failure(stmt.span, "Do with a step of zero"));
+                }
+                let mut iterations = 0;
+                while (step > 0.0 && current <= end) || (step < 0.0 && current >= end) {
+                    iterations += 1;
+                    if iterations > MAX_ITERATIONS {
+                        return Err(run.failure(stmt.span, "Do loop runs too long"));
+                    }
+                    run.vars
+                        .insert(var.clone(), Binding::Value(Value::Number(current)));
+                    if let Flow::Break = self.exec_block(run, body)? {
+                        break;
+                    }
+                    current += step;
+                }
+            }
+            StmtKind::Repeat { body } => {
+                let mut iterations = 0;
+                loop {
+                    iterations += 1;
+                    if iterations > MAX_ITERATIONS {
+                        return Err(run.failure(stmt.span, "Repeat loop never breaks"));
+                    }
+                    if let Flow::Break = self.exec_block(run, body)? {
+                        break;
+                    }
+                }
+            }
+            StmtKind::With { var, value, body } => {
+                let binding = self.with_binding(run, value)?;
+                // Without braces, the binding holds for the rest of the method
+                if body.is_empty() {
+                    run.vars.insert(var.clone(), binding);
+                    return Ok(Flow::Normal);
+                }
+                let previous = run.vars.insert(var.clone(), binding);
+                let flow = self.exec_block(run, body)?;
+                match previous {
+                    Some(previous) => run.vars.insert(var.clone(), previous),
+                    None => run.vars.remove(var),
+                };
+                return Ok(flow);
+            }
+            StmtKind::FunctionDef { .. } => {
+                return Err(run.unsupported(stmt.span, "a function definition"));
+            }
+            StmtKind::Break => return Ok(Flow::Break),
+            StmtKind::Next => return Ok(Flow::Next),
+        }
+        Ok(Flow::Normal)
+    }
+
+    fn assign(
+        &mut self,
+        run: &mut Run<'a>,
+        target: &'a Expr,
+        op: AssignOp,
+        value: Value,
+    ) -> Result<(), FailureReason> {
+        match &target.kind {
+            ExprKind::Identifier(name) => {
+                let current = match run.vars.get(name) {
+                    Some(Binding::Value(current)) => Some(current),
+                    _ => None,
+                };
+                let value = combine(run, target.span, op, current, value)?;
+                run.vars.insert(name.clone(), Binding::Value(value));
+            }
+            ExprKind::DataName { category, object } => {
+                let canonical = self
+                    .dictionary
+                    .resolve_name(&format!("_{category}.{object}"));
+                let value = combine(run, target.span, op, run.assigned.get(&canonical), value)?;
+                run.assigned.insert(canonical, value);
+            }
+            ExprKind::AttributeRef {
+                target: owner,
+                attribute,
+            } => {
+                let category = match &owner.kind {
+                    ExprKind::Identifier(var) => match run.vars.get(var) {
+                        Some(Binding::Set(category)) => category.clone(),
+                        Some(Binding::Packet(_)) => {
+                            return Err(run.unsupported(target.span, "assignment to a looped item"))
+                        }
+                        _ => return Err(run.unsupported(target.span, "assignment to an attribute")),
+                    },
+                    _ => return Err(run.unsupported(target.span, "assignment to an attribute")),
+                };
+                let canonical = self
+                    .dictionary
+                    .resolve_name(&format!("_{category}.{attribute}"));
+                let value = combine(run, target.span, op, run.assigned.get(&canonical), value)?;
+                run.assigned.insert(canonical, value);
+            }
+            _ => return Err(run.unsupported(target.span, "assignment to an element")),
+        }
+        Ok(())
+    }
+
+    fn eval(&mut self, run: &mut Run<'a>, expr: &'a Expr) -> Result<Value, FailureReason> {
+        let value = match &expr.kind {
+            ExprKind::Integer(n) => Value::Number(*n as f64),
+            ExprKind::Float(n) => Value::Number(*n),
+            ExprKind::String(text) => Value::Text(text.clone()),
+            ExprKind::Imaginary { .. } => {
+                return Err(run.unsupported(expr.span, "an imaginary number"))
+            }
+            ExprKind::Null | ExprKind::Missing => {
+                return Err(run.unsupported(expr.span, "a null or missing literal"))
+            }
+            ExprKind::Table(_) => return Err(run.unsupported(expr.span, "a table")),
+            ExprKind::Identifier(name) => match run.vars.get(name) {
+                Some(Binding::Value(value)) => value.clone(),
+                Some(_) => {
+                    return Err(
+                        run.failure(expr.span, format!("'{name}' is a category, not a value"))
+                    )
+                }
+                None => return Err(run.failure(expr.span, format!("'{name}' has no value"))),
+            },
+            ExprKind::DataName { category, object } => {
+                self.data_value(run, &format!("_{category}.{object}"), expr.span)?
+            }
+            ExprKind::BinaryOp { left, op, right } => {
+                let left = self.eval(run, left)?;
+                match op {
+                    BinaryOperator::And if !run.truth(expr.span, &left)? => Value::Bool(false),
+                    BinaryOperator::Or if run.truth(expr.span, &left)? => Value::Bool(true),
+                    BinaryOperator::And | BinaryOperator::Or => {
+                        let right = self.eval(run, right)?;
+                        Value::Bool(run.truth(expr.span, &right)?)
+                    }
+                    _ => {
+                        let right = self.eval(run, right)?;
+                        value::binary(*op, &left, &right)
+                            .map_err(|message| run.failure(expr.span, message))?
+                    }
+                }
+            }
+            ExprKind::UnaryOp { op, operand } => {
+                let operand = self.eval(run, operand)?;
+                value::unary(*op, &operand).map_err(|message| run.failure(expr.span, message))?
+            }
+            ExprKind::Subscription { target, subscripts } => {
+                self.subscript(run, expr, target, subscripts)?
+            }
+            ExprKind::AttributeRef { target, attribute } => {
+                let binding = match &target.kind {
+                    ExprKind::Identifier(var) => run.vars.get(var).cloned(),
+                    // Fails first if the target itself cannot be evaluated
+                    _ => Some(Binding::Value(self.eval(run, target)?)),
+                };
+                match binding {
+                    Some(Binding::Set(category)) => {
+                        self.data_value(run, &format!("_{category}.{attribute}"), expr.span)?
+                    }
+                    Some(Binding::Packet(packet)) => self.packet_value(run, &packet, attribute)?,
+                    _ => return Err(run.unsupported(expr.span, "an attribute of a value")),
+                }
+            }
+            ExprKind::FunctionCall { function, args } => {
+                let ExprKind::Identifier(name) = &function.kind else {
+                    return Err(run.unsupported(function.span, "a computed function"));
+                };
+                let args = args
+                    .iter()
+                    .map(|arg| self.eval(run, arg))
+                    .collect::<Result<Vec<_>, _>>()?;
+                value::call(name, &args).map_err(|error| match error {
+                    CallError::Unknown => {
+                        run.unsupported(expr.span, format!("the function {name}"))
+                    }
+                    CallError::Invalid(message) => run.failure(expr.span, message),
+                })?
+            }
+            ExprKind::List(items) => Value::List(
+                items
+                    .iter()
+                    .map(|item| self.eval(run, item))
+                    .collect::<Result<_, _>>()?,
+            ),
+        };
+        match value {
+            Value::Number(n) if !n.is_finite() => {
+                Err(run.failure(expr.span, "the result is not a finite number"))
+            }
+            value => Ok(value),
+        }
+    }
+
+    fn subscript(
+        &mut self,
+        run: &mut Run<'a>,
+        expr: &'a Expr,
+        target: &'a Expr,
+        subscripts: &'a [Subscript],
+    ) -> Result<Value, FailureReason> {
+        for subscript in subscripts {
+            match subscript {
+                Subscript::KeyMatch { .. } => {
+                    return Err(run.unsupported(expr.span, "a category key lookup"))
+                }
+                Subscript::Slice { .. } => return Err(run.unsupported(expr.span, "a slice")),
+                Subscript::Index(_) => {}
+            }
+        }
+        let mut value = self.eval(run, target)?;
+        for subscript in subscripts {
+            let Subscript::Index(index) = subscript else {
+                unreachable!("checked above");
+            };
+            let position = self.number(run, index)?;
+            let Value::List(mut items) = value else {
+                return Err(run.failure(expr.span, "only lists can be indexed"));
+            };
+            if position < 0.0 || position.fract() != 0.0 || position as usize >= items.len() {
+                return Err(run.failure(
+                    index.span,
+                    format!("index {position} is outside a list of {}", items.len()),
+                ));
+            }
+            value = items.swap_remove(position as usize);
+        }
+        Ok(value)
+    }
+
+    /// The value of a data name, from the block or computed by its method
+    fn data_value(
+        &mut self,
+        run: &mut Run<'a>,
+        name: &str,
+        span: Span,
+    ) -> Result<Value, FailureReason> {
+        let canonical = self.dictionary.resolve_name(name);
+        if let Some(value) = run.assigned.get(&canonical) {
+            return Ok(value.clone());
+        }
+        let spelling = self.spelling(&canonical);
+        if let Some(value) = self.present(&canonical) {
+            run.read(&spelling, Some(value.span));
+            return Value::from_cif(value).ok_or(FailureReason::MissingInput { item: spelling });
+        }
+        if find_looped(self.block, self.dictionary, &canonical, &[]).is_some() {
+            return Err(run.failure(span, format!("{spelling} is looped; read it in a Loop")));
+        }
+        match self.derive(&canonical) {
+            Ok(index) => {
+                run.read(&spelling, None);
+                Ok(self.computed[index].value.clone())
+            }
+            Err(FailureReason::UnknownItem | FailureReason::NoMethod) => {
+                Err(FailureReason::MissingInput { item: spelling })
+            }
+            Err(reason) => Err(reason),
+        }
+    }
+
+    /// The value of an item in a packet; looped items are not computed
+    fn packet_value(
+        &mut self,
+        run: &mut Run<'a>,
+        packet: &Packet<'a>,
+        attribute: &str,
+    ) -> Result<Value, FailureReason> {
+        let canonical = self
+            .dictionary
+            .resolve_name(&format!("_{}.{attribute}", packet.category));
+        let spelling = self.spelling(&canonical);
+        let value = match packet.row {
+            Some((loop_, row)) => find_column(loop_, self.dictionary, &canonical, &[])
+                .and_then(|col| loop_.get(row, col)),
+            None => self.present(&canonical),
+        };
+        match value.filter(|value| !value.is_unknown()) {
+            Some(value) => {
+                run.read(&spelling, Some(value.span));
+                Value::from_cif(value).ok_or(FailureReason::MissingInput { item: spelling })
+            }
+            None => Err(FailureReason::MissingInput { item: spelling }),
+        }
+    }
+
+    /// The packets of a looped category, or a missing input if the block
+    /// has none
+    fn packets(&self, category: &str) -> Result<Vec<Packet<'a>>, FailureReason> {
+        let dictionary = self.dictionary;
+        let belongs = |tag: &str| {
+            dictionary
+                .get_item(tag)
+                .is_some_and(|def| def.category.eq_ignore_ascii_case(category))
+        };
+        let category = category.to_lowercase();
+        if let Some(loop_) = self
+            .block
+            .loops
+            .iter()
+            .find(|loop_| loop_.tags.iter().any(|tag| belongs(tag)))
+        {
+            return Ok((0..loop_.len())
+                .map(|row| Packet {
+                    category: category.clone(),
+                    row: Some((loop_, row)),
+                })
+                .collect());
+        }
+        if self.block.items.keys().any(|tag| belongs(tag)) {
+            return Ok(vec![Packet {
+                category,
+                row: None,
+            }]);
+        }
+        Err(FailureReason::MissingInput { item: category })
+    }
+
+    fn with_binding(
+        &mut self,
+        run: &mut Run<'a>,
+        value: &'a Expr,
+    ) -> Result<Binding<'a>, FailureReason> {
+        if let ExprKind::Identifier(name) = &value.kind {
+            if !run.vars.contains_key(name) {
+                return match self.dictionary.get_category(name) {
+                    Some(category) if category.class == CategoryClass::Loop => {
+                        Err(run.unsupported(value.span, "With over a looped category"))
+                    }
+                    Some(category) => Ok(Binding::Set(category.name.to_lowercase())),
+                    None => Err(run.failure(value.span, format!("'{name}' is not a category"))),
+                };
+            }
+        }
+        self.eval(run, value).map(Binding::Value)
+    }
+
+    fn condition(&mut self, run: &mut Run<'a>, expr: &'a Expr) -> Result<bool, FailureReason> {
+        let value = self.eval(run, expr)?;
+        run.truth(expr.span, &value)
+    }
+
+    fn number(&mut self, run: &mut Run<'a>, expr: &'a Expr) -> Result<f64, FailureReason> {
+        match self.eval(run, expr)? {
+            Value::Number(n) => Ok(n),
+            _ => Err(run.failure(expr.span, "expected a number")),
+        }
+    }
+}
+
+impl Run<'_> {
+    fn failure(&self, span: Span, message: impl Into<String>) -> FailureReason {
+        FailureReason::Evaluation {
+            method: self.method.clone(),
+            message: message.into(),
+            span,
+        }
+    }
+
+    fn unsupported(&self, span: Span, construct: impl Into<String>) -> FailureReason {
+        FailureReason::Unsupported {
+            method: self.method.clone(),
+            construct: construct.into(),
+            span,
+        }
+    }
+
+    fn truth(&self, span: Span, value: &Value) -> Result<bool, FailureReason> {
+        value
+            .is_true()
+            .map_err(|message| self.failure(span, message))
+    }
+
+    /// Record an item the method read, once per value
+    fn read(&mut self, name: &str, span: Option<cif_parser::Span>) {
+        if !self
+            .inputs
+            .iter()
+            .any(|input| input.name == name && input.span == span)
+        {
+            self.inputs.push(DerivationInput {
+                name: name.to_string(),
+                span,
+            });
+        }
+    }
+}
+
+/// The value an assignment leaves in its target
+fn combine(
+    run: &Run,
+    span: Span,
+    op: AssignOp,
+    current: Option<&Value>,
+    value: Value,
+) -> Result<Value, FailureReason> {
+    if op == AssignOp::Assign {
+        return Ok(value);
+    }
+    let Some(current) = current else {
+        return Err(run.failure(
+            span,
+            format!("'{}' before the target has a value", op.as_str()),
+        ));
+    };
+    let combined = match op {
+        AssignOp::AddAssign => value::binary(BinaryOperator::Add, current, &value),
+        AssignOp::SubAssign => value::binary(BinaryOperator::Sub, current, &value),
+        AssignOp::MulAssign => value::binary(BinaryOperator::Mul, current, &value),
+        AssignOp::AppendAssign | AssignOp::PrependAssign => match current.clone() {
+            Value::List(mut items) => {
+                if op == AssignOp::AppendAssign {
+                    items.push(value);
+                } else {
+                    items.insert(0, value);
+                }
+                Ok(Value::List(items))
+            }
+            _ => Err(format!("'{}' needs a list", op.as_str())),
+        },
+        AssignOp::Assign => unreachable!("handled above"),
+    };
+    combined.map_err(|message| run.failure(span, message))
+}
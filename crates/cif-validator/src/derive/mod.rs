@@ -0,0 +1,278 @@
+//! Computing missing values with dictionary methods.
+//!
+//! [`DerivedCif::compute`] evaluates the dREL Evaluation methods of the
+//! requested items in every block that lacks them. Items the methods read
+//! are taken from the block, or computed by their own methods first, so a
+//! single request works through as much of the dictionary as it needs:
+//!
+//! ```rust,ignore
+//! let validated = validator.validate_typed(doc)?;
+//! let result = DerivedCif::compute(&validated, &["_cell.volume"]);
+//! for failure in &result.failures {
+//!     println!("{}: {}: {}", failure.block, failure.item, failure.reason);
+//! }
+//!
+//! let mut doc = validated.document().clone();
+//! result.apply(&mut doc);
+//! ```
+//!
+//! Each computed value records the method that produced it and the values
+//! that method read, so the result can be checked by hand. Items are only
+//! computed where the block has no value or `?`; a value already present is
+//! used as it is, and `.` is never replaced.
+//!
+//! Methods of looped items are not run: a `Loop` reads the values its
+//! packets hold, and a missing one is a missing input. Function
+//! definitions, category key lookups (`atom_type[.symbol = s]`), slices,
+//! tables and imaginary numbers are not supported yet.
+
+mod eval;
+mod value;
+
+pub use value::Value;
+
+use cif_parser::{CifBlock, CifDocument, CifValue, CifValueKind, Span};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::validated::ValidatedCif;
+use eval::Evaluator;
+
+/// Item [`DerivationResult::apply`] notes the computed items in
+pub const PROVENANCE_ITEM: &str = "_audit.update_record";
+
+/// Computes missing items of a validated document; see the
+/// [module documentation](self)
+#[derive(Debug, Clone, Copy)]
+pub struct DerivedCif;
+
+impl DerivedCif {
+    /// Compute `targets` in every block of the document that lacks them.
+    ///
+    /// Targets are matched like tags, through the dictionary's aliases and
+    /// without regard to case. A target that cannot be computed in a block
+    /// is reported as a [`DerivationFailure`] with the reason.
+    pub fn compute(validated: &ValidatedCif, targets: &[&str]) -> DerivationResult {
+        let dictionary = validated.dictionary();
+        let mut result = DerivationResult::default();
+        for block in &validated.document().blocks {
+            let mut evaluator = Evaluator::new(block, dictionary);
+            for target in targets {
+                let canonical = dictionary.resolve_name(target);
+                if evaluator.present(&canonical).is_some() {
+                    continue;
+                }
+                match evaluator.derive(&canonical) {
+                    Ok(index) => evaluator.request(index),
+                    Err(reason) => result.failures.push(DerivationFailure {
+                        block: block.name.to_string(),
+                        item: evaluator.spelling(&canonical),
+                        reason,
+                    }),
+                }
+            }
+            result.values.extend(evaluator.into_values());
+        }
+        result
+    }
+}
+
+/// Values computed by [`DerivedCif::compute`], and the targets it could not
+/// compute
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DerivationResult {
+    /// Every item computed, block by block, each after the items it was
+    /// computed from
+    pub values: Vec<DerivedValue>,
+    /// Targets that could not be computed
+    pub failures: Vec<DerivationFailure>,
+}
+
+/// An item computed by its method
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DerivedValue {
+    /// Block the value was computed for
+    pub block: String,
+    /// Item name as the dictionary spells it
+    pub name: String,
+    /// Tag [`DerivationResult::apply`] writes the value under: the tag of
+    /// the `?` it replaces, or the item name
+    pub tag: String,
+    /// The computed value
+    pub value: Value,
+    /// Whether the item was a target, rather than computed for one
+    pub requested: bool,
+    /// Definition of the method: the item, or its category
+    pub method: String,
+    /// Location of that definition in its dictionary
+    pub method_span: Span,
+    /// Items the method read, in the order it read them
+    pub inputs: Vec<DerivationInput>,
+}
+
+/// A value a method read
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DerivationInput {
+    /// Item name as the dictionary spells it
+    pub name: String,
+    /// Location of the value in the document, or `None` for a value
+    /// computed earlier in [`DerivationResult::values`]
+    pub span: Option<Span>,
+}
+
+/// A target that could not be computed in a block
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DerivationFailure {
+    /// Block the target was missing from
+    pub block: String,
+    /// Target name as the dictionary spells it
+    pub item: String,
+    /// Why it could not be computed
+    pub reason: FailureReason,
+}
+
+/// Why an item could not be computed
+///
+/// Spans in a reason locate the problem within the method's dREL source.
+#[derive(Debug, Clone, PartialEq, Error, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "reason")]
+#[non_exhaustive]
+pub enum FailureReason {
+    /// The dictionary does not define the item
+    #[error("the dictionary does not define the item")]
+    UnknownItem,
+    /// No Evaluation method computes the item
+    #[error("no Evaluation method computes the item")]
+    NoMethod,
+    /// An item or category the computation needs is absent and cannot be
+    /// computed
+    #[error("{item} is missing and cannot be computed")]
+    MissingInput {
+        /// The missing item, or category for a `Loop`
+        item: String,
+    },
+    /// A method does not parse
+    #[error("the method of {method} does not parse: {message}")]
+    InvalidMethod {
+        /// Definition holding the method
+        method: String,
+        /// The parse error
+        message: String,
+    },
+    /// Items need each other to be computed
+    #[error("{} need each other", items.join(" -> "))]
+    Cycle {
+        /// The items, each needing the next
+        items: Vec<String>,
+    },
+    /// A method failed while running
+    #[error("the method of {method} failed at {span}: {message}")]
+    Evaluation {
+        /// Definition holding the method
+        method: String,
+        /// What went wrong
+        message: String,
+        /// Where in the method
+        span: drel_parser::Span,
+    },
+    /// A method uses dREL the evaluator does not support
+    #[error("the method of {method} uses {construct} at {span}, which cannot be evaluated yet")]
+    Unsupported {
+        /// Definition holding the method
+        method: String,
+        /// The unsupported construct
+        construct: String,
+        /// Where in the method
+        span: drel_parser::Span,
+    },
+}
+
+impl DerivationResult {
+    /// The values of the targets, without the items computed for them
+    pub fn requested(&self) -> impl Iterator<Item = &DerivedValue> {
+        self.values.iter().filter(|value| value.requested)
+    }
+
+    /// The computed value of an item in a block, named as the dictionary
+    /// spells it, ignoring case
+    pub fn get(&self, block: &str, name: &str) -> Option<&DerivedValue> {
+        self.values.iter().find(|value| {
+            value.block.eq_ignore_ascii_case(block) && value.name.eq_ignore_ascii_case(name)
+        })
+    }
+
+    /// Whether every target was computed or already present
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Write the computed targets into a document, such as a copy of the
+    /// one they were computed from.
+    ///
+    /// Each value replaces the `?` it was computed for or is added as a new
+    /// item; items computed only on the way to a target are left out. The
+    /// names of the items written are noted in [`PROVENANCE_ITEM`], added to
+    /// any standalone record the block already has. Blocks are matched by
+    /// name. Returns the number of values written.
+    pub fn apply(&self, document: &mut CifDocument) -> usize {
+        let mut written = 0;
+        for block in &mut document.blocks {
+            let values: Vec<&DerivedValue> = self
+                .requested()
+                .filter(|value| block.name.matches(&value.block))
+                .collect();
+            if values.is_empty() {
+                continue;
+            }
+            for value in &values {
+                set_value(block, &value.tag, value.value.to_cif());
+            }
+            let names: Vec<&str> = values.iter().map(|value| value.name.as_str()).collect();
+            note(
+                block,
+                &format!("Computed from dictionary methods: {}", names.join(", ")),
+            );
+            written += values.len();
+        }
+        written
+    }
+}
+
+/// Replace the value of a tag, standalone or in a one-row loop, or add it
+fn set_value(block: &mut CifBlock, tag: &str, mut value: CifValue) {
+    if let Some(existing) = block.items.get_mut(tag) {
+        value.span = existing.span;
+        *existing = value;
+        return;
+    }
+    for loop_ in &mut block.loops {
+        if let Some(col) = loop_.tags.iter().position(|t| t == tag) {
+            if let Some(existing) = loop_.values.get_mut(0).and_then(|row| row.get_mut(col)) {
+                value.span = existing.span;
+                *existing = value;
+                return;
+            }
+        }
+    }
+    block.items.insert(tag.to_string(), value);
+}
+
+/// Add a line to the block's standalone update record
+fn note(block: &mut CifBlock, line: &str) {
+    let legacy = PROVENANCE_ITEM.replacen('.', "_", 1);
+    let existing = block.items.iter_mut().find(|(tag, _)| {
+        tag.eq_ignore_ascii_case(PROVENANCE_ITEM) || tag.eq_ignore_ascii_case(&legacy)
+    });
+    match existing {
+        Some((_, record)) => {
+            record.kind = match &record.kind {
+                CifValueKind::Text(text) => CifValueKind::Text(format!("{text}\n{line}")),
+                _ => CifValueKind::Text(line.to_string()),
+            };
+        }
+        None => {
+            let record = CifValue::text(line, Span::default());
+            block.items.insert(PROVENANCE_ITEM.to_string(), record);
+        }
+    }
+}
@@ -0,0 +1,455 @@
+//! Values a dREL method computes, and the operators and functions on them.
+//!
+//! Numbers are `f64`, as in [`CifValue`]. Vectors are lists of numbers and
+//! matrices are lists of rows, so `Matrix([1,0,0])` is the list it is given
+//! and `*` between them is the matrix or dot product.
+
+use cif_parser::{CifValue, CifValueKind, Span};
+use drel_parser::{BinaryOperator, UnaryOperator};
+use serde::{Deserialize, Serialize};
+
+/// A value read from a document or computed by a method
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Value {
+    /// A number
+    Number(f64),
+    /// Text
+    Text(String),
+    /// The result of a comparison
+    Bool(bool),
+    /// A list, vector or matrix
+    List(Vec<Value>),
+}
+
+/// Why a function call could not be made
+pub(super) enum CallError {
+    /// No built-in function has this name
+    Unknown,
+    /// The arguments do not suit the function
+    Invalid(String),
+}
+
+impl Value {
+    /// The value of a document value, or `None` for `?`, `.` and tables
+    pub(super) fn from_cif(value: &CifValue) -> Option<Self> {
+        match &value.kind {
+            CifValueKind::Numeric(n) => Some(Self::Number(*n)),
+            CifValueKind::NumericWithUncertainty { value, .. } => Some(Self::Number(*value)),
+            CifValueKind::Text(text) => Some(Self::Text(text.clone())),
+            CifValueKind::List(items) => items
+                .iter()
+                .map(Self::from_cif)
+                .collect::<Option<_>>()
+                .map(Self::List),
+            _ => None,
+        }
+    }
+
+    /// The document value holding this value, with a default span
+    pub fn to_cif(&self) -> CifValue {
+        let span = Span::default();
+        match self {
+            Self::Number(n) => CifValue::numeric(*n, span),
+            Self::Text(text) => CifValue::text(text.clone(), span),
+            Self::Bool(b) => CifValue::text(if *b { "yes" } else { "no" }, span),
+            Self::List(items) => CifValue::list(items.iter().map(Self::to_cif).collect(), span),
+        }
+    }
+
+    /// The number, if this is one
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            Self::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// Whether a condition holding this value is met
+    pub(super) fn is_true(&self) -> Result<bool, String> {
+        match self {
+            Self::Bool(b) => Ok(*b),
+            Self::Number(n) => Ok(*n != 0.0),
+            other => Err(format!("{} used as a condition", other.kind())),
+        }
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::Number(_) => "a number",
+            Self::Text(_) => "text",
+            Self::Bool(_) => "a truth value",
+            Self::List(items)
+                if items
+                    .first()
+                    .is_some_and(|row| matches!(row, Self::List(_))) =>
+            {
+                "a matrix"
+            }
+            Self::List(_) => "a list",
+        }
+    }
+
+    fn number(&self) -> Result<f64, String> {
+        self.as_number()
+            .ok_or_else(|| format!("expected a number, found {}", self.kind()))
+    }
+
+    /// The numbers of a vector
+    fn vector(&self) -> Result<Vec<f64>, String> {
+        match self {
+            Self::List(items) => items.iter().map(Self::number).collect(),
+            other => Err(format!("expected a vector, found {}", other.kind())),
+        }
+    }
+
+    /// The rows of a matrix
+    fn matrix(&self) -> Result<Vec<Vec<f64>>, String> {
+        match self {
+            Self::List(rows) => rows.iter().map(Self::vector).collect(),
+            other => Err(format!("expected a matrix, found {}", other.kind())),
+        }
+    }
+
+    fn is_matrix(&self) -> bool {
+        matches!(self, Self::List(rows) if rows.first().is_some_and(|row| matches!(row, Self::List(_))))
+    }
+}
+
+fn vector(numbers: impl IntoIterator<Item = f64>) -> Value {
+    Value::List(numbers.into_iter().map(Value::Number).collect())
+}
+
+fn matrix(rows: Vec<Vec<f64>>) -> Value {
+    Value::List(rows.into_iter().map(vector).collect())
+}
+
+/// Apply a binary operator other than `and` and `or`
+pub(super) fn binary(op: BinaryOperator, left: &Value, right: &Value) -> Result<Value, String> {
+    use BinaryOperator::*;
+    match op {
+        Eq => Ok(Value::Bool(equal(left, right))),
+        Ne => Ok(Value::Bool(!equal(left, right))),
+        In | NotIn => {
+            let Value::List(items) = right else {
+                return Err(format!(
+                    "'{}' needs a list, found {}",
+                    op.as_str(),
+                    right.kind()
+                ));
+            };
+            let found = items.iter().any(|item| equal(left, item));
+            Ok(Value::Bool(found == (op == In)))
+        }
+        Lt | Gt | Le | Ge => compare(op, left, right),
+        Add => add(left, right),
+        Sub => elementwise(op, left, right, |a, b| a - b),
+        Mul => multiply(left, right),
+        Div => {
+            let divisor = right.number()?;
+            if divisor == 0.0 {
+                return Err("division by zero".to_string());
+            }
+            match left {
+                Value::List(_) => scale(left, 1.0 / divisor),
+                _ => Ok(Value::Number(left.number()? / divisor)),
+            }
+        }
+        Power => Ok(Value::Number(left.number()?.powf(right.number()?))),
+        Cross => {
+            let (a, b) = (left.vector()?, right.vector()?);
+            if a.len() != 3 || b.len() != 3 {
+                return Err("cross product needs two vectors of length 3".to_string());
+            }
+            Ok(vector([
+                a[1] * b[2] - a[2] * b[1],
+                a[2] * b[0] - a[0] * b[2],
+                a[0] * b[1] - a[1] * b[0],
+            ]))
+        }
+        And | Or => unreachable!("short-circuited by the evaluator"),
+    }
+}
+
+/// Apply a unary operator
+pub(super) fn unary(op: UnaryOperator, operand: &Value) -> Result<Value, String> {
+    match op {
+        UnaryOperator::Pos => Ok(operand.clone()),
+        UnaryOperator::Neg => scale(operand, -1.0),
+        UnaryOperator::Not => Ok(Value::Bool(!operand.is_true()?)),
+    }
+}
+
+fn equal(left: &Value, right: &Value) -> bool {
+    match (left, right) {
+        // Symbols and codes in CIF compare without regard to case
+        (Value::Text(a), Value::Text(b)) => a.eq_ignore_ascii_case(b),
+        _ => left == right,
+    }
+}
+
+fn compare(op: BinaryOperator, left: &Value, right: &Value) -> Result<Value, String> {
+    let ordering = match (left, right) {
+        (Value::Text(a), Value::Text(b)) => a.cmp(b),
+        _ => left
+            .number()?
+            .partial_cmp(&right.number()?)
+            .ok_or_else(|| "comparison with a value that is not a number".to_string())?,
+    };
+    Ok(Value::Bool(match op {
+        BinaryOperator::Lt => ordering.is_lt(),
+        BinaryOperator::Gt => ordering.is_gt(),
+        BinaryOperator::Le => ordering.is_le(),
+        _ => ordering.is_ge(),
+    }))
+}
+
+fn add(left: &Value, right: &Value) -> Result<Value, String> {
+    match (left, right) {
+        (Value::Text(a), Value::Text(b)) => Ok(Value::Text(format!("{a}{b}"))),
+        _ => elementwise(BinaryOperator::Add, left, right, |a, b| a + b),
+    }
+}
+
+/// Combine two numbers, or two lists of the same shape element by element
+fn elementwise(
+    op: BinaryOperator,
+    left: &Value,
+    right: &Value,
+    f: fn(f64, f64) -> f64,
+) -> Result<Value, String> {
+    match (left, right) {
+        (Value::List(a), Value::List(b)) if a.len() == b.len() => a
+            .iter()
+            .zip(b)
+            .map(|(a, b)| elementwise(op, a, b, f))
+            .collect::<Result<_, _>>()
+            .map(Value::List),
+        (Value::List(_), Value::List(_)) => Err(format!(
+            "'{}' between lists of different lengths",
+            op.as_str()
+        )),
+        _ => Ok(Value::Number(f(left.number()?, right.number()?))),
+    }
+}
+
+fn scale(value: &Value, factor: f64) -> Result<Value, String> {
+    match value {
+        Value::List(items) => items
+            .iter()
+            .map(|item| scale(item, factor))
+            .collect::<Result<_, _>>()
+            .map(Value::List),
+        _ => Ok(Value::Number(value.number()? * factor)),
+    }
+}
+
+/// Scalar, dot, matrix-vector and matrix products
+fn multiply(left: &Value, right: &Value) -> Result<Value, String> {
+    match (left, right) {
+        (Value::List(_), Value::List(_)) => match (left.is_matrix(), right.is_matrix()) {
+            (false, false) => {
+                let (a, b) = same_length(left.vector()?, right.vector()?)?;
+                Ok(Value::Number(a.iter().zip(&b).map(|(a, b)| a * b).sum()))
+            }
+            (true, false) => {
+                let (rows, v) = (left.matrix()?, right.vector()?);
+                let product: Result<Vec<f64>, String> = rows
+                    .into_iter()
+                    .map(|row| {
+                        let (row, v) = same_length(row, v.clone())?;
+                        Ok(row.iter().zip(&v).map(|(a, b)| a * b).sum())
+                    })
+                    .collect();
+                Ok(vector(product?))
+            }
+            (false, true) => multiply(&transpose(right)?, left),
+            (true, true) => {
+                let columns = transpose(right)?.matrix()?;
+                let rows = left.matrix()?;
+                let product: Result<Vec<Vec<f64>>, String> = rows
+                    .into_iter()
+                    .map(|row| {
+                        columns
+                            .iter()
+                            .map(|column| {
+                                let (row, column) = same_length(row.clone(), column.clone())?;
+                                Ok(row.iter().zip(&column).map(|(a, b)| a * b).sum())
+                            })
+                            .collect()
+                    })
+                    .collect();
+                Ok(matrix(product?))
+            }
+        },
+        (Value::List(_), _) => scale(left, right.number()?),
+        (_, Value::List(_)) => scale(right, left.number()?),
+        _ => Ok(Value::Number(left.number()? * right.number()?)),
+    }
+}
+
+fn same_length(a: Vec<f64>, b: Vec<f64>) -> Result<(Vec<f64>, Vec<f64>), String> {
+    if a.len() == b.len() {
+        Ok((a, b))
+    } else {
+        Err(format!(
+            "product of a length {} and a length {} vector",
+            a.len(),
+            b.len()
+        ))
+    }
+}
+
+fn transpose(value: &Value) -> Result<Value, String> {
+    let rows = value.matrix()?;
+    let width = rows.first().map_or(0, Vec::len);
+    if rows.iter().any(|row| row.len() != width) {
+        return Err("matrix rows of different lengths".to_string());
+    }
+    Ok(matrix(
+        (0..width)
+            .map(|col| rows.iter().map(|row| row[col]).collect())
+            .collect(),
+    ))
+}
+
+/// Call a built-in function; names are matched without regard to case
+pub(super) fn call(name: &str, args: &[Value]) -> Result<Value, CallError> {
+    let one = || match args {
+        [arg] => Ok(arg),
+        _ => Err(CallError::Invalid(format!(
+            "{name} takes 1 argument, given {}",
+            args.len()
+        ))),
+    };
+    let number = |f: fn(f64) -> f64| -> Result<Value, CallError> {
+        let x = one()?.number().map_err(CallError::Invalid)?;
+        Ok(Value::Number(f(x)))
+    };
+    let invalid = CallError::Invalid;
+
+    match name.to_ascii_lowercase().as_str() {
+        "sqrt" => number(f64::sqrt),
+        "exp" => number(f64::exp),
+        "log" => number(f64::ln),
+        "abs" => number(f64::abs),
+        "int" => number(f64::trunc),
+        "float" | "real" => number(|x| x),
+        "sin" => number(f64::sin),
+        "cos" => number(f64::cos),
+        "tan" => number(f64::tan),
+        "asin" => number(f64::asin),
+        "acos" => number(f64::acos),
+        "atan" => number(f64::atan),
+        "sind" => number(|x| x.to_radians().sin()),
+        "cosd" => number(|x| x.to_radians().cos()),
+        "tand" => number(|x| x.to_radians().tan()),
+        "asind" => number(|x| x.asin().to_degrees()),
+        "acosd" => number(|x| x.acos().to_degrees()),
+        "atand" => number(|x| x.atan().to_degrees()),
+        "matrix" | "array" | "list" => Ok(one()?.clone()),
+        "transpose" => transpose(one()?).map_err(invalid),
+        "norm" => {
+            let v = one()?.vector().map_err(invalid)?;
+            Ok(Value::Number(v.iter().map(|x| x * x).sum::<f64>().sqrt()))
+        }
+        "len" => match one()? {
+            Value::List(items) => Ok(Value::Number(items.len() as f64)),
+            Value::Text(text) => Ok(Value::Number(text.chars().count() as f64)),
+            other => Err(invalid(format!("Len of {}", other.kind()))),
+        },
+        "min" | "max" => {
+            let numbers = match args {
+                [Value::List(items)] => items.iter().map(Value::number).collect(),
+                _ => args
+                    .iter()
+                    .map(Value::number)
+                    .collect::<Result<Vec<_>, _>>(),
+            }
+            .map_err(invalid)?;
+            let pick = if name.eq_ignore_ascii_case("min") {
+                f64::min
+            } else {
+                f64::max
+            };
+            numbers
+                .into_iter()
+                .reduce(pick)
+                .map(Value::Number)
+                .ok_or_else(|| invalid(format!("{name} of nothing")))
+        }
+        "mod" => match args {
+            [a, b] => {
+                let (a, b) = (a.number().map_err(invalid)?, b.number().map_err(invalid)?);
+                if b == 0.0 {
+                    return Err(invalid("Mod by zero".to_string()));
+                }
+                Ok(Value::Number(a.rem_euclid(b)))
+            }
+            _ => Err(invalid(format!(
+                "Mod takes 2 arguments, given {}",
+                args.len()
+            ))),
+        },
+        _ => Err(CallError::Unknown),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(numbers: &[f64]) -> Value {
+        vector(numbers.iter().copied())
+    }
+
+    #[test]
+    fn test_products() {
+        let m = matrix(vec![
+            vec![2.0, 0.0, 0.0],
+            vec![1.0, 3.0, 0.0],
+            vec![0.0, 0.0, 4.0],
+        ]);
+        assert_eq!(multiply(&m, &v(&[0.0, 1.0, 0.0])), Ok(v(&[0.0, 3.0, 0.0])));
+        assert_eq!(
+            multiply(&v(&[1.0, 2.0, 3.0]), &v(&[4.0, 5.0, 6.0])),
+            Ok(Value::Number(32.0))
+        );
+        assert_eq!(
+            binary(
+                BinaryOperator::Cross,
+                &v(&[1.0, 0.0, 0.0]),
+                &v(&[0.0, 1.0, 0.0])
+            ),
+            Ok(v(&[0.0, 0.0, 1.0]))
+        );
+        let square = multiply(&m, &m).unwrap();
+        assert_eq!(square.matrix().unwrap()[1], vec![5.0, 9.0, 0.0]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let err = binary(
+            BinaryOperator::Div,
+            &Value::Number(1.0),
+            &Value::Number(0.0),
+        );
+        assert_eq!(err, Err("division by zero".to_string()));
+        let err = multiply(&v(&[1.0, 2.0]), &v(&[1.0, 2.0, 3.0])).unwrap_err();
+        assert!(err.contains("length 2"), "{err}");
+        assert!(matches!(call("Frobnicate", &[]), Err(CallError::Unknown)));
+        assert!(matches!(call("Sqrt", &[]), Err(CallError::Invalid(_))));
+    }
+
+    #[test]
+    fn test_degree_functions() {
+        let Ok(Value::Number(x)) = call("Acosd", &[Value::Number(0.5)]) else {
+            panic!("Acosd failed");
+        };
+        assert!((x - 60.0).abs() < 1e-12);
+        assert_eq!(
+            call("cosd", &[Value::Number(0.0)]).ok(),
+            Some(Value::Number(1.0))
+        );
+    }
+}
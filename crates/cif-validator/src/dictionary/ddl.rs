@@ -0,0 +1,170 @@
+//! Dictionary definition language (DDL) detection.
+//!
+//! CIF dictionaries have been written in three incompatible definition
+//! languages. Each is recognisable from the attribute tags it uses, which
+//! lets loaders refuse a dictionary they cannot interpret instead of
+//! silently producing an empty or half-populated [`Dictionary`](super::Dictionary).
+
+use std::collections::HashSet;
+use std::fmt;
+
+use cif_parser::CifDocument;
+use serde::{Deserialize, Serialize};
+
+/// The definition language a dictionary is written in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum DdlFlavor {
+    /// DDLm (`_definition.id`, `_dictionary.ddl_conformance`), used by CIF 2.0 dictionaries
+    Ddlm,
+    /// DDL2 (`_datablock.id`, `_item.name`), used by mmCIF dictionaries
+    Ddl2,
+    /// DDL1 (`_name`, `_category`), used by legacy core CIF dictionaries
+    Ddl1,
+    /// No recognisable definition attributes
+    #[default]
+    Unknown,
+}
+
+impl fmt::Display for DdlFlavor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ddlm => write!(f, "DDLm"),
+            Self::Ddl2 => write!(f, "DDL2"),
+            Self::Ddl1 => write!(f, "DDL1"),
+            Self::Unknown => write!(f, "unknown DDL"),
+        }
+    }
+}
+
+/// DDLm attribute tags
+const DDLM_TAGS: &[&str] = &[
+    "_dictionary.ddl_conformance",
+    "_definition.id",
+    "_definition.scope",
+];
+
+/// DDL2 attribute tags
+const DDL2_TAGS: &[&str] = &["_datablock.id", "_item.name", "_category.id"];
+
+/// DDL1 attribute tags (both must be present)
+const DDL1_TAGS: &[&str] = &["_name", "_category"];
+
+/// Detect which definition language a dictionary document is written in.
+///
+/// DDLm is checked first, then DDL2, then DDL1, since each later language
+/// uses tags that could appear incidentally in the earlier ones' data.
+///
+/// # Example
+/// ```
+/// use cif_parser::CifDocument;
+/// use cif_validator::dictionary::{detect_ddl, DdlFlavor};
+///
+/// let doc = CifDocument::parse("data_d\nsave_x\n_definition.id '_x.y'\nsave_\n").unwrap();
+/// assert_eq!(detect_ddl(&doc), DdlFlavor::Ddlm);
+/// ```
+pub fn detect_ddl(doc: &CifDocument) -> DdlFlavor {
+    let tags: HashSet<String> = doc.all_tags().map(|t| t.to_lowercase()).collect();
+
+    if DDLM_TAGS.iter().any(|t| tags.contains(*t)) {
+        DdlFlavor::Ddlm
+    } else if DDL2_TAGS.iter().any(|t| tags.contains(*t)) {
+        DdlFlavor::Ddl2
+    } else if DDL1_TAGS.iter().all(|t| tags.contains(*t)) {
+        DdlFlavor::Ddl1
+    } else {
+        DdlFlavor::Unknown
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DDLM_DICT: &str = r#"#\#CIF_2.0
+data_MINIMAL_DDLM
+    _dictionary.title             MINIMAL_DDLM
+    _dictionary.ddl_conformance   4.2.0
+
+save_cell.length_a
+    _definition.id                '_cell.length_a'
+    _type.contents                Real
+save_
+"#;
+
+    const DDL2_DICT: &str = r#"
+data_minimal_ddl2.dic
+    _datablock.id                 minimal_ddl2.dic
+    _dictionary.title             minimal_ddl2.dic
+
+save_cell
+    _category.id                  cell
+    _category.mandatory_code      no
+save_
+
+save__cell.length_a
+    _item.name                    '_cell.length_a'
+    _item.category_id             cell
+    _item_type.code               float
+save_
+"#;
+
+    const DDL1_DICT: &str = r#"
+data_on_this_dictionary
+    _dictionary_name              minimal_ddl1.dic
+    _dictionary_version           1.0
+
+data_cell_length_a
+    _name                         '_cell_length_a'
+    _category                     cell
+    _type                         numb
+"#;
+
+    fn detect(content: &str) -> DdlFlavor {
+        detect_ddl(&CifDocument::parse(content).unwrap())
+    }
+
+    #[test]
+    fn test_detect_ddlm() {
+        assert_eq!(detect(DDLM_DICT), DdlFlavor::Ddlm);
+    }
+
+    #[test]
+    fn test_detect_ddl2() {
+        assert_eq!(detect(DDL2_DICT), DdlFlavor::Ddl2);
+    }
+
+    #[test]
+    fn test_detect_ddl1() {
+        assert_eq!(detect(DDL1_DICT), DdlFlavor::Ddl1);
+    }
+
+    #[test]
+    fn test_detect_unknown() {
+        assert_eq!(
+            detect("data_plain\n_cell.length_a 10.5\n"),
+            DdlFlavor::Unknown
+        );
+    }
+
+    #[test]
+    fn test_wrong_flavor_rejected_by_ddlm_loader() {
+        use crate::dictionary::load_dictionary;
+        use crate::error::DictionaryError;
+
+        for (content, flavor) in [(DDL2_DICT, DdlFlavor::Ddl2), (DDL1_DICT, DdlFlavor::Ddl1)] {
+            let doc = CifDocument::parse(content).unwrap();
+            let errors = load_dictionary(&doc).unwrap_err();
+            assert_eq!(errors.len(), 1);
+            assert!(matches!(
+                errors[0],
+                DictionaryError::WrongDdlFlavor {
+                    detected,
+                    expected: DdlFlavor::Ddlm,
+                } if detected == flavor
+            ));
+        }
+
+        let dict = load_dictionary(&CifDocument::parse(DDLM_DICT).unwrap()).unwrap();
+        assert_eq!(dict.metadata.ddl_flavor, DdlFlavor::Ddlm);
+    }
+}
@@ -0,0 +1,127 @@
+//! DDLm `_import.get` specifications.
+//!
+//! A definition can take attributes from a save frame of another file with
+//! `_import.get`, a list of tables such as
+//! `[{'file':templ_attr.cif 'save':general_su}]`. The loader reads each
+//! table into an [`ImportSpec`] and keeps it on the definition; the imported
+//! files themselves are not read yet.
+//!
+//! ```
+//! use cif_parser::CifDocument;
+//! use cif_validator::dictionary::{read_import_specs, ImportMode};
+//!
+//! let doc = CifDocument::parse(
+//!     "#\\#CIF_2.0\ndata_d\n_import.get [{'file':templ.cif 'save':su 'mode':Full}]\n",
+//! )
+//! .unwrap();
+//! let specs = read_import_specs(doc.blocks[0].get_item("_import.get").unwrap());
+//! assert_eq!(specs[0].file, "templ.cif");
+//! assert_eq!(specs[0].save.as_deref(), Some("su"));
+//! assert_eq!(specs[0].mode, ImportMode::Full);
+//! ```
+
+use cif_parser::{CifValue, Span};
+use serde::{Deserialize, Serialize};
+
+/// Keys DDLm defines for an import table
+pub const IMPORT_KEYS: &[&str] = &["file", "version", "save", "mode", "dupl", "miss"];
+
+/// How much of the imported save frame to take (`mode`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum ImportMode {
+    /// The attributes inside the save frame
+    #[default]
+    Contents,
+    /// The whole save frame, as a new definition
+    Full,
+}
+
+/// What to do when an imported attribute is already defined (`dupl`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum ImportDuplicate {
+    /// Stop with an error
+    #[default]
+    Exit,
+    /// Keep the existing attribute
+    Ignore,
+    /// Take the imported attribute
+    Replace,
+}
+
+/// What to do when the file or save frame cannot be found (`miss`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum ImportMissing {
+    /// Stop with an error
+    #[default]
+    Exit,
+    /// Carry on without the import
+    Ignore,
+}
+
+/// One table of an `_import.get` list
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImportSpec {
+    /// File to import from, as written (a URI relative to the dictionary)
+    pub file: String,
+    /// Save frame to import, if the table names one
+    pub save: Option<String>,
+    /// Version of the file required, if given
+    pub version: Option<String>,
+    /// How much of the save frame to take
+    pub mode: ImportMode,
+    /// What to do with attributes that are already defined
+    pub dupl: ImportDuplicate,
+    /// What to do if the file or save frame is missing
+    pub miss: ImportMissing,
+    /// Keys of the table that DDLm does not define, as written
+    pub unknown_keys: Vec<String>,
+    /// Location of the table in the dictionary file
+    pub span: Span,
+}
+
+/// Read the import tables of an `_import.get` value.
+///
+/// Takes every table that has a `file` key, at any depth, in document order.
+/// Codes are matched case-insensitively; an unrecognised one keeps the
+/// DDLm default.
+pub fn read_import_specs(value: &CifValue) -> Vec<ImportSpec> {
+    value
+        .find_tables_with_key("file")
+        .filter_map(|(_, table)| {
+            let text = |key: &str| {
+                table
+                    .as_table_get(key)
+                    .and_then(|value| value.as_string())
+                    .map(str::to_string)
+            };
+            let code = |key: &str| text(key).map(|code| code.to_lowercase());
+            let mut unknown_keys: Vec<String> = table
+                .as_table_keys()?
+                .filter(|key| !IMPORT_KEYS.contains(key))
+                .map(str::to_string)
+                .collect();
+            unknown_keys.sort();
+
+            Some(ImportSpec {
+                file: text("file")?,
+                save: text("save"),
+                version: text("version"),
+                mode: match code("mode").as_deref() {
+                    Some("full") => ImportMode::Full,
+                    _ => ImportMode::Contents,
+                },
+                dupl: match code("dupl").as_deref() {
+                    Some("ignore") => ImportDuplicate::Ignore,
+                    Some("replace") => ImportDuplicate::Replace,
+                    _ => ImportDuplicate::Exit,
+                },
+                miss: match code("miss").as_deref() {
+                    Some("ignore") => ImportMissing::Ignore,
+                    _ => ImportMissing::Exit,
+                },
+                unknown_keys,
+                span: table.span,
+            })
+        })
+        .collect()
+}
@@ -1,10 +1,19 @@
 //! Dictionary loading from CIF 2.0 files.
 //!
-//! DDLm dictionaries are written in CIF 2.0 format. Each save frame
-//! defines either a category or a data item.
+//! DDLm dictionaries are written in CIF 2.0 format. Most save frames
+//! define a category or a data item; `_definition.class` marks the others.
+//! The `Head` category is recorded on the metadata, the definitions of a
+//! `Functions` category are loaded as dREL functions, and `Attribute`
+//! frames, which define DDL attributes, are counted and skipped.
 
-use cif_parser::{CifBlock, CifDocument, CifFrame, CifValueKind};
+use std::collections::HashSet;
+use std::sync::Arc;
 
+use cif_parser::{CifBlock, CifDocument, CifFrame, CifValue, CifValueKind, QuoteStyle, Span};
+
+use super::ddl::{detect_ddl, DdlFlavor};
+use super::imports::{read_import_specs, ImportSpec};
+use super::memory::CategoryNames;
 use super::types::*;
 use crate::error::DictionaryError;
 
@@ -15,7 +24,9 @@ use crate::error::DictionaryError;
 ///
 /// # Returns
 /// * `Ok(Dictionary)` - The loaded dictionary
-/// * `Err(Vec<DictionaryError>)` - Errors encountered during loading
+/// * `Err(Vec<DictionaryError>)` - Errors encountered during loading, or a
+///   single [`DictionaryError::WrongDdlFlavor`] if the document is a DDL1 or
+///   DDL2 dictionary
 ///
 /// # Example
 /// ```ignore
@@ -26,33 +37,59 @@ use crate::error::DictionaryError;
 /// let dict = load_dictionary(&doc)?;
 /// ```
 pub fn load_dictionary(doc: &CifDocument) -> Result<Dictionary, Vec<DictionaryError>> {
+    let flavor = detect_ddl(doc);
+    if !matches!(flavor, DdlFlavor::Ddlm | DdlFlavor::Unknown) {
+        return Err(vec![DictionaryError::WrongDdlFlavor {
+            detected: flavor,
+            expected: DdlFlavor::Ddlm,
+        }]);
+    }
+
     let mut dict = Dictionary::new();
+    dict.metadata.ddl_flavor = flavor;
     let mut errors = Vec::new();
+    let mut category_names = CategoryNames::default();
 
     // Process first data block (dictionaries typically have one block)
     if let Some(block) = doc.first_block() {
         // Load metadata from block header
         load_metadata(&mut dict.metadata, block);
 
+        // Functions categories may come after the functions they hold
+        let function_categories = function_categories(block);
+
         // Process each save frame
         for frame in &block.frames {
-            match load_frame(frame) {
+            match load_frame(frame, &function_categories) {
                 Ok(FrameContent::Category(cat)) => {
-                    dict.categories.insert(cat.name.to_lowercase(), cat);
+                    if cat.class == CategoryClass::Head {
+                        match &dict.metadata.head_category {
+                            None => dict.metadata.head_category = Some(cat.name.clone()),
+                            Some(head) => errors.push(DictionaryError::InvalidField {
+                                item: cat.definition_id.clone(),
+                                field: "_definition.class".to_string(),
+                                message: format!("'{head}' is already the Head category"),
+                                span: cat.span,
+                            }),
+                        }
+                    }
+                    dict.categories.insert(cat.name.to_lowercase(), *cat);
                 }
-                Ok(FrameContent::Item(item)) => {
+                Ok(FrameContent::Item(mut item)) => {
                     let name_lower = item.name.to_lowercase();
-
-                    // Register aliases
-                    for alias in &item.aliases {
-                        dict.aliases
-                            .insert(alias.to_lowercase(), name_lower.clone());
-                    }
-
-                    dict.items.insert(name_lower, item);
+                    item.category = category_names.intern(&item.category);
+                    dict.register_aliases(&name_lower, &item);
+                    dict.items.insert(name_lower, *item);
+                }
+                Ok(FrameContent::Function(function)) => {
+                    dict.functions
+                        .insert(function.name.to_lowercase(), *function);
+                }
+                Ok(FrameContent::Attribute) => {
+                    dict.metadata.attribute_frames += 1;
                 }
                 Ok(FrameContent::Skip) => {
-                    // Frame type not recognized, skip
+                    // Dictionary-scope frames describe the dictionary itself
                 }
                 Err(e) => {
                     errors.push(e);
@@ -63,6 +100,14 @@ pub fn load_dictionary(doc: &CifDocument) -> Result<Dictionary, Vec<DictionaryEr
 
     // Second pass: populate category.item_names
     populate_category_items(&mut dict);
+    errors.extend(resolve_referenced_contents(&mut dict));
+
+    // Record where each definition came from, for provenance after merging
+    let source = dict.metadata.source_name();
+    for item in dict.items.values_mut() {
+        item.source_dictionary = source.clone();
+    }
+    dict.primary_dictionary = source;
 
     if errors.is_empty() {
         Ok(dict)
@@ -71,11 +116,72 @@ pub fn load_dictionary(doc: &CifDocument) -> Result<Dictionary, Vec<DictionaryEr
     }
 }
 
+/// Give each `ByReference` item the content types of the item it refers to.
+///
+/// References are followed through other `ByReference` items; one that
+/// names no definition, or leads back to itself, is an error.
+fn resolve_referenced_contents(dict: &mut Dictionary) -> Vec<DictionaryError> {
+    let mut errors = Vec::new();
+    let mut resolved = Vec::new();
+    for (key, item) in &dict.items {
+        if item.type_info.contents != ContentType::ByReference {
+            continue;
+        }
+        let mut current = item;
+        let mut visited = vec![key.as_str()];
+        let target = loop {
+            let Some(target) = current
+                .type_info
+                .contents_referenced_id
+                .as_deref()
+                .and_then(|id| dict.get_item(id))
+            else {
+                break None;
+            };
+            if visited
+                .iter()
+                .any(|seen| target.name.eq_ignore_ascii_case(seen))
+            {
+                break None;
+            }
+            if target.type_info.contents != ContentType::ByReference {
+                break Some(target);
+            }
+            visited.push(&target.name);
+            current = target;
+        };
+        match (target, &item.type_info.contents_referenced_id) {
+            (Some(target), _) => {
+                resolved.push((key.clone(), target.type_info.content_types.clone()))
+            }
+            (None, Some(referenced)) => errors.push(DictionaryError::DanglingContentsReference {
+                item: item.name.clone(),
+                referenced: referenced.clone(),
+                span: item.span,
+            }),
+            (None, None) => errors.push(DictionaryError::MissingField {
+                item: item.name.clone(),
+                field: "_type.contents_referenced_id".to_string(),
+                span: item.span,
+            }),
+        }
+    }
+    for (key, content_types) in resolved {
+        if let Some(item) = dict.items.get_mut(&key) {
+            item.type_info.content_types = content_types;
+        }
+    }
+    errors.sort_by_key(|error| error.span().map(|span| (span.start_line, span.start_col)));
+    errors
+}
+
 /// Result of loading a save frame
 enum FrameContent {
-    Category(Category),
-    Item(DataItem),
-    Skip, // Unknown frame type
+    Category(Box<Category>),
+    Item(Box<DataItem>),
+    Function(Box<FunctionDef>),
+    Attribute,
+    Skip, // Dictionary-scope frame
 }
 
 /// Load dictionary metadata from block header items
@@ -88,24 +194,84 @@ fn load_metadata(metadata: &mut DictionaryMetadata, block: &CifBlock) {
     metadata.namespace = get_string_item(block, "_dictionary.namespace");
 }
 
-/// Load a single save frame, returning Category or DataItem
-fn load_frame(frame: &CifFrame) -> Result<FrameContent, DictionaryError> {
-    // Determine if this is a category or item definition
-    let scope = get_string_item_frame(frame, "_definition.scope");
-
-    match scope.as_deref() {
-        Some("Category") | Some("category") => load_category(frame).map(FrameContent::Category),
-        _ => {
-            // Check if this has type info (indicating it's a data item)
-            if frame.get_item("_type.contents").is_some()
-                || frame.get_item("_definition.id").is_some()
-            {
-                load_item(frame).map(FrameContent::Item)
-            } else {
-                // Unknown frame type, skip
-                Ok(FrameContent::Skip)
+/// Names (lowercase) of the categories of class `Functions`
+fn function_categories(block: &CifBlock) -> HashSet<String> {
+    block
+        .frames
+        .iter()
+        .filter(|frame| {
+            frame_code(frame, "_definition.scope").as_deref() == Some("category")
+                && frame_code(frame, "_definition.class").as_deref() == Some("functions")
+        })
+        .filter_map(|frame| {
+            get_string_item_frame(frame, "_name.object_id")
+                .or_else(|| get_string_item_frame(frame, "_definition.id"))
+        })
+        .map(|name| name.to_lowercase())
+        .collect()
+}
+
+/// Load a single save frame, classified by its scope and class
+///
+/// The scope defaults to Item and codes ignore case. An item is a dREL
+/// function when its class is `Functions` or its category's class is.
+/// A scope or class outside the DDLm vocabulary, or an item class on a
+/// category (or the reverse), is an error rather than a guess.
+fn load_frame(
+    frame: &CifFrame,
+    function_categories: &HashSet<String>,
+) -> Result<FrameContent, DictionaryError> {
+    let scope = frame_code(frame, "_definition.scope");
+    let class = frame_code(frame, "_definition.class");
+
+    match scope.as_deref().unwrap_or("item") {
+        "category" => match class.as_deref() {
+            None | Some("head" | "loop" | "set" | "ref-loop" | "functions") => {
+                load_category(frame).map(|cat| FrameContent::Category(Box::new(cat)))
             }
-        }
+            Some(_) => Err(invalid_code(frame, "_definition.class", "a category class")),
+        },
+        "item" => match class.as_deref() {
+            Some("attribute") => Ok(FrameContent::Attribute),
+            Some("functions") => load_function(frame).map(|f| FrameContent::Function(Box::new(f))),
+            None | Some("datum") if in_function_category(frame, function_categories) => {
+                load_function(frame).map(|f| FrameContent::Function(Box::new(f)))
+            }
+            None | Some("datum") => load_item(frame).map(|item| FrameContent::Item(Box::new(item))),
+            Some(_) => Err(invalid_code(frame, "_definition.class", "an item class")),
+        },
+        "dictionary" => Ok(FrameContent::Skip),
+        _ => Err(invalid_code(
+            frame,
+            "_definition.scope",
+            "one of Dictionary, Category or Item",
+        )),
+    }
+}
+
+/// Whether an item frame belongs to one of the `Functions` categories
+fn in_function_category(frame: &CifFrame, function_categories: &HashSet<String>) -> bool {
+    let category = get_string_item_frame(frame, "_definition.id")
+        .and_then(|id| parse_data_name(&id))
+        .map(|(category, _)| category)
+        .or_else(|| get_string_item_frame(frame, "_name.category_id"));
+    category.is_some_and(|category| function_categories.contains(&category.to_lowercase()))
+}
+
+/// A code-valued frame item, lowercased
+fn frame_code(frame: &CifFrame, name: &str) -> Option<String> {
+    get_string_item_frame(frame, name).map(|code| code.to_lowercase())
+}
+
+/// Error for a frame item whose code is not `expected`
+fn invalid_code(frame: &CifFrame, field: &str, expected: &str) -> DictionaryError {
+    let value = get_string_item_frame(frame, field).unwrap_or_default();
+    DictionaryError::InvalidField {
+        item: get_string_item_frame(frame, "_definition.id")
+            .unwrap_or_else(|| frame.name.to_string()),
+        field: field.to_string(),
+        message: format!("'{value}' is not {expected}"),
+        span: frame.span,
     }
 }
 
@@ -113,7 +279,7 @@ fn load_frame(frame: &CifFrame) -> Result<FrameContent, DictionaryError> {
 fn load_category(frame: &CifFrame) -> Result<Category, DictionaryError> {
     let definition_id = get_string_item_frame(frame, "_definition.id").ok_or_else(|| {
         DictionaryError::MissingField {
-            item: frame.name.clone(),
+            item: frame.name.to_string(),
             field: "_definition.id".to_string(),
             span: frame.span,
         }
@@ -130,15 +296,22 @@ fn load_category(frame: &CifFrame) -> Result<Category, DictionaryError> {
     // Extract key items from _category_key.name (may be in a loop)
     let key_items = extract_category_keys(frame);
 
+    let (drel_method, method_purpose, method_origin) = extract_method(frame);
+
     Ok(Category {
         name: name.to_lowercase(),
         definition_id,
-        description: get_string_item_frame(frame, "_description.text"),
+        description: get_string_item_frame(frame, "_description.text").map(Arc::from),
         class,
         parent,
         key_items,
         item_names: Vec::new(), // Populated in second pass
+        drel_method: drel_method.map(Arc::from),
+        method_purpose,
+        method_origin,
+        imports: extract_imports(frame),
         span: frame.span,
+        method_cache: Default::default(),
     })
 }
 
@@ -146,7 +319,7 @@ fn load_category(frame: &CifFrame) -> Result<Category, DictionaryError> {
 fn load_item(frame: &CifFrame) -> Result<DataItem, DictionaryError> {
     let name = get_string_item_frame(frame, "_definition.id").ok_or_else(|| {
         DictionaryError::MissingField {
-            item: frame.name.clone(),
+            item: frame.name.to_string(),
             field: "_definition.id".to_string(),
             span: frame.span,
         }
@@ -173,23 +346,124 @@ fn load_item(frame: &CifFrame) -> Result<DataItem, DictionaryError> {
     let links = extract_links(frame);
 
     // Extract dREL method
-    let drel_method = get_string_item_frame(frame, "_method.expression");
+    let (drel_method, method_purpose, method_origin) = extract_method(frame);
 
     Ok(DataItem {
         name,
-        category,
+        category: category.into(),
         object,
         aliases,
         type_info,
         constraints,
         links,
-        description: get_string_item_frame(frame, "_description.text"),
+        description: get_string_item_frame(frame, "_description.text").map(Arc::from),
         default: get_string_item_frame(frame, "_enumeration.default"),
-        drel_method,
+        drel_method: drel_method.map(Arc::from),
+        method_purpose,
+        method_origin,
+        imports: extract_imports(frame),
         span: frame.span,
+        source_dictionary: None,
+        method_cache: Default::default(),
     })
 }
 
+/// Load a dREL function definition from a save frame
+///
+/// The function is named by `_name.object_id`, or else by the last part of
+/// its definition id, and must have a method.
+fn load_function(frame: &CifFrame) -> Result<FunctionDef, DictionaryError> {
+    let definition_id = get_string_item_frame(frame, "_definition.id").ok_or_else(|| {
+        DictionaryError::MissingField {
+            item: frame.name.to_string(),
+            field: "_definition.id".to_string(),
+            span: frame.span,
+        }
+    })?;
+    let name = get_string_item_frame(frame, "_name.object_id").unwrap_or_else(|| {
+        let object = definition_id.rsplit('.').next().unwrap_or(&definition_id);
+        object.trim_start_matches('_').to_string()
+    });
+    let (drel_source, _, method_origin) = extract_method(frame);
+    let drel_source = drel_source.ok_or_else(|| DictionaryError::MissingField {
+        item: definition_id.clone(),
+        field: "_method.expression".to_string(),
+        span: frame.span,
+    })?;
+
+    Ok(FunctionDef {
+        name,
+        drel_source: Arc::from(drel_source),
+        method_origin,
+        span: frame.span,
+        method_cache: Default::default(),
+    })
+}
+
+/// Extract the dREL method, its purpose and its origin from _method.expression
+///
+/// When several methods are looped with _method.purpose, the Evaluation
+/// method is preferred, then the first one listed.
+fn extract_method(frame: &CifFrame) -> (Option<String>, MethodPurpose, Option<Span>) {
+    if let Some(value) = frame.get_item("_method.expression") {
+        if let Some(expression) = value.as_string() {
+            let purpose = get_string_item_frame(frame, "_method.purpose").unwrap_or_default();
+            return (
+                Some(expression.to_string()),
+                MethodPurpose::parse(&purpose),
+                Some(method_origin(value)),
+            );
+        }
+    }
+
+    for loop_ in &frame.loops {
+        let Some(expressions) = loop_.get_column("_method.expression") else {
+            continue;
+        };
+        let purposes = loop_.get_column("_method.purpose");
+        let methods: Vec<(String, MethodPurpose, Span)> = expressions
+            .iter()
+            .enumerate()
+            .filter_map(|(row, value)| {
+                let purpose = purposes
+                    .as_ref()
+                    .and_then(|column| column[row].as_string())
+                    .unwrap_or_default();
+                Some((
+                    value.as_string()?.to_string(),
+                    MethodPurpose::parse(purpose),
+                    method_origin(value),
+                ))
+            })
+            .collect();
+        let chosen = methods
+            .iter()
+            .position(|(_, purpose, _)| *purpose == MethodPurpose::Evaluation)
+            .unwrap_or(0);
+        if let Some((expression, purpose, origin)) = methods.into_iter().nth(chosen) {
+            return (Some(expression), purpose, Some(origin));
+        }
+    }
+
+    (None, MethodPurpose::default(), None)
+}
+
+/// Where the text of a method value starts in the file.
+///
+/// Text fields are taken to start on the line after the opening `;`, as
+/// methods are written. The parser trims the field, so on its first line
+/// the column is counted from the first non-blank character.
+fn method_origin(value: &CifValue) -> Span {
+    let span = value.span;
+    let delimiter = match value.quote {
+        QuoteStyle::TextField => return Span::point(span.start_line + 1, 1),
+        QuoteStyle::SingleQuote | QuoteStyle::DoubleQuote => 1,
+        QuoteStyle::TripleSingle | QuoteStyle::TripleDouble => 3,
+        _ => 0,
+    };
+    Span::point(span.start_line, span.start_col + delimiter)
+}
+
 /// Extract aliases from _alias.definition_id
 fn extract_aliases(frame: &CifFrame) -> Vec<String> {
     let mut aliases = Vec::new();
@@ -271,8 +545,12 @@ fn extract_type_info(frame: &CifFrame) -> TypeInfo {
     let purpose_str = get_string_item_frame(frame, "_type.purpose").unwrap_or_default();
     let source_str = get_string_item_frame(frame, "_type.source").unwrap_or_default();
 
+    let content_types = ContentTypes::parse(&contents_str);
+
     TypeInfo {
-        contents: ContentType::parse(&contents_str),
+        contents: content_types.first(),
+        content_types,
+        contents_referenced_id: get_string_item_frame(frame, "_type.contents_referenced_id"),
         container: ContainerType::parse(&container_str),
         purpose: Purpose::parse(&purpose_str),
         source: Source::parse(&source_str),
@@ -281,11 +559,19 @@ fn extract_type_info(frame: &CifFrame) -> TypeInfo {
     }
 }
 
+/// Extract the import specifications of a frame (_import.get)
+fn extract_imports(frame: &CifFrame) -> Vec<ImportSpec> {
+    frame
+        .get_item("_import.get")
+        .map(read_import_specs)
+        .unwrap_or_default()
+}
+
 /// Extract value constraints from frame
 fn extract_constraints(frame: &CifFrame) -> ValueConstraints {
     ValueConstraints {
         enumeration: extract_enumeration(frame),
-        range: extract_range(frame),
+        ranges: extract_ranges(frame),
         mandatory: is_mandatory(frame),
     }
 }
@@ -342,10 +628,41 @@ fn extract_enumeration(frame: &CifFrame) -> Option<EnumerationConstraint> {
     }
 }
 
-/// Extract range constraint from frame
-fn extract_range(frame: &CifFrame) -> Option<RangeConstraint> {
-    let range_str = get_string_item_frame(frame, "_enumeration.range")?;
-    RangeConstraint::parse(&range_str)
+/// Extract range constraints from frame
+///
+/// A definition may give one range, a CIF 2.0 list of ranges, or a loop of
+/// `_enumeration.range` rows describing several permitted intervals.
+fn extract_ranges(frame: &CifFrame) -> Vec<RangeConstraint> {
+    let mut texts = Vec::new();
+
+    if let Some(value) = frame.get_item("_enumeration.range") {
+        match &value.kind {
+            CifValueKind::List(items) => {
+                texts.extend(items.iter().filter_map(|item| item.as_string()));
+            }
+            CifValueKind::Text(s) => texts.push(s.as_str()),
+            _ => {}
+        }
+    }
+
+    for loop_ in &frame.loops {
+        if let Some(col_idx) = loop_
+            .tags
+            .iter()
+            .position(|t| t.eq_ignore_ascii_case("_enumeration.range"))
+        {
+            for row in 0..loop_.len() {
+                if let Some(s) = loop_.get(row, col_idx).and_then(|v| v.as_string()) {
+                    texts.push(s);
+                }
+            }
+        }
+    }
+
+    texts
+        .into_iter()
+        .filter_map(RangeConstraint::parse)
+        .collect()
 }
 
 /// Check if item is mandatory
@@ -375,9 +692,10 @@ fn populate_category_items(dict: &mut Dictionary) {
             .push(name.clone());
     }
 
-    // Update categories
-    for (cat_name, item_names) in category_items {
+    // Update categories, with names sorted so checks run in a stable order
+    for (cat_name, mut item_names) in category_items {
         if let Some(category) = dict.categories.get_mut(&cat_name) {
+            item_names.sort_unstable();
             category.item_names = item_names;
         }
     }
@@ -407,8 +725,7 @@ mod tests {
 
     #[test]
     fn test_load_simple_dictionary() {
-        let cif_content = r#"
-#\#CIF_2.0
+        let cif_content = r#"#\#CIF_2.0
 data_TEST_DICT
     _dictionary.title             TEST_DICT
     _dictionary.version           1.0.0
@@ -467,7 +784,7 @@ save_
 
         let item_b = dict.items.get("_test_category.item_b").unwrap();
         assert_eq!(item_b.type_info.contents, ContentType::Real);
-        assert!(item_b.constraints.range.is_some());
+        assert_eq!(item_b.constraints.ranges.len(), 1);
 
         // Check alias
         assert!(dict.aliases.contains_key("_test_category_item_b"));
@@ -488,4 +805,98 @@ save_
         assert_eq!(range.min, Some(0.0));
         assert_eq!(range.max, None);
     }
+
+    #[test]
+    fn test_dangling_contents_reference() {
+        let cif_content = r#"
+data_TEST_DICT
+save_test_category.item_a
+    _definition.id                '_test_category.item_a'
+    _type.contents                ByReference
+    _type.contents_referenced_id  '_test_category.missing'
+save_
+
+save_test_category.item_b
+    _definition.id                '_test_category.item_b'
+    _type.contents                ByReference
+save_
+"#;
+
+        let doc = CifDocument::parse(cif_content).expect("Failed to parse CIF");
+        let errors = load_dictionary(&doc).expect_err("Dangling reference loaded");
+
+        assert_eq!(errors.len(), 2, "{:?}", errors);
+        assert!(matches!(
+            &errors[0],
+            DictionaryError::DanglingContentsReference { item, referenced, .. }
+                if item == "_test_category.item_a" && referenced == "_test_category.missing"
+        ));
+        assert!(matches!(
+            &errors[1],
+            DictionaryError::MissingField { field, .. } if field == "_type.contents_referenced_id"
+        ));
+    }
+
+    #[test]
+    fn test_misfit_frames_reported() {
+        let cif_content = r#"
+data_TEST_DICT
+save_odd_class
+    _definition.id                '_test_category.odd'
+    _definition.class             Widget
+save_
+
+save_odd_scope
+    _definition.id                ODD
+    _definition.scope             Everything
+save_
+
+save_category_as_datum
+    _definition.id                DATUM_CATEGORY
+    _definition.scope             Category
+    _definition.class             Datum
+save_
+
+save_function.empty
+    _definition.id                '_function.Empty'
+    _definition.class             Functions
+save_
+
+save_no_id
+    _description.text             'Neither an item nor a category'
+save_
+
+save_test_category.good
+    _definition.id                '_test_category.good'
+    _type.contents                Real
+save_
+"#;
+
+        let doc = CifDocument::parse(cif_content).expect("Failed to parse CIF");
+        let errors = load_dictionary(&doc).expect_err("Misfit frames loaded");
+
+        let fields: Vec<&str> = errors
+            .iter()
+            .map(|error| match error {
+                DictionaryError::InvalidField { field, .. }
+                | DictionaryError::MissingField { field, .. } => field.as_str(),
+                _ => panic!("unexpected error {error:?}"),
+            })
+            .collect();
+        assert_eq!(
+            fields,
+            [
+                "_definition.class",
+                "_definition.scope",
+                "_definition.class",
+                "_method.expression",
+                "_definition.id",
+            ]
+        );
+        assert_eq!(
+            errors[0].to_string(),
+            "Invalid value for '_definition.class' in '_test_category.odd': \
+             'Widget' is not an item class"
+        );
+    }
 }
@@ -0,0 +1,165 @@
+//! Memory footprint of dictionaries.
+//!
+//! Most of a dictionary's memory is description text and dREL methods,
+//! which are stored as [`Arc<str>`] so that clones and merged dictionaries
+//! share them. Category names are interned, so the items of a category
+//! share one string. [`Dictionary::memory_estimate`] reports the footprint.
+
+use std::collections::{HashMap, HashSet};
+use std::mem::{size_of, size_of_val};
+use std::sync::Arc;
+
+use super::types::{
+    Category, ContentType, DataItem, Dictionary, DictionaryMetadata, FunctionDef, RangeConstraint,
+};
+
+/// Category names handed out once each, shared by every item that uses them
+#[derive(Debug, Default)]
+pub(crate) struct CategoryNames(HashSet<Arc<str>>);
+
+impl CategoryNames {
+    /// The category names the items of `dict` already use
+    pub(crate) fn of(dict: &Dictionary) -> Self {
+        Self(
+            dict.items
+                .values()
+                .map(|item| item.category.clone())
+                .collect(),
+        )
+    }
+
+    /// The shared copy of `name`
+    pub(crate) fn intern(&mut self, name: &str) -> Arc<str> {
+        if let Some(shared) = self.0.get(name) {
+            return shared.clone();
+        }
+        let shared: Arc<str> = Arc::from(name);
+        self.0.insert(shared.clone());
+        shared
+    }
+}
+
+impl Dictionary {
+    /// Approximate number of bytes the dictionary occupies
+    ///
+    /// Counts the maps, the definitions in them and the strings they own.
+    /// A string shared with other dictionaries, as clones and merges share
+    /// descriptions and methods, is divided evenly between its holders, so
+    /// the estimates of dictionaries alive together add up to what they
+    /// occupy between them. Parsed methods are not counted.
+    pub fn memory_estimate(&self) -> usize {
+        let items: usize = self
+            .items
+            .iter()
+            .map(|(name, item)| name.capacity() + item_bytes(item))
+            .sum();
+        let categories: usize = self
+            .categories
+            .iter()
+            .map(|(name, category)| name.capacity() + category_bytes(category))
+            .sum();
+        let functions: usize = self
+            .functions
+            .iter()
+            .map(|(name, function)| name.capacity() + function_bytes(function))
+            .sum();
+        let aliases: usize = self
+            .aliases
+            .iter()
+            .map(|(alias, canonical)| alias.capacity() + shared(canonical))
+            .sum();
+
+        size_of::<Dictionary>()
+            + metadata_bytes(&self.metadata)
+            + optional(&self.primary_dictionary)
+            + table(&self.items)
+            + items
+            + table(&self.categories)
+            + categories
+            + table(&self.functions)
+            + functions
+            + table(&self.aliases)
+            + aliases
+    }
+}
+
+/// Heap bytes owned by an item definition
+fn item_bytes(item: &DataItem) -> usize {
+    let constraints = &item.constraints;
+    let enumeration = constraints
+        .enumeration
+        .as_ref()
+        .map_or(0, |set| strings(&set.values));
+    let dimensions = item
+        .type_info
+        .dimensions
+        .as_ref()
+        .map_or(0, |dims| dims.capacity() * size_of::<usize>());
+
+    item.name.capacity()
+        + shared(&item.category)
+        + item.object.capacity()
+        + strings(&item.aliases)
+        + optional(&item.type_info.units)
+        + item.type_info.content_types.0.capacity() * size_of::<ContentType>()
+        + optional(&item.type_info.contents_referenced_id)
+        + dimensions
+        + enumeration
+        + constraints.ranges.capacity() * size_of::<RangeConstraint>()
+        + optional(&item.links.linked_item)
+        + item.description.as_ref().map_or(0, shared)
+        + optional(&item.default)
+        + item.drel_method.as_ref().map_or(0, shared)
+        + optional(&item.source_dictionary)
+}
+
+/// Heap bytes owned by a category definition
+fn category_bytes(category: &Category) -> usize {
+    category.name.capacity()
+        + category.definition_id.capacity()
+        + category.description.as_ref().map_or(0, shared)
+        + optional(&category.parent)
+        + strings(&category.key_items)
+        + strings(&category.item_names)
+        + category.drel_method.as_ref().map_or(0, shared)
+}
+
+/// Heap bytes owned by a function definition
+fn function_bytes(function: &FunctionDef) -> usize {
+    function.name.capacity() + shared(&function.drel_source)
+}
+
+/// Heap bytes owned by the metadata
+fn metadata_bytes(metadata: &DictionaryMetadata) -> usize {
+    [
+        &metadata.title,
+        &metadata.version,
+        &metadata.date,
+        &metadata.uri,
+        &metadata.ddl_conformance,
+        &metadata.namespace,
+        &metadata.head_category,
+    ]
+    .into_iter()
+    .map(optional)
+    .sum()
+}
+
+/// Size of a map's table, one control byte per slot besides the entry
+fn table<K, V>(map: &HashMap<K, V>) -> usize {
+    map.capacity() * (size_of::<(K, V)>() + 1)
+}
+
+/// This holder's part of a shared string: the counts and the text, divided
+/// between everything holding it
+fn shared(text: &Arc<str>) -> usize {
+    (2 * size_of::<usize>() + text.len()) / Arc::strong_count(text)
+}
+
+fn optional(text: &Option<String>) -> usize {
+    text.as_ref().map_or(0, String::capacity)
+}
+
+fn strings(list: &[String]) -> usize {
+    size_of_val(list) + list.iter().map(String::capacity).sum::<usize>()
+}
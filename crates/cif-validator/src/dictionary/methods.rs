@@ -0,0 +1,286 @@
+//! Method resolution for dictionary items.
+//!
+//! An item's Evaluation method is not always written in its own definition.
+//! DDLm lets a category-level method compute several items of the category at
+//! once, so an item without a local method inherits the category method when
+//! that method assigns it.
+//!
+//! Methods imported from templates via `_import.get` are not resolved: the
+//! loader records each definition's imports but does not read the files.
+//!
+//! Each definition parses its method at most once, on first use through
+//! [`DataItem::method_ast`], [`Category::method_ast`] or
+//! [`FunctionDef::method_ast`]. The parsed program is shared between clones
+//! of the definition, so cloning or merging a dictionary keeps the methods
+//! already parsed.
+
+use cif_parser::Span;
+use drel_parser::{DrelError, Program};
+use serde::Serialize;
+use std::fmt;
+use std::sync::{Arc, OnceLock};
+
+use super::types::{Category, DataItem, Dictionary, FunctionDef, MethodPurpose};
+
+/// Where an item's effective method is defined
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[non_exhaustive]
+pub enum MethodSource {
+    /// In the item's own definition
+    Local,
+    /// In the definition of the named category, which assigns the item
+    Category(String),
+}
+
+/// The Evaluation method that applies to an item, and where it came from
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ResolvedMethod<'a> {
+    /// Definition the method was found in
+    pub source: MethodSource,
+    /// dREL source of the method
+    pub expression: &'a str,
+    /// Location of the definition the method was found in
+    pub span: Span,
+}
+
+impl Dictionary {
+    /// Resolve the Evaluation method that computes an item.
+    ///
+    /// Looks at the item's own definition first, then at its category's
+    /// method if that method assigns the item. Methods with a Definition or
+    /// Validation purpose are not Evaluation methods and are skipped.
+    /// Returns `None` for unknown items and items no method computes.
+    pub fn effective_method(&self, name: &str) -> Option<ResolvedMethod<'_>> {
+        let item = self.get_item(name)?;
+        if let Some(expression) = evaluation(item.drel_method(), item.method_purpose) {
+            return Some(ResolvedMethod {
+                source: MethodSource::Local,
+                expression,
+                span: item.span,
+            });
+        }
+
+        let category = self.get_category(&item.category)?;
+        let expression = evaluation(category.drel_method(), category.method_purpose)?;
+        let program = category.method_ast()?.ok()?;
+        let canonical = item.name.to_lowercase();
+        drel_parser::assigned_items(&program.statements)
+            .iter()
+            .any(|assigned| self.resolve_name(assigned) == canonical)
+            .then(|| ResolvedMethod {
+                source: MethodSource::Category(category.name.clone()),
+                expression,
+                span: category.span,
+            })
+    }
+}
+
+/// The method text if it is an Evaluation method
+fn evaluation(method: Option<&str>, purpose: MethodPurpose) -> Option<&str> {
+    method.filter(|_| purpose == MethodPurpose::Evaluation)
+}
+
+impl DataItem {
+    /// The parsed dREL method, or `None` if the item defines none
+    ///
+    /// Parsed on the first call and cached; see [`Self::set_drel_method`].
+    pub fn method_ast(&self) -> Option<Result<&Program, &DrelError>> {
+        self.method_cache.get(self.drel_method())
+    }
+
+    /// The statements of the method that parse, and an error for each one
+    /// that does not; see [`drel_parser::parse_lossy`]
+    pub fn method_parse(&self) -> Option<(&Program, &[DrelError])> {
+        self.method_cache.get_lossy(self.drel_method())
+    }
+
+    /// Replace the item's method, dropping the cached parse
+    pub fn set_drel_method(&mut self, method: Option<String>) {
+        self.drel_method = method.map(Arc::from);
+        self.method_origin = None;
+        self.method_cache = MethodCache::default();
+    }
+}
+
+impl Category {
+    /// The parsed dREL method, or `None` if the category defines none
+    ///
+    /// Parsed on the first call and cached; see [`Self::set_drel_method`].
+    pub fn method_ast(&self) -> Option<Result<&Program, &DrelError>> {
+        self.method_cache.get(self.drel_method())
+    }
+
+    /// The statements of the method that parse, and an error for each one
+    /// that does not; see [`drel_parser::parse_lossy`]
+    pub fn method_parse(&self) -> Option<(&Program, &[DrelError])> {
+        self.method_cache.get_lossy(self.drel_method())
+    }
+
+    /// Replace the category's method, dropping the cached parse
+    pub fn set_drel_method(&mut self, method: Option<String>) {
+        self.drel_method = method.map(Arc::from);
+        self.method_origin = None;
+        self.method_cache = MethodCache::default();
+    }
+}
+
+impl FunctionDef {
+    /// The parsed dREL function
+    ///
+    /// Parsed on the first call and cached.
+    pub fn method_ast(&self) -> Result<&Program, &DrelError> {
+        let (program, errors) = self.method_parse();
+        errors.first().map_or(Ok(program), Err)
+    }
+
+    /// The statements of the function that parse, and an error for each one
+    /// that does not; see [`drel_parser::parse_lossy`]
+    pub fn method_parse(&self) -> (&Program, &[DrelError]) {
+        self.method_cache.parse(&self.drel_source)
+    }
+}
+
+/// A definition's parsed method, filled on first use and shared by clones
+///
+/// Holds the parse of whatever method text it is first asked for, so the
+/// text must not change afterwards without replacing the cache. The method
+/// fields are private to the crate for that reason, and `set_drel_method`
+/// replaces both. The parse recovers from errors; a method with any error
+/// has no strict AST.
+#[derive(Clone, Default)]
+pub(crate) struct MethodCache(Arc<OnceLock<(Program, Vec<DrelError>)>>);
+
+impl MethodCache {
+    fn get(&self, source: Option<&str>) -> Option<Result<&Program, &DrelError>> {
+        let (program, errors) = self.get_lossy(source)?;
+        Some(errors.first().map_or(Ok(program), Err))
+    }
+
+    fn get_lossy(&self, source: Option<&str>) -> Option<(&Program, &[DrelError])> {
+        Some(self.parse(source?))
+    }
+
+    fn parse(&self, source: &str) -> (&Program, &[DrelError]) {
+        let (program, errors) = self.0.get_or_init(|| parse_method(source));
+        (program, errors)
+    }
+}
+
+impl fmt::Debug for MethodCache {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MethodCache")
+            .field("parsed", &self.0.get().is_some())
+            .finish()
+    }
+}
+
+fn parse_method(source: &str) -> (Program, Vec<DrelError>) {
+    #[cfg(test)]
+    tests::PARSES.with(|count| count.set(count.get() + 1));
+    drel_parser::parse_lossy(source)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dictionary::{load_dictionary, validate_dictionary};
+    use cif_parser::CifDocument;
+    use std::cell::Cell;
+
+    thread_local! {
+        pub(super) static PARSES: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn parses() -> usize {
+        PARSES.with(Cell::get)
+    }
+
+    const DICT: &str = r#"#\#CIF_2.0
+data_TEST_DICT
+save_cell
+    _definition.id                CELL
+    _definition.scope             Category
+    _definition.class             Set
+    _method.expression
+;
+    _cell.volume = _cell.length_a * _cell.length_b
+;
+save_
+save_cell.length_a
+    _definition.id                '_cell.length_a'
+    _type.contents                Real
+save_
+save_cell.length_b
+    _definition.id                '_cell.length_b'
+    _type.contents                Real
+save_
+save_cell.volume
+    _definition.id                '_cell.volume'
+    _type.contents                Real
+save_
+save_cell.area_ab
+    _definition.id                '_cell.area_ab'
+    _type.contents                Real
+    _method.expression            '_cell.area_ab = _cell.length_a * _cell.length_b'
+save_
+"#;
+
+    fn load() -> Dictionary {
+        load_dictionary(&CifDocument::parse(DICT).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_methods_parsed_once_across_consumers() {
+        let dict = load();
+        let start = parses();
+
+        // Two consumers of the item method parse it once between them
+        assert!(validate_dictionary(&dict).is_empty());
+        let item = dict.get_item("_cell.area_ab").unwrap();
+        assert_eq!(item.method_ast().unwrap().unwrap().len(), 1);
+        // The category method is parsed once too, for all items it assigns
+        assert!(dict.effective_method("_cell.volume").is_some());
+        assert!(dict.effective_method("_cell.length_a").is_none());
+        assert_eq!(parses() - start, 2);
+
+        // Clones and merged dictionaries share what is already parsed
+        let mut merged = Dictionary::new();
+        merged.merge(dict.clone());
+        assert!(validate_dictionary(&merged).is_empty());
+        assert!(merged.effective_method("_cell.volume").is_some());
+        assert_eq!(parses() - start, 2);
+
+        // A fresh load parses again
+        assert!(validate_dictionary(&load()).is_empty());
+        assert_eq!(parses() - start, 4);
+    }
+
+    #[test]
+    fn test_replacing_a_method_drops_its_parse() {
+        let mut item = load().get_item("_cell.area_ab").unwrap().clone();
+        assert!(item.method_ast().unwrap().is_ok());
+
+        item.set_drel_method(Some("_cell.area_ab = (".to_string()));
+        assert!(item.method_ast().unwrap().is_err());
+
+        item.set_drel_method(None);
+        assert!(item.method_ast().is_none());
+    }
+
+    #[test]
+    fn test_cache_not_serialized() {
+        let dict = load();
+        let before = serde_json::to_string(&dict).unwrap();
+        assert!(validate_dictionary(&dict).is_empty());
+        assert_eq!(serde_json::to_string(&dict).unwrap(), before);
+        assert!(!before.contains("method_cache"));
+
+        let back: Dictionary = serde_json::from_str(&before).unwrap();
+        assert!(back
+            .get_item("_cell.area_ab")
+            .unwrap()
+            .method_ast()
+            .unwrap()
+            .is_ok());
+    }
+}
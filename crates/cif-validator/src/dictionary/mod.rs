@@ -2,14 +2,29 @@
 //!
 //! This module provides types and functions for:
 //! - Representing DDLm dictionary structures (categories, data items, types)
+//! - Detecting the definition language (DDLm, DDL2, DDL1) of a dictionary
 //! - Loading dictionaries from CIF 2.0 files
-//! - Validating dictionary internal consistency (dREL references)
+//! - Reading the `_import.get` specifications of definitions
+//! - Resolving the dREL method that computes each item
+//! - Validating dictionary internal consistency (dREL references, unit codes)
+//! - The units vocabulary and conversion to SI
 //! - Multi-dictionary composition
 
+mod ddl;
+mod imports;
 mod loader;
+mod memory;
+mod methods;
 mod types;
+mod units;
 mod validator;
 
+pub use ddl::{detect_ddl, DdlFlavor};
+pub use imports::{
+    read_import_specs, ImportDuplicate, ImportMissing, ImportMode, ImportSpec, IMPORT_KEYS,
+};
 pub use loader::load_dictionary;
+pub use methods::{MethodSource, ResolvedMethod};
 pub use types::*;
-pub use validator::validate_dictionary;
+pub use units::{Dimension, Unit, Units};
+pub use validator::{dictionary_warnings, validate_dictionary};
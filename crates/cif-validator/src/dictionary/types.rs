@@ -3,21 +3,48 @@
 //! These types model the structure of DDLm dictionaries, which define
 //! valid data names, types, and constraints for CIF files.
 
-use cif_parser::Span;
-use serde::{Deserialize, Serialize};
+use super::imports::ImportSpec;
+use super::memory::CategoryNames;
+use super::methods::MethodCache;
+use super::units::{Dimension, Units};
+use super::DdlFlavor;
+use cif_parser::export::{loop_to_csv, CsvOptions};
+use cif_parser::{CifBlock, CifLoop, Span};
+use serde::{Deserialize, Serialize, Serializer};
 use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
 
 /// A complete DDLm dictionary (potentially composed from multiple files)
-#[derive(Debug, Clone, Default)]
+///
+/// The maps serialize with their keys sorted, so the same dictionary always
+/// gives the same JSON.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Dictionary {
     /// Dictionary metadata
     pub metadata: DictionaryMetadata,
     /// Categories indexed by name (lowercase)
+    #[serde(serialize_with = "serialize_sorted")]
     pub categories: HashMap<String, Category>,
     /// All data items indexed by canonical name (lowercase)
+    #[serde(serialize_with = "serialize_sorted")]
     pub items: HashMap<String, DataItem>,
     /// Alias map: alias (lowercase) -> canonical name (lowercase)
-    pub aliases: HashMap<String, String>,
+    ///
+    /// The aliases of one item share a single copy of its canonical name,
+    /// and aliases that are the canonical name itself are not stored.
+    #[serde(serialize_with = "serialize_sorted")]
+    pub aliases: HashMap<String, Arc<str>>,
+    /// dREL functions indexed by name (lowercase), from the definitions in
+    /// `Functions` categories
+    #[serde(default, serialize_with = "serialize_sorted")]
+    pub functions: HashMap<String, FunctionDef>,
+    /// Name of the dictionary the others were merged into
+    ///
+    /// Items with a different [`DataItem::source_dictionary`] came from an
+    /// extension, and validation messages about them name it.
+    #[serde(default)]
+    pub primary_dictionary: Option<String>,
 }
 
 impl Dictionary {
@@ -31,7 +58,10 @@ impl Dictionary {
     /// Returns the canonical name if found, otherwise returns the input lowercased.
     pub fn resolve_name(&self, name: &str) -> String {
         let lower = name.to_lowercase();
-        self.aliases.get(&lower).cloned().unwrap_or(lower)
+        match self.aliases.get(&lower) {
+            Some(canonical) => canonical.to_string(),
+            None => lower,
+        }
     }
 
     /// Look up a data item by name (handles aliases, case-insensitive)
@@ -51,10 +81,20 @@ impl Dictionary {
         self.categories.get(&name.to_lowercase())
     }
 
+    /// Look up a dREL function by name (case-insensitive)
+    pub fn get_function(&self, name: &str) -> Option<&FunctionDef> {
+        self.functions.get(&name.to_lowercase())
+    }
+
     /// Merge another dictionary into this one
     ///
     /// Later definitions override earlier ones (for domain-specific extensions).
-    pub fn merge(&mut self, other: Dictionary) {
+    /// Definitions are moved out of `other`, not copied, and take their
+    /// category names from this dictionary where it already has them.
+    /// Returns one [`MergeConflict`] for every item that `other` redefines
+    /// differently, naming both sources and what differed. An item repeated
+    /// without change keeps its original [`DataItem::source_dictionary`].
+    pub fn merge(&mut self, other: Dictionary) -> Vec<MergeConflict> {
         // Merge metadata (other takes precedence for non-None fields)
         if other.metadata.title.is_some() {
             self.metadata.title = other.metadata.title;
@@ -62,36 +102,220 @@ impl Dictionary {
         if other.metadata.version.is_some() {
             self.metadata.version = other.metadata.version;
         }
+        if self.metadata.head_category.is_none() {
+            self.metadata.head_category = other.metadata.head_category;
+        }
+        self.metadata.attribute_frames += other.metadata.attribute_frames;
+        if self.primary_dictionary.is_none() {
+            self.primary_dictionary = other.primary_dictionary;
+        }
 
-        // Merge categories
+        // Merge categories and functions
         for (name, cat) in other.categories {
             self.categories.insert(name, cat);
         }
+        self.functions.extend(other.functions);
 
-        // Merge items
-        for (name, item) in other.items {
-            // Register aliases from new item
-            for alias in &item.aliases {
-                self.aliases.insert(alias.to_lowercase(), name.clone());
+        // Merge items, noting definitions that change
+        let mut category_names = CategoryNames::of(self);
+        let mut conflicts = Vec::new();
+        for (name, mut item) in other.items {
+            item.category = category_names.intern(&item.category);
+            self.register_aliases(&name, &item);
+            if let Some(previous) = self.items.get(&name) {
+                let differences = definition_differences(previous, &item);
+                if differences.is_empty() {
+                    // A repeated definition stays attributed to its first source
+                    item.source_dictionary = previous.source_dictionary.clone();
+                } else {
+                    conflicts.push(MergeConflict {
+                        name: item.name.clone(),
+                        previous_source: previous.source_dictionary.clone(),
+                        source: item.source_dictionary.clone(),
+                        differences,
+                    });
+                }
             }
             self.items.insert(name, item);
         }
+        conflicts.sort_by(|a, b| a.name.cmp(&b.name));
 
         // Merge aliases
         self.aliases.extend(other.aliases);
+        conflicts
     }
 
-    /// Get all item names
+    /// Map each alias of `item`, stored under the lowercase `name`, to it
+    pub(crate) fn register_aliases(&mut self, name: &str, item: &DataItem) {
+        let mut canonical: Option<Arc<str>> = None;
+        for alias in &item.aliases {
+            let alias = alias.to_lowercase();
+            if alias != name {
+                let canonical = canonical.get_or_insert_with(|| Arc::from(name));
+                self.aliases.insert(alias, canonical.clone());
+            }
+        }
+    }
+
+    /// Get all item names, sorted
     pub fn item_names(&self) -> impl Iterator<Item = &str> {
-        self.items.keys().map(|s| s.as_str())
+        sorted_keys(&self.items)
     }
 
-    /// Get all category names
+    /// Get all category names, sorted
     pub fn category_names(&self) -> impl Iterator<Item = &str> {
-        self.categories.keys().map(|s| s.as_str())
+        sorted_keys(&self.categories)
+    }
+
+    /// Find the loop in `block` holding the items of `category`
+    ///
+    /// Tags are resolved through the dictionary, so legacy names such as
+    /// `_atom_site_label` are found and `_atom_site_aniso_label` is not taken
+    /// for `atom_site`.
+    pub fn find_category_loop<'a>(
+        &self,
+        block: &'a CifBlock,
+        category: &str,
+    ) -> Option<&'a CifLoop> {
+        block.loops.iter().find(|loop_| {
+            loop_.tags.iter().any(|tag| {
+                self.get_item(tag)
+                    .is_some_and(|item| item.category.eq_ignore_ascii_case(category))
+            })
+        })
+    }
+
+    /// Write the loop holding `category` as CSV; see [`cif_parser::export`]
+    ///
+    /// Returns `None` when the block has no loop for the category.
+    pub fn category_to_csv(
+        &self,
+        block: &CifBlock,
+        category: &str,
+        options: &CsvOptions,
+    ) -> Option<String> {
+        self.find_category_loop(block, category)
+            .map(|loop_| loop_to_csv(loop_, options))
     }
 }
 
+/// An item defined by two merged dictionaries with different definitions
+///
+/// The later definition wins; this records what it replaced.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MergeConflict {
+    /// Data name as the overriding definition spells it
+    pub name: String,
+    /// Dictionary of the replaced definition
+    pub previous_source: Option<String>,
+    /// Dictionary of the definition that replaced it
+    pub source: Option<String>,
+    /// What differed, one entry per field, e.g. `range: 0.0: -> 0.0:500.0`
+    pub differences: Vec<String>,
+}
+
+impl fmt::Display for MergeConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let source = |name: &Option<String>| name.clone().unwrap_or_else(|| "unnamed".to_string());
+        write!(
+            f,
+            "'{}' from {} overrides the definition from {} ({})",
+            self.name,
+            source(&self.source),
+            source(&self.previous_source),
+            self.differences.join("; ")
+        )
+    }
+}
+
+/// Fields that differ between two definitions of one item, as
+/// `field: old -> new`
+fn definition_differences(old: &DataItem, new: &DataItem) -> Vec<String> {
+    fn ranges(item: &DataItem) -> String {
+        match item.constraints.ranges.as_slice() {
+            [] => "none".to_string(),
+            ranges => ranges
+                .iter()
+                .map(RangeConstraint::to_string)
+                .collect::<Vec<_>>()
+                .join(" or "),
+        }
+    }
+    fn enumeration(item: &DataItem) -> String {
+        match &item.constraints.enumeration {
+            Some(set) => set.values.join(", "),
+            None => "none".to_string(),
+        }
+    }
+    fn optional(value: Option<&str>) -> String {
+        value.unwrap_or("none").to_string()
+    }
+
+    let old_type = &old.type_info;
+    let new_type = &new.type_info;
+    let fields = [
+        (
+            "type",
+            old_type.content_types.to_string(),
+            new_type.content_types.to_string(),
+        ),
+        (
+            "container",
+            format!("{:?}", old_type.container),
+            format!("{:?}", new_type.container),
+        ),
+        (
+            "dimensions",
+            format!("{:?}", old_type.dimensions),
+            format!("{:?}", new_type.dimensions),
+        ),
+        (
+            "units",
+            optional(old_type.units.as_deref()),
+            optional(new_type.units.as_deref()),
+        ),
+        ("range", ranges(old), ranges(new)),
+        ("enumeration", enumeration(old), enumeration(new)),
+        (
+            "mandatory",
+            old.constraints.mandatory.to_string(),
+            new.constraints.mandatory.to_string(),
+        ),
+        (
+            "default",
+            optional(old.default.as_deref()),
+            optional(new.default.as_deref()),
+        ),
+        (
+            "method",
+            optional(old.drel_method()),
+            optional(new.drel_method()),
+        ),
+    ];
+    fields
+        .into_iter()
+        .filter(|(_, old, new)| old != new)
+        .map(|(field, old, new)| format!("{field}: {old} -> {new}"))
+        .collect()
+}
+
+/// Keys of `map` in sorted order
+fn sorted_keys<V>(map: &HashMap<String, V>) -> impl Iterator<Item = &str> {
+    let mut keys: Vec<&str> = map.keys().map(String::as_str).collect();
+    keys.sort_unstable();
+    keys.into_iter()
+}
+
+/// Serialize `map` with its keys sorted
+fn serialize_sorted<V: Serialize, S: Serializer>(
+    map: &HashMap<String, V>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut entries: Vec<_> = map.iter().collect();
+    entries.sort_unstable_by_key(|(key, _)| *key);
+    serializer.collect_map(entries)
+}
+
 /// Dictionary-level metadata from _dictionary.* items
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DictionaryMetadata {
@@ -107,6 +331,24 @@ pub struct DictionaryMetadata {
     pub ddl_conformance: Option<String>,
     /// Namespace (_dictionary.namespace)
     pub namespace: Option<String>,
+    /// Definition language detected when the dictionary was loaded
+    #[serde(default)]
+    pub ddl_flavor: DdlFlavor,
+    /// Name of the `Head` category, the root of the category tree
+    #[serde(default)]
+    pub head_category: Option<String>,
+    /// Number of `_definition.class Attribute` frames, which define DDL
+    /// attributes rather than data items and are not loaded
+    #[serde(default)]
+    pub attribute_frames: usize,
+}
+
+impl DictionaryMetadata {
+    /// Name to record as the source of definitions: the title, or the
+    /// namespace when there is no title
+    pub fn source_name(&self) -> Option<String> {
+        self.title.clone().or_else(|| self.namespace.clone())
+    }
 }
 
 /// A category grouping related data items
@@ -116,18 +358,52 @@ pub struct Category {
     pub name: String,
     /// Full definition ID (e.g., "ATOM_SITE")
     pub definition_id: String,
-    /// Description of the category
-    pub description: Option<String>,
+    /// Description of the category; see [`Category::description`]
+    pub description: Option<Arc<str>>,
     /// Category class (Head, Loop, Set)
     pub class: CategoryClass,
     /// Parent category name (for hierarchy)
     pub parent: Option<String>,
     /// Category key items (items that uniquely identify a row)
     pub key_items: Vec<String>,
-    /// Items in this category (populated during loading)
+    /// Items in this category, sorted (populated during loading)
     pub item_names: Vec<String>,
+    /// dREL method source defined on the category
+    ///
+    /// Applies to each item of the category that it assigns and that has no
+    /// method of its own; see [`Dictionary::effective_method`]. Read with
+    /// [`Category::drel_method`] and change with [`Category::set_drel_method`],
+    /// which keeps [`Category::method_ast`] in step with the text.
+    pub(crate) drel_method: Option<Arc<str>>,
+    /// Purpose of `drel_method` (_method.purpose)
+    #[serde(default)]
+    pub method_purpose: MethodPurpose,
+    /// Position in the dictionary file of the first character of
+    /// `drel_method`, used to place errors found in the method
+    #[serde(default)]
+    pub method_origin: Option<Span>,
+    /// Imports of the definition (_import.get), not yet resolved
+    #[serde(default)]
+    pub imports: Vec<ImportSpec>,
     /// Location in dictionary file
     pub span: Span,
+    /// Parse of `drel_method`, made on first use; not serialized
+    #[serde(skip)]
+    pub(crate) method_cache: MethodCache,
+}
+
+impl Category {
+    /// Description of the category, if the definition has one
+    ///
+    /// The text is shared between clones of the definition.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// dREL method source defined on the category
+    pub fn drel_method(&self) -> Option<&str> {
+        self.drel_method.as_deref()
+    }
 }
 
 /// Category class indicating how items can appear
@@ -140,6 +416,9 @@ pub enum CategoryClass {
     /// Set category (items cannot appear in loops, single values only)
     #[default]
     Set,
+    /// Functions category, whose definitions are dREL functions rather than
+    /// data items; see [`Dictionary::functions`]
+    Functions,
 }
 
 impl CategoryClass {
@@ -149,18 +428,49 @@ impl CategoryClass {
             "head" => Self::Head,
             "loop" => Self::Loop,
             "set" => Self::Set,
+            "functions" => Self::Functions,
             _ => Self::Set, // Default to Set for unknown
         }
     }
 }
 
+/// A dREL function defined in a `Functions` category
+///
+/// Dictionary methods call these by name, as in `AtomType(_atom_site.label)`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FunctionDef {
+    /// Function name as the definition spells it (e.g., "AtomType")
+    pub name: String,
+    /// dREL source of the function, from `_method.expression`; read with
+    /// [`FunctionDef::drel_source`]
+    pub(crate) drel_source: Arc<str>,
+    /// Position in the dictionary file of the first character of
+    /// `drel_source`, used to place errors found in it
+    #[serde(default)]
+    pub method_origin: Option<Span>,
+    /// Location in dictionary file
+    pub span: Span,
+    /// Parse of `drel_source`, made on first use; not serialized
+    #[serde(skip)]
+    pub(crate) method_cache: MethodCache,
+}
+
+impl FunctionDef {
+    /// dREL source of the function
+    pub fn drel_source(&self) -> &str {
+        &self.drel_source
+    }
+}
+
 /// A single data item definition
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DataItem {
     /// Canonical data name (e.g., "_atom_site.label")
     pub name: String,
     /// Category this item belongs to (e.g., "atom_site")
-    pub category: String,
+    ///
+    /// Shared by the items of a category in one dictionary.
+    pub category: Arc<str>,
     /// Object name within category (e.g., "label")
     pub object: String,
     /// Legacy aliases (e.g., ["_atom_site_label"])
@@ -171,17 +481,53 @@ pub struct DataItem {
     pub constraints: ValueConstraints,
     /// Relationship to other items
     pub links: ItemLinks,
-    /// Description text
-    pub description: Option<String>,
+    /// Description text; see [`DataItem::description`]
+    pub description: Option<Arc<str>>,
     /// Default value
     pub default: Option<String>,
-    /// dREL method source (for dictionary validation)
-    pub drel_method: Option<String>,
+    /// dREL method source defined on the item itself
+    ///
+    /// Use [`Dictionary::effective_method`] to include methods inherited from
+    /// the category. Read with [`DataItem::drel_method`] and change with
+    /// [`DataItem::set_drel_method`], which keeps [`DataItem::method_ast`] in
+    /// step with the text.
+    pub(crate) drel_method: Option<Arc<str>>,
+    /// Purpose of `drel_method` (_method.purpose)
+    #[serde(default)]
+    pub method_purpose: MethodPurpose,
+    /// Position in the dictionary file of the first character of
+    /// `drel_method`, used to place errors found in the method
+    #[serde(default)]
+    pub method_origin: Option<Span>,
+    /// Imports of the definition (_import.get), not yet resolved
+    #[serde(default)]
+    pub imports: Vec<ImportSpec>,
     /// Location in dictionary file
     pub span: Span,
+    /// Title (or namespace) of the dictionary that defined the item
+    ///
+    /// Set when the dictionary is loaded and kept through
+    /// [`Dictionary::merge`].
+    #[serde(default)]
+    pub source_dictionary: Option<String>,
+    /// Parse of `drel_method`, made on first use; not serialized
+    #[serde(skip)]
+    pub(crate) method_cache: MethodCache,
 }
 
 impl DataItem {
+    /// Description text, if the definition has one
+    ///
+    /// The text is shared between clones of the definition.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// dREL method source defined on the item itself
+    pub fn drel_method(&self) -> Option<&str> {
+        self.drel_method.as_deref()
+    }
+
     /// Check if this item is mandatory
     pub fn is_mandatory(&self) -> bool {
         self.constraints.mandatory
@@ -195,13 +541,31 @@ impl DataItem {
             format!("_{}", self.name)
         }
     }
+    /// Factor converting values of this item to SI units
+    ///
+    /// `None` if the item declares no units, an unknown code, or
+    /// `unspecified`. See [`Units`].
+    pub fn si_factor(&self) -> Option<f64> {
+        let unit = Units::lookup(self.type_info.units.as_deref()?)?;
+        (unit.dimension != Dimension::Unspecified).then_some(unit.si_factor)
+    }
 }
 
 /// DDLm type information from _type.* items
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TypeInfo {
-    /// Content type (_type.contents): Real, Integer, Text, etc.
+    /// Content type (_type.contents): Real, Integer, Text, etc.; the first
+    /// member of a union such as `Integer,Text`
     pub contents: ContentType,
+    /// Every content type a value may have: several for a union, and for a
+    /// `ByReference` item the types of the item it refers to, once the
+    /// dictionary is loaded
+    #[serde(default)]
+    pub content_types: ContentTypes,
+    /// Item a `ByReference` item takes its content type from
+    /// (_type.contents_referenced_id)
+    #[serde(default)]
+    pub contents_referenced_id: Option<String>,
     /// Container type (_type.container): Single, List, Matrix, etc.
     pub container: ContainerType,
     /// Purpose (_type.purpose): Measurand, Describe, Link, etc.
@@ -215,7 +579,11 @@ pub struct TypeInfo {
 }
 
 /// DDLm _type.contents values
+///
+/// Non-exhaustive: DDLm revisions add content types, so downstream matches
+/// need a wildcard arm.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[non_exhaustive]
 pub enum ContentType {
     /// Real number (floating point)
     Real,
@@ -294,6 +662,71 @@ impl ContentType {
     }
 }
 
+/// The content types a value may have: one, or a union such as
+/// `Integer,Text` for a number that may also be the text `now`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ContentTypes(pub Vec<ContentType>);
+
+impl Default for ContentTypes {
+    fn default() -> Self {
+        ContentTypes(vec![ContentType::default()])
+    }
+}
+
+impl From<ContentType> for ContentTypes {
+    fn from(content: ContentType) -> Self {
+        ContentTypes(vec![content])
+    }
+}
+
+impl ContentTypes {
+    /// Parse a comma-separated `_type.contents` value (case-insensitive)
+    pub fn parse(s: &str) -> Self {
+        ContentTypes(
+            s.split(',')
+                .map(|member| ContentType::parse(member.trim()))
+                .collect(),
+        )
+    }
+
+    /// The first member
+    pub fn first(&self) -> ContentType {
+        self.0.first().copied().unwrap_or_default()
+    }
+
+    /// Check if a value may have more than one content type
+    pub fn is_union(&self) -> bool {
+        self.0.len() > 1
+    }
+
+    /// Check if `content` is one of the members
+    pub fn contains(&self, content: ContentType) -> bool {
+        self.0.contains(&content)
+    }
+
+    /// Check if every member is numeric
+    pub fn is_numeric(&self) -> bool {
+        self.0.iter().all(ContentType::is_numeric)
+    }
+
+    /// The members, in the order written
+    pub fn iter(&self) -> impl Iterator<Item = ContentType> + '_ {
+        self.0.iter().copied()
+    }
+}
+
+impl fmt::Display for ContentTypes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, content) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{content:?}")?;
+        }
+        Ok(())
+    }
+}
+
 /// DDLm _type.container values
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub enum ContainerType {
@@ -396,13 +829,38 @@ impl Source {
     }
 }
 
+/// DDLm _method.purpose values
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum MethodPurpose {
+    /// Computes the item's value
+    #[default]
+    Evaluation,
+    /// Tailors the definition itself (e.g. units) to the data
+    Definition,
+    /// Tests the consistency of values
+    Validation,
+}
+
+impl MethodPurpose {
+    /// Parse from string (case-insensitive)
+    pub fn parse(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "definition" => Self::Definition,
+            "validation" => Self::Validation,
+            _ => Self::Evaluation,
+        }
+    }
+}
+
 /// Value constraints from _enumeration.* items
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ValueConstraints {
     /// Enumerated allowed values (_enumeration.set)
     pub enumeration: Option<EnumerationConstraint>,
-    /// Numeric range constraint (_enumeration.range)
-    pub range: Option<RangeConstraint>,
+    /// Permitted numeric ranges (_enumeration.range, possibly looped)
+    ///
+    /// A value must fall in at least one of them; empty means unconstrained.
+    pub ranges: Vec<RangeConstraint>,
     /// Whether the item is mandatory
     pub mandatory: bool,
 }
@@ -428,51 +886,102 @@ impl EnumerationConstraint {
 }
 
 /// Range constraint for numeric values
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+///
+/// DDLm writes ranges as `min:max` with either side optional, both bounds
+/// inclusive. Interval notation marks a bound as exclusive: `(0.0:` means
+/// strictly positive and `(0:180)` excludes both ends. Square brackets are
+/// accepted for inclusive bounds, and inside brackets a comma may separate
+/// the bounds, as in `(0,180)`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct RangeConstraint {
-    /// Minimum value (inclusive), None = unbounded
+    /// Minimum value, None = unbounded
     pub min: Option<f64>,
-    /// Maximum value (inclusive), None = unbounded
+    /// Maximum value, None = unbounded
     pub max: Option<f64>,
+    /// Whether the minimum itself is excluded
+    #[serde(default)]
+    pub min_exclusive: bool,
+    /// Whether the maximum itself is excluded
+    #[serde(default)]
+    pub max_exclusive: bool,
 }
 
 impl RangeConstraint {
-    /// Parse from DDLm range string (e.g., "0.0:", ":100", "0:1")
-    pub fn parse(s: &str) -> Option<Self> {
-        let parts: Vec<&str> = s.split(':').collect();
-        if parts.len() != 2 {
-            return None;
+    /// Create an inclusive range
+    pub fn new(min: Option<f64>, max: Option<f64>) -> Self {
+        Self {
+            min,
+            max,
+            min_exclusive: false,
+            max_exclusive: false,
         }
+    }
 
-        let min = if parts[0].is_empty() {
-            None
-        } else {
-            parts[0].parse().ok()
+    /// Create a range that only has an inclusive lower bound
+    pub fn at_least(min: f64) -> Self {
+        Self::new(Some(min), None)
+    }
+
+    /// Parse from DDLm range string (e.g., "0.0:", ":100", "0:1", "(0:180)")
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        let (opened, min_exclusive, s) = match s.strip_prefix('(') {
+            Some(rest) => (true, true, rest),
+            None => match s.strip_prefix('[') {
+                Some(rest) => (true, false, rest),
+                None => (false, false, s),
+            },
+        };
+        let (closed, max_exclusive, s) = match s.strip_suffix(')') {
+            Some(rest) => (true, true, rest),
+            None => match s.strip_suffix(']') {
+                Some(rest) => (true, false, rest),
+                None => (false, false, s),
+            },
         };
 
-        let max = if parts[1].is_empty() {
-            None
+        let parts: Vec<&str> = if (opened || closed) && !s.contains(':') {
+            s.split(',').collect()
         } else {
-            parts[1].parse().ok()
+            s.split(':').collect()
         };
+        if parts.len() != 2 {
+            return None;
+        }
+
+        let bound = |part: &str| -> Result<Option<f64>, ()> {
+            let part = part.trim();
+            if part.is_empty() {
+                Ok(None)
+            } else {
+                part.parse().map(Some).map_err(|_| ())
+            }
+        };
+        let min = bound(parts[0]).ok()?;
+        let max = bound(parts[1]).ok()?;
 
         // At least one bound should be specified
         if min.is_none() && max.is_none() {
             return None;
         }
 
-        Some(Self { min, max })
+        Some(Self {
+            min,
+            max,
+            min_exclusive: min_exclusive && min.is_some(),
+            max_exclusive: max_exclusive && max.is_some(),
+        })
     }
 
     /// Check if a value is within the range
     pub fn contains(&self, value: f64) -> bool {
         if let Some(min) = self.min {
-            if value < min {
+            if value < min || (self.min_exclusive && value == min) {
                 return false;
             }
         }
         if let Some(max) = self.max {
-            if value > max {
+            if value > max || (self.max_exclusive && value == max) {
                 return false;
             }
         }
@@ -480,6 +989,31 @@ impl RangeConstraint {
     }
 }
 
+/// Writes the range the way `_enumeration.range` does, as `min:max`. A range
+/// with an exclusive bound is bracketed on both sides, a parenthesis on each
+/// exclusive or unbounded side: `0.0:180.0`, `(0.0:)`, `[0.5:1.0)`.
+impl fmt::Display for RangeConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bracketed = self.min_exclusive || self.max_exclusive;
+        if bracketed {
+            let open = self.min_exclusive || self.min.is_none();
+            f.write_str(if open { "(" } else { "[" })?;
+        }
+        if let Some(min) = self.min {
+            write!(f, "{:?}", min)?;
+        }
+        f.write_str(":")?;
+        if let Some(max) = self.max {
+            write!(f, "{:?}", max)?;
+        }
+        if bracketed {
+            let open = self.max_exclusive || self.max.is_none();
+            f.write_str(if open { ")" } else { "]" })?;
+        }
+        Ok(())
+    }
+}
+
 /// Links to other items (foreign keys, etc.)
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ItemLinks {
@@ -548,6 +1082,46 @@ mod tests {
         let range = RangeConstraint::parse("0:1").unwrap();
         assert_eq!(range.min, Some(0.0));
         assert_eq!(range.max, Some(1.0));
+        assert!(!range.min_exclusive && !range.max_exclusive);
+    }
+
+    #[test]
+    fn test_range_constraint_exclusive_bounds() {
+        let range = RangeConstraint::parse("(0.0:").unwrap();
+        assert!(range.min_exclusive);
+        assert!(!range.contains(0.0));
+        assert!(range.contains(1e-9));
+        assert_eq!(range.to_string(), "(0.0:)");
+
+        for text in ["(0:180)", "(0,180)", " ( 0 : 180 ) "] {
+            let range = RangeConstraint::parse(text).unwrap();
+            assert_eq!((range.min, range.max), (Some(0.0), Some(180.0)));
+            assert!(range.min_exclusive && range.max_exclusive, "{text}");
+            assert!(!range.contains(0.0) && !range.contains(180.0));
+            assert!(range.contains(90.0));
+        }
+
+        let range = RangeConstraint::parse("[0:1)").unwrap();
+        assert!(range.contains(0.0) && !range.contains(1.0));
+        assert_eq!(range.to_string(), "[0.0:1.0)");
+        assert_eq!(
+            RangeConstraint::parse("0:1").unwrap().to_string(),
+            "0.0:1.0"
+        );
+        assert_eq!(
+            RangeConstraint::parse(":1e-6").unwrap().to_string(),
+            ":1e-6"
+        );
+
+        // The written form reads back as the same range
+        for text in ["(0.0:", "(0:180)", "[0.5:1.0)", "-1.0:-0.5"] {
+            let range = RangeConstraint::parse(text).unwrap();
+            assert_eq!(RangeConstraint::parse(&range.to_string()), Some(range));
+        }
+
+        assert!(RangeConstraint::parse("(:)").is_none());
+        assert!(RangeConstraint::parse("0,1").is_none());
+        assert!(RangeConstraint::parse("a:b").is_none());
     }
 
     #[test]
@@ -558,6 +1132,20 @@ mod tests {
         assert!(!ContentType::Word.is_numeric());
     }
 
+    #[test]
+    fn test_content_types_union() {
+        let union = ContentTypes::parse("Integer, text");
+        assert!(union.is_union());
+        assert_eq!(union.first(), ContentType::Integer);
+        assert!(union.contains(ContentType::Text));
+        assert!(!union.is_numeric());
+        assert_eq!(union.to_string(), "Integer,Text");
+
+        let single = ContentTypes::parse("Real");
+        assert!(!single.is_union());
+        assert!(single.is_numeric());
+    }
+
     #[test]
     fn test_enumeration_constraint() {
         let constraint = EnumerationConstraint {
@@ -575,7 +1163,7 @@ mod tests {
         let mut dict = Dictionary::new();
         dict.aliases.insert(
             "_diffrn_ambient_pressure".to_string(),
-            "_diffrn.ambient_pressure".to_string(),
+            "_diffrn.ambient_pressure".into(),
         );
 
         assert_eq!(
@@ -0,0 +1,447 @@
+//! The DDLm units vocabulary and conversion to SI.
+//!
+//! `_units.code` takes its values from a fixed vocabulary. [`Units`] looks
+//! codes up in a table of every known code with its canonical name, the
+//! factor that converts a value to SI, and the physical dimension, so values
+//! can be converted between units of the same dimension:
+//!
+//! ```
+//! use cif_validator::dictionary::Units;
+//!
+//! let nm = Units::convert(15.0, "angstroms", "nanometres").unwrap();
+//! assert!((nm - 1.5).abs() < 1e-12);
+//! assert_eq!(Units::convert(1.0, "angstroms", "degrees"), None);
+//! assert_eq!(Units::suggest("angstroem"), Some("angstroms"));
+//! ```
+//!
+//! All conversions are multiplicative; the vocabulary has no units with an
+//! offset from SI (such as degrees Celsius).
+
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+/// Physical dimension of a unit; conversion only works within one dimension
+///
+/// Non-exhaustive: new codes may need new dimensions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum Dimension {
+    /// Pure number (`none`)
+    Dimensionless,
+    /// Depends on something else, such as the radiation probe (`unspecified`)
+    Unspecified,
+    /// Length (SI: metre)
+    Length,
+    /// Area (SI: square metre)
+    Area,
+    /// Volume (SI: cubic metre)
+    Volume,
+    /// Inverse length (SI: reciprocal metre)
+    ReciprocalLength,
+    /// Inverse area (SI: reciprocal square metre)
+    ReciprocalArea,
+    /// Plane angle (SI: radian)
+    Angle,
+    /// Rate of rotation (SI: radian per second)
+    AngularVelocity,
+    /// Thermodynamic temperature (SI: kelvin)
+    Temperature,
+    /// Time (SI: second)
+    Time,
+    /// Mass (SI: kilogram)
+    Mass,
+    /// Mass density (SI: kilogram per cubic metre)
+    Density,
+    /// Electric charge (SI: coulomb)
+    Charge,
+    /// Charge per length (SI: coulomb per metre)
+    LinearChargeDensity,
+    /// Charge per volume (SI: coulomb per cubic metre)
+    ChargeDensity,
+    /// Electric current (SI: ampere)
+    Current,
+    /// Electric potential (SI: volt)
+    Voltage,
+    /// Power (SI: watt)
+    Power,
+    /// Energy (SI: joule)
+    Energy,
+    /// Pressure (SI: pascal)
+    Pressure,
+    /// Absorbed radiation dose (SI: gray)
+    AbsorbedDose,
+    /// Number of events
+    Count,
+    /// Bond valence sum
+    BondValence,
+}
+
+/// One entry of the units vocabulary
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Unit {
+    /// Code as written in `_units.code`
+    pub code: &'static str,
+    /// Canonical unit name
+    pub name: &'static str,
+    /// Multiply a value in this unit by this to get SI
+    pub si_factor: f64,
+    /// Physical dimension
+    pub dimension: Dimension,
+}
+
+const fn unit(
+    code: &'static str,
+    name: &'static str,
+    si_factor: f64,
+    dimension: Dimension,
+) -> Unit {
+    Unit {
+        code,
+        name,
+        si_factor,
+        dimension,
+    }
+}
+
+/// Elementary charge in coulombs
+const ELECTRON: f64 = 1.602_176_634e-19;
+
+/// Every known `_units.code`; add new codes here
+const UNITS: &[Unit] = &[
+    unit("none", "dimensionless", 1.0, Dimension::Dimensionless),
+    unit("unspecified", "unspecified", 1.0, Dimension::Unspecified),
+    // Length
+    unit("metres", "metre", 1.0, Dimension::Length),
+    unit("centimetres", "centimetre", 1e-2, Dimension::Length),
+    unit("millimetres", "millimetre", 1e-3, Dimension::Length),
+    unit("micrometres", "micrometre", 1e-6, Dimension::Length),
+    unit("nanometres", "nanometre", 1e-9, Dimension::Length),
+    unit("angstroms", "angstrom", 1e-10, Dimension::Length),
+    unit("picometres", "picometre", 1e-12, Dimension::Length),
+    unit("femtometres", "femtometre", 1e-15, Dimension::Length),
+    // Area and volume
+    unit("metres_squared", "square metre", 1.0, Dimension::Area),
+    unit(
+        "centimetres_squared",
+        "square centimetre",
+        1e-4,
+        Dimension::Area,
+    ),
+    unit(
+        "millimetres_squared",
+        "square millimetre",
+        1e-6,
+        Dimension::Area,
+    ),
+    unit(
+        "nanometre_squared",
+        "square nanometre",
+        1e-18,
+        Dimension::Area,
+    ),
+    unit(
+        "angstrom_squared",
+        "square angstrom",
+        1e-20,
+        Dimension::Area,
+    ),
+    unit(
+        "picometre_squared",
+        "square picometre",
+        1e-24,
+        Dimension::Area,
+    ),
+    unit("metres_cubed", "cubic metre", 1.0, Dimension::Volume),
+    unit(
+        "centimetres_cubed",
+        "cubic centimetre",
+        1e-6,
+        Dimension::Volume,
+    ),
+    unit(
+        "millimetres_cubed",
+        "cubic millimetre",
+        1e-9,
+        Dimension::Volume,
+    ),
+    unit(
+        "nanometres_cubed",
+        "cubic nanometre",
+        1e-27,
+        Dimension::Volume,
+    ),
+    unit("angstrom_cubed", "cubic angstrom", 1e-30, Dimension::Volume),
+    // Reciprocal space
+    unit(
+        "reciprocal_metres",
+        "reciprocal metre",
+        1.0,
+        Dimension::ReciprocalLength,
+    ),
+    unit(
+        "reciprocal_centimetres",
+        "reciprocal centimetre",
+        1e2,
+        Dimension::ReciprocalLength,
+    ),
+    unit(
+        "reciprocal_millimetres",
+        "reciprocal millimetre",
+        1e3,
+        Dimension::ReciprocalLength,
+    ),
+    unit(
+        "reciprocal_nanometres",
+        "reciprocal nanometre",
+        1e9,
+        Dimension::ReciprocalLength,
+    ),
+    unit(
+        "reciprocal_angstroms",
+        "reciprocal angstrom",
+        1e10,
+        Dimension::ReciprocalLength,
+    ),
+    unit(
+        "reciprocal_picometres",
+        "reciprocal picometre",
+        1e12,
+        Dimension::ReciprocalLength,
+    ),
+    unit(
+        "pixels_per_millimetre",
+        "pixel per millimetre",
+        1e3,
+        Dimension::ReciprocalLength,
+    ),
+    unit(
+        "reciprocal_metre_squared",
+        "reciprocal square metre",
+        1.0,
+        Dimension::ReciprocalArea,
+    ),
+    unit(
+        "reciprocal_nanometre_squared",
+        "reciprocal square nanometre",
+        1e18,
+        Dimension::ReciprocalArea,
+    ),
+    unit(
+        "reciprocal_angstrom_squared",
+        "reciprocal square angstrom",
+        1e20,
+        Dimension::ReciprocalArea,
+    ),
+    // Angles
+    unit("radians", "radian", 1.0, Dimension::Angle),
+    unit("degrees", "degree", PI / 180.0, Dimension::Angle),
+    unit(
+        "radians_per_second",
+        "radian per second",
+        1.0,
+        Dimension::AngularVelocity,
+    ),
+    unit(
+        "degrees_per_second",
+        "degree per second",
+        PI / 180.0,
+        Dimension::AngularVelocity,
+    ),
+    unit(
+        "degree_per_minute",
+        "degree per minute",
+        PI / 180.0 / 60.0,
+        Dimension::AngularVelocity,
+    ),
+    // Temperature and time
+    unit("kelvins", "kelvin", 1.0, Dimension::Temperature),
+    unit("millikelvins", "millikelvin", 1e-3, Dimension::Temperature),
+    unit("seconds", "second", 1.0, Dimension::Time),
+    unit("milliseconds", "millisecond", 1e-3, Dimension::Time),
+    unit("microseconds", "microsecond", 1e-6, Dimension::Time),
+    unit("nanoseconds", "nanosecond", 1e-9, Dimension::Time),
+    unit("picoseconds", "picosecond", 1e-12, Dimension::Time),
+    unit("minutes", "minute", 60.0, Dimension::Time),
+    unit("hours", "hour", 3600.0, Dimension::Time),
+    unit("days", "day", 86400.0, Dimension::Time),
+    // Mass and density
+    unit("kilograms", "kilogram", 1.0, Dimension::Mass),
+    unit("grams", "gram", 1e-3, Dimension::Mass),
+    unit("milligrams", "milligram", 1e-6, Dimension::Mass),
+    unit("dalton", "dalton", 1.660_539_066_60e-27, Dimension::Mass),
+    unit(
+        "kilograms_per_metre_cubed",
+        "kilogram per cubic metre",
+        1.0,
+        Dimension::Density,
+    ),
+    unit(
+        "grams_per_centimetre_cubed",
+        "gram per cubic centimetre",
+        1e3,
+        Dimension::Density,
+    ),
+    unit(
+        "megagrams_per_metre_cubed",
+        "megagram per cubic metre",
+        1e3,
+        Dimension::Density,
+    ),
+    // Charge
+    unit("coulombs", "coulomb", 1.0, Dimension::Charge),
+    unit("electrons", "electron", ELECTRON, Dimension::Charge),
+    unit(
+        "electrons_per_angstrom",
+        "electron per angstrom",
+        ELECTRON / 1e-10,
+        Dimension::LinearChargeDensity,
+    ),
+    unit(
+        "electrons_per_angstrom_cubed",
+        "electron per cubic angstrom",
+        ELECTRON / 1e-30,
+        Dimension::ChargeDensity,
+    ),
+    unit(
+        "electrons_per_nanometre_cubed",
+        "electron per cubic nanometre",
+        ELECTRON / 1e-27,
+        Dimension::ChargeDensity,
+    ),
+    // Electrical
+    unit("amperes", "ampere", 1.0, Dimension::Current),
+    unit("milliamperes", "milliampere", 1e-3, Dimension::Current),
+    unit("microamperes", "microampere", 1e-6, Dimension::Current),
+    unit("volts", "volt", 1.0, Dimension::Voltage),
+    unit("millivolts", "millivolt", 1e-3, Dimension::Voltage),
+    unit("kilovolts", "kilovolt", 1e3, Dimension::Voltage),
+    unit("megavolts", "megavolt", 1e6, Dimension::Voltage),
+    unit("watts", "watt", 1.0, Dimension::Power),
+    unit("milliwatts", "milliwatt", 1e-3, Dimension::Power),
+    unit("kilowatts", "kilowatt", 1e3, Dimension::Power),
+    // Energy, pressure and dose
+    unit("joules", "joule", 1.0, Dimension::Energy),
+    unit(
+        "electron_volts",
+        "electronvolt",
+        ELECTRON,
+        Dimension::Energy,
+    ),
+    unit(
+        "kiloelectron_volts",
+        "kiloelectronvolt",
+        ELECTRON * 1e3,
+        Dimension::Energy,
+    ),
+    unit("pascals", "pascal", 1.0, Dimension::Pressure),
+    unit("hectopascals", "hectopascal", 1e2, Dimension::Pressure),
+    unit("kilopascals", "kilopascal", 1e3, Dimension::Pressure),
+    unit("megapascals", "megapascal", 1e6, Dimension::Pressure),
+    unit("gigapascals", "gigapascal", 1e9, Dimension::Pressure),
+    unit("grays", "gray", 1.0, Dimension::AbsorbedDose),
+    unit("megagray", "megagray", 1e6, Dimension::AbsorbedDose),
+    // Counts
+    unit("counts", "count", 1.0, Dimension::Count),
+    unit("valence_units", "valence unit", 1.0, Dimension::BondValence),
+];
+
+/// Lookups in the units vocabulary
+pub struct Units;
+
+impl Units {
+    /// Every known unit, in table order
+    pub fn all() -> &'static [Unit] {
+        UNITS
+    }
+
+    /// Look up a unit code (case-insensitive)
+    pub fn lookup(code: &str) -> Option<&'static Unit> {
+        UNITS
+            .iter()
+            .find(|unit| unit.code.eq_ignore_ascii_case(code))
+    }
+
+    /// Whether `code` is in the vocabulary
+    pub fn is_known(code: &str) -> bool {
+        Self::lookup(code).is_some()
+    }
+
+    /// The known code closest to a misspelled one, if any is close enough
+    pub fn suggest(code: &str) -> Option<&'static str> {
+        let code = code.to_ascii_lowercase();
+        let limit = (code.chars().count() / 3).max(1);
+        UNITS
+            .iter()
+            .map(|unit| (edit_distance(&code, unit.code), unit.code))
+            .filter(|(distance, _)| *distance <= limit)
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, code)| code)
+    }
+
+    /// Convert `value` from one unit to another.
+    ///
+    /// Returns `None` if either code is unknown or `unspecified`, or if the
+    /// units measure different dimensions.
+    pub fn convert(value: f64, from: &str, to: &str) -> Option<f64> {
+        let from = Self::lookup(from)?;
+        let to = Self::lookup(to)?;
+        if from.dimension != to.dimension || from.dimension == Dimension::Unspecified {
+            return None;
+        }
+        Some(value * from.si_factor / to.si_factor)
+    }
+}
+
+/// Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes_are_unique_and_lowercase() {
+        for (i, unit) in UNITS.iter().enumerate() {
+            assert_eq!(unit.code, unit.code.to_ascii_lowercase());
+            assert!(
+                UNITS[i + 1..].iter().all(|other| other.code != unit.code),
+                "duplicate code {}",
+                unit.code
+            );
+        }
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("angstroem", "angstroms"), 2);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kelvins", "kelvins"), 0);
+    }
+
+    #[test]
+    fn test_suggest_needs_a_close_match() {
+        assert_eq!(Units::suggest("Degres"), Some("degrees"));
+        assert_eq!(Units::suggest("furlongs_per_fortnight"), None);
+    }
+
+    #[test]
+    fn test_convert_refuses_unspecified_and_mixed_dimensions() {
+        assert_eq!(Units::convert(1.0, "unspecified", "unspecified"), None);
+        assert_eq!(Units::convert(1.0, "kelvins", "seconds"), None);
+        assert_eq!(Units::convert(1.0, "bogus", "metres"), None);
+        let radians = Units::convert(180.0, "DEGREES", "radians").unwrap();
+        assert!((radians - PI).abs() < 1e-12);
+    }
+}
@@ -2,29 +2,93 @@
 //!
 //! This module validates that a dictionary is internally consistent by
 //! checking that all dREL methods only reference items that exist in
-//! the dictionary.
+//! the dictionary, and warns about declared units missing from the units
+//! vocabulary.
 
 use cif_parser::Span;
-use drel_parser::{extract_references, parse, ReferenceKind};
+use drel_parser::{extract_references, DrelError, Program, ReferenceKind};
 
+use super::imports::{ImportSpec, IMPORT_KEYS};
 use super::types::Dictionary;
+use super::units::Units;
 use crate::error::DictionaryError;
 
-/// Convert a drel_parser::Span to cif_parser::Span
-fn convert_span(drel_span: drel_parser::Span) -> Span {
+/// Categories of the DDLm attribute dictionary (`ddl.dic`).
+///
+/// Definition methods assign attributes of the definition itself, such as
+/// `_units.code`; these names are defined by DDLm, not by the dictionary.
+const DDLM_ATTRIBUTE_CATEGORIES: &[&str] = &[
+    "alias",
+    "category_key",
+    "definition",
+    "definition_replaced",
+    "description",
+    "description_example",
+    "dictionary",
+    "dictionary_audit",
+    "dictionary_valid",
+    "dictionary_xref",
+    "enumeration",
+    "enumeration_default",
+    "enumeration_set",
+    "import",
+    "import_details",
+    "loop",
+    "method",
+    "name",
+    "type",
+    "units",
+];
+
+/// Whether a data name is a DDLm attribute, e.g. `_units.code`
+fn is_ddl_attribute(name: &str) -> bool {
+    let Some((category, _)) = name.trim_start_matches('_').split_once('.') else {
+        return false;
+    };
+    DDLM_ATTRIBUTE_CATEGORIES
+        .iter()
+        .any(|attribute| attribute.eq_ignore_ascii_case(category))
+}
+
+/// Place a span within a method in the dictionary file.
+///
+/// `origin` is where the method text starts (see
+/// [`DataItem::method_origin`](super::DataItem::method_origin)); columns
+/// are shifted on the method's first line only. Without an origin the span
+/// stays relative to the method.
+fn rebase(drel_span: drel_parser::Span, origin: Option<Span>) -> Span {
+    let origin = origin.unwrap_or(Span::point(1, 1));
+    let line = |line: usize| line + origin.start_line - 1;
+    let col = |line: usize, col: usize| {
+        if line == 1 {
+            col + origin.start_col - 1
+        } else {
+            col
+        }
+    };
     Span::new(
-        drel_span.start_line,
-        drel_span.start_col,
-        drel_span.end_line,
-        drel_span.end_col,
+        line(drel_span.start_line),
+        col(drel_span.start_line, drel_span.start_col),
+        line(drel_span.end_line),
+        col(drel_span.end_line, drel_span.end_col),
     )
 }
 
 /// Validate a dictionary's internal consistency.
 ///
-/// This function checks:
+/// This function checks, for item and category methods and dREL functions
+/// alike:
 /// - All dREL methods parse successfully
-/// - All items referenced in dREL methods exist in the dictionary
+/// - All items referenced in dREL methods exist in the dictionary, or are
+///   DDLm attributes such as `_units.code`
+/// - Import tables (`_import.get`) use only the keys DDLm defines
+///
+/// A method with syntax errors is reported once per bad statement, at its
+/// place in the dictionary file; the references of the statements that
+/// do parse are still checked.
+///
+/// Warnings, such as unknown units codes, come from [`dictionary_warnings`]
+/// instead.
 ///
 /// # Arguments
 /// * `dict` - The dictionary to validate
@@ -47,79 +111,163 @@ fn convert_span(drel_span: drel_parser::Span) -> Span {
 pub fn validate_dictionary(dict: &Dictionary) -> Vec<DictionaryError> {
     let mut errors = Vec::new();
 
-    for item in dict.items.values() {
-        if let Some(drel_source) = &item.drel_method {
-            // Try to parse the dREL method
-            match parse(drel_source) {
-                Ok(stmts) => {
-                    // Extract all references from the parsed dREL
-                    let refs = extract_references(&stmts);
-
-                    for ref_ in refs {
-                        // Only check data name references (not local variables)
-                        if ref_.kind == ReferenceKind::DataName {
-                            let ref_name = ref_.full_name();
-
-                            // Check if the referenced item exists in the dictionary
-                            if !dict.has_item(&ref_name) {
-                                errors.push(DictionaryError::MissingDrelReference {
-                                    item: item.name.clone(),
-                                    referenced: ref_name,
-                                    span: convert_span(ref_.span),
-                                });
-                            }
-                        }
-
-                        // Check category references (from Loop statements)
-                        if ref_.kind == ReferenceKind::Category {
-                            let cat_name = &ref_.category;
-                            if !dict.categories.contains_key(&cat_name.to_lowercase()) {
-                                // Category might be referenced indirectly, check if any
-                                // items have this category
-                                let category_exists = dict
-                                    .items
-                                    .values()
-                                    .any(|i| i.category.eq_ignore_ascii_case(cat_name));
-
-                                if !category_exists {
-                                    errors.push(DictionaryError::MissingDrelReference {
-                                        item: item.name.clone(),
-                                        referenced: format!("category '{}'", cat_name),
-                                        span: convert_span(ref_.span),
-                                    });
-                                }
-                            }
-                        }
-                    }
-                }
-                Err(e) => {
-                    errors.push(DictionaryError::InvalidDrel {
-                        item: item.name.clone(),
-                        message: e.to_string(),
-                        span: item.span,
+    // Definitions are checked in dictionary order so errors come out the same every run
+    let mut items: Vec<_> = dict.items.values().collect();
+    items.sort_by_key(|item| (item.span.start_line, item.span.start_col, &item.name));
+    for item in items {
+        check_imports(&item.name, &item.imports, &mut errors);
+        if let Some(method) = item.method_parse() {
+            check_method(dict, &item.name, method, item.method_origin, &mut errors);
+        }
+    }
+
+    // Category methods are checked once here rather than for each item they assign
+    let mut categories: Vec<_> = dict.categories.values().collect();
+    categories.sort_by_key(|cat| (cat.span.start_line, cat.span.start_col, &cat.name));
+    for category in categories {
+        check_imports(&category.name, &category.imports, &mut errors);
+        if let Some(method) = category.method_parse() {
+            check_method(
+                dict,
+                &category.name,
+                method,
+                category.method_origin,
+                &mut errors,
+            );
+        }
+    }
+
+    let mut functions: Vec<_> = dict.functions.values().collect();
+    functions.sort_by_key(|function| (function.span.start_line, function.span.start_col));
+    for function in functions {
+        check_method(
+            dict,
+            &function.name,
+            function.method_parse(),
+            function.method_origin,
+            &mut errors,
+        );
+    }
+
+    errors
+}
+
+/// Find likely mistakes that do not stop a dictionary from being used.
+///
+/// Reports `_units.code` values missing from the units vocabulary as
+/// [`DictionaryError::UnknownUnits`], suggesting the nearest known code.
+/// Every entry is one for which [`DictionaryError::is_warning`] is true;
+/// errors come from [`validate_dictionary`].
+pub fn dictionary_warnings(dict: &Dictionary) -> Vec<DictionaryError> {
+    let mut items: Vec<_> = dict.items.values().collect();
+    items.sort_by_key(|item| (item.span.start_line, item.span.start_col, &item.name));
+    items
+        .into_iter()
+        .filter_map(|item| {
+            let code = item.type_info.units.as_deref()?;
+            (!Units::is_known(code)).then(|| DictionaryError::UnknownUnits {
+                item: item.name.to_string(),
+                code: code.to_string(),
+                suggestion: Units::suggest(code).map(String::from),
+                span: item.span,
+            })
+        })
+        .collect()
+}
+
+/// Check that import tables use only the keys DDLm defines
+fn check_imports(owner: &str, imports: &[ImportSpec], errors: &mut Vec<DictionaryError>) {
+    for spec in imports {
+        for key in &spec.unknown_keys {
+            errors.push(DictionaryError::InvalidField {
+                item: owner.to_string(),
+                field: "_import.get".to_string(),
+                message: format!(
+                    "unknown key '{}' in the import of '{}'; expected one of {}",
+                    key,
+                    spec.file,
+                    IMPORT_KEYS.join(", ")
+                ),
+                span: spec.span,
+            });
+        }
+    }
+}
+
+/// Check that one dREL method parses and references only known items
+fn check_method(
+    dict: &Dictionary,
+    owner: &str,
+    (program, syntax_errors): (&Program, &[DrelError]),
+    origin: Option<Span>,
+    errors: &mut Vec<DictionaryError>,
+) {
+    for e in syntax_errors {
+        errors.push(DictionaryError::InvalidDrel {
+            item: owner.to_string(),
+            message: e.message(),
+            span: rebase(e.span(), origin),
+        });
+    }
+
+    // Extract all references from the parsed dREL
+    for ref_ in extract_references(&program.statements) {
+        // Only check data name references (not local variables)
+        if ref_.kind == ReferenceKind::DataName {
+            let ref_name = ref_.full_name();
+
+            // Check if the referenced item exists in the dictionary
+            if !dict.has_item(&ref_name) && !is_ddl_attribute(&ref_name) {
+                errors.push(DictionaryError::MissingDrelReference {
+                    item: owner.to_string(),
+                    referenced: ref_name,
+                    span: rebase(ref_.span, origin),
+                });
+            }
+        }
+
+        // Check category references (from Loop statements)
+        if ref_.kind == ReferenceKind::Category {
+            let cat_name = &ref_.category;
+            if !dict.categories.contains_key(&cat_name.to_lowercase()) {
+                // Category might be referenced indirectly, check if any
+                // items have this category
+                let category_exists = dict
+                    .items
+                    .values()
+                    .any(|i| i.category.eq_ignore_ascii_case(cat_name));
+
+                if !category_exists {
+                    errors.push(DictionaryError::MissingDrelReference {
+                        item: owner.to_string(),
+                        referenced: format!("category '{}'", cat_name),
+                        span: rebase(ref_.span, origin),
                     });
                 }
             }
         }
     }
-
-    errors
 }
 
 /// Check if a dictionary has any dREL methods
 #[allow(dead_code)]
 pub fn has_drel_methods(dict: &Dictionary) -> bool {
-    dict.items.values().any(|item| item.drel_method.is_some())
+    dict.items
+        .values()
+        .any(|item| dict.effective_method(&item.name).is_some())
 }
 
-/// Get all items with dREL methods
+/// Get all items an Evaluation method computes, including inherited methods, sorted
 #[allow(dead_code)]
 pub fn items_with_drel(dict: &Dictionary) -> Vec<&str> {
-    dict.items
+    let mut names: Vec<&str> = dict
+        .items
         .values()
-        .filter(|item| item.drel_method.is_some())
+        .filter(|item| dict.effective_method(&item.name).is_some())
         .map(|item| item.name.as_str())
-        .collect()
+        .collect();
+    names.sort_unstable();
+    names
 }
 
 #[cfg(test)]
@@ -128,11 +276,21 @@ mod tests {
     use crate::dictionary::load_dictionary;
     use cif_parser::CifDocument;
 
+    #[test]
+    fn test_is_ddl_attribute() {
+        assert!(is_ddl_attribute("_units.code"));
+        assert!(is_ddl_attribute("_Enumeration.Range"));
+        assert!(is_ddl_attribute("_type.contents"));
+
+        assert!(!is_ddl_attribute("_cell.length_a"));
+        assert!(!is_ddl_attribute("_units_code"));
+        assert!(!is_ddl_attribute("_unitsx.code"));
+    }
+
     #[test]
     fn test_validate_valid_dictionary() {
         // A dictionary with a dREL method referencing existing items
-        let cif_content = r#"
-#\#CIF_2.0
+        let cif_content = r#"#\#CIF_2.0
 data_TEST_DICT
     _dictionary.title             TEST_DICT
 
@@ -178,8 +336,7 @@ save_
     #[test]
     fn test_validate_missing_reference() {
         // A dictionary with a dREL method referencing a non-existent item
-        let cif_content = r#"
-#\#CIF_2.0
+        let cif_content = r#"#\#CIF_2.0
 data_TEST_DICT
     _dictionary.title             TEST_DICT
 
@@ -227,8 +384,7 @@ save_
 
     #[test]
     fn test_has_drel_methods() {
-        let cif_content = r#"
-#\#CIF_2.0
+        let cif_content = r#"#\#CIF_2.0
 data_TEST_DICT
 
 save_cell.length_a
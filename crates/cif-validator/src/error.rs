@@ -2,13 +2,19 @@
 //!
 //! All errors include source locations for IDE integration and rich error messages.
 
+use crate::dictionary::{DdlFlavor, RangeConstraint};
+use crate::symmetry::SymOpParseError;
 use cif_parser::Span;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use thiserror::Error;
 
 /// Categories of validation errors
+///
+/// Non-exhaustive: new checks add categories, so downstream matches need a
+/// wildcard arm.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum ErrorCategory {
     /// Unknown data name (not in dictionary)
     UnknownDataName,
@@ -26,6 +32,10 @@ pub enum ErrorCategory {
     LinkError,
     /// Dictionary loading/parsing error
     DictionaryError,
+    /// Item given more values than its category allows
+    DuplicateItem,
+    /// Mandatory or key item present with only unknown (`?`) values
+    UnknownMandatoryValue,
 }
 
 impl fmt::Display for ErrorCategory {
@@ -39,6 +49,8 @@ impl fmt::Display for ErrorCategory {
             Self::LoopStructure => write!(f, "loop structure error"),
             Self::LinkError => write!(f, "link error"),
             Self::DictionaryError => write!(f, "dictionary error"),
+            Self::DuplicateItem => write!(f, "duplicate item"),
+            Self::UnknownMandatoryValue => write!(f, "unknown mandatory value"),
         }
     }
 }
@@ -62,6 +74,12 @@ pub struct ValidationError {
     pub definition_span: Option<Span>,
     /// Suggestions for fixing the error
     pub suggestions: Vec<String>,
+    /// Text that could replace the value at `span` outright, best first
+    #[serde(default)]
+    pub replacements: Vec<String>,
+    /// Name of the data block the error was found in
+    #[serde(default)]
+    pub block: Option<String>,
 }
 
 impl ValidationError {
@@ -76,6 +94,8 @@ impl ValidationError {
             actual: None,
             definition_span: None,
             suggestions: Vec::new(),
+            replacements: Vec::new(),
+            block: None,
         }
     }
 
@@ -91,6 +111,8 @@ impl ValidationError {
             actual: None,
             definition_span: None,
             suggestions: Vec::new(),
+            replacements: Vec::new(),
+            block: None,
         }
     }
 
@@ -116,23 +138,27 @@ impl ValidationError {
             actual: Some(actual),
             definition_span: None,
             suggestions: Vec::new(),
+            replacements: Vec::new(),
+            block: None,
         }
     }
 
-    /// Create a range error
+    /// Create a range error listing every permitted range
     pub fn range_error(
         name: impl Into<String>,
         value: f64,
-        min: Option<f64>,
-        max: Option<f64>,
+        ranges: &[RangeConstraint],
         span: Span,
     ) -> Self {
         let name = name.into();
-        let range_desc = match (min, max) {
-            (Some(min), Some(max)) => format!("{} to {}", min, max),
-            (Some(min), None) => format!(">= {}", min),
-            (None, Some(max)) => format!("<= {}", max),
-            (None, None) => "any value".to_string(),
+        let range_desc = if ranges.is_empty() {
+            "any value".to_string()
+        } else {
+            ranges
+                .iter()
+                .map(|range| range.to_string())
+                .collect::<Vec<_>>()
+                .join(" or ")
         };
 
         Self {
@@ -147,6 +173,8 @@ impl ValidationError {
             actual: Some(value.to_string()),
             definition_span: None,
             suggestions: Vec::new(),
+            replacements: Vec::new(),
+            block: None,
         }
     }
 
@@ -173,6 +201,8 @@ impl ValidationError {
             actual: Some(actual),
             definition_span: None,
             suggestions: Vec::new(),
+            replacements: Vec::new(),
+            block: None,
         }
     }
 
@@ -188,6 +218,33 @@ impl ValidationError {
             actual: None,
             definition_span: None,
             suggestions: Vec::new(),
+            replacements: Vec::new(),
+            block: None,
+        }
+    }
+
+    /// Create an error for a mandatory or key item whose values are all `?`
+    ///
+    /// `role` names why the item is required (`"Mandatory"` or `"Key"`);
+    /// `span` is the item's value, or the whole loop for a looped item.
+    pub fn unknown_mandatory_value(
+        name: impl Into<String>,
+        role: &str,
+        looped: bool,
+        span: Span,
+    ) -> Self {
+        let name = name.into();
+        Self {
+            category: ErrorCategory::UnknownMandatoryValue,
+            message: uninformative_message(&name, role, looped, "unknown ('?')"),
+            span,
+            data_name: Some(name),
+            expected: Some("a known value".to_string()),
+            actual: Some("?".to_string()),
+            definition_span: None,
+            suggestions: Vec::new(),
+            replacements: Vec::new(),
+            block: None,
         }
     }
 
@@ -202,6 +259,49 @@ impl ValidationError {
             actual: None,
             definition_span: None,
             suggestions: Vec::new(),
+            replacements: Vec::new(),
+            block: None,
+        }
+    }
+
+    /// Create a duplicate item error for a Set-category item with several values
+    pub fn duplicate_item(name: impl Into<String>, count: usize, span: Span) -> Self {
+        let name = name.into();
+        Self {
+            category: ErrorCategory::DuplicateItem,
+            message: format!(
+                "Item '{}' belongs to a Set category and may only have one value, found {}",
+                name, count
+            ),
+            span,
+            data_name: Some(name),
+            expected: Some("1 value".to_string()),
+            actual: Some(format!("{} values", count)),
+            definition_span: None,
+            suggestions: Vec::new(),
+            replacements: Vec::new(),
+            block: None,
+        }
+    }
+
+    /// Create a type error for a symmetry operator string that does not parse
+    pub fn invalid_symmetry_operator(
+        name: impl Into<String>,
+        error: &SymOpParseError,
+        span: Span,
+    ) -> Self {
+        let name = name.into();
+        Self {
+            category: ErrorCategory::TypeError,
+            message: format!("{} in '{}'", error, name),
+            span,
+            data_name: Some(name),
+            expected: Some("symmetry operator such as '-x,1/2+y,z'".to_string()),
+            actual: Some(error.operator.clone()),
+            definition_span: None,
+            suggestions: Vec::new(),
+            replacements: Vec::new(),
+            block: None,
         }
     }
 
@@ -217,6 +317,12 @@ impl ValidationError {
         self
     }
 
+    /// Add text that could replace the value at the error's span
+    pub fn with_replacement(mut self, replacement: impl Into<String>) -> Self {
+        self.replacements.push(replacement.into());
+        self
+    }
+
     /// Set the definition span
     pub fn with_definition_span(mut self, span: Span) -> Self {
         self.definition_span = Some(span);
@@ -243,7 +349,10 @@ impl fmt::Display for ValidationError {
 impl std::error::Error for ValidationError {}
 
 /// Warning categories
+///
+/// Non-exhaustive for the same reason as [`ErrorCategory`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum WarningCategory {
     /// Mixed categories in a loop
     MixedCategories,
@@ -253,6 +362,21 @@ pub enum WarningCategory {
     Style,
     /// Unknown item in lenient mode
     UnknownItem,
+    /// Same item appears in more than one loop
+    DuplicateItem,
+    /// Text value contains lines that look like CIF structure
+    SuspiciousTextField,
+    /// Mandatory or key item present with only unknown (`?`) values
+    UnknownMandatoryValue,
+    /// Mandatory or key item given as not applicable (`.`), in pedantic mode
+    NotApplicableMandatory,
+    /// Set-category items written as a one-row loop
+    LoopedSetCategory,
+    /// Pointer to a data block that is not in the document
+    DanglingBlockLink,
+    /// Item whose content type is implied and so was not checked, in
+    /// pedantic mode
+    ImpliedType,
 }
 
 /// A validation warning (non-fatal)
@@ -264,6 +388,9 @@ pub struct ValidationWarning {
     pub message: String,
     /// Source location
     pub span: Span,
+    /// Name of the data block the warning was found in
+    #[serde(default)]
+    pub block: Option<String>,
 }
 
 impl ValidationWarning {
@@ -273,6 +400,7 @@ impl ValidationWarning {
             category,
             message: message.into(),
             span,
+            block: None,
         }
     }
 
@@ -285,6 +413,142 @@ impl ValidationWarning {
                 categories.join(", ")
             ),
             span,
+            block: None,
+        }
+    }
+
+    /// Create a warning for an item looped in more than one loop
+    pub fn duplicate_loop_item(name: &str, loops: usize, span: Span) -> Self {
+        Self {
+            category: WarningCategory::DuplicateItem,
+            message: format!("Item '{}' appears in {} different loops", name, loops),
+            span,
+            block: None,
+        }
+    }
+
+    /// Create a warning for an item given under more than one of its names
+    pub fn alias_conflict(name: &str, spellings: &[&str], span: Span) -> Self {
+        let spellings: Vec<String> = spellings.iter().map(|t| format!("'{}'", t)).collect();
+        Self {
+            category: WarningCategory::DuplicateItem,
+            message: format!(
+                "Item '{}' is given under more than one name: {}",
+                name,
+                spellings.join(", ")
+            ),
+            span,
+            block: None,
+        }
+    }
+
+    /// Create a warning for a text value that appears to contain CIF structure
+    pub fn suspicious_text_field(name: &str, line: &str, span: Span) -> Self {
+        Self {
+            category: WarningCategory::SuspiciousTextField,
+            message: format!(
+                "Text value of '{}' contains a line that looks like CIF structure ('{}'); \
+                 a closing ';' may be missing",
+                name, line
+            ),
+            span,
+            block: None,
+        }
+    }
+
+    /// Create a warning for a mandatory or key item whose values are all `?`
+    pub fn unknown_mandatory_value(name: &str, role: &str, looped: bool, span: Span) -> Self {
+        Self {
+            category: WarningCategory::UnknownMandatoryValue,
+            message: uninformative_message(name, role, looped, "unknown ('?')"),
+            span,
+            block: None,
+        }
+    }
+
+    /// Create a note for a mandatory or key item whose values are all `.`
+    pub fn not_applicable_mandatory(name: &str, role: &str, looped: bool, span: Span) -> Self {
+        Self {
+            category: WarningCategory::NotApplicableMandatory,
+            message: uninformative_message(name, role, looped, "not applicable ('.')"),
+            span,
+            block: None,
+        }
+    }
+
+    /// Create a warning for Set-category items wrapped in a one-row loop
+    pub fn looped_set_category(category: &str, span: Span) -> Self {
+        Self {
+            category: WarningCategory::LoopedSetCategory,
+            message: format!("Set category '{}' written as a one-row loop", category),
+            span,
+            block: None,
+        }
+    }
+
+    /// Create a warning for a pointer to a block that is not in the document
+    pub fn dangling_block_link(tag: &str, target: &str, span: Span) -> Self {
+        Self {
+            category: WarningCategory::DanglingBlockLink,
+            message: format!(
+                "'{}' points to block '{}', which is not in this document",
+                tag, target
+            ),
+            span,
+            block: None,
+        }
+    }
+
+    /// Create a note for an item with an implied content type
+    pub fn implied_type(name: &str, span: Span) -> Self {
+        Self {
+            category: WarningCategory::ImpliedType,
+            message: format!(
+                "'{}' has an implied content type and was not type-checked",
+                name
+            ),
+            span,
+            block: None,
+        }
+    }
+
+    /// Create a warning for bare text that must be quoted to round-trip
+    pub fn unquoted_text(name: &str, text: &str, span: Span) -> Self {
+        Self {
+            category: WarningCategory::Style,
+            message: format!(
+                "Value '{}' of '{}' is written without quotes but cannot be a bare token; \
+                 quote it",
+                text, name
+            ),
+            span,
+            block: None,
+        }
+    }
+
+    /// Create a warning for a single-line value in a text field or triple quotes
+    pub fn heavyweight_quoting(name: &str, delimiter: &str, span: Span) -> Self {
+        Self {
+            category: WarningCategory::Style,
+            message: format!(
+                "Single-line value of '{}' is written as a {}; plain quotes would do",
+                name, delimiter
+            ),
+            span,
+            block: None,
+        }
+    }
+
+    /// Create a warning for a simple value in quotes it does not need
+    pub fn unneeded_quotes(name: &str, text: &str, span: Span) -> Self {
+        Self {
+            category: WarningCategory::Style,
+            message: format!(
+                "Value '{}' of '{}' is quoted but reads the same without quotes",
+                text, name
+            ),
+            span,
+            block: None,
         }
     }
 }
@@ -299,6 +563,19 @@ impl fmt::Display for ValidationWarning {
     }
 }
 
+/// Message for a required item present without an informative value
+fn uninformative_message(name: &str, role: &str, looped: bool, value: &str) -> String {
+    let values = if looped {
+        "every value in its loop is"
+    } else {
+        "its value is"
+    };
+    format!(
+        "{} item '{}' is present but {} {}",
+        role, name, values, value
+    )
+}
+
 /// Error type for dictionary loading/parsing
 #[derive(Debug, Clone, Error)]
 pub enum DictionaryError {
@@ -339,6 +616,35 @@ pub enum DictionaryError {
         span: Span,
     },
 
+    /// `_units.code` is not in the units vocabulary (a warning, reported by
+    /// [`dictionary_warnings`](crate::dictionary::dictionary_warnings))
+    #[error(
+        "Unknown units code '{code}' in '{item}'{}",
+        .suggestion.as_ref().map(|s| format!(" (did you mean '{s}'?)")).unwrap_or_default()
+    )]
+    UnknownUnits {
+        item: String,
+        code: String,
+        suggestion: Option<String>,
+        span: Span,
+    },
+
+    /// `ByReference` content type naming no definition, or a chain of
+    /// references that leads back to itself
+    #[error("'{item}' takes its content type from '{referenced}', which does not define one")]
+    DanglingContentsReference {
+        item: String,
+        referenced: String,
+        span: Span,
+    },
+
+    /// Dictionary is written in a different definition language than the loader handles
+    #[error("Dictionary is written in {detected} but the loader expects {expected}")]
+    WrongDdlFlavor {
+        detected: DdlFlavor,
+        expected: DdlFlavor,
+    },
+
     /// IO error
     #[error("IO error: {0}")]
     IoError(String),
@@ -353,13 +659,40 @@ impl DictionaryError {
             Self::InvalidField { span, .. } => Some(*span),
             Self::InvalidDrel { span, .. } => Some(*span),
             Self::MissingDrelReference { span, .. } => Some(*span),
-            Self::IoError(_) => None,
+            Self::UnknownUnits { span, .. } => Some(*span),
+            Self::DanglingContentsReference { span, .. } => Some(*span),
+            Self::WrongDdlFlavor { .. } | Self::IoError(_) => None,
         }
     }
+
+    /// Whether this is a warning: a likely mistake that does not stop the
+    /// dictionary from being used
+    pub fn is_warning(&self) -> bool {
+        matches!(self, Self::UnknownUnits { .. })
+    }
+}
+
+/// Errors reading or writing a baseline file
+#[derive(Debug, Clone, Error)]
+pub enum BaselineError {
+    /// The file could not be read or written
+    #[error("IO error: {0}")]
+    IoError(String),
+
+    /// The file is not a valid baseline
+    #[error("Invalid baseline: {0}")]
+    InvalidFormat(String),
+
+    /// The file was written by a newer format version
+    #[error("Unsupported baseline version {found} (expected {expected})")]
+    UnsupportedVersion { found: u32, expected: u32 },
 }
 
 /// Result of validating a CIF document
-#[derive(Debug, Clone, Default)]
+///
+/// Errors and warnings are grouped by block and kept in document order, so
+/// validating the same document always gives the same result.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ValidationResult {
     /// Whether the document is valid (no errors)
     pub is_valid: bool,
@@ -367,6 +700,10 @@ pub struct ValidationResult {
     pub errors: Vec<ValidationError>,
     /// Validation warnings (non-fatal issues)
     pub warnings: Vec<ValidationWarning>,
+    /// Findings matched by a baseline and left out of `errors` and `warnings`
+    pub suppressed: Vec<Finding>,
+    /// Number of baseline entries that matched no finding (issues since fixed)
+    pub baseline_unmatched: usize,
 }
 
 impl ValidationResult {
@@ -374,8 +711,7 @@ impl ValidationResult {
     pub fn new() -> Self {
         Self {
             is_valid: true,
-            errors: Vec::new(),
-            warnings: Vec::new(),
+            ..Self::default()
         }
     }
 
@@ -399,4 +735,38 @@ impl ValidationResult {
     pub fn warning_count(&self) -> usize {
         self.warnings.len()
     }
+
+    /// Record the block for findings added since the given counts and put
+    /// them in document order
+    ///
+    /// Findings at the same position are ordered by data name; the sort is
+    /// stable, so anything left tied keeps the order it was found in.
+    pub(crate) fn finish_block(&mut self, errors: usize, warnings: usize, block: &str) {
+        let block_errors = &mut self.errors[errors..];
+        for error in block_errors.iter_mut() {
+            error.block.get_or_insert_with(|| block.to_string());
+        }
+        block_errors.sort_by(|a, b| {
+            (a.span.start_line, a.span.start_col, &a.data_name).cmp(&(
+                b.span.start_line,
+                b.span.start_col,
+                &b.data_name,
+            ))
+        });
+        let block_warnings = &mut self.warnings[warnings..];
+        for warning in block_warnings.iter_mut() {
+            warning.block.get_or_insert_with(|| block.to_string());
+        }
+        block_warnings.sort_by_key(|warning| (warning.span.start_line, warning.span.start_col));
+    }
+}
+
+/// A validation error or warning
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "severity", rename_all = "snake_case")]
+pub enum Finding {
+    /// An error
+    Error(ValidationError),
+    /// A warning
+    Warning(ValidationWarning),
 }
@@ -0,0 +1,335 @@
+//! Bond, angle and torsion tables read from validated blocks.
+//!
+//! The `_geom_bond`, `_geom_angle` and `_geom_torsion` loops list the atoms
+//! of each contact by label, a measurand with its standard uncertainty, the
+//! symmetry code that places each atom, and a flag marking entries for
+//! publication. [`GeometryTables`], from
+//! [`ValidatedBlock::geometry`](crate::ValidatedBlock::geometry), gathers
+//! each row into one record, whatever spelling the file uses and whether
+//! the uncertainty is written in parentheses (`1.523(2)`) or in its own
+//! `_su` column.
+
+use cif_parser::{CifBlock, CifLoop, CifValue, CifValueKind, Measurand};
+use serde::{Deserialize, Serialize};
+
+use crate::dictionary::Dictionary;
+use crate::error::ValidationError;
+use crate::resolve::{find_column, find_looped};
+
+/// A bond distance between two atom sites
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BondRecord {
+    /// Label of the first atom site
+    pub atom1: String,
+    /// Label of the second atom site
+    pub atom2: String,
+    /// Distance in ångströms
+    pub distance: Measurand,
+    /// Symmetry code placing the first atom, if given
+    pub site_symmetry_1: Option<String>,
+    /// Symmetry code placing the second atom, if given
+    pub site_symmetry_2: Option<String>,
+    /// Publication flag (`yes` or `no`), if given
+    pub publ_flag: Option<String>,
+}
+
+/// A bond angle at the second of three atom sites
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AngleRecord {
+    /// Label of the first atom site
+    pub atom1: String,
+    /// Label of the apex atom site
+    pub atom2: String,
+    /// Label of the third atom site
+    pub atom3: String,
+    /// Angle in degrees
+    pub angle: Measurand,
+    /// Symmetry code placing the first atom, if given
+    pub site_symmetry_1: Option<String>,
+    /// Symmetry code placing the apex atom, if given
+    pub site_symmetry_2: Option<String>,
+    /// Symmetry code placing the third atom, if given
+    pub site_symmetry_3: Option<String>,
+    /// Publication flag (`yes` or `no`), if given
+    pub publ_flag: Option<String>,
+}
+
+/// A torsion angle about the bond between the second and third atom sites
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TorsionRecord {
+    /// Label of the first atom site
+    pub atom1: String,
+    /// Label of the second atom site
+    pub atom2: String,
+    /// Label of the third atom site
+    pub atom3: String,
+    /// Label of the fourth atom site
+    pub atom4: String,
+    /// Torsion angle in degrees, clockwise positive
+    pub angle: Measurand,
+    /// Symmetry code placing the first atom, if given
+    pub site_symmetry_1: Option<String>,
+    /// Symmetry code placing the second atom, if given
+    pub site_symmetry_2: Option<String>,
+    /// Symmetry code placing the third atom, if given
+    pub site_symmetry_3: Option<String>,
+    /// Symmetry code placing the fourth atom, if given
+    pub site_symmetry_4: Option<String>,
+    /// Publication flag (`yes` or `no`), if given
+    pub publ_flag: Option<String>,
+}
+
+/// The geometry loops of a block.
+///
+/// Each accessor returns an empty list when the block has no such loop.
+/// Rows whose labels or measurand are unknown (`?`) or inapplicable (`.`)
+/// are skipped. A loop that lacks a label or measurand column is reported
+/// once per missing column, with the span of the loop; a measurand that is
+/// not a number is reported with the span of its value.
+#[derive(Debug, Clone, Copy)]
+pub struct GeometryTables<'a> {
+    block: &'a CifBlock,
+    dictionary: &'a Dictionary,
+}
+
+impl<'a> GeometryTables<'a> {
+    pub(crate) fn new(block: &'a CifBlock, dictionary: &'a Dictionary) -> Self {
+        Self { block, dictionary }
+    }
+
+    /// Bond distances from `_geom_bond`
+    pub fn bonds(&self) -> Result<Vec<BondRecord>, Vec<ValidationError>> {
+        let rows = self.read(&BOND)?;
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let [atom1, atom2] = row.atoms;
+                let [site_symmetry_1, site_symmetry_2] = row.site_symmetry;
+                BondRecord {
+                    atom1,
+                    atom2,
+                    distance: row.measurand,
+                    site_symmetry_1,
+                    site_symmetry_2,
+                    publ_flag: row.publ_flag,
+                }
+            })
+            .collect())
+    }
+
+    /// Bond angles from `_geom_angle`
+    pub fn angles(&self) -> Result<Vec<AngleRecord>, Vec<ValidationError>> {
+        let rows = self.read(&ANGLE)?;
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let [atom1, atom2, atom3] = row.atoms;
+                let [site_symmetry_1, site_symmetry_2, site_symmetry_3] = row.site_symmetry;
+                AngleRecord {
+                    atom1,
+                    atom2,
+                    atom3,
+                    angle: row.measurand,
+                    site_symmetry_1,
+                    site_symmetry_2,
+                    site_symmetry_3,
+                    publ_flag: row.publ_flag,
+                }
+            })
+            .collect())
+    }
+
+    /// Torsion angles from `_geom_torsion`
+    pub fn torsions(&self) -> Result<Vec<TorsionRecord>, Vec<ValidationError>> {
+        let rows = self.read(&TORSION)?;
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let [atom1, atom2, atom3, atom4] = row.atoms;
+                let [site_symmetry_1, site_symmetry_2, site_symmetry_3, site_symmetry_4] =
+                    row.site_symmetry;
+                TorsionRecord {
+                    atom1,
+                    atom2,
+                    atom3,
+                    atom4,
+                    angle: row.measurand,
+                    site_symmetry_1,
+                    site_symmetry_2,
+                    site_symmetry_3,
+                    site_symmetry_4,
+                    publ_flag: row.publ_flag,
+                }
+            })
+            .collect())
+    }
+
+    /// Read every usable row of a geometry loop
+    fn read<const N: usize>(&self, table: &Table<N>) -> Result<Vec<Row<N>>, Vec<ValidationError>> {
+        let Some(loop_) = self.find_loop(table) else {
+            return Ok(Vec::new());
+        };
+        let column = |(canonical, legacy): (&str, &[&str])| {
+            find_column(loop_, self.dictionary, canonical, legacy)
+        };
+
+        let missing = |canonical: &str| {
+            ValidationError::loop_structure(
+                format!(
+                    "Geometry loop '{}' has no '{}' column",
+                    table.category, canonical
+                ),
+                loop_.span,
+            )
+        };
+        let mut errors = Vec::new();
+        let atoms = table.atoms.map(|canonical| {
+            let col = column((canonical, &[]));
+            if col.is_none() {
+                errors.push(missing(canonical));
+            }
+            col
+        });
+        let measurand = column(table.measurand);
+        if measurand.is_none() {
+            errors.push(missing(table.measurand.0));
+        }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        let (atoms, measurand) = (atoms.map(Option::unwrap), measurand.unwrap());
+        let su = column(table.su);
+        let site_symmetry = table
+            .site_symmetry
+            .map(|canonical| column((canonical, &[])));
+        let publ_flag = column((table.publ_flag, &[]));
+
+        let mut rows = Vec::new();
+        for row in 0..loop_.len() {
+            let cell = |col: usize| loop_.get(row, col).filter(|value| is_given(value));
+            let labels = atoms.map(|col| cell(col).map(text_of));
+            let Some(value) = cell(measurand) else {
+                continue;
+            };
+            if labels.iter().any(Option::is_none) {
+                continue;
+            }
+            let Some(mut measured) = Measurand::from_value(value) else {
+                errors.push(ValidationError::type_error(
+                    &loop_.tags[measurand],
+                    "Real",
+                    "non-numeric value",
+                    value.span,
+                ));
+                continue;
+            };
+            if measured.uncertainty.is_none() {
+                measured.uncertainty = su.and_then(cell).and_then(CifValue::as_numeric);
+            }
+
+            rows.push(Row {
+                atoms: labels.map(Option::unwrap),
+                measurand: measured,
+                site_symmetry: site_symmetry.map(|col| col.and_then(cell).map(text_of)),
+                publ_flag: publ_flag.and_then(cell).map(text_of),
+            });
+        }
+
+        if errors.is_empty() {
+            Ok(rows)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// The loop holding a table, found by its first label or its measurand
+    fn find_loop<const N: usize>(&self, table: &Table<N>) -> Option<&'a CifLoop> {
+        let (canonical, legacy) = table.measurand;
+        find_looped(self.block, self.dictionary, table.atoms[0], &[])
+            .or_else(|| find_looped(self.block, self.dictionary, canonical, legacy))
+            .map(|(loop_, _)| loop_)
+    }
+}
+
+/// Canonical names, with extra legacy spellings, of one geometry loop
+struct Table<const N: usize> {
+    category: &'static str,
+    atoms: [&'static str; N],
+    site_symmetry: [&'static str; N],
+    measurand: (&'static str, &'static [&'static str]),
+    su: (&'static str, &'static [&'static str]),
+    publ_flag: &'static str,
+}
+
+const BOND: Table<2> = Table {
+    category: "geom_bond",
+    atoms: [
+        "_geom_bond.atom_site_label_1",
+        "_geom_bond.atom_site_label_2",
+    ],
+    site_symmetry: ["_geom_bond.site_symmetry_1", "_geom_bond.site_symmetry_2"],
+    measurand: ("_geom_bond.distance", &[]),
+    su: ("_geom_bond.distance_su", &[]),
+    publ_flag: "_geom_bond.publ_flag",
+};
+
+// In CIF 1.1 the angle values themselves are `_geom_angle` and `_geom_torsion`
+const ANGLE: Table<3> = Table {
+    category: "geom_angle",
+    atoms: [
+        "_geom_angle.atom_site_label_1",
+        "_geom_angle.atom_site_label_2",
+        "_geom_angle.atom_site_label_3",
+    ],
+    site_symmetry: [
+        "_geom_angle.site_symmetry_1",
+        "_geom_angle.site_symmetry_2",
+        "_geom_angle.site_symmetry_3",
+    ],
+    measurand: ("_geom_angle.value", &["_geom_angle"]),
+    su: ("_geom_angle.value_su", &["_geom_angle_su"]),
+    publ_flag: "_geom_angle.publ_flag",
+};
+
+const TORSION: Table<4> = Table {
+    category: "geom_torsion",
+    atoms: [
+        "_geom_torsion.atom_site_label_1",
+        "_geom_torsion.atom_site_label_2",
+        "_geom_torsion.atom_site_label_3",
+        "_geom_torsion.atom_site_label_4",
+    ],
+    site_symmetry: [
+        "_geom_torsion.site_symmetry_1",
+        "_geom_torsion.site_symmetry_2",
+        "_geom_torsion.site_symmetry_3",
+        "_geom_torsion.site_symmetry_4",
+    ],
+    measurand: (
+        "_geom_torsion.angle",
+        &["_geom_torsion", "_geom_torsion.value"],
+    ),
+    su: ("_geom_torsion.angle_su", &["_geom_torsion_su"]),
+    publ_flag: "_geom_torsion.publ_flag",
+};
+
+/// One row of a geometry loop, before it becomes a record
+struct Row<const N: usize> {
+    atoms: [String; N],
+    measurand: Measurand,
+    site_symmetry: [Option<String>; N],
+    publ_flag: Option<String>,
+}
+
+/// Whether a value is neither unknown nor inapplicable
+fn is_given(value: &CifValue) -> bool {
+    !value.is_unknown() && !value.is_not_applicable()
+}
+
+/// A label, symmetry code or flag as written; `1` is as good a label as `C1`
+fn text_of(value: &CifValue) -> String {
+    match &value.kind {
+        CifValueKind::Text(text) => text.clone(),
+        _ => value.to_string(),
+    }
+}
@@ -10,6 +10,9 @@
 //! - Constraint checking (enumerations, ranges, mandatory items)
 //! - Span preservation for IDE integration
 //! - ValidatedCIF type for definition lookup at source positions
+//! - Symmetry operators and unit cells read under either tag spelling
+//! - Baselines that suppress known findings so only regressions are reported
+//! - Missing values computed with the dictionary's dREL methods
 //!
 //! ## Usage
 //!
@@ -43,8 +46,16 @@
 //! - **Performance**: Skip validation for performance-critical use cases
 //! - **Binary size**: Keep parser lightweight for WASM/Python
 
+pub mod baseline;
+pub mod derive;
 pub mod dictionary;
 pub mod error;
+pub mod geometry;
+pub mod prelude;
+pub mod rename;
+pub mod report;
+mod resolve;
+pub mod symmetry;
 pub mod validated;
 mod validator;
 
@@ -55,18 +66,29 @@ pub mod python;
 pub mod wasm;
 
 // Re-exports
+pub use baseline::{Baseline, BaselineEntry, Fingerprint, BASELINE_VERSION};
+pub use derive::{
+    DerivationFailure, DerivationInput, DerivationResult, DerivedCif, DerivedValue, FailureReason,
+};
 pub use dictionary::{
-    Category, CategoryClass, ContainerType, ContentType, DataItem, Dictionary, DictionaryMetadata,
-    Purpose, RangeConstraint, Source, TypeInfo, ValueConstraints,
+    Category, CategoryClass, ContainerType, ContentType, ContentTypes, DataItem, DdlFlavor,
+    Dictionary, DictionaryMetadata, Dimension, MergeConflict, MethodPurpose, MethodSource, Purpose,
+    RangeConstraint, ResolvedMethod, Source, TypeInfo, Unit, Units, ValueConstraints,
 };
 pub use error::{
-    DictionaryError, ErrorCategory, ValidationError, ValidationResult, ValidationWarning,
-    WarningCategory,
+    BaselineError, DictionaryError, ErrorCategory, Finding, ValidationError, ValidationResult,
+    ValidationWarning, WarningCategory,
 };
+pub use geometry::{AngleRecord, BondRecord, GeometryTables, TorsionRecord};
+pub use rename::{RenameReport, RenameReportEntry, RenameStatus};
+pub use symmetry::{SymOp, SymOpParseError, UnitCell};
 pub use validated::{
     FromCifValue, Measurand, TypedValue, ValidatedBlock, ValidatedCif, ValidatedLoop, ValidatedRow,
 };
-pub use validator::{ValidationEngine, ValidationMode};
+pub use validator::{
+    Occurrence, OccurrenceMap, OccurrenceOptions, OccurrenceSource, ValidationEngine,
+    ValidationMode,
+};
 
 use cif_parser::CifDocument;
 use std::sync::Arc;
@@ -85,8 +107,15 @@ use std::sync::Arc;
 /// ```
 #[derive(Debug, Default)]
 pub struct Validator {
-    dictionaries: Vec<Arc<Dictionary>>,
+    /// Every dictionary added so far, merged in order
+    dictionary: Option<Arc<Dictionary>>,
+    /// Items later dictionaries redefined while merging
+    conflicts: Vec<MergeConflict>,
     mode: ValidationMode,
+    allow_structure_in_text: bool,
+    unknown_mandatory_as_warning: bool,
+    occurrence_options: OccurrenceOptions,
+    baseline: Option<Baseline>,
 }
 
 impl Validator {
@@ -96,20 +125,18 @@ impl Validator {
     }
 
     /// Load a dictionary from a file path.
+    ///
+    /// The dictionary's definition language is detected first (see
+    /// [`dictionary::detect_ddl`]) and the matching loader is used. Only DDLm
+    /// is currently supported; other flavors fail with
+    /// [`DictionaryError::WrongDdlFlavor`] naming the detected flavor.
     pub fn with_dictionary_file(
         mut self,
         path: &str,
     ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
         let doc = CifDocument::from_file(path)?;
-        let dict = dictionary::load_dictionary(&doc).map_err(|errors| {
-            let msg = errors
-                .iter()
-                .map(|e| e.to_string())
-                .collect::<Vec<_>>()
-                .join("\n");
-            std::io::Error::new(std::io::ErrorKind::InvalidData, msg)
-        })?;
-        self.dictionaries.push(Arc::new(dict));
+        let dict = load_detected_dictionary(&doc)?;
+        self.add_dictionary(dict);
         Ok(self)
     }
 
@@ -119,30 +146,79 @@ impl Validator {
         content: &str,
     ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
         let doc = CifDocument::parse(content)?;
-        let dict = dictionary::load_dictionary(&doc).map_err(|errors| {
-            let msg = errors
-                .iter()
-                .map(|e| e.to_string())
-                .collect::<Vec<_>>()
-                .join("\n");
-            std::io::Error::new(std::io::ErrorKind::InvalidData, msg)
-        })?;
-        self.dictionaries.push(Arc::new(dict));
+        let dict = load_detected_dictionary(&doc)?;
+        self.add_dictionary(dict);
         Ok(self)
     }
 
     /// Add a pre-loaded dictionary.
     pub fn with_dictionary(mut self, dict: Dictionary) -> Self {
-        self.dictionaries.push(Arc::new(dict));
+        self.add_dictionary(dict);
         self
     }
 
+    /// Merge a dictionary into those already added
+    ///
+    /// Merging as dictionaries arrive keeps one copy of each definition,
+    /// rather than the separate dictionaries and their combination.
+    fn add_dictionary(&mut self, dict: Dictionary) {
+        match &mut self.dictionary {
+            None => self.dictionary = Some(Arc::new(dict)),
+            Some(combined) => {
+                let conflicts = Arc::make_mut(combined).merge(dict);
+                self.conflicts.extend(conflicts);
+            }
+        }
+    }
+
     /// Set the validation mode.
     pub fn with_mode(mut self, mode: ValidationMode) -> Self {
         self.mode = mode;
         self
     }
 
+    /// Skip the pedantic warning for text fields that contain CIF structure.
+    ///
+    /// In [`ValidationMode::Pedantic`] a text value with lines such as
+    /// `_cell_length_a 10.5` or `loop_` is reported, since that is the usual
+    /// sign of a missing closing `;`. Files that quote CIF syntax on purpose
+    /// can turn the check off here.
+    pub fn allow_structure_in_text(mut self, allow: bool) -> Self {
+        self.allow_structure_in_text = allow;
+        self
+    }
+
+    /// Report mandatory and key items given only as `?` as warnings.
+    ///
+    /// Such an item passes the presence check but carries no information.
+    /// [`ValidationMode::Strict`] reports it as an error by default; the
+    /// other modes always report a warning.
+    pub fn unknown_mandatory_as_warning(mut self, as_warning: bool) -> Self {
+        self.unknown_mandatory_as_warning = as_warning;
+        self
+    }
+
+    /// Choose which structures count towards an item's occurrences.
+    ///
+    /// A Set-category item given more than one value in a block is an
+    /// error, and an item in two loops or under two of its names is a
+    /// warning. By default only the block's own items and loops count; see
+    /// [`OccurrenceOptions::include_frames`] to count save frames too.
+    pub fn with_occurrence_options(mut self, options: OccurrenceOptions) -> Self {
+        self.occurrence_options = options;
+        self
+    }
+
+    /// Suppress findings recorded in a baseline.
+    ///
+    /// Findings the baseline records are moved from `errors` and `warnings`
+    /// to `suppressed` on the result, so only new findings count against
+    /// `is_valid`. See [`Baseline::apply`].
+    pub fn with_baseline(mut self, baseline: Baseline) -> Self {
+        self.baseline = Some(baseline);
+        self
+    }
+
     /// Validate a CIF document.
     ///
     /// Returns a `ValidationResult` containing any errors and warnings.
@@ -150,39 +226,49 @@ impl Validator {
         &self,
         doc: &CifDocument,
     ) -> Result<ValidationResult, Box<dyn std::error::Error + Send + Sync>> {
-        let combined = self.combine_dictionaries()?;
-        let engine = ValidationEngine::new(&combined, self.mode);
-        Ok(engine.validate(doc))
+        let combined = self.combined()?;
+        let engine = ValidationEngine::new(combined, self.mode)
+            .allow_structure_in_text(self.allow_structure_in_text)
+            .unknown_mandatory_as_warning(self.unknown_mandatory_as_warning)
+            .with_occurrence_options(self.occurrence_options);
+        let mut result = engine.validate(doc);
+        if let Some(baseline) = &self.baseline {
+            baseline.apply(&mut result);
+        }
+        Ok(result)
     }
 
     /// Validate and return a ValidatedCif with typed access.
     ///
-    /// This allows looking up dictionary definitions at any source position.
+    /// The findings are those [`validate`](Self::validate) returns, kept in
+    /// [`ValidatedCif::result`]. This allows looking up dictionary
+    /// definitions and errors at any source position. For typed access
+    /// without validating, use [`ValidatedCif::new`].
     pub fn validate_typed(
         &self,
         doc: CifDocument,
     ) -> Result<ValidatedCif, Box<dyn std::error::Error + Send + Sync>> {
-        let combined = Arc::new(self.combine_dictionaries()?);
-        Ok(ValidatedCif::new(doc, combined))
+        let result = self.validate(&doc)?;
+        let combined = self.combined()?;
+        Ok(ValidatedCif::with_result(doc, Arc::clone(combined), result))
     }
 
     /// Get the combined dictionary (for advanced use cases).
+    ///
+    /// Also returns the items that later dictionaries redefined differently;
+    /// see [`Dictionary::merge`]. The copy shares its descriptions and
+    /// methods with the validator's.
     pub fn combined_dictionary(
         &self,
-    ) -> Result<Dictionary, Box<dyn std::error::Error + Send + Sync>> {
-        self.combine_dictionaries()
+    ) -> Result<(Dictionary, Vec<MergeConflict>), Box<dyn std::error::Error + Send + Sync>> {
+        let combined = self.combined()?;
+        Ok(((**combined).clone(), self.conflicts.clone()))
     }
 
-    fn combine_dictionaries(&self) -> Result<Dictionary, Box<dyn std::error::Error + Send + Sync>> {
-        if self.dictionaries.is_empty() {
-            return Err("No dictionaries loaded".into());
-        }
-
-        let mut combined = (*self.dictionaries[0]).clone();
-        for dict in &self.dictionaries[1..] {
-            combined.merge((**dict).clone());
-        }
-        Ok(combined)
+    fn combined(&self) -> Result<&Arc<Dictionary>, Box<dyn std::error::Error + Send + Sync>> {
+        self.dictionary
+            .as_ref()
+            .ok_or_else(|| "No dictionaries loaded".into())
     }
 }
 
@@ -211,15 +297,30 @@ pub fn load_dictionary_file(
     path: &str,
 ) -> Result<Dictionary, Box<dyn std::error::Error + Send + Sync>> {
     let doc = CifDocument::from_file(path)?;
-    dictionary::load_dictionary(&doc).map_err(|errors| {
-        let msg = errors
-            .iter()
-            .map(|e| e.to_string())
-            .collect::<Vec<_>>()
-            .join("\n");
-        Box::new(std::io::Error::new(std::io::ErrorKind::InvalidData, msg))
-            as Box<dyn std::error::Error + Send + Sync>
-    })
+    load_detected_dictionary(&doc)
+}
+
+/// Load a dictionary document with the loader for its detected DDL flavor.
+fn load_detected_dictionary(
+    doc: &CifDocument,
+) -> Result<Dictionary, Box<dyn std::error::Error + Send + Sync>> {
+    match dictionary::detect_ddl(doc) {
+        DdlFlavor::Ddlm | DdlFlavor::Unknown => {
+            dictionary::load_dictionary(doc).map_err(|errors| {
+                let msg = errors
+                    .iter()
+                    .map(|e| e.to_string())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                Box::new(std::io::Error::new(std::io::ErrorKind::InvalidData, msg))
+                    as Box<dyn std::error::Error + Send + Sync>
+            })
+        }
+        detected => Err(Box::new(DictionaryError::WrongDdlFlavor {
+            detected,
+            expected: DdlFlavor::Ddlm,
+        })),
+    }
 }
 
 #[cfg(test)]
@@ -246,8 +347,7 @@ mod tests {
     #[test]
     fn test_full_validation_flow() {
         // Create a simple dictionary
-        let dict_content = r#"
-#\#CIF_2.0
+        let dict_content = r#"#\#CIF_2.0
 data_TEST_DICT
     _dictionary.title             TEST_DICT
     _dictionary.version           1.0.0
@@ -296,8 +396,7 @@ _cell.length_a -5.0
 
     #[test]
     fn test_validated_cif_definition_lookup() {
-        let dict_content = r#"
-#\#CIF_2.0
+        let dict_content = r#"#\#CIF_2.0
 data_TEST_DICT
 
 save_cell.length_a
@@ -326,9 +425,32 @@ _cell.length_a 10.5
 
         assert!(value.is_numeric());
         assert!(def.is_some());
-        assert_eq!(
-            def.unwrap().description,
-            Some("Unit cell length a".to_string())
-        );
+        assert_eq!(def.unwrap().description(), Some("Unit cell length a"));
+    }
+
+    #[test]
+    fn test_with_dictionary_str_reports_detected_flavor() {
+        let ddl2 = r#"
+data_minimal_ddl2.dic
+    _datablock.id                 minimal_ddl2.dic
+
+save__cell.length_a
+    _item.name                    '_cell.length_a'
+    _item.category_id             cell
+save_
+"#;
+
+        let err = Validator::new().with_dictionary_str(ddl2).unwrap_err();
+        let err = err
+            .downcast_ref::<DictionaryError>()
+            .expect("Expected a DictionaryError");
+        assert!(matches!(
+            err,
+            DictionaryError::WrongDdlFlavor {
+                detected: DdlFlavor::Ddl2,
+                expected: DdlFlavor::Ddlm,
+            }
+        ));
+        assert!(err.to_string().contains("DDL2"));
     }
 }
@@ -0,0 +1,42 @@
+//! The stable, intended-for-users surface of the validator.
+//!
+//! ```
+//! use cif_validator::prelude::*;
+//!
+//! let dictionary = r#"#\#CIF_2.0
+//! data_TEST_DICT
+//!     _dictionary.title    TEST_DICT
+//! save_cell.length_a
+//!     _definition.id       '_cell.length_a'
+//!     _type.contents       Real
+//! save_
+//! "#;
+//! let validator = Validator::new()
+//!     .with_dictionary_str(dictionary)?
+//!     .with_mode(ValidationMode::Strict);
+//! let doc = CifDocument::parse("data_test\n_cell.length_a 10.5\n").unwrap();
+//! let result: ValidationResult = validator.validate(&doc)?;
+//! assert!(result.errors.iter().all(|e| e.category != ErrorCategory::TypeError));
+//! # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+//! ```
+//!
+//! Everything here follows semver. The parser's prelude is re-exported too,
+//! so one glob import covers parsing and validation.
+//!
+//! # Stability guarantees
+//!
+//! [`ErrorCategory`], [`WarningCategory`] and [`ContentType`] are
+//! `#[non_exhaustive]`, so matches need a wildcard arm; the compile-fail
+//! cases in `tests/ui` check each of them.
+
+pub use cif_parser::prelude::*;
+
+pub use crate::dictionary::{ContentType, DdlFlavor, Dictionary};
+pub use crate::error::{
+    DictionaryError, ErrorCategory, ValidationError, ValidationResult, ValidationWarning,
+    WarningCategory,
+};
+pub use crate::geometry::{AngleRecord, BondRecord, TorsionRecord};
+pub use crate::symmetry::{SymOp, UnitCell};
+pub use crate::validated::{FromCifValue, TypedValue, ValidatedBlock, ValidatedCif};
+pub use crate::{load_dictionary_file, validate, ValidationMode, Validator};
@@ -8,10 +8,10 @@ use pyo3::prelude::*;
 use crate::{ErrorCategory, ValidationMode, ValidationWarning, Validator, WarningCategory};
 use cif_parser::{CifDocument, Span};
 
-/// Python wrapper for source location (Span)
+/// Source location of a value or error in the CIF file
 ///
-/// Tracks where a value or error appears in the source CIF file.
-/// Useful for LSP/IDE features, error reporting, and highlighting.
+/// Line and column numbers are 1-indexed. Useful for LSP/IDE features,
+/// error reporting, and highlighting.
 #[pyclass(name = "Span")]
 #[derive(Clone, Copy)]
 pub struct PySpan {
@@ -45,6 +45,11 @@ impl PySpan {
     }
 
     /// Check if a line and column position is within this span
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line number (1-indexed)
+    /// * `col` - Column number (1-indexed)
     fn contains(&self, line: usize, col: usize) -> bool {
         self.inner.contains(line, col)
     }
@@ -69,7 +74,7 @@ impl From<Span> for PySpan {
     }
 }
 
-/// Python enum for error categories
+/// Kind of validation error
 #[pyclass(name = "ErrorCategory", eq, eq_int)]
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum PyErrorCategory {
@@ -89,10 +94,15 @@ pub enum PyErrorCategory {
     LinkError = 6,
     /// Dictionary loading/parsing error
     DictionaryError = 7,
+    /// Item given more values than its category allows
+    DuplicateItem = 8,
+    /// Mandatory or key item present with only unknown (`?`) values
+    UnknownMandatoryValue = 9,
 }
 
 #[pymethods]
 impl PyErrorCategory {
+    /// Variant name, such as "TypeError"
     #[getter]
     fn name(&self) -> &'static str {
         match self {
@@ -104,6 +114,8 @@ impl PyErrorCategory {
             PyErrorCategory::LoopStructure => "LoopStructure",
             PyErrorCategory::LinkError => "LinkError",
             PyErrorCategory::DictionaryError => "DictionaryError",
+            PyErrorCategory::DuplicateItem => "DuplicateItem",
+            PyErrorCategory::UnknownMandatoryValue => "UnknownMandatoryValue",
         }
     }
 
@@ -117,6 +129,8 @@ impl PyErrorCategory {
             PyErrorCategory::LoopStructure => "loop structure error",
             PyErrorCategory::LinkError => "link error",
             PyErrorCategory::DictionaryError => "dictionary error",
+            PyErrorCategory::DuplicateItem => "duplicate item",
+            PyErrorCategory::UnknownMandatoryValue => "unknown mandatory value",
         }
     }
 
@@ -132,6 +146,8 @@ impl PyErrorCategory {
                 PyErrorCategory::LoopStructure => "LoopStructure",
                 PyErrorCategory::LinkError => "LinkError",
                 PyErrorCategory::DictionaryError => "DictionaryError",
+                PyErrorCategory::DuplicateItem => "DuplicateItem",
+                PyErrorCategory::UnknownMandatoryValue => "UnknownMandatoryValue",
             }
         )
     }
@@ -148,11 +164,13 @@ impl From<ErrorCategory> for PyErrorCategory {
             ErrorCategory::LoopStructure => PyErrorCategory::LoopStructure,
             ErrorCategory::LinkError => PyErrorCategory::LinkError,
             ErrorCategory::DictionaryError => PyErrorCategory::DictionaryError,
+            ErrorCategory::DuplicateItem => PyErrorCategory::DuplicateItem,
+            ErrorCategory::UnknownMandatoryValue => PyErrorCategory::UnknownMandatoryValue,
         }
     }
 }
 
-/// Python enum for warning categories
+/// Kind of validation warning
 #[pyclass(name = "WarningCategory", eq, eq_int)]
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum PyWarningCategory {
@@ -164,10 +182,26 @@ pub enum PyWarningCategory {
     Style = 2,
     /// Unknown item in lenient mode
     UnknownItem = 3,
+    /// Same item appears in more than one loop
+    DuplicateItem = 4,
+    /// Text value contains lines that look like CIF structure
+    SuspiciousTextField = 5,
+    /// Mandatory or key item present with only unknown (`?`) values
+    UnknownMandatoryValue = 6,
+    /// Mandatory or key item given as not applicable (`.`), in pedantic mode
+    NotApplicableMandatory = 7,
+    /// Set-category items written as a one-row loop
+    LoopedSetCategory = 8,
+    /// Pointer to a data block that is not in the document
+    DanglingBlockLink = 9,
+    /// Item whose content type is implied and so was not checked, in
+    /// pedantic mode
+    ImpliedType = 10,
 }
 
 #[pymethods]
 impl PyWarningCategory {
+    /// Variant name, such as "DeprecatedItem"
     #[getter]
     fn name(&self) -> &'static str {
         match self {
@@ -175,6 +209,13 @@ impl PyWarningCategory {
             PyWarningCategory::DeprecatedItem => "DeprecatedItem",
             PyWarningCategory::Style => "Style",
             PyWarningCategory::UnknownItem => "UnknownItem",
+            PyWarningCategory::DuplicateItem => "DuplicateItem",
+            PyWarningCategory::SuspiciousTextField => "SuspiciousTextField",
+            PyWarningCategory::UnknownMandatoryValue => "UnknownMandatoryValue",
+            PyWarningCategory::NotApplicableMandatory => "NotApplicableMandatory",
+            PyWarningCategory::LoopedSetCategory => "LoopedSetCategory",
+            PyWarningCategory::DanglingBlockLink => "DanglingBlockLink",
+            PyWarningCategory::ImpliedType => "ImpliedType",
         }
     }
 
@@ -184,6 +225,13 @@ impl PyWarningCategory {
             PyWarningCategory::DeprecatedItem => "deprecated item",
             PyWarningCategory::Style => "style",
             PyWarningCategory::UnknownItem => "unknown item",
+            PyWarningCategory::DuplicateItem => "duplicate item",
+            PyWarningCategory::SuspiciousTextField => "suspicious text field",
+            PyWarningCategory::UnknownMandatoryValue => "unknown mandatory value",
+            PyWarningCategory::NotApplicableMandatory => "not applicable mandatory value",
+            PyWarningCategory::LoopedSetCategory => "looped set category",
+            PyWarningCategory::DanglingBlockLink => "dangling block link",
+            PyWarningCategory::ImpliedType => "implied type",
         }
     }
 
@@ -195,6 +243,13 @@ impl PyWarningCategory {
                 PyWarningCategory::DeprecatedItem => "DeprecatedItem",
                 PyWarningCategory::Style => "Style",
                 PyWarningCategory::UnknownItem => "UnknownItem",
+                PyWarningCategory::DuplicateItem => "DuplicateItem",
+                PyWarningCategory::SuspiciousTextField => "SuspiciousTextField",
+                PyWarningCategory::UnknownMandatoryValue => "UnknownMandatoryValue",
+                PyWarningCategory::NotApplicableMandatory => "NotApplicableMandatory",
+                PyWarningCategory::LoopedSetCategory => "LoopedSetCategory",
+                PyWarningCategory::DanglingBlockLink => "DanglingBlockLink",
+                PyWarningCategory::ImpliedType => "ImpliedType",
             }
         )
     }
@@ -207,11 +262,18 @@ impl From<WarningCategory> for PyWarningCategory {
             WarningCategory::DeprecatedItem => PyWarningCategory::DeprecatedItem,
             WarningCategory::Style => PyWarningCategory::Style,
             WarningCategory::UnknownItem => PyWarningCategory::UnknownItem,
+            WarningCategory::DuplicateItem => PyWarningCategory::DuplicateItem,
+            WarningCategory::SuspiciousTextField => PyWarningCategory::SuspiciousTextField,
+            WarningCategory::UnknownMandatoryValue => PyWarningCategory::UnknownMandatoryValue,
+            WarningCategory::NotApplicableMandatory => PyWarningCategory::NotApplicableMandatory,
+            WarningCategory::LoopedSetCategory => PyWarningCategory::LoopedSetCategory,
+            WarningCategory::DanglingBlockLink => PyWarningCategory::DanglingBlockLink,
+            WarningCategory::ImpliedType => PyWarningCategory::ImpliedType,
         }
     }
 }
 
-/// Python enum for validation modes
+/// How strictly to validate
 #[pyclass(name = "ValidationMode", eq, eq_int)]
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum PyValidationMode {
@@ -225,6 +287,7 @@ pub enum PyValidationMode {
 
 #[pymethods]
 impl PyValidationMode {
+    /// Variant name, such as "Strict"
     #[getter]
     fn name(&self) -> &'static str {
         match self {
@@ -452,6 +515,12 @@ impl PyValidator {
     }
 
     /// Add a dictionary from a string
+    ///
+    /// # Arguments
+    ///
+    /// * `dictionary_content` - DDLm dictionary content as a string
+    ///
+    /// Raises ValueError if the dictionary cannot be parsed.
     fn add_dictionary(&mut self, dictionary_content: &str) -> PyResult<()> {
         // Validate that the dictionary can be parsed
         CifDocument::parse(dictionary_content).map_err(|e| {
@@ -462,6 +531,13 @@ impl PyValidator {
     }
 
     /// Add a dictionary from a file path
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to a DDLm dictionary file
+    ///
+    /// Raises IOError if the file cannot be read and ValueError if it cannot
+    /// be parsed.
     fn add_dictionary_file(&mut self, path: &str) -> PyResult<()> {
         let content = std::fs::read_to_string(path).map_err(|e| {
             pyo3::exceptions::PyIOError::new_err(format!("Failed to read dictionary file: {}", e))
@@ -470,6 +546,10 @@ impl PyValidator {
     }
 
     /// Set the validation mode
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - How strictly to validate
     fn set_mode(&mut self, mode: PyValidationMode) {
         self.mode = mode.into();
     }
@@ -485,6 +565,15 @@ impl PyValidator {
     }
 
     /// Validate a CIF document string
+    ///
+    /// # Arguments
+    ///
+    /// * `cif_content` - CIF file content as a string
+    ///
+    /// # Returns
+    ///
+    /// A ValidationResult object with errors and warnings. Raises ValueError
+    /// if no dictionary has been added or the content cannot be parsed.
     fn validate(&self, cif_content: &str) -> PyResult<PyValidationResult> {
         // Parse the CIF content
         let doc = CifDocument::parse(cif_content).map_err(|e| {
@@ -516,6 +605,16 @@ impl PyValidator {
     }
 
     /// Validate a CIF file
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the CIF file
+    ///
+    /// # Returns
+    ///
+    /// A ValidationResult object with errors and warnings. Raises IOError if
+    /// the file cannot be read and ValueError if no dictionary has been added
+    /// or the file cannot be parsed.
     fn validate_file(&self, path: &str) -> PyResult<PyValidationResult> {
         let content = std::fs::read_to_string(path).map_err(|e| {
             pyo3::exceptions::PyIOError::new_err(format!("Failed to read CIF file: {}", e))
@@ -536,7 +635,8 @@ impl PyValidator {
 ///
 /// # Returns
 ///
-/// A ValidationResult object with errors and warnings.
+/// A ValidationResult object with errors and warnings. Raises ValueError if
+/// the CIF content or the dictionary cannot be parsed.
 #[pyfunction]
 fn validate(cif_content: &str, dictionary_content: &str) -> PyResult<PyValidationResult> {
     // Parse the CIF content
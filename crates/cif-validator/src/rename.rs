@@ -0,0 +1,161 @@
+//! Migrating legacy tags to their canonical dictionary names.
+//!
+//! The dictionary's alias table says which canonical name each legacy tag
+//! stands for. [`ValidatedCif::canonical_renames`] turns that into a rename
+//! map for [`CifDocument::rename_tags`](cif_parser::CifDocument::rename_tags),
+//! and [`ValidatedCif::rename_report`] shows ahead of time what the rename
+//! would do to every tag:
+//!
+//! ```rust,ignore
+//! let validated = validator.validate_typed(doc)?;
+//! let report = validated.rename_report();
+//! for entry in report.conflicts() {
+//!     println!("{}: {} cannot become {:?}", entry.location.block, entry.tag, entry.canonical);
+//! }
+//!
+//! let mut doc = validated.document().clone();
+//! doc.rename_tags(&validated.canonical_renames());
+//! ```
+
+use std::collections::HashMap;
+
+use cif_parser::{CifDocument, RenameConflict, TagLocation};
+use serde::{Deserialize, Serialize};
+
+use crate::validated::ValidatedCif;
+
+/// What renaming to the canonical name would do to a tag
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "status")]
+pub enum RenameStatus {
+    /// The tag already uses the canonical name
+    Canonical,
+    /// The dictionary does not define the tag
+    Unknown,
+    /// The tag can be renamed without a collision
+    Safe,
+    /// Renaming would collide with another tag in the same container
+    Conflict {
+        /// What the rename would collide with
+        conflict: RenameConflict,
+    },
+}
+
+/// One tag occurrence in a [`RenameReport`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RenameReportEntry {
+    /// Container of the tag
+    pub location: TagLocation,
+    /// Tag as written
+    pub tag: String,
+    /// Canonical name from the dictionary, if the tag is defined
+    pub canonical: Option<String>,
+    /// What renaming would do
+    #[serde(flatten)]
+    pub status: RenameStatus,
+}
+
+/// Every tag in a document with its canonical name
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RenameReport {
+    /// Tags in document order: per block, its items sorted by name, then its
+    /// loop columns, then the same for each save frame
+    pub entries: Vec<RenameReportEntry>,
+}
+
+impl RenameReport {
+    /// Entries whose tag would be renamed
+    pub fn safe(&self) -> impl Iterator<Item = &RenameReportEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.status == RenameStatus::Safe)
+    }
+
+    /// Entries whose rename would be refused
+    pub fn conflicts(&self) -> impl Iterator<Item = &RenameReportEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| matches!(entry.status, RenameStatus::Conflict { .. }))
+    }
+
+    /// Whether every defined tag already is, or can safely become, canonical
+    pub fn is_clean(&self) -> bool {
+        self.conflicts().next().is_none()
+    }
+}
+
+impl ValidatedCif {
+    /// Map each tag in the document that is an alias to its canonical name.
+    ///
+    /// Tags that differ from the canonical name only in case are left out,
+    /// since CIF names are case-insensitive anyway.
+    pub fn canonical_renames(&self) -> HashMap<String, String> {
+        let mut renames = HashMap::new();
+        for (_, tag) in tags(self.document()) {
+            if let Some(item) = self.dictionary().get_item(tag) {
+                if !item.name.eq_ignore_ascii_case(tag) {
+                    renames.insert(tag.to_string(), item.name.clone());
+                }
+            }
+        }
+        renames
+    }
+
+    /// Report, for every tag in the document, its canonical name and whether
+    /// renaming it with [`canonical_renames`](Self::canonical_renames) is
+    /// safe.
+    pub fn rename_report(&self) -> RenameReport {
+        let outcome = self.document().check_renames(&self.canonical_renames());
+        let mut refused: HashMap<(TagLocation, String), RenameConflict> = outcome
+            .refused
+            .into_iter()
+            .map(|refused| ((refused.location, refused.from), refused.conflict))
+            .collect();
+
+        let entries = tags(self.document())
+            .map(|(location, tag)| {
+                let canonical = self
+                    .dictionary()
+                    .get_item(tag)
+                    .map(|item| item.name.clone());
+                let status = match &canonical {
+                    None => RenameStatus::Unknown,
+                    Some(name) if name.eq_ignore_ascii_case(tag) => RenameStatus::Canonical,
+                    Some(_) => match refused.remove(&(location.clone(), tag.to_string())) {
+                        Some(conflict) => RenameStatus::Conflict { conflict },
+                        None => RenameStatus::Safe,
+                    },
+                };
+                RenameReportEntry {
+                    location,
+                    tag: tag.to_string(),
+                    canonical,
+                    status,
+                }
+            })
+            .collect();
+        RenameReport { entries }
+    }
+}
+
+/// Every tag in the document with its container, in report order
+fn tags(doc: &CifDocument) -> impl Iterator<Item = (TagLocation, &str)> {
+    doc.blocks.iter().flat_map(|block| {
+        let location = |frame: Option<&str>| TagLocation {
+            block: block.name.to_string(),
+            frame: frame.map(String::from),
+        };
+        let containers = std::iter::once((location(None), &block.items, &block.loops)).chain(
+            block
+                .frames
+                .iter()
+                .map(move |frame| (location(Some(&frame.name)), &frame.items, &frame.loops)),
+        );
+        containers.flat_map(|(location, items, loops)| {
+            let mut names: Vec<&str> = items.keys().map(String::as_str).collect();
+            names.sort_unstable();
+            names.extend(loops.iter().flat_map(|l| l.tags.iter().map(String::as_str)));
+            names.into_iter().map(move |tag| (location.clone(), tag))
+        })
+    })
+}
@@ -0,0 +1,539 @@
+//! Machine-readable reports of validation results.
+//!
+//! [`to_sarif`] writes a [`ValidationResult`] as a SARIF 2.1.0 log, the
+//! format code-scanning services ingest:
+//!
+//! ```rust,ignore
+//! use cif_validator::{report, Validator};
+//!
+//! let result = Validator::new().with_dictionary_file("cif_core.dic")?.validate(&doc)?;
+//! std::fs::write("validation.sarif", report::to_sarif(&result, "structure.cif"))?;
+//! ```
+//!
+//! Each error and warning category is a rule, identified by the same code
+//! as in a [`Fingerprint`](crate::Fingerprint) (`error.RangeError`,
+//! `warning.Style`). Findings suppressed by a baseline are included with an
+//! external suppression, so a scanning service shows them as dismissed.
+
+use cif_parser::Span;
+use serde::Serialize;
+
+use crate::error::{
+    ErrorCategory, Finding, ValidationError, ValidationResult, ValidationWarning, WarningCategory,
+};
+
+/// Version of SARIF written by [`to_sarif`]
+pub const SARIF_VERSION: &str = "2.1.0";
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Write a validation result as a SARIF 2.1.0 log.
+///
+/// `source_uri` names the validated file in every location, as written
+/// (typically a path relative to the repository root). Spans become
+/// regions counted in Unicode code points; findings without a position
+/// (line 0) get a location with no region. An error with
+/// [`replacements`](ValidationError::replacements), such as the allowed
+/// values close to a misspelt state, gets one fix per replacement.
+pub fn to_sarif(result: &ValidationResult, source_uri: &str) -> String {
+    let mut results: Vec<SarifResult> = result
+        .errors
+        .iter()
+        .map(|error| error_result(error, source_uri))
+        .chain(
+            result
+                .warnings
+                .iter()
+                .map(|warning| warning_result(warning, source_uri)),
+        )
+        .collect();
+    for finding in &result.suppressed {
+        let mut suppressed = match finding {
+            Finding::Error(error) => error_result(error, source_uri),
+            Finding::Warning(warning) => warning_result(warning, source_uri),
+        };
+        suppressed.suppressions = vec![Suppression {
+            kind: "external",
+            justification: "Matched by the validation baseline",
+        }];
+        results.push(suppressed);
+    }
+
+    let log = SarifLog {
+        schema: SARIF_SCHEMA,
+        version: SARIF_VERSION,
+        runs: vec![Run {
+            tool: Tool {
+                driver: Driver {
+                    name: env!("CARGO_PKG_NAME"),
+                    information_uri: env!("CARGO_PKG_REPOSITORY"),
+                    rules: RULES.iter().map(ReportingDescriptor::from).collect(),
+                },
+            },
+            column_kind: "unicodeCodePoints",
+            results,
+        }],
+    };
+    serde_json::to_string_pretty(&log).expect("SARIF log serializes") + "\n"
+}
+
+fn error_result(error: &ValidationError, source_uri: &str) -> SarifResult {
+    let rule_index = error_rule(error.category);
+    let mut text = error.message.clone();
+    if !error.suggestions.is_empty() {
+        text.push_str(&format!(" (suggestions: {})", error.suggestions.join(", ")));
+    }
+
+    let fixes = match error.span.start_line {
+        0 => Vec::new(),
+        _ => error
+            .replacements
+            .iter()
+            .map(|value| Fix {
+                description: Message {
+                    text: format!("Replace with '{value}'"),
+                },
+                artifact_changes: vec![ArtifactChange {
+                    artifact_location: ArtifactLocation::new(source_uri),
+                    replacements: vec![Replacement {
+                        deleted_region: Region::from(error.span),
+                        inserted_content: ArtifactContent {
+                            text: value.clone(),
+                        },
+                    }],
+                }],
+            })
+            .collect(),
+    };
+
+    SarifResult {
+        rule_id: RULES[rule_index].id,
+        rule_index,
+        level: RULES[rule_index].level,
+        message: Message { text },
+        locations: vec![location(source_uri, error.span, error.data_name.as_deref())],
+        fixes,
+        suppressions: Vec::new(),
+    }
+}
+
+fn warning_result(warning: &ValidationWarning, source_uri: &str) -> SarifResult {
+    let rule_index = warning_rule(warning.category);
+    SarifResult {
+        rule_id: RULES[rule_index].id,
+        rule_index,
+        level: RULES[rule_index].level,
+        message: Message {
+            text: warning.message.clone(),
+        },
+        locations: vec![location(source_uri, warning.span, None)],
+        fixes: Vec::new(),
+        suppressions: Vec::new(),
+    }
+}
+
+fn location(source_uri: &str, span: Span, data_name: Option<&str>) -> Location {
+    Location {
+        physical_location: PhysicalLocation {
+            artifact_location: ArtifactLocation::new(source_uri),
+            region: (span.start_line > 0).then(|| Region::from(span)),
+        },
+        logical_locations: data_name
+            .map(|name| LogicalLocation {
+                name: name.to_string(),
+                kind: "variable",
+            })
+            .into_iter()
+            .collect(),
+    }
+}
+
+/// A rule in the static table
+struct Rule {
+    id: &'static str,
+    name: &'static str,
+    description: &'static str,
+    level: &'static str,
+}
+
+/// Every error and warning category, errors first, in declaration order
+const RULES: &[Rule] = &[
+    Rule {
+        id: "error.UnknownDataName",
+        name: "UnknownDataName",
+        description: "Data name not defined in the dictionary",
+        level: "error",
+    },
+    Rule {
+        id: "error.TypeError",
+        name: "TypeError",
+        description: "Value does not match the type the dictionary gives the item",
+        level: "error",
+    },
+    Rule {
+        id: "error.RangeError",
+        name: "RangeError",
+        description: "Numeric value outside the range the dictionary allows",
+        level: "error",
+    },
+    Rule {
+        id: "error.EnumerationError",
+        name: "EnumerationError",
+        description: "Value not among the states the dictionary enumerates",
+        level: "error",
+    },
+    Rule {
+        id: "error.MissingMandatory",
+        name: "MissingMandatory",
+        description: "Mandatory item missing from its category",
+        level: "error",
+    },
+    Rule {
+        id: "error.LoopStructure",
+        name: "LoopStructure",
+        description: "Loop whose header, values or categories are inconsistent",
+        level: "error",
+    },
+    Rule {
+        id: "error.LinkError",
+        name: "LinkError",
+        description: "Foreign key that matches no row of the linked category",
+        level: "error",
+    },
+    Rule {
+        id: "error.DictionaryError",
+        name: "DictionaryError",
+        description: "Dictionary that could not be loaded or used",
+        level: "error",
+    },
+    Rule {
+        id: "error.DuplicateItem",
+        name: "DuplicateItem",
+        description: "Item given more values than its category allows",
+        level: "error",
+    },
+    Rule {
+        id: "error.UnknownMandatoryValue",
+        name: "UnknownMandatoryValue",
+        description: "Mandatory or key item present with only unknown (?) values",
+        level: "error",
+    },
+    Rule {
+        id: "warning.MixedCategories",
+        name: "MixedCategories",
+        description: "Loop holding items of more than one category",
+        level: "warning",
+    },
+    Rule {
+        id: "warning.DeprecatedItem",
+        name: "DeprecatedItem",
+        description: "Item the dictionary marks as deprecated",
+        level: "warning",
+    },
+    Rule {
+        id: "warning.Style",
+        name: "Style",
+        description: "Value written in a way that is valid but not recommended",
+        level: "note",
+    },
+    Rule {
+        id: "warning.UnknownItem",
+        name: "UnknownItem",
+        description: "Data name not defined in the dictionary, in lenient mode",
+        level: "warning",
+    },
+    Rule {
+        id: "warning.DuplicateItem",
+        name: "DuplicateItem",
+        description: "Item that appears in more than one loop",
+        level: "warning",
+    },
+    Rule {
+        id: "warning.SuspiciousTextField",
+        name: "SuspiciousTextField",
+        description: "Text value containing lines that look like CIF structure",
+        level: "warning",
+    },
+    Rule {
+        id: "warning.UnknownMandatoryValue",
+        name: "UnknownMandatoryValue",
+        description: "Mandatory or key item present with only unknown (?) values",
+        level: "warning",
+    },
+    Rule {
+        id: "warning.NotApplicableMandatory",
+        name: "NotApplicableMandatory",
+        description: "Mandatory or key item given as not applicable (.)",
+        level: "warning",
+    },
+    Rule {
+        id: "warning.LoopedSetCategory",
+        name: "LoopedSetCategory",
+        description: "Set-category items written as a one-row loop",
+        level: "warning",
+    },
+    Rule {
+        id: "warning.DanglingBlockLink",
+        name: "DanglingBlockLink",
+        description: "Pointer to a data block that is not in the document",
+        level: "warning",
+    },
+    Rule {
+        id: "warning.ImpliedType",
+        name: "ImpliedType",
+        description: "Item whose content type is implied and so was not checked",
+        level: "note",
+    },
+];
+
+/// Index in [`RULES`] of an error category
+fn error_rule(category: ErrorCategory) -> usize {
+    match category {
+        ErrorCategory::UnknownDataName => 0,
+        ErrorCategory::TypeError => 1,
+        ErrorCategory::RangeError => 2,
+        ErrorCategory::EnumerationError => 3,
+        ErrorCategory::MissingMandatory => 4,
+        ErrorCategory::LoopStructure => 5,
+        ErrorCategory::LinkError => 6,
+        ErrorCategory::DictionaryError => 7,
+        ErrorCategory::DuplicateItem => 8,
+        ErrorCategory::UnknownMandatoryValue => 9,
+    }
+}
+
+/// Index in [`RULES`] of a warning category
+fn warning_rule(category: WarningCategory) -> usize {
+    match category {
+        WarningCategory::MixedCategories => 10,
+        WarningCategory::DeprecatedItem => 11,
+        WarningCategory::Style => 12,
+        WarningCategory::UnknownItem => 13,
+        WarningCategory::DuplicateItem => 14,
+        WarningCategory::SuspiciousTextField => 15,
+        WarningCategory::UnknownMandatoryValue => 16,
+        WarningCategory::NotApplicableMandatory => 17,
+        WarningCategory::LoopedSetCategory => 18,
+        WarningCategory::DanglingBlockLink => 19,
+        WarningCategory::ImpliedType => 20,
+    }
+}
+
+// SARIF 2.1.0 objects, with only the properties written here
+
+#[derive(Serialize)]
+struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<Run>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Run {
+    tool: Tool,
+    column_kind: &'static str,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+struct Tool {
+    driver: Driver,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Driver {
+    name: &'static str,
+    information_uri: &'static str,
+    rules: Vec<ReportingDescriptor>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReportingDescriptor {
+    id: &'static str,
+    name: &'static str,
+    short_description: Message,
+    default_configuration: Configuration,
+}
+
+impl From<&Rule> for ReportingDescriptor {
+    fn from(rule: &Rule) -> Self {
+        Self {
+            id: rule.id,
+            name: rule.name,
+            short_description: Message {
+                text: rule.description.to_string(),
+            },
+            default_configuration: Configuration { level: rule.level },
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Configuration {
+    level: &'static str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    rule_id: &'static str,
+    rule_index: usize,
+    level: &'static str,
+    message: Message,
+    locations: Vec<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<Fix>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    suppressions: Vec<Suppression>,
+}
+
+#[derive(Serialize)]
+struct Message {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Location {
+    physical_location: PhysicalLocation,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    logical_locations: Vec<LogicalLocation>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<Region>,
+}
+
+#[derive(Serialize)]
+struct ArtifactLocation {
+    uri: String,
+}
+
+impl ArtifactLocation {
+    fn new(uri: &str) -> Self {
+        Self {
+            uri: uri.to_string(),
+        }
+    }
+}
+
+/// Spans and SARIF regions agree: lines and columns count from 1 and the
+/// end column is one past the last character
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+impl From<Span> for Region {
+    fn from(span: Span) -> Self {
+        Self {
+            start_line: span.start_line,
+            start_column: span.start_col.max(1),
+            end_line: span.end_line.max(span.start_line),
+            end_column: span.end_col.max(1),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct LogicalLocation {
+    name: String,
+    kind: &'static str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Fix {
+    description: Message,
+    artifact_changes: Vec<ArtifactChange>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ArtifactChange {
+    artifact_location: ArtifactLocation,
+    replacements: Vec<Replacement>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Replacement {
+    deleted_region: Region,
+    inserted_content: ArtifactContent,
+}
+
+#[derive(Serialize)]
+struct ArtifactContent {
+    text: String,
+}
+
+#[derive(Serialize)]
+struct Suppression {
+    kind: &'static str,
+    justification: &'static str,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rule_table_matches_categories() {
+        use ErrorCategory as E;
+        use WarningCategory as W;
+
+        let errors = [
+            E::UnknownDataName,
+            E::TypeError,
+            E::RangeError,
+            E::EnumerationError,
+            E::MissingMandatory,
+            E::LoopStructure,
+            E::LinkError,
+            E::DictionaryError,
+            E::DuplicateItem,
+            E::UnknownMandatoryValue,
+        ];
+        let warnings = [
+            W::MixedCategories,
+            W::DeprecatedItem,
+            W::Style,
+            W::UnknownItem,
+            W::DuplicateItem,
+            W::SuspiciousTextField,
+            W::UnknownMandatoryValue,
+            W::NotApplicableMandatory,
+            W::LoopedSetCategory,
+            W::DanglingBlockLink,
+            W::ImpliedType,
+        ];
+        let ids: Vec<String> = errors
+            .iter()
+            .map(|c| RULES[error_rule(*c)].id.to_string())
+            .chain(
+                warnings
+                    .iter()
+                    .map(|c| RULES[warning_rule(*c)].id.to_string()),
+            )
+            .collect();
+        let expected: Vec<String> = errors
+            .iter()
+            .map(|c| format!("error.{c:?}"))
+            .chain(warnings.iter().map(|c| format!("warning.{c:?}")))
+            .collect();
+        assert_eq!(ids, expected);
+        assert_eq!(RULES.len(), expected.len());
+    }
+}
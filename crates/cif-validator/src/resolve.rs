@@ -0,0 +1,105 @@
+//! Lookup of single-valued items wherever a block stores them.
+//!
+//! Legacy files often wrap Set-category items in a one-row loop
+//! (`loop_ _cell_length_a _cell_length_b 10.0 12.0`). Such a loop still
+//! gives each item exactly one value, so the validator and
+//! [`ValidatedBlock`](crate::ValidatedBlock) treat it like the standalone
+//! form: the validator through [`one_row_set_category`], typed access
+//! through [`find_single_value`]. Looped items are found by
+//! [`find_looped`], under the same spellings.
+
+use cif_parser::{CifBlock, CifLoop, CifValue};
+
+use crate::dictionary::{CategoryClass, Dictionary};
+
+/// Check if a tag names the canonical item.
+///
+/// Matches the dictionary's aliases, the CIF 1.1 form with the category
+/// dot replaced by an underscore, and any extra legacy spellings.
+pub(crate) fn is_spelling_of(
+    dictionary: &Dictionary,
+    tag: &str,
+    canonical: &str,
+    legacy: &[&str],
+) -> bool {
+    dictionary.resolve_name(tag) == canonical
+        || tag.eq_ignore_ascii_case(&canonical.replacen('.', "_", 1))
+        || legacy.iter().any(|name| tag.eq_ignore_ascii_case(name))
+}
+
+/// Find the one value of an item, standalone or in a one-row loop.
+///
+/// Standalone items are preferred. Returns the tag as written along with
+/// the value, or `None` if the item is absent or only looped over several
+/// rows.
+pub(crate) fn find_single_value<'a>(
+    block: &'a CifBlock,
+    dictionary: &Dictionary,
+    canonical: &str,
+    legacy: &[&str],
+) -> Option<(&'a str, &'a CifValue)> {
+    let matches = |tag: &str| is_spelling_of(dictionary, tag, canonical, legacy);
+
+    if let Some((tag, value)) = block.items.iter().find(|(tag, _)| matches(tag)) {
+        return Some((tag.as_str(), value));
+    }
+    block
+        .loops
+        .iter()
+        .filter(|loop_| loop_.len() == 1)
+        .find_map(|loop_| {
+            let col = find_column(loop_, dictionary, canonical, legacy)?;
+            Some((loop_.tags[col].as_str(), loop_.get(0, col)?))
+        })
+}
+
+/// The column of a loop holding any spelling of a canonical name
+pub(crate) fn find_column(
+    loop_: &CifLoop,
+    dictionary: &Dictionary,
+    canonical: &str,
+    legacy: &[&str],
+) -> Option<usize> {
+    loop_
+        .tags
+        .iter()
+        .position(|tag| is_spelling_of(dictionary, tag, canonical, legacy))
+}
+
+/// The first loop with a column for a canonical name, and that column
+pub(crate) fn find_looped<'a>(
+    block: &'a CifBlock,
+    dictionary: &Dictionary,
+    canonical: &str,
+    legacy: &[&str],
+) -> Option<(&'a CifLoop, usize)> {
+    block.loops.iter().find_map(|loop_| {
+        let col = find_column(loop_, dictionary, canonical, legacy)?;
+        Some((loop_, col))
+    })
+}
+
+/// The Set category a one-row loop holds, if it holds nothing else.
+///
+/// Tags the dictionary does not know are ignored, but at least one must be
+/// known; a loop spanning several categories is left to the mixed-category
+/// check.
+pub(crate) fn one_row_set_category<'d>(
+    loop_: &CifLoop,
+    dictionary: &'d Dictionary,
+) -> Option<&'d str> {
+    if loop_.len() != 1 {
+        return None;
+    }
+    let mut categories = loop_
+        .tags
+        .iter()
+        .filter_map(|tag| dictionary.get_item(tag))
+        .map(|def| &*def.category);
+    let first = categories.next()?;
+    if categories.any(|category| category != first) {
+        return None;
+    }
+    let category = dictionary.get_category(first)?;
+    (category.class == CategoryClass::Set).then_some(category.name.as_str())
+}
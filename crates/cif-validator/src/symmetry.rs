@@ -0,0 +1,332 @@
+//! Symmetry operators and unit cells read from validated blocks.
+//!
+//! [`SymOp`] parses the Jones-faithful notation used by
+//! `_space_group_symop.operation_xyz` (`-x,1/2+y,1/2-z`) into an integer
+//! rotation matrix and a fractional translation. [`UnitCell`] gathers the six
+//! cell parameters with their standard uncertainties. Both are produced by
+//! [`ValidatedBlock::symmetry_operations`](crate::ValidatedBlock::symmetry_operations)
+//! and [`ValidatedBlock::cell`](crate::ValidatedBlock::cell).
+
+use std::fmt;
+use std::str::FromStr;
+
+use cif_parser::Measurand;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Error from parsing a symmetry operator string
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("Invalid symmetry operator '{operator}': {reason}")]
+pub struct SymOpParseError {
+    /// The operator string as written
+    pub operator: String,
+    /// What is wrong with it
+    pub reason: String,
+}
+
+/// A crystallographic symmetry operation `x' = R·x + t` in fractional coordinates.
+///
+/// # Example
+/// ```
+/// use cif_validator::SymOp;
+///
+/// let op: SymOp = "-x,1/2+y,1/2-z".parse().unwrap();
+/// assert_eq!(op.rotation, [[-1, 0, 0], [0, 1, 0], [0, 0, -1]]);
+/// assert_eq!(op.translation, [0.0, 0.5, 0.5]);
+/// assert_eq!(op.to_string(), "-x,y+1/2,-z+1/2");
+/// assert_eq!(op.apply([0.1, 0.2, 0.3]), [-0.1, 0.7, 0.2]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SymOp {
+    /// Rotation part, one row per output coordinate
+    pub rotation: [[i32; 3]; 3],
+    /// Translation part in fractions of the cell edges
+    pub translation: [f64; 3],
+}
+
+impl SymOp {
+    /// The identity operation `x,y,z`
+    pub fn identity() -> Self {
+        SymOp {
+            rotation: [[1, 0, 0], [0, 1, 0], [0, 0, 1]],
+            translation: [0.0; 3],
+        }
+    }
+
+    /// Parse an operator such as `-y,x-y,z+1/3`.
+    ///
+    /// Each of the three comma-separated components is a sum of signed terms:
+    /// `x`, `y` or `z` with an optional integer coefficient (`2x`, `2*x`),
+    /// and constants written as integers, decimals or fractions. Letters are
+    /// case-insensitive and whitespace is ignored.
+    pub fn parse(operator: &str) -> Result<Self, SymOpParseError> {
+        let fail = |reason: String| SymOpParseError {
+            operator: operator.to_string(),
+            reason,
+        };
+
+        let components: Vec<&str> = operator.split(',').collect();
+        if components.len() != 3 {
+            return Err(fail(format!(
+                "expected 3 comma-separated components, found {}",
+                components.len()
+            )));
+        }
+
+        let mut op = SymOp {
+            rotation: [[0; 3]; 3],
+            translation: [0.0; 3],
+        };
+        for (row, component) in components.iter().enumerate() {
+            let (rotation, translation) = parse_component(component).map_err(fail)?;
+            op.rotation[row] = rotation;
+            op.translation[row] = translation;
+        }
+        Ok(op)
+    }
+
+    /// Apply the operation to a fractional position
+    pub fn apply(&self, position: [f64; 3]) -> [f64; 3] {
+        let mut result = self.translation;
+        for (row, out) in result.iter_mut().enumerate() {
+            for (col, x) in position.iter().enumerate() {
+                *out += f64::from(self.rotation[row][col]) * x;
+            }
+        }
+        result
+    }
+
+    /// Check if this is the identity operation
+    pub fn is_identity(&self) -> bool {
+        *self == SymOp::identity()
+    }
+}
+
+impl FromStr for SymOp {
+    type Err = SymOpParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SymOp::parse(s)
+    }
+}
+
+impl fmt::Display for SymOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..3 {
+            if row > 0 {
+                write!(f, ",")?;
+            }
+            let mut written = false;
+            for (col, axis) in ["x", "y", "z"].iter().enumerate() {
+                let coef = self.rotation[row][col];
+                if coef == 0 {
+                    continue;
+                }
+                let sign = if coef < 0 {
+                    "-"
+                } else if written {
+                    "+"
+                } else {
+                    ""
+                };
+                match coef.abs() {
+                    1 => write!(f, "{sign}{axis}")?,
+                    n => write!(f, "{sign}{n}{axis}")?,
+                }
+                written = true;
+            }
+            let t = self.translation[row];
+            if t != 0.0 || !written {
+                let sign = if t < 0.0 {
+                    "-"
+                } else if written {
+                    "+"
+                } else {
+                    ""
+                };
+                write!(f, "{sign}{}", format_fraction(t.abs()))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parse one component into its rotation row and translation
+fn parse_component(component: &str) -> Result<([i32; 3], f64), String> {
+    let chars: Vec<char> = component.chars().filter(|c| !c.is_whitespace()).collect();
+    if chars.is_empty() {
+        return Err("empty component".to_string());
+    }
+
+    let mut rotation = [0; 3];
+    let mut translation = 0.0;
+    let mut pos = 0;
+    while pos < chars.len() {
+        let sign = match chars[pos] {
+            '+' => {
+                pos += 1;
+                1.0
+            }
+            '-' => {
+                pos += 1;
+                -1.0
+            }
+            _ if pos == 0 => 1.0,
+            c => return Err(format!("expected '+' or '-' before '{c}'")),
+        };
+
+        let start = pos;
+        while pos < chars.len() && (chars[pos].is_ascii_digit() || chars[pos] == '.') {
+            pos += 1;
+        }
+        let number: String = chars[start..pos].iter().collect();
+        let mut value = if number.is_empty() {
+            None
+        } else {
+            Some(
+                number
+                    .parse::<f64>()
+                    .map_err(|_| format!("invalid number '{number}'"))?,
+            )
+        };
+
+        if pos < chars.len() && chars[pos] == '/' {
+            pos += 1;
+            let start = pos;
+            while pos < chars.len() && chars[pos].is_ascii_digit() {
+                pos += 1;
+            }
+            let denominator: String = chars[start..pos].iter().collect();
+            let denominator = denominator
+                .parse::<u32>()
+                .ok()
+                .filter(|d| *d != 0)
+                .ok_or_else(|| "invalid fraction denominator".to_string())?;
+            let numerator = value.ok_or_else(|| "fraction without numerator".to_string())?;
+            value = Some(numerator / f64::from(denominator));
+        }
+
+        if pos < chars.len() && chars[pos] == '*' && value.is_some() {
+            pos += 1;
+        }
+
+        let axis = chars
+            .get(pos)
+            .and_then(|c| "xyz".find(c.to_ascii_lowercase()));
+        match (axis, value) {
+            (Some(axis), coefficient) => {
+                pos += 1;
+                let coefficient = coefficient.unwrap_or(1.0);
+                if coefficient.fract() != 0.0 {
+                    return Err(format!("non-integer coefficient {coefficient}"));
+                }
+                rotation[axis] += (sign * coefficient) as i32;
+            }
+            (None, Some(value)) => translation += sign * value,
+            (None, None) => {
+                return Err(match chars.get(pos) {
+                    Some(c) => format!("unexpected character '{c}'"),
+                    None => "trailing sign".to_string(),
+                })
+            }
+        }
+    }
+    Ok((rotation, translation))
+}
+
+/// Format a translation as a small fraction when it is one
+fn format_fraction(value: f64) -> String {
+    for denominator in [1u32, 2, 3, 4, 6, 8, 12] {
+        let numerator = value * f64::from(denominator);
+        if (numerator - numerator.round()).abs() < 1e-9 {
+            let numerator = numerator.round() as i64;
+            return match denominator {
+                1 => numerator.to_string(),
+                d => format!("{numerator}/{d}"),
+            };
+        }
+    }
+    value.to_string()
+}
+
+/// Unit cell lengths (ångströms) and angles (degrees) with their uncertainties
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct UnitCell {
+    /// Cell length a
+    pub a: Measurand,
+    /// Cell length b
+    pub b: Measurand,
+    /// Cell length c
+    pub c: Measurand,
+    /// Angle between b and c
+    pub alpha: Measurand,
+    /// Angle between a and c
+    pub beta: Measurand,
+    /// Angle between a and b
+    pub gamma: Measurand,
+}
+
+impl UnitCell {
+    /// Lengths in the order a, b, c
+    pub fn lengths(&self) -> [f64; 3] {
+        [self.a.value, self.b.value, self.c.value]
+    }
+
+    /// Angles in degrees in the order α, β, γ
+    pub fn angles(&self) -> [f64; 3] {
+        [self.alpha.value, self.beta.value, self.gamma.value]
+    }
+
+    /// Cell volume in cubic ångströms, computed from the six parameters
+    pub fn volume(&self) -> f64 {
+        let [a, b, c] = self.lengths();
+        let [ca, cb, cg] = self.angles().map(|angle| angle.to_radians().cos());
+        a * b * c * (1.0 - ca * ca - cb * cb - cg * cg + 2.0 * ca * cb * cg).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_forms() {
+        let op = SymOp::parse("1/2+x, -Y+0.25, 2*z-1").unwrap();
+        assert_eq!(op.rotation, [[1, 0, 0], [0, -1, 0], [0, 0, 2]]);
+        assert_eq!(op.translation, [0.5, 0.25, -1.0]);
+
+        let op = SymOp::parse("-y,+z,x-y").unwrap();
+        assert_eq!(op.rotation, [[0, -1, 0], [0, 0, 1], [1, -1, 0]]);
+        assert_eq!(op.to_string(), "-y,z,x-y");
+
+        assert!(SymOp::parse("x,y,z").unwrap().is_identity());
+        assert_eq!(SymOp::parse("1/3,x,y").unwrap().to_string(), "1/3,x,y");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for bad in [
+            "x,y", "x,y,z,x", "x,,z", "x,y,w", "x,y,z+", "x,1/0,z", "x,0.5y,z",
+        ] {
+            let err = SymOp::parse(bad).unwrap_err();
+            assert_eq!(err.operator, bad);
+        }
+        assert_eq!(
+            SymOp::parse("x,y,w").unwrap_err().reason,
+            "unexpected character 'w'"
+        );
+    }
+
+    #[test]
+    fn test_cell_volume() {
+        let cell = UnitCell {
+            a: Measurand::new(2.0),
+            b: Measurand::new(3.0),
+            c: Measurand::new(4.0),
+            alpha: Measurand::new(90.0),
+            beta: Measurand::new(90.0),
+            gamma: Measurand::new(90.0),
+        };
+        assert!((cell.volume() - 24.0).abs() < 1e-9);
+    }
+}
@@ -3,6 +3,7 @@
 //! This module provides the `ValidatedCif` type that pairs a parsed CIF document
 //! with dictionary metadata, enabling:
 //! - Definition lookup at any source position (for IDE hover)
+//! - The validation findings at any source position (for IDE diagnostics)
 //! - Typed accessors based on dictionary type information
 //! - Rich error context with dictionary definitions
 
@@ -10,7 +11,30 @@ use std::sync::Arc;
 
 use cif_parser::{CifBlock, CifDocument, CifLoop, CifValue, Span};
 
+/// A value with standard uncertainty (shared with [`cif_parser::Measurand`]).
+pub use cif_parser::Measurand;
+
 use crate::dictionary::{DataItem, Dictionary};
+use crate::error::{ValidationError, ValidationResult};
+use crate::geometry::GeometryTables;
+use crate::resolve::{find_looped, find_single_value};
+use crate::symmetry::{SymOp, UnitCell};
+
+/// Canonical name of the symmetry operator item
+const SYMOP_XYZ: &str = "_space_group_symop.operation_xyz";
+
+/// Spellings of the operator item that dictionaries do not always alias
+const SYMOP_XYZ_LEGACY: &[&str] = &["_symmetry_equiv_pos_as_xyz", "_symmetry_equiv.pos_as_xyz"];
+
+/// Canonical names of the cell parameters in the order a, b, c, α, β, γ
+const CELL_PARAMETERS: [&str; 6] = [
+    "_cell.length_a",
+    "_cell.length_b",
+    "_cell.length_c",
+    "_cell.angle_alpha",
+    "_cell.angle_beta",
+    "_cell.angle_gamma",
+];
 
 /// A CIF document that has been validated against a dictionary.
 ///
@@ -18,15 +42,20 @@ use crate::dictionary::{DataItem, Dictionary};
 /// - Access to the original parsed document
 /// - Dictionary metadata for each data item
 /// - Span-to-definition lookup for IDE features
+/// - The validation result, with span-to-error lookup, when made by
+///   [`Validator::validate_typed`](crate::Validator::validate_typed)
 ///
 /// # Example
 /// ```ignore
-/// let validated = ValidatedCif::new(doc, dictionary);
+/// let validated = validator.validate_typed(doc)?;
 ///
 /// // Look up definition at cursor position (for hover info)
 /// if let Some(def) = validated.definition_at(5, 10) {
 ///     println!("Item: {}", def.name);
-///     println!("Description: {}", def.description.unwrap_or_default());
+///     println!("Description: {}", def.description().unwrap_or_default());
+/// }
+/// for error in validated.errors_at(5, 10) {
+///     println!("Error: {}", error.message);
 /// }
 /// ```
 #[derive(Debug, Clone)]
@@ -36,20 +65,77 @@ pub struct ValidatedCif {
     /// The dictionary used for validation
     dictionary: Arc<Dictionary>,
     /// Precomputed index for span-to-definition lookup
-    span_index: SpanIndex,
+    span_index: SpanIndex<String>,
+    /// Findings, if the document was validated
+    result: Option<ValidationResult>,
+    /// Precomputed index from spans to positions in `result.errors`
+    error_index: SpanIndex<usize>,
 }
 
 impl ValidatedCif {
     /// Create a ValidatedCif from a document and dictionary.
+    ///
+    /// This does not validate the document: [`result`](Self::result) is
+    /// `None` and [`errors_at`](Self::errors_at) finds nothing. Use
+    /// [`Validator::validate_typed`](crate::Validator::validate_typed) for
+    /// both.
     pub fn new(document: CifDocument, dictionary: Arc<Dictionary>) -> Self {
         let span_index = SpanIndex::build(&document, &dictionary);
         Self {
             document,
             dictionary,
             span_index,
+            result: None,
+            error_index: SpanIndex::default(),
         }
     }
 
+    /// Create a ValidatedCif holding the result of validating the document.
+    pub(crate) fn with_result(
+        document: CifDocument,
+        dictionary: Arc<Dictionary>,
+        result: ValidationResult,
+    ) -> Self {
+        let mut validated = Self::new(document, dictionary);
+        validated.error_index = SpanIndex::from_entries(
+            result
+                .errors
+                .iter()
+                .enumerate()
+                .map(|(index, error)| (error.span, index)),
+        );
+        validated.result = Some(result);
+        validated
+    }
+
+    /// The validation result, or `None` if the document was not validated.
+    pub fn result(&self) -> Option<&ValidationResult> {
+        self.result.as_ref()
+    }
+
+    /// Whether the document was validated and found to have no errors.
+    ///
+    /// `false` when it was not validated; see [`new`](Self::new).
+    pub fn is_valid(&self) -> bool {
+        self.result.as_ref().is_some_and(|result| result.is_valid)
+    }
+
+    /// The errors whose span contains a source position (for IDE
+    /// diagnostics), in the order validation reported them.
+    ///
+    /// Errors without a location are never returned.
+    pub fn errors_at(&self, line: usize, col: usize) -> Vec<&ValidationError> {
+        let Some(result) = &self.result else {
+            return Vec::new();
+        };
+        let mut indices: Vec<usize> = self.error_index.find_all(line, col).copied().collect();
+        indices.sort_unstable();
+        indices
+            .into_iter()
+            .map(|index| &result.errors[index])
+            .collect()
+    }
+
     /// Get the underlying CIF document.
     pub fn document(&self) -> &CifDocument {
         &self.document
@@ -71,24 +157,17 @@ impl ValidatedCif {
     }
 
     /// Get a typed value with its definition.
+    ///
+    /// As [`ValidatedBlock::get_typed`] on the named block.
     pub fn get_typed<T: FromCifValue>(
         &self,
         block_name: &str,
         item_name: &str,
     ) -> Option<TypedValue<T>> {
-        let block = self.document.get_block(block_name)?;
-        let value = block.get_item(item_name)?;
-        let canonical = self.dictionary.resolve_name(item_name);
-        let definition = self.dictionary.items.get(&canonical)?.clone();
-
-        T::from_cif_value(value).map(|typed| TypedValue {
-            value: typed,
-            raw: value.clone(),
-            definition,
-        })
+        self.block(block_name)?.get_typed(item_name)
     }
 
-    /// Get a validated block wrapper.
+    /// Get a validated block wrapper, looking the name up ignoring case.
     pub fn block(&self, name: &str) -> Option<ValidatedBlock<'_>> {
         self.document.get_block(name).map(|block| ValidatedBlock {
             block,
@@ -116,21 +195,70 @@ impl ValidatedCif {
     }
 }
 
-/// Index for quick span-to-definition lookup.
-#[derive(Debug, Clone, Default)]
-struct SpanIndex {
-    /// Entries mapping spans to item names
-    entries: Vec<SpanIndexEntry>,
+/// Index for quick span lookup: from item values to their names, or from
+/// errors to their position in the result.
+///
+/// Entries are sorted by where they start, so a lookup only looks at those
+/// starting at or before the position.
+#[derive(Debug, Clone)]
+struct SpanIndex<T> {
+    /// Entries mapping spans to values
+    entries: Vec<SpanIndexEntry<T>>,
+}
+
+impl<T> Default for SpanIndex<T> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
-struct SpanIndexEntry {
+struct SpanIndexEntry<T> {
     span: Span,
-    item_name: String, // Canonical name (lowercase)
+    value: T,
+}
+
+impl<T> SpanIndex<T> {
+    /// Build an index from spans and values, leaving out spans without a
+    /// location.
+    fn from_entries(entries: impl IntoIterator<Item = (Span, T)>) -> Self {
+        let mut entries: Vec<SpanIndexEntry<T>> = entries
+            .into_iter()
+            .filter(|(span, _)| span.start_line > 0)
+            .map(|(span, value)| SpanIndexEntry { span, value })
+            .collect();
+        entries.sort_by_key(|entry| (entry.span.start_line, entry.span.start_col));
+        SpanIndex { entries }
+    }
+
+    /// Entries that start at or before a position, latest start first.
+    fn candidates(&self, line: usize, col: usize) -> impl Iterator<Item = &SpanIndexEntry<T>> {
+        let end = self
+            .entries
+            .partition_point(|entry| (entry.span.start_line, entry.span.start_col) <= (line, col));
+        self.entries[..end].iter().rev()
+    }
+
+    /// The value of the latest-starting span containing a position.
+    fn find(&self, line: usize, col: usize) -> Option<&T> {
+        self.candidates(line, col)
+            .find(|entry| entry.span.contains(line, col))
+            .map(|entry| &entry.value)
+    }
+
+    /// The values of every span containing a position.
+    fn find_all(&self, line: usize, col: usize) -> impl Iterator<Item = &T> {
+        self.candidates(line, col)
+            .filter(move |entry| entry.span.contains(line, col))
+            .map(|entry| &entry.value)
+    }
 }
 
-impl SpanIndex {
-    /// Build a span index from a document and dictionary.
+impl SpanIndex<String> {
+    /// Build a span index from a document and dictionary, mapping each value
+    /// to the canonical name (lowercase) of its item.
     fn build(doc: &CifDocument, dict: &Dictionary) -> Self {
         let mut entries = Vec::new();
 
@@ -138,17 +266,14 @@ impl SpanIndex {
             Self::index_block(block, dict, &mut entries);
         }
 
-        SpanIndex { entries }
+        Self::from_entries(entries)
     }
 
-    fn index_block(block: &CifBlock, dict: &Dictionary, entries: &mut Vec<SpanIndexEntry>) {
+    fn index_block(block: &CifBlock, dict: &Dictionary, entries: &mut Vec<(Span, String)>) {
         // Index individual items
         for (name, value) in &block.items {
             let canonical = dict.resolve_name(name);
-            entries.push(SpanIndexEntry {
-                span: value.span,
-                item_name: canonical,
-            });
+            entries.push((value.span, canonical));
         }
 
         // Index loop values
@@ -157,10 +282,7 @@ impl SpanIndex {
                 let canonical = dict.resolve_name(tag);
                 for row in 0..loop_.len() {
                     if let Some(value) = loop_.get(row, col) {
-                        entries.push(SpanIndexEntry {
-                            span: value.span,
-                            item_name: canonical.clone(),
-                        });
+                        entries.push((value.span, canonical.clone()));
                     }
                 }
             }
@@ -170,34 +292,20 @@ impl SpanIndex {
         for frame in &block.frames {
             for (name, value) in &frame.items {
                 let canonical = dict.resolve_name(name);
-                entries.push(SpanIndexEntry {
-                    span: value.span,
-                    item_name: canonical,
-                });
+                entries.push((value.span, canonical));
             }
             for loop_ in &frame.loops {
                 for (col, tag) in loop_.tags.iter().enumerate() {
                     let canonical = dict.resolve_name(tag);
                     for row in 0..loop_.len() {
                         if let Some(value) = loop_.get(row, col) {
-                            entries.push(SpanIndexEntry {
-                                span: value.span,
-                                item_name: canonical.clone(),
-                            });
+                            entries.push((value.span, canonical.clone()));
                         }
                     }
                 }
             }
         }
     }
-
-    /// Find the item name at a given position.
-    fn find(&self, line: usize, col: usize) -> Option<&str> {
-        self.entries
-            .iter()
-            .find(|e| e.span.contains(line, col))
-            .map(|e| e.item_name.as_str())
-    }
 }
 
 /// A typed value with its dictionary definition.
@@ -224,7 +332,7 @@ impl<T> TypedValue<T> {
 
     /// Get the description from the definition.
     pub fn description(&self) -> Option<&str> {
-        self.definition.description.as_deref()
+        self.definition.description()
     }
 }
 
@@ -242,9 +350,16 @@ impl<'a> ValidatedBlock<'a> {
     }
 
     /// Get a value with its definition.
+    ///
+    /// Finds the item under any spelling the dictionary aliases to `name`,
+    /// whether given standalone or in a one-row loop, as legacy files often
+    /// write Set categories such as `_cell`.
     pub fn get_with_def(&self, name: &str) -> Option<(&CifValue, Option<&DataItem>)> {
-        let value = self.block.get_item(name)?;
         let canonical = self.dictionary.resolve_name(name);
+        let value = match self.block.get_item(name) {
+            Some(value) => value,
+            None => find_single_value(self.block, self.dictionary, &canonical, &[])?.1,
+        };
         let def = self.dictionary.items.get(&canonical);
         Some((value, def))
     }
@@ -268,9 +383,104 @@ impl<'a> ValidatedBlock<'a> {
         })
     }
 
-    /// Get all item names in this block.
+    /// Get all item names in this block, in document order.
     pub fn item_names(&self) -> impl Iterator<Item = &str> {
-        self.block.items.keys().map(|s| s.as_str())
+        self.block.items_iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Parse the block's symmetry operators.
+    ///
+    /// Reads `_space_group_symop.operation_xyz` under any spelling the
+    /// dictionary aliases to it, as well as `_space_group_symop_operation_xyz`
+    /// and the legacy `_symmetry_equiv_pos_as_xyz`, whether looped or given
+    /// once. Unknown (`?`) and inapplicable (`.`) entries are skipped, and a
+    /// block without operators yields an empty list. Every operator that
+    /// fails to parse is reported with the span of its value.
+    pub fn symmetry_operations(&self) -> Result<Vec<SymOp>, Vec<ValidationError>> {
+        let looped = find_looped(self.block, self.dictionary, SYMOP_XYZ, SYMOP_XYZ_LEGACY);
+        let values: Vec<(&str, &CifValue)> = match looped {
+            Some((loop_, col)) => (0..loop_.len())
+                .filter_map(|row| loop_.get(row, col))
+                .map(|value| (loop_.tags[col].as_str(), value))
+                .collect(),
+            None => self
+                .find_item(SYMOP_XYZ, SYMOP_XYZ_LEGACY)
+                .into_iter()
+                .collect(),
+        };
+
+        let mut operations = Vec::new();
+        let mut errors = Vec::new();
+        for (tag, value) in values {
+            if value.is_unknown() || value.is_not_applicable() {
+                continue;
+            }
+            let Some(text) = value.as_string() else {
+                errors.push(ValidationError::type_error(
+                    tag,
+                    "symmetry operator",
+                    "non-text value",
+                    value.span,
+                ));
+                continue;
+            };
+            match SymOp::parse(text) {
+                Ok(op) => operations.push(op),
+                Err(err) => errors.push(ValidationError::invalid_symmetry_operator(
+                    tag, &err, value.span,
+                )),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(operations)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Assemble the unit cell from the six cell parameters.
+    ///
+    /// Accepts the DDLm (`_cell.length_a`) and CIF 1.1 (`_cell_length_a`)
+    /// spellings, given standalone or in a one-row loop. Uncertainties come
+    /// from the value itself (`10.01(11)`) or, failing that, from a separate
+    /// `_su` item. Returns `None` if any of the six parameters is missing,
+    /// unknown, or not a number.
+    pub fn cell(&self) -> Option<UnitCell> {
+        let [a, b, c, alpha, beta, gamma] = CELL_PARAMETERS.map(|name| self.measurand(name));
+        Some(UnitCell {
+            a: a?,
+            b: b?,
+            c: c?,
+            alpha: alpha?,
+            beta: beta?,
+            gamma: gamma?,
+        })
+    }
+
+    /// Access the bond, angle and torsion loops.
+    ///
+    /// Accepts the DDLm (`_geom_bond.distance`) and CIF 1.1
+    /// (`_geom_bond_distance`) spellings; see [`GeometryTables`].
+    pub fn geometry(&self) -> GeometryTables<'a> {
+        GeometryTables::new(self.block, self.dictionary)
+    }
+
+    /// Read a measurand, taking its uncertainty from a `_su` item if needed
+    fn measurand(&self, canonical: &str) -> Option<Measurand> {
+        let (_, value) = self.find_item(canonical, &[])?;
+        let mut measurand = Measurand::from_value(value)?;
+        if measurand.uncertainty.is_none() {
+            measurand.uncertainty = self
+                .find_item(&format!("{canonical}_su"), &[])
+                .and_then(|(_, su)| su.as_numeric());
+        }
+        Some(measurand)
+    }
+
+    /// Find the single value stored under any spelling of a canonical name
+    fn find_item(&self, canonical: &str, legacy: &[&str]) -> Option<(&'a str, &'a CifValue)> {
+        find_single_value(self.block, self.dictionary, canonical, legacy)
     }
 }
 
@@ -388,6 +598,27 @@ impl<'a> ValidatedRow<'a> {
 }
 
 /// Trait for converting CIF values to typed Rust values.
+///
+/// This trait is deliberately open: implement it for your own types to read
+/// them with [`ValidatedBlock::get_typed`] and friends. Implementations should
+/// return `None` rather than panic when the value has the wrong shape.
+///
+/// # Example
+/// ```
+/// use cif_parser::CifValue;
+/// use cif_validator::FromCifValue;
+///
+/// struct Kelvin(f64);
+///
+/// impl FromCifValue for Kelvin {
+///     fn from_cif_value(value: &CifValue) -> Option<Self> {
+///         value.as_numeric().filter(|t| *t >= 0.0).map(Kelvin)
+///     }
+/// }
+///
+/// let t = Kelvin::from_cif_value(&CifValue::parse_value("293(2)")).unwrap();
+/// assert_eq!(t.0, 293.0);
+/// ```
 pub trait FromCifValue: Sized {
     /// Try to convert a CIF value to this type.
     fn from_cif_value(value: &CifValue) -> Option<Self>;
@@ -419,27 +650,9 @@ impl FromCifValue for bool {
     }
 }
 
-/// A value with standard uncertainty.
-#[derive(Debug, Clone, Copy)]
-pub struct Measurand {
-    /// The numeric value
-    pub value: f64,
-    /// The standard uncertainty (if present)
-    pub uncertainty: Option<f64>,
-}
-
 impl FromCifValue for Measurand {
     fn from_cif_value(value: &CifValue) -> Option<Self> {
-        match value.as_numeric_with_uncertainty() {
-            Some((v, u)) => Some(Measurand {
-                value: v,
-                uncertainty: Some(u),
-            }),
-            None => value.as_numeric().map(|v| Measurand {
-                value: v,
-                uncertainty: None,
-            }),
-        }
+        Measurand::from_value(value)
     }
 }
 
@@ -448,10 +661,24 @@ mod tests {
     use super::*;
     use crate::dictionary::load_dictionary;
 
+    #[test]
+    fn test_span_index_nested_spans() {
+        let index = SpanIndex::from_entries([
+            (Span::new(1, 1, 9, 1), "block"),
+            (Span::new(4, 5, 4, 9), "value"),
+            (Span::default(), "unplaced"),
+            (Span::new(2, 1, 5, 3), "loop"),
+        ]);
+        assert_eq!(index.find(4, 6), Some(&"value"));
+        let all: Vec<_> = index.find_all(4, 6).copied().collect();
+        assert_eq!(all, ["value", "loop", "block"]);
+        assert_eq!(index.find(6, 1), Some(&"block"));
+        assert_eq!(index.find(10, 1), None);
+    }
+
     #[test]
     fn test_validated_cif() {
-        let dict_content = r#"
-#\#CIF_2.0
+        let dict_content = r#"#\#CIF_2.0
 data_TEST_DICT
 
 save_cell.length_a
@@ -475,17 +702,13 @@ _cell.length_a 10.5
         let block = validated.first_block().unwrap();
         let (value, def) = block.get_with_def("_cell.length_a").unwrap();
         assert!(def.is_some());
-        assert_eq!(
-            def.unwrap().description,
-            Some("Unit cell length a".to_string())
-        );
+        assert_eq!(def.unwrap().description(), Some("Unit cell length a"));
         assert!(value.is_numeric());
     }
 
     #[test]
     fn test_typed_value() {
-        let dict_content = r#"
-#\#CIF_2.0
+        let dict_content = r#"#\#CIF_2.0
 data_TEST_DICT
 
 save_cell.length_a
@@ -0,0 +1,244 @@
+//! Heuristics for loop values shifted out of their columns.
+//!
+//! A value missing from a loop body moves every later value one column to
+//! the left, and a stray extra value moves them to the right. The file still
+//! parses if the count comes out a multiple of the tag count, and the shift
+//! otherwise surfaces as type and enumeration errors in nearly every row.
+//!
+//! Each column expects a kind of value, from its definition. A cell fits,
+//! misfits or says nothing about its column; a shift explains the misfits if
+//! moving the cells from some point on by a whole number of columns leaves
+//! almost none.
+
+use cif_parser::{CifLoop, CifValue, CifValueKind, Span};
+
+use crate::dictionary::{ContentType, DataItem, EnumerationConstraint};
+
+/// Percentage of misfitting cells above which a loop is checked for a shift
+const MISFIT_PERCENT: usize = 5;
+
+/// Fewest misfitting cells worth explaining
+const MIN_MISFITS: usize = 3;
+
+/// At most this share of the misfits may remain after the shift
+const UNEXPLAINED_FRACTION: usize = 10;
+
+/// What a column's definition says its values look like
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum ColumnKind<'a> {
+    /// Whole numbers
+    Integer,
+    /// Any number
+    Number,
+    /// Single words, such as labels and symbols, which are rarely numbers
+    Word,
+    /// One of a set of codes
+    Enumerated(&'a EnumerationConstraint),
+    /// Anything; undefined and free-text columns
+    Any,
+}
+
+impl<'a> ColumnKind<'a> {
+    /// The kind of a column from its definition, if the dictionary has one
+    pub(crate) fn of(def: Option<&'a DataItem>) -> Self {
+        let Some(def) = def else {
+            return Self::Any;
+        };
+        if let Some(enumeration) = &def.constraints.enumeration {
+            return Self::Enumerated(enumeration);
+        }
+        let content_types = &def.type_info.content_types;
+        if content_types.is_union() {
+            return Self::Any;
+        }
+        match content_types.first() {
+            ContentType::Integer | ContentType::Count | ContentType::Index => Self::Integer,
+            ContentType::Real => Self::Number,
+            ContentType::Word | ContentType::Code | ContentType::Name => Self::Word,
+            _ => Self::Any,
+        }
+    }
+
+    /// +1 if the value fits the column, -1 if it does not, 0 if it could be
+    /// in any column
+    fn fit(self, value: &CifValue) -> i32 {
+        let number = match &value.kind {
+            CifValueKind::Unknown | CifValueKind::NotApplicable => return 0,
+            CifValueKind::Numeric(n) | CifValueKind::NumericWithUncertainty { value: n, .. } => {
+                Some(*n)
+            }
+            CifValueKind::Text(text) => CifValue::parse_number(text).map(|(n, _)| n),
+            _ => return 0,
+        };
+        match (self, number) {
+            (Self::Any, _) => 0,
+            (Self::Integer, Some(n)) if n.fract() == 0.0 => 1,
+            (Self::Number, Some(_)) => 1,
+            (Self::Integer | Self::Number, _) => -1,
+            (Self::Word, Some(_)) => 0,
+            (Self::Word, None) => match value.as_string() {
+                Some(text) if text.contains(char::is_whitespace) => -1,
+                _ => 1,
+            },
+            // Numbers are not checked against enumerations
+            (Self::Enumerated(_), Some(_)) => 0,
+            (Self::Enumerated(enumeration), None) => match value.as_string() {
+                Some(text) if enumeration.contains(text) => 1,
+                _ => -1,
+            },
+        }
+    }
+}
+
+/// Loop values that appear shifted out of their columns
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ColumnShift {
+    /// Columns the values moved by: a value missing moves the later ones
+    /// by 1, an extra value by the loop width less 1
+    pub shift: usize,
+    /// Position in the flattened loop body of the first shifted value
+    pub first_cell: usize,
+    /// 1-based data row holding that value
+    pub row: usize,
+    /// Location of that value
+    pub span: Span,
+}
+
+impl ColumnShift {
+    /// Values missing before the first shifted one, or `None` if the shift
+    /// is better explained by extra values
+    pub(crate) fn missing(&self, width: usize) -> Option<usize> {
+        (self.shift * 2 <= width).then_some(self.shift)
+    }
+}
+
+/// Find the cyclic column shift that explains a loop's misfitting values.
+///
+/// `columns[i]` is the kind of column `i`. Returns `None` unless more than
+/// [`MISFIT_PERCENT`] of the cells misfit and moving the cells from some
+/// point on leaves at most a tenth of the misfits.
+pub(crate) fn find_column_shift(loop_: &CifLoop, columns: &[ColumnKind]) -> Option<ColumnShift> {
+    let width = loop_.tags.len();
+    let cells: Vec<&CifValue> = loop_.values.iter().flatten().collect();
+    if width < 2 || columns.len() != width || cells.len() < 2 * width {
+        return None;
+    }
+
+    let fits = |shift: usize| -> Vec<i32> {
+        cells
+            .iter()
+            .enumerate()
+            .map(|(i, value)| columns[(i + shift) % width].fit(value))
+            .collect()
+    };
+    let unshifted = fits(0);
+    let misfits = unshifted.iter().filter(|&&fit| fit < 0).count();
+    if misfits < MIN_MISFITS || misfits * 100 <= cells.len() * MISFIT_PERCENT {
+        return None;
+    }
+
+    // Score of each start: unshifted fits before it, shifted fits from it on
+    let mut before = vec![0; cells.len() + 1];
+    let mut misfits_before = vec![0; cells.len() + 1];
+    for (i, &fit) in unshifted.iter().enumerate() {
+        before[i + 1] = before[i] + fit;
+        misfits_before[i + 1] = misfits_before[i] + usize::from(fit < 0);
+    }
+
+    let mut best: Option<(i32, usize, ColumnShift)> = None;
+    for shift in 1..width {
+        let shifted = fits(shift);
+        let mut after = 0;
+        let mut misfits_after = 0;
+        let mut start: Option<(i32, usize, usize)> = None;
+        // Walk backwards so that ties go to the earliest start
+        for first in (0..cells.len()).rev() {
+            after += shifted[first];
+            misfits_after += usize::from(shifted[first] < 0);
+            let score = before[first] + after;
+            if start.is_none_or(|(best, _, _)| score >= best) {
+                start = Some((score, misfits_before[first] + misfits_after, first));
+            }
+        }
+        let Some((score, remaining, first)) = start else {
+            continue;
+        };
+        if best.as_ref().is_none_or(|(best, _, _)| score > *best) {
+            let found = ColumnShift {
+                shift,
+                first_cell: first,
+                row: first / width + 1,
+                span: cells[first].span,
+            };
+            best = Some((score, remaining, found));
+        }
+    }
+
+    let (score, remaining, found) = best?;
+    (score > before[cells.len()] && remaining * UNEXPLAINED_FRACTION <= misfits).then_some(found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cif_parser::CifDocument;
+
+    fn first_loop(cif: &str) -> CifLoop {
+        let doc = CifDocument::parse(cif).unwrap();
+        doc.blocks[0].loops[0].clone()
+    }
+
+    const COLUMNS: [ColumnKind; 4] = [
+        ColumnKind::Word,
+        ColumnKind::Number,
+        ColumnKind::Number,
+        ColumnKind::Integer,
+    ];
+
+    #[test]
+    fn test_missing_value_shifts_by_one() {
+        let loop_ = first_loop(
+            "data_t\nloop_\n_a\n_b\n_c\n_d\n\
+             C1 0.1 0.2 1\nC2 0.3 0.4 2\nC3 0.5 3\nC4 0.7 0.8 4\nC5 0.9 1.0 5\nC6 1.1 1.2 6\nC7\n",
+        );
+        let found = find_column_shift(&loop_, &COLUMNS).unwrap();
+
+        assert_eq!(found.shift, 1);
+        assert_eq!(found.missing(4), Some(1));
+        // 0.5 fits either way, so the gap is anywhere after C3
+        assert_eq!(found.row, 3);
+        assert_eq!(found.first_cell, 9);
+        assert_eq!((found.span.start_line, found.span.start_col), (9, 4));
+    }
+
+    #[test]
+    fn test_extra_value_shifts_back() {
+        let loop_ = first_loop(
+            "data_t\nloop_\n_a\n_b\n_c\n_d\n\
+             C1 0.1 0.2 1\nC2 0.3 0.4 0.45 2\nC3 0.5 0.6 3\nC4 0.7 0.8 4\nC5 0.9 1.0 5\nC6 1.1 1.2\n",
+        );
+        let found = find_column_shift(&loop_, &COLUMNS).unwrap();
+
+        assert_eq!(found.shift, 3);
+        assert_eq!(found.missing(4), None);
+        assert_eq!(found.row, 2);
+    }
+
+    #[test]
+    fn test_scattered_errors_not_a_shift() {
+        let loop_ = first_loop(
+            "data_t\nloop_\n_a\n_b\n_c\n_d\n\
+             C1 x 0.2 1\nC2 0.3 y 2\nC3 0.5 0.6 z\nC4 0.7 0.8 4\n",
+        );
+        assert_eq!(find_column_shift(&loop_, &COLUMNS), None);
+    }
+
+    #[test]
+    fn test_few_errors_not_checked() {
+        let loop_ = first_loop(
+            "data_t\nloop_\n_a\n_b\n_c\n_d\n\
+             C1 0.1 0.2 1\nC2 0.3 0.4 2\nC3 0.5 0.6 3\nC4 0.7 0.8 4\nC5 0.9 1.0 5\nC6 1.1 1.2 x\n",
+        );
+        assert_eq!(find_column_shift(&loop_, &COLUMNS), None);
+    }
+}
@@ -1,13 +1,21 @@
 //! Core validation engine implementation.
 
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
+use std::sync::Arc;
 
-use cif_parser::{CifBlock, CifDocument, CifLoop, CifValue, CifValueKind};
+use cif_parser::{BlockLink, CifBlock, CifDocument, CifLoop, CifValue, CifValueKind, Span};
 
+use super::column_shift::{find_column_shift, ColumnKind};
+use super::occurrence::{OccurrenceMap, OccurrenceOptions};
+use super::quoting::{find_quoting_issues, QuotingIssue};
+use super::split_number::{find_split_numbers, SplitKind};
+use super::text_structure::find_embedded_structure;
 use crate::dictionary::{
-    ContainerType, ContentType, DataItem, Dictionary, EnumerationConstraint, RangeConstraint,
+    CategoryClass, ContainerType, ContentType, DataItem, Dictionary, EnumerationConstraint,
+    RangeConstraint,
 };
 use crate::error::{ValidationError, ValidationResult, ValidationWarning, WarningCategory};
+use crate::resolve::one_row_set_category;
 
 /// Validation mode controlling strictness
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -25,6 +33,11 @@ pub enum ValidationMode {
 pub struct ValidationEngine<'dict> {
     dictionary: &'dict Dictionary,
     mode: ValidationMode,
+    occurrence_options: OccurrenceOptions,
+    allow_structure_in_text: bool,
+    unknown_mandatory_as_warning: bool,
+    /// Items with an implied content type already noted in this block
+    implied_noted: HashSet<String>,
     result: ValidationResult,
 }
 
@@ -34,22 +47,84 @@ impl<'dict> ValidationEngine<'dict> {
         Self {
             dictionary,
             mode,
+            occurrence_options: OccurrenceOptions::default(),
+            allow_structure_in_text: false,
+            unknown_mandatory_as_warning: false,
+            implied_noted: HashSet::new(),
             result: ValidationResult::new(),
         }
     }
 
+    /// Set which structures are counted by the per-block occurrence check
+    pub fn with_occurrence_options(mut self, options: OccurrenceOptions) -> Self {
+        self.occurrence_options = options;
+        self
+    }
+
+    /// Skip the pedantic check for text values that contain CIF structure.
+    ///
+    /// Use this for documentation-heavy files whose text fields legitimately
+    /// quote CIF syntax.
+    pub fn allow_structure_in_text(mut self, allow: bool) -> Self {
+        self.allow_structure_in_text = allow;
+        self
+    }
+
+    /// Report mandatory and key items given only as `?` as warnings in
+    /// [`ValidationMode::Strict`] too, instead of as errors
+    pub fn unknown_mandatory_as_warning(mut self, as_warning: bool) -> Self {
+        self.unknown_mandatory_as_warning = as_warning;
+        self
+    }
+
     /// Validate a CIF document
     pub fn validate(mut self, doc: &CifDocument) -> ValidationResult {
-        for block in &doc.blocks {
+        let links = doc.block_links();
+        for (index, block) in doc.blocks.iter().enumerate() {
+            let errors = self.result.errors.len();
+            let warnings = self.result.warnings.len();
+            self.implied_noted.clear();
             self.validate_block(block);
+            self.check_block_links(links.iter().filter(|link| link.from_block == index));
+            self.name_extension_sources(errors);
+            self.result.finish_block(errors, warnings, &block.name);
         }
         self.result
     }
 
+    /// Name the dictionary in errors about items that an extension, rather
+    /// than the primary dictionary, defined
+    fn name_extension_sources(&mut self, first_error: usize) {
+        let dictionary = self.dictionary;
+        for error in &mut self.result.errors[first_error..] {
+            let source = error
+                .data_name
+                .as_deref()
+                .and_then(|name| dictionary.get_item(name))
+                .and_then(|def| def.source_dictionary.as_deref())
+                .filter(|&source| dictionary.primary_dictionary.as_deref() != Some(source));
+            if let Some(source) = source {
+                error.message = format!("{} (defined in {})", error.message, source);
+            }
+        }
+    }
+
+    /// Warn about pointers to blocks that are not in the document
+    fn check_block_links<'a>(&mut self, links: impl Iterator<Item = &'a BlockLink>) {
+        for link in links.filter(|link| link.is_dangling()) {
+            self.result
+                .add_warning(ValidationWarning::dangling_block_link(
+                    &link.tag,
+                    &link.target_id,
+                    link.span,
+                ));
+        }
+    }
+
     /// Validate a single data block
     fn validate_block(&mut self, block: &CifBlock) {
         // Validate individual items
-        for (name, value) in &block.items {
+        for (name, value) in block.items_iter() {
             self.validate_item(name, value);
         }
 
@@ -60,7 +135,7 @@ impl<'dict> ValidationEngine<'dict> {
 
         // Validate save frames
         for frame in &block.frames {
-            for (name, value) in &frame.items {
+            for (name, value) in frame.items_iter() {
                 self.validate_item(name, value);
             }
             for loop_ in &frame.loops {
@@ -68,12 +143,19 @@ impl<'dict> ValidationEngine<'dict> {
             }
         }
 
+        // Check how many times each item receives a value
+        self.check_occurrences(block);
+
         // Check mandatory items
         self.check_mandatory_items(block);
+        self.check_uninformative_values(block);
     }
 
     /// Validate a single item
     fn validate_item(&mut self, name: &str, value: &CifValue) {
+        self.check_text_structure(name, value);
+        self.check_quoting(name, value);
+
         // Look up definition
         let Some(def) = self.dictionary.get_item(name) else {
             // Unknown data name
@@ -108,11 +190,94 @@ impl<'dict> ValidationEngine<'dict> {
         self.validate_constraints(name, value, def);
     }
 
+    /// Warn when a text value contains lines that look like CIF structure
+    fn check_text_structure(&mut self, name: &str, value: &CifValue) {
+        if self.mode != ValidationMode::Pedantic || self.allow_structure_in_text {
+            return;
+        }
+        if let Some(found) = find_embedded_structure(value) {
+            self.result
+                .add_warning(ValidationWarning::suspicious_text_field(
+                    name,
+                    &found.line,
+                    found.span,
+                ));
+        }
+    }
+
+    /// Warn about risky or needlessly heavy quoting
+    fn check_quoting(&mut self, name: &str, value: &CifValue) {
+        if self.mode != ValidationMode::Pedantic {
+            return;
+        }
+        for issue in find_quoting_issues(value) {
+            let warning = match issue {
+                QuotingIssue::NeedsQuotes { text, span } => {
+                    ValidationWarning::unquoted_text(name, &text, span)
+                }
+                QuotingIssue::Heavyweight { style, span } => {
+                    let delimiter = if style.is_triple() {
+                        "triple-quoted string"
+                    } else {
+                        "text field"
+                    };
+                    ValidationWarning::heavyweight_quoting(name, delimiter, span)
+                }
+                QuotingIssue::Unneeded { text, span } => {
+                    ValidationWarning::unneeded_quotes(name, &text, span)
+                }
+            };
+            self.result.add_warning(warning);
+        }
+    }
+
     /// Validate value type matches definition
+    ///
+    /// A union such as `Integer,Text` accepts a value that any one of its
+    /// members accepts.
     fn validate_type(&mut self, name: &str, value: &CifValue, def: &DataItem) {
-        match def.type_info.contents {
+        let content_types = &def.type_info.content_types;
+        if content_types.contains(ContentType::Implied) {
+            self.note_implied_type(name, value.span);
+            return;
+        }
+        if !content_types.is_union() {
+            self.validate_content(name, value, def, content_types.first());
+            return;
+        }
+
+        // Try each member against a scratch result, keeping none of their errors
+        let outer = std::mem::take(&mut self.result);
+        let accepted = content_types.iter().any(|content| {
+            self.result = ValidationResult::new();
+            self.validate_content(name, value, def, content);
+            self.result.errors.is_empty()
+        });
+        self.result = outer;
+        if !accepted {
+            let expected = content_types
+                .iter()
+                .map(|content| format!("{:?}", content).to_lowercase())
+                .collect::<Vec<_>>()
+                .join(" or ");
+            self.result.add_error(
+                ValidationError::type_error(name, expected, format!("'{}'", value), value.span)
+                    .with_definition_span(def.span),
+            );
+        }
+    }
+
+    /// Validate a value against one content type
+    fn validate_content(
+        &mut self,
+        name: &str,
+        value: &CifValue,
+        def: &DataItem,
+        content: ContentType,
+    ) {
+        match content {
             ContentType::Integer | ContentType::Index | ContentType::Count => {
-                self.validate_integer(name, value, def);
+                self.validate_integer(name, value, def, content);
             }
             ContentType::Real => {
                 self.validate_real(name, value, def);
@@ -131,8 +296,26 @@ impl<'dict> ValidationEngine<'dict> {
         }
     }
 
+    /// Note, once per block in pedantic mode, that an item with an implied
+    /// content type was not type-checked
+    fn note_implied_type(&mut self, name: &str, span: Span) {
+        if self.mode != ValidationMode::Pedantic {
+            return;
+        }
+        if self.implied_noted.insert(name.to_lowercase()) {
+            self.result
+                .add_warning(ValidationWarning::implied_type(name, span));
+        }
+    }
+
     /// Validate integer type
-    fn validate_integer(&mut self, name: &str, value: &CifValue, def: &DataItem) {
+    fn validate_integer(
+        &mut self,
+        name: &str,
+        value: &CifValue,
+        def: &DataItem,
+        content: ContentType,
+    ) {
         match &value.kind {
             CifValueKind::Numeric(n) => {
                 // Check if it's actually an integer
@@ -149,28 +332,22 @@ impl<'dict> ValidationEngine<'dict> {
                 }
 
                 // Check Index (must be positive) and Count (must be non-negative)
-                match def.type_info.contents {
-                    ContentType::Index => {
-                        if *n < 1.0 {
-                            self.result.add_error(ValidationError::range_error(
-                                name,
-                                *n,
-                                Some(1.0),
-                                None,
-                                value.span,
-                            ));
-                        }
+                match content {
+                    ContentType::Index if *n < 1.0 => {
+                        self.result.add_error(ValidationError::range_error(
+                            name,
+                            *n,
+                            &[RangeConstraint::at_least(1.0)],
+                            value.span,
+                        ));
                     }
-                    ContentType::Count => {
-                        if *n < 0.0 {
-                            self.result.add_error(ValidationError::range_error(
-                                name,
-                                *n,
-                                Some(0.0),
-                                None,
-                                value.span,
-                            ));
-                        }
+                    ContentType::Count if *n < 0.0 => {
+                        self.result.add_error(ValidationError::range_error(
+                            name,
+                            *n,
+                            &[RangeConstraint::at_least(0.0)],
+                            value.span,
+                        ));
                     }
                     _ => {}
                 }
@@ -216,7 +393,7 @@ impl<'dict> ValidationEngine<'dict> {
             }
             CifValueKind::Text(s) => {
                 // Try to parse as number
-                if s.parse::<f64>().is_err() {
+                if CifValue::parse_number(s).is_none() {
                     self.result.add_error(ValidationError::type_error(
                         name,
                         "real number",
@@ -340,8 +517,8 @@ impl<'dict> ValidationEngine<'dict> {
         }
 
         // Range check
-        if let Some(range) = &def.constraints.range {
-            self.validate_range(name, value, range);
+        if !def.constraints.ranges.is_empty() {
+            self.validate_range(name, value, &def.constraints.ranges);
         }
     }
 
@@ -361,34 +538,34 @@ impl<'dict> ValidationEngine<'dict> {
             let mut error =
                 ValidationError::enumeration_error(name, value_str, &constraint.values, value.span);
 
-            // Add suggestions for similar values
-            let suggestions = suggest_similar(&value_str.to_lowercase(), &constraint.values);
-            if !suggestions.is_empty() {
-                error = error.with_suggestions(suggestions);
+            // Suggest similar values, each of which could replace this one
+            for similar in suggest_similar(&value_str.to_lowercase(), &constraint.values) {
+                error = error
+                    .with_suggestion(format!("Did you mean '{}'?", similar))
+                    .with_replacement(similar);
             }
 
             self.result.add_error(error);
         }
     }
 
-    /// Validate range constraint
-    fn validate_range(&mut self, name: &str, value: &CifValue, range: &RangeConstraint) {
+    /// Validate range constraints; the value must fall in at least one range
+    fn validate_range(&mut self, name: &str, value: &CifValue, ranges: &[RangeConstraint]) {
         let num = match value.as_numeric() {
             Some(n) => n,
             None => return, // Non-numeric values don't match range
         };
 
-        if !range.contains(num) {
-            self.result.add_error(ValidationError::range_error(
-                name, num, range.min, range.max, value.span,
-            ));
+        if !ranges.iter().any(|range| range.contains(num)) {
+            self.result
+                .add_error(ValidationError::range_error(name, num, ranges, value.span));
         }
     }
 
     /// Validate a loop structure
     fn validate_loop(&mut self, loop_: &CifLoop) {
         // Collect categories for each tag
-        let mut categories: Vec<Option<String>> = Vec::new();
+        let mut categories: Vec<Option<Arc<str>>> = Vec::new();
         let mut unknown_tags = Vec::new();
 
         for tag in &loop_.tags {
@@ -418,7 +595,7 @@ impl<'dict> ValidationEngine<'dict> {
             if mixed
                 && (self.mode == ValidationMode::Pedantic || self.mode == ValidationMode::Strict)
             {
-                let unique_cats: HashSet<&str> = known_categories.into_iter().collect();
+                let unique_cats: BTreeSet<&str> = known_categories.into_iter().collect();
                 self.result.add_warning(ValidationWarning::mixed_categories(
                     &unique_cats
                         .into_iter()
@@ -429,9 +606,105 @@ impl<'dict> ValidationEngine<'dict> {
             }
         }
 
+        if let Some(category) = one_row_set_category(loop_, self.dictionary) {
+            match self.mode {
+                ValidationMode::Lenient => {}
+                ValidationMode::Strict => self
+                    .result
+                    .add_warning(ValidationWarning::looped_set_category(category, loop_.span)),
+                ValidationMode::Pedantic => self.result.add_error(
+                    ValidationError::loop_structure(
+                        format!("Set category '{category}' written as a one-row loop"),
+                        loop_.span,
+                    )
+                    .with_suggestion("Give each item on its own line, outside a loop"),
+                ),
+            }
+        }
+
+        // Broken-up numbers get one targeted error instead of a cascade of
+        // type errors from the cells they displace
+        let numeric_columns: Vec<bool> = loop_
+            .tags
+            .iter()
+            .map(|tag| {
+                self.dictionary
+                    .get_item(tag)
+                    .is_some_and(|def| def.type_info.content_types.is_numeric())
+            })
+            .collect();
+        let splits = find_split_numbers(loop_, &numeric_columns);
+        let mut skipped = HashSet::new();
+        let mut shifted = false;
+
+        for split in &splits {
+            let message = match split.kind {
+                SplitKind::AcrossFields => {
+                    shifted = true;
+                    format!(
+                        "Row {} appears to have a split numeric value '{}' across two fields",
+                        split.row, split.text
+                    )
+                }
+                SplitKind::EmbeddedSeparator => {
+                    skipped.insert((split.row - 1, split.column));
+                    format!(
+                        "Row {} has a numeric value '{}' with embedded digit separators",
+                        split.row, split.text
+                    )
+                }
+            };
+            self.result.add_error(
+                ValidationError::loop_structure(message, split.span)
+                    .with_suggestion(format!("Write the value as {}", split.joined)),
+            );
+        }
+
+        // Values after a split field are shifted, so checking them only adds noise
+        if shifted {
+            return;
+        }
+
+        // A missing or extra value shows up as mistyped cells in every later
+        // row; report where the values slipped and skip the cells that moved
+        let width = loop_.tags.len();
+        let columns: Vec<ColumnKind> = loop_
+            .tags
+            .iter()
+            .map(|tag| ColumnKind::of(self.dictionary.get_item(tag)))
+            .collect();
+        let mut first_shifted = usize::MAX;
+        if let Some(shift) = find_column_shift(loop_, &columns) {
+            first_shifted = shift.first_cell;
+            let line = shift.span.start_line;
+            let (by, cause) = match shift.missing(width) {
+                Some(1) => ("by 1 column".to_string(), "a value is likely missing"),
+                Some(n) => (format!("by {n} columns"), "values are likely missing"),
+                None if shift.shift + 1 == width => {
+                    ("back by 1 column".to_string(), "an extra value is likely")
+                }
+                None => (
+                    format!("back by {} columns", width - shift.shift),
+                    "extra values are likely",
+                ),
+            };
+            let message = format!(
+                "Loop values appear shifted {by} starting at row {}; {cause} near line {line}",
+                shift.row
+            );
+            self.result.add_error(
+                ValidationError::loop_structure(message, shift.span).with_suggestion(format!(
+                    "Check the values from line {line} on against the loop's {width} tags"
+                )),
+            );
+        }
+
         // Validate each value in the loop
         for (col, tag) in loop_.tags.iter().enumerate() {
             for row in 0..loop_.len() {
+                if skipped.contains(&(row, col)) || row * width + col >= first_shifted {
+                    continue;
+                }
                 if let Some(value) = loop_.get(row, col) {
                     self.validate_item(tag, value);
                 }
@@ -439,10 +712,69 @@ impl<'dict> ValidationEngine<'dict> {
         }
     }
 
+    /// Check per-block occurrence limits across items, loops and (optionally) frames
+    ///
+    /// A Set-category item may have one value in a block, an item should sit
+    /// in one loop, and an item should be given under one of its names, not
+    /// under its canonical name and an alias.
+    fn check_occurrences(&mut self, block: &CifBlock) {
+        let occurrences = OccurrenceMap::count(block, self.dictionary, self.occurrence_options);
+
+        for (name, found) in occurrences.iter() {
+            let is_set_item = self
+                .dictionary
+                .items
+                .get(name)
+                .and_then(|def| self.dictionary.get_category(&def.category))
+                .is_some_and(|cat| cat.class == CategoryClass::Set);
+            let spellings = occurrences.spellings(name);
+
+            let count = occurrences.value_count(name);
+            if is_set_item && count > 1 {
+                // Point at the first occurrence beyond the single allowed value
+                let span = if found[0].values > 1 {
+                    found[0].span
+                } else {
+                    found[1].span
+                };
+                let mut error = ValidationError::duplicate_item(&found[0].tag, count, span);
+                if spellings.len() > 1 {
+                    let names: Vec<String> = spellings.iter().map(|t| format!("'{}'", t)).collect();
+                    error = error.with_suggestion(format!(
+                        "{} name the same item; keep one",
+                        names.join(" and ")
+                    ));
+                }
+                self.result.add_error(error);
+                continue;
+            }
+
+            if spellings.len() > 1 {
+                let span = found
+                    .iter()
+                    .find(|o| !o.tag.eq_ignore_ascii_case(&found[0].tag))
+                    .map_or(found[0].span, |o| o.span);
+                self.result
+                    .add_warning(ValidationWarning::alias_conflict(name, &spellings, span));
+                continue;
+            }
+
+            let looped: Vec<_> = found.iter().filter(|o| o.source.is_loop()).collect();
+            if looped.len() > 1 {
+                self.result
+                    .add_warning(ValidationWarning::duplicate_loop_item(
+                        &looped[0].tag,
+                        looped.len(),
+                        looped[1].span,
+                    ));
+            }
+        }
+    }
+
     /// Check mandatory items for present categories
     fn check_mandatory_items(&mut self, block: &CifBlock) {
-        // Find all categories present in the block
-        let mut present_categories: HashSet<String> = HashSet::new();
+        // Find all categories present in the block, visited by name below
+        let mut present_categories: BTreeSet<Arc<str>> = BTreeSet::new();
         let mut present_items: HashSet<String> = HashSet::new();
 
         // Check individual items
@@ -487,10 +819,83 @@ impl<'dict> ValidationEngine<'dict> {
             }
         }
     }
+
+    /// Check that mandatory and key items carry information
+    ///
+    /// A `?` satisfies the presence check but says nothing, so an item whose
+    /// only values are `?` is reported: as an error in strict mode, else as a
+    /// warning. A looped item is reported once, at the loop, when every row
+    /// is `?`. A `.` asserts that the item does not apply and is only noted
+    /// in pedantic mode.
+    fn check_uninformative_values(&mut self, block: &CifBlock) {
+        for (name, value) in block.items_iter() {
+            if let Some(role) = self.required_role(name) {
+                self.report_uninformative(name, role, false, [value], value.span);
+            }
+        }
+        for loop_ in &block.loops {
+            if loop_.is_empty() {
+                continue;
+            }
+            for (col, tag) in loop_.tags.iter().enumerate() {
+                if let Some(role) = self.required_role(tag) {
+                    let column = (0..loop_.len()).filter_map(|row| loop_.get(row, col));
+                    self.report_uninformative(tag, role, true, column, loop_.span);
+                }
+            }
+        }
+    }
+
+    fn report_uninformative<'v>(
+        &mut self,
+        name: &str,
+        role: &str,
+        looped: bool,
+        values: impl IntoIterator<Item = &'v CifValue> + Clone,
+        span: Span,
+    ) {
+        if values.clone().into_iter().all(CifValue::is_unknown) {
+            if self.mode == ValidationMode::Strict && !self.unknown_mandatory_as_warning {
+                self.result
+                    .add_error(ValidationError::unknown_mandatory_value(
+                        name, role, looped, span,
+                    ));
+            } else {
+                self.result
+                    .add_warning(ValidationWarning::unknown_mandatory_value(
+                        name, role, looped, span,
+                    ));
+            }
+        } else if self.mode == ValidationMode::Pedantic
+            && values.into_iter().all(CifValue::is_not_applicable)
+        {
+            self.result
+                .add_warning(ValidationWarning::not_applicable_mandatory(
+                    name, role, looped, span,
+                ));
+        }
+    }
+
+    /// Why `name` must carry a value, if it must: mandatory items and the
+    /// keys of loop categories
+    fn required_role(&self, name: &str) -> Option<&'static str> {
+        let def = self.dictionary.get_item(name)?;
+        if def.is_mandatory() {
+            return Some("Mandatory");
+        }
+        let category = self.dictionary.get_category(&def.category)?;
+        let canonical = self.dictionary.resolve_name(&def.name);
+        let is_key = category.class == CategoryClass::Loop
+            && category
+                .key_items
+                .iter()
+                .any(|key| self.dictionary.resolve_name(key) == canonical);
+        is_key.then_some("Key")
+    }
 }
 
 /// Suggest similar strings using simple substring matching
-fn suggest_similar(input: &str, candidates: &[String]) -> Vec<String> {
+fn suggest_similar<'a>(input: &str, candidates: &'a [String]) -> Vec<&'a str> {
     candidates
         .iter()
         .filter(|c| {
@@ -498,7 +903,7 @@ fn suggest_similar(input: &str, candidates: &[String]) -> Vec<String> {
             c_lower.contains(input) || input.contains(&c_lower)
         })
         .take(3)
-        .map(|c| format!("Did you mean '{}'?", c))
+        .map(String::as_str)
         .collect()
 }
 
@@ -510,8 +915,7 @@ mod tests {
     use cif_parser::CifDocument;
 
     fn create_test_dict() -> Dictionary {
-        let cif_content = r#"
-#\#CIF_2.0
+        let cif_content = r#"#\#CIF_2.0
 data_TEST_DICT
     _dictionary.title             TEST_DICT
 
@@ -659,4 +1063,100 @@ _cell.length_a not_a_number
         assert!(!result.is_valid);
         assert_eq!(result.errors[0].category, ErrorCategory::TypeError);
     }
+
+    #[test]
+    fn test_set_item_duplicated_via_two_loops() {
+        let dict = create_test_dict();
+        let cif = CifDocument::parse(
+            r#"
+data_test
+loop_
+_cell.length_a
+10.5
+loop_
+_cell.length_a
+11.0
+"#,
+        )
+        .unwrap();
+
+        let engine = ValidationEngine::new(&dict, ValidationMode::Strict);
+        let result = engine.validate(&cif);
+
+        assert!(!result.is_valid);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].category, ErrorCategory::DuplicateItem);
+        assert_eq!(result.errors[0].span.start_line, 6);
+    }
+
+    #[test]
+    fn test_set_item_duplicated_via_item_and_loop() {
+        let dict = create_test_dict();
+        let cif = CifDocument::parse(
+            r#"
+data_test
+_cell.length_a 10.5
+loop_
+_cell.length_a
+10.5
+"#,
+        )
+        .unwrap();
+
+        let engine = ValidationEngine::new(&dict, ValidationMode::Strict);
+        let result = engine.validate(&cif);
+
+        assert!(!result.is_valid);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].category, ErrorCategory::DuplicateItem);
+        assert_eq!(
+            result.errors[0].data_name.as_deref(),
+            Some("_cell.length_a")
+        );
+    }
+
+    #[test]
+    fn test_set_item_single_occurrence() {
+        let dict = create_test_dict();
+        let cif = CifDocument::parse(
+            r#"
+data_test
+_cell.length_a 10.5
+"#,
+        )
+        .unwrap();
+
+        let engine = ValidationEngine::new(&dict, ValidationMode::Strict);
+        let result = engine.validate(&cif);
+
+        assert!(result.is_valid, "Expected valid, got: {:?}", result.errors);
+        assert!(result.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_item_in_two_loops_warns() {
+        let dict = create_test_dict();
+        let cif = CifDocument::parse(
+            r#"
+data_test
+loop_
+_atom.label
+C1
+C2
+loop_
+_atom.label
+N1
+"#,
+        )
+        .unwrap();
+
+        let engine = ValidationEngine::new(&dict, ValidationMode::Lenient);
+        let result = engine.validate(&cif);
+
+        assert!(result.is_valid);
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.category == WarningCategory::DuplicateItem));
+    }
 }
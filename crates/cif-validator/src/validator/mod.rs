@@ -3,6 +3,12 @@
 //! This module provides the core validation logic for checking CIF documents
 //! against DDLm dictionaries.
 
+mod column_shift;
 mod engine;
+mod occurrence;
+mod quoting;
+mod split_number;
+mod text_structure;
 
 pub use engine::{ValidationEngine, ValidationMode};
+pub use occurrence::{Occurrence, OccurrenceMap, OccurrenceOptions, OccurrenceSource};
@@ -0,0 +1,173 @@
+//! Cross-structure occurrence counting for data items.
+//!
+//! A data item can receive values from several places in a block: as a
+//! single item, as a column of one or more loops, and (optionally) inside
+//! save frames. [`OccurrenceMap`] records every such place under the item's
+//! canonical dictionary name so that checks which need a whole-block view
+//! (single-value enforcement, alias conflicts) can share one pass. Items are
+//! recorded in document order, then loop columns.
+
+use std::collections::BTreeMap;
+
+use cif_parser::{CifBlock, CifLoop, Span};
+
+use crate::dictionary::Dictionary;
+
+/// Options controlling which structures are counted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OccurrenceOptions {
+    /// Count items and loops inside save frames as part of the enclosing block
+    pub include_frames: bool,
+}
+
+impl OccurrenceOptions {
+    /// Create default options (save frames are not counted)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether save frame contents are counted
+    pub fn include_frames(mut self, include: bool) -> Self {
+        self.include_frames = include;
+        self
+    }
+}
+
+/// Where a data item received its value(s)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OccurrenceSource {
+    /// A single (non-looped) item in the block
+    Item,
+    /// A column of the block loop at this index
+    Loop(usize),
+    /// A single item in the save frame at this index
+    FrameItem(usize),
+    /// A column of a loop in a save frame: `(frame index, loop index)`
+    FrameLoop(usize, usize),
+}
+
+impl OccurrenceSource {
+    /// Whether this occurrence is a loop column
+    pub fn is_loop(&self) -> bool {
+        matches!(self, Self::Loop(_) | Self::FrameLoop(..))
+    }
+}
+
+/// A single place where a data item appears
+#[derive(Debug, Clone)]
+pub struct Occurrence {
+    /// The tag as written in the CIF (may be an alias)
+    pub tag: String,
+    /// Structure the value(s) came from
+    pub source: OccurrenceSource,
+    /// Number of values supplied (1 for items, row count for loop columns)
+    pub values: usize,
+    /// Source location of the item value or loop
+    pub span: Span,
+}
+
+/// All occurrences of data items in a block, keyed by canonical name
+#[derive(Debug, Clone, Default)]
+pub struct OccurrenceMap {
+    by_name: BTreeMap<String, Vec<Occurrence>>,
+}
+
+impl OccurrenceMap {
+    /// Count every occurrence of every data item in `block`
+    pub fn count(block: &CifBlock, dictionary: &Dictionary, options: OccurrenceOptions) -> Self {
+        let mut map = Self::default();
+
+        for (tag, value) in block.items_iter() {
+            map.record(dictionary, tag, OccurrenceSource::Item, 1, value.span);
+        }
+        for (index, loop_) in block.loops.iter().enumerate() {
+            map.record_loop(dictionary, loop_, OccurrenceSource::Loop(index));
+        }
+
+        if options.include_frames {
+            for (frame_index, frame) in block.frames.iter().enumerate() {
+                for (tag, value) in frame.items_iter() {
+                    map.record(
+                        dictionary,
+                        tag,
+                        OccurrenceSource::FrameItem(frame_index),
+                        1,
+                        value.span,
+                    );
+                }
+                for (loop_index, loop_) in frame.loops.iter().enumerate() {
+                    map.record_loop(
+                        dictionary,
+                        loop_,
+                        OccurrenceSource::FrameLoop(frame_index, loop_index),
+                    );
+                }
+            }
+        }
+
+        map
+    }
+
+    fn record_loop(&mut self, dictionary: &Dictionary, loop_: &CifLoop, source: OccurrenceSource) {
+        for tag in &loop_.tags {
+            self.record(dictionary, tag, source, loop_.len(), loop_.span);
+        }
+    }
+
+    fn record(
+        &mut self,
+        dictionary: &Dictionary,
+        tag: &str,
+        source: OccurrenceSource,
+        values: usize,
+        span: Span,
+    ) {
+        self.by_name
+            .entry(dictionary.resolve_name(tag))
+            .or_default()
+            .push(Occurrence {
+                tag: tag.to_string(),
+                source,
+                values,
+                span,
+            });
+    }
+
+    /// Occurrences of an item by canonical name (empty if absent)
+    pub fn get(&self, canonical: &str) -> &[Occurrence] {
+        self.by_name
+            .get(canonical)
+            .map(|v| v.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Iterate over `(canonical name, occurrences)` in name order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[Occurrence])> {
+        self.by_name
+            .iter()
+            .map(|(name, occurrences)| (name.as_str(), occurrences.as_slice()))
+    }
+
+    /// The different names an item is given under, as first written
+    ///
+    /// More than one means the block uses both the item's canonical name
+    /// and an alias, or two aliases, for the same item. Names differing only
+    /// in case count as one.
+    pub fn spellings(&self, canonical: &str) -> Vec<&str> {
+        let mut spellings: Vec<&str> = Vec::new();
+        for occurrence in self.get(canonical) {
+            if !spellings
+                .iter()
+                .any(|t| t.eq_ignore_ascii_case(&occurrence.tag))
+            {
+                spellings.push(&occurrence.tag);
+            }
+        }
+        spellings
+    }
+
+    /// Total number of values an item receives across all occurrences
+    pub fn value_count(&self, canonical: &str) -> usize {
+        self.get(canonical).iter().map(|o| o.values).sum()
+    }
+}
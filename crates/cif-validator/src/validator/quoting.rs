@@ -0,0 +1,136 @@
+//! Lints on how values were delimited in the source.
+//!
+//! The parser records each value's [`QuoteStyle`]. Three choices are worth a
+//! pedantic warning: text written bare that a reader (or a stricter parser)
+//! would tokenize differently, a short single-line value wrapped in a text
+//! field or triple quotes when plain quotes would do, and a simple word in
+//! quotes it does not need.
+
+use cif_parser::{CifValue, CifValueKind, QuoteStyle, Span};
+
+/// Words that end a value or start a new construct when written bare
+const RESERVED_PREFIXES: &[&str] = &["data_", "save_", "loop_", "global_", "stop_"];
+
+/// A quoting choice worth flagging
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum QuotingIssue {
+    /// Bare text that must be quoted to survive a round trip
+    NeedsQuotes { text: String, span: Span },
+    /// A single-line value written with heavier delimiters than needed
+    Heavyweight { style: QuoteStyle, span: Span },
+    /// A quoted value that reads the same written bare
+    Unneeded { text: String, span: Span },
+}
+
+/// Find quoting issues in a value and, for lists and tables, its elements.
+pub(crate) fn find_quoting_issues(value: &CifValue) -> Vec<QuotingIssue> {
+    value
+        .walk()
+        .filter_map(|(_, value)| quoting_issue(value))
+        .collect()
+}
+
+fn quoting_issue(value: &CifValue) -> Option<QuotingIssue> {
+    let CifValueKind::Text(text) = &value.kind else {
+        return None;
+    };
+    match value.quote {
+        QuoteStyle::Bare if needs_quotes(text) => Some(QuotingIssue::NeedsQuotes {
+            text: text.clone(),
+            span: value.span,
+        }),
+        style @ (QuoteStyle::TextField | QuoteStyle::TripleSingle | QuoteStyle::TripleDouble)
+            if fits_single_quotes(text) =>
+        {
+            Some(QuotingIssue::Heavyweight {
+                style,
+                span: value.span,
+            })
+        }
+        QuoteStyle::SingleQuote | QuoteStyle::DoubleQuote if reads_same_bare(text) => {
+            Some(QuotingIssue::Unneeded {
+                text: text.clone(),
+                span: value.span,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Whether text cannot be written as a single bare token.
+///
+/// Follows the CIF 1.1 rules for unquoted strings: no whitespace, and no
+/// leading `_`, `#`, `$`, quote, bracket or `;`, and no reserved word prefix.
+fn needs_quotes(text: &str) -> bool {
+    let Some(first) = text.chars().next() else {
+        return true;
+    };
+    if text.contains(char::is_whitespace) || "_#$'\"[];".contains(first) {
+        return true;
+    }
+    let lower = text.to_ascii_lowercase();
+    RESERVED_PREFIXES
+        .iter()
+        .any(|prefix| lower.starts_with(prefix))
+}
+
+/// Whether text written bare would read back as the same text.
+///
+/// Besides being a valid bare token, it must not read as a number, `?` or
+/// `.`, and must avoid quotes and brackets, which CIF 2.0 forbids anywhere in
+/// a bare token.
+fn reads_same_bare(text: &str) -> bool {
+    !needs_quotes(text)
+        && !text.contains(['\'', '"', '[', ']', '{', '}'])
+        && text != "?"
+        && text != "."
+        && CifValue::parse_number(text).is_none()
+}
+
+/// Whether text could be written with plain single or double quotes
+fn fits_single_quotes(text: &str) -> bool {
+    let both_quotes = text.contains('\'') && text.contains('"');
+    !text.contains(['\n', '\r']) && !both_quotes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_needs_quotes() {
+        assert!(needs_quotes("two words"));
+        assert!(needs_quotes("_looks_like_a_tag"));
+        assert!(needs_quotes("[a"));
+        assert!(needs_quotes("DATA_block"));
+        assert!(needs_quotes(""));
+
+        assert!(!needs_quotes("C1"));
+        assert!(!needs_quotes("P21/c"));
+        assert!(!needs_quotes("x_data_"));
+    }
+
+    #[test]
+    fn test_reads_same_bare() {
+        assert!(reads_same_bare("P21/c"));
+        assert!(reads_same_bare("yes"));
+
+        assert!(!reads_same_bare("1.5"));
+        assert!(!reads_same_bare("-2(1)"));
+        assert!(!reads_same_bare("?"));
+        assert!(!reads_same_bare("."));
+        assert!(!reads_same_bare("O'Brien"));
+        assert!(!reads_same_bare("a{b}"));
+        assert!(!reads_same_bare("two words"));
+    }
+
+    #[test]
+    fn test_fits_single_quotes() {
+        assert!(fits_single_quotes("Mo K\\a"));
+        assert!(fits_single_quotes("O'Brien"));
+        assert!(fits_single_quotes(""));
+
+        assert!(!fits_single_quotes("line one\nline two"));
+        assert!(!fits_single_quotes("it's \"both\""));
+    }
+}
@@ -0,0 +1,210 @@
+//! Heuristics for numeric values that were accidentally broken up.
+//!
+//! A number written with a digit-group separator (`1 000.5`, `1_000`) is not
+//! a CIF number. A space splits it into two values, which shifts every later
+//! value in the loop by one column; an underscore turns it into text. Both
+//! otherwise surface as a cascade of type errors that hide the real cause.
+
+use cif_parser::{CifLoop, CifValue, CifValueKind, Span};
+
+/// How a numeric value was broken up
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SplitKind {
+    /// Split by whitespace across two adjacent fields
+    AcrossFields,
+    /// Kept as one field but with embedded `_` separators
+    EmbeddedSeparator,
+}
+
+/// A numeric value that appears to have been broken up
+#[derive(Debug, Clone)]
+pub(crate) struct SplitNumber {
+    /// How the value was broken up
+    pub kind: SplitKind,
+    /// 1-based data row of the loop (source line for split fields)
+    pub row: usize,
+    /// Column the number was meant for
+    pub column: usize,
+    /// The value as written, e.g. `1 000.5`
+    pub text: String,
+    /// The number the value most likely stands for
+    pub joined: String,
+    /// Location of the broken-up value
+    pub span: Span,
+}
+
+/// Find broken-up numeric values in a loop.
+///
+/// `numeric_columns[i]` says whether the dictionary declares column `i` as
+/// numeric; other columns are never inspected.
+pub(crate) fn find_split_numbers(loop_: &CifLoop, numeric_columns: &[bool]) -> Vec<SplitNumber> {
+    let mut found = find_split_fields(loop_, numeric_columns);
+
+    for (row, values) in loop_.rows().enumerate() {
+        for (column, value) in values.iter().enumerate() {
+            if !numeric_columns.get(column).copied().unwrap_or(false) {
+                continue;
+            }
+            let CifValueKind::Text(text) = &value.kind else {
+                continue;
+            };
+            if let Some(joined) = join_separated_digits(text) {
+                found.push(SplitNumber {
+                    kind: SplitKind::EmbeddedSeparator,
+                    row: row + 1,
+                    column,
+                    text: text.clone(),
+                    joined,
+                    span: value.span,
+                });
+            }
+        }
+    }
+
+    found
+}
+
+/// Find source lines holding exactly one value too many where two adjacent
+/// fields join into a grouped number for a numeric column.
+fn find_split_fields(loop_: &CifLoop, numeric_columns: &[bool]) -> Vec<SplitNumber> {
+    let width = loop_.tags.len();
+    let lines = loop_.source_lines();
+
+    // Only trust the layout when the loop is mostly written one row per line;
+    // a single split leaves one long line and at most one short line behind
+    let aligned = lines.iter().filter(|line| line.len() == width).count();
+    if width == 0 || lines.len() - aligned > aligned.max(2) {
+        return Vec::new();
+    }
+
+    let mut found = Vec::new();
+    for (index, line) in lines.iter().enumerate() {
+        if line.len() != width + 1 {
+            continue;
+        }
+        for column in 0..width {
+            if !numeric_columns[column] {
+                continue;
+            }
+            let (left, right) = (line[column], line[column + 1]);
+            if let Some((text, joined)) = join_grouped_number(left, right) {
+                found.push(SplitNumber {
+                    kind: SplitKind::AcrossFields,
+                    row: index + 1,
+                    column,
+                    text,
+                    joined,
+                    span: left.span.merge(right.span),
+                });
+                break;
+            }
+        }
+    }
+
+    found
+}
+
+/// Join `1` and `000.5` into `1000.5` when they look like digit groups
+fn join_grouped_number(left: &CifValue, right: &CifValue) -> Option<(String, String)> {
+    let left_text = token_text(left)?;
+    let right_text = token_text(right)?;
+
+    let lead = left_text.trim_start_matches(['+', '-']);
+    if lead.is_empty() || lead.len() > 3 || !lead.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let group = right_text.split('.').next().unwrap_or_default();
+    if group.len() != 3 || !group.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let joined = format!("{}{}", left_text, right_text);
+    joined.parse::<f64>().ok()?;
+    Some((format!("{} {}", left_text, right_text), joined))
+}
+
+/// Join `1_000` into `1000` when every group between separators is digits
+fn join_separated_digits(text: &str) -> Option<String> {
+    if !text.contains('_') {
+        return None;
+    }
+    let unsigned = text.trim_start_matches(['+', '-']);
+    let integer_part = unsigned.split('.').next().unwrap_or_default();
+    let groups_are_digits = integer_part
+        .split('_')
+        .all(|group| !group.is_empty() && group.bytes().all(|b| b.is_ascii_digit()));
+    if !groups_are_digits {
+        return None;
+    }
+
+    let joined = text.replace('_', "");
+    joined.parse::<f64>().ok()?;
+    Some(joined)
+}
+
+/// Recover the source text of a single-line unquoted token.
+///
+/// Numbers are stored parsed, so the text is rebuilt from the value and the
+/// token width recorded in its span (which keeps leading zeros like `000.5`).
+fn token_text(value: &CifValue) -> Option<String> {
+    let span = value.span;
+    if span.start_line != span.end_line || span.end_col <= span.start_col {
+        return None;
+    }
+    let width = span.end_col - span.start_col;
+
+    match &value.kind {
+        CifValueKind::Text(text) if text.len() == width => Some(text.clone()),
+        CifValueKind::Numeric(n) => (0..width).find_map(|precision| {
+            let text = format!("{:0width$.precision$}", n);
+            (text.len() == width && text.parse::<f64>().ok() == Some(*n)).then_some(text)
+        }),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cif_parser::CifDocument;
+
+    fn first_loop(cif: &str) -> CifLoop {
+        let doc = CifDocument::parse(cif).unwrap();
+        doc.blocks[0].loops[0].clone()
+    }
+
+    #[test]
+    fn test_split_across_fields() {
+        let loop_ = first_loop("data_t\nloop_\n_a\n_b\nC1 1.5\nC2 1 000.5\nC3\n");
+        let found = find_split_numbers(&loop_, &[false, true]);
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].kind, SplitKind::AcrossFields);
+        assert_eq!(found[0].row, 2);
+        assert_eq!(found[0].text, "1 000.5");
+        assert_eq!(found[0].joined, "1000.5");
+    }
+
+    #[test]
+    fn test_adjacent_small_numbers_not_joined() {
+        let loop_ = first_loop("data_t\nloop_\n_a\n_b\n1 2\n3 4 5\n6\n");
+        assert!(find_split_numbers(&loop_, &[true, true]).is_empty());
+    }
+
+    #[test]
+    fn test_embedded_separator() {
+        let loop_ = first_loop("data_t\nloop_\n_a\n_b\nC1 1_000\n");
+        let found = find_split_numbers(&loop_, &[false, true]);
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].kind, SplitKind::EmbeddedSeparator);
+        assert_eq!(found[0].joined, "1000");
+    }
+
+    #[test]
+    fn test_text_column_ignored() {
+        let loop_ = first_loop("data_t\nloop_\n_a\n_b\nC_1 1.0\n");
+        assert!(find_split_numbers(&loop_, &[false, true]).is_empty());
+    }
+}
@@ -0,0 +1,103 @@
+//! Heuristic for text fields that swallowed CIF structure.
+//!
+//! A text field that loses its closing `;` runs on until the next line that
+//! starts with `;`, taking every item, loop and block in between with it.
+//! Nothing fails to parse; the swallowed items are simply missing. Lines
+//! inside a text value that look like CIF structure are the visible trace.
+
+use cif_parser::{CifValue, CifValueKind, Span};
+
+/// A line inside a text value that looks like CIF structure
+#[derive(Debug, Clone)]
+pub(crate) struct EmbeddedStructure {
+    /// The suspicious line, trimmed
+    pub line: String,
+    /// Location of the suspicious line in the source
+    pub span: Span,
+}
+
+/// Find the first line of a multi-line text value that looks like CIF structure.
+///
+/// A line is suspicious when it starts with `loop_` or `data_`, or with a
+/// `_tag` followed by a value on the same line.
+pub(crate) fn find_embedded_structure(value: &CifValue) -> Option<EmbeddedStructure> {
+    let CifValueKind::Text(text) = &value.kind else {
+        return None;
+    };
+    if !text.contains('\n') {
+        return None;
+    }
+
+    let (index, line) = text
+        .lines()
+        .enumerate()
+        .find(|(_, line)| looks_like_structure(line))?;
+
+    // Text field content is trimmed, so whether it began on the line of the
+    // opening `;` is recovered from the line count: the closing `;` sits on
+    // `end_line`, one line after the last content line.
+    let span = value.span;
+    let source_lines = span.end_line.saturating_sub(span.start_line);
+    let first_line = if text.lines().count() >= source_lines {
+        span.start_line
+    } else {
+        span.start_line + 1
+    };
+    let on_opening_line = index == 0 && first_line == span.start_line;
+
+    let trimmed = line.trim();
+    let indent = line.len() - line.trim_start().len();
+    let start_col = indent + 1 + usize::from(on_opening_line);
+    Some(EmbeddedStructure {
+        line: trimmed.to_string(),
+        span: Span::new(
+            first_line + index,
+            start_col,
+            first_line + index,
+            start_col + trimmed.len(),
+        ),
+    })
+}
+
+/// Whether a single line reads like a CIF item, loop or block header
+fn looks_like_structure(line: &str) -> bool {
+    let line = line.trim();
+    let lower = line.to_ascii_lowercase();
+    if lower == "loop_" || lower.starts_with("loop_ ") {
+        return true;
+    }
+    if lower.starts_with("data_") && lower.len() > "data_".len() {
+        return !lower.contains(char::is_whitespace);
+    }
+
+    let Some(rest) = line.strip_prefix('_') else {
+        return false;
+    };
+    let mut parts = rest.splitn(2, char::is_whitespace);
+    let name = parts.next().unwrap_or("");
+    let value = parts.next().map(str::trim).unwrap_or("");
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "_.-/[]()".contains(c))
+        && !value.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_structure_lines() {
+        assert!(looks_like_structure("_cell_length_b 11.2"));
+        assert!(looks_like_structure("  _atom_site.label   C1"));
+        assert!(looks_like_structure("loop_"));
+        assert!(looks_like_structure("LOOP_"));
+        assert!(looks_like_structure("data_next"));
+
+        assert!(!looks_like_structure("_cell_length_b"));
+        assert!(!looks_like_structure("Measured at 293 K"));
+        assert!(!looks_like_structure("data_ collection was slow"));
+        assert!(!looks_like_structure("_ 1"));
+    }
+}
@@ -42,6 +42,10 @@ pub enum JsErrorCategory {
     LinkError = 6,
     /// Dictionary loading/parsing error
     DictionaryError = 7,
+    /// Item given more values than its category allows
+    DuplicateItem = 8,
+    /// Mandatory or key item present with only unknown (`?`) values
+    UnknownMandatoryValue = 9,
 }
 
 impl From<ErrorCategory> for JsErrorCategory {
@@ -55,6 +59,8 @@ impl From<ErrorCategory> for JsErrorCategory {
             ErrorCategory::LoopStructure => JsErrorCategory::LoopStructure,
             ErrorCategory::LinkError => JsErrorCategory::LinkError,
             ErrorCategory::DictionaryError => JsErrorCategory::DictionaryError,
+            ErrorCategory::DuplicateItem => JsErrorCategory::DuplicateItem,
+            ErrorCategory::UnknownMandatoryValue => JsErrorCategory::UnknownMandatoryValue,
         }
     }
 }
@@ -71,6 +77,21 @@ pub enum JsWarningCategory {
     Style = 2,
     /// Unknown item in lenient mode
     UnknownItem = 3,
+    /// Same item appears in more than one loop
+    DuplicateItem = 4,
+    /// Text value contains lines that look like CIF structure
+    SuspiciousTextField = 5,
+    /// Mandatory or key item present with only unknown (`?`) values
+    UnknownMandatoryValue = 6,
+    /// Mandatory or key item given as not applicable (`.`), in pedantic mode
+    NotApplicableMandatory = 7,
+    /// Set-category items written as a one-row loop
+    LoopedSetCategory = 8,
+    /// Pointer to a data block that is not in the document
+    DanglingBlockLink = 9,
+    /// Item whose content type is implied and so was not checked, in
+    /// pedantic mode
+    ImpliedType = 10,
 }
 
 impl From<WarningCategory> for JsWarningCategory {
@@ -80,6 +101,13 @@ impl From<WarningCategory> for JsWarningCategory {
             WarningCategory::DeprecatedItem => JsWarningCategory::DeprecatedItem,
             WarningCategory::Style => JsWarningCategory::Style,
             WarningCategory::UnknownItem => JsWarningCategory::UnknownItem,
+            WarningCategory::DuplicateItem => JsWarningCategory::DuplicateItem,
+            WarningCategory::SuspiciousTextField => JsWarningCategory::SuspiciousTextField,
+            WarningCategory::UnknownMandatoryValue => JsWarningCategory::UnknownMandatoryValue,
+            WarningCategory::NotApplicableMandatory => JsWarningCategory::NotApplicableMandatory,
+            WarningCategory::LoopedSetCategory => JsWarningCategory::LoopedSetCategory,
+            WarningCategory::DanglingBlockLink => JsWarningCategory::DanglingBlockLink,
+            WarningCategory::ImpliedType => JsWarningCategory::ImpliedType,
         }
     }
 }
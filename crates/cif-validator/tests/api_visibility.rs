@@ -0,0 +1,50 @@
+// tests/api_visibility.rs
+// The prelude covers validation, typed access, and custom conversions.
+// Compile-fail checks for non-exhaustive enums live in `tests/ui` (see compile_fail.rs).
+
+use cif_validator::prelude::*;
+
+// FromCifValue is an open extension point
+struct Kelvin(f64);
+
+impl FromCifValue for Kelvin {
+    fn from_cif_value(value: &CifValue) -> Option<Self> {
+        value.as_numeric().map(Kelvin)
+    }
+}
+
+fn describe(category: ErrorCategory) -> &'static str {
+    match category {
+        ErrorCategory::TypeError => "type",
+        ErrorCategory::UnknownDataName => "unknown",
+        _ => "other",
+    }
+}
+
+#[test]
+fn test_prelude_covers_common_workflow() {
+    let dictionary =
+        "#\\#CIF_2.0\ndata_D\nsave_x\n_definition.id '_x'\n_type.contents Real\nsave_\n";
+    let validator = Validator::new()
+        .with_dictionary_str(dictionary)
+        .expect("dictionary loads");
+
+    let doc: CifDocument = parse_string("data_t\n_x 1\n").unwrap();
+    let result: ValidationResult = validator.validate(&doc).unwrap();
+    assert!(result.errors.iter().all(|e| describe(e.category) != "type"));
+
+    let doc = parse_string("data_t\n_x one\n").unwrap();
+    let result = validator.validate(&doc).unwrap();
+    assert_eq!(describe(result.errors[0].category), "type");
+
+    // Without a dictionary the validator reports an error rather than a result
+    assert!(Validator::new().validate(&doc).is_err());
+
+    let t = Kelvin::from_cif_value(&CifValue::parse_value("293")).unwrap();
+    assert_eq!(t.0, 293.0);
+
+    let _: ContentType = ContentType::Real;
+    let _: Option<DdlFlavor> = None;
+    let _: Option<Dictionary> = None;
+    let _: ValidationMode = ValidationMode::Strict;
+}
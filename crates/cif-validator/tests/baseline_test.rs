@@ -0,0 +1,97 @@
+//! Integration tests for suppressing known findings with a baseline
+
+mod common;
+
+use cif_parser::CifDocument;
+use cif_validator::{Baseline, ErrorCategory, Finding, ValidationResult, Validator};
+use common::fixture_path;
+
+fn fixture(name: &str) -> String {
+    std::fs::read_to_string(fixture_path(&format!("validation/{name}")))
+        .expect("Failed to read fixture")
+}
+
+fn validate(content: &str, baseline: Option<Baseline>) -> ValidationResult {
+    let mut validator = Validator::new()
+        .with_dictionary_file(&fixture_path("validation/test_validation.dic"))
+        .expect("Failed to load dictionary");
+    if let Some(baseline) = baseline {
+        validator = validator.with_baseline(baseline);
+    }
+    let doc = CifDocument::parse(content).expect("Failed to parse CIF");
+    validator.validate(&doc).expect("Validation failed")
+}
+
+#[test]
+fn test_baseline_reports_only_new_findings() {
+    let original = fixture("invalid_structure.cif");
+    let before = validate(&original, None);
+    assert_eq!(before.errors.len(), 9);
+    let baseline = before.to_baseline();
+    assert_eq!(baseline.len(), 9);
+
+    // A new error near the top moves every existing finding to a new line
+    let edited = original.replace(
+        "# Entry metadata\n",
+        "# Entry metadata\n# Added later\n# (moves every later line down)\n_cell.angle_gamma 500\n",
+    );
+    let edited = edited.replace("_cell.angle_gamma                90.0\n", "");
+    let after = validate(&edited, Some(baseline));
+
+    assert_eq!(after.errors.len(), 1, "errors: {:?}", after.errors);
+    let new = &after.errors[0];
+    assert_eq!(new.category, ErrorCategory::RangeError);
+    assert_eq!(new.data_name.as_deref(), Some("_cell.angle_gamma"));
+    assert_eq!(new.block.as_deref(), Some("invalid_structure"));
+    assert_eq!(
+        after
+            .suppressed
+            .iter()
+            .filter(|f| matches!(f, Finding::Error(_)))
+            .count(),
+        9
+    );
+    assert_eq!(after.baseline_unmatched, 0);
+    assert!(!after.is_valid);
+}
+
+#[test]
+fn test_baseline_counts_fixed_findings() {
+    let original = fixture("invalid_structure.cif");
+    let baseline = validate(&original, None).to_baseline();
+
+    let fixed = original
+        .replace(
+            "_cell.length_a                   -5.0",
+            "_cell.length_a 5.0",
+        )
+        .replace(
+            "_symmetry.crystal_system         dodecahedral",
+            "_symmetry.crystal_system cubic",
+        );
+    let result = validate(&fixed, Some(baseline));
+
+    assert!(result.errors.is_empty(), "errors: {:?}", result.errors);
+    assert!(result.is_valid);
+    assert_eq!(result.suppressed.len(), 7);
+    assert_eq!(result.baseline_unmatched, 2);
+
+    // Re-baselining drops the fixed findings
+    assert_eq!(result.to_baseline().len(), 7);
+}
+
+#[test]
+fn test_baseline_save_and_load() {
+    let baseline = validate(&fixture("invalid_structure.cif"), None).to_baseline();
+    let path = std::env::temp_dir().join(format!(
+        "cif-validator-baseline-{}.json",
+        std::process::id()
+    ));
+    baseline.save(&path).expect("Failed to save baseline");
+    let loaded = Baseline::load(&path).expect("Failed to load baseline");
+    std::fs::remove_file(&path).ok();
+
+    assert_eq!(loaded, baseline);
+    assert!(Baseline::from_json("{\"version\": 99, \"entries\": []}").is_err());
+    assert!(Baseline::from_json("not json").is_err());
+}
@@ -0,0 +1,53 @@
+//! Integration tests for warnings about pointers to missing data blocks
+
+mod common;
+
+use cif_parser::CifDocument;
+use cif_validator::{ValidationMode, WarningCategory};
+use common::{fixture_path, validator};
+
+#[test]
+fn test_dangling_powder_link_warns_once() {
+    let doc = CifDocument::from_file(fixture_path("powder_links.cif")).unwrap();
+
+    for mode in [
+        ValidationMode::Strict,
+        ValidationMode::Lenient,
+        ValidationMode::Pedantic,
+    ] {
+        let result = validator(&fixture_path("validation/test_validation.dic"))
+            .with_mode(mode)
+            .validate(&doc)
+            .unwrap();
+        let dangling: Vec<_> = result
+            .warnings
+            .iter()
+            .filter(|w| w.category == WarningCategory::DanglingBlockLink)
+            .collect();
+
+        assert_eq!(dangling.len(), 1, "{mode:?}: {dangling:?}");
+        let warning = dangling[0];
+        assert_eq!(
+            warning.message,
+            "'_pd_block_diffractogram_id' points to block \
+             '2023-05-12T09:40|CORUNDUM_NPD|A.Smith|POLARIS', which is not in this document"
+        );
+        assert_eq!(warning.span.start_line, 20);
+        assert_eq!(warning.block.as_deref(), Some("corundum_phase"));
+    }
+}
+
+#[test]
+fn test_resolved_links_do_not_warn() {
+    let cif = "data_a\n_audit_link_block_code b\ndata_b\n_audit_link_block_code .\n";
+    let doc = CifDocument::parse(cif).unwrap();
+    let result = validator(&fixture_path("validation/test_validation.dic"))
+        .with_mode(ValidationMode::Lenient)
+        .validate(&doc)
+        .unwrap();
+
+    assert!(result
+        .warnings
+        .iter()
+        .all(|w| w.category != WarningCategory::DanglingBlockLink));
+}
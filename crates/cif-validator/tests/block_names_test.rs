@@ -0,0 +1,43 @@
+//! Integration tests for case-insensitive block and frame names
+
+mod common;
+
+use cif_parser::CifDocument;
+use cif_validator::dictionary::load_dictionary;
+use cif_validator::{load_dictionary_file, Validator};
+use common::fixture_path;
+
+#[test]
+fn test_validated_block_lookup_ignores_case() {
+    let dict = load_dictionary_file(&fixture_path("validation/methods.dic"))
+        .expect("Failed to load dictionary");
+    let doc = CifDocument::parse("data_Geom\n_cell_geom.length_a 5.0\n").unwrap();
+    let cif = Validator::new()
+        .with_dictionary(dict)
+        .validate_typed(doc)
+        .expect("Failed to validate");
+
+    let block = cif.block("GEOM").unwrap();
+    assert_eq!(block.name(), "Geom");
+    let a = cif.get_typed::<f64>("geom", "_cell_geom.length_a").unwrap();
+    assert_eq!(a.value, 5.0);
+    assert!(cif.block("Geom2").is_none());
+}
+
+#[test]
+fn test_dictionary_frames_in_any_case() {
+    let doc = CifDocument::parse(
+        "#\\#CIF_2.0\ndata_Mixed_Dic\n\
+         SAVE_Cell\n_definition.id CELL\n_definition.scope CATEGORY\n\
+         _definition.class Set\nSave_\n\
+         save_CELL.Length_A\n_definition.id '_cell.length_a'\n\
+         _name.category_id cell\n_type.contents Real\nsave_\n",
+    )
+    .unwrap();
+    assert_eq!(doc.blocks[0].frames[0].name.as_str(), "Cell");
+    assert!(doc.blocks[0].get_frame("cell.length_a").is_some());
+
+    let dict = load_dictionary(&doc).expect("Failed to load dictionary");
+    assert!(dict.get_category("Cell").is_some());
+    assert_eq!(&*dict.get_item("_CELL.LENGTH_A").unwrap().category, "cell");
+}
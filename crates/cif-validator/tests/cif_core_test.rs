@@ -1,7 +1,7 @@
 //! Integration tests using the real cif_core.dic dictionary
 
 use cif_parser::CifDocument;
-use cif_validator::{load_dictionary_file, ValidationMode, Validator};
+use cif_validator::{load_dictionary_file, ErrorCategory, ValidationMode, Validator};
 
 const DICT_PATH: &str = "dics/cif_core.dic";
 
@@ -157,3 +157,33 @@ _diffrn.ambient_pressure 101.325
         cif_validator::ContentType::Real
     );
 }
+
+#[test]
+fn test_signed_and_dotted_numbers_type_check() {
+    let validator = Validator::new()
+        .with_dictionary_file(DICT_PATH)
+        .expect("Failed to load dictionary");
+
+    let type_errors = |cif: &str| {
+        let doc = CifDocument::parse(cif).expect("Failed to parse CIF");
+        let result = validator.validate(&doc).expect("Validation failed");
+        result
+            .errors
+            .into_iter()
+            .filter(|e| e.category == ErrorCategory::TypeError)
+            .map(|e| e.message)
+            .collect::<Vec<_>>()
+    };
+
+    // A leading '+' is part of a CIF integer, quoted or not
+    let cif = "data_t\n_space_group.IT_number +3\n_diffrn_reflns.number '+3'\n";
+    assert!(type_errors(cif).is_empty(), "{:?}", type_errors(cif));
+
+    let cif = "data_t\n_cell.volume .5(2)\n_exptl_crystal.density_diffrn +5.(3)\n\
+               _cell.formula_units_Z 5.e1\n";
+    assert!(type_errors(cif).is_empty(), "{:?}", type_errors(cif));
+
+    // `f64::from_str` accepts these, CIF does not
+    assert_eq!(type_errors("data_t\n_cell.volume 'inf'\n").len(), 1);
+    assert_eq!(type_errors("data_t\n_space_group.IT_number nan\n").len(), 1);
+}
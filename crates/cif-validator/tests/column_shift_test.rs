@@ -0,0 +1,109 @@
+//! Integration tests for loop values shifted out of their columns
+
+mod common;
+
+use cif_parser::CifDocument;
+use cif_validator::{ErrorCategory, Fingerprint};
+use common::{fixture_path, validator, CORE_DICT};
+
+#[test]
+fn test_missing_value_single_diagnostic() {
+    let doc = CifDocument::from_file(fixture_path("validation/loop_shifted_column.cif"))
+        .expect("Failed to parse fixture");
+    let result = validator(CORE_DICT)
+        .validate(&doc)
+        .expect("Validation failed");
+
+    assert_eq!(result.errors.len(), 1, "errors: {:?}", result.errors);
+    let error = &result.errors[0];
+    assert_eq!(error.category, ErrorCategory::LoopStructure);
+    assert_eq!(
+        error.message,
+        "Loop values appear shifted by 1 column starting at row 3; \
+         a value is likely missing near line 27"
+    );
+    assert_eq!((error.span.start_line, error.span.start_col), (27, 1));
+    assert_eq!(
+        error.suggestions,
+        vec!["Check the values from line 27 on against the loop's 9 tags"]
+    );
+}
+
+#[test]
+fn test_extra_value_single_diagnostic() {
+    let cif = "data_extra
+loop_
+_atom_site.label
+_atom_site.type_symbol
+_atom_site.U_iso_or_equiv
+_atom_site.adp_type
+_atom_site.occupancy
+_atom_site.calc_flag
+C1 C 0.021 Uani 1 d
+C2 C 0.023 0.023 Uani 1 d
+C3 C 0.025 Uani 1 d
+C4 C 0.022 Uani 1 d
+N1 N 0.019 Uani 1 d
+N2 N 0.020 Uani 1
+";
+    let doc = CifDocument::parse(cif).unwrap();
+    let result = validator(CORE_DICT).validate(&doc).unwrap();
+
+    assert_eq!(result.errors.len(), 1, "errors: {:?}", result.errors);
+    assert_eq!(
+        result.errors[0].message,
+        "Loop values appear shifted back by 1 column starting at row 2; \
+         an extra value is likely near line 10"
+    );
+}
+
+#[test]
+fn test_shift_fingerprint_survives_lines_above() {
+    let body = "loop_
+_atom_site.label
+_atom_site.type_symbol
+_atom_site.U_iso_or_equiv
+_atom_site.adp_type
+C1 C 0.021 Uani
+C2 C 0.023 0.023 Uani
+C3 C 0.025 Uani
+C4 C 0.022 Uani
+N1 N 0.019 Uani
+N2 N 0.020
+";
+    let fingerprint = |cif: &str| {
+        let doc = CifDocument::parse(cif).unwrap();
+        let result = validator(CORE_DICT).validate(&doc).unwrap();
+        assert_eq!(result.errors.len(), 1, "errors: {:?}", result.errors);
+        Fingerprint::of_error(&result.errors[0])
+    };
+
+    let before = fingerprint(&format!("data_moved\n{body}"));
+    let after = fingerprint(&format!("data_moved\n# Added later\n\n{body}"));
+    assert_eq!(before, after);
+}
+
+#[test]
+fn test_scattered_type_errors_reported_per_cell() {
+    let cif = "data_scattered
+loop_
+_atom_site.label
+_atom_site.occupancy
+_atom_site.U_iso_or_equiv
+_atom_site.attached_hydrogens
+C1 1 0.02 0
+C2 abc 0.02 0
+C3 1 0.02 0
+C4 1 xyz 0
+C5 1 0.02 0
+C6 1 0.02 pqr
+";
+    let doc = CifDocument::parse(cif).unwrap();
+    let result = validator(CORE_DICT).validate(&doc).unwrap();
+
+    assert!(result
+        .errors
+        .iter()
+        .all(|e| e.category == ErrorCategory::TypeError));
+    assert_eq!(result.errors.len(), 3, "errors: {:?}", result.errors);
+}
@@ -0,0 +1,21 @@
+//! Helpers shared by the validator integration tests
+
+// Each test binary compiles its own copy and uses only some of these
+#![allow(dead_code)]
+
+use cif_validator::Validator;
+
+/// The CIF core dictionary, relative to the crate directory
+pub const CORE_DICT: &str = "dics/cif_core.dic";
+
+/// Path of a file in the project root fixtures/ directory
+pub fn fixture_path(name: &str) -> String {
+    format!("{}/../../fixtures/{}", env!("CARGO_MANIFEST_DIR"), name)
+}
+
+/// A validator over the dictionary at `path`, in the default mode
+pub fn validator(path: &str) -> Validator {
+    Validator::new()
+        .with_dictionary_file(path)
+        .expect("Failed to load dictionary")
+}
@@ -0,0 +1,11 @@
+//! Compile-fail tests of the public API: matches on non-exhaustive enums
+//! without a wildcard arm, and writes to fields kept private, must not
+//! compile. Each case in `tests/ui` has the
+//! compiler output it is expected to give; regenerate it with
+//! `TRYBUILD=overwrite cargo test -p cif-validator --test compile_fail`.
+
+#[test]
+fn test_api_visibility_compile_failures() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
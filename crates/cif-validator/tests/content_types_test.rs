@@ -0,0 +1,117 @@
+//! Integration tests for union, by-reference and implied content types
+
+use cif_parser::CifDocument;
+use cif_validator::{
+    load_dictionary_file, ContentType, ErrorCategory, ValidationMode, ValidationResult, Validator,
+    WarningCategory,
+};
+
+const DICT_PATH: &str = "../../fixtures/validation/content_types.dic";
+
+fn validate(cif: &str, mode: ValidationMode) -> ValidationResult {
+    let doc = CifDocument::parse(cif).expect("Failed to parse CIF");
+    Validator::new()
+        .with_dictionary_file(DICT_PATH)
+        .expect("Failed to load dictionary")
+        .with_mode(mode)
+        .validate(&doc)
+        .expect("Validation failed")
+}
+
+#[test]
+fn test_union_members_loaded() {
+    let dict = load_dictionary_file(DICT_PATH).expect("Failed to load dictionary");
+    let delay = &dict.get_item("_content_test.delay").unwrap().type_info;
+
+    assert!(delay.content_types.is_union());
+    assert_eq!(delay.content_types.to_string(), "Integer,Code");
+    assert_eq!(delay.contents, ContentType::Integer);
+}
+
+#[test]
+fn test_union_accepts_any_member() {
+    for value in ["5", "now"] {
+        let result = validate(
+            &format!("data_test\n_content_test.delay {}\n", value),
+            ValidationMode::Strict,
+        );
+        assert!(result.is_valid, "{}: {:?}", value, result.errors);
+    }
+}
+
+#[test]
+fn test_union_rejects_value_no_member_accepts() {
+    let result = validate(
+        "data_test\n_content_test.delay 'two words'\n",
+        ValidationMode::Strict,
+    );
+
+    assert_eq!(result.errors.len(), 1, "{:?}", result.errors);
+    let error = &result.errors[0];
+    assert_eq!(error.category, ErrorCategory::TypeError);
+    assert_eq!(error.expected.as_deref(), Some("integer or code"));
+}
+
+#[test]
+fn test_by_reference_takes_target_type() {
+    let dict = load_dictionary_file(DICT_PATH).expect("Failed to load dictionary");
+    for name in [
+        "_content_test.temperature_copy",
+        "_content_test.temperature_copy_copy",
+    ] {
+        let type_info = &dict.get_item(name).unwrap().type_info;
+        assert_eq!(type_info.contents, ContentType::ByReference);
+        assert!(
+            type_info.content_types.contains(ContentType::Real),
+            "{}",
+            name
+        );
+    }
+
+    let valid = validate(
+        "data_test\n_content_test.temperature_copy 293.5(2)\n",
+        ValidationMode::Strict,
+    );
+    assert!(valid.is_valid, "{:?}", valid.errors);
+
+    let invalid = validate(
+        "data_test\n_content_test.temperature_copy_copy warm\n",
+        ValidationMode::Strict,
+    );
+    assert_eq!(invalid.errors.len(), 1, "{:?}", invalid.errors);
+    assert_eq!(invalid.errors[0].category, ErrorCategory::TypeError);
+    assert_eq!(invalid.errors[0].expected.as_deref(), Some("real number"));
+}
+
+#[test]
+fn test_implied_is_not_type_checked() {
+    let cif = "data_test\n_content_test.setting 'anything at all'\n";
+
+    let strict = validate(cif, ValidationMode::Strict);
+    assert!(strict.is_valid, "{:?}", strict.errors);
+    assert!(strict.warnings.is_empty(), "{:?}", strict.warnings);
+
+    let pedantic = validate(cif, ValidationMode::Pedantic);
+    assert!(pedantic.is_valid, "{:?}", pedantic.errors);
+    let notes: Vec<_> = pedantic
+        .warnings
+        .iter()
+        .filter(|w| w.category == WarningCategory::ImpliedType)
+        .collect();
+    assert_eq!(notes.len(), 1, "{:?}", pedantic.warnings);
+    assert!(notes[0].message.contains("'_content_test.setting'"));
+}
+
+#[test]
+fn test_implied_noted_once_per_block() {
+    let cif = "data_a\nloop_\n_content_test.setting\n1\n2\n3\n\
+               data_b\n_content_test.setting x\n";
+    let result = validate(cif, ValidationMode::Pedantic);
+
+    let notes = result
+        .warnings
+        .iter()
+        .filter(|w| w.category == WarningCategory::ImpliedType)
+        .count();
+    assert_eq!(notes, 2, "{:?}", result.warnings);
+}
@@ -0,0 +1,62 @@
+//! Integration tests for locating category loops through a dictionary for CSV export
+
+use cif_parser::export::{csv_to_loop, CsvOptions};
+use cif_parser::CifDocument;
+use cif_validator::Dictionary;
+
+fn core_dictionary() -> Dictionary {
+    cif_validator::load_dictionary_file("dics/cif_core.dic").expect("Failed to load dictionary")
+}
+
+const ATOMS: &str = "\
+data_test
+loop_
+_atom_site_aniso_label
+_atom_site_aniso_U_11
+C1 0.021(2)
+loop_
+_atom_site_label
+_atom_site_fract_x
+_atom_site_occupancy
+C1 0.1234(5) ?
+N1 0.5 1
+";
+
+#[test]
+fn test_dictionary_resolves_legacy_category() {
+    let dict = core_dictionary();
+    let doc = CifDocument::parse(ATOMS).unwrap();
+    let block = doc.first_block().unwrap();
+
+    let atoms = dict.find_category_loop(block, "atom_site").unwrap();
+    assert_eq!(atoms.tags[0], "_atom_site_label");
+    let aniso = dict.find_category_loop(block, "ATOM_SITE_ANISO").unwrap();
+    assert_eq!(aniso.tags[0], "_atom_site_aniso_label");
+    assert!(dict.find_category_loop(block, "refln").is_none());
+
+    // Without a dictionary the prefix also matches the aniso loop
+    let by_prefix = cif_parser::export::category_to_csv(block, "atom_site", &CsvOptions::new());
+    assert!(by_prefix.unwrap().starts_with("_atom_site_aniso_label,"));
+}
+
+#[test]
+fn test_dictionary_category_to_csv() {
+    let dict = core_dictionary();
+    let doc = CifDocument::parse(ATOMS).unwrap();
+    let block = doc.first_block().unwrap();
+
+    let options = CsvOptions::new().strip_category_prefix(true);
+    let csv = dict.category_to_csv(block, "atom_site", &options).unwrap();
+    assert_eq!(
+        csv,
+        "label,fract_x,occupancy\r\nC1,0.1234(5),\r\nN1,0.5,1\r\n"
+    );
+
+    let tags = &dict.find_category_loop(block, "atom_site").unwrap().tags;
+    let back = csv_to_loop(tags, &csv).unwrap();
+    assert_eq!(
+        back.get(0, 1).unwrap().as_numeric_with_uncertainty(),
+        Some((0.1234, 0.0005))
+    );
+    assert!(back.get(0, 2).unwrap().is_unknown());
+}
@@ -0,0 +1,280 @@
+//! Integration tests for computing missing values with dictionary methods
+
+mod common;
+
+use cif_parser::{CifDocument, CifValueKind};
+use cif_validator::derive::{Value, PROVENANCE_ITEM};
+use cif_validator::{DerivedCif, FailureReason, ValidatedCif, ValidationMode, Validator};
+use common::{fixture_path, validator};
+
+fn validated(validator: &Validator, cif: &str) -> ValidatedCif {
+    let doc = CifDocument::parse(cif).unwrap();
+    validator.validate_typed(doc).unwrap()
+}
+
+fn number(value: &Value) -> f64 {
+    value.as_number().expect("a number")
+}
+
+/// The dREL paper's example: cell volume and density from the cell
+/// parameters and the atom types, through the methods of `cif_core`
+#[test]
+fn test_volume_and_density_from_cif_core() {
+    let validator = Validator::new()
+        .with_dictionary_file("dics/cif_core.dic")
+        .expect("Failed to load cif_core.dic")
+        .with_mode(ValidationMode::Strict);
+    let cif = "data_nacl
+_cell.length_a 5.6402
+_cell.length_b 5.6402
+_cell.length_c 5.6402
+_cell.angle_alpha 90
+_cell.angle_beta 90
+_cell.angle_gamma 90
+loop_
+_atom_type.symbol
+_atom_type.number_in_cell
+_atom_type.atomic_mass
+Na 4 22.990
+Cl 4 35.453
+
+data_triclinic
+_cell_length_a 5.1
+_cell_length_b 6.2
+_cell_length_c 7.3
+_cell_angle_alpha 81.0
+_cell_angle_beta 95.5
+_cell_angle_gamma 103.2
+";
+    let validated = validated(&validator, cif);
+    assert!(validated.is_valid());
+
+    let targets = ["_cell.volume", "_exptl_crystal.density_diffrn"];
+    let result = DerivedCif::compute(&validated, &targets);
+
+    let volume = number(&result.get("nacl", "_cell.volume").unwrap().value);
+    assert!((volume - 5.6402_f64.powi(3)).abs() < 1e-9, "{volume}");
+    let density = result.get("nacl", "_exptl_crystal.density_diffrn").unwrap();
+    let expected = 1.6605 * 4.0 * (22.990 + 35.453) / volume;
+    assert!((number(&density.value) - expected).abs() < 1e-9);
+    assert_eq!(density.method, "_exptl_crystal.density_diffrn");
+    let inputs: Vec<&str> = density.inputs.iter().map(|i| i.name.as_str()).collect();
+    assert_eq!(inputs, ["_cell.atomic_mass", "_cell.volume"]);
+
+    // The general volume formula, from legacy tags
+    let [a, b, c] = [5.1, 6.2, 7.3];
+    let [cos_a, cos_b, cos_g] = [81.0_f64, 95.5, 103.2].map(|x| x.to_radians().cos());
+    let expected = a
+        * b
+        * c
+        * (1.0 - cos_a * cos_a - cos_b * cos_b - cos_g * cos_g + 2.0 * cos_a * cos_b * cos_g)
+            .sqrt();
+    let volume = number(&result.get("triclinic", "_cell.volume").unwrap().value);
+    assert!((volume - expected).abs() < 1e-9, "{volume} vs {expected}");
+    // The triclinic block has no atom types to weigh
+    assert_eq!(result.failures.len(), 1);
+    assert_eq!(result.failures[0].block, "triclinic");
+    assert_eq!(
+        result.failures[0].reason,
+        FailureReason::MissingInput {
+            item: "atom_type".to_string()
+        }
+    );
+
+    // Only the targets are written, and the document still validates
+    let mut doc = validated.document().clone();
+    assert_eq!(result.apply(&mut doc), 3);
+    let block = doc.get_block("nacl").unwrap();
+    assert_eq!(
+        block.items["_cell.volume"].as_numeric(),
+        Some(volume_of(&result, "nacl"))
+    );
+    assert!(block.get_item("_cell.vector_a").is_none());
+    assert_eq!(
+        block.items[PROVENANCE_ITEM].as_string(),
+        Some("Computed from dictionary methods: _cell.volume, _exptl_crystal.density_diffrn")
+    );
+    let revalidated = validator.validate(&doc).unwrap();
+    assert!(revalidated.is_valid, "{:?}", revalidated.errors);
+    assert!(
+        revalidated.warnings.is_empty(),
+        "{:?}",
+        revalidated.warnings
+    );
+}
+
+fn volume_of(result: &cif_validator::DerivationResult, block: &str) -> f64 {
+    number(&result.get(block, "_cell.volume").unwrap().value)
+}
+
+#[test]
+fn test_values_record_their_method_and_inputs() {
+    let cif = "data_s
+_sample.mass 12.0
+_sample.volume 4.0
+loop_
+_component.id
+_component.mass
+a 5.0
+b 7.0
+c -1.0
+";
+    let validated = validated(&validator(&fixture_path("validation/derivation.dic")), cif);
+    let result = DerivedCif::compute(
+        &validated,
+        &[
+            "_sample.specific_volume",
+            "_SAMPLE.TOTAL_MASS",
+            "_sample.mass",
+        ],
+    );
+    assert!(result.is_complete(), "{:?}", result.failures);
+
+    // The density is computed first, for the specific volume
+    let names: Vec<&str> = result.values.iter().map(|v| v.name.as_str()).collect();
+    assert_eq!(
+        names,
+        [
+            "_sample.density",
+            "_sample.specific_volume",
+            "_sample.total_mass"
+        ]
+    );
+    let density = &result.values[0];
+    assert!(!density.requested);
+    assert_eq!(density.value, Value::Number(3.0));
+    assert_eq!(density.inputs.len(), 2);
+    assert_eq!(density.inputs[0].name, "_sample.mass");
+    let span = density.inputs[0].span.unwrap();
+    assert_eq!((span.start_line, span.start_col), (2, 14));
+    let def = validated.dictionary().get_item("_sample.density").unwrap();
+    assert_eq!(density.method_span, def.span);
+
+    let specific = &result.values[1];
+    assert!(specific.requested);
+    assert!((number(&specific.value) - 1.0 / 3.0).abs() < 1e-12);
+    assert_eq!(specific.inputs[0].span, None);
+
+    // The Where condition reads every packet, the sum only those passing it
+    let total = &result.values[2];
+    assert_eq!(total.value, Value::Number(12.0));
+    let rows: Vec<usize> = total
+        .inputs
+        .iter()
+        .map(|input| input.span.unwrap().start_line)
+        .collect();
+    assert_eq!(rows, [7, 8, 9]);
+    assert_eq!(result.requested().count(), 2);
+}
+
+#[test]
+fn test_failures_give_their_reason() {
+    let cif = "data_s\n_sample.mass 12.0\n_sample.volume 0.0\n";
+    let validated = validated(&validator(&fixture_path("validation/derivation.dic")), cif);
+    let targets = [
+        "_sample.density",
+        "_sample.specific_volume",
+        "_sample.total_mass",
+        "_sample.first_mass",
+        "_sample.ping",
+        "_component.id",
+        "_sample.unknown",
+    ];
+    let result = DerivedCif::compute(&validated, &targets);
+    assert!(result.values.is_empty());
+    let reasons: Vec<(&str, &FailureReason)> = result
+        .failures
+        .iter()
+        .map(|failure| (failure.item.as_str(), &failure.reason))
+        .collect();
+    assert_eq!(reasons.len(), targets.len());
+
+    let FailureReason::Evaluation {
+        method,
+        message,
+        span,
+    } = reasons[0].1
+    else {
+        panic!("{:?}", reasons[0]);
+    };
+    assert_eq!(method, "_sample.density");
+    assert_eq!(message, "division by zero");
+    // Lines count from the start of the method
+    assert_eq!(span.start_line, 3);
+    // The failure is passed on to what needed the value
+    assert_eq!(reasons[1].1, reasons[0].1);
+    assert_eq!(
+        reasons[2].1,
+        &FailureReason::MissingInput {
+            item: "component".to_string()
+        }
+    );
+    let FailureReason::Unsupported { construct, .. } = reasons[3].1 else {
+        panic!("{:?}", reasons[3]);
+    };
+    assert_eq!(construct, "a category key lookup");
+    assert_eq!(
+        reasons[4].1,
+        &FailureReason::Cycle {
+            items: vec![
+                "_sample.ping".to_string(),
+                "_sample.pong".to_string(),
+                "_sample.ping".to_string()
+            ]
+        }
+    );
+    assert_eq!(reasons[5].1, &FailureReason::NoMethod);
+    assert_eq!(reasons[6], ("_sample.unknown", &FailureReason::UnknownItem));
+
+    // Without a volume, the volume is the missing input
+    let validated = self::validated(
+        &validator(&fixture_path("validation/derivation.dic")),
+        "data_s\n_sample.mass 12.0\n",
+    );
+    let result = DerivedCif::compute(&validated, &["_sample.specific_volume"]);
+    assert_eq!(
+        result.failures[0].reason.to_string(),
+        "_sample.volume is missing and cannot be computed"
+    );
+}
+
+#[test]
+fn test_apply_replaces_placeholders_and_notes_the_items() {
+    let cif = "data_s
+_sample.mass 12.0
+_sample.volume 4.0
+_sample_density ?
+_audit.update_record 'Collected by hand'
+";
+    let validated = validated(&validator(&fixture_path("validation/derivation.dic")), cif);
+    let result = DerivedCif::compute(&validated, &["_sample.specific_volume", "_sample_density"]);
+    assert_eq!(result.requested().count(), 2);
+    assert_eq!(
+        result.get("S", "_sample.density").unwrap().tag,
+        "_sample_density"
+    );
+
+    let mut doc = validated.document().clone();
+    assert_eq!(result.apply(&mut doc), 2);
+    let block = &doc.blocks[0];
+    let density = &block.items["_sample_density"];
+    assert_eq!(density.kind, CifValueKind::Numeric(3.0));
+    assert_eq!(density.span.start_line, 4);
+    assert!(block.get_item("_sample.density").is_none());
+    assert_eq!(
+        block.items[PROVENANCE_ITEM].as_string(),
+        Some(
+            "Collected by hand\n\
+             Computed from dictionary methods: _sample.density, _sample.specific_volume"
+        )
+    );
+
+    // Values present are not computed again
+    let again = DerivedCif::compute(
+        &validator(&fixture_path("validation/derivation.dic"))
+            .validate_typed(doc)
+            .unwrap(),
+        &["_sample.density"],
+    );
+    assert!(again.values.is_empty() && again.is_complete());
+}
@@ -0,0 +1,62 @@
+//! Integration tests for run-to-run stable output
+//!
+//! Every `HashMap` gets its own random hash seed, so validating the same file
+//! twice in one process already iterates the maps in different orders. Any
+//! output that leaked that order would differ between the two runs.
+
+mod common;
+
+use cif_parser::CifDocument;
+use cif_validator::ValidationMode;
+use common::{fixture_path, validator, CORE_DICT};
+
+/// Parse, validate and serialize a fixture from scratch
+fn run(name: &str) -> (String, String, String) {
+    let validator = validator(CORE_DICT).with_mode(ValidationMode::Pedantic);
+    let doc = CifDocument::from_file(fixture_path(name)).expect("Failed to parse CIF");
+    let result = validator.validate(&doc).expect("Validation failed");
+    let (dict, _) = validator.combined_dictionary().unwrap();
+
+    (
+        serde_json::to_string(&doc).unwrap(),
+        serde_json::to_string(&result).unwrap(),
+        serde_json::to_string(&dict).unwrap(),
+    )
+}
+
+#[test]
+fn test_serialized_output_is_identical_across_runs() {
+    for name in [
+        "ccdc_paracetamol.cif",
+        "cif2_comprehensive.cif",
+        "validation/invalid_structure.cif",
+    ] {
+        let (doc, result, dict) = run(name);
+        let (doc_again, result_again, dict_again) = run(name);
+        assert_eq!(doc, doc_again, "{name}: document JSON differs");
+        assert_eq!(result, result_again, "{name}: result JSON differs");
+        assert_eq!(dict, dict_again, "{name}: dictionary JSON differs");
+    }
+}
+
+#[test]
+fn test_findings_are_in_document_order() {
+    let validator = validator(CORE_DICT);
+    let doc = CifDocument::from_file(fixture_path("validation/invalid_structure.cif"))
+        .expect("Failed to parse CIF");
+    let result = validator.validate(&doc).unwrap();
+    assert!(
+        result.errors.len() > 1,
+        "fixture should give several errors"
+    );
+
+    for pair in result.errors.windows(2) {
+        let (a, b) = (&pair[0], &pair[1]);
+        if a.block != b.block {
+            continue;
+        }
+        let a_key = (a.span.start_line, a.span.start_col, &a.data_name);
+        let b_key = (b.span.start_line, b.span.start_col, &b.data_name);
+        assert!(a_key <= b_key, "{a_key:?} reported after {b_key:?}");
+    }
+}
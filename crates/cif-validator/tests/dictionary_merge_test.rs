@@ -0,0 +1,76 @@
+//! Integration tests for provenance and conflicts when merging dictionaries
+
+mod common;
+
+use cif_parser::CifDocument;
+use cif_validator::{load_dictionary_file, ErrorCategory, Validator};
+use common::fixture_path;
+
+fn validator() -> Validator {
+    Validator::new()
+        .with_dictionary_file(&fixture_path("validation/merge_base.dic"))
+        .expect("Failed to load base dictionary")
+        .with_dictionary_file(&fixture_path("validation/merge_extension.dic"))
+        .expect("Failed to load extension dictionary")
+}
+
+#[test]
+fn test_items_record_their_source() {
+    let base = load_dictionary_file(&fixture_path("validation/merge_base.dic")).unwrap();
+    assert_eq!(base.primary_dictionary.as_deref(), Some("MERGE_BASE_DIC"));
+    let item = base.get_item("_measure.temperature").unwrap();
+    assert_eq!(item.source_dictionary.as_deref(), Some("MERGE_BASE_DIC"));
+
+    let (combined, _) = validator().combined_dictionary().unwrap();
+    assert_eq!(
+        combined.primary_dictionary.as_deref(),
+        Some("MERGE_BASE_DIC")
+    );
+    let source = |name: &str| combined.get_item(name).unwrap().source_dictionary.clone();
+    assert_eq!(
+        source("_measure.temperature").as_deref(),
+        Some("MERGE_EXT_DIC")
+    );
+    assert_eq!(source("_measure.field").as_deref(), Some("MERGE_EXT_DIC"));
+}
+
+#[test]
+fn test_merge_reports_changed_definitions() {
+    let (_, conflicts) = validator().combined_dictionary().unwrap();
+
+    // The pressure definition is repeated unchanged and is not a conflict
+    assert_eq!(conflicts.len(), 1, "conflicts: {conflicts:?}");
+    let conflict = &conflicts[0];
+    assert_eq!(conflict.name, "_measure.temperature");
+    assert_eq!(conflict.previous_source.as_deref(), Some("MERGE_BASE_DIC"));
+    assert_eq!(conflict.source.as_deref(), Some("MERGE_EXT_DIC"));
+    assert_eq!(conflict.differences, ["range: 0.0: -> 0.0:500.0"]);
+    assert_eq!(
+        conflict.to_string(),
+        "'_measure.temperature' from MERGE_EXT_DIC overrides the definition \
+         from MERGE_BASE_DIC (range: 0.0: -> 0.0:500.0)"
+    );
+}
+
+#[test]
+fn test_errors_name_extension_dictionary() {
+    let doc =
+        CifDocument::parse("data_test\n_measure.temperature 600\n_measure.pressure -1\n").unwrap();
+    let result = validator().validate(&doc).unwrap();
+
+    assert_eq!(result.errors.len(), 2, "errors: {:?}", result.errors);
+    assert!(result
+        .errors
+        .iter()
+        .all(|e| e.category == ErrorCategory::RangeError));
+    // The extension's narrower range applies and is attributed to it
+    assert!(
+        result.errors[0]
+            .message
+            .ends_with("(defined in MERGE_EXT_DIC)"),
+        "{}",
+        result.errors[0].message
+    );
+    // Items from the primary dictionary need no attribution
+    assert!(!result.errors[1].message.contains("defined in"));
+}
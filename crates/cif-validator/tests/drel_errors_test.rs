@@ -0,0 +1,97 @@
+//! Integration tests for reporting dREL syntax errors in dictionary methods
+
+use cif_parser::Span;
+use cif_validator::dictionary::validate_dictionary;
+use cif_validator::{load_dictionary_file, Dictionary, DictionaryError};
+use drel_parser::{ExprKind, StmtKind};
+
+fn dictionary() -> Dictionary {
+    let path = format!(
+        "{}/../../fixtures/validation/bad_methods.dic",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    load_dictionary_file(&path).expect("Failed to load dictionary")
+}
+
+/// The `(item, span)` of each syntax error
+fn syntax_errors(errors: &[DictionaryError]) -> Vec<(&str, Span)> {
+    errors
+        .iter()
+        .filter_map(|e| match e {
+            DictionaryError::InvalidDrel { item, span, .. } => Some((item.as_str(), *span)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_each_syntax_error_reported_once_at_file_position() {
+    let dict = dictionary();
+    let errors = validate_dictionary(&dict);
+
+    let category: Vec<_> = syntax_errors(&errors)
+        .into_iter()
+        .filter(|(item, _)| *item == "cell_geom")
+        .collect();
+    assert_eq!(
+        category,
+        [
+            ("cell_geom", Span::point(31, 17)),
+            ("cell_geom", Span::point(33, 19)),
+        ]
+    );
+    assert!(errors[1]
+        .to_string()
+        .contains("Unexpected '=', expected expression"));
+}
+
+#[test]
+fn test_quoted_method_error_rebased_past_the_quote() {
+    let dict = dictionary();
+    let errors = validate_dictionary(&dict);
+
+    // The quoted value starts at column 34; the parenthesis is never closed
+    assert_eq!(
+        syntax_errors(&errors)[0],
+        ("_cell_geom.length_c", Span::point(57, 77))
+    );
+}
+
+#[test]
+fn test_valid_statements_still_checked() {
+    let dict = dictionary();
+    let errors = validate_dictionary(&dict);
+
+    let missing: Vec<_> = errors
+        .iter()
+        .filter_map(|e| match e {
+            DictionaryError::MissingDrelReference {
+                referenced, span, ..
+            } => Some((referenced.as_str(), *span)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(missing, [("_cell_geom.volume", Span::new(32, 39, 32, 56))]);
+    assert_eq!(errors.len(), 4, "errors: {errors:?}");
+}
+
+#[test]
+fn test_partial_ast_keeps_valid_statements() {
+    let dict = dictionary();
+    let category = dict.get_category("cell_geom").unwrap();
+
+    let (program, errors) = category.method_parse().unwrap();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(program.statements.len(), 3);
+    let StmtKind::Assignment { target, .. } = &program.statements[2].kind else {
+        panic!("expected an assignment: {:?}", program.statements[2]);
+    };
+    assert!(matches!(
+        &target.kind,
+        ExprKind::AttributeRef { attribute, .. } if attribute == "area_ca"
+    ));
+
+    // The strict view of the method reports the first error only
+    let first = category.method_ast().unwrap().unwrap_err();
+    assert_eq!(first.span().start_line, 4);
+}
@@ -0,0 +1,75 @@
+//! Integration tests for documents with no blocks, empty blocks and
+//! trailing comments
+
+mod common;
+
+use cif_parser::CifDocument;
+use cif_validator::{load_dictionary_file, ValidationMode, Validator};
+use common::{fixture_path, validator, CORE_DICT};
+
+const MODES: [ValidationMode; 3] = [
+    ValidationMode::Strict,
+    ValidationMode::Lenient,
+    ValidationMode::Pedantic,
+];
+
+/// A validator for each mode, sharing one load of the dictionary
+fn validators() -> Vec<(ValidationMode, Validator)> {
+    let dict = load_dictionary_file(CORE_DICT).expect("Failed to load dictionary");
+    MODES
+        .iter()
+        .map(|&mode| {
+            let validator = Validator::new()
+                .with_dictionary(dict.clone())
+                .with_mode(mode);
+            (mode, validator)
+        })
+        .collect()
+}
+
+fn assert_no_findings(validators: &[(ValidationMode, Validator)], doc: &CifDocument, what: &str) {
+    for (mode, validator) in validators {
+        let result = validator.validate(doc).expect("Validation failed");
+
+        assert!(result.is_valid, "{what} {mode:?}: {:?}", result.errors);
+        assert!(
+            result.warnings.is_empty(),
+            "{what} {mode:?}: {:?}",
+            result.warnings
+        );
+    }
+}
+
+#[test]
+fn test_fixtures_have_no_findings() {
+    let validators = validators();
+    for name in [
+        "comments_only.cif",
+        "empty_block.cif",
+        "trailing_comments.cif",
+    ] {
+        let doc = CifDocument::from_file(fixture_path(name)).expect("Failed to parse fixture");
+        assert_no_findings(&validators, &doc, name);
+    }
+}
+
+#[test]
+fn test_empty_inputs_have_no_findings() {
+    let validators = validators();
+    for input in ["", "# nothing here\n", "data_a\ndata_b\n", "global_\n"] {
+        let doc = CifDocument::parse(input).expect("Failed to parse CIF");
+        assert_no_findings(&validators, &doc, input);
+    }
+}
+
+#[test]
+fn test_empty_block_typed_access() {
+    let doc = CifDocument::from_file(fixture_path("empty_block.cif")).unwrap();
+    let validated = validator(CORE_DICT).validate_typed(doc).unwrap();
+
+    assert!(validated.is_valid());
+    let block = validated.blocks().next().unwrap();
+    assert_eq!(block.name(), "empty");
+    assert_eq!(block.item_names().count(), 0);
+    assert!(block.get_with_def("_cell.length_a").is_none());
+}
@@ -0,0 +1,76 @@
+//! Smoke tests running the logic of the runnable examples on their bundled inputs
+
+mod common;
+
+use common::{fixture_path, validator};
+
+// Each example is compiled into a module here, so a change to the API that
+// breaks it fails the tests, and `main` goes unused
+#[allow(dead_code)]
+mod validate_with_core {
+    include!("../examples/validate_with_core.rs");
+
+    #[test]
+    fn test_bundled_structure_is_valid() {
+        let doc = CifDocument::from_file(super::fixture_path("examples/structure.cif")).unwrap();
+        let result = super::validator(&super::fixture_path("examples/core_trimmed.dic"))
+            .validate(&doc)
+            .unwrap();
+
+        assert_eq!(
+            render_report("structure.cif", &result),
+            "Validating structure.cif\n\nvalid: 0 error(s), 0 warning(s)\n"
+        );
+    }
+
+    #[test]
+    fn test_report_groups_findings_by_block() {
+        let doc = CifDocument::parse(
+            "data_a\n_cell.length_a 0.5\n_cell.volume ?\ndata_b\n_space_group.IT_number 231\n",
+        )
+        .unwrap();
+        let result = super::validator(&super::fixture_path("examples/core_trimmed.dic"))
+            .validate(&doc)
+            .unwrap();
+
+        let report = render_report("inline", &result);
+        let a = report.find("\ndata_a\n").expect(&report);
+        let b = report.find("\ndata_b\n").expect(&report);
+        assert!(a < b);
+        assert!(report[a..b].contains("error   [range error] Value 0.5 for '_cell.length_a'"));
+        assert!(report[b..].contains("Value 231 for '_space_group.IT_number'"));
+        assert!(
+            report.ends_with("INVALID: 2 error(s), 0 warning(s)\n"),
+            "{report}"
+        );
+    }
+
+    #[test]
+    fn test_format_flag() {
+        let args = |list: &[&str]| parse_args(list.iter().map(|s| s.to_string()));
+
+        assert_eq!(args(&[]).unwrap(), (Format::Text, vec![]));
+        let (format, paths) = args(&["a.cif", "--format", "sarif", "b.dic"]).unwrap();
+        assert_eq!(format, Format::Sarif);
+        assert_eq!(paths, ["a.cif", "b.dic"]);
+        assert_eq!(args(&["--format=text"]).unwrap().0, Format::Text);
+        assert!(args(&["--format", "xml"]).is_err());
+        assert!(args(&["--format"]).is_err());
+    }
+}
+
+#[allow(dead_code)]
+mod dictionary_info {
+    include!("../examples/dictionary_info.rs");
+
+    #[test]
+    fn test_dictionary_info() {
+        let dict = load_dictionary_file(&super::fixture_path("examples/core_trimmed.dic")).unwrap();
+        let info = describe(&dict);
+
+        assert!(info.starts_with("CORE_TRIMMED_DIC 1.0.0 (4 categories, 17 items, 17 aliases)\n"));
+        assert!(info.contains("\natom_site [Loop] key _atom_site.label\n"));
+        assert!(info.contains("  _cell.length_a                   Real in angstroms\n"));
+        assert!(info.ends_with("Computed by dREL methods:\n  _cell.volume (own method)\n"));
+    }
+}
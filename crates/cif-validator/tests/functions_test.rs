@@ -0,0 +1,92 @@
+//! Integration tests for Head, Functions and Attribute definition frames
+
+use cif_validator::dictionary::validate_dictionary;
+use cif_validator::{load_dictionary_file, CategoryClass, Dictionary};
+
+const DICT_PATH: &str = "../../fixtures/validation/functions.dic";
+
+fn load() -> Dictionary {
+    load_dictionary_file(DICT_PATH).expect("Failed to load dictionary")
+}
+
+#[test]
+fn test_functions_loaded_apart_from_items() {
+    let dict = load();
+
+    let mut names: Vec<&str> = dict.functions.values().map(|f| f.name.as_str()).collect();
+    names.sort_unstable();
+    assert_eq!(names, ["Double", "Half"]);
+    assert!(dict.get_function("DOUBLE").is_some());
+
+    // Neither the functions nor the attribute definition are items
+    let items: Vec<&str> = dict.item_names().collect();
+    assert_eq!(items, ["_rod.double_length", "_rod.length"]);
+    assert!(!dict.has_item("_function.double"));
+    assert!(!dict.has_item("_units.code"));
+}
+
+#[test]
+fn test_function_source_and_location() {
+    let dict = load();
+    let double = dict.get_function("double").unwrap();
+
+    assert!(double.drel_source().contains("Double = 2 * x"));
+    assert_eq!(double.span.start_line, 27);
+    assert_eq!(double.method_origin.unwrap().start_line, 35);
+    assert!(double.method_ast().is_ok());
+}
+
+#[test]
+fn test_head_and_attribute_frames() {
+    let dict = load();
+
+    assert_eq!(
+        dict.metadata.head_category.as_deref(),
+        Some("functions_head")
+    );
+    assert_eq!(dict.metadata.attribute_frames, 1);
+    assert_eq!(
+        dict.get_category("function").unwrap().class,
+        CategoryClass::Functions
+    );
+    assert_eq!(
+        dict.get_category("functions_head").unwrap().class,
+        CategoryClass::Head
+    );
+}
+
+#[test]
+fn test_functions_checked_with_dictionary() {
+    assert!(validate_dictionary(&load()).is_empty());
+}
+
+#[test]
+fn test_functions_survive_merge_and_serde() {
+    let mut merged = Dictionary::new();
+    merged.merge(load());
+    assert_eq!(merged.functions.len(), 2);
+    assert_eq!(
+        merged.metadata.head_category.as_deref(),
+        Some("functions_head")
+    );
+
+    let json = serde_json::to_string(&merged).unwrap();
+    let back: Dictionary = serde_json::from_str(&json).unwrap();
+    let half = back.get_function("half").unwrap();
+    assert_eq!(half.method_ast().unwrap().statements.len(), 1);
+}
+
+#[test]
+fn test_cif_core_functions() {
+    let dict = load_dictionary_file("dics/cif_core.dic").expect("Failed to load cif_core");
+
+    assert!(dict.get_function("AtomType").is_some());
+    assert!(dict.get_function("SymEquiv").is_some());
+    assert!(dict
+        .item_names()
+        .all(|name| !name.starts_with("_function.")));
+    assert_eq!(
+        dict.metadata.head_category.as_deref(),
+        Some("cif_core_head")
+    );
+}
@@ -0,0 +1,102 @@
+//! Integration tests for bond, angle and torsion extraction
+
+mod common;
+
+use cif_parser::{CifDocument, Measurand};
+use cif_validator::{BondRecord, ErrorCategory, ValidatedCif};
+use common::{fixture_path, validator, CORE_DICT};
+
+fn validated(doc: CifDocument) -> ValidatedCif {
+    validator(CORE_DICT)
+        .validate_typed(doc)
+        .expect("Failed to validate")
+}
+
+fn fixture(name: &str) -> ValidatedCif {
+    validated(CifDocument::from_file(fixture_path(name)).expect("Failed to parse fixture"))
+}
+
+fn assert_measurand(actual: Measurand, value: f64, uncertainty: f64) {
+    assert!((actual.value - value).abs() < 1e-12, "{actual:?}");
+    assert!(
+        (actual.uncertainty.unwrap() - uncertainty).abs() < 1e-12,
+        "{actual:?}"
+    );
+}
+
+#[test]
+fn test_geometry_with_both_su_conventions() {
+    let cif = fixture("validation/geometry_su.cif");
+    let geometry = cif.first_block().unwrap().geometry();
+
+    // The bond with an unknown distance is skipped
+    let bonds = geometry.bonds().unwrap();
+    assert_eq!(bonds.len(), 3);
+    assert_eq!(
+        bonds[0],
+        BondRecord {
+            atom1: "O1".to_string(),
+            atom2: "C1".to_string(),
+            distance: Measurand::with_uncertainty(1.2345, 0.0017),
+            site_symmetry_1: None,
+            site_symmetry_2: None,
+            publ_flag: Some("yes".to_string()),
+        }
+    );
+    assert_eq!(bonds[2].site_symmetry_2.as_deref(), Some("2_655"));
+    assert_eq!(bonds[2].distance.uncertainty, None);
+
+    let angles = geometry.angles().unwrap();
+    assert_eq!(angles.len(), 2);
+    assert_eq!(angles[0].atom2, "C1");
+    assert_measurand(angles[0].angle, 121.4, 0.09);
+    assert_eq!(angles[1].site_symmetry_3.as_deref(), Some("4_566"));
+    assert_eq!(angles[1].publ_flag, None);
+
+    let torsions = geometry.torsions().unwrap();
+    assert_eq!(torsions.len(), 1);
+    assert_eq!(torsions[0].atom4, "H1");
+    assert_measurand(torsions[0].angle, -179.8, 0.3);
+}
+
+#[test]
+fn test_missing_columns_are_reported_with_the_loop_span() {
+    let doc = CifDocument::parse(
+        "data_x\n_cell.length_a 5.0\n\
+         loop_\n_geom_bond.atom_site_label_1\n_geom_bond.publ_flag\nC1 yes\n",
+    )
+    .unwrap();
+    let cif = validated(doc);
+    let geometry = cif.first_block().unwrap().geometry();
+
+    let errors = geometry.bonds().unwrap_err();
+    let messages: Vec<_> = errors.iter().map(|e| e.message.as_str()).collect();
+    assert_eq!(
+        messages,
+        [
+            "Geometry loop 'geom_bond' has no '_geom_bond.atom_site_label_2' column",
+            "Geometry loop 'geom_bond' has no '_geom_bond.distance' column",
+        ]
+    );
+    assert!(errors
+        .iter()
+        .all(|e| e.category == ErrorCategory::LoopStructure && e.span.start_line == 3));
+
+    // Blocks without a loop have no records rather than an error
+    assert!(geometry.angles().unwrap().is_empty());
+}
+
+#[test]
+fn test_non_numeric_measurand() {
+    let doc = CifDocument::parse(
+        "data_x\nloop_\n_geom_bond_atom_site_label_1\n_geom_bond_atom_site_label_2\n\
+         _geom_bond_distance\nC1 C2 1.54\nC2 C3 long\n",
+    )
+    .unwrap();
+    let cif = validated(doc);
+
+    let errors = cif.first_block().unwrap().geometry().bonds().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].category, ErrorCategory::TypeError);
+    assert_eq!(errors[0].span.start_line, 7);
+}
@@ -0,0 +1,55 @@
+//! Integration tests for reading `_import.get` specifications from definitions
+
+use cif_validator::dictionary::{validate_dictionary, ImportDuplicate, ImportMissing, ImportMode};
+use cif_validator::{load_dictionary_file, DictionaryError};
+
+fn dict_path() -> String {
+    format!(
+        "{}/../../fixtures/validation/imports.dic",
+        env!("CARGO_MANIFEST_DIR")
+    )
+}
+
+#[test]
+fn test_import_specs_loaded() {
+    let dict = load_dictionary_file(&dict_path()).expect("Failed to load dictionary");
+
+    let category = dict.get_category("cell").unwrap();
+    assert_eq!(category.imports.len(), 1);
+    assert_eq!(category.imports[0].file, "templ_cat.cif");
+    assert_eq!(category.imports[0].save.as_deref(), Some("set_category"));
+
+    // Unset codes take the DDLm defaults; tables come in document order
+    let imports = &dict.get_item("_cell.length_a").unwrap().imports;
+    assert_eq!(imports.len(), 2);
+    assert_eq!(imports[0].file, "templ_attr.cif");
+    assert_eq!(imports[0].mode, ImportMode::Full);
+    assert_eq!(imports[0].dupl, ImportDuplicate::Exit);
+    assert_eq!(imports[1].save.as_deref(), Some("units_code"));
+    assert_eq!(imports[1].mode, ImportMode::Contents);
+    assert_eq!(imports[1].dupl, ImportDuplicate::Replace);
+    assert_eq!(imports[1].miss, ImportMissing::Ignore);
+    assert_eq!(imports[1].span.start_line, 34);
+}
+
+#[test]
+fn test_unknown_import_key_reported() {
+    let dict = load_dictionary_file(&dict_path()).expect("Failed to load dictionary");
+
+    let errors = validate_dictionary(&dict);
+    assert_eq!(errors.len(), 1, "errors: {errors:?}");
+    match &errors[0] {
+        DictionaryError::InvalidField {
+            item,
+            field,
+            message,
+            span,
+        } => {
+            assert_eq!(item, "_cell.angle_alpha");
+            assert_eq!(field, "_import.get");
+            assert!(message.starts_with("unknown key 'if_miss'"), "{message}");
+            assert_eq!(span.start_line, 42);
+        }
+        other => panic!("expected an invalid import key, got {other:?}"),
+    }
+}
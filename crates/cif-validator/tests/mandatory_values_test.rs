@@ -0,0 +1,171 @@
+//! Integration tests for mandatory and key items given only as '?' or '.'
+
+use cif_parser::CifDocument;
+use cif_validator::{ErrorCategory, ValidationMode, ValidationResult, Validator, WarningCategory};
+
+fn dict_path() -> String {
+    format!(
+        "{}/../../fixtures/validation/mandatory.dic",
+        env!("CARGO_MANIFEST_DIR")
+    )
+}
+
+fn validator(mode: ValidationMode) -> Validator {
+    Validator::new()
+        .with_dictionary_file(&dict_path())
+        .expect("Failed to load dictionary")
+        .with_mode(mode)
+}
+
+fn validate(validator: &Validator, cif: &str) -> ValidationResult {
+    let doc = CifDocument::parse(cif).expect("Failed to parse CIF");
+    validator.validate(&doc).expect("Validation failed")
+}
+
+fn has_warning(result: &ValidationResult, category: WarningCategory) -> bool {
+    result.warnings.iter().any(|w| w.category == category)
+}
+
+#[test]
+fn test_single_unknown_mandatory_item() {
+    let cif = "data_test\n_sample.id s1\n_sample.operator ?\n";
+
+    let result = validate(&validator(ValidationMode::Strict), cif);
+    assert_eq!(result.errors.len(), 1, "errors: {:?}", result.errors);
+    let error = &result.errors[0];
+    assert_eq!(error.category, ErrorCategory::UnknownMandatoryValue);
+    assert_eq!(
+        error.message,
+        "Mandatory item '_sample.operator' is present but its value is unknown ('?')"
+    );
+    assert_eq!((error.span.start_line, error.span.start_col), (3, 18));
+
+    let result = validate(&validator(ValidationMode::Lenient), cif);
+    assert!(result.is_valid, "errors: {:?}", result.errors);
+    assert!(has_warning(&result, WarningCategory::UnknownMandatoryValue));
+}
+
+#[test]
+fn test_unknown_mandatory_can_be_a_warning_in_strict_mode() {
+    let cif = "data_test\n_sample.id s1\n_sample.operator ?\n";
+    let result = validate(
+        &validator(ValidationMode::Strict).unknown_mandatory_as_warning(true),
+        cif,
+    );
+
+    assert!(result.is_valid, "errors: {:?}", result.errors);
+    assert!(has_warning(&result, WarningCategory::UnknownMandatoryValue));
+}
+
+#[test]
+fn test_loop_column_all_unknown() {
+    let cif = "\
+data_test
+loop_
+_sample.id
+_sample.operator
+_sample.mass
+?  alice 1.5
+?  bob   2.0
+";
+    let result = validate(&validator(ValidationMode::Strict), cif);
+
+    assert_eq!(result.errors.len(), 1, "errors: {:?}", result.errors);
+    let error = &result.errors[0];
+    assert_eq!(error.category, ErrorCategory::UnknownMandatoryValue);
+    assert_eq!(
+        error.message,
+        "Key item '_sample.id' is present but every value in its loop is unknown ('?')"
+    );
+    // Reported once, at the loop
+    assert_eq!(error.span.start_line, 2);
+}
+
+#[test]
+fn test_loop_column_partly_unknown() {
+    let cif = "\
+data_test
+loop_
+_sample.id
+_sample.operator
+s1 ?
+s2 bob
+";
+    for mode in [
+        ValidationMode::Strict,
+        ValidationMode::Lenient,
+        ValidationMode::Pedantic,
+    ] {
+        let result = validate(&validator(mode), cif);
+        assert!(result.is_valid, "{mode:?}: {:?}", result.errors);
+        assert!(
+            !has_warning(&result, WarningCategory::UnknownMandatoryValue),
+            "{mode:?}: {:?}",
+            result.warnings
+        );
+    }
+}
+
+#[test]
+fn test_not_applicable_noted_only_in_pedantic_mode() {
+    let single = "data_test\n_sample.id s1\n_sample.operator .\n";
+    let looped = "data_test\nloop_\n_sample.id\n_sample.operator\ns1 .\ns2 .\n";
+
+    for cif in [single, looped] {
+        for mode in [ValidationMode::Strict, ValidationMode::Lenient] {
+            let result = validate(&validator(mode), cif);
+            assert!(result.is_valid, "{mode:?}: {:?}", result.errors);
+            assert!(
+                result.warnings.is_empty(),
+                "{mode:?}: {:?}",
+                result.warnings
+            );
+        }
+    }
+
+    let result = validate(&validator(ValidationMode::Pedantic), single);
+    assert!(result.is_valid, "errors: {:?}", result.errors);
+    let notes: Vec<_> = result
+        .warnings
+        .iter()
+        .filter(|w| w.category == WarningCategory::NotApplicableMandatory)
+        .collect();
+    assert_eq!(notes.len(), 1, "warnings: {:?}", result.warnings);
+    assert_eq!(
+        notes[0].message,
+        "Mandatory item '_sample.operator' is present but its value is not applicable ('.')"
+    );
+
+    let result = validate(&validator(ValidationMode::Pedantic), looped);
+    assert!(has_warning(
+        &result,
+        WarningCategory::NotApplicableMandatory
+    ));
+}
+
+#[test]
+fn test_core_atom_site_label_all_unknown() {
+    let cif = "\
+data_test
+loop_
+_atom_site.label
+_atom_site.type_symbol
+?  C
+?  N
+";
+    let doc = CifDocument::parse(cif).expect("Failed to parse CIF");
+    let result = Validator::new()
+        .with_dictionary_file("dics/cif_core.dic")
+        .expect("Failed to load dictionary")
+        .with_mode(ValidationMode::Lenient)
+        .validate(&doc)
+        .expect("Validation failed");
+
+    let warnings: Vec<_> = result
+        .warnings
+        .iter()
+        .filter(|w| w.category == WarningCategory::UnknownMandatoryValue)
+        .collect();
+    assert_eq!(warnings.len(), 1, "warnings: {:?}", result.warnings);
+    assert!(warnings[0].message.contains("'_atom_site.label'"));
+}
@@ -0,0 +1,222 @@
+//! Integration tests for the memory footprint of merged dictionaries
+//!
+//! A core dictionary and two extensions are generated with multi-kilobyte
+//! descriptions and methods, as in `cif_core`. Before dictionaries were
+//! merged as they were added, the validator kept each dictionary and built a
+//! deep copy of their combination for every validation. A counting allocator
+//! measures what the validator holds and what validating allocates, against
+//! that layout.
+
+use cif_parser::CifDocument;
+use cif_validator::dictionary::load_dictionary;
+use cif_validator::{Dictionary, Validator};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Write;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// The system allocator, counting the bytes live and the most live since
+/// the last [`Counting::reset_peak`]
+struct Counting {
+    live: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+impl Counting {
+    fn live(&self) -> usize {
+        self.live.load(Ordering::SeqCst)
+    }
+
+    fn peak(&self) -> usize {
+        self.peak.load(Ordering::SeqCst)
+    }
+
+    fn reset_peak(&self) {
+        self.peak.store(self.live(), Ordering::SeqCst);
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            let live = self.live.fetch_add(layout.size(), Ordering::SeqCst) + layout.size();
+            self.peak.fetch_max(live, Ordering::SeqCst);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        self.live.fetch_sub(layout.size(), Ordering::SeqCst);
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting {
+    live: AtomicUsize::new(0),
+    peak: AtomicUsize::new(0),
+};
+
+/// Held by each test, so the counts see one test's allocations at a time
+static SERIAL: Mutex<()> = Mutex::new(());
+
+/// A DDLm dictionary with `items` items in categories of 20, each with an
+/// alias, a long description and a method; the items of `redefined`, of the
+/// form `_<prefix>_<category>.item_<n>`, are defined again with a range
+fn generated_dictionary(title: &str, prefix: &str, items: usize, redefined: &[&str]) -> String {
+    let mut text =
+        format!("#\\#CIF_2.0\ndata_{title}\n_dictionary.title {title}\n_dictionary.version 1.0\n");
+    let description = "Describes the quantity at length, as the core dictionary does. ".repeat(30);
+    let method = "    _cell.volume = _cell.length_a * _cell.length_b * _cell.length_c\n".repeat(8);
+
+    for category in 0..items.div_ceil(20) {
+        let id = format!("{prefix}_{category}");
+        writeln!(
+            text,
+            "save_{id}\n_definition.id {id}\n_definition.scope Category\n\
+             _definition.class Loop\n_name.object_id {id}\n\
+             _description.text\n;\n{description}\n;\nsave_"
+        )
+        .unwrap();
+        for n in (category * 20)..items.min((category + 1) * 20) {
+            let name = format!("_{id}.item_{n}");
+            writeln!(
+                text,
+                "save_{id}.item_{n}\n_definition.id '{name}'\n\
+                 _alias.definition_id '_{id}_item_{n}'\n\
+                 _type.contents Real\n_units.code angstroms\n\
+                 _description.text\n;\n{n}: {description}\n;\n\
+                 _method.purpose Evaluation\n_method.expression\n;\n{method};\nsave_"
+            )
+            .unwrap();
+        }
+    }
+    for name in redefined {
+        let (id, object) = name[1..].split_once('.').unwrap();
+        writeln!(
+            text,
+            "save_{id}.{object}\n_definition.id '{name}'\n_type.contents Real\n\
+             _enumeration.range 0.0:\n_description.text\n;\n{description}\n;\nsave_"
+        )
+        .unwrap();
+    }
+    text
+}
+
+fn dictionaries() -> [String; 3] {
+    [
+        generated_dictionary("GEN_CORE", "core", 1000, &[]),
+        generated_dictionary("GEN_EXT_A", "exta", 150, &["_core_3.item_61"]),
+        generated_dictionary("GEN_EXT_B", "extb", 150, &["_core_7.item_140"]),
+    ]
+}
+
+fn load(text: &str) -> Dictionary {
+    load_dictionary(&CifDocument::parse(text).unwrap()).unwrap()
+}
+
+/// Bytes still allocated once `make` has run, and its result
+fn allocated_by<T>(make: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATOR.live();
+    let made = make();
+    (made, ALLOCATOR.live().saturating_sub(before))
+}
+
+#[test]
+fn test_validator_keeps_one_copy_of_merged_dictionaries() {
+    let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+    let texts = dictionaries();
+    let doc = CifDocument::parse("data_x\n_core_3.item_61 1.5\n_exta_0.item_2 2.5\n").unwrap();
+
+    // What the validator used to keep: every dictionary as loaded, and
+    // while validating a deep copy of their combination. A copy read back
+    // from JSON shares no text, as the clones of that layout did not.
+    let (separate, separate_bytes) =
+        allocated_by(|| texts.iter().map(|text| load(text)).collect::<Vec<_>>());
+    drop(separate);
+    let json = {
+        let mut validator = Validator::new();
+        for text in &texts {
+            validator = validator.with_dictionary_str(text).unwrap();
+        }
+        serde_json::to_string(&validator.combined_dictionary().unwrap().0).unwrap()
+    };
+    let (deep_copy, copy_bytes) =
+        allocated_by(|| serde_json::from_str::<Dictionary>(&json).unwrap());
+    drop(deep_copy);
+    let previously = separate_bytes + copy_bytes;
+
+    let (validator, held) = allocated_by(|| {
+        let mut validator = Validator::new();
+        for text in &texts {
+            validator = validator.with_dictionary_str(text).unwrap();
+        }
+        validator
+    });
+    assert!(
+        held <= separate_bytes,
+        "validator holds {held} bytes, the dictionaries alone {separate_bytes} bytes"
+    );
+
+    // Validating used to build a deep copy of the combination as well
+    ALLOCATOR.reset_peak();
+    let before = ALLOCATOR.live();
+    let result = validator.validate(&doc).unwrap();
+    let during = ALLOCATOR.peak() - before;
+    assert!(result.is_valid, "{:?}", result.errors);
+    assert!(
+        during * 20 < separate_bytes,
+        "validating allocated {during} bytes against {separate_bytes} bytes of dictionaries"
+    );
+
+    // At least half of the resident memory is gone
+    assert!(
+        (held + during) * 2 <= previously,
+        "validating with {held} + {during} bytes, previously {previously} bytes"
+    );
+
+    // The combined copy shares the validator's text
+    let ((combined, conflicts), cloned) = allocated_by(|| validator.combined_dictionary().unwrap());
+    assert!(cloned * 2 < held, "copy allocated {cloned} of {held} bytes");
+    assert_eq!(conflicts.len(), 2);
+    assert_eq!(combined.items.len(), 1300);
+}
+
+#[test]
+fn test_clones_share_descriptions_and_methods() {
+    let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+    let dict = load(&dictionaries()[1]);
+    let text: usize = dict
+        .items
+        .values()
+        .map(|item| item.description().map_or(0, str::len) + item.drel_method().map_or(0, str::len))
+        .sum();
+
+    // The clone allocates everything but the text
+    let (copy, cloned) = allocated_by(|| dict.clone());
+    let (_, loaded) = allocated_by(|| load(&dictionaries()[1]));
+    assert!(
+        cloned + text <= loaded,
+        "clone allocated {cloned} bytes of {loaded}, the text is {text} bytes"
+    );
+
+    // The estimates divide the text between the two
+    let alone = load(&dictionaries()[1]).memory_estimate();
+    let total = dict.memory_estimate() + copy.memory_estimate();
+    assert!(total <= 2 * alone - text, "{total} bytes for two copies");
+}
+
+#[test]
+fn test_aliases_share_the_canonical_name() {
+    let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+    let dict = load(&dictionaries()[1]);
+    let canonical = &dict.aliases["_exta_0_item_3"];
+    assert_eq!(&**canonical, "_exta_0.item_3");
+    assert_eq!(dict.resolve_name("_EXTA_0_ITEM_3"), "_exta_0.item_3");
+
+    // Items of one category share its name
+    let first = dict.get_item("_exta_0.item_0").unwrap();
+    let second = dict.get_item("_exta_0.item_1").unwrap();
+    assert!(std::ptr::eq(&*first.category, &*second.category));
+}
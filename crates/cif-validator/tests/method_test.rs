@@ -0,0 +1,69 @@
+//! Integration tests for resolving the dREL method that computes each item
+
+use cif_validator::dictionary::validate_dictionary;
+use cif_validator::{load_dictionary_file, Dictionary, MethodPurpose, MethodSource};
+
+fn dictionary() -> Dictionary {
+    let path = format!(
+        "{}/../../fixtures/validation/methods.dic",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    load_dictionary_file(&path).expect("Failed to load dictionary")
+}
+
+#[test]
+fn test_category_method_applies_to_assigned_item() {
+    let dict = dictionary();
+
+    let method = dict.effective_method("_cell_geom.area_ab").unwrap();
+    assert_eq!(
+        method.source,
+        MethodSource::Category("cell_geom".to_string())
+    );
+    assert!(method.expression.contains("g.area_ab ="));
+    assert_eq!(method.span, dict.get_category("cell_geom").unwrap().span);
+}
+
+#[test]
+fn test_item_method_overrides_category_method() {
+    let dict = dictionary();
+
+    let method = dict.effective_method("_CELL_GEOM.AREA_BC").unwrap();
+    assert_eq!(method.source, MethodSource::Local);
+    assert_eq!(
+        method.expression,
+        "_cell_geom.area_bc = _cell_geom.length_c * _cell_geom.length_b"
+    );
+    assert_eq!(
+        dict.get_item("_cell_geom.area_bc").unwrap().method_purpose,
+        MethodPurpose::Evaluation
+    );
+}
+
+#[test]
+fn test_items_without_evaluation_method() {
+    let dict = dictionary();
+
+    // A Definition method does not compute the value, and the category
+    // method does not assign this item
+    let item = dict.get_item("_cell_geom.length_a").unwrap();
+    assert_eq!(item.method_purpose, MethodPurpose::Definition);
+    assert!(dict.effective_method("_cell_geom.length_a").is_none());
+
+    assert!(dict.effective_method("_cell_geom.length_b").is_none());
+    assert!(dict.effective_method("_cell_geom.unknown").is_none());
+}
+
+#[test]
+fn test_category_method_validated_once() {
+    let dict = dictionary();
+
+    let errors = validate_dictionary(&dict);
+    // The Definition method assigns a DDLm attribute, which is not a
+    // missing reference even though the dictionary does not define it
+    assert!(
+        !errors.iter().any(|e| e.to_string().contains("_units.code")),
+        "errors: {errors:?}"
+    );
+    assert!(errors.is_empty(), "errors: {errors:?}");
+}
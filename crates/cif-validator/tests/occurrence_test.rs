@@ -0,0 +1,129 @@
+//! Integration tests for item occurrences counted across a block: Set items
+//! given twice, items under two of their names, and save frames
+
+mod common;
+
+use cif_parser::CifDocument;
+use cif_validator::{
+    Dictionary, ErrorCategory, OccurrenceMap, OccurrenceOptions, ValidationMode, WarningCategory,
+};
+use common::{validator, CORE_DICT};
+
+fn dictionary() -> Dictionary {
+    validator(CORE_DICT)
+        .with_mode(ValidationMode::Lenient)
+        .combined_dictionary()
+        .unwrap()
+        .0
+}
+
+#[test]
+fn test_set_item_under_name_and_alias() {
+    let doc = CifDocument::parse("data_t\n_cell_length_a 10.5\n_cell.length_a 10.5\n").unwrap();
+    let result = validator(CORE_DICT)
+        .with_mode(ValidationMode::Lenient)
+        .validate(&doc)
+        .unwrap();
+
+    let duplicates: Vec<_> = result
+        .errors
+        .iter()
+        .filter(|e| e.category == ErrorCategory::DuplicateItem)
+        .collect();
+    assert_eq!(duplicates.len(), 1, "{:?}", result.errors);
+    assert_eq!(duplicates[0].span.start_line, 3);
+    assert_eq!(
+        duplicates[0].suggestions,
+        ["'_cell_length_a' and '_cell.length_a' name the same item; keep one"]
+    );
+}
+
+#[test]
+fn test_looped_item_under_name_and_alias() {
+    let doc = CifDocument::parse(
+        "data_t\nloop_\n_atom_site_label\nC1\nC2\nloop_\n_atom_site.label\nN1\n",
+    )
+    .unwrap();
+    let result = validator(CORE_DICT)
+        .with_mode(ValidationMode::Lenient)
+        .validate(&doc)
+        .unwrap();
+
+    let duplicates: Vec<_> = result
+        .warnings
+        .iter()
+        .filter(|w| w.category == WarningCategory::DuplicateItem)
+        .collect();
+    assert_eq!(duplicates.len(), 1, "{:?}", result.warnings);
+    assert_eq!(
+        duplicates[0].message,
+        "Item '_atom_site.label' is given under more than one name: \
+         '_atom_site_label', '_atom_site.label'"
+    );
+    assert_eq!(duplicates[0].span.start_line, 6);
+}
+
+#[test]
+fn test_same_spelling_in_two_loops() {
+    let doc =
+        CifDocument::parse("data_t\nloop_\n_atom_site_label\nC1\nloop_\n_atom_site_label\nN1\n")
+            .unwrap();
+    let result = validator(CORE_DICT)
+        .with_mode(ValidationMode::Lenient)
+        .validate(&doc)
+        .unwrap();
+
+    let duplicates: Vec<_> = result
+        .warnings
+        .iter()
+        .filter(|w| w.category == WarningCategory::DuplicateItem)
+        .collect();
+    assert_eq!(duplicates.len(), 1);
+    assert!(duplicates[0].message.contains("2 different loops"));
+}
+
+#[test]
+fn test_spellings_ignore_case() {
+    let doc = CifDocument::parse(
+        "data_t\n_cell_length_a 10.5\nloop_\n_CELL_LENGTH_A\n10.5\nloop_\n_cell.length_a\n10.5\n",
+    )
+    .unwrap();
+    let map = OccurrenceMap::count(&doc.blocks[0], &dictionary(), OccurrenceOptions::new());
+
+    assert_eq!(map.get("_cell.length_a").len(), 3);
+    assert_eq!(
+        map.spellings("_cell.length_a"),
+        ["_cell_length_a", "_cell.length_a"]
+    );
+    assert_eq!(map.value_count("_cell.length_a"), 3);
+}
+
+#[test]
+fn test_frames_counted_on_request() {
+    let doc = CifDocument::parse(
+        "#\\#CIF_2.0\ndata_t\n_cell.length_a 10.5\nsave_f\n_cell.length_a 11.0\nsave_\n",
+    )
+    .unwrap();
+
+    let result = validator(CORE_DICT)
+        .with_mode(ValidationMode::Lenient)
+        .validate(&doc)
+        .unwrap();
+    assert!(!result
+        .errors
+        .iter()
+        .any(|e| e.category == ErrorCategory::DuplicateItem));
+
+    let result = validator(CORE_DICT)
+        .with_mode(ValidationMode::Lenient)
+        .with_occurrence_options(OccurrenceOptions::new().include_frames(true))
+        .validate(&doc)
+        .unwrap();
+    let duplicates: Vec<_> = result
+        .errors
+        .iter()
+        .filter(|e| e.category == ErrorCategory::DuplicateItem)
+        .collect();
+    assert_eq!(duplicates.len(), 1, "{:?}", result.errors);
+    assert_eq!(duplicates[0].span.start_line, 5);
+}
@@ -0,0 +1,69 @@
+//! Integration tests for pedantic warnings about quoting style
+
+mod common;
+
+use cif_parser::{CifDocument, Span};
+use cif_validator::{ValidationMode, ValidationWarning, WarningCategory};
+use common::{fixture_path, validator, CORE_DICT};
+
+fn style_warnings(doc: &CifDocument, mode: ValidationMode) -> Vec<ValidationWarning> {
+    let result = validator(CORE_DICT)
+        .with_mode(mode)
+        .validate(doc)
+        .expect("Validation failed");
+    let mut warnings: Vec<ValidationWarning> = result
+        .warnings
+        .into_iter()
+        .filter(|w| w.category == WarningCategory::Style)
+        .collect();
+    warnings.sort_by_key(|w| w.span.start_line);
+    warnings
+}
+
+#[test]
+fn test_quoting_warnings_in_pedantic_mode() {
+    let doc = CifDocument::from_file(fixture_path("validation/quoting_style.cif"))
+        .expect("Failed to parse fixture");
+    let warnings = style_warnings(&doc, ValidationMode::Pedantic);
+    assert_eq!(warnings.len(), 3, "warnings: {warnings:?}");
+
+    // CIF 1.1 has no lists, so this is bare text containing a space
+    assert!(warnings[0].message.contains("'[not a list]'"));
+    assert!(warnings[0].message.contains("'_chemical_name.common'"));
+    assert_eq!(warnings[0].span, Span::new(3, 35, 3, 47));
+
+    assert!(warnings[1].message.contains("'_exptl_crystal.colour'"));
+    assert!(warnings[1].message.contains("text field"));
+    assert_eq!(warnings[1].span.start_line, 5);
+
+    // A quoted number stays text, so only the space group name is flagged
+    assert_eq!(
+        warnings[2].message,
+        "Value 'P21/c' of '_space_group.name_H-M_alt' is quoted but reads the same \
+         without quotes"
+    );
+    assert_eq!(warnings[2].span.start_line, 9);
+}
+
+#[test]
+fn test_quoting_silent_outside_pedantic_mode() {
+    let doc = CifDocument::from_file(fixture_path("validation/quoting_style.cif"))
+        .expect("Failed to parse fixture");
+    for mode in [ValidationMode::Strict, ValidationMode::Lenient] {
+        let warnings = style_warnings(&doc, mode);
+        assert!(warnings.is_empty(), "{mode:?}: {warnings:?}");
+    }
+}
+
+#[test]
+fn test_heavyweight_triple_quotes() {
+    let doc = CifDocument::parse(
+        "#\\#CIF_2.0\ndata_x\n_journal.name_full '''Acta Cryst.'''\n\
+         _journal.coden_ASTM '''it's \"odd\" '''\n",
+    )
+    .unwrap();
+    let warnings = style_warnings(&doc, ValidationMode::Pedantic);
+    assert_eq!(warnings.len(), 1, "warnings: {warnings:?}");
+    assert!(warnings[0].message.contains("triple-quoted string"));
+    assert_eq!(warnings[0].span.start_line, 3);
+}
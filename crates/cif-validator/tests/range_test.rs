@@ -0,0 +1,97 @@
+//! Integration tests for exclusive range bounds and multiple permitted ranges
+
+use cif_parser::CifDocument;
+use cif_validator::{load_dictionary_file, ErrorCategory, ValidationError, Validator};
+
+fn dict_path() -> String {
+    format!(
+        "{}/../../fixtures/validation/ranges.dic",
+        env!("CARGO_MANIFEST_DIR")
+    )
+}
+
+fn range_errors(item: &str, value: &str) -> Vec<ValidationError> {
+    let cif = format!("data_test\n{} {}\n", item, value);
+    let doc = CifDocument::parse(&cif).expect("Failed to parse CIF");
+    let result = Validator::new()
+        .with_dictionary_file(&dict_path())
+        .expect("Failed to load dictionary")
+        .validate(&doc)
+        .expect("Validation failed");
+    result
+        .errors
+        .into_iter()
+        .filter(|e| e.category == ErrorCategory::RangeError)
+        .collect()
+}
+
+#[test]
+fn test_ranges_loaded() {
+    let dict = load_dictionary_file(&dict_path()).expect("Failed to load dictionary");
+
+    let length = &dict.get_item("_range_test.length").unwrap().constraints;
+    assert_eq!(length.ranges.len(), 1);
+    assert!(length.ranges[0].min_exclusive);
+
+    let window = &dict.get_item("_range_test.window").unwrap().constraints;
+    assert_eq!(window.ranges.len(), 2);
+
+    let listed = &dict.get_item("_range_test.listed").unwrap().constraints;
+    assert_eq!(listed.ranges.len(), 2);
+    assert!(listed.ranges[1].max_exclusive);
+}
+
+#[test]
+fn test_strictly_positive_length() {
+    assert!(range_errors("_range_test.length", "0.001").is_empty());
+
+    let errors = range_errors("_range_test.length", "0.0");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].expected.as_deref(), Some("(0.0:)"));
+    assert_eq!(
+        errors[0].message,
+        "Value 0 for '_range_test.length' is outside allowed range (0.0:)"
+    );
+}
+
+#[test]
+fn test_exclusive_angle() {
+    assert!(range_errors("_range_test.angle", "90").is_empty());
+    assert!(range_errors("_range_test.angle", "179.99").is_empty());
+
+    for value in ["0", "180", "-10", "200"] {
+        let errors = range_errors("_range_test.angle", value);
+        assert_eq!(errors.len(), 1, "{value} should be out of range");
+        assert_eq!(errors[0].expected.as_deref(), Some("(0.0:180.0)"));
+    }
+}
+
+#[test]
+fn test_two_interval_constraint() {
+    for value in ["0", "5", "10", "20", "30"] {
+        assert!(
+            range_errors("_range_test.window", value).is_empty(),
+            "{value} should be permitted"
+        );
+    }
+
+    let errors = range_errors("_range_test.window", "15");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].expected.as_deref(), Some("0.0:10.0 or 20.0:30.0"));
+    assert!(errors[0].message.contains("0.0:10.0 or 20.0:30.0"));
+
+    assert_eq!(range_errors("_range_test.window", "30.5").len(), 1);
+}
+
+#[test]
+fn test_listed_intervals() {
+    assert!(range_errors("_range_test.listed", "-0.75").is_empty());
+    assert!(range_errors("_range_test.listed", "0.5").is_empty());
+
+    let errors = range_errors("_range_test.listed", "1.0");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].expected.as_deref(),
+        Some("-1.0:-0.5 or [0.5:1.0)")
+    );
+}
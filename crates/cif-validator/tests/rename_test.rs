@@ -0,0 +1,169 @@
+//! Integration tests for renaming legacy tags to canonical names
+
+mod common;
+
+use cif_parser::{CifDocument, RenameConflict, TagLocation};
+use cif_validator::{RenameStatus, ValidatedCif};
+use common::{validator, CORE_DICT};
+
+fn validated(doc: CifDocument) -> ValidatedCif {
+    validator(CORE_DICT)
+        .validate_typed(doc)
+        .expect("Failed to build ValidatedCif")
+}
+
+#[test]
+fn test_rename_legacy_fixture_to_canonical() {
+    let path = format!(
+        "{}/../../fixtures/ccdc_paracetamol.cif",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let original = CifDocument::from_file(&path).expect("Failed to parse CIF");
+    let legacy = validated(original.clone());
+
+    let report = legacy.rename_report();
+    assert!(report.is_clean());
+    assert_eq!(report.safe().count(), report.entries.len());
+    let hm = report
+        .entries
+        .iter()
+        .find(|entry| entry.tag == "_symmetry_space_group_name_H-M")
+        .unwrap();
+    assert_eq!(hm.canonical.as_deref(), Some("_space_group.name_H-M_full"));
+
+    let mut doc = original.clone();
+    let outcome = doc.rename_tags(&legacy.canonical_renames());
+    assert!(outcome.is_complete());
+    assert_eq!(outcome.renamed.len(), report.entries.len());
+
+    // Values, spans and column order survive the rename
+    let before = original.first_block().unwrap();
+    let after = doc.first_block().unwrap();
+    assert_eq!(
+        after.get_item("_cell.length_a"),
+        before.get_item("_cell_length_a")
+    );
+    assert!(after.get_item("_cell_length_a").is_none());
+    assert_eq!(
+        after.loops[0].tags,
+        ["_space_group_symop.id", "_space_group_symop.operation_xyz"]
+    );
+    assert_eq!(after.loops[0].values, before.loops[0].values);
+
+    let result = validator(CORE_DICT)
+        .validate(&doc)
+        .expect("Validation failed");
+    assert!(result.errors.is_empty(), "errors: {:?}", result.errors);
+    assert!(
+        result.warnings.is_empty(),
+        "warnings: {:?}",
+        result.warnings
+    );
+
+    let modern = validated(doc).rename_report();
+    assert!(modern
+        .entries
+        .iter()
+        .all(|entry| entry.status == RenameStatus::Canonical));
+}
+
+#[test]
+fn test_collisions_are_refused_per_block() {
+    let input = "\
+data_clash
+_cell_length_a 5.0
+_cell.length_a 5.1
+_cell_length_b 6.0
+loop_
+_symmetry_equiv_pos_as_xyz
+_space_group_symop_operation_xyz
+x,y,z x,y,z
+
+data_clean
+_cell_length_a 7.0
+_Unknown_tag 1
+";
+    let original = CifDocument::parse(input).expect("Failed to parse CIF");
+    let validated = validated(original.clone());
+    let report = validated.rename_report();
+
+    let statuses: Vec<(&str, &str, &RenameStatus)> = report
+        .entries
+        .iter()
+        .map(|e| (e.location.block.as_str(), e.tag.as_str(), &e.status))
+        .collect();
+    assert_eq!(
+        statuses,
+        [
+            ("clash", "_cell.length_a", &RenameStatus::Canonical),
+            (
+                "clash",
+                "_cell_length_a",
+                &RenameStatus::Conflict {
+                    conflict: RenameConflict::TargetExists {
+                        existing: "_cell.length_a".to_string()
+                    }
+                }
+            ),
+            ("clash", "_cell_length_b", &RenameStatus::Safe),
+            (
+                "clash",
+                "_symmetry_equiv_pos_as_xyz",
+                &RenameStatus::Conflict {
+                    conflict: RenameConflict::SameTarget {
+                        other: "_space_group_symop_operation_xyz".to_string()
+                    }
+                }
+            ),
+            (
+                "clash",
+                "_space_group_symop_operation_xyz",
+                &RenameStatus::Conflict {
+                    conflict: RenameConflict::SameTarget {
+                        other: "_symmetry_equiv_pos_as_xyz".to_string()
+                    }
+                }
+            ),
+            ("clean", "_Unknown_tag", &RenameStatus::Unknown),
+            ("clean", "_cell_length_a", &RenameStatus::Safe),
+        ]
+    );
+
+    let mut doc = original;
+    let outcome = doc.rename_tags(&validated.canonical_renames());
+    assert_eq!(outcome.refused.len(), 3);
+    let refused = &outcome.refused[0];
+    assert_eq!(
+        refused.location,
+        TagLocation {
+            block: "clash".to_string(),
+            frame: None
+        }
+    );
+    assert_eq!(refused.from, "_cell_length_a");
+    assert_eq!(refused.to, "_cell.length_a");
+
+    // Refused tags keep their names and values; the rest are renamed
+    let clash = doc.get_block("clash").unwrap();
+    assert_eq!(
+        clash.get_item("_cell_length_a").unwrap().as_numeric(),
+        Some(5.0)
+    );
+    assert_eq!(
+        clash.get_item("_cell.length_a").unwrap().as_numeric(),
+        Some(5.1)
+    );
+    assert!(clash.get_item("_cell.length_b").is_some());
+    assert_eq!(
+        clash.loops[0].tags,
+        [
+            "_symmetry_equiv_pos_as_xyz",
+            "_space_group_symop_operation_xyz"
+        ]
+    );
+    let clean = doc.get_block("clean").unwrap();
+    assert_eq!(
+        clean.get_item("_cell.length_a").unwrap().as_numeric(),
+        Some(7.0)
+    );
+}
@@ -0,0 +1,141 @@
+//! Integration tests for SARIF reports of validation results
+//!
+//! The report for sarif_findings.cif is checked in as
+//! fixtures/validation/sarif_findings.sarif. If a change to it is intended,
+//! rewrite it with
+//! CIF_UPDATE_GOLDEN=1 cargo test -p cif-validator --test sarif_test
+
+mod common;
+
+use cif_parser::{CifDocument, Span};
+use cif_validator::report::{to_sarif, SARIF_VERSION};
+use cif_validator::{ValidationResult, ValidationWarning, Validator};
+use common::{fixture_path, validator};
+use serde_json::Value;
+
+const SOURCE_URI: &str = "fixtures/validation/sarif_findings.cif";
+
+fn validate(name: &str) -> ValidationResult {
+    let doc = CifDocument::from_file(fixture_path(name)).expect("Failed to parse fixture");
+    validator(&fixture_path("validation/test_validation.dic"))
+        .validate(&doc)
+        .expect("Failed to validate")
+}
+
+#[test]
+fn test_golden_report() {
+    let result = validate("validation/sarif_findings.cif");
+    assert_eq!(result.error_count(), 2, "{:?}", result.errors);
+    let sarif = to_sarif(&result, SOURCE_URI);
+
+    let golden_path = fixture_path("validation/sarif_findings.sarif");
+    if std::env::var_os("CIF_UPDATE_GOLDEN").is_some() {
+        std::fs::write(&golden_path, &sarif).unwrap();
+        return;
+    }
+    let golden =
+        std::fs::read_to_string(&golden_path).unwrap_or_else(|e| panic!("{golden_path}: {e}"));
+    assert!(sarif == golden, "SARIF report differs from {golden_path}");
+}
+
+/// The properties SARIF 2.1.0 requires of each object written
+#[test]
+fn test_required_properties() {
+    let result = validate("validation/sarif_findings.cif");
+    let log: Value = serde_json::from_str(&to_sarif(&result, SOURCE_URI)).unwrap();
+
+    assert_eq!(log["version"], SARIF_VERSION);
+    assert!(log["$schema"]
+        .as_str()
+        .unwrap()
+        .ends_with("sarif-2.1.0.json"));
+    let runs = log["runs"].as_array().unwrap();
+    assert_eq!(runs.len(), 1);
+
+    let driver = &runs[0]["tool"]["driver"];
+    assert_eq!(driver["name"], "cif-validator");
+    let rules = driver["rules"].as_array().unwrap();
+    for rule in rules {
+        assert!(rule["id"].is_string());
+        assert!(rule["shortDescription"]["text"].is_string());
+        let level = rule["defaultConfiguration"]["level"].as_str().unwrap();
+        assert!(["error", "warning", "note"].contains(&level));
+    }
+
+    let results = runs[0]["results"].as_array().unwrap();
+    assert_eq!(results.len(), 2);
+    for result in results {
+        let index = result["ruleIndex"].as_u64().unwrap() as usize;
+        assert_eq!(result["ruleId"], rules[index]["id"]);
+        assert_eq!(result["level"], "error");
+        assert!(result["message"]["text"].is_string());
+
+        let location = &result["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], SOURCE_URI);
+        let region = &location["region"];
+        for key in ["startLine", "startColumn", "endLine", "endColumn"] {
+            assert!(region[key].as_u64().unwrap() >= 1, "{key}: {region}");
+        }
+    }
+}
+
+#[test]
+fn test_enumeration_suggestion_becomes_fix() {
+    let result = validate("validation/sarif_findings.cif");
+    let log: Value = serde_json::from_str(&to_sarif(&result, SOURCE_URI)).unwrap();
+    let results = log["runs"][0]["results"].as_array().unwrap();
+
+    let range = &results[0];
+    assert_eq!(range["ruleId"], "error.RangeError");
+    assert_eq!(
+        range["locations"][0]["physicalLocation"]["region"]["startLine"],
+        12
+    );
+    assert!(range.get("fixes").is_none());
+
+    let enumeration = &results[1];
+    assert_eq!(enumeration["ruleId"], "error.EnumerationError");
+    let fix = &enumeration["fixes"][0]["artifactChanges"][0];
+    assert_eq!(fix["artifactLocation"]["uri"], SOURCE_URI);
+    let replacement = &fix["replacements"][0];
+    assert_eq!(replacement["insertedContent"]["text"], "monoclinic");
+    assert_eq!(
+        replacement["deletedRegion"],
+        enumeration["locations"][0]["physicalLocation"]["region"]
+    );
+}
+
+#[test]
+fn test_warnings_use_their_own_rules() {
+    let mut result = ValidationResult::new();
+    result.add_warning(ValidationWarning::looped_set_category(
+        "cell",
+        Span::new(3, 1, 5, 10),
+    ));
+    let log: Value = serde_json::from_str(&to_sarif(&result, "x.cif")).unwrap();
+
+    let warning = &log["runs"][0]["results"][0];
+    assert_eq!(warning["ruleId"], "warning.LoopedSetCategory");
+    assert_eq!(warning["level"], "warning");
+    assert!(warning["locations"][0].get("logicalLocations").is_none());
+}
+
+#[test]
+fn test_baselined_findings_are_suppressed() {
+    let doc = CifDocument::from_file(fixture_path("validation/sarif_findings.cif")).unwrap();
+    let baseline = validate("validation/sarif_findings.cif").to_baseline();
+    let result = Validator::new()
+        .with_dictionary_file(&fixture_path("validation/test_validation.dic"))
+        .unwrap()
+        .with_baseline(baseline)
+        .validate(&doc)
+        .unwrap();
+    assert!(result.errors.is_empty());
+
+    let log: Value = serde_json::from_str(&to_sarif(&result, SOURCE_URI)).unwrap();
+    let results = log["runs"][0]["results"].as_array().unwrap();
+    assert_eq!(results.len(), 2);
+    assert!(results
+        .iter()
+        .all(|r| r["suppressions"][0]["kind"] == "external"));
+}
@@ -0,0 +1,128 @@
+//! Integration tests for Set-category items written as a one-row loop
+
+mod common;
+
+use cif_parser::CifDocument;
+use cif_validator::{ErrorCategory, ValidationMode, WarningCategory};
+use common::{fixture_path, validator, CORE_DICT};
+
+const FIXTURE: &str = "validation/cell_one_row_loop.cif";
+
+fn parse(fixture: &str) -> CifDocument {
+    CifDocument::from_file(fixture_path(fixture)).expect("Failed to parse fixture")
+}
+
+#[test]
+fn test_lenient_accepts_one_row_loop() {
+    let result = validator(CORE_DICT)
+        .with_mode(ValidationMode::Lenient)
+        .validate(&parse(FIXTURE))
+        .unwrap();
+
+    assert!(result.is_valid, "{:?}", result.errors);
+    assert!(result
+        .warnings
+        .iter()
+        .all(|w| w.category != WarningCategory::LoopedSetCategory));
+}
+
+#[test]
+fn test_strict_warns_once_per_loop() {
+    let result = validator(CORE_DICT)
+        .with_mode(ValidationMode::Strict)
+        .validate(&parse(FIXTURE))
+        .unwrap();
+
+    assert!(result.is_valid, "{:?}", result.errors);
+    let looped: Vec<_> = result
+        .warnings
+        .iter()
+        .filter(|w| w.category == WarningCategory::LoopedSetCategory)
+        .collect();
+    // The wavelength loop is a Loop category and is left alone
+    assert_eq!(looped.len(), 1, "{:?}", result.warnings);
+    assert_eq!(
+        looped[0].message,
+        "Set category 'cell' written as a one-row loop"
+    );
+    assert_eq!(looped[0].span.start_line, 4);
+}
+
+#[test]
+fn test_pedantic_rejects_one_row_loop() {
+    let result = validator(CORE_DICT)
+        .with_mode(ValidationMode::Pedantic)
+        .validate(&parse(FIXTURE))
+        .unwrap();
+
+    let errors: Vec<_> = result
+        .errors
+        .iter()
+        .filter(|e| e.category == ErrorCategory::LoopStructure)
+        .collect();
+    assert_eq!(errors.len(), 1, "{:?}", result.errors);
+    assert!(errors[0].message.contains("Set category 'cell'"));
+}
+
+#[test]
+fn test_several_rows_are_still_duplicates() {
+    let doc =
+        CifDocument::parse("data_x\nloop_\n_cell_length_a\n_cell_length_b\n10.0 12.0\n11.0 13.0\n")
+            .unwrap();
+    let result = validator(CORE_DICT)
+        .with_mode(ValidationMode::Lenient)
+        .validate(&doc)
+        .unwrap();
+
+    assert!(result
+        .errors
+        .iter()
+        .any(|e| e.category == ErrorCategory::DuplicateItem));
+}
+
+#[test]
+fn test_typed_access_through_one_row_loop() {
+    let cif = validator(CORE_DICT)
+        .with_mode(ValidationMode::Lenient)
+        .validate_typed(parse(FIXTURE))
+        .unwrap();
+    let block = cif.first_block().unwrap();
+
+    let a = block.get_typed::<f64>("_cell.length_a").unwrap();
+    assert_eq!(a.value, 10.0);
+    assert_eq!(a.definition().name, "_cell.length_a");
+    assert_eq!(
+        block.get_typed::<f64>("_cell_length_b").unwrap().value,
+        12.0
+    );
+    let c = cif.get_typed::<f64>("cell_one_row_loop", "_cell.length_c");
+    assert_eq!(c.unwrap().value, 7.5);
+
+    let cell = block.cell().unwrap();
+    assert_eq!(cell.lengths(), [10.0, 12.0, 7.5]);
+    assert_eq!(cell.beta.uncertainty, Some(0.1));
+}
+
+#[test]
+fn test_typed_access_is_the_same_for_standalone_items() {
+    let looped = parse(FIXTURE);
+    let standalone = CifDocument::parse(
+        "data_x\n_cell_length_a 10.0\n_cell_length_b 12.0(2)\n_cell_length_c 7.5\n\
+         _cell_angle_alpha 90\n_cell_angle_beta 104.3(1)\n_cell_angle_gamma 90\n",
+    )
+    .unwrap();
+
+    let cells: Vec<_> = [looped, standalone]
+        .into_iter()
+        .map(|doc| {
+            let cif = validator(CORE_DICT)
+                .with_mode(ValidationMode::Lenient)
+                .validate_typed(doc)
+                .unwrap();
+            let block = cif.first_block().unwrap();
+            let b = block.get_typed::<f64>("_cell.length_b").unwrap();
+            (b.value, b.raw.as_numeric_with_uncertainty(), block.cell())
+        })
+        .collect();
+    assert_eq!(cells[0], cells[1]);
+}
@@ -0,0 +1,44 @@
+//! Integration tests for numeric values broken up by digit-group separators
+
+mod common;
+
+use cif_parser::CifDocument;
+use cif_validator::ErrorCategory;
+use common::{fixture_path, validator, CORE_DICT};
+
+#[test]
+fn test_split_across_fields_single_diagnostic() {
+    let doc = CifDocument::from_file(fixture_path("validation/split_number_fields.cif"))
+        .expect("Failed to parse fixture");
+    let result = validator(CORE_DICT)
+        .validate(&doc)
+        .expect("Validation failed");
+
+    assert_eq!(result.errors.len(), 1, "errors: {:?}", result.errors);
+    let error = &result.errors[0];
+    assert_eq!(error.category, ErrorCategory::LoopStructure);
+    assert_eq!(
+        error.message,
+        "Row 3 appears to have a split numeric value '1 204.7' across two fields"
+    );
+    assert_eq!(error.span.start_line, 22);
+    assert_eq!(error.suggestions, vec!["Write the value as 1204.7"]);
+}
+
+#[test]
+fn test_embedded_separator_single_diagnostic() {
+    let doc = CifDocument::from_file(fixture_path("validation/split_number_separator.cif"))
+        .expect("Failed to parse fixture");
+    let result = validator(CORE_DICT)
+        .validate(&doc)
+        .expect("Validation failed");
+
+    assert_eq!(result.errors.len(), 1, "errors: {:?}", result.errors);
+    let error = &result.errors[0];
+    assert_eq!(error.category, ErrorCategory::LoopStructure);
+    assert_eq!(
+        error.message,
+        "Row 3 has a numeric value '1_204.7' with embedded digit separators"
+    );
+    assert_eq!(error.suggestions, vec!["Write the value as 1204.7"]);
+}
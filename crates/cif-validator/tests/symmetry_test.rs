@@ -0,0 +1,112 @@
+//! Integration tests for symmetry operator and unit cell extraction
+
+mod common;
+
+use cif_parser::{CifDocument, Span};
+use cif_validator::{ErrorCategory, SymOp, ValidatedCif};
+use common::{fixture_path, validator, CORE_DICT};
+
+fn validated(fixture: &str) -> ValidatedCif {
+    let doc = CifDocument::from_file(fixture_path(fixture)).expect("Failed to parse fixture");
+    validator(CORE_DICT)
+        .validate_typed(doc)
+        .expect("Failed to validate")
+}
+
+fn ops(operators: &[&str]) -> Vec<SymOp> {
+    operators.iter().map(|op| op.parse().unwrap()).collect()
+}
+
+#[test]
+fn test_legacy_spelling() {
+    let cif = validated("pycifrw_xanthine.cif");
+    let block = cif.first_block().unwrap();
+
+    let operations = block.symmetry_operations().unwrap();
+    assert_eq!(
+        operations,
+        ops(&["x,y,z", "-x,y+1/2,-z+1/2", "-x,-y,-z", "x,-y+1/2,z+1/2"])
+    );
+    assert!(operations[0].is_identity());
+
+    let cell = block.cell().unwrap();
+    assert_eq!(cell.lengths(), [10.01, 18.23, 6.93]);
+    assert_eq!(cell.angles(), [90.0, 107.5, 90.0]);
+    assert!((cell.a.uncertainty.unwrap() - 0.11).abs() < 1e-12);
+    assert!((cell.beta.uncertainty.unwrap() - 0.9).abs() < 1e-12);
+    assert_eq!(cell.alpha.uncertainty, None);
+    assert!((cell.volume() - 1204.62).abs() < 5.0);
+}
+
+#[test]
+fn test_cif1_form_of_modern_spelling() {
+    let cif = validated("cod_urea.cif");
+    let block = cif.first_block().unwrap();
+
+    let operations = block.symmetry_operations().unwrap();
+    assert_eq!(operations.len(), 192);
+    assert_eq!(operations[9], "-y,z,-x".parse().unwrap());
+
+    let cell = block.cell().unwrap();
+    assert_eq!(cell.lengths(), [5.430941; 3]);
+    assert!((cell.volume() - 160.186).abs() < 1e-3);
+}
+
+#[test]
+fn test_modern_spelling() {
+    let cif = validated("validation/symmetry_modern.cif");
+    let block = cif.first_block().unwrap();
+
+    assert_eq!(
+        block.symmetry_operations().unwrap(),
+        ops(&["x,y,z", "-x,y+1/2,-z+1/2", "-x,-y,-z", "x,-y+1/2,z+1/2"])
+    );
+
+    let cell = block.cell().unwrap();
+    assert_eq!(cell.a.value, 7.470);
+    assert_eq!(cell.a.uncertainty, Some(0.006));
+    assert!((cell.b.uncertainty.unwrap() - 0.004).abs() < 1e-12);
+    assert_eq!(cell.beta.value, 112.4);
+    assert_eq!(cell.gamma.uncertainty, None);
+}
+
+#[test]
+fn test_bad_operators_carry_spans() {
+    let cif = validated("validation/symmetry_bad_operator.cif");
+    let block = cif.first_block().unwrap();
+
+    let errors = block.symmetry_operations().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(errors
+        .iter()
+        .all(|e| e.category == ErrorCategory::TypeError));
+    assert!(errors
+        .iter()
+        .all(|e| e.data_name.as_deref() == Some("_symmetry_equiv_pos_as_xyz")));
+
+    assert_eq!(errors[0].actual.as_deref(), Some("-x,y+1/2"));
+    assert_eq!(errors[0].span, Span::new(6, 1, 6, 11));
+    assert_eq!(errors[1].actual.as_deref(), Some("x,-y+1/2,w+1/2"));
+    assert_eq!(errors[1].span, Span::new(8, 1, 8, 17));
+    assert!(errors[1].message.contains("unexpected character 'w'"));
+}
+
+#[test]
+fn test_missing_angle() {
+    let cif = validated("validation/cell_missing_angle.cif");
+    let block = cif.first_block().unwrap();
+
+    assert!(block.cell().is_none());
+    assert_eq!(block.symmetry_operations().unwrap(), ops(&["x,y,z"]));
+}
+
+#[test]
+fn test_block_without_symmetry() {
+    let cif = validated("simple.cif");
+    let block = cif.first_block().unwrap();
+
+    assert_eq!(block.symmetry_operations().unwrap(), Vec::new());
+    let cell = block.cell().unwrap();
+    assert_eq!(cell.lengths(), [10.0, 10.0, 15.0]);
+    assert!((cell.volume() - 1500.0).abs() < 1e-9);
+}
@@ -0,0 +1,84 @@
+//! Integration tests for text fields that appear to contain CIF structure
+
+mod common;
+
+use cif_parser::CifDocument;
+use cif_validator::{ValidationMode, Validator, WarningCategory};
+use common::{fixture_path, validator, CORE_DICT};
+
+fn text_warnings(validator: &Validator, fixture: &str) -> Vec<cif_validator::ValidationWarning> {
+    let doc = CifDocument::from_file(fixture_path(&format!("validation/{fixture}")))
+        .expect("Failed to parse fixture");
+    let result = validator.validate(&doc).expect("Validation failed");
+    result
+        .warnings
+        .into_iter()
+        .filter(|w| w.category == WarningCategory::SuspiciousTextField)
+        .collect()
+}
+
+#[test]
+fn test_swallowed_items_warn_in_pedantic_mode() {
+    let doc = CifDocument::from_file(fixture_path("validation/text_field_swallowed.cif"))
+        .expect("Failed to parse fixture");
+    // The items really are gone: they are part of the text value
+    assert!(doc.blocks[0].get_item("_cell.length_b").is_none());
+
+    let warnings = text_warnings(
+        &validator(CORE_DICT).with_mode(ValidationMode::Pedantic),
+        "text_field_swallowed.cif",
+    );
+    assert_eq!(warnings.len(), 1, "warnings: {warnings:?}");
+    let warning = &warnings[0];
+    assert!(warning.message.contains("'_exptl_crystal.preparation'"));
+    assert!(warning
+        .message
+        .contains("'_cell.length_b                    11.2'"));
+    assert!(warning.message.contains("closing ';' may be missing"));
+    assert_eq!(warning.span.start_line, 8);
+    assert_eq!(warning.span.start_col, 1);
+    assert_eq!(warning.span.end_line, 8);
+}
+
+#[test]
+fn test_swallowed_items_silent_outside_pedantic_mode() {
+    for mode in [ValidationMode::Strict, ValidationMode::Lenient] {
+        let warnings = text_warnings(
+            &validator(CORE_DICT).with_mode(mode),
+            "text_field_swallowed.cif",
+        );
+        assert!(warnings.is_empty(), "{mode:?}: {warnings:?}");
+    }
+}
+
+#[test]
+fn test_documentation_quoting_cif_can_be_suppressed() {
+    let pedantic = validator(CORE_DICT).with_mode(ValidationMode::Pedantic);
+    let warnings = text_warnings(&pedantic, "text_field_quotes_cif.cif");
+    assert_eq!(warnings.len(), 1, "warnings: {warnings:?}");
+    // Indented quote inside the field: column points at the tag, not the indent
+    assert_eq!(warnings[0].span.start_line, 7);
+    assert_eq!(warnings[0].span.start_col, 5);
+
+    let suppressed = validator(CORE_DICT)
+        .with_mode(ValidationMode::Pedantic)
+        .allow_structure_in_text(true);
+    let warnings = text_warnings(&suppressed, "text_field_quotes_cif.cif");
+    assert!(warnings.is_empty(), "warnings: {warnings:?}");
+}
+
+#[test]
+fn test_plain_text_field_does_not_warn() {
+    let doc = CifDocument::parse(
+        "data_plain\n_exptl_crystal.preparation\n;\nGrown from ethanol at 293 K.\n_ underscores alone and loop_ mid-line are fine\n;\n",
+    )
+    .unwrap();
+    let result = validator(CORE_DICT)
+        .with_mode(ValidationMode::Pedantic)
+        .validate(&doc)
+        .expect("Validation failed");
+    assert!(!result
+        .warnings
+        .iter()
+        .any(|w| w.category == WarningCategory::SuspiciousTextField));
+}
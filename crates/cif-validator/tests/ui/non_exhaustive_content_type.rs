@@ -0,0 +1,27 @@
+// ContentType is non-exhaustive: naming every variant is not enough
+use cif_validator::prelude::*;
+
+fn is_numeric(content: ContentType) -> bool {
+    match content {
+        ContentType::Real | ContentType::Integer | ContentType::Count | ContentType::Index => true,
+        ContentType::Text
+        | ContentType::Word
+        | ContentType::Code
+        | ContentType::Name
+        | ContentType::Tag
+        | ContentType::Uri
+        | ContentType::Date
+        | ContentType::DateTime
+        | ContentType::Version
+        | ContentType::Dimension
+        | ContentType::Range
+        | ContentType::Complex
+        | ContentType::Binary
+        | ContentType::ByReference
+        | ContentType::Implied => false,
+    }
+}
+
+fn main() {
+    println!("{}", is_numeric(ContentType::Real));
+}
@@ -0,0 +1,21 @@
+// ErrorCategory is non-exhaustive: naming every variant is not enough
+use cif_validator::prelude::*;
+
+fn is_fatal(category: ErrorCategory) -> bool {
+    match category {
+        ErrorCategory::UnknownDataName => false,
+        ErrorCategory::TypeError
+        | ErrorCategory::RangeError
+        | ErrorCategory::EnumerationError
+        | ErrorCategory::MissingMandatory
+        | ErrorCategory::LoopStructure
+        | ErrorCategory::LinkError
+        | ErrorCategory::DictionaryError
+        | ErrorCategory::DuplicateItem
+        | ErrorCategory::UnknownMandatoryValue => true,
+    }
+}
+
+fn main() {
+    println!("{}", is_fatal(ErrorCategory::TypeError));
+}
@@ -0,0 +1,21 @@
+// WarningCategory is non-exhaustive: naming every variant is not enough
+use cif_validator::prelude::*;
+
+fn is_style(category: WarningCategory) -> bool {
+    match category {
+        WarningCategory::Style | WarningCategory::ImpliedType => true,
+        WarningCategory::MixedCategories
+        | WarningCategory::DeprecatedItem
+        | WarningCategory::UnknownItem
+        | WarningCategory::DuplicateItem
+        | WarningCategory::SuspiciousTextField
+        | WarningCategory::UnknownMandatoryValue
+        | WarningCategory::NotApplicableMandatory
+        | WarningCategory::LoopedSetCategory
+        | WarningCategory::DanglingBlockLink => false,
+    }
+}
+
+fn main() {
+    println!("{}", is_style(WarningCategory::Style));
+}
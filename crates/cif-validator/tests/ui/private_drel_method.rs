@@ -0,0 +1,11 @@
+// The method text is private so the cached parse cannot go stale;
+// set_drel_method replaces both
+use cif_validator::dictionary::DataItem;
+
+fn clear_method(item: &mut DataItem) {
+    item.drel_method = None;
+}
+
+fn main() {
+    let _ = clear_method;
+}
@@ -0,0 +1,91 @@
+//! Integration tests for the units vocabulary and dictionary unit checks
+
+use cif_parser::CifDocument;
+use cif_validator::dictionary::{
+    dictionary_warnings, load_dictionary, validate_dictionary, Dimension, Units,
+};
+use cif_validator::{load_dictionary_file, DictionaryError};
+
+const DICT_PATH: &str = "dics/cif_core.dic";
+
+const LOCAL_DICT: &str = r#"#\#CIF_2.0
+data_LOCAL_DICT
+    _dictionary.title             LOCAL_DICT
+
+save_probe
+    _definition.id                PROBE
+    _definition.scope             Category
+    _definition.class             Set
+save_
+
+save_probe.distance
+    _definition.id                '_probe.distance'
+    _name.category_id             probe
+    _name.object_id               distance
+    _type.contents                Real
+    _units.code                   angstroem
+save_
+
+save_probe.angle
+    _definition.id                '_probe.angle'
+    _name.category_id             probe
+    _name.object_id               angle
+    _type.contents                Real
+    _units.code                   degrees
+save_
+"#;
+
+#[test]
+fn test_misspelled_unit_code_suggests_nearest() {
+    let doc = CifDocument::parse(LOCAL_DICT).unwrap();
+    let dict = load_dictionary(&doc).unwrap();
+
+    assert!(validate_dictionary(&dict).is_empty());
+    let findings = dictionary_warnings(&dict);
+    assert_eq!(findings.len(), 1, "findings: {findings:?}");
+    let finding = &findings[0];
+    assert!(finding.is_warning());
+    match finding {
+        DictionaryError::UnknownUnits {
+            item,
+            code,
+            suggestion,
+            span,
+        } => {
+            assert_eq!(item, "_probe.distance");
+            assert_eq!(code, "angstroem");
+            assert_eq!(suggestion.as_deref(), Some("angstroms"));
+            assert_eq!(Some(*span), finding.span());
+        }
+        other => panic!("unexpected finding: {other}"),
+    }
+    assert!(finding.to_string().contains("did you mean 'angstroms'?"));
+
+    // An unknown code gives no SI factor; a known one does
+    assert_eq!(dict.get_item("_probe.distance").unwrap().si_factor(), None);
+    let degrees = dict.get_item("_probe.angle").unwrap().si_factor().unwrap();
+    assert!((degrees - std::f64::consts::PI / 180.0).abs() < 1e-15);
+}
+
+#[test]
+fn test_angstrom_nanometre_round_trip() {
+    let nm = Units::convert(12.345, "angstroms", "nanometres").unwrap();
+    assert!((nm - 1.2345).abs() < 1e-12);
+    let back = Units::convert(nm, "nanometres", "angstroms").unwrap();
+    assert!((back - 12.345).abs() < 1e-12);
+
+    let angstrom = Units::lookup("Angstroms").unwrap();
+    assert_eq!(angstrom.name, "angstrom");
+    assert_eq!(angstrom.dimension, Dimension::Length);
+    assert_eq!(angstrom.si_factor, 1e-10);
+}
+
+#[test]
+fn test_core_dictionary_units_are_all_known() {
+    let dict = load_dictionary_file(DICT_PATH).expect("Failed to load dictionary");
+    let unknown = dictionary_warnings(&dict);
+    assert!(unknown.is_empty(), "unknown units: {unknown:?}");
+
+    let volume = dict.get_item("_cell_volume").unwrap();
+    assert_eq!(volume.si_factor(), Some(1e-30));
+}
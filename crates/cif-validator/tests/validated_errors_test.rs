@@ -0,0 +1,74 @@
+//! Integration tests for the findings `validate_typed` keeps on `ValidatedCif`
+
+mod common;
+
+use cif_parser::CifDocument;
+use cif_validator::{ErrorCategory, ValidatedCif};
+use common::{fixture_path, validator};
+
+/// One cell length out of range, on line 3 from column 16
+const ONE_RANGE_ERROR: &str = "data_test
+_cell.length_a 10.5
+_cell.length_b 2000.0
+_cell.length_c 7.25
+";
+
+#[test]
+fn test_validate_typed_keeps_the_result() {
+    let doc = CifDocument::parse(ONE_RANGE_ERROR).unwrap();
+    let expected = validator(&fixture_path("validation/test_validation.dic"))
+        .validate(&doc)
+        .unwrap();
+    let validated = validator(&fixture_path("validation/test_validation.dic"))
+        .validate_typed(doc)
+        .unwrap();
+
+    assert!(!validated.is_valid());
+    let result = validated.result().expect("validate_typed validates");
+    assert_eq!(result.errors.len(), 1);
+    assert_eq!(result.errors[0].message, expected.errors[0].message);
+    assert_eq!(result.errors[0].category, ErrorCategory::RangeError);
+}
+
+#[test]
+fn test_errors_at_finds_the_error_inside_its_value() {
+    let doc = CifDocument::parse(ONE_RANGE_ERROR).unwrap();
+    let validated = validator(&fixture_path("validation/test_validation.dic"))
+        .validate_typed(doc)
+        .unwrap();
+    let error = &validated.result().unwrap().errors[0];
+    assert_eq!(
+        (error.span.start_line, error.span.start_col),
+        (3, 16),
+        "span: {}",
+        error.span
+    );
+
+    for col in error.span.start_col..=error.span.end_col {
+        let found = validated.errors_at(3, col);
+        assert_eq!(found.len(), 1, "column {col}");
+        assert!(std::ptr::eq(found[0], error));
+    }
+    assert!(validated.errors_at(3, error.span.start_col - 1).is_empty());
+    assert!(validated.errors_at(3, error.span.end_col + 1).is_empty());
+    assert!(validated.errors_at(2, 17).is_empty());
+    assert!(validated.errors_at(4, 16).is_empty());
+
+    // The definition at the same position is the item in error
+    let def = validated.definition_at(3, 17).unwrap();
+    assert_eq!(def.name, "_cell.length_b");
+}
+
+#[test]
+fn test_new_does_not_validate() {
+    let doc = CifDocument::parse(ONE_RANGE_ERROR).unwrap();
+    let (dict, _) = validator(&fixture_path("validation/test_validation.dic"))
+        .combined_dictionary()
+        .unwrap();
+    let validated = ValidatedCif::new(doc, dict.into());
+
+    assert!(validated.result().is_none());
+    assert!(!validated.is_valid());
+    assert!(validated.errors_at(3, 17).is_empty());
+    assert!(validated.definition_at(3, 17).is_some());
+}
@@ -0,0 +1,183 @@
+//! Find the data items a dREL method assigns
+//!
+//! A category-level method may compute several items at once instead of each
+//! item carrying its own method. Knowing which items a method writes lets a
+//! dictionary attribute the method to each of them.
+
+use crate::ast::{Expr, ExprKind, Stmt, StmtKind};
+use std::collections::{BTreeSet, HashMap};
+
+/// Collect the data names assigned anywhere in a dREL program
+///
+/// Recognized assignment targets:
+/// - data names: `_cell.volume = ...`
+/// - attributes of a `Loop` or `With` variable bound to a category:
+///   `With c as cell  c.volume = ...` (a `With` without braces binds the
+///   variable for the rest of the enclosing block)
+/// - attributes of a category name used directly: `cell.volume = ...`
+///
+/// Subscripted targets (`_cell.vector_a[0] = ...`) count as assigning the
+/// item. Names are returned as `_category.object`, sorted and without
+/// duplicates.
+///
+/// # Example
+///
+/// ```
+/// use drel_parser::{analysis::assigned_items, parse};
+///
+/// let stmts = parse("With c as cell\n  c.volume = 1.0\n_cell.area = 2.0").unwrap();
+/// assert_eq!(assigned_items(&stmts), ["_cell.area", "_cell.volume"]);
+/// ```
+pub fn assigned_items(stmts: &[Stmt]) -> Vec<String> {
+    let mut collector = AssignmentCollector::default();
+    collector.visit_block(stmts);
+    collector.items.into_iter().collect()
+}
+
+/// Internal visitor tracking category bindings and assigned names
+#[derive(Default)]
+struct AssignmentCollector {
+    items: BTreeSet<String>,
+    /// Variables bound to categories by `Loop` or `With`
+    bindings: HashMap<String, String>,
+}
+
+impl AssignmentCollector {
+    fn visit_block(&mut self, stmts: &[Stmt]) {
+        // A `With` without braces parses with an empty body; its binding
+        // covers the rest of the enclosing block
+        let mut scoped = Vec::new();
+        for stmt in stmts {
+            if let StmtKind::With { var, value, body } = &stmt.kind {
+                if let (ExprKind::Identifier(category), true) = (&value.kind, body.is_empty()) {
+                    let previous = self.bindings.insert(var.clone(), category.clone());
+                    scoped.push((var, previous));
+                    continue;
+                }
+            }
+            self.visit_stmt(stmt);
+        }
+        for (var, previous) in scoped.into_iter().rev() {
+            self.restore(var, previous);
+        }
+    }
+
+    /// Visit a block with a variable bound to a category
+    fn visit_bound(&mut self, var: &str, category: &str, body: &[Stmt]) {
+        let previous = self.bindings.insert(var.to_string(), category.to_string());
+        self.visit_block(body);
+        self.restore(var, previous);
+    }
+
+    /// Put back the binding a variable had before it was shadowed
+    fn restore(&mut self, var: &str, previous: Option<String>) {
+        match previous {
+            Some(category) => self.bindings.insert(var.to_string(), category),
+            None => self.bindings.remove(var),
+        };
+    }
+
+    fn visit_stmt(&mut self, stmt: &Stmt) {
+        match &stmt.kind {
+            StmtKind::If {
+                then_block,
+                elseif_blocks,
+                else_block,
+                ..
+            } => {
+                self.visit_block(then_block);
+                for (_, block) in elseif_blocks {
+                    self.visit_block(block);
+                }
+                if let Some(block) = else_block {
+                    self.visit_block(block);
+                }
+            }
+            StmtKind::Loop {
+                var,
+                category,
+                body,
+                ..
+            } => self.visit_bound(var, category, body),
+            StmtKind::With { var, value, body } => match &value.kind {
+                ExprKind::Identifier(category) => self.visit_bound(var, category, body),
+                _ => self.visit_block(body),
+            },
+            StmtKind::For { body, .. }
+            | StmtKind::Do { body, .. }
+            | StmtKind::Repeat { body }
+            | StmtKind::FunctionDef { body, .. } => self.visit_block(body),
+            StmtKind::Assignment { target, .. } => {
+                if let Some(name) = self.target_name(target) {
+                    self.items.insert(name);
+                }
+            }
+            StmtKind::Expr(_) | StmtKind::Break | StmtKind::Next => {}
+        }
+    }
+
+    /// The data name an assignment target refers to, if it is an item
+    fn target_name(&self, target: &Expr) -> Option<String> {
+        match &target.kind {
+            ExprKind::DataName { category, object } => Some(format!("_{category}.{object}")),
+            ExprKind::AttributeRef { target, attribute } => match &target.kind {
+                ExprKind::Identifier(name) => {
+                    let category = self.bindings.get(name).unwrap_or(name);
+                    Some(format!("_{category}.{attribute}"))
+                }
+                _ => None,
+            },
+            ExprKind::Subscription { target, .. } => self.target_name(target),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn test_data_name_targets() {
+        let stmts = parse("_cell.volume = 1.0\n_cell.volume = 2.0\nx = 3").unwrap();
+        assert_eq!(assigned_items(&stmts), ["_cell.volume"]);
+    }
+
+    #[test]
+    fn test_bound_variables() {
+        let stmts = parse(
+            r#"
+            Loop a as atom_site {
+                a.fract_x = 0.5
+                If (a.occupancy < 1) { a.calc_flag = "dum" }
+            }
+            atom_type.number_in_cell = 4
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            assigned_items(&stmts),
+            [
+                "_atom_site.calc_flag",
+                "_atom_site.fract_x",
+                "_atom_type.number_in_cell"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_with_without_braces() {
+        let stmts = parse("With g as cell_geom\n\ng.area_ab = 1\ng.area_bc = 2").unwrap();
+        assert_eq!(
+            assigned_items(&stmts),
+            ["_cell_geom.area_ab", "_cell_geom.area_bc"]
+        );
+    }
+
+    #[test]
+    fn test_reads_are_not_assignments() {
+        let stmts = parse("mass = _cell.atomic_mass / _cell.volume").unwrap();
+        assert!(assigned_items(&stmts).is_empty());
+    }
+}
@@ -6,17 +6,20 @@
 use super::references::extract_references;
 use crate::ast::{Span, Stmt};
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 
 /// A dependency graph showing relationships between data items
+///
+/// Items are kept sorted by name, so cycle searches, topological order and
+/// serialized output are the same on every run.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DependencyGraph {
     /// Map from item name to the items it depends on
-    dependencies: HashMap<String, HashSet<String>>,
+    dependencies: BTreeMap<String, BTreeSet<String>>,
     /// Map from item name to the items that depend on it
-    dependents: HashMap<String, HashSet<String>>,
+    dependents: BTreeMap<String, BTreeSet<String>>,
     /// Track where each dependency was declared: (from, to) -> all spans where it appears
-    dependency_spans: HashMap<(String, String), Vec<Span>>,
+    dependency_spans: BTreeMap<(String, String), Vec<Span>>,
 }
 
 impl DependencyGraph {
@@ -65,12 +68,12 @@ impl DependencyGraph {
     }
 
     /// Get all items that `item` depends on
-    pub fn get_dependencies(&self, item: &str) -> Option<&HashSet<String>> {
+    pub fn get_dependencies(&self, item: &str) -> Option<&BTreeSet<String>> {
         self.dependencies.get(item)
     }
 
     /// Get all items that depend on `item`
-    pub fn get_dependents(&self, item: &str) -> Option<&HashSet<String>> {
+    pub fn get_dependents(&self, item: &str) -> Option<&BTreeSet<String>> {
         self.dependents.get(item)
     }
 
@@ -80,9 +83,9 @@ impl DependencyGraph {
             .get(&(from.to_string(), to.to_string()))
     }
 
-    /// Get all items in the graph
-    pub fn all_items(&self) -> HashSet<&str> {
-        let mut items = HashSet::new();
+    /// Get all items in the graph, sorted by name
+    pub fn all_items(&self) -> BTreeSet<&str> {
+        let mut items = BTreeSet::new();
         for key in self.dependencies.keys() {
             items.insert(key.as_str());
         }
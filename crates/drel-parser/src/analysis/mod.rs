@@ -3,11 +3,14 @@
 //! This module provides functions for analyzing dREL programs without
 //! evaluating them, including:
 //! - Extracting item references (data names, categories)
+//! - Finding the items a method assigns
 //! - Building dependency graphs
 //! - Validating references against dictionaries
 
+mod assignments;
 mod dependencies;
 mod references;
 
+pub use assignments::assigned_items;
 pub use dependencies::{build_dependency_graph, DependencyGraph};
 pub use references::{extract_references, ItemReference, ReferenceKind};
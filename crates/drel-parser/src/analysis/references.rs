@@ -90,6 +90,8 @@ impl ItemReference {
 /// - Data names (e.g., _cell.length_a)
 /// - Categories (e.g., atom_site in Loop statements)
 ///
+/// Each distinct reference appears once, in source order.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -107,7 +109,15 @@ pub fn extract_references(stmts: &[Stmt]) -> Vec<ItemReference> {
         collector.visit_stmt(stmt);
     }
 
-    refs.into_iter().collect()
+    let mut refs: Vec<ItemReference> = refs.into_iter().collect();
+    refs.sort_by(|a, b| {
+        (a.span.start_line, a.span.start_col, a.full_name()).cmp(&(
+            b.span.start_line,
+            b.span.start_col,
+            b.full_name(),
+        ))
+    });
+    refs
 }
 
 /// Internal visitor for collecting references
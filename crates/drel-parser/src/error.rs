@@ -1,47 +1,69 @@
 //! Error types for dREL parsing
+//!
+//! Every error carries the [`Span`] it applies to, relative to the method
+//! source. The parser also records the source line the error starts on, so
+//! that the [`Display`](fmt::Display) output can quote it:
+//!
+//! ```text
+//! Unexpected '=', expected expression at 2:5
+//!    2 | a = = 5
+//!      |     ^
+//! ```
 
-use thiserror::Error;
+use std::fmt;
+
+use crate::ast::Span;
 
 /// Errors that can occur during dREL parsing
-#[derive(Error, Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum DrelError {
-    /// Grammar/syntax error from PEST parser
-    #[error("Parse error: {0}")]
-    ParseError(Box<pest::error::Error<crate::Rule>>),
+    /// A token the grammar does not allow at this point
+    UnexpectedToken {
+        /// The token found, quoted, or `end of input`
+        found: String,
+        /// What the grammar would have accepted
+        expected: String,
+        /// Location of the token
+        span: Span,
+        /// Source line the token is on, once known
+        source_line: Option<String>,
+    },
+
+    /// A `{` with no matching `}`
+    UnterminatedBlock {
+        /// Location of the opening brace
+        opened_at: Span,
+        /// Source line the brace is on, once known
+        source_line: Option<String>,
+    },
+
+    /// The left-hand side of an assignment is not something that can be
+    /// assigned to (a literal, an operator expression or a call)
+    InvalidAssignTarget {
+        /// Location of the target expression
+        span: Span,
+        /// Source line the target starts on, once known
+        source_line: Option<String>,
+    },
 
     /// Invalid AST construction
-    #[error("Invalid structure at {location}: {message}")]
     InvalidStructure {
         /// Error message describing the issue
         message: String,
-        /// Location in source (line:column)
-        location: String,
-    },
-
-    /// Unexpected token or construct
-    #[error("Unexpected {found} at {location}, expected {expected}")]
-    Unexpected {
-        /// What was found
-        found: String,
-        /// What was expected
-        expected: String,
         /// Location in source
-        location: String,
+        span: Span,
+        /// Source line the error starts on, once known
+        source_line: Option<String>,
     },
 }
 
-impl From<pest::error::Error<crate::Rule>> for DrelError {
-    fn from(err: pest::error::Error<crate::Rule>) -> Self {
-        Self::ParseError(Box::new(err))
-    }
-}
-
 impl DrelError {
     /// Create an invalid structure error
     pub fn invalid_structure(message: impl Into<String>, line: usize, col: usize) -> Self {
         Self::InvalidStructure {
             message: message.into(),
-            location: format!("{}:{}", line, col),
+            span: Span::point(line, col),
+            source_line: None,
         }
     }
 
@@ -52,10 +74,103 @@ impl DrelError {
         line: usize,
         col: usize,
     ) -> Self {
-        Self::Unexpected {
+        Self::UnexpectedToken {
             found: found.into(),
             expected: expected.into(),
-            location: format!("{}:{}", line, col),
+            span: Span::point(line, col),
+            source_line: None,
+        }
+    }
+
+    /// Create an invalid assignment target error
+    pub fn invalid_assign_target(span: Span) -> Self {
+        Self::InvalidAssignTarget {
+            span,
+            source_line: None,
+        }
+    }
+
+    /// Create an unterminated block error for the brace at `opened_at`
+    pub fn unterminated_block(opened_at: Span) -> Self {
+        Self::UnterminatedBlock {
+            opened_at,
+            source_line: None,
+        }
+    }
+
+    /// Location of the error in the method source
+    pub fn span(&self) -> Span {
+        match self {
+            Self::UnexpectedToken { span, .. }
+            | Self::InvalidAssignTarget { span, .. }
+            | Self::InvalidStructure { span, .. } => *span,
+            Self::UnterminatedBlock { opened_at, .. } => *opened_at,
         }
     }
+
+    /// The source line quoted by [`Display`](fmt::Display), if known
+    pub fn source_line(&self) -> Option<&str> {
+        match self {
+            Self::UnexpectedToken { source_line, .. }
+            | Self::UnterminatedBlock { source_line, .. }
+            | Self::InvalidAssignTarget { source_line, .. }
+            | Self::InvalidStructure { source_line, .. } => source_line.as_deref(),
+        }
+    }
+
+    /// One-line description without location or source line
+    pub fn message(&self) -> String {
+        match self {
+            Self::UnexpectedToken {
+                found, expected, ..
+            } => format!("Unexpected {found}, expected {expected}"),
+            Self::UnterminatedBlock { .. } => "Block opened with '{' is never closed".to_string(),
+            Self::InvalidAssignTarget { .. } => {
+                "Invalid assignment target: only names, data names, subscriptions and \
+                 attributes can be assigned to"
+                    .to_string()
+            }
+            Self::InvalidStructure { message, .. } => message.clone(),
+        }
+    }
+
+    /// Record the line of `source` the error starts on, unless already known
+    pub fn with_source(mut self, source: &str) -> Self {
+        let line = self.span().start_line;
+        let text = line
+            .checked_sub(1)
+            .and_then(|index| source.lines().nth(index))
+            .map(|text| text.trim_end().to_string());
+        match &mut self {
+            Self::UnexpectedToken { source_line, .. }
+            | Self::UnterminatedBlock { source_line, .. }
+            | Self::InvalidAssignTarget { source_line, .. }
+            | Self::InvalidStructure { source_line, .. } => {
+                if source_line.is_none() {
+                    *source_line = text;
+                }
+            }
+        }
+        self
+    }
 }
+
+impl fmt::Display for DrelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let span = self.span();
+        write!(f, "{} at {}", self.message(), span)?;
+        if let Some(line) = self.source_line() {
+            let number = span.start_line.to_string();
+            let gutter = " ".repeat(number.len().max(4));
+            let indent: String = line
+                .chars()
+                .take(span.start_col.saturating_sub(1))
+                .map(|c| if c == '\t' { '\t' } else { ' ' })
+                .collect();
+            write!(f, "\n{number:>4} | {line}\n{gutter} | {indent}^")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for DrelError {}
@@ -54,7 +54,8 @@ pub use error::DrelError;
 
 // Re-export analysis types
 pub use analysis::{
-    build_dependency_graph, extract_references, DependencyGraph, ItemReference, ReferenceKind,
+    assigned_items, build_dependency_graph, extract_references, DependencyGraph, ItemReference,
+    ReferenceKind,
 };
 
 use pest::Parser;
@@ -79,8 +80,35 @@ use pest_parser::{DrelParser, Rule};
 /// "#)?;
 /// ```
 pub fn parse(source: &str) -> Result<Vec<Stmt>, DrelError> {
-    let pairs = DrelParser::parse(Rule::program, source)?;
-    parser::parse_program(pairs)
+    let pairs =
+        DrelParser::parse(Rule::program, source).map_err(|e| parser::pest_error(e, source))?;
+    parser::parse_program(pairs).map_err(|e| e.with_source(source))
+}
+
+/// Parse a dREL program, keeping every statement that parses
+///
+/// Where [`parse`] stops at the first error, this skips the top-level
+/// statement holding it and carries on, so a method with two independent
+/// mistakes yields both errors, in source order, and a [`Program`] of the
+/// statements that were fine. A `{` that is never closed swallows the rest
+/// of the method and is reported as [`DrelError::UnterminatedBlock`].
+///
+/// # Example
+///
+/// ```
+/// let (program, errors) = drel_parser::parse_lossy("a = 1\nb = = 2\nc = a + 1\n");
+/// assert_eq!(program.len(), 2);
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!(errors[0].span().start_line, 2);
+/// ```
+pub fn parse_lossy(source: &str) -> (Program, Vec<DrelError>) {
+    match parse(source) {
+        Ok(statements) => (Program::new(statements), Vec::new()),
+        Err(_) => {
+            let (statements, errors) = parser::parse_program_lossy(source);
+            (Program::new(statements), errors)
+        }
+    }
 }
 
 /// Parse a single dREL expression
@@ -91,8 +119,9 @@ pub fn parse(source: &str) -> Result<Vec<Stmt>, DrelError> {
 /// let expr = drel_parser::parse_expr("_cell.length_a * _cell.length_b")?;
 /// ```
 pub fn parse_expr(source: &str) -> Result<Expr, DrelError> {
-    let pairs = DrelParser::parse(Rule::expression, source)?;
-    parser::parse_expression(pairs)
+    let pairs =
+        DrelParser::parse(Rule::expression, source).map_err(|e| parser::pest_error(e, source))?;
+    parser::parse_expression(pairs).map_err(|e| e.with_source(source))
 }
 
 #[cfg(test)]
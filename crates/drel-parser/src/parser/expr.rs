@@ -136,13 +136,17 @@ fn parse_binary_or_unary(pair: Pair<Rule>) -> Result<Expr, DrelError> {
     Ok(result)
 }
 
-fn parse_postfix(pairs: Vec<Pair<Rule>>, _outer_span: crate::ast::Span) -> Result<Expr, DrelError> {
+fn parse_postfix(pairs: Vec<Pair<Rule>>, outer_span: crate::ast::Span) -> Result<Expr, DrelError> {
     let mut iter = pairs.into_iter();
 
     // First should be the primary expression
-    let primary = iter
-        .next()
-        .ok_or_else(|| DrelError::invalid_structure("Empty postfix expression", 0, 0))?;
+    let primary = iter.next().ok_or_else(|| {
+        DrelError::invalid_structure(
+            "Empty postfix expression",
+            outer_span.start_line,
+            outer_span.start_col,
+        )
+    })?;
     let mut result = parse_expr(primary)?;
 
     // Process postfix operations
@@ -1,7 +1,9 @@
 //! Helper functions for parsing
 
 use crate::ast::Span;
+use crate::error::DrelError;
 use crate::Rule;
+use pest::error::{ErrorVariant, InputLocation};
 use pest::iterators::Pair;
 
 /// Extract a full span from a PEST pair
@@ -26,3 +28,81 @@ pub fn location(pair: &Pair<Rule>) -> (usize, usize) {
 pub fn text<'a>(pair: &Pair<'a, Rule>) -> &'a str {
     pair.as_str()
 }
+
+/// Convert a grammar error into a [`DrelError::UnexpectedToken`] quoting `source`
+pub fn pest_error(err: pest::error::Error<Rule>, source: &str) -> DrelError {
+    let offset = match err.location {
+        InputLocation::Pos(pos) => pos,
+        InputLocation::Span((start, _)) => start,
+    };
+    // The grammar reports where a rule was tried, which may be before the
+    // whitespace leading up to the token that broke it
+    let rest = &source[offset.min(source.len())..];
+    let offset = offset + (rest.len() - rest.trim_start().len());
+    let (line, col) = line_col(source, offset);
+
+    let expected = match &err.variant {
+        ErrorVariant::ParsingError { positives, .. } => describe_rules(positives),
+        ErrorVariant::CustomError { message } => message.clone(),
+    };
+    DrelError::unexpected(token_at(source, offset), expected, line, col).with_source(source)
+}
+
+/// 1-based line and column of a byte offset
+pub fn line_col(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset.min(source.len())];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (line, before[line_start..].chars().count() + 1)
+}
+
+/// The token starting at `offset`, quoted, or `end of input`
+fn token_at(source: &str, offset: usize) -> String {
+    let rest = &source[offset.min(source.len())..];
+    let word_len = rest
+        .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.'))
+        .unwrap_or(rest.len());
+    match rest.chars().next() {
+        None => "end of input".to_string(),
+        Some(_) if word_len > 0 => format!("'{}'", &rest[..word_len]),
+        Some(c) => format!("'{c}'"),
+    }
+}
+
+/// What a set of grammar rules would have accepted, in words
+fn describe_rules(rules: &[Rule]) -> String {
+    let mut names: Vec<&str> = Vec::new();
+    for rule in rules {
+        let name = match rule {
+            Rule::EOI => "end of input",
+            Rule::statement
+            | Rule::if_stmt
+            | Rule::for_stmt
+            | Rule::loop_stmt
+            | Rule::do_stmt
+            | Rule::repeat_stmt
+            | Rule::with_stmt
+            | Rule::function_def
+            | Rule::break_stmt
+            | Rule::next_stmt
+            | Rule::assignment
+            | Rule::expression_stmt => "statement",
+            Rule::compound_stmt => "'{'",
+            Rule::assign_op => "assignment operator",
+            Rule::identifier | Rule::category_ref => "name",
+            Rule::comp_op => "comparison operator",
+            Rule::and_op | Rule::or_op => "logical operator",
+            Rule::add_op | Rule::mul_op | Rule::power_op => "operator",
+            Rule::subscription | Rule::attribute_ref | Rule::call => "operator",
+            _ => "expression",
+        };
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    match names.split_last() {
+        None => "valid dREL".to_string(),
+        Some((last, [])) => last.to_string(),
+        Some((last, rest)) => format!("{} or {}", rest.join(", "), last),
+    }
+}
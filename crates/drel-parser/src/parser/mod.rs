@@ -10,8 +10,12 @@ use pest::iterators::Pairs;
 
 mod expr;
 mod helpers;
+mod recover;
 mod stmt;
 
+pub use helpers::pest_error;
+pub use recover::parse_program_lossy;
+
 /// Parse a complete program from PEST pairs
 pub fn parse_program(pairs: Pairs<Rule>) -> Result<Vec<Stmt>, DrelError> {
     let mut statements = Vec::new();
@@ -46,5 +50,5 @@ pub fn parse_expression(pairs: Pairs<Rule>) -> Result<Expr, DrelError> {
         }
     }
 
-    Err(DrelError::invalid_structure("No expression found", 0, 0))
+    Err(DrelError::invalid_structure("No expression found", 1, 1))
 }
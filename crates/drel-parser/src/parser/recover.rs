@@ -0,0 +1,214 @@
+//! Parsing that recovers from errors at statement boundaries
+//!
+//! The grammar stops at the first error, so recovery works on the text: the
+//! top-level statement holding the error is blanked out (keeping every line
+//! break and byte offset) and the program is parsed again, until what is
+//! left parses. Top-level statements start on a new line outside any
+//! brackets, which is how dictionary methods are written; a line starting
+//! with `Else` or `ElseIf` continues the `If` above it.
+
+use std::ops::Range;
+
+use pest::Parser;
+
+use crate::ast::{Span, Stmt};
+use crate::error::DrelError;
+use crate::{DrelParser, Rule};
+
+use super::helpers::{line_col, pest_error};
+use super::stmt::parse_stmt;
+
+/// Parse every statement that can be parsed, collecting one error for each
+/// statement that cannot
+pub fn parse_program_lossy(source: &str) -> (Vec<Stmt>, Vec<DrelError>) {
+    let mut text = source.to_string();
+    let mut errors = Vec::new();
+
+    loop {
+        let err = match DrelParser::parse(Rule::program, &text) {
+            Ok(_) => break,
+            Err(err) => err,
+        };
+        let offset = match err.location {
+            pest::error::InputLocation::Pos(pos) => pos,
+            pest::error::InputLocation::Span((start, _)) => start,
+        };
+
+        let scan = scan_statements(&text);
+        let Some(segment) = scan
+            .segments
+            .iter()
+            .rev()
+            .find(|segment| segment.start <= offset)
+            .or(scan.segments.first())
+            .cloned()
+        else {
+            // Nothing left to blank; report the error and give up
+            errors.push(pest_error(err, source));
+            return (Vec::new(), errors);
+        };
+
+        let error = match scan.unclosed_brace {
+            Some(brace) if segment.contains(&brace) => {
+                let (line, col) = line_col(source, brace);
+                DrelError::unterminated_block(Span::point(line, col)).with_source(source)
+            }
+            _ => pest_error(err, source),
+        };
+        errors.push(error);
+        blank(&mut text, segment);
+    }
+
+    let mut statements = Vec::new();
+    if let Ok(pairs) = DrelParser::parse(Rule::program, &text) {
+        let top_level = pairs
+            .flat_map(|pair| pair.into_inner())
+            .filter(|pair| pair.as_rule() != Rule::EOI);
+        for pair in top_level {
+            match parse_stmt(pair) {
+                Ok(stmt) => statements.push(stmt),
+                Err(err) => errors.push(err.with_source(source)),
+            }
+        }
+    }
+
+    errors.sort_by_key(|err| {
+        let span = err.span();
+        (span.start_line, span.start_col)
+    });
+    (statements, errors)
+}
+
+/// Where the top-level statements of a text start and end
+struct Scan {
+    /// Byte range of each statement, running up to the start of the next
+    segments: Vec<Range<usize>>,
+    /// Offset of the first `{` that is never closed
+    unclosed_brace: Option<usize>,
+}
+
+/// Split a text into top-level statements, skipping strings and comments
+fn scan_statements(text: &str) -> Scan {
+    let bytes = text.as_bytes();
+    let mut starts = Vec::new();
+    let mut open: Vec<(u8, usize)> = Vec::new();
+    let mut line_start = true;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let c = bytes[i];
+        match c {
+            b'\n' => {
+                line_start = open.is_empty();
+                i += 1;
+                continue;
+            }
+            b' ' | b'\t' | b'\r' => {
+                i += 1;
+                continue;
+            }
+            _ => {}
+        }
+        if line_start && open.is_empty() && c != b'#' && !continues_if(&bytes[i..]) {
+            starts.push(i);
+        }
+        line_start = false;
+
+        match c {
+            b'#' => i = find_from(text, i, "\n").unwrap_or(bytes.len()),
+            b'\'' | b'"' => {
+                let quote = &text[i..i + 1];
+                let triple = quote.repeat(3);
+                i = if text[i..].starts_with(&triple) {
+                    find_from(text, i + 3, &triple).map_or(bytes.len(), |end| end + 3)
+                } else {
+                    let line_end = find_from(text, i + 1, "\n").unwrap_or(bytes.len());
+                    find_from(text, i + 1, quote)
+                        .filter(|&end| end < line_end)
+                        .map_or(line_end, |end| end + 1)
+                };
+            }
+            b'(' | b'[' | b'{' => {
+                open.push((c, i));
+                i += 1;
+            }
+            b')' | b']' | b'}' => {
+                open.pop();
+                i += 1;
+            }
+            _ => i += 1,
+        }
+    }
+
+    let mut segments: Vec<Range<usize>> = starts.windows(2).map(|pair| pair[0]..pair[1]).collect();
+    if let Some(&last) = starts.last() {
+        segments.push(last..bytes.len());
+    }
+    Scan {
+        segments,
+        unclosed_brace: open
+            .iter()
+            .find(|(bracket, _)| *bracket == b'{')
+            .map(|&(_, offset)| offset),
+    }
+}
+
+/// Whether text starts with the `Else` or `ElseIf` of an `If` statement
+fn continues_if(text: &[u8]) -> bool {
+    let keyword = |word: &str| {
+        text.get(..word.len())
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case(word.as_bytes()))
+            && text
+                .get(word.len())
+                .is_none_or(|&next| !next.is_ascii_alphanumeric() && next != b'_')
+    };
+    keyword("else") || keyword("elseif")
+}
+
+/// Byte offset of `needle` at or after `from`
+fn find_from(text: &str, from: usize, needle: &str) -> Option<usize> {
+    text.get(from..)?.find(needle).map(|i| from + i)
+}
+
+/// Replace a range with spaces, keeping line breaks and byte offsets
+fn blank(text: &mut String, range: Range<usize>) {
+    let blanked: String = text[range.clone()]
+        .chars()
+        .map(|c| match c {
+            '\n' | '\r' => c.to_string(),
+            _ => " ".repeat(c.len_utf8()),
+        })
+        .collect();
+    text.replace_range(range, &blanked);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan_statements() {
+        let text = "a = 1\nLoop t as x {\n  b = 2\n}\n# note\nc = 'x # y'\nd = [1,\n 2]\n";
+        let scan = scan_statements(text);
+        let starts: Vec<&str> = scan
+            .segments
+            .iter()
+            .map(|segment| text[segment.clone()].lines().next().unwrap())
+            .collect();
+        assert_eq!(starts, ["a = 1", "Loop t as x {", "c = 'x # y'", "d = [1,"]);
+        assert_eq!(scan.unclosed_brace, None);
+
+        let scan = scan_statements("a = 1\nIf (a) {\n  b = 2\n");
+        assert_eq!(scan.segments.len(), 2);
+        assert_eq!(scan.unclosed_brace, Some(13));
+
+        // Else and ElseIf on their own lines continue the If
+        let text = "If (a) {\n}\nElseIf (b) {\n}\nelse {\n}\nElsewhere = 1\n";
+        let starts: Vec<usize> = scan_statements(text)
+            .segments
+            .iter()
+            .map(|s| s.start)
+            .collect();
+        assert_eq!(starts, [0, text.find("Elsewhere").unwrap()]);
+    }
+}
@@ -313,8 +313,11 @@ fn parse_function(pair: Pair<Rule>) -> Result<Stmt, DrelError> {
     for next in inner {
         match next.as_rule() {
             Rule::param_list => {
+                // Declared parameter types are not kept
                 for param in next.into_inner() {
-                    params.push(text(&param).to_string());
+                    if param.as_rule() == Rule::identifier {
+                        params.push(text(&param).to_string());
+                    }
                 }
             }
             Rule::identifier => {
@@ -342,6 +345,9 @@ fn parse_assignment(pair: Pair<Rule>) -> Result<Stmt, DrelError> {
         .next()
         .ok_or_else(|| DrelError::invalid_structure("Missing assignment target", line, col))?;
     let target = parse_expr(target)?;
+    if !is_assignable(&target) {
+        return Err(DrelError::invalid_assign_target(target.span));
+    }
 
     let op = inner
         .next()
@@ -359,6 +365,17 @@ fn parse_assignment(pair: Pair<Rule>) -> Result<Stmt, DrelError> {
     ))
 }
 
+/// Whether an expression names something a value can be stored in
+fn is_assignable(target: &Expr) -> bool {
+    matches!(
+        target.kind,
+        ExprKind::Identifier(_)
+            | ExprKind::DataName { .. }
+            | ExprKind::Subscription { .. }
+            | ExprKind::AttributeRef { .. }
+    )
+}
+
 fn parse_assign_op(pair: &Pair<Rule>) -> Result<AssignOp, DrelError> {
     let (line, col) = location(pair);
     let s = text(pair);
@@ -0,0 +1,98 @@
+//! Tests for structured parse errors and recovery at statement boundaries
+
+use drel_parser::{parse, parse_lossy, DrelError, Span, StmtKind};
+
+/// A method with two independent mistakes between valid statements
+const TWO_ERRORS: &str = "\
+mass = 0.
+volume = = _cell.volume
+Loop t as atom_type {
+    mass += t.number_in_cell * t.atomic_mass
+}
+5 = mass
+_exptl_crystal.density_diffrn = mass / volume
+";
+
+#[test]
+fn test_two_errors_two_findings() {
+    let (program, errors) = parse_lossy(TWO_ERRORS);
+
+    assert_eq!(errors.len(), 2, "{errors:#?}");
+    assert!(matches!(
+        &errors[0],
+        DrelError::UnexpectedToken { found, expected, span, .. }
+            if found == "'='" && expected == "expression" && *span == Span::point(2, 10)
+    ));
+    assert!(matches!(
+        &errors[1],
+        DrelError::InvalidAssignTarget { span, .. } if span.start_line == 6
+    ));
+
+    // The three good statements survive with their original spans
+    assert_eq!(program.len(), 3);
+    assert!(matches!(
+        program.statements[0].kind,
+        StmtKind::Assignment { .. }
+    ));
+    assert!(matches!(program.statements[1].kind, StmtKind::Loop { .. }));
+    assert_eq!(program.statements[1].span.start_line, 3);
+    assert_eq!(program.statements[2].span.start_line, 7);
+}
+
+#[test]
+fn test_display_quotes_source_line() {
+    let err = parse("a = 1\nb = = 2\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unexpected '=', expected expression at 2:5\n   2 | b = = 2\n     |     ^"
+    );
+    assert_eq!(err.source_line(), Some("b = = 2"));
+}
+
+#[test]
+fn test_strict_parse_stops_at_first_error() {
+    let err = parse(TWO_ERRORS).unwrap_err();
+    assert_eq!(err.span().start_line, 2);
+}
+
+#[test]
+fn test_unterminated_block() {
+    let (program, errors) = parse_lossy("x = 1\nIf (x > 0) {\n    y = 2\n");
+
+    assert_eq!(program.len(), 1);
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(
+        &errors[0],
+        DrelError::UnterminatedBlock { opened_at, .. } if *opened_at == Span::point(2, 12)
+    ));
+    assert!(errors[0].to_string().contains("   2 | If (x > 0) {"));
+}
+
+#[test]
+fn test_error_in_if_with_else_on_its_own_line() {
+    let source = "If (a > 1) {\n   b = = 2\n}\nElse {\n   b = 3\n}\nc = 4\n";
+    let (program, errors) = parse_lossy(source);
+
+    assert_eq!(errors.len(), 1, "errors: {errors:?}");
+    assert_eq!(errors[0].span().start_line, 2);
+    assert_eq!(program.len(), 1);
+}
+
+#[test]
+fn test_stray_closing_bracket() {
+    let (program, errors) = parse_lossy("a = 1\nb = (a + 1))\nc = b\n");
+
+    assert_eq!(program.len(), 2);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].span(), Span::point(2, 12));
+    assert!(errors[0].message().starts_with("Unexpected ')'"));
+}
+
+#[test]
+fn test_lossy_matches_strict_on_valid_source() {
+    let source = "a = 1\nb = a * 2\n";
+    let (program, errors) = parse_lossy(source);
+
+    assert!(errors.is_empty());
+    assert_eq!(program.statements, parse(source).unwrap());
+}
@@ -367,3 +367,39 @@ fn test_extract_categories() {
         .iter()
         .any(|r| r.full_name() == "_atom_type.number_in_cell"));
 }
+
+/// Test parsing a function with typed parameters (from cif_core.dic function.AtomType)
+#[test]
+fn test_function_typed_params() {
+    let source = r#"
+    Function AtomType( s :[Single, Word])  {  # atom label
+
+       m = Len(s)
+       f = ""
+       if (m > 0) f += Upper(s[0])
+       If (m > 1 and s[1] not in '0123456789') f += Lower(s[1])
+
+                AtomType =  f
+    }
+    "#;
+
+    let stmts = parse(source).expect("Failed to parse function.AtomType");
+    assert_eq!(stmts.len(), 1);
+    match &stmts[0].kind {
+        StmtKind::FunctionDef { name, params, body } => {
+            assert_eq!(name, "AtomType");
+            assert_eq!(params, &["s"]);
+            assert!(matches!(
+                body.last().map(|stmt| &stmt.kind),
+                Some(StmtKind::Assignment { .. })
+            ));
+        }
+        _ => panic!("Expected FunctionDef"),
+    }
+
+    let stmts = parse("Function Closest( v :[Matrix, Real], w :[Matrix, Real]) { Closest = v }")
+        .expect("Failed to parse two typed parameters");
+    assert!(
+        matches!(&stmts[0].kind, StmtKind::FunctionDef { params, .. } if params == &["v", "w"])
+    );
+}
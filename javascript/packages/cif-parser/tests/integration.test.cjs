@@ -8,7 +8,7 @@
 const assert = require('node:assert');
 const fs = require('node:fs');
 const path = require('node:path');
-const { parse } = require('../pkg-node/cif_parser.js');
+const { parse, parseWithOptions } = require('../pkg-node/cif_parser.js');
 
 // Helper to load fixture files
 function loadFixture(name) {
@@ -145,6 +145,9 @@ describe('Integration Tests', () => {
       // Access by name
       const block2 = doc.get_block_by_name('block2');
       assert.strictEqual(block2.get_item('_title').text_value, 'Second Data Block');
+
+      // Names are looked up ignoring case
+      assert.strictEqual(doc.get_block_by_name('BLOCK2').name, 'block2');
     });
 
     it('should access save frames', () => {
@@ -883,4 +886,26 @@ describe('Integration Tests', () => {
       assert.strictEqual(propsTable3.get('mass').numeric_value, 14.0);
     });
   });
+
+  // =============================================================================
+  // leading_prose.cif - Non-CIF text before the first data block
+  // =============================================================================
+
+  describe('leading_prose.cif', () => {
+    it('should reject leading prose by default', () => {
+      const content = loadFixture('leading_prose.cif');
+      assert.throws(() => parse(content));
+    });
+
+    it('should skip leading prose when asked and report it', () => {
+      const content = loadFixture('leading_prose.cif');
+      const result = parseWithOptions(content, { skipLeadingGarbage: true });
+
+      assert.strictEqual(result.document.first_block().name, 'x');
+      assert.strictEqual(result.notes.length, 1);
+      assert.strictEqual(result.notes[0].noteId, 'leading-content-skipped');
+      assert.strictEqual(result.notes[0].span.start_line, 1);
+      assert.strictEqual(result.notes[0].span.end_line, 3);
+    });
+  });
 });
@@ -0,0 +1,339 @@
+"""Check the type stubs of the native modules against what they export.
+
+The stubs (``_cif_parser.pyi``, ``_cif_validator.pyi``) are written by hand,
+since the compiled module knows its parameter names but not their types. This
+script keeps them honest: every class, function, method, property and enum
+member of the compiled module must have a stub and every stub must exist at
+runtime, with the same kind (method, property, static method, constant) and
+the same parameters. Every public function and method must have a Rust doc
+comment that names each parameter in backticks, as its ``# Arguments`` list
+does, and each stub's docstring must start with the same summary line as the
+Rust doc comment it mirrors.
+
+Run it after building the extensions with ``just python-develop-all``:
+
+    just python-check-stubs
+
+The packages' test suites run the same checks.
+"""
+
+from __future__ import annotations
+
+import ast
+import importlib
+import inspect
+import sys
+from pathlib import Path
+from typing import Any, Tuple
+
+# Each package's directory under python/ by its import name; the native
+# module is `<package>._<package>`
+PACKAGES = {
+    "cif_parser": "cif-parser",
+    "cif_validator": "cif-validator",
+}
+
+# Dunders PyO3 adds on its own: comparisons derived from __eq__ and the
+# integer conversion of simple enums
+GENERATED_DUNDERS = {
+    "__lt__",
+    "__le__",
+    "__ne__",
+    "__gt__",
+    "__ge__",
+    "__int__",
+}
+
+IGNORED_ATTRIBUTES = {"__doc__", "__module__", "__new__", "__dict__", "__weakref__"}
+
+
+def summary(doc: str | None) -> str:
+    """The first line of a docstring, without a trailing period."""
+    lines = inspect.cleandoc(doc or "").splitlines()
+    return lines[0].rstrip(".") if lines else ""
+
+
+# A parameter as (name, kind, default), with NO_DEFAULT for required ones and
+# Ellipsis for defaults that cannot be written as a literal
+Parameter = Tuple[str, str, Any]
+
+NO_DEFAULT = object()
+
+
+def runtime_parameters(obj: Any) -> list[Parameter] | None:
+    """The parameters of a native callable, from its text signature."""
+    if getattr(obj, "__text_signature__", None) is None:
+        return None
+    try:
+        signature = inspect.signature(obj)
+    except ValueError:
+        return None
+    parameters = []
+    for parameter in signature.parameters.values():
+        if parameter.name in ("self", "cls", "type"):
+            continue
+        default = parameter.default
+        if default is inspect.Parameter.empty:
+            default = NO_DEFAULT
+        parameters.append((parameter.name, parameter.kind.name, default))
+    return parameters
+
+
+def literal(node: ast.expr | None) -> Any:
+    """The value of a stub default, Ellipsis if it is not a literal."""
+    if node is None:
+        return NO_DEFAULT
+    try:
+        return ast.literal_eval(node)
+    except ValueError:
+        return ...
+
+
+def stub_parameters(node: ast.FunctionDef, method: bool) -> list[Parameter]:
+    """The parameters of a stub function, without `self`."""
+    args = node.args
+    positional = args.posonlyargs + args.args
+    padding: list[ast.expr | None] = [None] * (len(positional) - len(args.defaults))
+    defaults = padding + list(args.defaults)
+    parameters: list[Parameter] = []
+    for index, (arg, default) in enumerate(zip(positional, defaults)):
+        if method and index == 0:
+            continue
+        if index < len(args.posonlyargs):
+            kind = "POSITIONAL_ONLY"
+        else:
+            kind = "POSITIONAL_OR_KEYWORD"
+        parameters.append((arg.arg, kind, literal(default)))
+    if args.vararg is not None:
+        parameters.append((args.vararg.arg, "VAR_POSITIONAL", NO_DEFAULT))
+    for arg, kw_default in zip(args.kwonlyargs, args.kw_defaults):
+        parameters.append((arg.arg, "KEYWORD_ONLY", literal(kw_default)))
+    if args.kwarg is not None:
+        parameters.append((args.kwarg.arg, "VAR_KEYWORD", NO_DEFAULT))
+    return parameters
+
+
+def same_parameters(runtime: list[Parameter], stub: list[Parameter]) -> bool:
+    """Whether the names, kinds and defaults agree; `...` matches any default."""
+    if len(runtime) != len(stub):
+        return False
+    for (name, kind, default), (stub_name, stub_kind, stub_default) in zip(
+        runtime, stub
+    ):
+        if (name, kind) != (stub_name, stub_kind):
+            return False
+        if (default is NO_DEFAULT) != (stub_default is NO_DEFAULT):
+            return False
+        if ... not in (default, stub_default) and default != stub_default:
+            return False
+    return True
+
+
+def describe(parameters: list[Parameter]) -> str:
+    """Parameters as they would be written in a signature."""
+    written = []
+    for name, _, default in parameters:
+        written.append(name if default is NO_DEFAULT else f"{name}={default!r}")
+    return "(" + ", ".join(written) + ")"
+
+
+def decorators(node: ast.FunctionDef) -> set[str]:
+    """Names of the decorators on a stub function."""
+    names: set[str] = set()
+    for decorator in node.decorator_list:
+        if isinstance(decorator, ast.Name):
+            names.add(decorator.id)
+        elif isinstance(decorator, ast.Attribute):
+            names.add(decorator.attr)
+    return names
+
+
+def stub_members(body: list[ast.stmt]) -> dict[str, list[ast.stmt]]:
+    """Stub statements by the name they define; overloads share a name."""
+    members: dict[str, list[ast.stmt]] = {}
+    for node in body:
+        if isinstance(node, (ast.FunctionDef, ast.ClassDef)):
+            members.setdefault(node.name, []).append(node)
+        elif isinstance(node, ast.AnnAssign) and isinstance(node.target, ast.Name):
+            members.setdefault(node.target.id, []).append(node)
+        elif isinstance(node, ast.Assign):
+            for target in node.targets:
+                if isinstance(target, ast.Name):
+                    members.setdefault(target.id, []).append(node)
+    return members
+
+
+class Checker:
+    """Collects the differences between one native module and its stub."""
+
+    def __init__(self, module_name: str, stub_path: Path) -> None:
+        self.module = importlib.import_module(module_name)
+        self.tree = ast.parse(stub_path.read_text(), filename=str(stub_path))
+        self.problems: list[str] = []
+
+    def report(self, where: str, problem: str) -> None:
+        self.problems.append(f"{where}: {problem}")
+
+    def check_docstring(
+        self, where: str, obj: Any, node: ast.stmt, parameters: list[str]
+    ) -> None:
+        doc = inspect.getdoc(obj) or ""
+        if not doc.strip():
+            self.report(where, "has no docstring in the Rust source")
+            return
+        for name in parameters:
+            if f"`{name}`" not in doc:
+                self.report(where, f"docstring does not document parameter `{name}`")
+        stub_doc = (
+            ast.get_docstring(node)
+            if isinstance(node, (ast.FunctionDef, ast.ClassDef))
+            else None
+        )
+        if not stub_doc:
+            self.report(where, "has no docstring in the stub")
+        elif summary(stub_doc) != summary(doc):
+            self.report(
+                where,
+                f"stub docstring starts {summary(stub_doc)!r}, "
+                f"Rust doc comment starts {summary(doc)!r}",
+            )
+
+    def check_function(
+        self, where: str, obj: Any, nodes: list[ast.stmt], method: bool
+    ) -> None:
+        node = nodes[0]
+        if not isinstance(node, ast.FunctionDef):
+            self.report(where, "is a function but the stub is not")
+            return
+        parameters = runtime_parameters(obj)
+        if parameters is None:
+            self.report(where, "has no text signature")
+            return
+        overloaded = "overload" in decorators(node)
+        if not overloaded and not same_parameters(
+            parameters, stub_parameters(node, method)
+        ):
+            self.report(
+                where,
+                f"stub parameters {describe(stub_parameters(node, method))} "
+                f"differ from runtime {describe(parameters)}",
+            )
+        self.check_docstring(where, obj, node, [name for name, _, _ in parameters])
+
+    def check_class(self, cls: type, node: ast.ClassDef) -> None:
+        name = cls.__name__
+        self.check_docstring(name, cls, node, [])
+        members = stub_members(node.body)
+        seen: set[str] = set()
+
+        constructor = runtime_parameters(cls)
+        if constructor is not None:
+            seen.add("__init__")
+            init = members.get("__init__")
+            if not init or not isinstance(init[0], ast.FunctionDef):
+                self.report(name, "has a constructor but the stub has no __init__")
+            elif not same_parameters(constructor, stub_parameters(init[0], True)):
+                self.report(
+                    f"{name}.__init__",
+                    f"stub parameters {describe(stub_parameters(init[0], True))} "
+                    f"differ from runtime {describe(constructor)}",
+                )
+
+        for attr, value in vars(cls).items():
+            if attr in IGNORED_ATTRIBUTES:
+                continue
+            if attr in GENERATED_DUNDERS:
+                # Stubs may declare these, but need not
+                seen.add(attr)
+                continue
+            if attr == "__hash__" and value is None:
+                continue
+            where = f"{name}.{attr}"
+            seen.add(attr)
+            nodes = members.get(attr)
+            if not nodes:
+                self.report(where, "is missing from the stub")
+                continue
+            if attr.startswith("__") and attr.endswith("__"):
+                continue
+            node = nodes[0]
+            if isinstance(value, cls):
+                if not isinstance(node, (ast.Assign, ast.AnnAssign)):
+                    self.report(where, "is a constant but the stub is not")
+            elif type(value).__name__ == "getset_descriptor":
+                if not (
+                    isinstance(node, ast.FunctionDef)
+                    and "property" in decorators(node)
+                ):
+                    self.report(where, "is a property but the stub is not")
+                else:
+                    self.check_docstring(where, value, node, [])
+            elif isinstance(value, staticmethod):
+                if not (
+                    isinstance(node, ast.FunctionDef)
+                    and "staticmethod" in decorators(node)
+                ):
+                    self.report(where, "is a static method but the stub is not")
+                else:
+                    self.check_function(where, value.__func__, nodes, False)
+            else:
+                if isinstance(node, ast.FunctionDef) and decorators(node) & {
+                    "property",
+                    "staticmethod",
+                }:
+                    self.report(where, "is a method but the stub is not")
+                else:
+                    self.check_function(where, value, nodes, True)
+
+        for attr in members:
+            if attr not in seen:
+                self.report(f"{name}.{attr}", "is in the stub but not the module")
+
+    def check(self) -> list[str]:
+        members = stub_members(self.tree.body)
+        runtime = {
+            name: value
+            for name, value in vars(self.module).items()
+            if not name.startswith("_") or name in ("__version__", "__author__")
+        }
+        for name, value in runtime.items():
+            nodes = members.get(name)
+            if not nodes:
+                self.report(name, "is missing from the stub")
+            elif isinstance(value, type):
+                if isinstance(nodes[0], ast.ClassDef):
+                    self.check_class(value, nodes[0])
+                else:
+                    self.report(name, "is a class but the stub is not")
+            elif callable(value):
+                self.check_function(name, value, nodes, False)
+        for name, nodes in members.items():
+            if name not in runtime and not isinstance(nodes[0], ast.ImportFrom):
+                self.report(name, "is in the stub but not the module")
+        return self.problems
+
+
+def check_stubs(module_name: str, stub_path: Path) -> list[str]:
+    """Every difference between a native module and its stub file."""
+    return Checker(module_name, stub_path).check()
+
+
+def check_package(package: str) -> list[str]:
+    """Every difference between a package's native module and its stub file."""
+    directory = Path(__file__).parent / PACKAGES[package]
+    stub_path = directory / "src" / package / f"_{package}.pyi"
+    return check_stubs(f"{package}._{package}", stub_path)
+
+
+def main() -> int:
+    failed = False
+    for package in PACKAGES:
+        problems = check_package(package)
+        for problem in problems:
+            print(f"{package}: {problem}")
+        failed |= bool(problems)
+    return 1 if failed else 0
+
+
+if __name__ == "__main__":
+    sys.exit(main())
@@ -26,22 +26,47 @@ Classes:
     Frame: Save frame container
     Value: Individual CIF value with type information
     Span: Source location information for values
+    BlockSummary: Common crystallographic metadata extracted from a block
+    Measurand: Numeric value with optional standard uncertainty
+    ColumnStats: Statistics of one numeric loop column
+    Anomaly: Loop value flagged by Block.screen_numeric_anomalies
+    ParseResult: Document with upgrade issues and notes from parse_with_options
+    ParseNote: Non-fatal observation recorded while parsing
+    FeatureReport: Version-sensitive constructs used in a document
+    FeatureUsage: Count and first location of one construct
 
 Functions:
     parse(content): Parse CIF content from string
     parse_file(path): Parse CIF file
+    parse_with_options(content, ...): Parse with options, returning notes
+    feature_report(content): Inventory version-sensitive constructs
+    csv_to_loop(tags, csv): Read a CSV table back into a loop
+    tsv_to_loop(tags, tsv): Read a TSV table back into a loop
 """
 
 from ._cif_parser import (
+    Anomaly,
     Block,
+    BlockSummary,
+    ColumnStats,
     Document,
+    FeatureReport,
+    FeatureUsage,
     Frame,
     Loop,
+    Measurand,
+    ParseNote,
+    ParseResult,
     Span,
     Value,
+    Version,
     __version__,
+    csv_to_loop,
+    feature_report,
     parse,
     parse_file,
+    parse_with_options,
+    tsv_to_loop,
 )
 
 __all__ = [
@@ -51,8 +76,21 @@ __all__ = [
     "Frame",
     "Value",
     "Span",
+    "Version",
+    "BlockSummary",
+    "Measurand",
+    "ColumnStats",
+    "Anomaly",
+    "ParseResult",
+    "ParseNote",
+    "FeatureReport",
+    "FeatureUsage",
     "parse",
     "parse_file",
+    "parse_with_options",
+    "feature_report",
+    "csv_to_loop",
+    "tsv_to_loop",
     "__version__",
 ]
 
@@ -1,62 +1,298 @@
-"""Type stubs for the native CIF parser module."""
+"""
+Type stubs for the native CIF parser module (_cif_parser).
 
-from typing import Iterator, overload
+CIF (Crystallographic Information File) parser with Python bindings.
+
+Note on typing: CIF files are dynamically typed - tag values can be text, numeric,
+or special values (unknown/not applicable). This is reflected in the type system
+through runtime type checking using properties like is_numeric, is_text, etc.
+
+Example usage with type checking:
+    import cif_parser
+
+    doc = cif_parser.parse(cif_content)
+    block = doc.first_block()
+
+    if block is not None:
+        value = block.get_item("_cell_length_a")
+        if value is not None and value.is_numeric:
+            length = value.numeric
+            if length is not None:
+                print(f"Cell length: {length}")
+
+The first line of every docstring here matches the Rust doc comment that
+becomes the runtime ``__doc__``; ``python/check_stubs.py`` keeps the two in
+step.
+"""
+
+from typing import Any, ClassVar, overload
 
 __version__: str
 __author__: str
 
+class Version:
+    """
+    CIF specification version of a document.
+
+    Compare with ``==`` against ``Version.V1_1`` and ``Version.V2_0``.
+    """
+
+    V1_1: ClassVar[Version]
+    """CIF 1.1 specification"""
+    V2_0: ClassVar[Version]
+    """CIF 2.0 specification"""
+
+    @property
+    def name(self) -> str:
+        """Variant name, "V1_1" or "V2_0"."""
+        ...
+
+    @property
+    def is_cif2(self) -> bool:
+        """Check if this is CIF 2.0."""
+        ...
+
+    @property
+    def is_cif1(self) -> bool:
+        """Check if this is CIF 1.1."""
+        ...
+
+    def __str__(self) -> str:
+        """String representation ("CIF 1.1" or "CIF 2.0")."""
+        ...
+
+    def __repr__(self) -> str:
+        """Debug representation."""
+        ...
+
+    def __eq__(self, other: object) -> bool: ...
+    def __int__(self) -> int: ...
+
+class Span:
+    """
+    Source location of a value in the CIF file.
+
+    Tracks where a value appears in the source, enabling LSP/IDE features
+    like go-to-definition, hover information, and syntax highlighting.
+
+    All line and column numbers are 1-indexed.
+    """
+
+    @property
+    def start_line(self) -> int:
+        """Starting line number (1-indexed)."""
+        ...
+
+    @property
+    def start_col(self) -> int:
+        """Starting column number (1-indexed)."""
+        ...
+
+    @property
+    def end_line(self) -> int:
+        """Ending line number (1-indexed)."""
+        ...
+
+    @property
+    def end_col(self) -> int:
+        """Ending column number (1-indexed)."""
+        ...
+
+    def contains(self, line: int, col: int) -> bool:
+        """
+        Check if a line and column position is within this span.
+
+        Args:
+            line: Line number (1-indexed)
+            col: Column number (1-indexed)
+
+        Returns:
+            True if the position is within the span.
+        """
+        ...
+
+    def __str__(self) -> str:
+        """String representation (e.g., '1:5-3:10')."""
+        ...
+
+    def __repr__(self) -> str:
+        """Debug representation."""
+        ...
+
+    def __eq__(self, other: object) -> bool:
+        """Check equality with another Span."""
+        ...
+
+    def __hash__(self) -> int:
+        """Hash for use in sets/dicts."""
+        ...
+
 class Value:
-    """A CIF value with automatic type detection."""
+    """
+    A single CIF value with its type and source location.
+
+    CIF values can be text, numeric (with or without an uncertainty),
+    special values (unknown '?' or not applicable '.'), or CIF 2.0 lists
+    and tables. Use the type-checking properties to determine the actual
+    type at runtime.
+
+    Type checking pattern:
+        if value.is_numeric:
+            num = value.numeric  # Optional[float]
+            if num is not None:
+                # Use num as float
+        elif value.is_text:
+            text = value.text  # Optional[str]
+            if text is not None:
+                # Use text as str
+    """
 
     @property
     def is_text(self) -> bool:
-        """True if this is a text value."""
+        """Check if this is a text value."""
         ...
 
     @property
     def is_numeric(self) -> bool:
-        """True if this is a numeric value."""
+        """Check if this is a numeric value (including values with uncertainty)."""
+        ...
+
+    @property
+    def is_numeric_with_uncertainty(self) -> bool:
+        """Check if this is a numeric value with uncertainty."""
         ...
 
     @property
     def is_unknown(self) -> bool:
-        """True if this is an unknown value (?)."""
+        """Check if this is an unknown value (?)."""
         ...
 
     @property
     def is_not_applicable(self) -> bool:
-        """True if this is a not-applicable value (.)."""
+        """Check if this is a not-applicable value (.)."""
+        ...
+
+    @property
+    def is_list(self) -> bool:
+        """Check if this is a list value (CIF 2.0 only)."""
+        ...
+
+    @property
+    def is_table(self) -> bool:
+        """Check if this is a table value (CIF 2.0 only)."""
+        ...
+
+    @property
+    def span(self) -> Span:
+        """
+        Get the source location span for this value.
+
+        Returns the position in the source CIF file where this value appears.
+        Useful for LSP/IDE features, error reporting, and syntax highlighting.
+        """
         ...
 
     @property
     def text(self) -> str | None:
-        """Get text content (None if not a text value)."""
+        """Get the value as text (returns None if not a text value)."""
         ...
 
     @property
     def numeric(self) -> float | None:
-        """Get numeric content (None if not numeric)."""
+        """
+        Get the value as a number (returns None if not numeric).
+
+        For values with uncertainty, returns just the value; see uncertainty.
+        """
+        ...
+
+    @property
+    def uncertainty(self) -> float | None:
+        """Get the uncertainty (returns None if not a numeric value with uncertainty)."""
         ...
 
     @property
     def value_type(self) -> str:
-        """Get the value type as a string."""
+        """
+        Get the value type as a string.
+
+        One of "text", "numeric", "numeric_with_uncertainty", "unknown",
+        "not_applicable", "list" or "table".
+        """
         ...
 
-    def to_python(self) -> str | float | None:
-        """Convert to native Python type."""
+    def walk(self) -> list[tuple[str, Value]]:
+        """
+        Visit this value and every value nested in its lists and tables.
+
+        Pairs are yielded depth-first, each value before its children,
+        starting with this value at the empty path "". Paths use the form
+        "[2].file"; table entries are visited in document order.
+
+        Returns:
+            A list of (path, value) pairs.
+        """
         ...
 
-    def __str__(self) -> str: ...
-    def __repr__(self) -> str: ...
-    def __eq__(self, other: object) -> bool: ...
+    def get_path(self, path: str) -> Value | None:
+        """
+        Get a nested value by path string such as `[2].file`.
+
+        Args:
+            path: Indices and keys from this value down, such as "[2].file"
+
+        Returns:
+            The value at the path, or None if the path leads nowhere.
+
+        Raises:
+            ValueError: If the path string is malformed.
+        """
+        ...
+
+    def to_python(self) -> str | float | list[Any] | dict[str, Any] | None:
+        """
+        Convert to Python native type.
+
+        Returns:
+            - str if text value
+            - float if numeric value, dropping any uncertainty
+            - None if unknown or not applicable
+            - list or dict for CIF 2.0 lists and tables, converted recursively
+        """
+        ...
+
+    def __str__(self) -> str:
+        """String representation of the value."""
+        ...
+
+    def __repr__(self) -> str:
+        """Debug representation of the value."""
+        ...
+
+    def __eq__(self, other: object) -> bool:
+        """Check equality with another Value."""
+        ...
 
 class Loop:
-    """A CIF loop structure (tabular data)."""
+    """
+    A loop of tagged columns (tabular data) in a block or save frame.
+
+    Loops contain rows of values organized by column tags. Use iteration
+    to process rows as dictionaries, or use positional access methods.
+
+    Example:
+        for row in loop:
+            label = row["_atom_site_label"]
+            if label.is_text:
+                print(label.text)
+
+        # Or access by position
+        value = loop.get(row=0, col=1)
+    """
 
     @property
     def tags(self) -> list[str]:
-        """Get column tags (headers)."""
+        """Get the column tags (headers)."""
         ...
 
     @property
@@ -64,43 +300,140 @@ class Loop:
         """Get the number of columns."""
         ...
 
-    def __len__(self) -> int:
-        """Get the number of rows."""
-        ...
-
     def is_empty(self) -> bool:
-        """Check if the loop has no rows."""
+        """Check if the loop is empty."""
         ...
 
     def get(self, row: int, col: int) -> Value | None:
-        """Get a value by row and column index."""
+        """
+        Get a value by row and column index.
+
+        Args:
+            row: Row index (0-based)
+            col: Column index (0-based)
+
+        Returns:
+            The value at the specified position, or None if out of bounds.
+        """
         ...
 
     def get_by_tag(self, row: int, tag: str) -> Value | None:
-        """Get a value by row index and tag name."""
+        """
+        Get a value by row index and tag name.
+
+        Args:
+            row: Row index (0-based)
+            tag: Column tag name (e.g., "_atom_site_label")
+
+        Returns:
+            The value at the specified row for the given tag, or None if not found.
+        """
         ...
 
     def get_column(self, tag: str) -> list[Value] | None:
-        """Get all values for a specific tag."""
+        """
+        Get all values for a specific tag as a list.
+
+        Args:
+            tag: Column tag name
+
+        Returns:
+            List of values for the column, or None if tag doesn't exist.
+        """
+        ...
+
+    def column_stats(self, tag: str) -> ColumnStats | None:
+        """
+        Get statistics of a numeric column.
+
+        Args:
+            tag: Column tag, compared ignoring case
+
+        Returns:
+            The column's statistics, or None if the loop has no such tag.
+        """
+        ...
+
+    def get_row_dict(self, row: int) -> dict[str, Value] | None:
+        """
+        Get a row as a dictionary mapping tags to values, in column order.
+
+        Args:
+            row: Row index (0-based)
+
+        Returns:
+            Dictionary of {tag: value}, or None if row doesn't exist.
+        """
         ...
 
     def rows(self) -> list[list[Value]]:
         """Get all rows as lists of values."""
         ...
 
-    def get_row_dict(self, row: int) -> dict[str, Value] | None:
-        """Get a row as a dictionary mapping tags to values."""
+    def to_csv(
+        self,
+        *,
+        delimiter: str = ",",
+        strip_category_prefix: bool = False,
+        unknown_as_empty: bool = True,
+    ) -> str:
+        """
+        Write the loop as CSV (or TSV with `delimiter="\\t"`).
+
+        The table is RFC 4180: a header row of tags, then one row per packet.
+
+        Args:
+            delimiter: Field delimiter; use "\\t" for TSV
+            strip_category_prefix: Write "label" rather than "_atom_site_label"
+                in the header
+            unknown_as_empty: Write unknown values ('?') as empty cells rather
+                than "?"
+
+        Returns:
+            The table, with rows ending in CR LF. Numbers keep their
+            uncertainties, e.g. "0.1234(5)".
+        """
+        ...
+
+    def __len__(self) -> int:
+        """Get the number of rows."""
         ...
 
-    def __iter__(self) -> Iterator[list[Value]]:
-        """Iterate over rows."""
+    def __iter__(self) -> PyLoopIterator:
+        """
+        Iterate over rows as dictionaries.
+
+        Yields:
+            Dictionary mapping tag names to values for each row.
+
+        Example:
+            for row in loop:
+                label = row["_atom_site_label"]
+                x_coord = row["_atom_site_fract_x"]
+        """
+        ...
+
+    def __str__(self) -> str:
+        """String representation."""
         ...
 
-    def __str__(self) -> str: ...
-    def __repr__(self) -> str: ...
+    def __repr__(self) -> str:
+        """Debug representation."""
+        ...
+
+class PyLoopIterator:
+    """Iterator over the rows of a Loop, as dictionaries from tag to value."""
+
+    def __iter__(self) -> PyLoopIterator: ...
+    def __next__(self) -> dict[str, Value]: ...
 
 class Frame:
-    """A CIF save frame."""
+    """
+    A save frame within a data block.
+
+    Save frames are named sub-containers within data blocks that group
+    related items and loops.
+    """
 
     @property
     def name(self) -> str:
@@ -109,7 +442,7 @@ class Frame:
 
     @property
     def item_keys(self) -> list[str]:
-        """Get all item keys."""
+        """Get all item keys, in document order."""
         ...
 
     @property
@@ -123,22 +456,441 @@ class Frame:
         ...
 
     def get_item(self, key: str) -> Value | None:
-        """Get an item by key."""
+        """
+        Get an item by key.
+
+        Args:
+            key: Tag name (e.g., "_definition.id")
+
+        Returns:
+            The value for the tag, or None if not found.
+        """
         ...
 
     def items(self) -> dict[str, Value]:
-        """Get all items as a dictionary."""
+        """Get all items as a dictionary, in document order."""
         ...
 
     def get_loop(self, index: int) -> Loop | None:
-        """Get a loop by index."""
+        """
+        Get a loop by index.
+
+        Args:
+            index: Loop index (0-based)
+
+        Returns:
+            The loop at the specified index, or None if out of bounds.
+        """
+        ...
+
+    def __str__(self) -> str:
+        """String representation."""
+        ...
+
+    def __repr__(self) -> str:
+        """Debug representation."""
+        ...
+
+class Measurand:
+    """A measured value with its optional standard uncertainty."""
+
+    @property
+    def value(self) -> float:
+        """The measured value."""
+        ...
+
+    @property
+    def uncertainty(self) -> float | None:
+        """The standard uncertainty, if one was written."""
+        ...
+
+    def __str__(self) -> str:
+        """String representation, e.g. "5.432 ± 0.002"."""
+        ...
+
+    def __repr__(self) -> str:
+        """Debug representation."""
+        ...
+
+    def __eq__(self, other: object) -> bool: ...
+
+class BlockSummary:
+    """
+    Common crystallographic values found in a data block.
+
+    Every field is None when none of its tag spellings are present or the
+    value is unknown (?), not applicable (.), or of the wrong type.
+    """
+
+    @property
+    def block_name(self) -> str:
+        """Name of the block the summary was taken from."""
+        ...
+
+    @property
+    def chemical_formula_sum(self) -> str | None:
+        """Empirical formula (`_chemical_formula.sum`)."""
+        ...
+
+    @property
+    def space_group_name_hm(self) -> str | None:
+        """Hermann-Mauguin space group symbol (`_space_group.name_H-M_alt`)."""
+        ...
+
+    @property
+    def space_group_number(self) -> int | None:
+        """International Tables space group number (`_space_group.IT_number`)."""
+        ...
+
+    @property
+    def cell(self) -> list[Measurand | None]:
+        """Cell lengths and angles in the order a, b, c, alpha, beta, gamma."""
+        ...
+
+    @property
+    def volume(self) -> Measurand | None:
+        """Cell volume (`_cell.volume`)."""
+        ...
+
+    @property
+    def z(self) -> int | None:
+        """Formula units per cell (`_cell.formula_units_Z`)."""
+        ...
+
+    @property
+    def temperature(self) -> Measurand | None:
+        """Measurement temperature in kelvin (`_diffrn.ambient_temperature`)."""
+        ...
+
+    @property
+    def wavelength(self) -> Measurand | None:
+        """Radiation wavelength in angstroms (`_diffrn_radiation_wavelength.value`)."""
+        ...
+
+    @property
+    def r_factor_gt(self) -> float | None:
+        """R factor for significantly intense reflections (`_refine_ls.R_factor_gt`)."""
+        ...
+
+    @property
+    def wr_factor(self) -> float | None:
+        """Weighted R factor for all reflections (`_refine_ls.wR_factor_ref`)."""
+        ...
+
+    def is_empty(self) -> bool:
+        """Check if no summary field was found."""
+        ...
+
+    def __repr__(self) -> str:
+        """Debug representation."""
         ...
 
-    def __str__(self) -> str: ...
-    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+
+class ColumnStats:
+    """
+    Summary statistics of one loop column.
+
+    Only numbers, with or without an uncertainty, enter the statistics; the
+    value statistics are None when the column has no numbers.
+    """
+
+    @property
+    def tag(self) -> str:
+        """The column tag, as written."""
+        ...
+
+    @property
+    def count(self) -> int:
+        """Number of cells."""
+        ...
+
+    @property
+    def numeric(self) -> int:
+        """Number of numeric cells."""
+        ...
+
+    @property
+    def unknown(self) -> int:
+        """Number of unknown (`?`) cells."""
+        ...
+
+    @property
+    def not_applicable(self) -> int:
+        """Number of not applicable (`.`) cells."""
+        ...
+
+    @property
+    def non_numeric(self) -> int:
+        """Number of cells that are neither numbers nor `?` or `.`."""
+        ...
+
+    @property
+    def min(self) -> float | None:
+        """Smallest number."""
+        ...
+
+    @property
+    def max(self) -> float | None:
+        """Largest number."""
+        ...
+
+    @property
+    def mean(self) -> float | None:
+        """Mean of the numbers."""
+        ...
+
+    @property
+    def std_dev(self) -> float | None:
+        """Sample standard deviation of the numbers; None below two numbers."""
+        ...
+
+    @property
+    def q1(self) -> float | None:
+        """First quartile of the numbers."""
+        ...
+
+    @property
+    def median(self) -> float | None:
+        """Median of the numbers."""
+        ...
+
+    @property
+    def q3(self) -> float | None:
+        """Third quartile of the numbers."""
+        ...
+
+    @property
+    def iqr(self) -> float | None:
+        """Interquartile range of the numbers."""
+        ...
+
+    def is_numeric(self) -> bool:
+        """Check if the column is mostly numbers."""
+        ...
+
+    def __repr__(self) -> str:
+        """Debug representation."""
+        ...
+
+    def __eq__(self, other: object) -> bool: ...
+
+class Anomaly:
+    """A number in a loop column that looks like a mistake."""
+
+    @property
+    def loop_index(self) -> int:
+        """Index of the loop in the block."""
+        ...
+
+    @property
+    def tag(self) -> str:
+        """The column tag, as written."""
+        ...
+
+    @property
+    def row(self) -> int:
+        """Row of the number in the loop (0-based)."""
+        ...
+
+    @property
+    def value(self) -> float:
+        """The number."""
+        ...
+
+    @property
+    def reason(self) -> str:
+        """Why the number was flagged: "out_of_range" or "outlier"."""
+        ...
+
+    @property
+    def bounds(self) -> tuple[float, float]:
+        """The plausible range, or the outlier fences, the number is outside of."""
+        ...
+
+    @property
+    def span(self) -> Span:
+        """Location of the cell."""
+        ...
+
+    def __repr__(self) -> str:
+        """Debug representation."""
+        ...
+
+    def __eq__(self, other: object) -> bool: ...
+
+class ParseNote:
+    """
+    A non-fatal observation recorded while parsing.
+
+    Notes never make a document invalid; they flag things callers may want
+    to tell users about, such as skipped text or numbers that lost precision.
+    """
+
+    @property
+    def note_id(self) -> str:
+        """
+        Machine-readable note identifier, e.g. "leading-content-skipped".
+
+        One of "numeric-precision-exceeded", "uncertainty-underflow",
+        "leading-content-skipped", "loop-duplicate-tag",
+        "duplicate-block-name", "duplicate-frame-name", "long-data-name" or
+        "long-unquoted-value".
+        """
+        ...
+
+    @property
+    def message(self) -> str:
+        """Human-readable description."""
+        ...
+
+    @property
+    def span(self) -> Span:
+        """Source location the note refers to."""
+        ...
+
+    @property
+    def related_span(self) -> Span | None:
+        """Another location the note refers to, such as the first of two duplicate tags."""
+        ...
+
+    def __str__(self) -> str:
+        """String representation."""
+        ...
+
+    def __repr__(self) -> str:
+        """Debug representation."""
+        ...
+
+class ParseResult:
+    """A parsed document together with upgrade issues and notes."""
+
+    @property
+    def document(self) -> Document:
+        """The parsed document."""
+        ...
+
+    @property
+    def upgrade_issues(self) -> list[str]:
+        """Changes needed for CIF 2.0, if upgrade guidance was requested."""
+        ...
+
+    @property
+    def notes(self) -> list[ParseNote]:
+        """Informational notes, such as skipped leading text."""
+        ...
+
+    def __repr__(self) -> str:
+        """Debug representation."""
+        ...
+
+class FeatureUsage:
+    """Count and first location of one version-sensitive construct."""
+
+    @property
+    def feature(self) -> str:
+        """
+        Snake-case feature name, e.g. "triple_quoted_string".
+
+        One of "list", "table", "triple_quoted_string", "non_ascii",
+        "doubled_quote_escape", "empty_block_name", "empty_frame_name",
+        "long_line", "line_folding", "text_prefix", "long_data_name" or
+        "long_unquoted_value".
+        """
+        ...
+
+    @property
+    def count(self) -> int:
+        """Number of occurrences."""
+        ...
+
+    @property
+    def first(self) -> Span:
+        """Location of the first occurrence."""
+        ...
+
+    def __repr__(self) -> str:
+        """Debug representation."""
+        ...
+
+class FeatureReport:
+    """
+    Version-sensitive constructs used in a document.
+
+    Features are looked up by their snake-case names (see FeatureUsage.feature).
+    """
+
+    @property
+    def features(self) -> list[FeatureUsage]:
+        """Features found, in a fixed order."""
+        ...
+
+    @property
+    def header_line(self) -> int | None:
+        """Line of the `#\\#CIF_2.0` header, or None if there is none."""
+        ...
+
+    @property
+    def declared_version(self) -> Version:
+        """Version the header declares."""
+        ...
+
+    def minimum_version(self) -> Version:
+        """
+        Oldest CIF version that can represent the document.
+
+        V2_0 when the file contains non-ASCII characters, or declares CIF 2.0
+        and uses lists, tables or triple-quoted strings.
+        """
+        ...
+
+    def maximum_compatible_version(self) -> Version:
+        """
+        Newest CIF version the document can be read as unchanged.
+
+        V1_1 when the file uses doubled-quote escapes or empty block or frame
+        names, or declares CIF 1.1 and contains list, table or triple-quote
+        syntax. When this is older than minimum_version(), no version accepts
+        the file unchanged.
+        """
+        ...
+
+    def get(self, feature: str) -> FeatureUsage | None:
+        """
+        Get the usage of a feature by name, or None if it is absent.
+
+        Args:
+            feature: Snake-case feature name, such as "table"
+        """
+        ...
+
+    def count(self, feature: str) -> int:
+        """
+        Number of occurrences of a feature by name.
+
+        Args:
+            feature: Snake-case feature name, such as "table"
+        """
+        ...
+
+    def __len__(self) -> int:
+        """Number of distinct features found."""
+        ...
+
+    def __contains__(self, feature: str) -> bool:
+        """Check if a feature is used, by name."""
+        ...
+
+    def __repr__(self) -> str:
+        """Debug representation."""
+        ...
 
 class Block:
-    """A CIF data block."""
+    """
+    A data block holding items, loops and save frames.
+
+    Data blocks are the primary organizational unit of a CIF file.
+    """
 
     @property
     def name(self) -> str:
@@ -147,7 +899,7 @@ class Block:
 
     @property
     def item_keys(self) -> list[str]:
-        """Get all item keys."""
+        """Get all item keys, in document order."""
         ...
 
     @property
@@ -171,43 +923,208 @@ class Block:
         ...
 
     def get_item(self, key: str) -> Value | None:
-        """Get an item by key."""
+        """
+        Get an item by key.
+
+        Args:
+            key: Tag name (e.g., "_cell_length_a")
+
+        Returns:
+            The value for the tag, or None if not found.
+        """
         ...
 
     def items(self) -> dict[str, Value]:
-        """Get all items as a dictionary."""
+        """Get all items as a dictionary, in document order."""
         ...
 
     def get_loop(self, index: int) -> Loop | None:
-        """Get a loop by index."""
+        """
+        Get a loop by index.
+
+        Args:
+            index: Loop index (0-based)
+
+        Returns:
+            The loop at the specified index, or None if out of bounds.
+        """
         ...
 
     def find_loop(self, tag: str) -> Loop | None:
-        """Find a loop containing a specific tag."""
+        """
+        Find a loop containing a specific tag.
+
+        Args:
+            tag: Tag name to search for
+
+        Returns:
+            The first loop containing the tag, or None if not found.
+        """
         ...
 
     def get_loop_tags(self) -> list[str]:
-        """Get all loop tags."""
+        """Get all loop tags, from every loop in the block."""
+        ...
+
+    def category_to_csv(
+        self,
+        category_or_tag: str,
+        *,
+        delimiter: str = ",",
+        strip_category_prefix: bool = False,
+        unknown_as_empty: bool = True,
+    ) -> str | None:
+        """
+        Write the loop holding a category (or a given tag) as CSV.
+
+        Args:
+            category_or_tag: A category name ("atom_site") or a tag of the loop
+            delimiter: Field delimiter; use "\\t" for TSV
+            strip_category_prefix: Write "label" rather than "_atom_site_label"
+                in the header
+            unknown_as_empty: Write unknown values ('?') as empty cells rather
+                than "?"
+
+        Returns:
+            The table, or None if no loop matches. See Loop.to_csv.
+
+        Example:
+            csv = block.category_to_csv("atom_site")
+            loop = cif_parser.csv_to_loop(block.find_loop("_atom_site_label").tags, csv)
+        """
         ...
 
     def get_frame(self, index: int) -> Frame | None:
-        """Get a frame by index."""
+        """
+        Get a frame by index.
+
+        Args:
+            index: Frame index (0-based)
+
+        Returns:
+            The frame at the specified index, or None if out of bounds.
+        """
         ...
 
-    def __str__(self) -> str: ...
-    def __repr__(self) -> str: ...
+    def summary(self) -> BlockSummary:
+        """
+        Get common crystallographic metadata (formula, space group, cell, ...).
+
+        Modern (`_cell.length_a`) and legacy (`_cell_length_a`) tag spellings
+        are both checked; no dictionary is required.
+
+        Returns:
+            A BlockSummary whose fields are None when not present.
+        """
+        ...
+
+    def screen_numeric_anomalies(
+        self,
+        *,
+        iqr_factor: float = 3.0,
+        min_values: int = 5,
+        ranges: dict[str, tuple[float, float]] | None = None,
+        known_ranges: bool = True,
+    ) -> list[Anomaly]:
+        """
+        Find numbers in loop columns that look like mistakes.
+
+        A number is flagged when it lies outside the plausible range of its
+        tag (fractional coordinates, occupancies and geometric angles are
+        built in) or more than `iqr_factor` interquartile ranges outside the
+        quartiles of its column. No dictionary is required.
+
+        Args:
+            iqr_factor: How many interquartile ranges make an outlier
+            min_values: Fewest numbers a column needs to be checked for outliers
+            ranges: Extra plausible ranges as a dict from tag to (min, max)
+            known_ranges: Whether to apply the built-in ranges
+
+        Returns:
+            The flagged numbers, by loop, then column, then row.
+
+        Example:
+            >>> for anomaly in block.screen_numeric_anomalies():
+            ...     print(anomaly.tag, anomaly.row, anomaly.value, anomaly.reason)
+        """
+        ...
+
+    def __str__(self) -> str:
+        """String representation."""
+        ...
+
+    def __repr__(self) -> str:
+        """Debug representation."""
+        ...
 
 class Document:
-    """A CIF document containing one or more data blocks."""
+    """
+    A CIF document containing one or more data blocks.
+
+    This is the entry point for parsing CIF files.
+
+    Example:
+        doc = Document.parse(cif_string)
+        # or
+        doc = Document.from_file("structure.cif")
+
+        for block in doc:
+            print(block.name)
+    """
 
     @staticmethod
     def parse(content: str) -> Document:
-        """Parse CIF content from a string."""
+        """
+        Parse a CIF string.
+
+        Args:
+            content: CIF file content as string
+
+        Returns:
+            Parsed document
+
+        Raises:
+            ValueError: If parsing fails due to invalid CIF syntax
+        """
         ...
 
     @staticmethod
     def from_file(path: str) -> Document:
-        """Parse CIF content from a file."""
+        """
+        Parse a CIF file.
+
+        Args:
+            path: Path to CIF file
+
+        Returns:
+            Parsed document
+
+        Raises:
+            IOError: If file cannot be read
+            ValueError: If parsing fails due to invalid CIF syntax
+        """
+        ...
+
+    @property
+    def version(self) -> Version:
+        """
+        Get the CIF version of this document.
+
+        CIF 2.0 is indicated by the `#\\#CIF_2.0` magic header. Documents
+        without this header default to CIF 1.1.
+        """
+        ...
+
+    def is_cif2(self) -> bool:
+        """
+        Check if this document is CIF 2.0.
+
+        CIF 2.0 adds support for lists, tables, and other advanced features.
+        """
+        ...
+
+    def is_cif1(self) -> bool:
+        """Check if this document is CIF 1.1."""
         ...
 
     @property
@@ -220,37 +1137,210 @@ class Document:
         """Get all block names."""
         ...
 
-    def __len__(self) -> int:
-        """Get the number of blocks."""
-        ...
-
     def get_block(self, index: int) -> Block | None:
-        """Get a block by index."""
+        """
+        Get a block by index.
+
+        Args:
+            index: Block index (0-based)
+
+        Returns:
+            The block at the specified index, or None if out of bounds.
+        """
         ...
 
     def get_block_by_name(self, name: str) -> Block | None:
-        """Get a block by name."""
+        """
+        Get a block by name, ignoring case.
+
+        Args:
+            name: Block name (without 'data_' prefix)
+
+        Returns:
+            The block with the specified name, or None if not found.
+        """
         ...
 
     def first_block(self) -> Block | None:
-        """Get the first block."""
+        """
+        Get the first block.
+
+        Returns:
+            The first block, or None if document is empty.
+        """
+        ...
+
+    def __len__(self) -> int:
+        """Get the number of blocks."""
         ...
 
     @overload
     def __getitem__(self, key: int) -> Block: ...
     @overload
     def __getitem__(self, key: str) -> Block: ...
-    def __iter__(self) -> Iterator[Block]:
-        """Iterate over blocks."""
+    def __iter__(self) -> PyDocumentIterator:
+        """
+        Iterate over all blocks.
+
+        Yields:
+            Each block in the document.
+        """
+        ...
+
+    def __str__(self) -> str:
+        """String representation."""
         ...
 
-    def __str__(self) -> str: ...
-    def __repr__(self) -> str: ...
+    def __repr__(self) -> str:
+        """Debug representation."""
+        ...
+
+class PyDocumentIterator:
+    """Iterator over the blocks of a Document."""
+
+    def __iter__(self) -> PyDocumentIterator: ...
+    def __next__(self) -> Block: ...
+
+# Module-level convenience functions
 
 def parse(content: str) -> Document:
-    """Parse CIF content from a string."""
+    """
+    Parse a CIF string.
+
+    This is a convenience function equivalent to Document.parse().
+
+    Args:
+        content: CIF file content as string
+
+    Returns:
+        Parsed document
+
+    Raises:
+        ValueError: If parsing fails due to invalid CIF syntax
+
+    Example:
+        import cif_parser
+
+        cif_content = '''
+        data_example
+        _cell_length_a  10.000
+        _title 'My Structure'
+        '''
+
+        doc = cif_parser.parse(cif_content)
+        block = doc.first_block()
+    """
     ...
 
 def parse_file(path: str) -> Document:
-    """Parse CIF content from a file."""
+    """
+    Parse a CIF file.
+
+    This is a convenience function equivalent to Document.from_file().
+
+    Args:
+        path: Path to CIF file
+
+    Returns:
+        Parsed document
+
+    Raises:
+        IOError: If file cannot be read
+        ValueError: If parsing fails due to invalid CIF syntax
+
+    Example:
+        import cif_parser
+
+        doc = cif_parser.parse_file("structure.cif")
+        for block in doc:
+            print(f"Block: {block.name}")
+    """
+    ...
+
+def parse_with_options(
+    content: str,
+    *,
+    upgrade_guidance: bool = False,
+    skip_leading_garbage: bool = False,
+) -> ParseResult:
+    """
+    Parse CIF content with options, returning notes alongside the document.
+
+    Args:
+        content: CIF file content as string
+        upgrade_guidance: For CIF 1.1 input, also list what would need to
+            change for the file to be valid CIF 2.0
+        skip_leading_garbage: Ignore prose, mail headers or markup above the
+            first data_ line (or CIF 2.0 header). Skipped lines are reported
+            as a "leading-content-skipped" note; spans still count them.
+
+    Returns:
+        The document with any upgrade issues and notes
+
+    Raises:
+        ValueError: If parsing fails due to invalid CIF syntax
+
+    Example:
+        import cif_parser
+
+        result = cif_parser.parse_with_options(content, skip_leading_garbage=True)
+        for note in result.notes:
+            print(f"Warning: {note}")
+        doc = result.document
+    """
+    ...
+
+def csv_to_loop(tags: list[str], csv: str) -> Loop:
+    """
+    Read CSV written by `Loop.to_csv` back into a loop with the given tags.
+
+    The header row is skipped; tags name the columns instead. Empty cells and
+    "?" become unknown values, "." is not applicable, and numbers (with or
+    without an uncertainty) are numeric.
+
+    Args:
+        tags: Full tag names for the columns, in order
+        csv: The table
+
+    Returns:
+        A loop with the given tags and one row per table row
+
+    Raises:
+        ValueError: If a row has the wrong number of cells or a quoted field is not closed
+    """
+    ...
+
+def tsv_to_loop(tags: list[str], tsv: str) -> Loop:
+    """
+    Read TSV back into a loop with the given tags.
+
+    See csv_to_loop.
+
+    Args:
+        tags: Full tag names for the columns, in order
+        tsv: The table
+    """
+    ...
+
+def feature_report(content: str) -> FeatureReport:
+    """
+    Inventory the version-sensitive constructs used in CIF content.
+
+    Args:
+        content: CIF file content as string
+
+    Returns:
+        Report of the constructs found, with counts and first locations
+
+    Raises:
+        ValueError: If parsing fails due to invalid CIF syntax
+
+    Example:
+        import cif_parser
+
+        report = cif_parser.feature_report(content)
+        if "table" in report:
+            print(f"Tables used {report.count('table')} times")
+        print(report.minimum_version(), report.maximum_compatible_version())
+    """
     ...
@@ -58,3 +58,21 @@ def cif2_tables_cif(fixtures_dir):
 def cif2_comprehensive_cif(fixtures_dir):
     """Return path to cif2_comprehensive.cif test file with all CIF 2.0 features."""
     return fixtures_dir / "cif2_comprehensive.cif"
+
+
+@pytest.fixture
+def leading_prose_cif(fixtures_dir):
+    """Return path to leading_prose.cif test file with prose before data_x."""
+    return fixtures_dir / "leading_prose.cif"
+
+
+@pytest.fixture
+def cif2_nested_cif(fixtures_dir):
+    """Return path to cif2_nested.cif test file with deeply nested lists and tables."""
+    return fixtures_dir / "cif2_nested.cif"
+
+
+@pytest.fixture
+def numeric_anomalies_cif(fixtures_dir):
+    """Return path to numeric_anomalies.cif test file with planted bad values."""
+    return fixtures_dir / "numeric_anomalies.cif"
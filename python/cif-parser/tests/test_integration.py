@@ -4,6 +4,8 @@ These tests mirror the Rust integration tests in shared_fixtures.rs
 for test parity across Rust, Python, and JavaScript.
 """
 
+import sys
+
 import cif_parser
 import pytest
 
@@ -134,6 +136,9 @@ def test_complex_multiple_blocks(complex_cif):
     block2 = doc.get_block_by_name("block2")
     assert block2.get_item("_title").text == "Second Data Block"
 
+    # Names are looked up ignoring case
+    assert doc.get_block_by_name("BLOCK2").name == "block2"
+
 
 def test_complex_save_frame(complex_cif):
     """Test save frame access."""
@@ -217,6 +222,24 @@ def test_xanthine_plain_numeric_no_uncertainty(xanthine_cif):
     assert alpha.uncertainty is None
 
 
+def test_xanthine_summary(xanthine_cif):
+    """Test block summary extraction from legacy tag spellings."""
+    doc = cif_parser.parse_file(str(xanthine_cif))
+    summary = doc.first_block().summary()
+
+    assert summary.space_group_name_hm == "P 21/c"
+    assert summary.space_group_number == 14
+
+    a, b, c, alpha, beta, gamma = summary.cell
+    assert a.value == pytest.approx(10.01)
+    assert a.uncertainty == pytest.approx(0.11)
+    assert c.uncertainty == pytest.approx(0.13)
+    assert alpha.value == pytest.approx(90.0)
+    assert alpha.uncertainty is None
+    assert summary.volume.value == pytest.approx(1204.62)
+    assert summary.chemical_formula_sum is None
+
+
 # =============================================================================
 # crystalmaker_LuAG.cif - High precision uncertainty values
 # =============================================================================
@@ -244,6 +267,18 @@ def test_luag_zero_uncertainty(luag_cif):
     assert alpha.uncertainty == pytest.approx(0.0, abs=0.0000001)
 
 
+def test_luag_summary(luag_cif):
+    """Test block summary cell parameters keep their uncertainties."""
+    doc = cif_parser.parse_file(str(luag_cif))
+    summary = doc.first_block().summary()
+
+    assert summary.space_group_number == 230
+    assert summary.space_group_name_hm == "I a -3 d"
+    for length in summary.cell[:3]:
+        assert length.value == pytest.approx(11.9104)
+        assert length.uncertainty == pytest.approx(0.000004, abs=0.0000001)
+
+
 # =============================================================================
 # cif2_lists.cif - CIF 2.0 list syntax
 # =============================================================================
@@ -890,3 +925,209 @@ def test_cif2_comprehensive_loop_with_cif2_values(cif2_comprehensive_cif):
     props_dict = props.to_python()
     assert props_dict["element"] == "N"
     assert props_dict["mass"] == 14.0
+
+
+# =============================================================================
+# feature_report - version-sensitive construct inventory
+# =============================================================================
+
+
+def test_feature_report_cif2_lists(cif2_lists_cif):
+    """Test the feature report of a file that uses CIF 2.0 lists."""
+    report = cif_parser.feature_report(cif2_lists_cif.read_text())
+
+    assert report.header_line == 1
+    assert report.declared_version == cif_parser.Version.V2_0
+    assert "list" in report
+    assert report.count("list") == 7
+    first = report.get("list").first
+    assert (first.start_line, first.start_col) == (5, 20)
+    assert report.minimum_version() == cif_parser.Version.V2_0
+    assert report.maximum_compatible_version() == cif_parser.Version.V2_0
+
+
+def test_feature_report_cif1_only():
+    """Test the feature report of CIF 1.1 constructs CIF 2.0 rejects."""
+    report = cif_parser.feature_report("data_\n_name 'O''Brien'\n")
+
+    assert report.header_line is None
+    assert [usage.feature for usage in report.features] == [
+        "doubled_quote_escape",
+        "empty_block_name",
+    ]
+    assert report.count("table") == 0
+    assert report.get("table") is None
+    assert report.maximum_compatible_version() == cif_parser.Version.V1_1
+
+
+# =============================================================================
+# leading_prose.cif - Non-CIF text before the first data block
+# =============================================================================
+
+
+def test_leading_prose_rejected_by_default(leading_prose_cif):
+    """Test that prose before the first block is an error unless skipped."""
+    with pytest.raises(ValueError):
+        cif_parser.parse(leading_prose_cif.read_text())
+
+
+def test_leading_prose_skipped(leading_prose_cif):
+    """Test skipping prose before the first block and reporting it."""
+    result = cif_parser.parse_with_options(
+        leading_prose_cif.read_text(), skip_leading_garbage=True
+    )
+
+    block = result.document.first_block()
+    assert block.name == "x"
+    assert block.get_item("_cell_length_a").span.start_line == 5
+
+    assert len(result.notes) == 1
+    note = result.notes[0]
+    assert note.note_id == "leading-content-skipped"
+    assert (note.span.start_line, note.span.end_line) == (1, 3)
+
+
+# =============================================================================
+# Shared document views - blocks and loops do not copy the document
+# =============================================================================
+
+
+def _peak_rss_bytes():
+    """Peak resident set size of this process, in bytes."""
+    resource = pytest.importorskip("resource")
+    peak = resource.getrusage(resource.RUSAGE_SELF).ru_maxrss
+    # Linux reports kilobytes, macOS bytes
+    return peak if sys.platform == "darwin" else peak * 1024
+
+
+def test_loop_views_share_document():
+    """Test that block and loop views cost far less than copies of the document."""
+    parts = ["data_many\n"]
+    for i in range(1000):
+        parts.append(f"loop_\n_l{i}.a\n_l{i}.b\n_l{i}.label\n")
+        parts.extend(f"{row} {row}.5(2) 'label {i} {row}'\n" for row in range(20))
+    text = "".join(parts)
+    doc = cif_parser.parse(text)
+
+    # Every view stays alive while the peak is measured, so a view that
+    # copied its block would add one block per view
+    before = _peak_rss_bytes()
+    blocks = [doc.first_block() for _ in range(1000)]
+    loops = [block.get_loop(i) for i, block in enumerate(blocks)]
+    views_grown = _peak_rss_bytes() - before
+
+    # Baseline: what two real copies of the document cost
+    before = _peak_rss_bytes()
+    copies = [cif_parser.parse(text) for _ in range(2)]
+    copies_grown = _peak_rss_bytes() - before
+
+    assert views_grown < copies_grown / 2
+    assert len(copies) == 2
+    assert len(loops) == 1000
+    assert loops[999].tags == ["_l999.a", "_l999.b", "_l999.label"]
+    assert loops[999].get_by_tag(19, "_l999.label").text == "label 999 19"
+
+
+def test_views_outlive_document_handle():
+    """Test that blocks and loops stay valid after the document is dropped."""
+    doc = cif_parser.parse("data_a\nloop_\n_x\n1\n2\nsave_f\nloop_\n_y\n3\nsave_\n")
+    block = doc.first_block()
+    frame_loop = block.get_frame(0).get_loop(0)
+    del doc
+
+    assert block.find_loop("_x").get_column("_x")[1].numeric == 2.0
+    assert frame_loop.get(0, 0).numeric == 3.0
+    assert [row["_x"].numeric for row in block.loops[0]] == [1.0, 2.0]
+
+
+# =============================================================================
+# cif2_nested.cif - Walking nested lists and tables by path
+# =============================================================================
+
+
+def test_value_walk_paths(cif2_nested_cif):
+    """Test walk() yields depth-first paths in document order."""
+    doc = cif_parser.parse_file(str(cif2_nested_cif))
+    record = doc.first_block().get_item("_record")
+
+    paths = [path for path, _ in record.walk()]
+    assert paths == [
+        "",
+        "name",
+        "tags",
+        "tags[0]",
+        "tags[1]",
+        "tags[2]",
+        "tags[2].deep",
+        "tags[2].deep.leaf",
+        "empty",
+    ]
+
+
+def test_value_get_path_round_trip(cif2_nested_cif):
+    """Test every walked path leads back to its value."""
+    doc = cif_parser.parse_file(str(cif2_nested_cif))
+    imports = doc.first_block().get_item("_import.get")
+
+    for path, value in imports.walk():
+        assert imports.get_path(path) == value
+    assert imports.get_path("[1].file").text == "templ_enum.cif"
+    assert imports.get_path("[7]") is None
+    with pytest.raises(ValueError):
+        imports.get_path("[x]")
+
+
+def test_atom_site_csv_round_trip(loops_cif):
+    """Test the atom_site loop survives a trip through CSV."""
+    doc = cif_parser.parse_file(str(loops_cif))
+    block = doc.first_block()
+    atoms = block.find_loop("_atom_site_label")
+
+    csv = block.category_to_csv("atom_site")
+    assert csv.splitlines()[3] == "N1,N,0.3456,0.4567,0.5678,0.95"
+    assert atoms.to_csv(strip_category_prefix=True).startswith("label,type_symbol,")
+
+    back = cif_parser.csv_to_loop(atoms.tags, csv)
+    assert back.tags == atoms.tags
+    assert len(back) == 5
+    assert back.get_by_tag(2, "_atom_site_occupancy").numeric == 0.95
+    assert block.category_to_csv("refln") is None
+
+
+def test_screen_numeric_anomalies(numeric_anomalies_cif):
+    """Test exactly the planted values are flagged."""
+    doc = cif_parser.parse_file(str(numeric_anomalies_cif))
+    block = doc.first_block()
+
+    found = [
+        (a.loop_index, a.tag, a.row, a.reason)
+        for a in block.screen_numeric_anomalies()
+    ]
+    assert found == [
+        (0, "_atom_site_fract_y", 2, "out_of_range"),
+        (0, "_atom_site_U_iso_or_equiv", 6, "outlier"),
+        (0, "_atom_site_occupancy", 4, "out_of_range"),
+        (1, "_geom_angle", 2, "out_of_range"),
+        (2, "_geom_bond_distance", 4, "outlier"),
+    ]
+
+    custom = block.screen_numeric_anomalies(
+        iqr_factor=1000.0,
+        known_ranges=False,
+        ranges={"_geom_bond_distance": (1.0, 2.0)},
+    )
+    assert [(a.row, a.bounds) for a in custom] == [(4, (1.0, 2.0)), (5, (1.0, 2.0))]
+
+
+def test_column_stats(numeric_anomalies_cif):
+    """Test statistics of a numeric column."""
+    doc = cif_parser.parse_file(str(numeric_anomalies_cif))
+    atoms = doc.first_block().find_loop("_atom_site_label")
+
+    stats = atoms.column_stats("_atom_site_occupancy")
+    assert (stats.count, stats.numeric, stats.unknown) == (8, 7, 1)
+    assert stats.max == 11.0
+    assert stats.iqr == 0.0
+    assert stats.is_numeric()
+    assert not atoms.column_stats("_atom_site_label").is_numeric()
+    assert atoms.column_stats("_atom_site_missing") is None
@@ -1,61 +1,141 @@
 """Type stubs for the native CIF validator module (_cif_validator).
 
-This module provides the Rust-based validation implementation with
-precise span information for errors and warnings.
+DDLm-based CIF validation library with Python bindings.
+Provides precise span information for errors and warnings.
+
+Example usage:
+    from cif_validator import Validator, ValidationMode
+
+    validator = Validator()
+    validator.add_dictionary(dictionary_content)
+    validator.set_mode(ValidationMode.Strict)
+
+    result = validator.validate(cif_content)
+    for error in result.errors:
+        print(f"Line {error.span.start_line}: {error.message}")
+
+The first line of every docstring here matches the Rust doc comment that
+becomes the runtime ``__doc__``; ``python/check_stubs.py`` keeps the two in
+step.
 """
 
-from enum import IntEnum
+from typing import ClassVar
 
 __version__: str
 __author__: str
 
-class ErrorCategory(IntEnum):
-    """Categories of validation errors."""
+class ErrorCategory:
+    """Kind of validation error."""
 
-    UnknownDataName = 0
+    UnknownDataName: ClassVar[ErrorCategory]
     """Unknown data name (not in dictionary)"""
-    TypeError = 1
+    TypeError: ClassVar[ErrorCategory]
     """Type mismatch (e.g., text where Real expected)"""
-    RangeError = 2
+    RangeError: ClassVar[ErrorCategory]
     """Value outside allowed range"""
-    EnumerationError = 3
+    EnumerationError: ClassVar[ErrorCategory]
     """Value not in enumerated set"""
-    MissingMandatory = 4
+    MissingMandatory: ClassVar[ErrorCategory]
     """Missing mandatory item"""
-    LoopStructure = 5
+    LoopStructure: ClassVar[ErrorCategory]
     """Invalid loop structure"""
-    LinkError = 6
+    LinkError: ClassVar[ErrorCategory]
     """Foreign key reference error"""
-    DictionaryError = 7
+    DictionaryError: ClassVar[ErrorCategory]
     """Dictionary loading/parsing error"""
+    DuplicateItem: ClassVar[ErrorCategory]
+    """Item given more values than its category allows"""
+    UnknownMandatoryValue: ClassVar[ErrorCategory]
+    """Mandatory or key item present with only unknown (`?`) values"""
 
-class WarningCategory(IntEnum):
-    """Categories of validation warnings."""
+    @property
+    def name(self) -> str:
+        """Variant name, such as "TypeError"."""
+        ...
+
+    def __str__(self) -> str:
+        """Lower-case description, such as "type error"."""
+        ...
 
-    MixedCategories = 0
+    def __repr__(self) -> str:
+        """Debug representation, such as "ErrorCategory.TypeError"."""
+        ...
+
+    def __eq__(self, other: object) -> bool: ...
+    def __int__(self) -> int: ...
+
+class WarningCategory:
+    """Kind of validation warning."""
+
+    MixedCategories: ClassVar[WarningCategory]
     """Mixed categories in a loop"""
-    DeprecatedItem = 1
+    DeprecatedItem: ClassVar[WarningCategory]
     """Deprecated item usage"""
-    Style = 2
+    Style: ClassVar[WarningCategory]
     """Style recommendation"""
-    UnknownItem = 3
+    UnknownItem: ClassVar[WarningCategory]
     """Unknown item in lenient mode"""
+    DuplicateItem: ClassVar[WarningCategory]
+    """Same item appears in more than one loop"""
+    SuspiciousTextField: ClassVar[WarningCategory]
+    """Text value contains lines that look like CIF structure"""
+    UnknownMandatoryValue: ClassVar[WarningCategory]
+    """Mandatory or key item present with only unknown (`?`) values"""
+    NotApplicableMandatory: ClassVar[WarningCategory]
+    """Mandatory or key item given as not applicable (`.`), in pedantic mode"""
+    LoopedSetCategory: ClassVar[WarningCategory]
+    """Set-category items written as a one-row loop"""
+    DanglingBlockLink: ClassVar[WarningCategory]
+    """Pointer to a data block that is not in the document"""
+    ImpliedType: ClassVar[WarningCategory]
+    """Item whose content type is implied and so was not checked, in pedantic mode"""
+
+    @property
+    def name(self) -> str:
+        """Variant name, such as "DeprecatedItem"."""
+        ...
 
-class ValidationMode(IntEnum):
-    """Validation strictness modes."""
+    def __str__(self) -> str:
+        """Lower-case description, such as "deprecated item"."""
+        ...
+
+    def __repr__(self) -> str:
+        """Debug representation, such as "WarningCategory.DeprecatedItem"."""
+        ...
 
-    Strict = 0
-    """Strict validation - all checks enabled, unknown items are errors"""
-    Lenient = 1
+    def __eq__(self, other: object) -> bool: ...
+    def __int__(self) -> int: ...
+
+class ValidationMode:
+    """How strictly to validate."""
+
+    Strict: ClassVar[ValidationMode]
+    """Strict validation - all checks enabled"""
+    Lenient: ClassVar[ValidationMode]
     """Lenient validation - unknown items are warnings"""
-    Pedantic = 2
-    """Pedantic validation - extra style checks enabled"""
+    Pedantic: ClassVar[ValidationMode]
+    """Pedantic validation - extra style checks"""
+
+    @property
+    def name(self) -> str:
+        """Variant name, such as "Strict"."""
+        ...
+
+    def __str__(self) -> str:
+        """Lower-case name, such as "strict"."""
+        ...
+
+    def __repr__(self) -> str:
+        """Debug representation, such as "ValidationMode.Strict"."""
+        ...
+
+    def __eq__(self, other: object) -> bool: ...
+    def __int__(self) -> int: ...
 
 class Span:
-    """Source location information for a token or error.
+    """Source location of a value or error in the CIF file.
 
-    Tracks where a value or error appears in the source CIF file.
-    All positions are 1-indexed.
+    Line and column numbers are 1-indexed.
 
     Attributes:
         start_line: Starting line number (1-indexed)
@@ -85,7 +165,7 @@ class Span:
         ...
 
     def contains(self, line: int, col: int) -> bool:
-        """Check if a position is within this span.
+        """Check if a line and column position is within this span.
 
         Args:
             line: Line number to check (1-indexed)
@@ -124,7 +204,7 @@ class ValidationError:
 
     @property
     def message(self) -> str:
-        """Human-readable error message."""
+        """Human-readable message."""
         ...
 
     @property
@@ -161,7 +241,7 @@ class ValidationError:
         ...
 
 class ValidationWarning:
-    """A validation warning (non-fatal issue).
+    """A validation warning (non-fatal).
 
     Attributes:
         category: Warning category
@@ -176,12 +256,12 @@ class ValidationWarning:
 
     @property
     def message(self) -> str:
-        """Human-readable warning message."""
+        """Human-readable message."""
         ...
 
     @property
     def span(self) -> Span:
-        """Source location in the CIF file."""
+        """Source location."""
         ...
 
     def __str__(self) -> str:
@@ -214,22 +294,22 @@ class ValidationResult:
 
     @property
     def errors(self) -> list[ValidationError]:
-        """List of validation errors."""
+        """Validation errors encountered."""
         ...
 
     @property
     def warnings(self) -> list[ValidationWarning]:
-        """List of validation warnings."""
+        """Validation warnings (non-fatal issues)."""
         ...
 
     @property
     def error_count(self) -> int:
-        """Number of validation errors."""
+        """Get the number of errors."""
         ...
 
     @property
     def warning_count(self) -> int:
-        """Number of validation warnings."""
+        """Get the number of warnings."""
         ...
 
     def __str__(self) -> str:
@@ -245,10 +325,10 @@ class ValidationResult:
         ...
 
 class Validator:
-    """CIF Validator for validating documents against DDLm dictionaries.
+    """CIF Validator class for validating CIF documents against DDLm dictionaries.
 
-    The Validator class allows you to load one or more dictionaries and
-    validate multiple CIF documents against them.
+    Load one or more dictionaries, then validate any number of CIF
+    documents against them.
 
     Example:
         >>> validator = Validator()
@@ -264,7 +344,7 @@ class Validator:
     """
 
     def __init__(self) -> None:
-        """Create a new validator with default settings."""
+        """Create a new validator."""
         ...
 
     def add_dictionary(self, dictionary_content: str) -> None:
@@ -294,13 +374,13 @@ class Validator:
         """Set the validation mode.
 
         Args:
-            mode: Validation strictness mode
+            mode: How strictly to validate
         """
         ...
 
     @property
     def mode(self) -> ValidationMode:
-        """Current validation mode."""
+        """Get the current validation mode."""
         ...
 
     def validate(self, cif_content: str) -> ValidationResult:
@@ -333,11 +413,10 @@ class Validator:
         ...
 
 def validate(cif_content: str, dictionary_content: str) -> ValidationResult:
-    """Validate a CIF string against a dictionary string.
+    """Validate a CIF string against a dictionary string (convenience function).
 
-    This is a convenience function for one-shot validation. For validating
-    multiple documents against the same dictionary, use the Validator class
-    instead for better performance.
+    This is a simple one-shot validation function. For validating multiple
+    documents against the same dictionary, use the Validator class instead.
 
     Args:
         cif_content: CIF file content as string
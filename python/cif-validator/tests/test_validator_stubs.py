@@ -0,0 +1,18 @@
+"""Check the cif_validator type stubs against the compiled module.
+
+Fails when a class, function or method added on the Rust side has no stub,
+its stub disagrees with the runtime signature or summary line, or its Rust
+doc comment does not name each parameter. See python/check_stubs.py.
+"""
+
+import sys
+from pathlib import Path
+
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from check_stubs import check_package  # noqa: E402
+
+
+def test_stubs_match_native_module():
+    """Every runtime member has a stub with the same signature and summary."""
+    assert check_package("cif_validator") == []